@@ -18,6 +18,7 @@ enum TWCurve {
     TWCurveNIST256p1              /* "nist256p1" */,
     TWCurveED25519ExtendedCardano /* "ed25519-cardano-seed" */,
     TWCurveStarkex                /* "starkex" */,
+    TWCurveSr25519                /* "sr25519" */,
     TWCurveNone
 };
 
@@ -20,6 +20,7 @@ enum TWPublicKeyType {
     TWPublicKeyTypeCURVE25519 = 6,
     TWPublicKeyTypeED25519Cardano = 7,
     TWPublicKeyTypeStarkex = 8,
+    TWPublicKeyTypeSr25519 = 9,
 };
 
 TW_EXTERN_C_END
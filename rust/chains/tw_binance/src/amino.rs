@@ -2,7 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use quick_protobuf::MessageWrite;
+use quick_protobuf::{BytesReader, MessageWrite};
 use tw_encoding::{EncodingError, EncodingResult};
 use tw_memory::Data;
 use tw_proto::serialize;
@@ -57,6 +57,36 @@ impl AminoEncoder {
     }
 }
 
+pub struct AminoDecoder;
+
+impl AminoDecoder {
+    /// The Amino prefix length.
+    pub const PREFIX_LEN: usize = 4;
+
+    /// Splits the Amino content into the prefix and the rest data.
+    pub fn split_prefix(content: &[u8]) -> EncodingResult<([u8; 4], &[u8])> {
+        if content.len() < Self::PREFIX_LEN {
+            return Err(EncodingError::InvalidInput);
+        }
+        let (prefix, rest) = content.split_at(Self::PREFIX_LEN);
+        let prefix = prefix.try_into().expect("Prefix length is checked above");
+        Ok((prefix, rest))
+    }
+
+    /// Reads the content prefixed with its size.
+    /// Returns an error if there are trailing bytes after the content.
+    pub fn decode_size_prefixed(data: &[u8]) -> EncodingResult<&[u8]> {
+        let mut reader = BytesReader::from_bytes(data);
+        let content = reader
+            .read_bytes(data)
+            .map_err(|_| EncodingError::InvalidInput)?;
+        if !reader.is_eof() {
+            return Err(EncodingError::InvalidInput);
+        }
+        Ok(content)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +115,23 @@ mod tests {
 
         let expected = input.expected.decode_hex().unwrap();
         assert_eq!(actual, expected);
+
+        // Check if the encoded data can be decoded back.
+        let decoded = if input.content_size_prefixed {
+            AminoDecoder::decode_size_prefixed(&actual)
+                .expect("Error on Amino decoding with content size prefix")
+        } else {
+            actual.as_slice()
+        };
+        let (actual_prefix, actual_content) = AminoDecoder::split_prefix(decoded).unwrap();
+        assert_eq!(
+            actual_prefix.as_slice(),
+            &prefix[..AminoDecoder::PREFIX_LEN]
+        );
+        assert_eq!(
+            actual_content,
+            [&prefix[AminoDecoder::PREFIX_LEN..], content.as_slice()].concat()
+        );
     }
 
     #[test]
@@ -122,4 +169,18 @@ mod tests {
             expected: "dc020b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0102030405060708",
         });
     }
+
+    #[test]
+    fn test_amino_decode_size_prefixed_error() {
+        // The content is shorter than expected.
+        let data = "0d0b0c0d0e0102030405060708".decode_hex().unwrap();
+        AminoDecoder::decode_size_prefixed(&data).unwrap_err();
+
+        // There are trailing bytes after the content.
+        let data = "0b0b0c0d0e010203040506070809".decode_hex().unwrap();
+        AminoDecoder::decode_size_prefixed(&data).unwrap_err();
+
+        // The prefix is too short.
+        AminoDecoder::split_prefix(&[0x0b, 0x0c, 0x0d]).unwrap_err();
+    }
 }
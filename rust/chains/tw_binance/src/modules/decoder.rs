@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::amino::AminoDecoder;
+use crate::modules::preimager::{JsonPreimager, JsonTxPreimage};
+use crate::modules::serializer::{PUBLIC_KEY_PREFIX, TRANSACTION_AMINO_PREFIX};
+use crate::modules::tx_builder::TxBuilder;
+use crate::signature::BinanceSignature;
+use crate::transaction::message::BinanceMessageEnum;
+use crate::transaction::UnsignedTransaction;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::Binance::Proto;
+
+pub struct BinanceDecoder;
+
+impl BinanceDecoder {
+    /// Decodes an Amino encoded signed transaction, and verifies its signatures.
+    pub fn decode_transaction(
+        coin: &dyn CoinContext,
+        input: Proto::DecodingTransactionInput<'_>,
+    ) -> Proto::DecodingTransactionOutput<'static> {
+        Self::decode_transaction_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+
+    fn decode_transaction_impl(
+        coin: &dyn CoinContext,
+        input: Proto::DecodingTransactionInput<'_>,
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let content = AminoDecoder::decode_size_prefixed(&input.encoded_tx)?;
+        let (prefix, tx_data) = AminoDecoder::split_prefix(content)?;
+        if prefix != TRANSACTION_AMINO_PREFIX {
+            return Err(SigningError(SigningErrorType::Error_input_parse));
+        }
+        let tx: Proto::Transaction = tw_proto::deserialize(tx_data)?;
+
+        let msgs = tx
+            .msgs
+            .iter()
+            .map(|msg| BinanceMessageEnum::from_amino_protobuf(coin, msg))
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let signatures = tx
+            .signatures
+            .iter()
+            .map(|sign| Self::decode_signature(sign.as_ref()))
+            .collect::<SigningResult<Vec<_>>>()?;
+        let first_signature = signatures
+            .first()
+            .ok_or(SigningError(SigningErrorType::Error_signatures_count))?;
+
+        let mut unsigned = UnsignedTransaction {
+            account_number: first_signature.account_number,
+            chain_id: input.chain_id.to_string(),
+            data: (!tx.data.is_empty()).then(|| tx.data.to_vec()),
+            memo: tx.memo.to_string(),
+            msgs,
+            sequence: first_signature.sequence,
+            source: tx.source,
+        };
+
+        // Every signer signs the transaction with its own account and sequence numbers.
+        for signature in signatures.iter() {
+            unsigned.account_number = signature.account_number;
+            unsigned.sequence = signature.sequence;
+            Self::verify_signature(&unsigned, signature)?;
+        }
+
+        unsigned.account_number = first_signature.account_number;
+        unsigned.sequence = first_signature.sequence;
+        let transaction = TxBuilder::unsigned_tx_to_proto(&unsigned)?;
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            signatures,
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    fn decode_signature(encoded: &[u8]) -> SigningResult<Proto::DecodedSignature<'static>> {
+        // There is no Amino prefix as the signature is serialized as a plain Protobuf message.
+        let sign: Proto::Signature = tw_proto::deserialize(encoded)?;
+
+        // The public key is encoded as `PUBLIC_KEY_PREFIX || public_key_len || public_key`.
+        let (prefix, key_data) = AminoDecoder::split_prefix(&sign.pub_key)?;
+        let Some((&public_key_len, public_key)) = key_data.split_first() else {
+            return Err(SigningError(SigningErrorType::Error_input_parse));
+        };
+        if prefix != PUBLIC_KEY_PREFIX || public_key.len() != public_key_len as usize {
+            return Err(SigningError(SigningErrorType::Error_input_parse));
+        }
+
+        Ok(Proto::DecodedSignature {
+            public_key: public_key.to_vec().into(),
+            signature: sign.signature.to_vec().into(),
+            account_number: sign.account_number,
+            sequence: sign.sequence,
+        })
+    }
+
+    fn verify_signature(
+        unsigned: &UnsignedTransaction,
+        signature: &Proto::DecodedSignature<'_>,
+    ) -> SigningResult<()> {
+        let public_key = secp256k1::PublicKey::try_from(signature.public_key.as_ref())?;
+        let sign = BinanceSignature::try_from(signature.signature.as_ref())?;
+
+        let JsonTxPreimage { tx_hash, .. } = JsonPreimager::preimage_hash(unsigned)?;
+        if !public_key.verify(sign, tx_hash) {
+            return Err(SigningError(SigningErrorType::Error_signing));
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod decoder;
 pub mod preimager;
 pub mod serializer;
 pub mod tx_builder;
@@ -4,6 +4,7 @@
 
 use crate::modules::tx_builder::TxBuilder;
 use crate::modules::wallet_connect::types::SignAminoRequest;
+use crate::transaction::UnsignedTransaction;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_coin_entry::modules::wallet_connector::WalletConnector;
@@ -32,6 +33,7 @@ impl BinanceWalletConnector {
     ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
         match request.method {
             WCProto::Method::CosmosSignAmino => Self::parse_sign_amino_request(coin, request),
+            WCProto::Method::BnbSign => Self::parse_bnb_sign_request(coin, request),
             _ => Err(SigningError(SigningErrorType::Error_not_supported)),
         }
     }
@@ -51,4 +53,20 @@ impl BinanceWalletConnector {
             ..WCProto::ParseRequestOutput::default()
         })
     }
+
+    pub fn parse_bnb_sign_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        // The `bnb_sign` request contains the `signDoc` itself.
+        let sign_doc: UnsignedTransaction = serde_json::from_str(&request.payload)
+            .map_err(|_| SigningError(SigningErrorType::Error_input_parse))?;
+
+        let signing_input = TxBuilder::unsigned_tx_to_proto(&sign_doc)?;
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::binance(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
 }
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::CoinAddress;
 use tw_coin_entry::error::SigningResult;
-use tw_encoding::hex::as_hex;
+use tw_encoding::hex::{as_hex, from_hex};
 use tw_memory::Data;
 use tw_proto::Binance::Proto;
 
@@ -20,7 +20,7 @@ pub struct HTLTOrder {
     pub expected_income: String,
     pub from: BinanceAddress,
     pub height_span: i64,
-    #[serde(serialize_with = "as_hex")]
+    #[serde(serialize_with = "as_hex", deserialize_with = "from_hex")]
     pub random_number_hash: Data,
     pub recipient_other_chain: String,
     pub sender_other_chain: String,
@@ -84,7 +84,7 @@ impl TWBinanceProto for HTLTOrder {
 pub struct DepositHTLTOrder {
     pub amount: Vec<Token>,
     pub from: BinanceAddress,
-    #[serde(serialize_with = "as_hex")]
+    #[serde(serialize_with = "as_hex", deserialize_with = "from_hex")]
     pub swap_id: Data,
 }
 
@@ -127,9 +127,9 @@ impl TWBinanceProto for DepositHTLTOrder {
 #[derive(Deserialize, Serialize)]
 pub struct ClaimHTLTOrder {
     pub from: BinanceAddress,
-    #[serde(serialize_with = "as_hex")]
+    #[serde(serialize_with = "as_hex", deserialize_with = "from_hex")]
     pub random_number: Data,
-    #[serde(serialize_with = "as_hex")]
+    #[serde(serialize_with = "as_hex", deserialize_with = "from_hex")]
     pub swap_id: Data,
 }
 
@@ -171,7 +171,7 @@ impl TWBinanceProto for ClaimHTLTOrder {
 #[derive(Deserialize, Serialize)]
 pub struct RefundHTLTOrder {
     pub from: BinanceAddress,
-    #[serde(serialize_with = "as_hex")]
+    #[serde(serialize_with = "as_hex", deserialize_with = "from_hex")]
     pub swap_id: Data,
 }
 
@@ -2,10 +2,13 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use serde::{Deserialize, Serialize, Serializer};
+use crate::amino::AminoDecoder;
+use quick_protobuf::MessageRead;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_memory::Data;
+use tw_misc::serde::as_string;
 use tw_proto::Binance::Proto::{self, mod_SigningInput::OneOforder_oneof as BinanceMessageProto};
 
 pub mod htlt_order;
@@ -196,6 +199,103 @@ impl TWBinanceProto for BinanceMessageEnum {
     }
 }
 
+impl BinanceMessageEnum {
+    /// Decodes an Amino encoded message.
+    /// The message type is detected by the Amino prefix.
+    pub fn from_amino_protobuf(coin: &dyn CoinContext, encoded: &[u8]) -> SigningResult<Self> {
+        use side_chain_delegate::{
+            SideDelegateOrderValue, SideRedelegateOrderValue, SideUndelegateOrderValue,
+        };
+
+        let (prefix, content) = AminoDecoder::split_prefix(encoded)?;
+        match prefix {
+            htlt_order::HTLTOrder::PREFIX => decode_msg::<htlt_order::HTLTOrder>(coin, content)
+                .map(BinanceMessageEnum::HTLTOrder),
+            htlt_order::DepositHTLTOrder::PREFIX => {
+                decode_msg::<htlt_order::DepositHTLTOrder>(coin, content)
+                    .map(BinanceMessageEnum::DepositHTLTOrder)
+            },
+            htlt_order::ClaimHTLTOrder::PREFIX => {
+                decode_msg::<htlt_order::ClaimHTLTOrder>(coin, content)
+                    .map(BinanceMessageEnum::ClaimHTLTOrder)
+            },
+            htlt_order::RefundHTLTOrder::PREFIX => {
+                decode_msg::<htlt_order::RefundHTLTOrder>(coin, content)
+                    .map(BinanceMessageEnum::RefundHTLTOrder)
+            },
+            send_order::SendOrder::PREFIX => decode_msg::<send_order::SendOrder>(coin, content)
+                .map(BinanceMessageEnum::SendOrder),
+            SideDelegateOrderValue::PREFIX => {
+                decode_msg::<side_chain_delegate::SideDelegateOrder>(coin, content)
+                    .map(BinanceMessageEnum::SideDelegateOrder)
+            },
+            SideRedelegateOrderValue::PREFIX => {
+                decode_msg::<side_chain_delegate::SideRedelegateOrder>(coin, content)
+                    .map(BinanceMessageEnum::SideRedelegateOrder)
+            },
+            SideUndelegateOrderValue::PREFIX => {
+                decode_msg::<side_chain_delegate::SideUndelegateOrder>(coin, content)
+                    .map(BinanceMessageEnum::SideUndelegateOrder)
+            },
+            time_lock_order::TimeLockOrder::PREFIX => {
+                decode_msg::<time_lock_order::TimeLockOrder>(coin, content)
+                    .map(BinanceMessageEnum::TimeLockOrder)
+            },
+            time_lock_order::TimeRelockOrder::PREFIX => {
+                decode_msg::<time_lock_order::TimeRelockOrder>(coin, content)
+                    .map(BinanceMessageEnum::TimeRelockOrder)
+            },
+            time_lock_order::TimeUnlockOrder::PREFIX => {
+                decode_msg::<time_lock_order::TimeUnlockOrder>(coin, content)
+                    .map(BinanceMessageEnum::TimeUnlockOrder)
+            },
+            token_order::TokenFreezeOrder::PREFIX => {
+                decode_msg::<token_order::TokenFreezeOrder>(coin, content)
+                    .map(BinanceMessageEnum::TokenFreezeOrder)
+            },
+            token_order::TokenUnfreezeOrder::PREFIX => {
+                decode_msg::<token_order::TokenUnfreezeOrder>(coin, content)
+                    .map(BinanceMessageEnum::TokenUnfreezeOrder)
+            },
+            token_order::TokenIssueOrder::PREFIX => {
+                decode_msg::<token_order::TokenIssueOrder>(coin, content)
+                    .map(BinanceMessageEnum::TokenIssueOrder)
+            },
+            token_order::TokenMintOrder::PREFIX => {
+                decode_msg::<token_order::TokenMintOrder>(coin, content)
+                    .map(BinanceMessageEnum::TokenMintOrder)
+            },
+            token_order::TokenBurnOrder::PREFIX => {
+                decode_msg::<token_order::TokenBurnOrder>(coin, content)
+                    .map(BinanceMessageEnum::TokenBurnOrder)
+            },
+            trade_order::NewTradeOrder::PREFIX => {
+                decode_msg::<trade_order::NewTradeOrder>(coin, content)
+                    .map(BinanceMessageEnum::NewTradeOrder)
+            },
+            trade_order::CancelTradeOrder::PREFIX => {
+                decode_msg::<trade_order::CancelTradeOrder>(coin, content)
+                    .map(BinanceMessageEnum::CancelTradeOrder)
+            },
+            tranfer_out_order::TransferOutOrder::PREFIX => {
+                decode_msg::<tranfer_out_order::TransferOutOrder>(coin, content)
+                    .map(BinanceMessageEnum::TransferOutOrder)
+            },
+            _ => Err(SigningError(SigningErrorType::Error_invalid_params)),
+        }
+    }
+}
+
+/// Deserializes the Protobuf `content` and converts it into the Binance message.
+fn decode_msg<'a, T>(coin: &dyn CoinContext, content: &'a [u8]) -> SigningResult<T>
+where
+    T: TWBinanceProto,
+    T::Proto<'a>: MessageRead<'a>,
+{
+    let msg: T::Proto<'a> = tw_proto::deserialize(content)?;
+    T::from_tw_proto(coin, &msg)
+}
+
 impl<'a> AsRef<dyn BinanceMessage + 'a> for BinanceMessageEnum {
     fn as_ref(&self) -> &(dyn BinanceMessage + 'a) {
         match self {
@@ -248,6 +348,24 @@ impl Token {
         .serialize(serializer)
     }
 
+    pub fn deserialize_with_string_amount<'de, D>(deserializer: D) -> Result<Token, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct TokenWithStringAmount {
+            #[serde(with = "as_string")]
+            amount: i64,
+            denom: String,
+        }
+
+        let token = TokenWithStringAmount::deserialize(deserializer)?;
+        Ok(Token {
+            amount: token.amount,
+            denom: token.denom,
+        })
+    }
+
     fn from_tw_proto(msg: &Proto::mod_SendOrder::Token<'_>) -> Self {
         Token {
             denom: msg.denom.to_string(),
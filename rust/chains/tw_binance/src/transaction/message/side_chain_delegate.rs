@@ -18,7 +18,10 @@ pub type SideDelegateOrder = Typed<SideDelegateOrderValue>;
 /// cosmos-sdk/MsgSideChainDelegate
 #[derive(Deserialize, Serialize)]
 pub struct SideDelegateOrderValue {
-    #[serde(serialize_with = "Token::serialize_with_string_amount")]
+    #[serde(
+        serialize_with = "Token::serialize_with_string_amount",
+        deserialize_with = "Token::deserialize_with_string_amount"
+    )]
     pub delegation: Token,
     pub delegator_addr: BinanceAddress,
     pub side_chain_id: String,
@@ -80,7 +83,10 @@ pub type SideRedelegateOrder = Typed<SideRedelegateOrderValue>;
 /// cosmos-sdk/MsgSideChainRedelegate
 #[derive(Deserialize, Serialize)]
 pub struct SideRedelegateOrderValue {
-    #[serde(serialize_with = "Token::serialize_with_string_amount")]
+    #[serde(
+        serialize_with = "Token::serialize_with_string_amount",
+        deserialize_with = "Token::deserialize_with_string_amount"
+    )]
     pub amount: Token,
     pub delegator_addr: BinanceAddress,
     pub side_chain_id: String,
@@ -148,7 +154,10 @@ pub type SideUndelegateOrder = Typed<SideUndelegateOrderValue>;
 /// cosmos-sdk/MsgSideChainUndelegate
 #[derive(Deserialize, Serialize)]
 pub struct SideUndelegateOrderValue {
-    #[serde(serialize_with = "Token::serialize_with_string_amount")]
+    #[serde(
+        serialize_with = "Token::serialize_with_string_amount",
+        deserialize_with = "Token::deserialize_with_string_amount"
+    )]
     pub amount: Token,
     pub delegator_addr: BinanceAddress,
     pub side_chain_id: String,
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::shelley::KEY_HASH_LEN;
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::cbor::{self, Value};
+use tw_hash::blake2::blake2_b;
+use tw_hash::crc32::crc32;
+use tw_hash::sha3::sha3_256;
+use tw_keypair::ed25519::cardano::ExtendedPublicKey;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+
+/// CBOR tag of the embedded payload.
+const PAYLOAD_TAG: u64 = 24;
+/// The address is derived from the first half of the extended public key (public key + chain code).
+const XPUB_LEN: usize = 64;
+
+/// Byron (legacy, V2) address:
+/// `Base58(CBOR([Tag(24, bytes(payload)), crc32(payload)]))`
+/// where `payload = CBOR([bytes(root), attributes, type])`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ByronAddress {
+    /// CBOR-encoded payload, kept as is to preserve attributes of V1 addresses.
+    payload: Data,
+}
+
+impl ByronAddress {
+    /// Creates a V2 address with empty attributes from a Cardano extended public key.
+    pub fn with_public_key(public_key: &ExtendedPublicKey) -> AddressResult<ByronAddress> {
+        let public_key = public_key.to_vec();
+        let root = key_hash(&public_key[..XPUB_LEN])?;
+        let payload = encode_cbor(&Value::Array(vec![
+            Value::Bytes(root),
+            Value::Map(Vec::new()),
+            Value::Integer(0.into()),
+        ]))?;
+        Ok(ByronAddress { payload })
+    }
+
+    /// Parses and validates the CBOR representation of the address.
+    pub fn from_bytes(data: &[u8]) -> AddressResult<ByronAddress> {
+        let value: Value = cbor::decode(data).map_err(|_| AddressError::InvalidInput)?;
+        let Value::Array(elements) = value else {
+            return Err(AddressError::InvalidInput);
+        };
+        let (Some(Value::Tag(PAYLOAD_TAG, payload)), Some(Value::Integer(crc))) =
+            (elements.first(), elements.get(1))
+        else {
+            return Err(AddressError::InvalidInput);
+        };
+        let Value::Bytes(payload) = payload.as_ref() else {
+            return Err(AddressError::InvalidInput);
+        };
+
+        if u64::try_from(*crc).ok() != Some(crc32(payload) as u64) {
+            return Err(AddressError::InvalidInput);
+        }
+
+        // The payload is expected to be `[root, attributes, type]`.
+        let payload_value: Value =
+            cbor::decode(payload.as_slice()).map_err(|_| AddressError::InvalidInput)?;
+        match payload_value {
+            Value::Array(items) if items.len() >= 3 && items[0].is_bytes() => (),
+            _ => return Err(AddressError::InvalidInput),
+        }
+
+        Ok(ByronAddress {
+            payload: payload.clone(),
+        })
+    }
+
+    /// Returns the CBOR representation of the address.
+    pub fn data(&self) -> Data {
+        let value = Value::Array(vec![
+            Value::Tag(PAYLOAD_TAG, Box::new(Value::Bytes(self.payload.clone()))),
+            Value::Integer(crc32(&self.payload).into()),
+        ]);
+        // Encoding of the well-formed value cannot fail.
+        cbor::encode(&value).unwrap_or_default()
+    }
+}
+
+impl FromStr for ByronAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = base58::decode(s, Alphabet::BITCOIN).map_err(|_| AddressError::InvalidInput)?;
+        ByronAddress::from_bytes(&data)
+    }
+}
+
+impl fmt::Display for ByronAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base58::encode(&self.data(), Alphabet::BITCOIN))
+    }
+}
+
+/// Address root: `blake2b-224(sha3_256(CBOR([0, [0, bytes(xpub)], {}])))`.
+fn key_hash(xpub: &[u8]) -> AddressResult<Data> {
+    let spending_data = encode_cbor(&Value::Array(vec![
+        Value::Integer(0.into()),
+        Value::Array(vec![Value::Integer(0.into()), Value::Bytes(xpub.to_vec())]),
+        Value::Map(Vec::new()),
+    ]))?;
+    blake2_b(&sha3_256(&spending_data), KEY_HASH_LEN).map_err(|_| AddressError::InvalidInput)
+}
+
+fn encode_cbor(value: &Value) -> AddressResult<Data> {
+    cbor::encode(value).map_err(|_| AddressError::InvalidInput)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byron_address_from_public_key() {
+        let public_key = ExtendedPublicKey::try_from("e6f04522f875c1563682ca876ddb04c2e2e3ae718e3ff9f11c03dd9f9dccf69869272d81c376382b8a87c21370a7ae9618df8da708d1a9490939ec54ebe43000857eed804ff087b97f87848f6493e87257a8c5203cb9f422f6e7a7d8a4d299f31111111111111111111111111111111111111111111111111111111111111111").unwrap();
+        let address = ByronAddress::with_public_key(&public_key).unwrap();
+        assert_eq!(
+            address.to_string(),
+            "Ae2tdPwUPEZCxt4UV1Uj2AMMRvg5pYPypqZowVptz3GYpK4pkcvn3EjkuNH"
+        );
+    }
+
+    #[test]
+    fn test_byron_address_round_trip() {
+        let addresses = [
+            "Ae2tdPwUPEZ18ZjTLnLVr9CEvUEUX4eW1LBHbxxxJgxdAYHrDeSCSbCxrvx",
+            "DdzFFzCqrhssmYoG5Eca1bKZFdGS8d6iag1mU4wbLeYcSPVvBNF2wRG8yhjzQqErbg63N6KJA4DHqha113tjKDpGEwS5x1dT2KfLSbSJ",
+        ];
+        for s in addresses {
+            let address = ByronAddress::from_str(s).unwrap();
+            assert_eq!(address.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_byron_address_invalid_checksum() {
+        ByronAddress::from_str("Ae2tdPwUPEZ18ZjTLnLVr9CEvUEUX4eW1LBHbxxxJgxdAYHrDeSCSbCxrvm")
+            .unwrap_err();
+    }
+}
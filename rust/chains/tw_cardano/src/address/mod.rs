@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_keypair::ed25519::cardano::ExtendedPublicKey;
+use tw_memory::Data;
+
+pub mod byron;
+pub mod shelley;
+
+pub use byron::ByronAddress;
+pub use shelley::{AddressKind, ShelleyAddress};
+
+/// Cardano address: either a Shelley (Bech32) or a legacy Byron (Base58) address.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CardanoAddress {
+    Shelley(ShelleyAddress),
+    Byron(ByronAddress),
+}
+
+impl CardanoAddress {
+    /// Creates a Shelley base address from a Cardano extended public key.
+    pub fn with_public_key(public_key: &ExtendedPublicKey) -> AddressResult<CardanoAddress> {
+        ShelleyAddress::with_public_key(public_key).map(CardanoAddress::Shelley)
+    }
+
+    /// Parses the binary representation of the address as it appears in transaction outputs.
+    pub fn from_bytes(data: &[u8]) -> AddressResult<CardanoAddress> {
+        match ShelleyAddress::from_bytes(data) {
+            Ok(shelley) => Ok(CardanoAddress::Shelley(shelley)),
+            Err(_) => ByronAddress::from_bytes(data).map(CardanoAddress::Byron),
+        }
+    }
+
+    pub fn is_byron(&self) -> bool {
+        matches!(self, CardanoAddress::Byron(_))
+    }
+
+    /// Returns the reward address associated with a Shelley base address.
+    pub fn staking_address(&self) -> Option<ShelleyAddress> {
+        match self {
+            CardanoAddress::Shelley(shelley) => shelley.staking_address(),
+            CardanoAddress::Byron(_) => None,
+        }
+    }
+}
+
+impl CoinAddress for CardanoAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        match self {
+            CardanoAddress::Shelley(shelley) => shelley.data(),
+            CardanoAddress::Byron(byron) => byron.data(),
+        }
+    }
+}
+
+impl FromStr for CardanoAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(shelley) = ShelleyAddress::from_str(s) {
+            return Ok(CardanoAddress::Shelley(shelley));
+        }
+        ByronAddress::from_str(s).map(CardanoAddress::Byron)
+    }
+}
+
+impl fmt::Display for CardanoAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardanoAddress::Shelley(shelley) => write!(f, "{shelley}"),
+            CardanoAddress::Byron(byron) => write!(f, "{byron}"),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::bech32;
+use tw_hash::blake2::blake2_b;
+use tw_keypair::ed25519::cardano::ExtendedPublicKey;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+
+/// Only the mainnet addresses are supported.
+pub const NETWORK_PRODUCTION: u8 = 1;
+/// Size of a key hash (`blake2b-224`).
+pub const KEY_HASH_LEN: usize = 28;
+
+const ADDRESS_HRP: &str = "addr";
+const REWARD_HRP: &str = "stake";
+
+const SPENDING_KEY_RANGE: std::ops::Range<usize> = 0..32;
+const STAKING_KEY_RANGE: std::ops::Range<usize> = 64..96;
+
+/// Shelley address type, stored in the upper 4 bits of the header byte.
+/// https://cips.cardano.org/cips/cip19/#shelleyaddresses
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressKind {
+    /// Spending key hash + staking key hash.
+    Base,
+    /// Spending key hash only.
+    Enterprise,
+    /// Staking key hash only, used for rewards and staking certificates.
+    Reward,
+    /// Other address types (pointer, script) are accepted, but not constructed.
+    Other(u8),
+}
+
+impl AddressKind {
+    fn from_header(header: u8) -> AddressKind {
+        match header >> 4 {
+            0 => AddressKind::Base,
+            6 => AddressKind::Enterprise,
+            14 => AddressKind::Reward,
+            other => AddressKind::Other(other),
+        }
+    }
+
+    fn to_header(self, network: u8) -> u8 {
+        let kind = match self {
+            AddressKind::Base => 0,
+            AddressKind::Enterprise => 6,
+            AddressKind::Reward => 14,
+            AddressKind::Other(other) => other,
+        };
+        (kind << 4) | (network & 0x0F)
+    }
+
+    /// Returns the expected length of the address including the header byte.
+    fn expected_len(self) -> Option<usize> {
+        match self {
+            AddressKind::Base => Some(1 + 2 * KEY_HASH_LEN),
+            AddressKind::Enterprise | AddressKind::Reward => Some(1 + KEY_HASH_LEN),
+            AddressKind::Other(_) => None,
+        }
+    }
+
+    fn hrp(self) -> &'static str {
+        match self {
+            AddressKind::Reward => REWARD_HRP,
+            _ => ADDRESS_HRP,
+        }
+    }
+}
+
+/// Shelley (V3) address: Bech32(header byte + key hashes).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShelleyAddress {
+    kind: AddressKind,
+    network: u8,
+    /// Key hashes without the header byte.
+    bytes: Data,
+}
+
+impl ShelleyAddress {
+    /// Creates a base address from a Cardano extended public key.
+    /// The spending key is the first 32 bytes, and the staking key is the 32 bytes at offset 64.
+    pub fn with_public_key(public_key: &ExtendedPublicKey) -> AddressResult<ShelleyAddress> {
+        let public_key = public_key.to_vec();
+        let mut bytes = key_hash(&public_key[SPENDING_KEY_RANGE])?;
+        bytes.extend_from_slice(&key_hash(&public_key[STAKING_KEY_RANGE])?);
+        Ok(ShelleyAddress {
+            kind: AddressKind::Base,
+            network: NETWORK_PRODUCTION,
+            bytes,
+        })
+    }
+
+    /// Creates a reward address from a staking key hash.
+    pub fn reward(staking_key_hash: &[u8]) -> AddressResult<ShelleyAddress> {
+        if staking_key_hash.len() != KEY_HASH_LEN {
+            return Err(AddressError::InvalidInput);
+        }
+        Ok(ShelleyAddress {
+            kind: AddressKind::Reward,
+            network: NETWORK_PRODUCTION,
+            bytes: staking_key_hash.to_vec(),
+        })
+    }
+
+    /// Parses the raw address bytes including the header byte.
+    pub fn from_bytes(raw: &[u8]) -> AddressResult<ShelleyAddress> {
+        let (header, bytes) = raw.split_first().ok_or(AddressError::InvalidInput)?;
+        let kind = AddressKind::from_header(*header);
+        let network = header & 0x0F;
+        if network != NETWORK_PRODUCTION {
+            return Err(AddressError::InvalidInput);
+        }
+        match kind.expected_len() {
+            Some(expected_len) if expected_len != raw.len() => {
+                return Err(AddressError::InvalidInput)
+            },
+            _ => (),
+        }
+        Ok(ShelleyAddress {
+            kind,
+            network,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    pub fn kind(&self) -> AddressKind {
+        self.kind
+    }
+
+    /// Returns the key hashes without the header byte.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the raw address bytes including the header byte.
+    pub fn data(&self) -> Data {
+        let mut data = Vec::with_capacity(1 + self.bytes.len());
+        data.push(self.kind.to_header(self.network));
+        data.extend_from_slice(&self.bytes);
+        data
+    }
+
+    /// Returns the reward address associated with the base address,
+    /// or `None` if the address has no staking part.
+    pub fn staking_address(&self) -> Option<ShelleyAddress> {
+        if self.kind != AddressKind::Base {
+            return None;
+        }
+        ShelleyAddress::reward(&self.bytes[KEY_HASH_LEN..]).ok()
+    }
+}
+
+impl FromStr for ShelleyAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decoded = bech32::decode(s).map_err(|_| AddressError::InvalidInput)?;
+        let address = ShelleyAddress::from_bytes(&decoded.bytes)?;
+        if decoded.hrp != address.kind.hrp() {
+            return Err(AddressError::InvalidHrp);
+        }
+        Ok(address)
+    }
+}
+
+impl fmt::Display for ShelleyAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = bech32::encode(self.kind.hrp(), &self.data()).map_err(|_| fmt::Error)?;
+        write!(f, "{encoded}")
+    }
+}
+
+/// `blake2b-224` hash of a 32-byte public key.
+pub fn key_hash(public_key: &[u8]) -> AddressResult<Data> {
+    blake2_b(public_key, KEY_HASH_LEN).map_err(|_| AddressError::InvalidInput)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_shelley_address_from_str() {
+        let base = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+        let address = ShelleyAddress::from_str(base).unwrap();
+        assert_eq!(address.kind(), AddressKind::Base);
+        assert_eq!(address.to_string(), base);
+        assert_eq!(
+            address.staking_address().unwrap().to_string(),
+            "stake1u80jysjtdzqt88jt4jx93h5lumfr67d273r4vwyasfa2pxcwxllmx"
+        );
+
+        let enterprise = "addr1vyuca7esanpgs4ke0um3ft6f4yaeuz3ftpfqx9nxpct2uyqu7dvlp";
+        let address = ShelleyAddress::from_str(enterprise).unwrap();
+        assert_eq!(address.kind(), AddressKind::Enterprise);
+        assert_eq!(
+            address.data().to_hex(),
+            "61398efb30ecc28856d97f3714af49a93b9e0a2958520316660e16ae10"
+        );
+        assert!(address.staking_address().is_none());
+    }
+
+    #[test]
+    fn test_shelley_address_invalid() {
+        // Reward address bytes with the `addr` prefix.
+        let reward =
+            ShelleyAddress::from_str("stake1uy9ggsc9qls4pu9qvyyacwnmr9tt0gzcdt5s0zj4au8qkqc65geks")
+                .unwrap();
+        let wrong_hrp = bech32::encode(ADDRESS_HRP, &reward.data()).unwrap();
+        assert_eq!(
+            ShelleyAddress::from_str(&wrong_hrp),
+            Err(AddressError::InvalidHrp)
+        );
+
+        // Testnet network id.
+        let mut testnet = reward.data();
+        testnet[0] = 0xe0;
+        ShelleyAddress::from_bytes(&testnet).unwrap_err();
+
+        // Wrong length.
+        ShelleyAddress::from_bytes(&reward.data()[..20]).unwrap_err();
+    }
+}
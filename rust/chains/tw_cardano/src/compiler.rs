@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_builder::TransactionBuilder;
+use crate::signer::{has_legacy_utxos, CardanoSigner};
+use crate::transaction::{SignedTransaction, Witness};
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Cardano::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct CardanoCompiler;
+
+impl CardanoCompiler {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    /// The transaction ID is the hash to be signed.
+    fn preimage_hashes_impl(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let plan = CardanoSigner::plan_for_signing(coin, &input)?;
+        let transaction = TransactionBuilder::build(&input, &plan)?;
+
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(transaction.encode()?),
+            data_hash: Cow::from(transaction.id()?.to_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    /// Only one signature is supported, so the transaction must not require staking key signatures.
+    /// The public key is either a 128-byte extended public key or a 32-byte `ed25519` public key
+    /// (the latter cannot be used to spend UTXOs of Byron addresses).
+    fn compile_impl(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let SingleSignaturePubkey {
+            signature,
+            public_key,
+        } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
+
+        let plan = CardanoSigner::plan_for_signing(coin, &input)?;
+        let transaction = TransactionBuilder::build(&input, &plan)?;
+        let tx_id = transaction.id()?;
+
+        let signed = SignedTransaction {
+            transaction,
+            witnesses: vec![Witness {
+                public_key,
+                signature,
+            }],
+            bootstrap_witnesses: has_legacy_utxos(&input),
+        };
+
+        Ok(Proto::SigningOutput {
+            encoded: Cow::from(signed.encode()?),
+            tx_id: Cow::from(tx_id.to_vec()),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
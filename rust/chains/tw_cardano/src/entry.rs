@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::CardanoAddress;
+use crate::compiler::CardanoCompiler;
+use crate::modules::plan_builder::CardanoPlanBuilder;
+use crate::signer::CardanoSigner;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_coin_entry::modules::json_signer::NoJsonSigner;
+use tw_coin_entry::modules::message_signer::NoMessageSigner;
+use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
+use tw_coin_entry::prefix::NoPrefix;
+use tw_keypair::tw::PublicKey;
+use tw_proto::Cardano::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct CardanoEntry;
+
+impl CoinEntry for CardanoEntry {
+    type AddressPrefix = NoPrefix;
+    type Address = CardanoAddress;
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+    type SigningOutput = Proto::SigningOutput<'static>;
+    type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+
+    // Optional modules:
+    type JsonSigner = NoJsonSigner;
+    type PlanBuilder = CardanoPlanBuilder;
+    type MessageSigner = NoMessageSigner;
+    type WalletConnector = NoWalletConnector;
+
+    #[inline]
+    fn parse_address(
+        &self,
+        _coin: &dyn CoinContext,
+        address: &str,
+        _prefix: Option<Self::AddressPrefix>,
+    ) -> AddressResult<Self::Address> {
+        CardanoAddress::from_str(address)
+    }
+
+    #[inline]
+    fn parse_address_unchecked(
+        &self,
+        _coin: &dyn CoinContext,
+        address: &str,
+    ) -> AddressResult<Self::Address> {
+        CardanoAddress::from_str(address)
+    }
+
+    /// Derives a Shelley base address from a Cardano extended public key.
+    #[inline]
+    fn derive_address(
+        &self,
+        _coin: &dyn CoinContext,
+        public_key: PublicKey,
+        _derivation: Derivation,
+        _prefix: Option<Self::AddressPrefix>,
+    ) -> AddressResult<Self::Address> {
+        let public_key = public_key
+            .to_ed25519_extended_cardano()
+            .ok_or(AddressError::PublicKeyTypeMismatch)?;
+        CardanoAddress::with_public_key(public_key)
+    }
+
+    #[inline]
+    fn sign(&self, coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::SigningOutput {
+        CardanoSigner::sign(coin, input)
+    }
+
+    #[inline]
+    fn preimage_hashes(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+    ) -> Self::PreSigningOutput {
+        CardanoCompiler::preimage_hashes(coin, input)
+    }
+
+    #[inline]
+    fn compile(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Self::SigningOutput {
+        CardanoCompiler::compile(coin, input, signatures, public_keys)
+    }
+
+    #[inline]
+    fn plan_builder(&self) -> Option<Self::PlanBuilder> {
+        Some(CardanoPlanBuilder)
+    }
+}
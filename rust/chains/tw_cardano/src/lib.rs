@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod address;
+pub mod compiler;
+pub mod entry;
+pub mod modules;
+pub mod signer;
+pub mod transaction;
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+/// Linear fee function: `fee = constant + coefficient * tx_size`.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearFee {
+    pub constant: f64,
+    /// Fee per byte of the encoded signed transaction.
+    pub coefficient: f64,
+}
+
+impl LinearFee {
+    /// Mainnet `minFeeB` and `minFeeA` protocol parameters with some over-estimation.
+    pub const DEFAULT: LinearFee = LinearFee {
+        constant: 155381.0 + 500.0,
+        coefficient: 43.946 + 0.1,
+    };
+
+    pub fn fee(&self, tx_size: usize) -> u64 {
+        (self.constant + tx_size as f64 * self.coefficient).ceil() as u64
+    }
+}
+
+impl Default for LinearFee {
+    fn default() -> Self {
+        LinearFee::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_fee() {
+        assert_eq!(LinearFee::DEFAULT.fee(0), 155881);
+        assert_eq!(LinearFee::DEFAULT.fee(271), 167818);
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod fee;
+pub mod plan_builder;
+pub mod planner;
+pub mod tx_builder;
+pub mod utxo_selector;
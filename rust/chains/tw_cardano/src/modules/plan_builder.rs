@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::planner::TxPlanner;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::modules::plan_builder::PlanBuilder;
+use tw_proto::Cardano::Proto;
+
+pub struct CardanoPlanBuilder;
+
+impl PlanBuilder for CardanoPlanBuilder {
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+    type Plan = Proto::TransactionPlan<'static>;
+
+    #[inline]
+    fn plan(&self, coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::Plan {
+        // `TransactionPlan` has no `error_message` field, so `signing_output_error!` cannot be used.
+        TxPlanner::plan(coin, &input)
+            .map(|plan| plan.to_proto())
+            .unwrap_or_else(|e| Proto::TransactionPlan {
+                error: e.0,
+                ..Proto::TransactionPlan::default()
+            })
+    }
+}
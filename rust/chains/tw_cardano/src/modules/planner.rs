@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::CardanoAddress;
+use crate::modules::fee::LinearFee;
+use crate::modules::utxo_selector::select_inputs;
+use crate::signer::CardanoSigner;
+use crate::transaction::plan::TransactionPlan;
+use crate::transaction::token::TokenBundle;
+use crate::transaction::{TxInput, TxOutput};
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_proto::Cardano::Proto;
+
+/// The fee used to estimate the transaction size.
+const PLACEHOLDER_FEE: u64 = 170_000;
+/// Extra ADA to select in addition to the amount, deposits, fee and minimal change.
+const EXTRA_INPUT_AMOUNT: u64 = 500_000;
+
+pub struct TxPlanner;
+
+impl TxPlanner {
+    /// Selects UTXOs and estimates the fee.
+    pub fn plan(
+        _coin: &dyn CoinContext,
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<TransactionPlan> {
+        let transfer = input.transfer_message.clone().unwrap_or_default();
+        let mut max_amount = transfer.use_max_amount;
+        let requested_tokens_proto = transfer
+            .token_amount
+            .as_ref()
+            .map(|bundle| bundle.token.as_slice())
+            .unwrap_or_default();
+
+        if transfer.amount == 0 && !max_amount && requested_tokens_proto.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_zero_amount_requested));
+        }
+
+        let utxos = input
+            .utxos
+            .iter()
+            .map(TxInput::from_proto)
+            .collect::<SigningResult<Vec<_>>>()?;
+        let input_sum = utxos
+            .iter()
+            .try_fold(0_u64, |sum, utxo| sum.checked_add(utxo.amount))
+            .ok_or(SigningError(SigningErrorType::Error_invalid_utxo_amount))?;
+        if input_sum == 0 {
+            return Err(SigningError(SigningErrorType::Error_missing_input_utxos));
+        }
+
+        let deposit = sum_deposits(input);
+        let undeposit = sum_undeposits(input);
+        let input_sum_after_deposit = after_deposit(input_sum, deposit, undeposit)?;
+
+        let mut extra_outputs = Vec::with_capacity(input.extra_outputs.len());
+        let mut extra_amount_sum: u64 = 0;
+        for output in input.extra_outputs.iter() {
+            extra_outputs.push(TxOutput {
+                address: CardanoAddress::from_str(&output.address)?,
+                amount: output.amount,
+                tokens: TokenBundle::default(),
+            });
+            extra_amount_sum = extra_amount_sum
+                .checked_add(output.amount)
+                .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        }
+
+        let requested_tokens = TokenBundle::from_proto(requested_tokens_proto)?;
+        // Only one token can be transferred (for simplicity).
+        // Inputs may contain more tokens which are preserved.
+        if requested_tokens.len() > 1 {
+            return Err(SigningError(
+                SigningErrorType::Error_invalid_requested_token_amount,
+            ));
+        }
+
+        // If the requested amount is the same or more than the available amount,
+        // treat it as the max amount, and send the maximum available (which will be less).
+        if !max_amount && transfer.amount >= input_sum_after_deposit {
+            max_amount = true;
+        }
+
+        let selected_utxos = if max_amount {
+            utxos
+        } else {
+            // Aim for a larger total input, enough for 4/3 of the target amount,
+            // plus a typical fee, plus minimal ADA for the change, plus some extra.
+            let target_amount = (transfer.amount.saturating_mul(4) / 3)
+                .saturating_add(deposit)
+                .saturating_sub(undeposit)
+                .saturating_add(PLACEHOLDER_FEE)
+                .saturating_add(requested_tokens.min_ada_amount())
+                .saturating_add(EXTRA_INPUT_AMOUNT);
+            select_inputs(&utxos, target_amount, &requested_tokens)
+        };
+
+        let mut plan = TransactionPlan {
+            utxos: selected_utxos,
+            extra_outputs,
+            amount: transfer.amount,
+            deposit,
+            undeposit,
+            ..TransactionPlan::default()
+        };
+        sum_available(&mut plan)?;
+        if plan.available_amount == 0 {
+            return Err(SigningError(SigningErrorType::Error_missing_input_utxos));
+        }
+        let available_after_deposit = after_deposit(plan.available_amount, deposit, undeposit)?;
+
+        // Check that there are enough coins and tokens in the inputs.
+        if plan.amount > available_after_deposit {
+            return Err(SigningError(SigningErrorType::Error_low_balance));
+        }
+        for (key, amount) in requested_tokens.iter() {
+            if *amount > plan.available_tokens.amount(key) {
+                return Err(SigningError(SigningErrorType::Error_low_balance));
+            }
+        }
+
+        plan.fee = if transfer.force_fee == 0 {
+            let simple_plan = simple_plan(
+                &plan,
+                available_after_deposit,
+                &requested_tokens,
+                max_amount,
+            );
+            Self::estimate_fee(input, &simple_plan)
+        } else {
+            available_after_deposit
+                .saturating_sub(plan.amount)
+                .saturating_sub(extra_amount_sum)
+                .min(transfer.force_fee)
+        };
+
+        let max_output_amount = available_after_deposit
+            .saturating_sub(plan.fee)
+            .saturating_sub(extra_amount_sum);
+        plan.amount = if max_amount {
+            max_output_amount
+        } else {
+            plan.amount.min(max_output_amount)
+        };
+
+        let total_spent = plan
+            .amount
+            .checked_add(extra_amount_sum)
+            .and_then(|sum| sum.checked_add(plan.fee))
+            .ok_or(SigningError(SigningErrorType::Error_low_balance))?;
+        if total_spent > available_after_deposit {
+            return Err(SigningError(SigningErrorType::Error_low_balance));
+        }
+
+        plan.output_tokens = if max_amount {
+            plan.available_tokens.clone()
+        } else {
+            requested_tokens
+        };
+        plan.change = available_after_deposit - total_spent;
+        // Omit zero-amount change tokens.
+        plan.change_tokens = change_tokens(&plan, false);
+
+        Ok(plan)
+    }
+
+    /// Estimates the fee by encoding the `simple_plan` with placeholder keys where missing.
+    fn estimate_fee(input: &Proto::SigningInput<'_>, simple_plan: &TransactionPlan) -> u64 {
+        // The fee is still estimated even if the transaction cannot be encoded.
+        // The error will be returned on signing.
+        let tx_size = CardanoSigner::encode_transaction(input, simple_plan, true)
+            .map(|(encoded, _tx_id)| encoded.len())
+            .unwrap_or_default();
+        LinearFee::DEFAULT.fee(tx_size)
+    }
+}
+
+/// Creates a plan with the placeholder fee used to estimate the transaction size.
+fn simple_plan(
+    plan: &TransactionPlan,
+    available_after_deposit: u64,
+    requested_tokens: &TokenBundle,
+    max_amount: bool,
+) -> TransactionPlan {
+    let max_output_amount = available_after_deposit.saturating_sub(PLACEHOLDER_FEE);
+
+    let mut simple = TransactionPlan {
+        fee: PLACEHOLDER_FEE,
+        ..plan.clone()
+    };
+    if max_amount {
+        simple.amount = max_output_amount;
+        simple.output_tokens = plan.available_tokens.clone();
+    } else {
+        simple.amount = plan.amount.min(max_output_amount);
+        simple.output_tokens = requested_tokens.clone();
+    }
+    simple.change = available_after_deposit.saturating_sub(simple.amount + simple.fee);
+    // Keep zero-amount change tokens to over-estimate the size.
+    simple.change_tokens = change_tokens(&simple, true);
+    simple
+}
+
+/// Sums the amounts and the tokens of the selected UTXOs.
+fn sum_available(plan: &mut TransactionPlan) -> SigningResult<()> {
+    let mut available_amount: u64 = 0;
+    let mut available_tokens = TokenBundle::default();
+    for utxo in plan.utxos.iter() {
+        available_amount = available_amount
+            .checked_add(utxo.amount)
+            .ok_or(SigningError(SigningErrorType::Error_invalid_utxo_amount))?;
+        available_tokens.add_bundle(&utxo.tokens)?;
+    }
+    plan.available_amount = available_amount;
+    plan.available_tokens = available_tokens;
+    Ok(())
+}
+
+/// Returns the available tokens that are not sent to the destination address.
+fn change_tokens(plan: &TransactionPlan, keep_zero_amounts: bool) -> TokenBundle {
+    let mut change = TokenBundle::default();
+    for (key, amount) in plan.available_tokens.iter() {
+        let change_amount = amount.saturating_sub(plan.output_tokens.amount(key));
+        if change_amount > 0 || keep_zero_amounts {
+            change.set(key.clone(), change_amount);
+        }
+    }
+    change
+}
+
+fn after_deposit(amount: u64, deposit: u64, undeposit: u64) -> SigningResult<u64> {
+    amount
+        .checked_add(undeposit)
+        .and_then(|amount| amount.checked_sub(deposit))
+        .ok_or(SigningError(SigningErrorType::Error_low_balance))
+}
+
+fn sum_deposits(input: &Proto::SigningInput<'_>) -> u64 {
+    let register = input
+        .register_staking_key
+        .as_ref()
+        .map(|register| register.deposit_amount)
+        .unwrap_or_default();
+    let delegate = input
+        .delegate
+        .as_ref()
+        .map(|delegate| delegate.deposit_amount)
+        .unwrap_or_default();
+    register.saturating_add(delegate)
+}
+
+fn sum_undeposits(input: &Proto::SigningInput<'_>) -> u64 {
+    let deregister = input
+        .deregister_staking_key
+        .as_ref()
+        .map(|deregister| deregister.undeposit_amount)
+        .unwrap_or_default();
+    let withdraw = input
+        .withdraw
+        .as_ref()
+        .map(|withdraw| withdraw.withdraw_amount)
+        .unwrap_or_default();
+    deregister.saturating_add(withdraw)
+}
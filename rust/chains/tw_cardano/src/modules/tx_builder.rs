@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::{CardanoAddress, ShelleyAddress};
+use crate::transaction::certificate::{Certificate, Withdrawal};
+use crate::transaction::plan::TransactionPlan;
+use crate::transaction::{Transaction, TxOutput};
+use std::str::FromStr;
+use tw_coin_entry::error::SigningResult;
+use tw_proto::Cardano::Proto;
+
+pub struct TransactionBuilder;
+
+impl TransactionBuilder {
+    /// Builds a transaction body according to the given `plan`.
+    pub fn build(
+        input: &Proto::SigningInput<'_>,
+        plan: &TransactionPlan,
+    ) -> SigningResult<Transaction> {
+        let transfer = input.transfer_message.clone().unwrap_or_default();
+
+        let inputs = plan
+            .utxos
+            .iter()
+            .map(|utxo| utxo.out_point.clone())
+            .collect();
+
+        let mut outputs = vec![TxOutput {
+            address: CardanoAddress::from_str(&transfer.to_address)?,
+            amount: plan.amount,
+            tokens: plan.output_tokens.clone(),
+        }];
+        outputs.extend(plan.extra_outputs.iter().cloned());
+
+        if plan.change > 0 || plan.change_tokens.has_positive_amount() {
+            outputs.push(TxOutput {
+                address: CardanoAddress::from_str(&transfer.change_address)?,
+                amount: plan.change,
+                tokens: plan.change_tokens.clone(),
+            });
+        }
+
+        let mut certificates = Vec::new();
+        let mut withdrawals = Vec::new();
+
+        if let Some(ref register) = input.register_staking_key {
+            certificates.push(Certificate::StakeRegistration {
+                staking_key: staking_key(&register.staking_address)?,
+            });
+        }
+        if let Some(ref delegate) = input.delegate {
+            certificates.push(Certificate::Delegation {
+                staking_key: staking_key(&delegate.staking_address)?,
+                pool_id: delegate.pool_id.to_vec(),
+            });
+        }
+        if let Some(ref withdraw) = input.withdraw {
+            withdrawals.push(Withdrawal {
+                reward_address: ShelleyAddress::from_str(&withdraw.staking_address)?.data(),
+                amount: withdraw.withdraw_amount,
+            });
+        }
+        if let Some(ref deregister) = input.deregister_staking_key {
+            certificates.push(Certificate::StakeDeregistration {
+                staking_key: staking_key(&deregister.staking_address)?,
+            });
+        }
+
+        Ok(Transaction {
+            inputs,
+            outputs,
+            fee: plan.fee,
+            ttl: input.ttl,
+            certificates,
+            withdrawals,
+        })
+    }
+}
+
+/// Returns the bare staking key hash of the given reward address.
+fn staking_key(staking_address: &str) -> SigningResult<Vec<u8>> {
+    Ok(ShelleyAddress::from_str(staking_address)?.bytes().to_vec())
+}
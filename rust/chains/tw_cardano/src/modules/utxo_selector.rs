@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::token::{TokenBundle, TokenKey};
+use crate::transaction::TxInput;
+use std::cmp::Reverse;
+
+/// Selects a subset of `inputs` to cover the desired ADA `amount` and the `requested_tokens`.
+/// Simple algorithm: pick the largest ones.
+pub fn select_inputs(
+    inputs: &[TxInput],
+    amount: u64,
+    requested_tokens: &TokenBundle,
+) -> Vec<TxInput> {
+    let mut selected = select_inputs_native(inputs, amount);
+    for (key, token_amount) in requested_tokens.iter() {
+        select_inputs_token(inputs, key, *token_amount, &mut selected);
+    }
+    selected
+}
+
+/// Picks the largest inputs until the `amount` is covered. At least one input is selected.
+fn select_inputs_native(inputs: &[TxInput], amount: u64) -> Vec<TxInput> {
+    let mut sorted = inputs.to_vec();
+    sorted.sort_by_key(|input| Reverse(input.amount));
+
+    let mut selected = Vec::new();
+    let mut selected_amount: u64 = 0;
+    for input in sorted {
+        selected_amount = selected_amount.saturating_add(input.amount);
+        selected.push(input);
+        if selected_amount >= amount {
+            break;
+        }
+    }
+    selected
+}
+
+/// Adds the inputs with the largest amount of the `key` token until the `amount` is covered.
+fn select_inputs_token(
+    inputs: &[TxInput],
+    key: &TokenKey,
+    amount: u64,
+    selected: &mut Vec<TxInput>,
+) {
+    let mut selected_amount = selected.iter().fold(0_u64, |sum, input| {
+        sum.saturating_add(input.tokens.amount(key))
+    });
+    if selected_amount >= amount {
+        return;
+    }
+
+    let mut sorted = inputs.to_vec();
+    sorted.sort_by_key(|input| Reverse(input.tokens.amount(key)));
+    for input in sorted {
+        let already_selected = selected.iter().any(|s| s.out_point == input.out_point);
+        if already_selected {
+            continue;
+        }
+        selected_amount = selected_amount.saturating_add(input.tokens.amount(key));
+        selected.push(input);
+        if selected_amount >= amount {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::OutPoint;
+
+    const POLICY_ID: &str = "9a9693a9a37912a5097918f97918d15240c92ab729a0b7c4aa144d77";
+
+    fn input(index: u64, amount: u64, token_amount: u64) -> TxInput {
+        let mut tokens = TokenBundle::default();
+        if token_amount > 0 {
+            tokens
+                .add(TokenKey::new(POLICY_ID, b"SUNDAE"), token_amount)
+                .unwrap();
+        }
+        TxInput {
+            out_point: OutPoint {
+                tx_hash: vec![1; 32],
+                output_index: index,
+            },
+            address: String::default(),
+            amount,
+            tokens,
+        }
+    }
+
+    fn indexes(selected: &[TxInput]) -> Vec<u64> {
+        selected.iter().map(|i| i.out_point.output_index).collect()
+    }
+
+    #[test]
+    fn test_select_inputs_native() {
+        let inputs = [
+            input(0, 1_500_000, 0),
+            input(1, 6_500_000, 0),
+            input(2, 3_000_000, 0),
+        ];
+        let no_tokens = TokenBundle::default();
+
+        assert_eq!(indexes(&select_inputs(&inputs, 1, &no_tokens)), [1]);
+        assert_eq!(
+            indexes(&select_inputs(&inputs, 7_000_000, &no_tokens)),
+            [1, 2]
+        );
+        assert_eq!(
+            indexes(&select_inputs(&inputs, 20_000_000, &no_tokens)),
+            [1, 2, 0]
+        );
+    }
+
+    #[test]
+    fn test_select_inputs_with_tokens() {
+        let inputs = [
+            input(0, 1_500_000, 0),
+            input(1, 6_500_000, 0),
+            input(2, 1_200_000, 80),
+            input(3, 1_100_000, 40),
+        ];
+        let mut requested = TokenBundle::default();
+        requested
+            .add(TokenKey::new(POLICY_ID, b"SUNDAE"), 100)
+            .unwrap();
+
+        assert_eq!(indexes(&select_inputs(&inputs, 1, &requested)), [1, 2, 3]);
+    }
+}
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::{ByronAddress, CardanoAddress};
+use crate::modules::planner::TxPlanner;
+use crate::modules::tx_builder::TransactionBuilder;
+use crate::transaction::plan::TransactionPlan;
+use crate::transaction::{SignedTransaction, Witness};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
+use tw_keypair::ed25519::cardano::ExtendedPrivateKey;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Cardano::Proto;
+
+/// Used to estimate the transaction size when a private key is not provided.
+static PLACEHOLDER_PRIVATE_KEY: [u8; ExtendedPrivateKey::LEN] = placeholder_private_key();
+
+/// The private key is a pair of 96-byte extended keys: the spending key and the staking key.
+const HALF_PRIVATE_KEY_LEN: usize = ExtendedPrivateKey::LEN / 2;
+
+pub struct CardanoSigner;
+
+impl CardanoSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let plan = Self::plan_for_signing(coin, &input)?;
+        let (encoded, tx_id) = Self::encode_transaction(&input, &plan, false)?;
+        Ok(Proto::SigningOutput {
+            encoded: Cow::from(encoded),
+            tx_id: Cow::from(tx_id.to_vec()),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    /// Returns `input.plan` if provided, otherwise plans the transaction.
+    pub(crate) fn plan_for_signing(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<TransactionPlan> {
+        match input.plan {
+            Some(ref plan) if plan.error != SigningErrorType::OK => Err(SigningError(plan.error)),
+            Some(ref plan) => TransactionPlan::from_proto(plan),
+            None => TxPlanner::plan(coin, input),
+        }
+    }
+
+    /// Builds, signs and encodes the transaction. Returns the encoded transaction and its ID.
+    /// If `size_estimation_only` is set, missing private keys are replaced with a placeholder.
+    pub(crate) fn encode_transaction(
+        input: &Proto::SigningInput<'_>,
+        plan: &TransactionPlan,
+        size_estimation_only: bool,
+    ) -> SigningResult<(Data, H256)> {
+        let transaction = TransactionBuilder::build(input, plan)?;
+        let tx_id = transaction.id()?;
+        let witnesses = Self::sign_witnesses(input, plan, &tx_id, size_estimation_only)?;
+
+        let signed = SignedTransaction {
+            transaction,
+            witnesses,
+            bootstrap_witnesses: has_legacy_utxos(input),
+        };
+        Ok((signed.encode()?, tx_id))
+    }
+
+    /// Signs the transaction ID by every key required to spend the selected UTXOs
+    /// and to authorize the staking operations.
+    fn sign_witnesses(
+        input: &Proto::SigningInput<'_>,
+        plan: &TransactionPlan,
+        tx_id: &H256,
+        size_estimation_only: bool,
+    ) -> SigningResult<Vec<Witness>> {
+        let private_keys = private_keys_by_address(input)?;
+
+        let mut addresses: Vec<&str> = Vec::new();
+        for utxo in plan.utxos.iter() {
+            CardanoAddress::from_str(&utxo.address)?;
+            addresses.push(&utxo.address);
+        }
+        // Staking keys also need signatures.
+        if let Some(ref register) = input.register_staking_key {
+            addresses.push(&register.staking_address);
+        }
+        if let Some(ref deregister) = input.deregister_staking_key {
+            addresses.push(&deregister.staking_address);
+        }
+        if let Some(ref delegate) = input.delegate {
+            addresses.push(&delegate.staking_address);
+        }
+        if let Some(ref withdraw) = input.withdraw {
+            addresses.push(&withdraw.staking_address);
+        }
+
+        let mut unique_addresses: Vec<&str> = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            if !unique_addresses.contains(&address) {
+                unique_addresses.push(address);
+            }
+        }
+
+        unique_addresses
+            .into_iter()
+            .map(|address| -> SigningResult<Witness> {
+                let private_key = match private_keys.get(address) {
+                    Some(private_key) => private_key.as_slice(),
+                    None if size_estimation_only => PLACEHOLDER_PRIVATE_KEY.as_slice(),
+                    None => return Err(SigningError(SigningErrorType::Error_missing_private_key)),
+                };
+                let private_key = ExtendedPrivateKey::try_from(private_key)?;
+                let signature = private_key.sign(tx_id.to_vec())?;
+                Ok(Witness {
+                    public_key: private_key.public().to_vec(),
+                    signature: signature.to_bytes().to_vec(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Whether any of the input UTXOs belongs to a Byron address.
+pub(crate) fn has_legacy_utxos(input: &Proto::SigningInput<'_>) -> bool {
+    input
+        .utxos
+        .iter()
+        .any(|utxo| ByronAddress::from_str(&utxo.address).is_ok())
+}
+
+/// Maps every address controlled by the given private keys to the corresponding private key:
+/// the Shelley base address, the Byron address and the staking address.
+fn private_keys_by_address(
+    input: &Proto::SigningInput<'_>,
+) -> SigningResult<HashMap<String, Data>> {
+    let mut private_keys = HashMap::new();
+    for private_key_data in input.private_key.iter() {
+        let private_key = ExtendedPrivateKey::try_from(private_key_data.as_ref())?;
+        let public_key = private_key.public();
+
+        let address = CardanoAddress::with_public_key(&public_key)?;
+        private_keys.insert(address.to_string(), private_key_data.to_vec());
+
+        let legacy_address = ByronAddress::with_public_key(&public_key)?;
+        private_keys.insert(legacy_address.to_string(), private_key_data.to_vec());
+
+        if let Some(staking_address) = address.staking_address() {
+            let mut staking_private_key = private_key_data[HALF_PRIVATE_KEY_LEN..].to_vec();
+            staking_private_key.resize(ExtendedPrivateKey::LEN, 0);
+            private_keys.insert(staking_address.to_string(), staking_private_key);
+        }
+    }
+    Ok(private_keys)
+}
+
+/// `000102..1f` repeated 6 times.
+const fn placeholder_private_key() -> [u8; ExtendedPrivateKey::LEN] {
+    let mut key = [0; ExtendedPrivateKey::LEN];
+    let mut i = 0;
+    while i < ExtendedPrivateKey::LEN {
+        key[i] = (i % 32) as u8;
+        i += 1;
+    }
+    key
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Helpers to build CBOR values in the canonical form expected by Cardano nodes.
+
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::cbor::{self, Value};
+use tw_memory::Data;
+
+pub(crate) fn uint(num: u64) -> Value {
+    Value::Integer(num.into())
+}
+
+pub(crate) fn bytes(data: &[u8]) -> Value {
+    Value::Bytes(data.to_vec())
+}
+
+/// Creates a map with entries sorted by the encoded keys.
+/// If there are duplicate keys, the first entry is kept.
+pub(crate) fn map(entries: Vec<(Value, Value)>) -> SigningResult<Value> {
+    let mut encoded = entries
+        .into_iter()
+        .map(|(key, value)| -> SigningResult<_> { Ok((encode(&key)?, key, value)) })
+        .collect::<SigningResult<Vec<_>>>()?;
+    // `sort_by` is stable, so the first of the duplicate entries remains first.
+    encoded.sort_by(|(lhs, ..), (rhs, ..)| lhs.cmp(rhs));
+    encoded.dedup_by(|(lhs, ..), (rhs, ..)| lhs == rhs);
+
+    Ok(Value::Map(
+        encoded
+            .into_iter()
+            .map(|(_, key, value)| (key, value))
+            .collect(),
+    ))
+}
+
+pub(crate) fn encode(value: &Value) -> SigningResult<Data> {
+    cbor::encode(value).map_err(|_| SigningError(SigningErrorType::Error_internal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_map_canonical_order() {
+        let value = map(vec![
+            (bytes(b"ab"), uint(1)),
+            (uint(10), uint(2)),
+            (bytes(b"b"), uint(3)),
+            (uint(10), uint(4)),
+        ])
+        .unwrap();
+        // {10: 2, h'62': 3, h'6162': 1}
+        assert_eq!(encode(&value).unwrap().to_hex(), "a30a0241620342616201");
+    }
+}
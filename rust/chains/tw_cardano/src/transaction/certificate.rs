@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::cbor;
+use tw_encoding::cbor::Value;
+use tw_memory::Data;
+
+/// Only key hash credentials are supported (no script hashes).
+const ADDRESS_KEY_HASH: u64 = 0;
+
+/// Staking certificate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Certificate {
+    StakeRegistration {
+        /// Staking key hash without the address header.
+        staking_key: Data,
+    },
+    StakeDeregistration {
+        staking_key: Data,
+    },
+    Delegation {
+        staking_key: Data,
+        pool_id: Data,
+    },
+}
+
+impl Certificate {
+    fn type_id(&self) -> u64 {
+        match self {
+            Certificate::StakeRegistration { .. } => 0,
+            Certificate::StakeDeregistration { .. } => 1,
+            Certificate::Delegation { .. } => 2,
+        }
+    }
+
+    /// Encodes the certificate as `[type, [0, staking_key], pool_id?]`.
+    pub(crate) fn to_cbor_value(&self) -> Value {
+        let (staking_key, pool_id) = match self {
+            Certificate::StakeRegistration { staking_key }
+            | Certificate::StakeDeregistration { staking_key } => (staking_key, None),
+            Certificate::Delegation {
+                staking_key,
+                pool_id,
+            } => (staking_key, Some(pool_id)),
+        };
+
+        let mut items = vec![
+            cbor::uint(self.type_id()),
+            Value::Array(vec![cbor::uint(ADDRESS_KEY_HASH), cbor::bytes(staking_key)]),
+        ];
+        if let Some(pool_id) = pool_id.filter(|pool_id| !pool_id.is_empty()) {
+            items.push(cbor::bytes(pool_id));
+        }
+        Value::Array(items)
+    }
+}
+
+/// Withdrawal of staking rewards.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Withdrawal {
+    /// Raw reward address including the header byte.
+    pub reward_address: Data,
+    pub amount: u64,
+}
@@ -0,0 +1,389 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::CardanoAddress;
+use crate::transaction::certificate::{Certificate, Withdrawal};
+use crate::transaction::token::TokenBundle;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::cbor::Value;
+use tw_hash::blake2::blake2_b;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_proto::Cardano::Proto;
+
+pub mod cbor;
+pub mod certificate;
+pub mod plan;
+pub mod token;
+
+/// Witness set key of the Shelley (`vkey`) witnesses.
+const VKEY_WITNESSES_KEY: u64 = 0;
+/// Witness set key of the Byron (`bootstrap`) witnesses.
+const BOOTSTRAP_WITNESSES_KEY: u64 = 2;
+/// CBOR-encoded empty attributes (`{}`) of a Byron address.
+const BOOTSTRAP_ATTRIBUTES: [u8; 1] = [0xa0];
+
+/// Reference to an output of a previous transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutPoint {
+    pub tx_hash: Data,
+    pub output_index: u64,
+}
+
+/// UTXO to be spent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TxInput {
+    pub out_point: OutPoint,
+    /// The address is used to find the corresponding private key only,
+    /// so it's validated at the signing stage.
+    pub address: String,
+    pub amount: u64,
+    pub tokens: TokenBundle,
+}
+
+impl TxInput {
+    pub fn from_proto(proto: &Proto::TxInput<'_>) -> SigningResult<TxInput> {
+        let out_point = proto.out_point.as_ref().map(|out_point| OutPoint {
+            tx_hash: out_point.tx_hash.to_vec(),
+            output_index: out_point.output_index,
+        });
+        Ok(TxInput {
+            out_point: out_point.unwrap_or(OutPoint {
+                tx_hash: Data::default(),
+                output_index: 0,
+            }),
+            address: proto.address.to_string(),
+            amount: proto.amount,
+            tokens: TokenBundle::from_proto(&proto.token_amount)?,
+        })
+    }
+
+    pub fn to_proto(&self) -> Proto::TxInput<'static> {
+        Proto::TxInput {
+            out_point: Some(Proto::OutPoint {
+                tx_hash: Cow::from(self.out_point.tx_hash.clone()),
+                output_index: self.out_point.output_index,
+            }),
+            address: Cow::from(self.address.clone()),
+            amount: self.amount,
+            token_amount: self.tokens.to_proto(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TxOutput {
+    pub address: CardanoAddress,
+    pub amount: u64,
+    pub tokens: TokenBundle,
+}
+
+impl TxOutput {
+    pub fn from_proto(proto: &Proto::TxOutput<'_>) -> SigningResult<TxOutput> {
+        Ok(TxOutput {
+            address: CardanoAddress::from_str(&proto.address)?,
+            amount: proto.amount,
+            tokens: TokenBundle::from_proto(&proto.token_amount)?,
+        })
+    }
+
+    pub fn to_proto(&self) -> Proto::TxOutput<'static> {
+        Proto::TxOutput {
+            address: Cow::from(self.address.to_string()),
+            amount: self.amount,
+            token_amount: self.tokens.to_proto(),
+        }
+    }
+
+    /// Returns the minimal ADA amount of the output depending on its serialized size.
+    /// https://github.com/Emurgo/cardano-serialization-lib/blob/78184e0a2c207c2f8bba57b0d3c437f4c808c125/rust/src/utils.rs#L1388
+    pub fn min_ada_amount(&self, coins_per_utxo_byte: u64) -> SigningResult<u64> {
+        /// Constant overhead of a UTXO entry in bytes.
+        const UTXO_ENTRY_OVERHEAD: u64 = 160;
+
+        let mut output = self.clone();
+        loop {
+            let output_size = cbor::encode(&output.to_cbor_value()?)?.len() as u64;
+            let min_amount = (output_size + UTXO_ENTRY_OVERHEAD)
+                .checked_mul(coins_per_utxo_byte)
+                .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+            if output.amount >= min_amount {
+                return Ok(min_amount);
+            }
+            // The amount may take more bytes, so try again.
+            output.amount = min_amount;
+        }
+    }
+
+    /// Encodes the output as `[address, amount]`,
+    /// where `amount` is either `uint` or `[uint, tokens]` if there are native tokens.
+    pub(crate) fn to_cbor_value(&self) -> SigningResult<Value> {
+        let amount = if self.tokens.is_empty() {
+            cbor::uint(self.amount)
+        } else {
+            Value::Array(vec![cbor::uint(self.amount), self.tokens.to_cbor_value()?])
+        };
+        Ok(Value::Array(vec![
+            Value::Bytes(self.address.data()),
+            amount,
+        ]))
+    }
+}
+
+/// Transaction body.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Transaction {
+    pub inputs: Vec<OutPoint>,
+    pub outputs: Vec<TxOutput>,
+    pub fee: u64,
+    pub ttl: u64,
+    pub certificates: Vec<Certificate>,
+    pub withdrawals: Vec<Withdrawal>,
+}
+
+impl Transaction {
+    /// Encodes the transaction body as a CBOR map with numeric keys.
+    /// Auxiliary data hash (7) and validity interval start (8) are not included.
+    pub fn to_cbor_value(&self) -> SigningResult<Value> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| {
+                Value::Array(vec![
+                    cbor::bytes(&input.tx_hash),
+                    cbor::uint(input.output_index),
+                ])
+            })
+            .collect();
+        let outputs = self
+            .outputs
+            .iter()
+            .map(TxOutput::to_cbor_value)
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let mut entries = vec![
+            (cbor::uint(0), Value::Array(inputs)),
+            (cbor::uint(1), Value::Array(outputs)),
+            (cbor::uint(2), cbor::uint(self.fee)),
+            (cbor::uint(3), cbor::uint(self.ttl)),
+        ];
+
+        if !self.certificates.is_empty() {
+            let certificates = self
+                .certificates
+                .iter()
+                .map(Certificate::to_cbor_value)
+                .collect();
+            entries.push((cbor::uint(4), Value::Array(certificates)));
+        }
+
+        if !self.withdrawals.is_empty() {
+            let withdrawals = self
+                .withdrawals
+                .iter()
+                .map(|w| (cbor::bytes(&w.reward_address), cbor::uint(w.amount)))
+                .collect();
+            entries.push((cbor::uint(5), cbor::map(withdrawals)?));
+        }
+
+        cbor::map(entries)
+    }
+
+    pub fn encode(&self) -> SigningResult<Data> {
+        cbor::encode(&self.to_cbor_value()?)
+    }
+
+    /// Transaction ID is `blake2b-256` hash of the encoded transaction body.
+    pub fn id(&self) -> SigningResult<H256> {
+        let hash = blake2_b(&self.encode()?, H256::LEN)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+        H256::try_from(hash.as_slice()).map_err(|_| SigningError(SigningErrorType::Error_internal))
+    }
+}
+
+/// Signature of the transaction ID along with the signer's public key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Witness {
+    /// Either a 128-byte extended public key or just the 32-byte `ed25519` public key.
+    pub public_key: Data,
+    pub signature: Data,
+}
+
+impl Witness {
+    fn vkey(&self) -> SigningResult<&[u8]> {
+        self.public_key
+            .get(0..32)
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))
+    }
+
+    /// Encodes the witness as `[vkey, signature]`.
+    fn to_cbor_value(&self) -> SigningResult<Value> {
+        Ok(Value::Array(vec![
+            cbor::bytes(self.vkey()?),
+            cbor::bytes(&self.signature),
+        ]))
+    }
+
+    /// Encodes the witness as `[vkey, signature, chain_code, attributes]`.
+    fn to_bootstrap_cbor_value(&self) -> SigningResult<Value> {
+        let chain_code = self
+            .public_key
+            .get(32..64)
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        Ok(Value::Array(vec![
+            cbor::bytes(self.vkey()?),
+            cbor::bytes(&self.signature),
+            cbor::bytes(chain_code),
+            cbor::bytes(&BOOTSTRAP_ATTRIBUTES),
+        ]))
+    }
+}
+
+/// Transaction body along with the witnesses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedTransaction {
+    pub transaction: Transaction,
+    pub witnesses: Vec<Witness>,
+    /// Whether to add Byron witnesses required to spend UTXOs of legacy addresses.
+    pub bootstrap_witnesses: bool,
+}
+
+impl SignedTransaction {
+    /// Encodes the transaction as `[body, witness_set, auxiliary_data]`.
+    pub fn encode(&self) -> SigningResult<Data> {
+        let vkey_witnesses = self
+            .witnesses
+            .iter()
+            .map(Witness::to_cbor_value)
+            .collect::<SigningResult<Vec<_>>>()?;
+        let mut witness_set = vec![(cbor::uint(VKEY_WITNESSES_KEY), Value::Array(vkey_witnesses))];
+
+        if self.bootstrap_witnesses && !self.witnesses.is_empty() {
+            let bootstrap_witnesses = self
+                .witnesses
+                .iter()
+                .map(Witness::to_bootstrap_cbor_value)
+                .collect::<SigningResult<Vec<_>>>()?;
+            witness_set.push((
+                cbor::uint(BOOTSTRAP_WITNESSES_KEY),
+                Value::Array(bootstrap_witnesses),
+            ));
+        }
+
+        cbor::encode(&Value::Array(vec![
+            self.transaction.to_cbor_value()?,
+            cbor::map(witness_set)?,
+            Value::Null,
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::token::TokenKey;
+    use tw_encoding::hex::{DecodeHex, ToHex};
+
+    fn output(address: &str, amount: u64, tokens: TokenBundle) -> TxOutput {
+        TxOutput {
+            address: CardanoAddress::from_str(address).unwrap(),
+            amount,
+            tokens,
+        }
+    }
+
+    fn create_tx() -> Transaction {
+        let out_point = |tx_hash: &str, output_index| OutPoint {
+            tx_hash: tx_hash.decode_hex().unwrap(),
+            output_index,
+        };
+        Transaction {
+            inputs: vec![
+                out_point("f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e767", 1),
+                out_point("554f2fd942a23d06835d26bbd78f0106fa94c8a551114a0bef81927f66467af0", 0),
+            ],
+            outputs: vec![
+                output("addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23", 2000000, TokenBundle::default()),
+                output("addr1q92cmkgzv9h4e5q7mnrzsuxtgayvg4qr7y3gyx97ukmz3dfx7r9fu73vqn25377ke6r0xk97zw07dqr9y5myxlgadl2s0dgke5", 16749189, TokenBundle::default()),
+            ],
+            fee: 165555,
+            ttl: 53333345,
+            ..Transaction::default()
+        }
+    }
+
+    #[test]
+    fn test_encode_transaction() {
+        let tx = create_tx();
+        assert_eq!(tx.encode().unwrap().to_hex(), "a40082825820f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e76701825820554f2fd942a23d06835d26bbd78f0106fa94c8a551114a0bef81927f66467af000018282583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a001e848082583901558dd902616f5cd01edcc62870cb4748c45403f1228218bee5b628b526f0ca9e7a2c04d548fbd6ce86f358be139fe680652536437d1d6fd51a00ff9285021a000286b3031a032dcd61");
+        assert_eq!(
+            tx.id().unwrap().to_hex(),
+            "cc262713a3e15a0fa245b062f33ffc6c2aa5a64c3ae7bfa793414069914e1bbf"
+        );
+    }
+
+    #[test]
+    fn test_output_min_ada_amount() {
+        const COINS_PER_UTXO_BYTE: u64 = 4310;
+        let to_address = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+        let to_legacy = "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo";
+
+        let ada_only = output(to_address, 0, TokenBundle::default());
+        assert_eq!(
+            ada_only.min_ada_amount(COINS_PER_UTXO_BYTE).unwrap(),
+            969750
+        );
+
+        let ada_only_legacy = output(to_legacy, 0, TokenBundle::default());
+        assert_eq!(
+            ada_only_legacy.min_ada_amount(COINS_PER_UTXO_BYTE).unwrap(),
+            909410
+        );
+
+        let mut nft = TokenBundle::default();
+        nft.add(
+            TokenKey::new(
+                "219820e6cb04316f41a337fea356480f412e7acc147d28f175f21b5e",
+                b"coolcatssociety4567",
+            ),
+            1,
+        )
+        .unwrap();
+        let nft_output = output(to_address, 0, nft);
+        assert_eq!(
+            nft_output.min_ada_amount(COINS_PER_UTXO_BYTE).unwrap(),
+            1202490
+        );
+
+        let mut tokens = TokenBundle::default();
+        tokens
+            .add(
+                TokenKey::new(
+                    "8fef2d34078659493ce161a6c7fba4b56afefa8535296a5743f69587",
+                    b"AADA",
+                ),
+                20,
+            )
+            .unwrap();
+        tokens
+            .add(
+                TokenKey::new(
+                    "6ac8ef33b510ec004fe11585f7c5a9f0c07f0c23428ab4f29c1d7d10",
+                    b"MELD",
+                ),
+                20,
+            )
+            .unwrap();
+        let tokens_output = output(to_address, 0, tokens);
+        assert_eq!(
+            tokens_output.min_ada_amount(COINS_PER_UTXO_BYTE).unwrap(),
+            1297310
+        );
+
+        ada_only.min_ada_amount(u64::MAX).unwrap_err();
+    }
+}
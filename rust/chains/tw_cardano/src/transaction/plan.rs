@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::token::TokenBundle;
+use crate::transaction::{TxInput, TxOutput};
+use tw_coin_entry::error::SigningResult;
+use tw_proto::Cardano::Proto;
+
+/// The result of UTXO selection and fee estimation.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TransactionPlan {
+    /// Selected UTXOs.
+    pub utxos: Vec<TxInput>,
+    pub extra_outputs: Vec<TxOutput>,
+    /// Sum of the selected UTXO amounts.
+    pub available_amount: u64,
+    /// Amount to be sent to the destination address.
+    pub amount: u64,
+    pub fee: u64,
+    pub change: u64,
+    /// Staking key registration and delegation deposits.
+    pub deposit: u64,
+    /// Staking key deregistration refund and withdrawn rewards.
+    pub undeposit: u64,
+    pub available_tokens: TokenBundle,
+    pub output_tokens: TokenBundle,
+    pub change_tokens: TokenBundle,
+}
+
+impl TransactionPlan {
+    /// Note the `error` field is expected to be checked by the caller.
+    pub fn from_proto(proto: &Proto::TransactionPlan<'_>) -> SigningResult<TransactionPlan> {
+        Ok(TransactionPlan {
+            utxos: proto
+                .utxos
+                .iter()
+                .map(TxInput::from_proto)
+                .collect::<SigningResult<_>>()?,
+            extra_outputs: proto
+                .extra_outputs
+                .iter()
+                .map(TxOutput::from_proto)
+                .collect::<SigningResult<_>>()?,
+            available_amount: proto.available_amount,
+            amount: proto.amount,
+            fee: proto.fee,
+            change: proto.change,
+            deposit: proto.deposit,
+            undeposit: proto.undeposit,
+            available_tokens: TokenBundle::from_proto(&proto.available_tokens)?,
+            output_tokens: TokenBundle::from_proto(&proto.output_tokens)?,
+            change_tokens: TokenBundle::from_proto(&proto.change_tokens)?,
+        })
+    }
+
+    pub fn to_proto(&self) -> Proto::TransactionPlan<'static> {
+        Proto::TransactionPlan {
+            available_amount: self.available_amount,
+            amount: self.amount,
+            fee: self.fee,
+            change: self.change,
+            deposit: self.deposit,
+            undeposit: self.undeposit,
+            available_tokens: self.available_tokens.to_proto(),
+            output_tokens: self.output_tokens.to_proto(),
+            change_tokens: self.change_tokens.to_proto(),
+            utxos: self.utxos.iter().map(TxInput::to_proto).collect(),
+            extra_outputs: self.extra_outputs.iter().map(TxOutput::to_proto).collect(),
+            ..Proto::TransactionPlan::default()
+        }
+    }
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::cbor;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::cbor::Value;
+use tw_encoding::hex::{self, DecodeHex, ToHex};
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Cardano::Proto;
+
+/// Minimal amount of an ADA-only UTXO (pre-Babbage `minUTxOValue`).
+pub const MIN_UTXO_VALUE: u64 = 1_000_000;
+
+/// Identifies a native token by its minting policy and asset name.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TokenKey {
+    /// Hex-encoded policy ID.
+    pub policy_id: String,
+    pub asset_name: Data,
+}
+
+impl TokenKey {
+    pub fn new(policy_id: &str, asset_name: &[u8]) -> TokenKey {
+        TokenKey {
+            policy_id: policy_id.to_string(),
+            asset_name: asset_name.to_vec(),
+        }
+    }
+}
+
+/// Native token amounts grouped by [`TokenKey`].
+/// Amounts are limited to `u64` as they are encoded as `uint` in CBOR.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TokenBundle {
+    bundle: BTreeMap<TokenKey, u64>,
+}
+
+impl TokenBundle {
+    pub fn from_proto(tokens: &[Proto::TokenAmount<'_>]) -> SigningResult<TokenBundle> {
+        let mut bundle = TokenBundle::default();
+        for token in tokens {
+            let (key, amount) = token_from_proto(token)?;
+            bundle.add(key, amount)?;
+        }
+        Ok(bundle)
+    }
+
+    pub fn to_proto(&self) -> Vec<Proto::TokenAmount<'static>> {
+        self.bundle
+            .iter()
+            .map(|(key, amount)| Proto::TokenAmount {
+                policy_id: Cow::from(key.policy_id.clone()),
+                asset_name: Cow::from(String::from_utf8_lossy(&key.asset_name).into_owned()),
+                asset_name_hex: Cow::from(key.asset_name.to_hex()),
+                amount: Cow::from(U256::from(*amount).to_big_endian_compact_min_len(1)),
+            })
+            .collect()
+    }
+
+    /// Adds the `amount` to the existing amount of the token.
+    pub fn add(&mut self, key: TokenKey, amount: u64) -> SigningResult<()> {
+        let total = self.bundle.entry(key).or_default();
+        *total = total.checked_add(amount).ok_or(SigningError(
+            SigningErrorType::Error_invalid_requested_token_amount,
+        ))?;
+        Ok(())
+    }
+
+    /// Adds all tokens of the `other` bundle.
+    pub fn add_bundle(&mut self, other: &TokenBundle) -> SigningResult<()> {
+        for (key, amount) in other.iter() {
+            self.add(key.clone(), *amount)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the amount of the token, keeping zero amounts.
+    pub fn set(&mut self, key: TokenKey, amount: u64) {
+        self.bundle.insert(key, amount);
+    }
+
+    pub fn amount(&self, key: &TokenKey) -> u64 {
+        self.bundle.get(key).copied().unwrap_or_default()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&TokenKey, &u64)> {
+        self.bundle.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bundle.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bundle.is_empty()
+    }
+
+    /// Returns `true` if at least one token has a non-zero amount.
+    pub fn has_positive_amount(&self) -> bool {
+        self.bundle.values().any(|amount| *amount > 0)
+    }
+
+    /// Estimates the minimal ADA amount of an output holding the tokens.
+    /// https://github.com/input-output-hk/cardano-ledger/blob/master/doc/explanations/min-utxo-alonzo.rst
+    pub fn min_ada_amount(&self) -> u64 {
+        if self.is_empty() {
+            return MIN_UTXO_VALUE;
+        }
+
+        let policy_ids: HashSet<_> = self.bundle.keys().map(|key| &key.policy_id).collect();
+        let asset_names: HashSet<_> = self
+            .bundle
+            .keys()
+            .map(|key| &key.asset_name)
+            .filter(|name| !name.is_empty())
+            .collect();
+        let sum_asset_name_lengths = asset_names.iter().map(|name| name.len() as u64).sum();
+
+        min_ada_amount_helper(
+            policy_ids.len() as u64,
+            asset_names.len() as u64,
+            sum_asset_name_lengths,
+        )
+    }
+
+    /// Encodes the tokens as a two-level map: `{policy_id: {asset_name: amount}}`.
+    pub(crate) fn to_cbor_value(&self) -> SigningResult<Value> {
+        let mut policies: BTreeMap<&str, Vec<(Value, Value)>> = BTreeMap::new();
+        for (key, amount) in self.bundle.iter() {
+            policies
+                .entry(key.policy_id.as_str())
+                .or_default()
+                .push((cbor::bytes(&key.asset_name), cbor::uint(*amount)));
+        }
+
+        let mut entries = Vec::with_capacity(policies.len());
+        for (policy_id, assets) in policies {
+            let policy_id = policy_id
+                .decode_hex()
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+            entries.push((Value::Bytes(policy_id), cbor::map(assets)?));
+        }
+        cbor::map(entries)
+    }
+}
+
+/// Minimal ADA amount of an output containing `num_pids` policies and `num_assets` assets.
+pub fn min_ada_amount_helper(num_pids: u64, num_assets: u64, sum_asset_name_lengths: u64) -> u64 {
+    if num_pids == 0 {
+        return MIN_UTXO_VALUE;
+    }
+
+    const UTXO_ENTRY_SIZE_WITHOUT_VAL: u64 = 27;
+    const ADA_ONLY_UTXO_SIZE: u64 = UTXO_ENTRY_SIZE_WITHOUT_VAL;
+    const PID_SIZE: u64 = 28;
+
+    let size_b =
+        6 + roundup_bytes_to_words(num_assets * 12 + sum_asset_name_lengths + num_pids * PID_SIZE);
+    let min_ada = (MIN_UTXO_VALUE / ADA_ONLY_UTXO_SIZE) * (UTXO_ENTRY_SIZE_WITHOUT_VAL + size_b);
+    min_ada.max(MIN_UTXO_VALUE)
+}
+
+fn roundup_bytes_to_words(bytes: u64) -> u64 {
+    (bytes + 7) / 8
+}
+
+/// `asset_name` takes precedence over `asset_name_hex`.
+fn token_from_proto(proto: &Proto::TokenAmount<'_>) -> SigningResult<(TokenKey, u64)> {
+    let asset_name = if !proto.asset_name.is_empty() {
+        proto.asset_name.as_bytes().to_vec()
+    } else {
+        hex::decode(&proto.asset_name_hex)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?
+    };
+    let amount = U256::from_big_endian_slice(&proto.amount)?;
+    let key = TokenKey {
+        policy_id: proto.policy_id.to_string(),
+        asset_name,
+    };
+    Ok((key, u64::try_from(amount)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_min_ada_amount() {
+        assert_eq!(TokenBundle::default().min_ada_amount(), 1000000);
+
+        // 1 policyId, 1 6-char asset name.
+        let mut bundle = TokenBundle::default();
+        let policy_id = "9a9693a9a37912a5097918f97918d15240c92ab729a0b7c4aa144d77";
+        bundle.add(TokenKey::new(policy_id, b"TOKEN1"), 0).unwrap();
+        assert_eq!(bundle.min_ada_amount(), 1444443);
+
+        // 2 policyId, 2 4-char asset names.
+        let mut bundle = TokenBundle::default();
+        bundle
+            .add(TokenKey::new("012345678901234567890POLICY1", b"TOK1"), 20)
+            .unwrap();
+        bundle
+            .add(TokenKey::new("012345678901234567890POLICY2", b"TOK2"), 20)
+            .unwrap();
+        assert_eq!(bundle.min_ada_amount(), 1629628);
+
+        // 10 policyId, 10 6-char asset names.
+        let mut bundle = TokenBundle::default();
+        for i in 0..10 {
+            let policy_id = format!("012345678901234567890123456{i}");
+            let name = format!("ASSET{i}");
+            bundle
+                .add(TokenKey::new(&policy_id, name.as_bytes()), 0)
+                .unwrap();
+        }
+        assert_eq!(bundle.min_ada_amount(), 3370367);
+    }
+
+    #[test]
+    fn test_min_ada_amount_helper() {
+        assert_eq!(min_ada_amount_helper(0, 0, 0), 1000000);
+        assert_eq!(min_ada_amount_helper(1, 0, 0), 1370369);
+        assert_eq!(min_ada_amount_helper(1, 1, 1), 1444443);
+        assert_eq!(min_ada_amount_helper(1, 1, 6), 1444443);
+        assert_eq!(min_ada_amount_helper(1, 1, 32), 1555554);
+        assert_eq!(min_ada_amount_helper(1, 110, 110 * 32), 23777754);
+        assert_eq!(min_ada_amount_helper(2, 2, 8), 1629628);
+        assert_eq!(min_ada_amount_helper(3, 5, 20), 1999998);
+        assert_eq!(min_ada_amount_helper(10, 10, 10 * 6), 3370367);
+        assert_eq!(min_ada_amount_helper(60, 60, 60 * 32), 21222201);
+    }
+
+    #[test]
+    fn test_bundle_add() {
+        let key = TokenKey::new("012345678901234567890POLICY1", b"TOK1");
+        let mut bundle = TokenBundle::default();
+        bundle.add(key.clone(), 10).unwrap();
+        bundle.add(key.clone(), 20).unwrap();
+        assert_eq!(bundle.len(), 1);
+        assert_eq!(bundle.amount(&key), 30);
+        assert_eq!(
+            bundle.amount(&TokenKey::new("012345678901234567890POLICY1", b"TOK2")),
+            0
+        );
+
+        bundle.add(key, u64::MAX).unwrap_err();
+    }
+}
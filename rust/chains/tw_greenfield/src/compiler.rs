@@ -7,6 +7,7 @@ use crate::modules::eip712_signer::{Eip712Signer, Eip712TxPreimage};
 use crate::modules::tx_builder::TxBuilder;
 use crate::public_key::GreenfieldPublicKey;
 use crate::signature::GreenfieldSignature;
+use crate::transaction::GreenfieldUnsignedTransaction;
 use std::borrow::Cow;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
@@ -14,13 +15,21 @@ use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
 use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_coin_entry::signing_output_error;
 use tw_cosmos_sdk::modules::broadcast_msg::{BroadcastMode, BroadcastMsg};
+use tw_cosmos_sdk::modules::compiler::protobuf_preimager::{ProtobufPreimager, ProtobufTxPreimage};
 use tw_cosmos_sdk::modules::serializer::json_serializer::JsonSerializer;
 use tw_cosmos_sdk::modules::serializer::protobuf_serializer::ProtobufSerializer;
 use tw_cosmos_sdk::public_key::CosmosPublicKey;
+use tw_hash::H256;
+use tw_memory::Data;
 use tw_misc::traits::ToBytesVec;
 use tw_proto::Greenfield::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
 
+pub(crate) struct TxPreimage {
+    pub data: Data,
+    pub tx_hash: H256,
+}
+
 pub struct GreenfieldCompiler;
 
 impl GreenfieldCompiler {
@@ -40,15 +49,48 @@ impl GreenfieldCompiler {
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
         let unsigned = TxBuilder::unsigned_tx_from_proto(coin, &input)?;
-        let Eip712TxPreimage { eip712_tx, tx_hash } = Eip712Signer::preimage_hash(&unsigned)?;
+        let TxPreimage { data, tx_hash } = Self::preimage_hash(unsigned, input.signing_mode)?;
 
         Ok(CompilerProto::PreSigningOutput {
-            data: Cow::from(eip712_tx.to_vec()),
+            data: Cow::from(data),
             data_hash: Cow::from(tx_hash.to_vec()),
             ..CompilerProto::PreSigningOutput::default()
         })
     }
 
+    /// Computes the transaction preimage according to the given `signing_mode`:
+    /// * [`Proto::SigningMode::Eip712`] - EIP712 JSON message and its hash.
+    /// * [`Proto::SigningMode::Direct`] - serialized `SignDoc` and its Keccak256 hash.
+    pub(crate) fn preimage_hash(
+        unsigned: GreenfieldUnsignedTransaction,
+        signing_mode: Proto::SigningMode,
+    ) -> SigningResult<TxPreimage> {
+        match signing_mode {
+            Proto::SigningMode::Eip712 => {
+                let Eip712TxPreimage { eip712_tx, tx_hash } =
+                    Eip712Signer::preimage_hash(&unsigned)?;
+                Ok(TxPreimage {
+                    data: eip712_tx.into_bytes(),
+                    tx_hash,
+                })
+            },
+            Proto::SigningMode::Direct => {
+                let ProtobufTxPreimage {
+                    encoded_tx,
+                    tx_hash,
+                } = ProtobufPreimager::<GreenfieldContext>::preimage_hash(
+                    &unsigned.into_cosmos_unsigned(),
+                )?;
+                let tx_hash = H256::try_from(tx_hash.as_slice())
+                    .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+                Ok(TxPreimage {
+                    data: encoded_tx,
+                    tx_hash,
+                })
+            },
+        }
+    }
+
     #[inline]
     pub fn compile(
         coin: &dyn CoinContext,
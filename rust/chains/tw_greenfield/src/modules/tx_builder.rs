@@ -4,6 +4,10 @@
 
 use crate::address::GreenfieldAddress;
 use crate::public_key::GreenfieldPublicKey;
+use crate::transaction::message::policy::{
+    ActionType, Effect, Principal, PrincipalType, Statement,
+};
+use crate::transaction::message::storage_types::{Approval, VisibilityType};
 use crate::transaction::message::GreenfieldMessageBox;
 use crate::transaction::{
     GreenfieldFee, GreenfieldSignMode, GreenfieldSignerInfo, GreenfieldTxBody,
@@ -11,7 +15,7 @@ use crate::transaction::{
 };
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
-use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::error::{AddressResult, SigningError, SigningErrorType, SigningResult};
 use tw_cosmos_sdk::public_key::CosmosPublicKey;
 use tw_cosmos_sdk::transaction::{Coin, SignerInfo};
 use tw_misc::traits::OptionalEmpty;
@@ -39,8 +43,12 @@ impl TxBuilder {
             .ok_or(SigningError(SigningErrorType::Error_wrong_fee))?;
         let fee = Self::fee_from_proto(fee, &signer)?;
 
-        let eth_chain_id = U256::from_str(&input.eth_chain_id)
-            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        let eth_chain_id = match input.signing_mode {
+            // ETH chain ID is not used in the `Direct` mode.
+            Proto::SigningMode::Direct if input.eth_chain_id.is_empty() => U256::zero(),
+            _ => U256::from_str(&input.eth_chain_id)
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?,
+        };
 
         Ok(GreenfieldUnsignedTransaction {
             signer,
@@ -59,6 +67,7 @@ impl TxBuilder {
         let public_key = GreenfieldPublicKey::from_bytes(coin, &input.public_key)?;
         let sign_mode = match input.signing_mode {
             Proto::SigningMode::Eip712 => GreenfieldSignMode::Eip712,
+            Proto::SigningMode::Direct => GreenfieldSignMode::Direct,
         };
         Ok(SignerInfo {
             public_key,
@@ -120,6 +129,18 @@ impl TxBuilder {
             MessageEnum::bridge_transfer_out(ref transfer_out) => {
                 Self::bridge_transfer_out_from_proto(transfer_out)
             },
+            MessageEnum::create_bucket(ref create) => Self::create_bucket_from_proto(create),
+            MessageEnum::delete_bucket(ref delete) => Self::delete_bucket_from_proto(delete),
+            MessageEnum::create_object(ref create) => Self::create_object_from_proto(create),
+            MessageEnum::delete_object(ref delete) => Self::delete_object_from_proto(delete),
+            MessageEnum::put_policy(ref put) => Self::put_policy_from_proto(put),
+            MessageEnum::delete_policy(ref delete) => Self::delete_policy_from_proto(delete),
+            MessageEnum::create_group(ref create) => Self::create_group_from_proto(create),
+            MessageEnum::delete_group(ref delete) => Self::delete_group_from_proto(delete),
+            MessageEnum::update_group_member(ref update) => {
+                Self::update_group_member_from_proto(update)
+            },
+            MessageEnum::leave_group(ref leave) => Self::leave_group_from_proto(leave),
             MessageEnum::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
         }
     }
@@ -162,4 +183,261 @@ impl TxBuilder {
         };
         Ok(Box::new(msg))
     }
+
+    pub fn create_bucket_from_proto(
+        create: &Proto::mod_Message::CreateBucket<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::bucket::GreenfieldCreateBucket;
+
+        let payment_address = if create.payment_address.is_empty() {
+            None
+        } else {
+            Some(GreenfieldAddress::from_str(&create.payment_address)?)
+        };
+
+        let msg = GreenfieldCreateBucket {
+            custom_type_prefix: create.type_prefix.to_string().empty_or_some(),
+            creator: GreenfieldAddress::from_str(&create.creator)?,
+            bucket_name: create.bucket_name.to_string(),
+            visibility: Self::visibility_from_proto(create.visibility),
+            payment_address,
+            primary_sp_address: GreenfieldAddress::from_str(&create.primary_sp_address)?,
+            primary_sp_approval: Self::approval_from_proto(create.primary_sp_approval.as_ref())?,
+            charged_read_quota: create.charged_read_quota,
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn delete_bucket_from_proto(
+        delete: &Proto::mod_Message::DeleteBucket<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::bucket::GreenfieldDeleteBucket;
+
+        let msg = GreenfieldDeleteBucket {
+            custom_type_prefix: delete.type_prefix.to_string().empty_or_some(),
+            operator: GreenfieldAddress::from_str(&delete.operator)?,
+            bucket_name: delete.bucket_name.to_string(),
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn create_object_from_proto(
+        create: &Proto::mod_Message::CreateObject<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::object::GreenfieldCreateObject;
+        use crate::transaction::message::storage_types::RedundancyType;
+
+        let redundancy_type = match create.redundancy_type {
+            Proto::RedundancyType::REDUNDANCY_EC_TYPE => RedundancyType::ErasureCoding,
+            Proto::RedundancyType::REDUNDANCY_REPLICA_TYPE => RedundancyType::Replica,
+        };
+
+        let msg = GreenfieldCreateObject {
+            custom_type_prefix: create.type_prefix.to_string().empty_or_some(),
+            creator: GreenfieldAddress::from_str(&create.creator)?,
+            bucket_name: create.bucket_name.to_string(),
+            object_name: create.object_name.to_string(),
+            payload_size: create.payload_size,
+            visibility: Self::visibility_from_proto(create.visibility),
+            content_type: create.content_type.to_string(),
+            primary_sp_approval: Self::approval_from_proto(create.primary_sp_approval.as_ref())?,
+            expect_checksums: create
+                .expect_checksums
+                .iter()
+                .map(|checksum| checksum.to_vec())
+                .collect(),
+            redundancy_type,
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn delete_object_from_proto(
+        delete: &Proto::mod_Message::DeleteObject<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::object::GreenfieldDeleteObject;
+
+        let msg = GreenfieldDeleteObject {
+            custom_type_prefix: delete.type_prefix.to_string().empty_or_some(),
+            operator: GreenfieldAddress::from_str(&delete.operator)?,
+            bucket_name: delete.bucket_name.to_string(),
+            object_name: delete.object_name.to_string(),
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn put_policy_from_proto(
+        put: &Proto::mod_Message::PutPolicy<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::policy::GreenfieldPutPolicy;
+
+        let msg = GreenfieldPutPolicy {
+            custom_type_prefix: put.type_prefix.to_string().empty_or_some(),
+            operator: GreenfieldAddress::from_str(&put.operator)?,
+            principal: Self::principal_from_proto(put.principal.as_ref())?,
+            resource: put.resource.to_string(),
+            statements: put
+                .statements
+                .iter()
+                .map(Self::statement_from_proto)
+                .collect(),
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn delete_policy_from_proto(
+        delete: &Proto::mod_Message::DeletePolicy<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::policy::GreenfieldDeletePolicy;
+
+        let msg = GreenfieldDeletePolicy {
+            custom_type_prefix: delete.type_prefix.to_string().empty_or_some(),
+            operator: GreenfieldAddress::from_str(&delete.operator)?,
+            principal: Self::principal_from_proto(delete.principal.as_ref())?,
+            resource: delete.resource.to_string(),
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn create_group_from_proto(
+        create: &Proto::mod_Message::CreateGroup<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::group::GreenfieldCreateGroup;
+
+        let msg = GreenfieldCreateGroup {
+            custom_type_prefix: create.type_prefix.to_string().empty_or_some(),
+            creator: GreenfieldAddress::from_str(&create.creator)?,
+            group_name: create.group_name.to_string(),
+            extra: create.extra.to_string(),
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn delete_group_from_proto(
+        delete: &Proto::mod_Message::DeleteGroup<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::group::GreenfieldDeleteGroup;
+
+        let msg = GreenfieldDeleteGroup {
+            custom_type_prefix: delete.type_prefix.to_string().empty_or_some(),
+            operator: GreenfieldAddress::from_str(&delete.operator)?,
+            group_name: delete.group_name.to_string(),
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn update_group_member_from_proto(
+        update: &Proto::mod_Message::UpdateGroupMember<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::group::{GreenfieldUpdateGroupMember, GroupMember};
+
+        let members_to_add = update
+            .members_to_add
+            .iter()
+            .map(|member| -> SigningResult<GroupMember> {
+                Ok(GroupMember {
+                    member: GreenfieldAddress::from_str(member)?,
+                })
+            })
+            .collect::<SigningResult<_>>()?;
+        let members_to_delete = update
+            .members_to_delete
+            .iter()
+            .map(|member| GreenfieldAddress::from_str(member))
+            .collect::<AddressResult<_>>()?;
+
+        let msg = GreenfieldUpdateGroupMember {
+            custom_type_prefix: update.type_prefix.to_string().empty_or_some(),
+            operator: GreenfieldAddress::from_str(&update.operator)?,
+            group_owner: GreenfieldAddress::from_str(&update.group_owner)?,
+            group_name: update.group_name.to_string(),
+            members_to_add,
+            members_to_delete,
+        };
+        Ok(Box::new(msg))
+    }
+
+    pub fn leave_group_from_proto(
+        leave: &Proto::mod_Message::LeaveGroup<'_>,
+    ) -> SigningResult<GreenfieldMessageBox> {
+        use crate::transaction::message::group::GreenfieldLeaveGroup;
+
+        let msg = GreenfieldLeaveGroup {
+            custom_type_prefix: leave.type_prefix.to_string().empty_or_some(),
+            member: GreenfieldAddress::from_str(&leave.member)?,
+            group_owner: GreenfieldAddress::from_str(&leave.group_owner)?,
+            group_name: leave.group_name.to_string(),
+        };
+        Ok(Box::new(msg))
+    }
+
+    fn visibility_from_proto(visibility: Proto::VisibilityType) -> VisibilityType {
+        match visibility {
+            Proto::VisibilityType::VISIBILITY_TYPE_UNSPECIFIED => VisibilityType::Unspecified,
+            Proto::VisibilityType::VISIBILITY_TYPE_PUBLIC_READ => VisibilityType::PublicRead,
+            Proto::VisibilityType::VISIBILITY_TYPE_PRIVATE => VisibilityType::Private,
+            Proto::VisibilityType::VISIBILITY_TYPE_INHERIT => VisibilityType::Inherit,
+        }
+    }
+
+    fn approval_from_proto(approval: Option<&Proto::Approval<'_>>) -> SigningResult<Approval> {
+        let approval = approval.ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        Ok(Approval {
+            expired_height: approval.expired_height,
+            global_virtual_group_family_id: approval.global_virtual_group_family_id,
+            sig: approval.sig.to_vec(),
+        })
+    }
+
+    fn principal_from_proto(principal: Option<&Proto::Principal<'_>>) -> SigningResult<Principal> {
+        let principal = principal.ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        let principal_type = match principal.type_pb {
+            Proto::PrincipalType::PRINCIPAL_TYPE_UNSPECIFIED => PrincipalType::Unspecified,
+            Proto::PrincipalType::PRINCIPAL_TYPE_GNFD_ACCOUNT => PrincipalType::Account,
+            Proto::PrincipalType::PRINCIPAL_TYPE_GNFD_GROUP => PrincipalType::Group,
+        };
+        Ok(Principal {
+            principal_type,
+            value: principal.value.to_string(),
+        })
+    }
+
+    fn statement_from_proto(statement: &Proto::Statement<'_>) -> Statement {
+        let effect = match statement.effect {
+            Proto::Effect::EFFECT_UNSPECIFIED => Effect::Unspecified,
+            Proto::Effect::EFFECT_ALLOW => Effect::Allow,
+            Proto::Effect::EFFECT_DENY => Effect::Deny,
+        };
+        Statement {
+            effect,
+            actions: statement
+                .actions
+                .iter()
+                .map(|action| Self::action_from_proto(*action))
+                .collect(),
+            resources: statement
+                .resources
+                .iter()
+                .map(|resource| resource.to_string())
+                .collect(),
+        }
+    }
+
+    fn action_from_proto(action: Proto::ActionType) -> ActionType {
+        match action {
+            Proto::ActionType::ACTION_UNSPECIFIED => ActionType::Unspecified,
+            Proto::ActionType::ACTION_UPDATE_BUCKET_INFO => ActionType::UpdateBucketInfo,
+            Proto::ActionType::ACTION_DELETE_BUCKET => ActionType::DeleteBucket,
+            Proto::ActionType::ACTION_CREATE_OBJECT => ActionType::CreateObject,
+            Proto::ActionType::ACTION_DELETE_OBJECT => ActionType::DeleteObject,
+            Proto::ActionType::ACTION_COPY_OBJECT => ActionType::CopyObject,
+            Proto::ActionType::ACTION_GET_OBJECT => ActionType::GetObject,
+            Proto::ActionType::ACTION_EXECUTE_OBJECT => ActionType::ExecuteObject,
+            Proto::ActionType::ACTION_LIST_OBJECT => ActionType::ListObject,
+            Proto::ActionType::ACTION_UPDATE_GROUP_MEMBER => ActionType::UpdateGroupMember,
+            Proto::ActionType::ACTION_DELETE_GROUP => ActionType::DeleteGroup,
+            Proto::ActionType::ACTION_UPDATE_OBJECT_INFO => ActionType::UpdateObjectInfo,
+            Proto::ActionType::ACTION_UPDATE_GROUP_EXTRA => ActionType::UpdateGroupExtra,
+            Proto::ActionType::ACTION_TYPE_ALL => ActionType::All,
+        }
+    }
 }
@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use tw_coin_entry::coin_context::CoinContext;
+use tw_cosmos_sdk::public_key::secp256k1::verify_secp256k1_signature;
 use tw_cosmos_sdk::public_key::{CosmosPublicKey, JsonPublicKey, ProtobufPublicKey};
 use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::tw::{PrivateKey, PublicKeyType};
@@ -44,4 +45,8 @@ impl CosmosPublicKey for GreenfieldPublicKey {
     fn to_bytes(&self) -> Data {
         self.0.compressed().to_vec()
     }
+
+    fn verify(&self, signature: &[u8], message_hash: &[u8]) -> bool {
+        verify_secp256k1_signature(self.0.compressed().as_slice(), signature, message_hash)
+    }
 }
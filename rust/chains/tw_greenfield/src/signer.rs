@@ -2,8 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::compiler::GreenfieldCompiler;
-use crate::modules::eip712_signer::{Eip712Signer, Eip712TxPreimage};
+use crate::compiler::{GreenfieldCompiler, TxPreimage};
 use crate::modules::tx_builder::TxBuilder;
 use std::borrow::Cow;
 use tw_coin_entry::coin_context::CoinContext;
@@ -36,7 +35,8 @@ impl GreenfieldSigner {
         input.public_key = Cow::from(public_key.clone());
         let unsigned_tx = TxBuilder::unsigned_tx_from_proto(coin, &input)?;
 
-        let Eip712TxPreimage { tx_hash, .. } = Eip712Signer::preimage_hash(&unsigned_tx)?;
+        let TxPreimage { tx_hash, .. } =
+            GreenfieldCompiler::preimage_hash(unsigned_tx, input.signing_mode)?;
         // Get the standard secp256k1 signature. It will be EIP155 protected at the `GreenfieldCompiler::compile_impl`.
         let signature = key_pair.sign(tx_hash)?;
 
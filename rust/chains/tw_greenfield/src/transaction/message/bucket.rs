@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::GreenfieldAddress;
+use crate::transaction::message::storage_types::{Approval, VisibilityType};
+use crate::transaction::message::GreenfieldMessage;
+use serde::Serialize;
+use tw_coin_entry::error::SigningResult;
+use tw_cosmos_sdk::proto::greenfield as GreenfieldProto;
+use tw_cosmos_sdk::transaction::message::{
+    message_to_json, CosmosMessage, CosmosMessageBox, JsonMessage, ProtobufMessage,
+};
+use tw_evm::message::eip712::message_types::MessageTypesBuilder;
+use tw_evm::message::eip712::property::PropertyType;
+use tw_misc::serde::as_string;
+use tw_proto::{to_any, type_url};
+
+/// greenfield.storage.MsgCreateBucket
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>PrimarySpApproval": [ ... ],
+///     "Msg<IDX>": [
+///         {
+///             "name": "bucket_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "charged_read_quota",
+///             "type": "uint256"
+///         },
+///         {
+///             "name": "creator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "payment_address",
+///             "type": "string"
+///         },
+///         {
+///             "name": "primary_sp_address",
+///             "type": "string"
+///         },
+///         {
+///             "name": "primary_sp_approval",
+///             "type": "TypeMsg<IDX>PrimarySpApproval"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         },
+///         {
+///             "name": "visibility",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldCreateBucket {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub creator: GreenfieldAddress,
+    pub bucket_name: String,
+    pub visibility: VisibilityType,
+    /// Serialized as an empty string if not set.
+    #[serde(serialize_with = "serialize_optional_address")]
+    pub payment_address: Option<GreenfieldAddress>,
+    pub primary_sp_address: GreenfieldAddress,
+    pub primary_sp_approval: Approval,
+    #[serde(with = "as_string")]
+    pub charged_read_quota: u64,
+}
+
+impl CosmosMessage for GreenfieldCreateBucket {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgCreateBucket {
+            creator: self.creator.to_string(),
+            bucket_name: self.bucket_name.clone(),
+            visibility: self.visibility.to_proto(),
+            payment_address: self
+                .payment_address
+                .as_ref()
+                .map(GreenfieldAddress::to_string)
+                .unwrap_or_default(),
+            primary_sp_address: self.primary_sp_address.to_string(),
+            primary_sp_approval: Some(self.primary_sp_approval.to_proto()),
+            charged_read_quota: self.charged_read_quota,
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgCreateBucket>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldCreateBucket {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        let this_msg_type_name = self.eip712_type(msg_idx);
+
+        Approval::declare_eip712_type(msg_idx, message_types);
+
+        if let Some(mut builder) = message_types.add_custom_type(this_msg_type_name) {
+            let approval_msg_type = PropertyType::Custom(Approval::eip712_type(msg_idx));
+            builder
+                .add_property("bucket_name", PropertyType::String)
+                .add_property("charged_read_quota", PropertyType::Uint)
+                .add_property("creator", PropertyType::String)
+                .add_property("payment_address", PropertyType::String)
+                .add_property("primary_sp_address", PropertyType::String)
+                .add_property("primary_sp_approval", approval_msg_type)
+                .add_property("type", PropertyType::String)
+                .add_property("visibility", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
+
+/// greenfield.storage.MsgDeleteBucket
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "Msg<IDX>": [
+///         {
+///             "name": "bucket_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "operator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldDeleteBucket {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub operator: GreenfieldAddress,
+    pub bucket_name: String,
+}
+
+impl CosmosMessage for GreenfieldDeleteBucket {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgDeleteBucket {
+            operator: self.operator.to_string(),
+            bucket_name: self.bucket_name.clone(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgDeleteBucket>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldDeleteBucket {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(self.eip712_type(msg_idx)) {
+            builder
+                .add_property("bucket_name", PropertyType::String)
+                .add_property("operator", PropertyType::String)
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
+
+fn serialize_optional_address<S>(
+    address: &Option<GreenfieldAddress>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    address
+        .as_ref()
+        .map(GreenfieldAddress::to_string)
+        .unwrap_or_default()
+        .serialize(serializer)
+}
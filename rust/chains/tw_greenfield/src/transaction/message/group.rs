@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::GreenfieldAddress;
+use crate::transaction::message::GreenfieldMessage;
+use serde::Serialize;
+use tw_coin_entry::error::SigningResult;
+use tw_cosmos_sdk::proto::greenfield as GreenfieldProto;
+use tw_cosmos_sdk::transaction::message::{
+    message_to_json, CosmosMessage, CosmosMessageBox, JsonMessage, ProtobufMessage,
+};
+use tw_evm::abi::param_type::constructor::TypeConstructor;
+use tw_evm::message::eip712::message_types::MessageTypesBuilder;
+use tw_evm::message::eip712::property::PropertyType;
+use tw_proto::{to_any, type_url};
+
+/// greenfield.storage.MsgCreateGroup
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "Msg<IDX>": [
+///         {
+///             "name": "creator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "extra",
+///             "type": "string"
+///         },
+///         {
+///             "name": "group_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldCreateGroup {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub creator: GreenfieldAddress,
+    pub group_name: String,
+    pub extra: String,
+}
+
+impl CosmosMessage for GreenfieldCreateGroup {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgCreateGroup {
+            creator: self.creator.to_string(),
+            group_name: self.group_name.clone(),
+            extra: self.extra.clone(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgCreateGroup>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldCreateGroup {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(self.eip712_type(msg_idx)) {
+            builder
+                .add_property("creator", PropertyType::String)
+                .add_property("extra", PropertyType::String)
+                .add_property("group_name", PropertyType::String)
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
+
+/// greenfield.storage.MsgDeleteGroup
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "Msg<IDX>": [
+///         {
+///             "name": "group_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "operator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldDeleteGroup {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub operator: GreenfieldAddress,
+    pub group_name: String,
+}
+
+impl CosmosMessage for GreenfieldDeleteGroup {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgDeleteGroup {
+            operator: self.operator.to_string(),
+            group_name: self.group_name.clone(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgDeleteGroup>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldDeleteGroup {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(self.eip712_type(msg_idx)) {
+            builder
+                .add_property("group_name", PropertyType::String)
+                .add_property("operator", PropertyType::String)
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Clone, Serialize)]
+pub struct GroupMember {
+    pub member: GreenfieldAddress,
+}
+
+/// greenfield.storage.MsgUpdateGroupMember
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>MembersToAdd": [
+///         {
+///             "name": "member",
+///             "type": "string"
+///         }
+///     ],
+///     "Msg<IDX>": [
+///         {
+///             "name": "group_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "group_owner",
+///             "type": "string"
+///         },
+///         {
+///             "name": "members_to_add",
+///             "type": "TypeMsg<IDX>MembersToAdd[]"
+///         },
+///         {
+///             "name": "members_to_delete",
+///             "type": "string[]"
+///         },
+///         {
+///             "name": "operator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldUpdateGroupMember {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub operator: GreenfieldAddress,
+    pub group_owner: GreenfieldAddress,
+    pub group_name: String,
+    pub members_to_add: Vec<GroupMember>,
+    pub members_to_delete: Vec<GreenfieldAddress>,
+}
+
+impl GreenfieldUpdateGroupMember {
+    fn members_to_add_eip712_type(msg_idx: usize) -> String {
+        format!("TypeMsg{msg_idx}MembersToAdd")
+    }
+}
+
+impl CosmosMessage for GreenfieldUpdateGroupMember {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgUpdateGroupMember {
+            operator: self.operator.to_string(),
+            group_owner: self.group_owner.to_string(),
+            group_name: self.group_name.clone(),
+            members_to_add: self
+                .members_to_add
+                .iter()
+                .map(|member| GreenfieldProto::storage::MsgGroupMember {
+                    member: member.member.to_string(),
+                })
+                .collect(),
+            members_to_delete: self
+                .members_to_delete
+                .iter()
+                .map(GreenfieldAddress::to_string)
+                .collect(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgUpdateGroupMember>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldUpdateGroupMember {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        let members_to_add_type_name = Self::members_to_add_eip712_type(msg_idx);
+        if let Some(mut builder) = message_types.add_custom_type(members_to_add_type_name.clone()) {
+            builder.add_property("member", PropertyType::String);
+        }
+
+        if let Some(mut builder) = message_types.add_custom_type(self.eip712_type(msg_idx)) {
+            let members_to_add_type = PropertyType::Custom(members_to_add_type_name);
+            builder
+                .add_property("group_name", PropertyType::String)
+                .add_property("group_owner", PropertyType::String)
+                .add_property("members_to_add", PropertyType::array(members_to_add_type))
+                .add_property(
+                    "members_to_delete",
+                    PropertyType::array(PropertyType::String),
+                )
+                .add_property("operator", PropertyType::String)
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
+
+/// greenfield.storage.MsgLeaveGroup
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "Msg<IDX>": [
+///         {
+///             "name": "group_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "group_owner",
+///             "type": "string"
+///         },
+///         {
+///             "name": "member",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldLeaveGroup {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub member: GreenfieldAddress,
+    pub group_owner: GreenfieldAddress,
+    pub group_name: String,
+}
+
+impl CosmosMessage for GreenfieldLeaveGroup {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgLeaveGroup {
+            member: self.member.to_string(),
+            group_owner: self.group_owner.to_string(),
+            group_name: self.group_name.clone(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgLeaveGroup>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldLeaveGroup {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(self.eip712_type(msg_idx)) {
+            builder
+                .add_property("group_name", PropertyType::String)
+                .add_property("group_owner", PropertyType::String)
+                .add_property("member", PropertyType::String)
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
@@ -6,7 +6,12 @@ use crate::eip712_types::MsgPropertyType;
 use tw_cosmos_sdk::transaction::message::{CosmosMessage, CosmosMessageBox};
 use tw_evm::message::eip712::message_types::MessageTypesBuilder;
 
+pub mod bucket;
+pub mod group;
+pub mod object;
+pub mod policy;
 pub mod send_order;
+pub mod storage_types;
 pub mod transfer_out;
 pub mod type_msg_amount;
 
@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::GreenfieldAddress;
+use crate::transaction::message::storage_types::{
+    as_hex_array, Approval, RedundancyType, VisibilityType,
+};
+use crate::transaction::message::GreenfieldMessage;
+use serde::Serialize;
+use tw_coin_entry::error::SigningResult;
+use tw_cosmos_sdk::proto::greenfield as GreenfieldProto;
+use tw_cosmos_sdk::transaction::message::{
+    message_to_json, CosmosMessage, CosmosMessageBox, JsonMessage, ProtobufMessage,
+};
+use tw_evm::abi::param_type::constructor::TypeConstructor;
+use tw_evm::message::eip712::message_types::MessageTypesBuilder;
+use tw_evm::message::eip712::property::PropertyType;
+use tw_memory::Data;
+use tw_misc::serde::as_string;
+use tw_proto::{to_any, type_url};
+
+/// greenfield.storage.MsgCreateObject
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>PrimarySpApproval": [ ... ],
+///     "Msg<IDX>": [
+///         {
+///             "name": "bucket_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "content_type",
+///             "type": "string"
+///         },
+///         {
+///             "name": "creator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "expect_checksums",
+///             "type": "bytes[]"
+///         },
+///         {
+///             "name": "object_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "payload_size",
+///             "type": "uint256"
+///         },
+///         {
+///             "name": "primary_sp_approval",
+///             "type": "TypeMsg<IDX>PrimarySpApproval"
+///         },
+///         {
+///             "name": "redundancy_type",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         },
+///         {
+///             "name": "visibility",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldCreateObject {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub creator: GreenfieldAddress,
+    pub bucket_name: String,
+    pub object_name: String,
+    #[serde(with = "as_string")]
+    pub payload_size: u64,
+    pub visibility: VisibilityType,
+    pub content_type: String,
+    pub primary_sp_approval: Approval,
+    #[serde(serialize_with = "as_hex_array")]
+    pub expect_checksums: Vec<Data>,
+    pub redundancy_type: RedundancyType,
+}
+
+impl CosmosMessage for GreenfieldCreateObject {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgCreateObject {
+            creator: self.creator.to_string(),
+            bucket_name: self.bucket_name.clone(),
+            object_name: self.object_name.clone(),
+            payload_size: self.payload_size,
+            visibility: self.visibility.to_proto(),
+            content_type: self.content_type.clone(),
+            primary_sp_approval: Some(self.primary_sp_approval.to_proto()),
+            expect_checksums: self.expect_checksums.clone(),
+            redundancy_type: self.redundancy_type.to_proto(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgCreateObject>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldCreateObject {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        let this_msg_type_name = self.eip712_type(msg_idx);
+
+        Approval::declare_eip712_type(msg_idx, message_types);
+
+        if let Some(mut builder) = message_types.add_custom_type(this_msg_type_name) {
+            let approval_msg_type = PropertyType::Custom(Approval::eip712_type(msg_idx));
+            builder
+                .add_property("bucket_name", PropertyType::String)
+                .add_property("content_type", PropertyType::String)
+                .add_property("creator", PropertyType::String)
+                .add_property("expect_checksums", PropertyType::array(PropertyType::Bytes))
+                .add_property("object_name", PropertyType::String)
+                .add_property("payload_size", PropertyType::Uint)
+                .add_property("primary_sp_approval", approval_msg_type)
+                .add_property("redundancy_type", PropertyType::String)
+                .add_property("type", PropertyType::String)
+                .add_property("visibility", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
+
+/// greenfield.storage.MsgDeleteObject
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "Msg<IDX>": [
+///         {
+///             "name": "bucket_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "object_name",
+///             "type": "string"
+///         },
+///         {
+///             "name": "operator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldDeleteObject {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub operator: GreenfieldAddress,
+    pub bucket_name: String,
+    pub object_name: String,
+}
+
+impl CosmosMessage for GreenfieldDeleteObject {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgDeleteObject {
+            operator: self.operator.to_string(),
+            bucket_name: self.bucket_name.clone(),
+            object_name: self.object_name.clone(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgDeleteObject>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldDeleteObject {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(self.eip712_type(msg_idx)) {
+            builder
+                .add_property("bucket_name", PropertyType::String)
+                .add_property("object_name", PropertyType::String)
+                .add_property("operator", PropertyType::String)
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
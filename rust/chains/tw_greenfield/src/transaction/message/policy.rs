@@ -0,0 +1,380 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::GreenfieldAddress;
+use crate::transaction::message::GreenfieldMessage;
+use serde::Serialize;
+use tw_coin_entry::error::SigningResult;
+use tw_cosmos_sdk::proto::greenfield as GreenfieldProto;
+use tw_cosmos_sdk::transaction::message::{
+    message_to_json, CosmosMessage, CosmosMessageBox, JsonMessage, ProtobufMessage,
+};
+use tw_evm::abi::param_type::constructor::TypeConstructor;
+use tw_evm::message::eip712::message_types::MessageTypesBuilder;
+use tw_evm::message::eip712::property::PropertyType;
+use tw_proto::{to_any, type_url};
+
+#[derive(Clone, Copy, Serialize)]
+pub enum PrincipalType {
+    #[serde(rename = "PRINCIPAL_TYPE_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "PRINCIPAL_TYPE_GNFD_ACCOUNT")]
+    Account,
+    #[serde(rename = "PRINCIPAL_TYPE_GNFD_GROUP")]
+    Group,
+}
+
+impl PrincipalType {
+    fn to_proto(self) -> GreenfieldProto::permission::PrincipalType {
+        use GreenfieldProto::permission::PrincipalType as ProtoPrincipalType;
+
+        match self {
+            PrincipalType::Unspecified => ProtoPrincipalType::PRINCIPAL_TYPE_UNSPECIFIED,
+            PrincipalType::Account => ProtoPrincipalType::PRINCIPAL_TYPE_GNFD_ACCOUNT,
+            PrincipalType::Group => ProtoPrincipalType::PRINCIPAL_TYPE_GNFD_GROUP,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub enum Effect {
+    #[serde(rename = "EFFECT_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "EFFECT_ALLOW")]
+    Allow,
+    #[serde(rename = "EFFECT_DENY")]
+    Deny,
+}
+
+impl Effect {
+    fn to_proto(self) -> GreenfieldProto::permission::Effect {
+        use GreenfieldProto::permission::Effect as ProtoEffect;
+
+        match self {
+            Effect::Unspecified => ProtoEffect::EFFECT_UNSPECIFIED,
+            Effect::Allow => ProtoEffect::EFFECT_ALLOW,
+            Effect::Deny => ProtoEffect::EFFECT_DENY,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub enum ActionType {
+    #[serde(rename = "ACTION_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "ACTION_UPDATE_BUCKET_INFO")]
+    UpdateBucketInfo,
+    #[serde(rename = "ACTION_DELETE_BUCKET")]
+    DeleteBucket,
+    #[serde(rename = "ACTION_CREATE_OBJECT")]
+    CreateObject,
+    #[serde(rename = "ACTION_DELETE_OBJECT")]
+    DeleteObject,
+    #[serde(rename = "ACTION_COPY_OBJECT")]
+    CopyObject,
+    #[serde(rename = "ACTION_GET_OBJECT")]
+    GetObject,
+    #[serde(rename = "ACTION_EXECUTE_OBJECT")]
+    ExecuteObject,
+    #[serde(rename = "ACTION_LIST_OBJECT")]
+    ListObject,
+    #[serde(rename = "ACTION_UPDATE_GROUP_MEMBER")]
+    UpdateGroupMember,
+    #[serde(rename = "ACTION_DELETE_GROUP")]
+    DeleteGroup,
+    #[serde(rename = "ACTION_UPDATE_OBJECT_INFO")]
+    UpdateObjectInfo,
+    #[serde(rename = "ACTION_UPDATE_GROUP_EXTRA")]
+    UpdateGroupExtra,
+    #[serde(rename = "ACTION_TYPE_ALL")]
+    All,
+}
+
+impl ActionType {
+    fn to_proto(self) -> GreenfieldProto::permission::ActionType {
+        use GreenfieldProto::permission::ActionType as ProtoActionType;
+
+        match self {
+            ActionType::Unspecified => ProtoActionType::ACTION_UNSPECIFIED,
+            ActionType::UpdateBucketInfo => ProtoActionType::ACTION_UPDATE_BUCKET_INFO,
+            ActionType::DeleteBucket => ProtoActionType::ACTION_DELETE_BUCKET,
+            ActionType::CreateObject => ProtoActionType::ACTION_CREATE_OBJECT,
+            ActionType::DeleteObject => ProtoActionType::ACTION_DELETE_OBJECT,
+            ActionType::CopyObject => ProtoActionType::ACTION_COPY_OBJECT,
+            ActionType::GetObject => ProtoActionType::ACTION_GET_OBJECT,
+            ActionType::ExecuteObject => ProtoActionType::ACTION_EXECUTE_OBJECT,
+            ActionType::ListObject => ProtoActionType::ACTION_LIST_OBJECT,
+            ActionType::UpdateGroupMember => ProtoActionType::ACTION_UPDATE_GROUP_MEMBER,
+            ActionType::DeleteGroup => ProtoActionType::ACTION_DELETE_GROUP,
+            ActionType::UpdateObjectInfo => ProtoActionType::ACTION_UPDATE_OBJECT_INFO,
+            ActionType::UpdateGroupExtra => ProtoActionType::ACTION_UPDATE_GROUP_EXTRA,
+            ActionType::All => ProtoActionType::ACTION_TYPE_ALL,
+        }
+    }
+}
+
+/// A role that permissions can be granted to.
+///
+/// # EIP712 custom type
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>Principal": [
+///         {
+///             "name": "type",
+///             "type": "string"
+///         },
+///         {
+///             "name": "value",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct Principal {
+    #[serde(rename = "type")]
+    pub principal_type: PrincipalType,
+    /// An account address or a group ID.
+    pub value: String,
+}
+
+impl Principal {
+    fn eip712_type(msg_idx: usize) -> String {
+        format!("TypeMsg{msg_idx}Principal")
+    }
+
+    fn declare_eip712_type(msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(Self::eip712_type(msg_idx)) {
+            builder
+                .add_property("type", PropertyType::String)
+                .add_property("value", PropertyType::String);
+        }
+    }
+
+    fn to_proto(&self) -> GreenfieldProto::permission::Principal {
+        GreenfieldProto::permission::Principal {
+            type_pb: self.principal_type.to_proto(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+/// # EIP712 custom type
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>Statements": [
+///         {
+///             "name": "actions",
+///             "type": "string[]"
+///         },
+///         {
+///             "name": "effect",
+///             "type": "string"
+///         },
+///         {
+///             "name": "resources",
+///             "type": "string[]"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct Statement {
+    pub effect: Effect,
+    pub actions: Vec<ActionType>,
+    pub resources: Vec<String>,
+}
+
+impl Statement {
+    fn eip712_type(msg_idx: usize) -> String {
+        format!("TypeMsg{msg_idx}Statements")
+    }
+
+    fn declare_eip712_type(msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(Self::eip712_type(msg_idx)) {
+            builder
+                .add_property("actions", PropertyType::array(PropertyType::String))
+                .add_property("effect", PropertyType::String)
+                .add_property("resources", PropertyType::array(PropertyType::String));
+        }
+    }
+
+    fn to_proto(&self) -> GreenfieldProto::permission::Statement {
+        GreenfieldProto::permission::Statement {
+            effect: self.effect.to_proto(),
+            actions: self
+                .actions
+                .iter()
+                .map(|action| action.to_proto())
+                .collect(),
+            resources: self.resources.clone(),
+        }
+    }
+}
+
+/// greenfield.storage.MsgPutPolicy
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>Principal": [ ... ],
+///     "TypeMsg<IDX>Statements": [ ... ],
+///     "Msg<IDX>": [
+///         {
+///             "name": "operator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "principal",
+///             "type": "TypeMsg<IDX>Principal"
+///         },
+///         {
+///             "name": "resource",
+///             "type": "string"
+///         },
+///         {
+///             "name": "statements",
+///             "type": "TypeMsg<IDX>Statements[]"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldPutPolicy {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub operator: GreenfieldAddress,
+    pub principal: Principal,
+    /// Greenfield resource name (GRN).
+    pub resource: String,
+    pub statements: Vec<Statement>,
+}
+
+impl CosmosMessage for GreenfieldPutPolicy {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgPutPolicy {
+            operator: self.operator.to_string(),
+            principal: Some(self.principal.to_proto()),
+            resource: self.resource.clone(),
+            statements: self.statements.iter().map(Statement::to_proto).collect(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgPutPolicy>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldPutPolicy {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        let this_msg_type_name = self.eip712_type(msg_idx);
+
+        Principal::declare_eip712_type(msg_idx, message_types);
+        Statement::declare_eip712_type(msg_idx, message_types);
+
+        if let Some(mut builder) = message_types.add_custom_type(this_msg_type_name) {
+            let principal_type = PropertyType::Custom(Principal::eip712_type(msg_idx));
+            let statement_type = PropertyType::Custom(Statement::eip712_type(msg_idx));
+            builder
+                .add_property("operator", PropertyType::String)
+                .add_property("principal", principal_type)
+                .add_property("resource", PropertyType::String)
+                .add_property("statements", PropertyType::array(statement_type))
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
+
+/// greenfield.storage.MsgDeletePolicy
+///
+/// # EIP712 custom types
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>Principal": [ ... ],
+///     "Msg<IDX>": [
+///         {
+///             "name": "operator",
+///             "type": "string"
+///         },
+///         {
+///             "name": "principal",
+///             "type": "TypeMsg<IDX>Principal"
+///         },
+///         {
+///             "name": "resource",
+///             "type": "string"
+///         },
+///         {
+///             "name": "type",
+///             "type": "string"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct GreenfieldDeletePolicy {
+    #[serde(skip)]
+    pub custom_type_prefix: Option<String>,
+    pub operator: GreenfieldAddress,
+    pub principal: Principal,
+    /// Greenfield resource name (GRN).
+    pub resource: String,
+}
+
+impl CosmosMessage for GreenfieldDeletePolicy {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msg = GreenfieldProto::storage::MsgDeletePolicy {
+            operator: self.operator.to_string(),
+            principal: Some(self.principal.to_proto()),
+            resource: self.resource.clone(),
+        };
+        Ok(to_any(&msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let msg_type = self
+            .custom_type_prefix
+            .clone()
+            .unwrap_or_else(type_url::<GreenfieldProto::storage::MsgDeletePolicy>);
+        message_to_json(&msg_type, self)
+    }
+}
+
+impl GreenfieldMessage for GreenfieldDeletePolicy {
+    fn declare_eip712_type(&self, msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        let this_msg_type_name = self.eip712_type(msg_idx);
+
+        Principal::declare_eip712_type(msg_idx, message_types);
+
+        if let Some(mut builder) = message_types.add_custom_type(this_msg_type_name) {
+            let principal_type = PropertyType::Custom(Principal::eip712_type(msg_idx));
+            builder
+                .add_property("operator", PropertyType::String)
+                .add_property("principal", principal_type)
+                .add_property("resource", PropertyType::String)
+                .add_property("type", PropertyType::String);
+        }
+    }
+
+    fn to_cosmos_message(&self) -> CosmosMessageBox {
+        Box::new(self.clone())
+    }
+}
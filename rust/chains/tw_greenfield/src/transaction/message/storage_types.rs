@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::{Serialize, Serializer};
+use tw_cosmos_sdk::proto::greenfield as GreenfieldProto;
+use tw_encoding::hex::{as_hex, ToHex};
+use tw_evm::message::eip712::message_types::MessageTypesBuilder;
+use tw_evm::message::eip712::property::PropertyType;
+use tw_memory::Data;
+use tw_misc::serde::as_string;
+
+#[derive(Clone, Copy, Serialize)]
+pub enum VisibilityType {
+    #[serde(rename = "VISIBILITY_TYPE_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "VISIBILITY_TYPE_PUBLIC_READ")]
+    PublicRead,
+    #[serde(rename = "VISIBILITY_TYPE_PRIVATE")]
+    Private,
+    #[serde(rename = "VISIBILITY_TYPE_INHERIT")]
+    Inherit,
+}
+
+impl VisibilityType {
+    pub fn to_proto(self) -> GreenfieldProto::storage::VisibilityType {
+        use GreenfieldProto::storage::VisibilityType as ProtoVisibilityType;
+
+        match self {
+            VisibilityType::Unspecified => ProtoVisibilityType::VISIBILITY_TYPE_UNSPECIFIED,
+            VisibilityType::PublicRead => ProtoVisibilityType::VISIBILITY_TYPE_PUBLIC_READ,
+            VisibilityType::Private => ProtoVisibilityType::VISIBILITY_TYPE_PRIVATE,
+            VisibilityType::Inherit => ProtoVisibilityType::VISIBILITY_TYPE_INHERIT,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub enum RedundancyType {
+    #[serde(rename = "REDUNDANCY_EC_TYPE")]
+    ErasureCoding,
+    #[serde(rename = "REDUNDANCY_REPLICA_TYPE")]
+    Replica,
+}
+
+impl RedundancyType {
+    pub fn to_proto(self) -> GreenfieldProto::storage::RedundancyType {
+        use GreenfieldProto::storage::RedundancyType as ProtoRedundancyType;
+
+        match self {
+            RedundancyType::ErasureCoding => ProtoRedundancyType::REDUNDANCY_EC_TYPE,
+            RedundancyType::Replica => ProtoRedundancyType::REDUNDANCY_REPLICA_TYPE,
+        }
+    }
+}
+
+/// Primary Storage Provider approval.
+///
+/// # EIP712 custom type
+///
+/// ```json
+/// {
+///     "TypeMsg<IDX>PrimarySpApproval": [
+///         {
+///             "name": "expired_height",
+///             "type": "uint256"
+///         },
+///         {
+///             "name": "global_virtual_group_family_id",
+///             "type": "uint256"
+///         },
+///         {
+///             "name": "sig",
+///             "type": "bytes"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Serialize)]
+pub struct Approval {
+    #[serde(with = "as_string")]
+    pub expired_height: u64,
+    pub global_virtual_group_family_id: u32,
+    #[serde(serialize_with = "as_hex")]
+    pub sig: Data,
+}
+
+impl Approval {
+    pub fn eip712_type(msg_idx: usize) -> String {
+        format!("TypeMsg{msg_idx}PrimarySpApproval")
+    }
+
+    pub fn declare_eip712_type(msg_idx: usize, message_types: &mut MessageTypesBuilder) {
+        if let Some(mut builder) = message_types.add_custom_type(Self::eip712_type(msg_idx)) {
+            builder
+                .add_property("expired_height", PropertyType::Uint)
+                .add_property("global_virtual_group_family_id", PropertyType::Uint)
+                .add_property("sig", PropertyType::Bytes);
+        }
+    }
+
+    pub fn to_proto(&self) -> GreenfieldProto::common::Approval {
+        GreenfieldProto::common::Approval {
+            expired_height: self.expired_height,
+            global_virtual_group_family_id: self.global_virtual_group_family_id,
+            sig: self.sig.clone(),
+        }
+    }
+}
+
+/// Serializes a list of binary items as a list of hex strings.
+pub fn as_hex_array<S>(items: &[Data], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    items
+        .iter()
+        .map(|item| item.to_hex())
+        .collect::<Vec<_>>()
+        .serialize(serializer)
+}
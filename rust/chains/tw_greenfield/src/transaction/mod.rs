@@ -20,6 +20,7 @@ pub type GreenfieldSignedTransaction = SignedTransaction<GreenfieldContext>;
 pub mod message;
 
 pub enum GreenfieldSignMode {
+    Direct,
     Eip712,
 }
 
@@ -28,6 +29,7 @@ impl From<GreenfieldSignMode> for SignMode {
         use tw_cosmos_sdk::proto::cosmos::signing::v1beta1 as signing_proto;
 
         match mode {
+            GreenfieldSignMode::Direct => SignMode::Direct,
             GreenfieldSignMode::Eip712 => {
                 SignMode::Other(signing_proto::SignMode::SIGN_MODE_EIP_712 as i32)
             },
@@ -69,7 +71,7 @@ impl GreenfieldUnsignedTransaction {
         self.into_cosmos_unsigned().into_signed(signature.to_vec())
     }
 
-    fn into_cosmos_unsigned(self) -> UnsignedTransaction<GreenfieldContext> {
+    pub fn into_cosmos_unsigned(self) -> UnsignedTransaction<GreenfieldContext> {
         UnsignedTransaction {
             signer: self.signer,
             fee: self.fee,
@@ -9,11 +9,11 @@ use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::AddressResult;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_cosmos_sdk::address::{Address, Bech32Prefix};
 use tw_cosmos_sdk::modules::compiler::tw_compiler::TWTransactionCompiler;
+use tw_cosmos_sdk::modules::message_signer::tw_message_signer::TWMessageSigner;
 use tw_cosmos_sdk::modules::signer::tw_signer::TWSigner;
 use tw_keypair::tw;
 use tw_proto::Cosmos::Proto;
@@ -29,7 +29,7 @@ impl CoinEntry for NativeEvmosEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = TWMessageSigner<NativeEvmosContext>;
     type WalletConnector = NoWalletConnector;
 
     #[inline]
@@ -86,4 +86,9 @@ impl CoinEntry for NativeEvmosEntry {
     ) -> Self::SigningOutput {
         TWTransactionCompiler::<NativeEvmosContext>::compile(coin, input, signatures, public_keys)
     }
+
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(TWMessageSigner::default())
+    }
 }
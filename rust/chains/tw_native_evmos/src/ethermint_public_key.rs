@@ -4,6 +4,7 @@
 
 use tw_coin_entry::coin_context::CoinContext;
 use tw_cosmos_sdk::proto::ethermint;
+use tw_cosmos_sdk::public_key::secp256k1::verify_secp256k1_signature;
 use tw_cosmos_sdk::public_key::{CosmosPublicKey, JsonPublicKey, ProtobufPublicKey};
 use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::KeyPairResult;
@@ -45,6 +46,10 @@ impl CosmosPublicKey for EthermintEthSecp256PublicKey {
     fn to_bytes(&self) -> Data {
         self.public_key.clone()
     }
+
+    fn verify(&self, signature: &[u8], message_hash: &[u8]) -> bool {
+        verify_secp256k1_signature(&self.public_key, signature, message_hash)
+    }
 }
 
 impl JsonPublicKey for EthermintEthSecp256PublicKey {
@@ -4,7 +4,9 @@
 
 use tw_coin_entry::coin_context::CoinContext;
 use tw_cosmos_sdk::proto::injective;
-use tw_cosmos_sdk::public_key::secp256k1::prepare_secp256k1_public_key;
+use tw_cosmos_sdk::public_key::secp256k1::{
+    prepare_secp256k1_public_key, verify_secp256k1_signature,
+};
 use tw_cosmos_sdk::public_key::{CosmosPublicKey, JsonPublicKey, ProtobufPublicKey};
 use tw_keypair::tw::PrivateKey;
 use tw_keypair::KeyPairResult;
@@ -34,6 +36,10 @@ impl CosmosPublicKey for InjectiveEthSecp256PublicKey {
     fn to_bytes(&self) -> Data {
         self.public_key.clone()
     }
+
+    fn verify(&self, signature: &[u8], message_hash: &[u8]) -> bool {
+        verify_secp256k1_signature(&self.public_key, signature, message_hash)
+    }
 }
 
 impl JsonPublicKey for InjectiveEthSecp256PublicKey {
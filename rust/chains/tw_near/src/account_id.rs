@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::error::AddressError;
+
+/// NEAR account ID: either a named account like `alice.near`,
+/// or an implicit account, i.e. a hex-encoded `ed25519` public key.
+/// https://nomicon.io/DataStructures/Account#account-id-rules
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct AccountId(String);
+
+impl AccountId {
+    pub const MIN_LEN: usize = 2;
+    pub const MAX_LEN: usize = 64;
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Checks whether the account ID satisfies the following rules:
+    /// * length is between 2 and 64 characters;
+    /// * consists of parts separated by `.`;
+    /// * every part consists of lowercase alphanumeric characters separated by a single `-` or `_`.
+    pub fn is_valid(s: &str) -> bool {
+        if s.len() < Self::MIN_LEN || s.len() > Self::MAX_LEN {
+            return false;
+        }
+        s.split('.').all(Self::is_valid_part)
+    }
+
+    fn is_valid_part(part: &str) -> bool {
+        let mut prev_is_separator = true;
+        for c in part.chars() {
+            match c {
+                'a'..='z' | '0'..='9' => prev_is_separator = false,
+                '-' | '_' if !prev_is_separator => prev_is_separator = true,
+                _ => return false,
+            }
+        }
+        // The part must not be empty or end with a separator.
+        !prev_is_separator
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !AccountId::is_valid(s) {
+            return Err(AddressError::InvalidInput);
+        }
+        Ok(AccountId(s.to_string()))
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_id_valid() {
+        let valid = [
+            "9902c136629fc630416e50d4f2fef6aff867ea7e.lockup.near",
+            "app_1.alice.near",
+            "test-trust.vlad.near",
+            "deadbeef",
+            "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d",
+        ];
+        for s in valid {
+            assert_eq!(AccountId::from_str(s).unwrap().as_str(), s);
+        }
+    }
+
+    #[test]
+    fn test_account_id_invalid() {
+        let invalid = [
+            "a",
+            "!?:",
+            "11111111111111111111111111111111222222222222222222222222222222223",
+            "Alice.near",
+            "alice..near",
+            ".near",
+            "alice.",
+            "alice--bob.near",
+            "-alice.near",
+            "alice_.near",
+        ];
+        for s in invalid {
+            AccountId::from_str(s).unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::hex;
+use tw_hash::H256;
+use tw_keypair::ed25519;
+use tw_memory::Data;
+
+const LEGACY_PREFIX: &str = "NEAR";
+const LEGACY_CHECKSUM_LEN: usize = 4;
+
+/// NEAR implicit account address: a hex-encoded `ed25519` public key.
+/// The legacy `NEAR`-prefixed Base58 representation is also accepted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NearAddress {
+    bytes: H256,
+}
+
+impl NearAddress {
+    pub fn with_public_key(public_key: &ed25519::sha512::PublicKey) -> NearAddress {
+        NearAddress {
+            bytes: public_key.to_bytes(),
+        }
+    }
+
+    pub fn bytes(&self) -> H256 {
+        self.bytes
+    }
+
+    /// Decodes `NEAR` + Base58(public key + checksum).
+    /// Note the checksum is not verified for compatibility with the existing addresses.
+    fn from_legacy_str(s: &str) -> AddressResult<NearAddress> {
+        let encoded = s
+            .strip_prefix(LEGACY_PREFIX)
+            .ok_or(AddressError::InvalidInput)?;
+        let decoded =
+            base58::decode(encoded, Alphabet::BITCOIN).map_err(|_| AddressError::InvalidInput)?;
+        if decoded.len() != H256::LEN + LEGACY_CHECKSUM_LEN {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let bytes =
+            H256::try_from(&decoded[..H256::LEN]).map_err(|_| AddressError::InvalidInput)?;
+        Ok(NearAddress { bytes })
+    }
+
+    fn from_hex_str(s: &str) -> AddressResult<NearAddress> {
+        if s.len() != H256::LEN * 2 {
+            return Err(AddressError::InvalidInput);
+        }
+        let bytes = H256::from_str(s).map_err(|_| AddressError::FromHexError)?;
+        Ok(NearAddress { bytes })
+    }
+}
+
+impl CoinAddress for NearAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.bytes.to_vec()
+    }
+}
+
+impl FromStr for NearAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(LEGACY_PREFIX) {
+            NearAddress::from_legacy_str(s)
+        } else {
+            NearAddress::from_hex_str(s)
+        }
+    }
+}
+
+/// Displays the address as a lowercase hex string, which is also the implicit account ID.
+impl fmt::Display for NearAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.bytes.as_slice(), false))
+    }
+}
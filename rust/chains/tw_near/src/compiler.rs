@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_builder::TransactionBuilder;
+use crate::signer::NearSigner;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ed25519;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_proto::NEAR::Proto;
+
+pub struct NearCompiler;
+
+impl NearCompiler {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    /// The signer's public key must be specified in `input.public_key`.
+    fn preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())?;
+        let unsigned_tx = TransactionBuilder::build(&input, &public_key)?;
+
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(unsigned_tx.preimage()?),
+            data_hash: Cow::from(unsigned_tx.hash()?.to_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn compile_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let SingleSignaturePubkey {
+            signature,
+            public_key,
+        } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
+
+        let public_key = ed25519::sha512::PublicKey::try_from(public_key.as_slice())?;
+        let signature = ed25519::Signature::try_from(signature.as_slice())?;
+
+        let unsigned_tx = TransactionBuilder::build(&input, &public_key)?;
+        let hash = unsigned_tx.hash()?;
+        let output = NearSigner::output_from_signed(unsigned_tx, &signature)?;
+        if !public_key.verify(signature, hash.to_vec()) {
+            return Err(SigningError(SigningErrorType::Error_signing));
+        }
+        Ok(output)
+    }
+}
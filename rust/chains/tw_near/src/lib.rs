@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod account_id;
+pub mod address;
+pub mod compiler;
+pub mod entry;
+pub mod modules;
+mod serde_helper;
+pub mod signer;
+pub mod transaction;
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::serde_helper::fixed_bytes;
+use serde::Serialize;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::borsh;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_memory::Data;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_proto::NEAR::Proto;
+
+/// The prefix that distinguishes a signed message from a signed transaction.
+/// https://github.com/near/NEPs/blob/master/neps/nep-0461.md
+pub const NEP413_PREFIX: u32 = (1 << 31) + 413;
+
+/// NEP-413 message payload.
+/// https://github.com/near/NEPs/blob/master/neps/nep-0413.md
+#[derive(Serialize)]
+struct Payload<'a> {
+    message: &'a str,
+    #[serde(serialize_with = "fixed_bytes::serialize")]
+    nonce: H256,
+    /// An arbitrary string rather than an account ID, e.g. `myapp.com` or the app name.
+    recipient: &'a str,
+    callback_url: Option<&'a str>,
+}
+
+impl<'a> Payload<'a> {
+    fn new(
+        message: &'a str,
+        nonce: &[u8],
+        recipient: &'a str,
+        callback_url: &'a str,
+    ) -> SigningResult<Payload<'a>> {
+        let nonce = H256::try_from(nonce)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        if recipient.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        let callback_url = if callback_url.is_empty() {
+            None
+        } else {
+            Some(callback_url)
+        };
+        Ok(Payload {
+            message,
+            nonce,
+            recipient,
+            callback_url,
+        })
+    }
+
+    /// Returns the Borsh-encoded [`NEP413_PREFIX`] followed by the payload.
+    fn preimage(&self) -> SigningResult<Data> {
+        let mut preimage = borsh::encode(&NEP413_PREFIX)?;
+        preimage.extend_from_slice(&borsh::encode(self)?);
+        Ok(preimage)
+    }
+}
+
+/// Signs and verifies off-chain messages according to NEP-413.
+/// The `sha256` hash of the Borsh-encoded payload is signed with `ed25519`.
+#[derive(Default)]
+pub struct NearMessageSigner;
+
+impl MessageSigner for NearMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl NearMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let preimage = Payload::new(
+            &input.message,
+            &input.nonce,
+            &input.recipient,
+            &input.callback_url,
+        )?
+        .preimage()?;
+        let hash = sha256(&preimage);
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::Owned(preimage),
+            data_hash: Cow::Owned(hash),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let preimage = Payload::new(
+            &input.message,
+            &input.nonce,
+            &input.recipient,
+            &input.callback_url,
+        )?
+        .preimage()?;
+        let signature = key_pair.sign(sha256(&preimage))?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(signature.to_bytes().into_vec()),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())?;
+        let signature = ed25519::Signature::try_from(input.signature.as_ref())?;
+        let preimage = Payload::new(
+            &input.message,
+            &input.nonce,
+            &input.recipient,
+            &input.callback_url,
+        )?
+        .preimage()?;
+        Ok(public_key.verify(signature, sha256(&preimage)))
+    }
+}
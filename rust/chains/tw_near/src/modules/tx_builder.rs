@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::account_id::AccountId;
+use crate::transaction::action::{
+    AccessKey, AccessKeyPermission, Action, AddKey, DeleteAccount, DeleteKey, DeployContract,
+    FunctionCall, FunctionCallPermission, Stake, Transfer,
+};
+use crate::transaction::delegate_action::{DelegateAction, SignedDelegateAction};
+use crate::transaction::{PublicKey, Signature, Transaction, UnsignedTransaction};
+use serde::Serialize;
+use std::str::FromStr;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_hash::{H256, H512};
+use tw_keypair::ed25519;
+use tw_proto::NEAR::Proto;
+use tw_proto::NEAR::Proto::mod_AccessKey::OneOfpermission as ProtoPermission;
+use tw_proto::NEAR::Proto::mod_Action::OneOfpayload as ProtoActionType;
+
+/// NEP-141 fungible token transfer method.
+const FT_TRANSFER_METHOD_NAME: &str = "ft_transfer";
+
+/// Arguments of the [`FT_TRANSFER_METHOD_NAME`] method.
+#[derive(Serialize)]
+struct FtTransferArgs<'a> {
+    amount: &'a str,
+    receiver_id: &'a str,
+}
+
+pub struct TransactionBuilder;
+
+impl TransactionBuilder {
+    /// Builds either a transaction or a delegate action depending on the `input.delegate_action`.
+    /// `public_key` is the signer's public key.
+    pub fn build(
+        input: &Proto::SigningInput,
+        public_key: &ed25519::sha512::PublicKey,
+    ) -> SigningResult<UnsignedTransaction> {
+        let signer_id = AccountId::from_str(&input.signer_id)?;
+        let receiver_id = AccountId::from_str(&input.receiver_id)?;
+        let public_key = PublicKey::ed25519(public_key);
+        let actions = input
+            .actions
+            .iter()
+            .map(Self::action_from_proto)
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        if let Some(ref delegate_params) = input.delegate_action {
+            let delegate_action = DelegateAction {
+                sender_id: signer_id,
+                receiver_id,
+                actions: Self::check_non_delegate_actions(actions)?,
+                nonce: input.nonce,
+                max_block_height: delegate_params.max_block_height,
+                public_key,
+            };
+            return Ok(UnsignedTransaction::DelegateAction(delegate_action));
+        }
+
+        let block_hash = H256::try_from(input.block_hash.as_ref())
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        Ok(UnsignedTransaction::Transaction(Transaction {
+            signer_id,
+            public_key,
+            nonce: input.nonce,
+            receiver_id,
+            block_hash,
+            actions,
+        }))
+    }
+
+    fn action_from_proto(action: &Proto::Action) -> SigningResult<Action> {
+        match action.payload {
+            ProtoActionType::create_account(_) => Ok(Action::CreateAccount),
+            ProtoActionType::deploy_contract(ref deploy) => {
+                Ok(Action::DeployContract(DeployContract {
+                    code: deploy.code.to_vec(),
+                }))
+            },
+            ProtoActionType::function_call(ref call) => Ok(Action::FunctionCall(FunctionCall {
+                method_name: call.method_name.to_string(),
+                args: call.args.to_vec(),
+                gas: call.gas,
+                deposit: u128_from_le_bytes(&call.deposit)?,
+            })),
+            ProtoActionType::transfer(ref transfer) => Ok(Action::Transfer(Transfer {
+                deposit: u128_from_le_bytes(&transfer.deposit)?,
+            })),
+            ProtoActionType::stake(ref stake) => Ok(Action::Stake(Stake {
+                stake: u128_from_le_bytes(&stake.stake)?,
+                public_key: public_key_from_proto(stake.public_key.as_ref())?,
+            })),
+            ProtoActionType::add_key(ref add_key) => Ok(Action::AddKey(AddKey {
+                public_key: public_key_from_proto(add_key.public_key.as_ref())?,
+                access_key: Self::access_key_from_proto(add_key.access_key.as_ref())?,
+            })),
+            ProtoActionType::delete_key(ref delete_key) => Ok(Action::DeleteKey(DeleteKey {
+                public_key: public_key_from_proto(delete_key.public_key.as_ref())?,
+            })),
+            ProtoActionType::delete_account(ref delete_account) => {
+                Ok(Action::DeleteAccount(DeleteAccount {
+                    beneficiary_id: AccountId::from_str(&delete_account.beneficiary_id)?,
+                }))
+            },
+            ProtoActionType::delegate(ref signed) => {
+                Ok(Action::Delegate(Self::signed_delegate_from_proto(signed)?))
+            },
+            ProtoActionType::token_transfer(ref token_transfer) => {
+                Self::token_transfer_from_proto(token_transfer)
+            },
+            ProtoActionType::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
+        }
+    }
+
+    fn access_key_from_proto(access_key: Option<&Proto::AccessKey>) -> SigningResult<AccessKey> {
+        let access_key = access_key.ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+
+        let permission = match access_key.permission {
+            ProtoPermission::function_call(ref permission) => {
+                let allowance = if permission.allowance.is_empty() {
+                    None
+                } else {
+                    Some(u128_from_le_bytes(&permission.allowance)?)
+                };
+                AccessKeyPermission::FunctionCall(FunctionCallPermission {
+                    allowance,
+                    receiver_id: AccountId::from_str(&permission.receiver_id)?,
+                    method_names: permission
+                        .method_names
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect(),
+                })
+            },
+            ProtoPermission::full_access(_) => AccessKeyPermission::FullAccess,
+            ProtoPermission::None => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params))
+            },
+        };
+
+        Ok(AccessKey {
+            nonce: access_key.nonce,
+            permission,
+        })
+    }
+
+    /// Converts a NEP-141 token transfer into the `ft_transfer` function call.
+    fn token_transfer_from_proto(transfer: &Proto::TokenTransfer) -> SigningResult<Action> {
+        // Token amount is `U128` passed as a decimal string.
+        u128::from_str(&transfer.token_amount)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        let receiver_id = AccountId::from_str(&transfer.receiver_id)?;
+
+        let args = serde_json::to_vec(&FtTransferArgs {
+            amount: &transfer.token_amount,
+            receiver_id: receiver_id.as_str(),
+        })
+        .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+
+        Ok(Action::FunctionCall(FunctionCall {
+            method_name: FT_TRANSFER_METHOD_NAME.to_string(),
+            args,
+            gas: transfer.gas,
+            deposit: u128_from_le_bytes(&transfer.deposit)?,
+        }))
+    }
+
+    fn signed_delegate_from_proto(
+        signed: &Proto::SignedDelegateAction,
+    ) -> SigningResult<SignedDelegateAction> {
+        let delegate = signed
+            .delegate_action
+            .as_ref()
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+
+        let actions = delegate
+            .actions
+            .iter()
+            .map(Self::action_from_proto)
+            .collect::<SigningResult<Vec<_>>>()?;
+        let signature = H512::try_from(signed.signature.as_ref())
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+
+        Ok(SignedDelegateAction {
+            delegate_action: DelegateAction {
+                sender_id: AccountId::from_str(&delegate.sender_id)?,
+                receiver_id: AccountId::from_str(&delegate.receiver_id)?,
+                actions: Self::check_non_delegate_actions(actions)?,
+                nonce: delegate.nonce,
+                max_block_height: delegate.max_block_height,
+                public_key: public_key_from_proto(delegate.public_key.as_ref())?,
+            },
+            signature: Signature::Ed25519(signature),
+        })
+    }
+
+    /// Delegate actions cannot be nested.
+    fn check_non_delegate_actions(actions: Vec<Action>) -> SigningResult<Vec<Action>> {
+        if actions
+            .iter()
+            .any(|action| matches!(action, Action::Delegate(_)))
+        {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        Ok(actions)
+    }
+}
+
+fn public_key_from_proto(public_key: Option<&Proto::PublicKey>) -> SigningResult<PublicKey> {
+    let public_key = public_key.ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+    if public_key.key_type != PublicKey::ED25519_KEY_TYPE {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    }
+    let public_key = ed25519::sha512::PublicKey::try_from(public_key.data.as_ref())?;
+    Ok(PublicKey::ed25519(&public_key))
+}
+
+/// Parses a little-endian `uint128`. Empty bytes are considered as zero.
+fn u128_from_le_bytes(bytes: &[u8]) -> SigningResult<u128> {
+    if bytes.len() > 16 {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    }
+    let mut le_bytes = [0_u8; 16];
+    le_bytes[..bytes.len()].copy_from_slice(bytes);
+    Ok(u128::from_le_bytes(le_bytes))
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::ser::{SerializeTuple, Serializer};
+use tw_hash::Hash;
+
+/// Serializes the hash as a fixed size byte array (without a length prefix),
+/// as opposed to the default hex string representation.
+pub fn serialize<S, const N: usize>(data: &Hash<N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for byte in data.as_slice() {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_builder::TransactionBuilder;
+use crate::transaction::UnsignedTransaction;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_proto::NEAR::Proto;
+
+pub struct NearSigner;
+
+impl NearSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let unsigned_tx = TransactionBuilder::build(&input, key_pair.public())?;
+
+        let hash = unsigned_tx.hash()?;
+        let signature = key_pair.sign(hash.to_vec())?;
+
+        Self::output_from_signed(unsigned_tx, &signature)
+    }
+
+    pub(crate) fn output_from_signed(
+        unsigned_tx: UnsignedTransaction,
+        signature: &ed25519::Signature,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let hash = unsigned_tx.hash()?;
+        let signed_transaction = unsigned_tx.into_signed_bytes(signature)?;
+        Ok(Proto::SigningOutput {
+            signed_transaction: Cow::from(signed_transaction),
+            hash: Cow::from(hash.to_vec()),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
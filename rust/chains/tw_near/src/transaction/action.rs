@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::account_id::AccountId;
+use crate::transaction::delegate_action::SignedDelegateAction;
+use crate::transaction::PublicKey;
+use serde::Serialize;
+use tw_memory::Data;
+
+/// Transaction action. The order of the variants defines their Borsh indexes.
+#[derive(Clone, Debug, Serialize)]
+pub enum Action {
+    CreateAccount,
+    DeployContract(DeployContract),
+    FunctionCall(FunctionCall),
+    Transfer(Transfer),
+    Stake(Stake),
+    AddKey(AddKey),
+    DeleteKey(DeleteKey),
+    DeleteAccount(DeleteAccount),
+    Delegate(SignedDelegateAction),
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DeployContract {
+    pub code: Data,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FunctionCall {
+    pub method_name: String,
+    pub args: Data,
+    pub gas: u64,
+    pub deposit: u128,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Transfer {
+    pub deposit: u128,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Stake {
+    pub stake: u128,
+    pub public_key: PublicKey,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AddKey {
+    pub public_key: PublicKey,
+    pub access_key: AccessKey,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DeleteKey {
+    pub public_key: PublicKey,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DeleteAccount {
+    pub beneficiary_id: AccountId,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AccessKey {
+    pub nonce: u64,
+    pub permission: AccessKeyPermission,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub enum AccessKeyPermission {
+    FunctionCall(FunctionCallPermission),
+    FullAccess,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FunctionCallPermission {
+    /// `None` means unlimited allowance.
+    pub allowance: Option<u128>,
+    pub receiver_id: AccountId,
+    pub method_names: Vec<String>,
+}
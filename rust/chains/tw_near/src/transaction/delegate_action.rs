@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::account_id::AccountId;
+use crate::transaction::action::Action;
+use crate::transaction::{PublicKey, Signature};
+use serde::Serialize;
+use tw_encoding::{borsh, EncodingResult};
+use tw_memory::Data;
+
+/// The prefix that distinguishes a signed delegate action from a signed transaction.
+/// https://github.com/near/NEPs/blob/master/neps/nep-0461.md
+pub const DELEGATE_ACTION_PREFIX: u32 = (1 << 30) + 366;
+
+/// A set of actions to be relayed on behalf of the `sender_id`.
+/// https://github.com/near/NEPs/blob/master/neps/nep-0366.md
+#[derive(Clone, Debug, Serialize)]
+pub struct DelegateAction {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    /// Must not contain nested [`Action::Delegate`] actions.
+    pub actions: Vec<Action>,
+    pub nonce: u64,
+    pub max_block_height: u64,
+    pub public_key: PublicKey,
+}
+
+impl DelegateAction {
+    /// Returns the Borsh-encoded [`DELEGATE_ACTION_PREFIX`] followed by the delegate action.
+    pub fn preimage(&self) -> EncodingResult<Data> {
+        let mut preimage = borsh::encode(&DELEGATE_ACTION_PREFIX)?;
+        preimage.extend_from_slice(&borsh::encode(self)?);
+        Ok(preimage)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SignedDelegateAction {
+    pub delegate_action: DelegateAction,
+    pub signature: Signature,
+}
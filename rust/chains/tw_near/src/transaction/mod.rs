@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::account_id::AccountId;
+use crate::serde_helper::fixed_bytes;
+use crate::transaction::action::Action;
+use crate::transaction::delegate_action::{DelegateAction, SignedDelegateAction};
+use serde::Serialize;
+use tw_encoding::{borsh, EncodingResult};
+use tw_hash::sha2::sha256;
+use tw_hash::{H256, H512};
+use tw_keypair::ed25519;
+use tw_memory::Data;
+
+pub mod action;
+pub mod delegate_action;
+
+#[derive(Clone, Debug, Serialize)]
+pub enum PublicKey {
+    Ed25519(#[serde(serialize_with = "fixed_bytes::serialize")] H256),
+}
+
+impl PublicKey {
+    /// The only supported key type.
+    pub const ED25519_KEY_TYPE: u32 = 0;
+
+    pub fn ed25519(public_key: &ed25519::sha512::PublicKey) -> PublicKey {
+        PublicKey::Ed25519(public_key.to_bytes())
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub enum Signature {
+    Ed25519(#[serde(serialize_with = "fixed_bytes::serialize")] H512),
+}
+
+impl Signature {
+    pub fn ed25519(signature: &ed25519::Signature) -> Signature {
+        Signature::Ed25519(signature.to_bytes())
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Transaction {
+    pub signer_id: AccountId,
+    pub public_key: PublicKey,
+    pub nonce: u64,
+    pub receiver_id: AccountId,
+    #[serde(serialize_with = "fixed_bytes::serialize")]
+    pub block_hash: H256,
+    pub actions: Vec<Action>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SignedTransaction {
+    pub transaction: Transaction,
+    pub signature: Signature,
+}
+
+/// Either a transaction or a NEP-366 delegate action which hash is signed by the sender.
+pub enum UnsignedTransaction {
+    Transaction(Transaction),
+    DelegateAction(DelegateAction),
+}
+
+impl UnsignedTransaction {
+    /// Returns the Borsh-encoded data which hash is signed.
+    pub fn preimage(&self) -> EncodingResult<Data> {
+        match self {
+            UnsignedTransaction::Transaction(tx) => borsh::encode(tx),
+            UnsignedTransaction::DelegateAction(delegate_action) => delegate_action.preimage(),
+        }
+    }
+
+    /// Returns the `sha256` hash of the [`UnsignedTransaction::preimage`].
+    /// In case of a transaction, it's also the transaction hash.
+    pub fn hash(&self) -> EncodingResult<H256> {
+        let preimage = self.preimage()?;
+        Ok(H256::try_from(sha256(&preimage).as_slice()).expect("sha256 must return 32 bytes"))
+    }
+
+    /// Returns the Borsh-encoded signed transaction or signed delegate action.
+    pub fn into_signed_bytes(self, signature: &ed25519::Signature) -> EncodingResult<Data> {
+        let signature = Signature::ed25519(signature);
+        match self {
+            UnsignedTransaction::Transaction(transaction) => borsh::encode(&SignedTransaction {
+                transaction,
+                signature,
+            }),
+            UnsignedTransaction::DelegateAction(delegate_action) => {
+                borsh::encode(&SignedDelegateAction {
+                    delegate_action,
+                    signature,
+                })
+            },
+        }
+    }
+}
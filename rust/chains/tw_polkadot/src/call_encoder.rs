@@ -0,0 +1,396 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::extrinsic::AccountId;
+use crate::network;
+use std::borrow::Cow;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::scale::{Compact, RawOwned, ToScale};
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Polkadot::Proto;
+use tw_ss58_address::SS58Address;
+
+use Proto::mod_Balance::OneOfmessage_oneof as BalanceVariant;
+use Proto::mod_CallIndices::OneOfvariant as CallIndicesVariant;
+use Proto::mod_Identity::OneOfmessage_oneof as IdentityVariant;
+use Proto::mod_PolymeshCall::OneOfmessage_oneof as PolymeshVariant;
+use Proto::mod_SigningInput::OneOfmessage_oneof as SigningVariant;
+use Proto::mod_Staking::OneOfmessage_oneof as StakingVariant;
+
+const MEMO_LEN: usize = 32;
+
+/// Module and method indices of a call.
+pub type CallIndex = [u8; 2];
+
+/// Calls that are known to the encoder.
+#[derive(Clone, Copy)]
+enum Call {
+    BalancesTransfer,
+    UtilityBatchAll,
+    StakingBond,
+    StakingBondExtra,
+    StakingUnbond,
+    StakingWithdrawUnbonded,
+    StakingNominate,
+    StakingChill,
+    StakingRebond,
+    // Non-existent modules and methods on Polkadot and Kusama chains.
+    AssetsTransfer,
+    IdentityJoinIdentityAsKey,
+    IdentityAddAuthorization,
+}
+
+impl Call {
+    /// Readable decoded call indices can be found at https://www.subscan.io/
+    fn polkadot_index(self) -> Option<CallIndex> {
+        let index = match self {
+            Call::BalancesTransfer => [0x05, 0x00],
+            Call::StakingBond => [0x07, 0x00],
+            Call::StakingBondExtra => [0x07, 0x01],
+            Call::StakingUnbond => [0x07, 0x02],
+            Call::StakingWithdrawUnbonded => [0x07, 0x03],
+            Call::StakingNominate => [0x07, 0x05],
+            Call::StakingChill => [0x07, 0x06],
+            Call::UtilityBatchAll => [0x1a, 0x02],
+            Call::StakingRebond => [0x07, 0x13],
+            Call::AssetsTransfer
+            | Call::IdentityJoinIdentityAsKey
+            | Call::IdentityAddAuthorization => return None,
+        };
+        Some(index)
+    }
+
+    fn kusama_index(self) -> Option<CallIndex> {
+        let index = match self {
+            Call::BalancesTransfer => [0x04, 0x00],
+            Call::StakingBond => [0x06, 0x00],
+            Call::StakingBondExtra => [0x06, 0x01],
+            Call::StakingUnbond => [0x06, 0x02],
+            Call::StakingWithdrawUnbonded => [0x06, 0x03],
+            Call::StakingNominate => [0x06, 0x05],
+            Call::StakingChill => [0x06, 0x06],
+            Call::UtilityBatchAll => [0x18, 0x02],
+            Call::StakingRebond => [0x06, 0x13],
+            Call::AssetsTransfer
+            | Call::IdentityJoinIdentityAsKey
+            | Call::IdentityAddAuthorization => return None,
+        };
+        Some(index)
+    }
+}
+
+/// Encodes the call described by `SigningInput::message_oneof`.
+pub struct CallEncoder {
+    network: u16,
+    /// Whether accounts are encoded as `MultiAddress::Id` rather than raw `AccountId`.
+    multi_address: bool,
+}
+
+impl CallEncoder {
+    pub fn from_input(input: &Proto::SigningInput<'_>) -> SigningResult<CallEncoder> {
+        let network = u16::try_from(input.network)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        let multi_address = input.multi_address
+            || (network == network::POLKADOT
+                && input.spec_version >= network::POLKADOT_MULTI_ADDRESS_SPEC)
+            || (network == network::KUSAMA
+                && input.spec_version >= network::KUSAMA_MULTI_ADDRESS_SPEC);
+        Ok(CallEncoder {
+            network,
+            multi_address,
+        })
+    }
+
+    pub fn network(&self) -> u16 {
+        self.network
+    }
+
+    pub fn multi_address(&self) -> bool {
+        self.multi_address
+    }
+
+    pub fn encode_call(&self, input: &Proto::SigningInput<'_>) -> SigningResult<Data> {
+        match input.message_oneof {
+            SigningVariant::balance_call(ref balance) => self.encode_balance_call(balance),
+            SigningVariant::staking_call(ref staking) => self.encode_staking_call(staking),
+            SigningVariant::polymesh_call(ref polymesh) => self.encode_polymesh_call(polymesh),
+            SigningVariant::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
+        }
+    }
+
+    fn encode_balance_call(&self, balance: &Proto::Balance<'_>) -> SigningResult<Data> {
+        match balance.message_oneof {
+            BalanceVariant::transfer(ref transfer) => self.encode_transfer(transfer),
+            BalanceVariant::batchTransfer(ref batch) => {
+                let calls = batch
+                    .transfers
+                    .iter()
+                    .map(|transfer| self.encode_transfer(transfer))
+                    .collect::<SigningResult<Vec<_>>>()?;
+                self.encode_batch(&batch.call_indices, calls)
+            },
+            BalanceVariant::asset_transfer(ref transfer) => self.encode_asset_transfer(transfer),
+            BalanceVariant::batch_asset_transfer(ref batch) => {
+                let calls = batch
+                    .transfers
+                    .iter()
+                    .map(|transfer| self.encode_asset_transfer(transfer))
+                    .collect::<SigningResult<Vec<_>>>()?;
+                self.encode_batch(&batch.call_indices, calls)
+            },
+            BalanceVariant::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
+        }
+    }
+
+    fn encode_transfer(&self, transfer: &Proto::mod_Balance::Transfer<'_>) -> SigningResult<Data> {
+        let call_index = self.call_index(&transfer.call_indices, Call::BalancesTransfer)?;
+        let destination = self.account_id(&transfer.to_address)?;
+        let value = parse_amount(&transfer.value)?;
+
+        let mut call = (call_index, destination, Compact(value)).to_scale();
+        if !transfer.memo.is_empty() {
+            if transfer.memo.len() > MEMO_LEN {
+                return Err(SigningError(SigningErrorType::Error_invalid_memo));
+            }
+            // The memo is padded with zeros up to 32 bytes.
+            let mut memo = [0_u8; MEMO_LEN];
+            memo[..transfer.memo.len()].copy_from_slice(transfer.memo.as_bytes());
+            Some(memo).to_scale_into(&mut call);
+        }
+        Ok(call)
+    }
+
+    fn encode_asset_transfer(
+        &self,
+        transfer: &Proto::mod_Balance::AssetTransfer<'_>,
+    ) -> SigningResult<Data> {
+        let call_index = self.call_index(&transfer.call_indices, Call::AssetsTransfer)?;
+        let destination = self.account_id(&transfer.to_address)?;
+        let value = parse_amount(&transfer.value)?;
+
+        let mut call = call_index.to_scale();
+        // Asset ID is omitted for the native token transfer.
+        if transfer.asset_id > 0 {
+            Compact(transfer.asset_id).to_scale_into(&mut call);
+        }
+        (destination, Compact(value)).to_scale_into(&mut call);
+        Ok(call)
+    }
+
+    fn encode_batch(
+        &self,
+        call_indices: &Option<Proto::CallIndices>,
+        calls: Vec<Data>,
+    ) -> SigningResult<Data> {
+        let call_index = self.call_index(call_indices, Call::UtilityBatchAll)?;
+        let calls: Vec<_> = calls.into_iter().map(RawOwned).collect();
+        Ok((call_index, calls).to_scale())
+    }
+
+    fn encode_staking_call(&self, staking: &Proto::Staking<'_>) -> SigningResult<Data> {
+        match staking.message_oneof {
+            StakingVariant::bond(ref bond) => self.encode_bond(
+                &bond.call_indices,
+                &bond.controller,
+                &bond.value,
+                bond.reward_destination,
+            ),
+            StakingVariant::bond_and_nominate(ref bond_and_nominate) => {
+                let bond = self.encode_bond(
+                    &None,
+                    &bond_and_nominate.controller,
+                    &bond_and_nominate.value,
+                    bond_and_nominate.reward_destination,
+                )?;
+                let nominate = self.encode_nominate(&None, &bond_and_nominate.nominators)?;
+                self.encode_batch(&bond_and_nominate.call_indices, vec![bond, nominate])
+            },
+            StakingVariant::bond_extra(ref bond_extra) => self.encode_value_call(
+                &bond_extra.call_indices,
+                Call::StakingBondExtra,
+                &bond_extra.value,
+            ),
+            StakingVariant::unbond(ref unbond) => {
+                self.encode_value_call(&unbond.call_indices, Call::StakingUnbond, &unbond.value)
+            },
+            StakingVariant::rebond(ref rebond) => {
+                self.encode_value_call(&rebond.call_indices, Call::StakingRebond, &rebond.value)
+            },
+            StakingVariant::withdraw_unbonded(ref withdraw) => {
+                let call_index =
+                    self.call_index(&withdraw.call_indices, Call::StakingWithdrawUnbonded)?;
+                // `num_slashing_spans` is encoded as a fixed-width `u32`.
+                Ok((call_index, withdraw.slashing_spans as u32).to_scale())
+            },
+            StakingVariant::nominate(ref nominate) => {
+                self.encode_nominate(&nominate.call_indices, &nominate.nominators)
+            },
+            StakingVariant::chill(ref chill) => {
+                let call_index = self.call_index(&chill.call_indices, Call::StakingChill)?;
+                Ok(call_index.to_scale())
+            },
+            StakingVariant::chill_and_unbond(ref chill_and_unbond) => {
+                let chill_index = self.call_index(&None, Call::StakingChill)?;
+                let unbond =
+                    self.encode_value_call(&None, Call::StakingUnbond, &chill_and_unbond.value)?;
+                self.encode_batch(
+                    &chill_and_unbond.call_indices,
+                    vec![chill_index.to_vec(), unbond],
+                )
+            },
+            StakingVariant::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
+        }
+    }
+
+    fn encode_bond(
+        &self,
+        call_indices: &Option<Proto::CallIndices>,
+        controller: &str,
+        value: &[u8],
+        reward_destination: Proto::RewardDestination,
+    ) -> SigningResult<Data> {
+        let call_index = self.call_index(call_indices, Call::StakingBond)?;
+        let value = parse_amount(value)?;
+
+        let mut call = call_index.to_scale();
+        // The controller is optional.
+        if !controller.is_empty() {
+            self.account_id(controller)?.to_scale_into(&mut call);
+        }
+        (Compact(value), reward_destination as u8).to_scale_into(&mut call);
+        Ok(call)
+    }
+
+    fn encode_nominate(
+        &self,
+        call_indices: &Option<Proto::CallIndices>,
+        nominators: &[Cow<'_, str>],
+    ) -> SigningResult<Data> {
+        let call_index = self.call_index(call_indices, Call::StakingNominate)?;
+        let targets = nominators
+            .iter()
+            .map(|nominator| self.account_id(nominator))
+            .collect::<SigningResult<Vec<_>>>()?;
+        Ok((call_index, targets).to_scale())
+    }
+
+    fn encode_value_call(
+        &self,
+        call_indices: &Option<Proto::CallIndices>,
+        call: Call,
+        value: &[u8],
+    ) -> SigningResult<Data> {
+        let call_index = self.call_index(call_indices, call)?;
+        let value = parse_amount(value)?;
+        Ok((call_index, Compact(value)).to_scale())
+    }
+
+    fn encode_polymesh_call(&self, polymesh: &Proto::PolymeshCall<'_>) -> SigningResult<Data> {
+        let identity = match polymesh.message_oneof {
+            PolymeshVariant::identity_call(ref identity) => identity,
+            PolymeshVariant::None => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params))
+            },
+        };
+
+        match identity.message_oneof {
+            IdentityVariant::join_identity_as_key(ref join) => {
+                let call_index =
+                    self.call_index(&join.call_indices, Call::IdentityJoinIdentityAsKey)?;
+                Ok((call_index, join.auth_id).to_scale())
+            },
+            IdentityVariant::add_authorization(ref add_authorization) => {
+                self.encode_add_authorization(add_authorization)
+            },
+            IdentityVariant::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
+        }
+    }
+
+    fn encode_add_authorization(
+        &self,
+        add_authorization: &Proto::mod_Identity::AddAuthorization<'_>,
+    ) -> SigningResult<Data> {
+        /// `Signatory::Account` variant index.
+        const SIGNATORY_ACCOUNT: u8 = 0x01;
+        /// `AuthorizationData::JoinIdentity` variant index.
+        const JOIN_IDENTITY: u8 = 0x05;
+
+        let call_index = self.call_index(
+            &add_authorization.call_indices,
+            Call::IdentityAddAuthorization,
+        )?;
+        let target = SS58Address::from_str_checked(&add_authorization.target, self.network)?;
+
+        let mut call = (call_index, SIGNATORY_ACCOUNT).to_scale();
+        // The target is always encoded as a raw `AccountId`.
+        call.extend_from_slice(target.key().as_slice());
+        call.push(JOIN_IDENTITY);
+
+        match add_authorization.data {
+            Some(ref auth_data) => {
+                (
+                    permission(&auth_data.asset),
+                    permission(&auth_data.extrinsic),
+                    permission(&auth_data.portfolio),
+                )
+                    .to_scale_into(&mut call);
+            },
+            None => {
+                // Authorize all permissions: asset, extrinsic and portfolio.
+                let all = Some(RawOwned(vec![0x00]));
+                (all.clone(), all.clone(), all).to_scale_into(&mut call);
+            },
+        }
+
+        Compact(add_authorization.expiry).to_scale_into(&mut call);
+        Ok(call)
+    }
+
+    fn account_id(&self, address: &str) -> SigningResult<AccountId> {
+        let address = SS58Address::from_str_checked(address, self.network)?;
+        Ok(AccountId {
+            key: address.key(),
+            multi_address: self.multi_address,
+        })
+    }
+
+    fn call_index(
+        &self,
+        call_indices: &Option<Proto::CallIndices>,
+        call: Call,
+    ) -> SigningResult<CallIndex> {
+        if let Some(Proto::CallIndices {
+            variant: CallIndicesVariant::custom(ref custom),
+        }) = call_indices
+        {
+            let module_index = u8::try_from(custom.module_index)
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+            let method_index = u8::try_from(custom.method_index)
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+            return Ok([module_index, method_index]);
+        }
+
+        let default_index = match self.network {
+            network::POLKADOT => call.polkadot_index(),
+            network::KUSAMA => call.kusama_index(),
+            _ => None,
+        };
+        // Call indices must be set explicitly for other networks.
+        default_index.ok_or(SigningError(SigningErrorType::Error_not_supported))
+    }
+}
+
+/// An authorization permission: `None` means no permissions, otherwise the encoded permission data.
+fn permission(
+    data: &Option<Proto::mod_Identity::mod_AddAuthorization::Data<'_>>,
+) -> Option<RawOwned> {
+    data.as_ref().map(|data| RawOwned(data.data.to_vec()))
+}
+
+/// Parses a big-endian encoded amount.
+pub fn parse_amount(value: &[u8]) -> SigningResult<u128> {
+    let value = U256::from_big_endian_slice(value)?;
+    Ok(u128::try_from(value)?)
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::extrinsic::MultiSignature;
+use crate::tx_builder::TxBuilder;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_hash::{H256, H512};
+use tw_proto::Polkadot::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct PolkadotCompiler;
+
+impl PolkadotCompiler {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let unsigned_tx = TxBuilder::unsigned_tx_from_proto(&input)?;
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(unsigned_tx.encode_payload()),
+            data_hash: Cow::from(unsigned_tx.payload_to_sign()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn compile_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let SingleSignaturePubkey {
+            signature,
+            public_key,
+        } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
+
+        let signer = H256::try_from(public_key.as_slice())
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        let signature = H512::try_from(signature.as_slice())
+            .map_err(|_| SigningError(SigningErrorType::Error_signing))?;
+        let signature = match input.key_type {
+            Proto::KeyType::ED25519 => MultiSignature::Ed25519(signature),
+            Proto::KeyType::SR25519 => MultiSignature::Sr25519(signature),
+        };
+
+        let unsigned_tx = TxBuilder::unsigned_tx_from_proto(&input)?;
+        let encoded = unsigned_tx.encode_signed(signer, signature);
+        Ok(Proto::SigningOutput {
+            encoded: Cow::from(encoded),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
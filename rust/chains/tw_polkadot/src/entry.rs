@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::compiler::PolkadotCompiler;
+use crate::signer::PolkadotSigner;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::AddressResult;
+use tw_coin_entry::modules::json_signer::NoJsonSigner;
+use tw_coin_entry::modules::message_signer::NoMessageSigner;
+use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
+use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
+use tw_keypair::tw::PublicKey;
+use tw_proto::Polkadot::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_ss58_address::ss58_prefix::SS58Prefix;
+use tw_ss58_address::SS58Address;
+
+/// Polkadot, Kusama and other Substrate-based chains that use the SS58 address format.
+pub struct PolkadotEntry;
+
+impl CoinEntry for PolkadotEntry {
+    type AddressPrefix = SS58Prefix;
+    type Address = SS58Address;
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+    type SigningOutput = Proto::SigningOutput<'static>;
+    type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+
+    // Optional modules:
+    type JsonSigner = NoJsonSigner;
+    type PlanBuilder = NoPlanBuilder;
+    type MessageSigner = NoMessageSigner;
+    type WalletConnector = NoWalletConnector;
+
+    #[inline]
+    fn parse_address(
+        &self,
+        coin: &dyn CoinContext,
+        address: &str,
+        prefix: Option<Self::AddressPrefix>,
+    ) -> AddressResult<Self::Address> {
+        SS58Address::from_str_with_coin_and_prefix(coin, address, prefix)
+    }
+
+    #[inline]
+    fn parse_address_unchecked(
+        &self,
+        _coin: &dyn CoinContext,
+        address: &str,
+    ) -> AddressResult<Self::Address> {
+        SS58Address::from_str(address)
+    }
+
+    #[inline]
+    fn derive_address(
+        &self,
+        coin: &dyn CoinContext,
+        public_key: PublicKey,
+        _derivation: Derivation,
+        prefix: Option<Self::AddressPrefix>,
+    ) -> AddressResult<Self::Address> {
+        SS58Address::with_public_key_coin_context(coin, &public_key, prefix)
+    }
+
+    #[inline]
+    fn sign(&self, coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::SigningOutput {
+        PolkadotSigner::sign(coin, input)
+    }
+
+    #[inline]
+    fn preimage_hashes(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+    ) -> Self::PreSigningOutput {
+        PolkadotCompiler::preimage_hashes(coin, input)
+    }
+
+    #[inline]
+    fn compile(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Self::SigningOutput {
+        PolkadotCompiler::compile(coin, input, signatures, public_keys)
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Signed extensions that are appended to the extrinsic ("extra")
+//! and to the signing payload only ("additional signed").
+//! https://docs.substrate.io/reference/transaction-format/#signed-extensions
+
+use tw_encoding::scale::{Compact, RawOwned, ToScale};
+use tw_hash::H256;
+use tw_memory::Data;
+
+const MIN_PERIOD: u64 = 4;
+const MAX_PERIOD: u64 = 1 << 16;
+
+/// The period of validity of a transaction.
+/// https://github.com/paritytech/polkadot-sdk/blob/master/substrate/primitives/runtime/src/generic/era.rs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Era {
+    Immortal,
+    Mortal { period: u64, phase: u64 },
+}
+
+impl Era {
+    /// Creates a mortal era that starts at the given `block` number
+    /// and is valid for `period` blocks rounded up to the next power of two.
+    pub fn mortal(block: u64, period: u64) -> Era {
+        let period = period
+            .checked_next_power_of_two()
+            .unwrap_or(MAX_PERIOD)
+            .clamp(MIN_PERIOD, MAX_PERIOD);
+        let phase = block % period;
+        let quantize_factor = (period >> 12).max(1);
+        let quantized_phase = phase / quantize_factor * quantize_factor;
+        Era::Mortal {
+            period,
+            phase: quantized_phase,
+        }
+    }
+}
+
+impl ToScale for Era {
+    fn to_scale_into(&self, out: &mut Data) {
+        match self {
+            Era::Immortal => out.push(0x00),
+            Era::Mortal { period, phase } => {
+                let quantize_factor = (*period >> 12).max(1);
+                let encoded_period = (period.trailing_zeros() - 1).clamp(1, 15) as u16;
+                let encoded_phase = ((phase / quantize_factor) << 4) as u16;
+                (encoded_period | encoded_phase).to_scale_into(out);
+            },
+        }
+    }
+}
+
+/// `ChargeAssetTxPayment` signed extension: a tip and an optional asset to pay the fee with.
+/// If the extension is not used, only the tip is encoded (`ChargeTransactionPayment`).
+/// https://github.com/paritytech/polkadot-sdk/blob/master/substrate/frame/transaction-payment/asset-tx-payment/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChargeAssetTxPayment {
+    /// The chain doesn't use the extension.
+    Disabled,
+    /// The fee is paid in the native token if the asset is `None`.
+    Enabled { asset_id: Option<u32> },
+}
+
+/// `CheckMetadataHash` signed extension.
+/// https://github.com/polkadot-fellows/RFCs/blob/main/text/0078-merkleized-metadata.md
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CheckMetadataHash {
+    Disabled,
+    Enabled(H256),
+}
+
+impl CheckMetadataHash {
+    fn mode(&self) -> u8 {
+        match self {
+            CheckMetadataHash::Disabled => 0,
+            CheckMetadataHash::Enabled(_) => 1,
+        }
+    }
+
+    fn additional_signed(&self) -> Option<RawOwned> {
+        match self {
+            CheckMetadataHash::Disabled => None,
+            CheckMetadataHash::Enabled(hash) => Some(RawOwned(hash.to_vec())),
+        }
+    }
+}
+
+/// Signed extensions data that is included into the extrinsic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignedExtra {
+    pub era: Era,
+    pub nonce: u64,
+    pub tip: u128,
+    pub charge_asset_tx_payment: ChargeAssetTxPayment,
+    pub check_metadata_hash: Option<CheckMetadataHash>,
+}
+
+impl ToScale for SignedExtra {
+    fn to_scale_into(&self, out: &mut Data) {
+        self.era.to_scale_into(out);
+        Compact(self.nonce).to_scale_into(out);
+        Compact(self.tip).to_scale_into(out);
+        if let ChargeAssetTxPayment::Enabled { asset_id } = self.charge_asset_tx_payment {
+            asset_id.to_scale_into(out);
+        }
+        if let Some(check_metadata_hash) = self.check_metadata_hash {
+            check_metadata_hash.mode().to_scale_into(out);
+        }
+    }
+}
+
+/// Signed extensions data that is not included into the extrinsic, but is signed along with it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdditionalSigned {
+    pub spec_version: u32,
+    pub transaction_version: u32,
+    pub genesis_hash: H256,
+    /// The block hash the `Era` starts at, or the genesis hash if the era is immortal.
+    pub block_hash: H256,
+    pub check_metadata_hash: Option<CheckMetadataHash>,
+}
+
+impl ToScale for AdditionalSigned {
+    fn to_scale_into(&self, out: &mut Data) {
+        self.spec_version.to_scale_into(out);
+        self.transaction_version.to_scale_into(out);
+        out.extend_from_slice(self.genesis_hash.as_slice());
+        out.extend_from_slice(self.block_hash.as_slice());
+        if let Some(check_metadata_hash) = self.check_metadata_hash {
+            check_metadata_hash.additional_signed().to_scale_into(out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_encode_era() {
+        assert_eq!(Era::Immortal.to_scale().to_hex(), "00");
+        assert_eq!(Era::mortal(3541050, 64).to_scale().to_hex(), "a503");
+        assert_eq!(Era::mortal(5898150, 10000).to_scale().to_hex(), "9dfe");
+        assert_eq!(Era::mortal(3893613, 64).to_scale().to_hex(), "d502");
+        assert_eq!(Era::mortal(8, 4).to_scale().to_hex(), "0100");
+        assert_eq!(Era::mortal(1000000, 1 << 20).to_scale().to_hex(), "4f42");
+    }
+
+    #[test]
+    fn test_encode_check_metadata_hash() {
+        let hash = H256::from("0101010101010101010101010101010101010101010101010101010101010101");
+        let extra = SignedExtra {
+            era: Era::Immortal,
+            nonce: 1,
+            tip: 0,
+            charge_asset_tx_payment: ChargeAssetTxPayment::Enabled { asset_id: None },
+            check_metadata_hash: Some(CheckMetadataHash::Enabled(hash)),
+        };
+        assert_eq!(extra.to_scale().to_hex(), "0004000001");
+
+        assert_eq!(
+            CheckMetadataHash::Disabled
+                .additional_signed()
+                .to_scale()
+                .to_hex(),
+            "00"
+        );
+        assert_eq!(
+            CheckMetadataHash::Enabled(hash)
+                .additional_signed()
+                .to_scale()
+                .to_hex(),
+            format!("01{}", hash.to_hex())
+        );
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::extensions::{AdditionalSigned, SignedExtra};
+use tw_encoding::scale::{RawOwned, ToScale};
+use tw_hash::blake2::blake2_b;
+use tw_hash::{H256, H512};
+use tw_memory::Data;
+
+/// Extrinsic format version.
+const EXTRINSIC_VERSION: u8 = 4;
+/// The bit is set if the extrinsic is signed.
+const SIGNED_BIT: u8 = 0x80;
+/// Payloads longer than the threshold are hashed before signing.
+const PAYLOAD_HASH_THRESHOLD: usize = 256;
+const PAYLOAD_HASH_SIZE: usize = 32;
+
+/// An account that is encoded either as a raw `AccountId32` or as `MultiAddress::Id`.
+/// https://github.com/paritytech/polkadot-sdk/blob/master/substrate/primitives/runtime/src/multiaddress.rs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AccountId {
+    pub key: H256,
+    pub multi_address: bool,
+}
+
+impl ToScale for AccountId {
+    fn to_scale_into(&self, out: &mut Data) {
+        if self.multi_address {
+            // `MultiAddress::Id` variant index.
+            out.push(0x00);
+        }
+        out.extend_from_slice(self.key.as_slice());
+    }
+}
+
+/// https://github.com/paritytech/polkadot-sdk/blob/master/substrate/primitives/runtime/src/lib.rs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultiSignature {
+    Ed25519(H512),
+    Sr25519(H512),
+}
+
+impl ToScale for MultiSignature {
+    fn to_scale_into(&self, out: &mut Data) {
+        let (variant, signature) = match self {
+            MultiSignature::Ed25519(signature) => (0x00_u8, signature),
+            MultiSignature::Sr25519(signature) => (0x01_u8, signature),
+        };
+        out.push(variant);
+        out.extend_from_slice(signature.as_slice());
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsignedExtrinsic {
+    /// Encoded call.
+    pub call: Data,
+    /// Whether the signer account is encoded as `MultiAddress::Id`.
+    pub multi_address: bool,
+    pub extra: SignedExtra,
+    pub additional: AdditionalSigned,
+}
+
+impl UnsignedExtrinsic {
+    /// Returns the payload that needs to be signed.
+    pub fn encode_payload(&self) -> Data {
+        (RawOwned(self.call.clone()), &self.extra, &self.additional).to_scale()
+    }
+
+    /// Returns the data that is actually signed:
+    /// the payload itself, or its `blake2b-256` hash if the payload is too long.
+    pub fn payload_to_sign(&self) -> Data {
+        let payload = self.encode_payload();
+        if payload.len() > PAYLOAD_HASH_THRESHOLD {
+            return blake2_b(&payload, PAYLOAD_HASH_SIZE)
+                .expect("'PAYLOAD_HASH_SIZE' is a valid blake2b hash size");
+        }
+        payload
+    }
+
+    /// Encodes the signed extrinsic prefixed with its compact length.
+    pub fn encode_signed(&self, signer: H256, signature: MultiSignature) -> Data {
+        let signer = AccountId {
+            key: signer,
+            multi_address: self.multi_address,
+        };
+        let extrinsic = (
+            EXTRINSIC_VERSION | SIGNED_BIT,
+            signer,
+            signature,
+            (&self.extra, RawOwned(self.call.clone())),
+        )
+            .to_scale();
+        // Encoded as `Vec<u8>` so the length prefix is added.
+        extrinsic.to_scale()
+    }
+}
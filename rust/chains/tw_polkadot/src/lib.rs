@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod call_encoder;
+pub mod compiler;
+pub mod entry;
+pub mod extensions;
+pub mod extrinsic;
+pub mod network;
+pub mod signer;
+pub mod tx_builder;
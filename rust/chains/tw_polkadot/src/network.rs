@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+/// SS58 network prefixes of the chains that have default call indices.
+/// https://github.com/paritytech/ss58-registry/blob/main/ss58-registry.json
+pub const POLKADOT: u16 = 0;
+pub const KUSAMA: u16 = 2;
+
+/// The first runtime versions that use `MultiAddress` instead of raw `AccountId`.
+pub const POLKADOT_MULTI_ADDRESS_SPEC: u32 = 28;
+pub const KUSAMA_MULTI_ADDRESS_SPEC: u32 = 2028;
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::extrinsic::MultiSignature;
+use crate::tx_builder::TxBuilder;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_keypair::{ed25519, sr25519};
+use tw_proto::Polkadot::Proto;
+
+pub struct PolkadotSigner;
+
+impl PolkadotSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let unsigned_tx = TxBuilder::unsigned_tx_from_proto(&input)?;
+        let payload = unsigned_tx.payload_to_sign();
+
+        let (signer, signature) = match input.key_type {
+            Proto::KeyType::ED25519 => {
+                let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+                let signature = key_pair.sign(payload)?;
+                (
+                    key_pair.public().to_bytes(),
+                    MultiSignature::Ed25519(signature.to_bytes()),
+                )
+            },
+            Proto::KeyType::SR25519 => {
+                let key_pair = sr25519::KeyPair::try_from(input.private_key.as_ref())?;
+                let signature = key_pair.sign(payload)?;
+                (
+                    key_pair.public().to_bytes(),
+                    MultiSignature::Sr25519(signature.to_bytes()),
+                )
+            },
+        };
+
+        let encoded = unsigned_tx.encode_signed(signer, signature);
+        Ok(Proto::SigningOutput {
+            encoded: Cow::from(encoded),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
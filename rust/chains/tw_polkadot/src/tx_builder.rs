@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::call_encoder::{parse_amount, CallEncoder};
+use crate::extensions::{
+    AdditionalSigned, ChargeAssetTxPayment, CheckMetadataHash, Era, SignedExtra,
+};
+use crate::extrinsic::UnsignedExtrinsic;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_hash::H256;
+use tw_proto::Polkadot::Proto;
+
+use Proto::mod_Balance::OneOfmessage_oneof as BalanceVariant;
+use Proto::mod_SigningInput::OneOfmessage_oneof as SigningVariant;
+
+pub struct TxBuilder;
+
+impl TxBuilder {
+    pub fn unsigned_tx_from_proto(
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<UnsignedExtrinsic> {
+        let call_encoder = CallEncoder::from_input(input)?;
+        let call = call_encoder.encode_call(input)?;
+
+        let era = match input.era {
+            Some(ref era) => Era::mortal(era.block_number, era.period),
+            None => Era::Immortal,
+        };
+        let check_metadata_hash = input
+            .check_metadata_hash
+            .as_ref()
+            .map(Self::check_metadata_hash)
+            .transpose()?;
+
+        let extra = SignedExtra {
+            era,
+            nonce: input.nonce,
+            tip: parse_amount(&input.tip)?,
+            charge_asset_tx_payment: Self::charge_asset_tx_payment(input),
+            check_metadata_hash,
+        };
+        let additional = AdditionalSigned {
+            spec_version: input.spec_version,
+            transaction_version: input.transaction_version,
+            genesis_hash: parse_hash(&input.genesis_hash)?,
+            block_hash: parse_hash(&input.block_hash)?,
+            check_metadata_hash,
+        };
+
+        Ok(UnsignedExtrinsic {
+            call,
+            multi_address: call_encoder.multi_address(),
+            extra,
+            additional,
+        })
+    }
+
+    /// The fee asset is specified for asset transfers only.
+    /// https://github.com/paritytech/polkadot-sdk/blob/master/substrate/frame/transaction-payment/asset-tx-payment/src/lib.rs
+    fn charge_asset_tx_payment(input: &Proto::SigningInput<'_>) -> ChargeAssetTxPayment {
+        let fee_asset_id = match input.message_oneof {
+            SigningVariant::balance_call(ref balance) => match balance.message_oneof {
+                BalanceVariant::asset_transfer(ref transfer) => Some(transfer.fee_asset_id),
+                BalanceVariant::batch_asset_transfer(ref batch) => Some(batch.fee_asset_id),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match fee_asset_id {
+            // Zero means the fee is paid in the native token.
+            Some(0) => ChargeAssetTxPayment::Enabled { asset_id: None },
+            Some(asset_id) => ChargeAssetTxPayment::Enabled {
+                asset_id: Some(asset_id),
+            },
+            None if input.charge_native_as_asset_tx_payment => {
+                ChargeAssetTxPayment::Enabled { asset_id: None }
+            },
+            None => ChargeAssetTxPayment::Disabled,
+        }
+    }
+
+    fn check_metadata_hash(
+        check_metadata_hash: &Proto::CheckMetadataHash<'_>,
+    ) -> SigningResult<CheckMetadataHash> {
+        if check_metadata_hash.metadata_hash.is_empty() {
+            return Ok(CheckMetadataHash::Disabled);
+        }
+        parse_hash(&check_metadata_hash.metadata_hash).map(CheckMetadataHash::Enabled)
+    }
+}
+
+fn parse_hash(hash: &[u8]) -> SigningResult<H256> {
+    H256::try_from(hash).map_err(|_| SigningError(SigningErrorType::Error_invalid_params))
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::H256;
+use tw_keypair::ed25519;
+use tw_memory::Data;
+
+/// Solana account address, which is a Base58-encoded 32-byte `ed25519` public key
+/// or a program derived address that doesn't lie on the curve.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SolanaAddress {
+    bytes: H256,
+}
+
+impl SolanaAddress {
+    pub const LEN: usize = H256::len();
+
+    pub fn with_public_key_ed25519(public_key: &ed25519::sha512::PublicKey) -> SolanaAddress {
+        SolanaAddress {
+            bytes: public_key.to_bytes(),
+        }
+    }
+
+    pub fn with_public_key_bytes(bytes: H256) -> SolanaAddress {
+        SolanaAddress { bytes }
+    }
+
+    pub fn bytes(&self) -> H256 {
+        self.bytes
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl CoinAddress for SolanaAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.bytes.to_vec()
+    }
+}
+
+impl FromStr for SolanaAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base58::decode(s, Alphabet::BITCOIN).map_err(|_| AddressError::InvalidInput)?;
+        let bytes = H256::try_from(bytes.as_slice()).map_err(|_| AddressError::InvalidInput)?;
+        Ok(SolanaAddress { bytes })
+    }
+}
+
+/// Implement `str` -> `SolanaAddress` conversion for well-known addresses.
+impl From<&'static str> for SolanaAddress {
+    fn from(s: &'static str) -> Self {
+        SolanaAddress::from_str(s).expect("Expected a valid Solana address")
+    }
+}
+
+impl fmt::Display for SolanaAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            base58::encode(self.bytes.as_slice(), Alphabet::BITCOIN)
+        )
+    }
+}
+
+/// Returns `Ok(None)` if the given `s` string is empty.
+pub fn parse_optional_address(s: &str) -> AddressResult<Option<SolanaAddress>> {
+    if s.is_empty() {
+        return Ok(None);
+    }
+    SolanaAddress::from_str(s).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_from_str() {
+        let addr = SolanaAddress::from_str("2gVkYWexTHR5Hb2aLeQN3tnngvWzisFKXDUPrgMHpdST").unwrap();
+        assert_eq!(
+            addr.bytes(),
+            H256::from("18f9d8d877393bbbe8d697a8a2e52879cc7e84f467656d1cce6bab5a8d2637ec")
+        );
+        assert_eq!(
+            addr.to_string(),
+            "2gVkYWexTHR5Hb2aLeQN3tnngvWzisFKXDUPrgMHpdST"
+        );
+    }
+
+    #[test]
+    fn test_address_from_str_invalid() {
+        // Too short.
+        SolanaAddress::from_str("2gVkYWexTHR5Hb2aLeQN3tnngvWzisFKXDUPrgMHpd").unwrap_err();
+        // Invalid Base58 character.
+        SolanaAddress::from_str("0gVkYWexTHR5Hb2aLeQN3tnngvWzisFKXDUPrgMHpdST").unwrap_err();
+    }
+}
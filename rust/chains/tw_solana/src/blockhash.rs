@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::error::{SigningError, SigningErrorType};
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::H256;
+
+/// A Base58-encoded hash of a recent ledger entry.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Blockhash(H256);
+
+impl Blockhash {
+    pub fn with_bytes(bytes: H256) -> Blockhash {
+        Blockhash(bytes)
+    }
+
+    pub fn to_bytes(&self) -> H256 {
+        self.0
+    }
+}
+
+impl FromStr for Blockhash {
+    type Err = SigningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base58::decode(s, Alphabet::BITCOIN)?;
+        let bytes = H256::try_from(bytes.as_slice())
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        Ok(Blockhash(bytes))
+    }
+}
+
+impl fmt::Display for Blockhash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            base58::encode(self.0.as_slice(), Alphabet::BITCOIN)
+        )
+    }
+}
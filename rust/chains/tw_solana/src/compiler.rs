@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::modules::message_builder::MessageBuilder;
+use crate::modules::proto_builder::ProtoBuilder;
+use crate::modules::tx_signer::TxSigner;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::H512;
+use tw_keypair::ed25519;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::Solana::Proto;
+
+pub struct SolanaCompiler;
+
+impl SolanaCompiler {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::PreSigningOutput, e))
+    }
+
+    fn preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::PreSigningOutput<'static>> {
+        let builder = MessageBuilder::new(input);
+        let external_signatures = builder.external_signatures()?;
+        let unsigned_msg = builder.build()?;
+        let data_to_sign = unsigned_msg.serialize();
+
+        // Skip the signers that have signed the message already.
+        let signers: Vec<_> = unsigned_msg
+            .signers()
+            .iter()
+            .filter(|addr| !external_signatures.contains_key(addr))
+            .map(|addr| Cow::from(addr.to_string().into_bytes()))
+            .collect();
+
+        // There is no need to hash the message as Solana uses `ed25519` signatures.
+        Ok(Proto::PreSigningOutput {
+            signers,
+            data: Cow::from(data_to_sign),
+            ..Proto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn compile_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        if signatures.len() != public_keys.len() {
+            return Err(SigningError(SigningErrorType::Error_signatures_count));
+        }
+
+        let builder = MessageBuilder::new(input);
+        let mut key_signs = builder.external_signatures()?;
+        let unsigned_msg = builder.build()?;
+        let data_to_sign = unsigned_msg.serialize();
+
+        let compiled_signs = public_keys
+            .iter()
+            .zip(signatures.iter())
+            .map(|(public_key, signature)| {
+                let public_key = ed25519::sha512::PublicKey::try_from(public_key.as_slice())?;
+                let ed25519_signature = ed25519::Signature::try_from(signature.as_slice())?;
+                if !public_key.verify(ed25519_signature, data_to_sign.clone()) {
+                    return Err(SigningError(SigningErrorType::Error_signing));
+                }
+
+                let signature = H512::try_from(signature.as_slice())
+                    .map_err(|_| SigningError(SigningErrorType::Error_signing))?;
+                Ok((
+                    SolanaAddress::with_public_key_ed25519(&public_key),
+                    signature,
+                ))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        key_signs.extend(compiled_signs);
+
+        let encoded_unsigned = base58::encode(&data_to_sign, Alphabet::BITCOIN);
+        let signed_tx = TxSigner::compile_versioned(unsigned_msg, key_signs)?;
+        if !builder.allows_partial_signing() {
+            TxSigner::check_fully_signed(&signed_tx)?;
+        }
+
+        Ok(Proto::SigningOutput {
+            encoded: Cow::from(signed_tx.to_base58()),
+            unsigned_tx: Cow::from(encoded_unsigned),
+            signatures: ProtoBuilder::pubkey_signatures(&signed_tx),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Well-known program and sysvar account addresses.
+
+pub const SYSTEM_PROGRAM_ID_ADDRESS: &str = "11111111111111111111111111111111";
+pub const STAKE_PROGRAM_ID_ADDRESS: &str = "Stake11111111111111111111111111111111111111";
+pub const TOKEN_PROGRAM_ID_ADDRESS: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+pub const ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS: &str =
+    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+pub const MEMO_PROGRAM_ID_ADDRESS: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+pub const SYSVAR_RENT_ID_ADDRESS: &str = "SysvarRent111111111111111111111111111111111";
+pub const SYSVAR_CLOCK_ID_ADDRESS: &str = "SysvarC1ock11111111111111111111111111111111";
+pub const SYSVAR_STAKE_HISTORY_ID_ADDRESS: &str = "SysvarStakeHistory1111111111111111111111111";
+pub const SYSVAR_RECENT_BLOCKHASHS_ADDRESS: &str = "SysvarRecentB1ockHashes11111111111111111111";
+pub const STAKE_CONFIG_ID_ADDRESS: &str = "StakeConfig11111111111111111111111111111111";
+pub const COMPUTE_BUDGET_PROGRAM_ID_ADDRESS: &str = "ComputeBudget111111111111111111111111111111";
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use tw_memory::Data;
+
+/// Describes a single account read or written by a program during instruction execution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountMeta {
+    /// An account's public key.
+    pub pubkey: SolanaAddress,
+    /// True if an `Instruction` requires a `Transaction` signature matching `pubkey`.
+    pub is_signer: bool,
+    /// True if the account data or metadata may be mutated during program execution.
+    pub is_writable: bool,
+}
+
+impl AccountMeta {
+    /// Construct metadata for a writable account.
+    pub fn new(pubkey: SolanaAddress, is_signer: bool) -> AccountMeta {
+        AccountMeta {
+            pubkey,
+            is_signer,
+            is_writable: true,
+        }
+    }
+
+    /// Construct metadata for a read-only account.
+    pub fn readonly(pubkey: SolanaAddress, is_signer: bool) -> AccountMeta {
+        AccountMeta {
+            pubkey,
+            is_signer,
+            is_writable: false,
+        }
+    }
+}
+
+/// A directive for a single invocation of a Solana program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Instruction {
+    /// The address of the program account that executes this instruction.
+    pub program_id: SolanaAddress,
+    /// Metadata describing accounts that should be passed to the program.
+    pub accounts: Vec<AccountMeta>,
+    /// Opaque data passed to the program for its own interpretation.
+    pub data: Data,
+}
+
+impl Instruction {
+    pub fn new(program_id: SolanaAddress, data: Data, accounts: Vec<AccountMeta>) -> Instruction {
+        Instruction {
+            program_id,
+            accounts,
+            data,
+        }
+    }
+
+    /// Appends the given `references` as read-only non-signer accounts.
+    pub fn with_references(mut self, references: Vec<SolanaAddress>) -> Instruction {
+        self.accounts.extend(
+            references
+                .into_iter()
+                .map(|reference| AccountMeta::readonly(reference, false)),
+        );
+        self
+    }
+}
@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod address;
+pub mod blockhash;
+pub mod compiler;
+pub mod defined_addresses;
+pub mod entry;
+pub mod instruction;
+pub mod modules;
+pub mod program;
+pub mod signer;
+pub mod transaction;
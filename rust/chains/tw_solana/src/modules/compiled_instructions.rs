@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::instruction::Instruction;
+use crate::transaction::CompiledInstruction;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+
+/// Replaces the program ID and accounts of each instruction with indexes into `account_keys`.
+pub fn compile_instructions(
+    instructions: &[Instruction],
+    account_keys: &[SolanaAddress],
+) -> SigningResult<Vec<CompiledInstruction>> {
+    instructions
+        .iter()
+        .map(|ix| compile_instruction(ix, account_keys))
+        .collect()
+}
+
+fn compile_instruction(
+    instruction: &Instruction,
+    account_keys: &[SolanaAddress],
+) -> SigningResult<CompiledInstruction> {
+    let program_id_index = find_account_index(&instruction.program_id, account_keys)?;
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|account| find_account_index(&account.pubkey, account_keys))
+        .collect::<SigningResult<Vec<_>>>()?;
+
+    Ok(CompiledInstruction {
+        program_id_index,
+        accounts,
+        data: instruction.data.clone(),
+    })
+}
+
+fn find_account_index(
+    account: &SolanaAddress,
+    account_keys: &[SolanaAddress],
+) -> SigningResult<u8> {
+    let index = account_keys
+        .iter()
+        .position(|key| key == account)
+        .ok_or(SigningError(SigningErrorType::Error_internal))?;
+    u8::try_from(index).map_err(|_| SigningError(SigningErrorType::Error_tx_too_big))
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::instruction::{AccountMeta, Instruction};
+use crate::transaction::MessageHeader;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+
+/// Collects the accounts used by a set of instructions into three buckets:
+/// signed, unsigned writable and unsigned read-only accounts.
+/// Program IDs are added as read-only accounts after all instruction accounts.
+#[derive(Default)]
+pub struct CompiledKeys {
+    signed: Vec<SolanaAddress>,
+    unsigned_writable: Vec<SolanaAddress>,
+    readonly: Vec<SolanaAddress>,
+}
+
+impl CompiledKeys {
+    /// `fee_payer` is always the first signed account if specified.
+    pub fn compile(fee_payer: Option<SolanaAddress>, instructions: &[Instruction]) -> CompiledKeys {
+        let mut keys = CompiledKeys::default();
+
+        if let Some(fee_payer) = fee_payer {
+            keys.add_account(&AccountMeta::new(fee_payer, true));
+        }
+
+        for account in instructions.iter().flat_map(|ix| ix.accounts.iter()) {
+            keys.add_account(account);
+        }
+
+        for instruction in instructions.iter() {
+            keys.add_account(&AccountMeta::readonly(instruction.program_id, false));
+        }
+
+        keys
+    }
+
+    /// Returns the message header and the ordered list of account keys.
+    pub fn try_into_message_components(self) -> SigningResult<(MessageHeader, Vec<SolanaAddress>)> {
+        let num_required_signatures = u8::try_from(self.signed.len())
+            .map_err(|_| SigningError(SigningErrorType::Error_tx_too_big))?;
+        let num_readonly_unsigned_accounts = u8::try_from(self.readonly.len())
+            .map_err(|_| SigningError(SigningErrorType::Error_tx_too_big))?;
+
+        let header = MessageHeader {
+            num_required_signatures,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts,
+        };
+
+        let mut account_keys = Vec::new();
+        let ordered = self
+            .signed
+            .into_iter()
+            .chain(self.unsigned_writable)
+            .chain(self.readonly);
+        for account in ordered {
+            if !account_keys.contains(&account) {
+                account_keys.push(account);
+            }
+        }
+
+        Ok((header, account_keys))
+    }
+
+    fn add_account(&mut self, account: &AccountMeta) {
+        let in_signed = self.signed.contains(&account.pubkey);
+        let in_unsigned_writable = self.unsigned_writable.contains(&account.pubkey);
+        let in_readonly = self.readonly.contains(&account.pubkey);
+
+        if account.is_signer {
+            if !in_signed {
+                self.signed.push(account.pubkey);
+            }
+        } else if account.is_writable {
+            if !in_signed && !in_unsigned_writable {
+                self.unsigned_writable.push(account.pubkey);
+            }
+        } else if !in_signed && !in_unsigned_writable && !in_readonly {
+            self.readonly.push(account.pubkey);
+        }
+    }
+}
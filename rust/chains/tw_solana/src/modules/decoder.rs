@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::instruction_decoder::InstructionDecoder;
+use crate::modules::proto_builder::ProtoBuilder;
+use crate::transaction::versioned::VersionedTransaction;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Solana::Proto;
+
+pub struct SolanaDecoder;
+
+impl SolanaDecoder {
+    /// Decodes a legacy or v0 transaction in the wire format,
+    /// including the instructions of well-known programs.
+    pub fn decode_transaction(
+        coin: &dyn CoinContext,
+        input: Proto::DecodingTransactionInput<'_>,
+    ) -> Proto::DecodingTransactionOutput<'static> {
+        Self::decode_transaction_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+
+    fn decode_transaction_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::DecodingTransactionInput<'_>,
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let tx = VersionedTransaction::deserialize(&input.encoded_tx)?;
+
+        let instruction_decoder = InstructionDecoder::new(&tx.message);
+        let instructions = tx
+            .message
+            .instructions()
+            .iter()
+            .map(|instruction| instruction_decoder.decode(instruction))
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(ProtoBuilder::raw_message_from_tx(&tx)),
+            instructions,
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::COMPUTE_BUDGET_PROGRAM_ID_ADDRESS;
+use crate::instruction::Instruction;
+use tw_memory::Data;
+
+/// Compute Budget program instruction types.
+/// https://github.com/solana-labs/solana/blob/master/sdk/src/compute_budget.rs
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum ComputeBudgetInstruction {
+    RequestHeapFrame = 1,
+    SetComputeUnitLimit = 2,
+    SetComputeUnitPrice = 3,
+}
+
+impl ComputeBudgetInstruction {
+    pub fn discriminant(self) -> u8 {
+        self as u8
+    }
+}
+
+pub struct ComputeBudgetInstructionBuilder;
+
+impl ComputeBudgetInstructionBuilder {
+    pub fn set_compute_unit_limit(limit: u32) -> Instruction {
+        Self::compute_budget_instruction(Self::set_compute_unit_limit_data(limit))
+    }
+
+    pub fn set_compute_unit_price(price: u64) -> Instruction {
+        Self::compute_budget_instruction(Self::set_compute_unit_price_data(price))
+    }
+
+    pub fn set_compute_unit_limit_data(limit: u32) -> Data {
+        let mut data = vec![ComputeBudgetInstruction::SetComputeUnitLimit.discriminant()];
+        data.extend_from_slice(&limit.to_le_bytes());
+        data
+    }
+
+    pub fn set_compute_unit_price_data(price: u64) -> Data {
+        let mut data = vec![ComputeBudgetInstruction::SetComputeUnitPrice.discriminant()];
+        data.extend_from_slice(&price.to_le_bytes());
+        data
+    }
+
+    fn compute_budget_instruction(data: Data) -> Instruction {
+        Instruction::new(
+            SolanaAddress::from(COMPUTE_BUDGET_PROGRAM_ID_ADDRESS),
+            data,
+            Vec::default(),
+        )
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::MEMO_PROGRAM_ID_ADDRESS;
+use crate::instruction::Instruction;
+use compute_budget_instruction::ComputeBudgetInstructionBuilder;
+use system_instruction::SystemInstructionBuilder;
+
+pub mod compute_budget_instruction;
+pub mod stake_instruction;
+pub mod system_instruction;
+pub mod token_instruction;
+
+/// Collects the instructions of a transaction in the order they will be executed.
+#[derive(Default)]
+pub struct InstructionBuilder {
+    instructions: Vec<Instruction>,
+}
+
+impl InstructionBuilder {
+    /// Adds an `AdvanceNonceAccount` instruction if a durable transaction nonce is used.
+    /// The instruction must be the first one in the transaction.
+    pub fn maybe_advance_nonce(
+        &mut self,
+        nonce_account: Option<SolanaAddress>,
+        authorized: SolanaAddress,
+    ) -> &mut Self {
+        if let Some(nonce_account) = nonce_account {
+            self.instructions
+                .push(SystemInstructionBuilder::advance_nonce_account(
+                    nonce_account,
+                    authorized,
+                ));
+        }
+        self
+    }
+
+    /// Adds a `SetComputeUnitPrice` instruction if the price is specified.
+    pub fn maybe_priority_fee_price(&mut self, price: Option<u64>) -> &mut Self {
+        if let Some(price) = price {
+            self.instructions
+                .push(ComputeBudgetInstructionBuilder::set_compute_unit_price(
+                    price,
+                ));
+        }
+        self
+    }
+
+    /// Adds a `SetComputeUnitLimit` instruction if the limit is specified.
+    pub fn maybe_priority_fee_limit(&mut self, limit: Option<u32>) -> &mut Self {
+        if let Some(limit) = limit {
+            self.instructions
+                .push(ComputeBudgetInstructionBuilder::set_compute_unit_limit(
+                    limit,
+                ));
+        }
+        self
+    }
+
+    /// Adds a Memo instruction if the given `memo` is not empty.
+    pub fn maybe_memo(&mut self, memo: &str) -> &mut Self {
+        if !memo.is_empty() {
+            self.instructions.push(Self::memo(memo));
+        }
+        self
+    }
+
+    pub fn add_instruction(&mut self, instruction: Instruction) -> &mut Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    pub fn add_instructions<I>(&mut self, instructions: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Instruction>,
+    {
+        self.instructions.extend(instructions);
+        self
+    }
+
+    pub fn output(self) -> Vec<Instruction> {
+        self.instructions
+    }
+
+    fn memo(memo: &str) -> Instruction {
+        Instruction::new(
+            SolanaAddress::from(MEMO_PROGRAM_ID_ADDRESS),
+            memo.as_bytes().to_vec(),
+            Vec::default(),
+        )
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::{
+    STAKE_CONFIG_ID_ADDRESS, STAKE_PROGRAM_ID_ADDRESS, SYSVAR_CLOCK_ID_ADDRESS,
+    SYSVAR_RENT_ID_ADDRESS, SYSVAR_STAKE_HISTORY_ID_ADDRESS,
+};
+use crate::instruction::{AccountMeta, Instruction};
+use crate::modules::instruction_builder::system_instruction::SystemInstructionBuilder;
+use tw_memory::Data;
+
+/// The size of a stake account state in bytes.
+const STAKE_ACCOUNT_SPACE: u64 = 200;
+/// The size of an encoded default `Lockup`: `unix_timestamp: i64`, `epoch: u64`, `custodian: Pubkey`.
+const LOCKUP_LEN: usize = 48;
+
+/// Stake program instruction types.
+/// https://github.com/solana-labs/solana/blob/master/programs/stake/src/stake_instruction.rs
+#[repr(u32)]
+enum StakeInstruction {
+    Initialize = 0,
+    DelegateStake = 2,
+    Withdraw = 4,
+    Deactivate = 5,
+}
+
+impl StakeInstruction {
+    fn encode(self) -> Data {
+        (self as u32).to_le_bytes().to_vec()
+    }
+}
+
+pub struct StakeInstructionBuilder;
+
+impl StakeInstructionBuilder {
+    /// Creates a stake account derived from `signer` and `seed`,
+    /// initializes it and delegates the given `lamports` to the `vote_account`.
+    pub fn deposit_stake(
+        signer: SolanaAddress,
+        stake_account: SolanaAddress,
+        vote_account: SolanaAddress,
+        seed: &[u8],
+        lamports: u64,
+    ) -> Vec<Instruction> {
+        let create_account_instruction = SystemInstructionBuilder::create_account_with_seed(
+            signer,
+            stake_account,
+            signer,
+            seed,
+            lamports,
+            STAKE_ACCOUNT_SPACE,
+            SolanaAddress::from(STAKE_PROGRAM_ID_ADDRESS),
+        );
+        let initialize_instruction = Self::initialize(stake_account, signer);
+        let delegate_instruction = Self::delegate(stake_account, vote_account, signer);
+
+        vec![
+            create_account_instruction,
+            initialize_instruction,
+            delegate_instruction,
+        ]
+    }
+
+    /// Initializes a stake account with `authority` as both the staker and the withdrawer,
+    /// and with no lockup.
+    pub fn initialize(stake_account: SolanaAddress, authority: SolanaAddress) -> Instruction {
+        let mut data = StakeInstruction::Initialize.encode();
+        // Staker.
+        data.extend_from_slice(authority.as_slice());
+        // Withdrawer.
+        data.extend_from_slice(authority.as_slice());
+        data.extend_from_slice(&[0; LOCKUP_LEN]);
+
+        let account_metas = vec![
+            AccountMeta::new(stake_account, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_RENT_ID_ADDRESS), false),
+        ];
+        Self::stake_instruction(data, account_metas)
+    }
+
+    pub fn delegate(
+        stake_account: SolanaAddress,
+        vote_account: SolanaAddress,
+        authority: SolanaAddress,
+    ) -> Instruction {
+        let data = StakeInstruction::DelegateStake.encode();
+
+        let account_metas = vec![
+            // Initialized stake account to be delegated.
+            AccountMeta::new(stake_account, false),
+            // Vote account to which this stake will be delegated.
+            AccountMeta::readonly(vote_account, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_CLOCK_ID_ADDRESS), false),
+            // Stake history sysvar that carries stake warmup/cooldown history.
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_STAKE_HISTORY_ID_ADDRESS), false),
+            // Address of config account that carries stake config.
+            AccountMeta::readonly(SolanaAddress::from(STAKE_CONFIG_ID_ADDRESS), false),
+            // Stake authority.
+            AccountMeta::readonly(authority, true),
+        ];
+        Self::stake_instruction(data, account_metas)
+    }
+
+    pub fn deactivate(stake_account: SolanaAddress, authority: SolanaAddress) -> Instruction {
+        let data = StakeInstruction::Deactivate.encode();
+
+        let account_metas = vec![
+            // Delegated stake account.
+            AccountMeta::new(stake_account, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_CLOCK_ID_ADDRESS), false),
+            // Stake authority.
+            AccountMeta::new(authority, true),
+        ];
+        Self::stake_instruction(data, account_metas)
+    }
+
+    pub fn withdraw(
+        stake_account: SolanaAddress,
+        withdraw_authority: SolanaAddress,
+        recipient: SolanaAddress,
+        lamports: u64,
+    ) -> Instruction {
+        let mut data = StakeInstruction::Withdraw.encode();
+        data.extend_from_slice(&lamports.to_le_bytes());
+
+        let account_metas = vec![
+            // Stake account from which to withdraw.
+            AccountMeta::new(stake_account, false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_CLOCK_ID_ADDRESS), false),
+            // Stake history sysvar that carries stake warmup/cooldown history.
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_STAKE_HISTORY_ID_ADDRESS), false),
+            // Withdraw authority.
+            AccountMeta::new(withdraw_authority, true),
+        ];
+        Self::stake_instruction(data, account_metas)
+    }
+
+    fn stake_instruction(data: Data, account_metas: Vec<AccountMeta>) -> Instruction {
+        Instruction::new(
+            SolanaAddress::from(STAKE_PROGRAM_ID_ADDRESS),
+            data,
+            account_metas,
+        )
+    }
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::{
+    SYSTEM_PROGRAM_ID_ADDRESS, SYSVAR_RECENT_BLOCKHASHS_ADDRESS, SYSVAR_RENT_ID_ADDRESS,
+};
+use crate::instruction::{AccountMeta, Instruction};
+use tw_memory::Data;
+
+/// System program instruction types.
+/// https://github.com/solana-labs/solana/blob/master/sdk/program/src/system_instruction.rs
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SystemInstruction {
+    CreateAccount = 0,
+    Transfer = 2,
+    CreateAccountWithSeed = 3,
+    AdvanceNonceAccount = 4,
+    WithdrawNonceAccount = 5,
+    InitializeNonceAccount = 6,
+}
+
+impl SystemInstruction {
+    pub fn discriminant(self) -> u32 {
+        self as u32
+    }
+
+    pub fn encode(self) -> Data {
+        self.discriminant().to_le_bytes().to_vec()
+    }
+}
+
+pub struct SystemInstructionBuilder;
+
+impl SystemInstructionBuilder {
+    pub fn transfer(from: SolanaAddress, to: SolanaAddress, lamports: u64) -> Instruction {
+        let mut data = SystemInstruction::Transfer.encode();
+        data.extend_from_slice(&lamports.to_le_bytes());
+
+        let account_metas = vec![AccountMeta::new(from, true), AccountMeta::new(to, false)];
+        Self::system_instruction(data, account_metas)
+    }
+
+    pub fn create_account(
+        from: SolanaAddress,
+        to: SolanaAddress,
+        lamports: u64,
+        space: u64,
+        owner: SolanaAddress,
+    ) -> Instruction {
+        let mut data = SystemInstruction::CreateAccount.encode();
+        data.extend_from_slice(&lamports.to_le_bytes());
+        data.extend_from_slice(&space.to_le_bytes());
+        data.extend_from_slice(owner.as_slice());
+
+        let account_metas = vec![AccountMeta::new(from, true), AccountMeta::new(to, true)];
+        Self::system_instruction(data, account_metas)
+    }
+
+    pub fn create_account_with_seed(
+        from: SolanaAddress,
+        to: SolanaAddress,
+        base: SolanaAddress,
+        seed: &[u8],
+        lamports: u64,
+        space: u64,
+        owner: SolanaAddress,
+    ) -> Instruction {
+        let mut data = SystemInstruction::CreateAccountWithSeed.encode();
+        data.extend_from_slice(base.as_slice());
+        data.extend_from_slice(&(seed.len() as u64).to_le_bytes());
+        data.extend_from_slice(seed);
+        data.extend_from_slice(&lamports.to_le_bytes());
+        data.extend_from_slice(&space.to_le_bytes());
+        data.extend_from_slice(owner.as_slice());
+
+        let account_metas = vec![
+            AccountMeta::readonly(from, true),
+            AccountMeta::new(to, false),
+            AccountMeta::readonly(base, true),
+        ];
+        Self::system_instruction(data, account_metas)
+    }
+
+    pub fn advance_nonce_account(
+        nonce_account: SolanaAddress,
+        authorized: SolanaAddress,
+    ) -> Instruction {
+        let data = SystemInstruction::AdvanceNonceAccount.encode();
+
+        let account_metas = vec![
+            AccountMeta::new(nonce_account, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_RECENT_BLOCKHASHS_ADDRESS), false),
+            AccountMeta::readonly(authorized, true),
+        ];
+        Self::system_instruction(data, account_metas)
+    }
+
+    pub fn initialize_nonce_account(
+        nonce_account: SolanaAddress,
+        authority: SolanaAddress,
+    ) -> Instruction {
+        let mut data = SystemInstruction::InitializeNonceAccount.encode();
+        data.extend_from_slice(authority.as_slice());
+
+        let account_metas = vec![
+            AccountMeta::new(nonce_account, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_RECENT_BLOCKHASHS_ADDRESS), false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_RENT_ID_ADDRESS), false),
+        ];
+        Self::system_instruction(data, account_metas)
+    }
+
+    pub fn withdraw_nonce_account(
+        nonce_account: SolanaAddress,
+        authorized: SolanaAddress,
+        to: SolanaAddress,
+        lamports: u64,
+    ) -> Instruction {
+        let mut data = SystemInstruction::WithdrawNonceAccount.encode();
+        data.extend_from_slice(&lamports.to_le_bytes());
+
+        let account_metas = vec![
+            AccountMeta::new(nonce_account, false),
+            AccountMeta::new(to, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_RECENT_BLOCKHASHS_ADDRESS), false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_RENT_ID_ADDRESS), false),
+            AccountMeta::readonly(authorized, true),
+        ];
+        Self::system_instruction(data, account_metas)
+    }
+
+    fn system_instruction(data: Data, account_metas: Vec<AccountMeta>) -> Instruction {
+        Instruction::new(
+            SolanaAddress::from(SYSTEM_PROGRAM_ID_ADDRESS),
+            data,
+            account_metas,
+        )
+    }
+}
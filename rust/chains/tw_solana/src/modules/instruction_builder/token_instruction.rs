@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::{
+    ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS, SYSTEM_PROGRAM_ID_ADDRESS, SYSVAR_RENT_ID_ADDRESS,
+    TOKEN_PROGRAM_ID_ADDRESS,
+};
+use crate::instruction::{AccountMeta, Instruction};
+
+/// SPL Token program instruction types.
+/// https://github.com/solana-labs/solana-program-library/blob/master/token/program/src/instruction.rs
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum TokenInstruction {
+    Transfer = 3,
+    TransferChecked = 12,
+}
+
+impl TokenInstruction {
+    pub fn discriminant(self) -> u8 {
+        self as u8
+    }
+}
+
+pub struct TokenInstructionBuilder;
+
+impl TokenInstructionBuilder {
+    /// Creates an associated token account for the given `other_main_address` and `token_mint_address`.
+    /// https://github.com/solana-labs/solana-program-library/blob/master/associated-token-account/program/src/instruction.rs
+    pub fn create_account(
+        funding_address: SolanaAddress,
+        other_main_address: SolanaAddress,
+        token_address: SolanaAddress,
+        token_mint_address: SolanaAddress,
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(funding_address, true),
+            AccountMeta::new(token_address, false),
+            AccountMeta::readonly(other_main_address, false),
+            AccountMeta::readonly(token_mint_address, false),
+            AccountMeta::readonly(SolanaAddress::from(SYSTEM_PROGRAM_ID_ADDRESS), false),
+            AccountMeta::readonly(SolanaAddress::from(TOKEN_PROGRAM_ID_ADDRESS), false),
+            AccountMeta::readonly(SolanaAddress::from(SYSVAR_RENT_ID_ADDRESS), false),
+        ];
+        Instruction::new(
+            SolanaAddress::from(ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS),
+            Vec::default(),
+            account_metas,
+        )
+    }
+
+    pub fn transfer_checked(
+        sender_token_address: SolanaAddress,
+        token_mint_address: SolanaAddress,
+        recipient_token_address: SolanaAddress,
+        signer: SolanaAddress,
+        amount: u64,
+        decimals: u8,
+    ) -> Instruction {
+        let mut data = vec![TokenInstruction::TransferChecked.discriminant()];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(decimals);
+
+        let account_metas = vec![
+            AccountMeta::new(sender_token_address, false),
+            AccountMeta::readonly(token_mint_address, false),
+            AccountMeta::new(recipient_token_address, false),
+            AccountMeta::new(signer, true),
+        ];
+        Instruction::new(
+            SolanaAddress::from(TOKEN_PROGRAM_ID_ADDRESS),
+            data,
+            account_metas,
+        )
+    }
+}
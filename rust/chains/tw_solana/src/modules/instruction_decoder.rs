@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::{
+    ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS, COMPUTE_BUDGET_PROGRAM_ID_ADDRESS,
+    SYSTEM_PROGRAM_ID_ADDRESS, TOKEN_PROGRAM_ID_ADDRESS,
+};
+use crate::modules::instruction_builder::compute_budget_instruction::ComputeBudgetInstruction;
+use crate::modules::instruction_builder::system_instruction::SystemInstruction;
+use crate::modules::instruction_builder::token_instruction::TokenInstruction;
+use crate::transaction::versioned::VersionedMessage;
+use crate::transaction::CompiledInstruction;
+use std::borrow::Cow;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_proto::Solana::Proto;
+use Proto::mod_DecodedInstruction::OneOfinstruction as DecodedInstructionType;
+
+/// Associated Token Account program instruction types.
+/// https://github.com/solana-labs/solana-program-library/blob/master/associated-token-account/program/src/instruction.rs
+const ATA_CREATE: u8 = 0;
+const ATA_CREATE_IDEMPOTENT: u8 = 1;
+
+/// Decodes compiled instructions of well-known programs into a readable form.
+pub struct InstructionDecoder<'a> {
+    message: &'a VersionedMessage,
+    /// The number of accounts loaded from address lookup tables.
+    num_lookup_accounts: usize,
+}
+
+impl<'a> InstructionDecoder<'a> {
+    pub fn new(message: &'a VersionedMessage) -> InstructionDecoder<'a> {
+        let num_lookup_accounts = match message {
+            VersionedMessage::Legacy(_) => 0,
+            VersionedMessage::V0(v0) => v0
+                .address_table_lookups
+                .iter()
+                .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
+                .sum(),
+        };
+        InstructionDecoder {
+            message,
+            num_lookup_accounts,
+        }
+    }
+
+    pub fn decode(
+        &self,
+        instruction: &CompiledInstruction,
+    ) -> SigningResult<Proto::DecodedInstruction<'static>> {
+        // Programs cannot be loaded from address lookup tables.
+        let program_id = self
+            .message
+            .account_keys()
+            .get(instruction.program_id_index as usize)
+            .copied()
+            .ok_or(SigningError(SigningErrorType::Error_input_parse))?;
+
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|index| self.account(*index))
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let data = instruction.data.as_slice();
+        let decoded = if program_id == SolanaAddress::from(SYSTEM_PROGRAM_ID_ADDRESS) {
+            Self::decode_system_instruction(&accounts, data)
+        } else if program_id == SolanaAddress::from(TOKEN_PROGRAM_ID_ADDRESS) {
+            Self::decode_token_instruction(&accounts, data)
+        } else if program_id == SolanaAddress::from(ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS) {
+            Self::decode_ata_instruction(&accounts, data)
+        } else if program_id == SolanaAddress::from(COMPUTE_BUDGET_PROGRAM_ID_ADDRESS) {
+            Self::decode_compute_budget_instruction(data)
+        } else {
+            None
+        };
+
+        Ok(Proto::DecodedInstruction {
+            program_id: Cow::from(program_id.to_string()),
+            accounts: accounts.into_iter().map(Cow::from).collect(),
+            data: Cow::from(instruction.data.clone()),
+            instruction: decoded.unwrap_or_default(),
+        })
+    }
+
+    /// Returns a Base58-encoded account by the given `index`,
+    /// or an empty string if the account is loaded from an address lookup table.
+    fn account(&self, index: u8) -> SigningResult<String> {
+        let index = index as usize;
+        let account_keys = self.message.account_keys();
+        if let Some(account) = account_keys.get(index) {
+            return Ok(account.to_string());
+        }
+        if index < account_keys.len() + self.num_lookup_accounts {
+            return Ok(String::default());
+        }
+        Err(SigningError(SigningErrorType::Error_input_parse))
+    }
+
+    fn decode_system_instruction(
+        accounts: &[String],
+        data: &[u8],
+    ) -> Option<DecodedInstructionType<'static>> {
+        let (discriminant, data) = split_array::<4>(data)?;
+        let discriminant = u32::from_le_bytes(discriminant);
+
+        if discriminant == SystemInstruction::CreateAccount.discriminant() {
+            let [funding_account, new_account, ..] = accounts else {
+                return None;
+            };
+            let (lamports, data) = split_array::<8>(data)?;
+            let (space, owner) = split_array::<8>(data)?;
+            let owner = SolanaAddress::with_public_key_bytes(owner.try_into().ok()?);
+            return Some(DecodedInstructionType::system_create_account(
+                Proto::mod_DecodedInstruction::SystemCreateAccount {
+                    funding_account: Cow::from(funding_account.clone()),
+                    new_account: Cow::from(new_account.clone()),
+                    lamports: u64::from_le_bytes(lamports),
+                    space: u64::from_le_bytes(space),
+                    owner: Cow::from(owner.to_string()),
+                },
+            ));
+        }
+
+        if discriminant == SystemInstruction::Transfer.discriminant() {
+            let [from, to, ..] = accounts else {
+                return None;
+            };
+            let lamports = u64::from_le_bytes(data.try_into().ok()?);
+            return Some(DecodedInstructionType::system_transfer(
+                Proto::mod_DecodedInstruction::SystemTransfer {
+                    from: Cow::from(from.clone()),
+                    to: Cow::from(to.clone()),
+                    lamports,
+                },
+            ));
+        }
+
+        if discriminant == SystemInstruction::AdvanceNonceAccount.discriminant() {
+            let [nonce_account, _recent_blockhashes, authority, ..] = accounts else {
+                return None;
+            };
+            if !data.is_empty() {
+                return None;
+            }
+            return Some(DecodedInstructionType::system_advance_nonce_account(
+                Proto::mod_DecodedInstruction::SystemAdvanceNonceAccount {
+                    nonce_account: Cow::from(nonce_account.clone()),
+                    authority: Cow::from(authority.clone()),
+                },
+            ));
+        }
+
+        None
+    }
+
+    fn decode_token_instruction(
+        accounts: &[String],
+        data: &[u8],
+    ) -> Option<DecodedInstructionType<'static>> {
+        let (&discriminant, data) = data.split_first()?;
+
+        if discriminant == TokenInstruction::Transfer.discriminant() {
+            let [source, destination, owner, ..] = accounts else {
+                return None;
+            };
+            let amount = u64::from_le_bytes(data.try_into().ok()?);
+            return Some(DecodedInstructionType::token_transfer(
+                Proto::mod_DecodedInstruction::TokenTransfer {
+                    source: Cow::from(source.clone()),
+                    destination: Cow::from(destination.clone()),
+                    owner: Cow::from(owner.clone()),
+                    amount,
+                },
+            ));
+        }
+
+        if discriminant == TokenInstruction::TransferChecked.discriminant() {
+            let [source, mint, destination, owner, ..] = accounts else {
+                return None;
+            };
+            let (amount, decimals) = split_array::<8>(data)?;
+            let [decimals] = decimals else {
+                return None;
+            };
+            return Some(DecodedInstructionType::token_transfer_checked(
+                Proto::mod_DecodedInstruction::TokenTransferChecked {
+                    source: Cow::from(source.clone()),
+                    mint: Cow::from(mint.clone()),
+                    destination: Cow::from(destination.clone()),
+                    owner: Cow::from(owner.clone()),
+                    amount: u64::from_le_bytes(amount),
+                    decimals: *decimals as u32,
+                },
+            ));
+        }
+
+        None
+    }
+
+    fn decode_ata_instruction(
+        accounts: &[String],
+        data: &[u8],
+    ) -> Option<DecodedInstructionType<'static>> {
+        let idempotent = match data {
+            // An empty data is a legacy encoding of the `Create` instruction.
+            [] | [ATA_CREATE] => false,
+            [ATA_CREATE_IDEMPOTENT] => true,
+            _ => return None,
+        };
+        let [funding_account, associated_account, wallet, mint, ..] = accounts else {
+            return None;
+        };
+        Some(DecodedInstructionType::create_associated_token_account(
+            Proto::mod_DecodedInstruction::CreateAssociatedTokenAccount {
+                funding_account: Cow::from(funding_account.clone()),
+                associated_account: Cow::from(associated_account.clone()),
+                wallet: Cow::from(wallet.clone()),
+                mint: Cow::from(mint.clone()),
+                idempotent,
+            },
+        ))
+    }
+
+    fn decode_compute_budget_instruction(data: &[u8]) -> Option<DecodedInstructionType<'static>> {
+        let (&discriminant, data) = data.split_first()?;
+
+        if discriminant == ComputeBudgetInstruction::SetComputeUnitLimit.discriminant() {
+            let limit = u32::from_le_bytes(data.try_into().ok()?);
+            return Some(DecodedInstructionType::set_compute_unit_limit(
+                Proto::mod_DecodedInstruction::SetComputeUnitLimit { limit },
+            ));
+        }
+
+        if discriminant == ComputeBudgetInstruction::SetComputeUnitPrice.discriminant() {
+            let price = u64::from_le_bytes(data.try_into().ok()?);
+            return Some(DecodedInstructionType::set_compute_unit_price(
+                Proto::mod_DecodedInstruction::SetComputeUnitPrice { price },
+            ));
+        }
+
+        None
+    }
+}
+
+/// Splits the given `data` into a fixed-size array and the rest bytes.
+fn split_array<const N: usize>(data: &[u8]) -> Option<([u8; N], &[u8])> {
+    if data.len() < N {
+        return None;
+    }
+    let (array, rest) = data.split_at(N);
+    Some((array.try_into().ok()?, rest))
+}
@@ -0,0 +1,649 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::{parse_optional_address, SolanaAddress};
+use crate::blockhash::Blockhash;
+use crate::defined_addresses::{STAKE_PROGRAM_ID_ADDRESS, SYSTEM_PROGRAM_ID_ADDRESS};
+use crate::instruction::Instruction;
+use crate::modules::compiled_instructions::compile_instructions;
+use crate::modules::compiled_keys::CompiledKeys;
+use crate::modules::instruction_builder::stake_instruction::StakeInstructionBuilder;
+use crate::modules::instruction_builder::system_instruction::SystemInstructionBuilder;
+use crate::modules::instruction_builder::token_instruction::TokenInstructionBuilder;
+use crate::modules::instruction_builder::InstructionBuilder;
+use crate::modules::message_modifier::MessageModifier;
+use crate::program::stake_program::StakeProgram;
+use crate::transaction::versioned::VersionedMessage;
+use crate::transaction::{legacy, v0, CompiledInstruction, MessageHeader};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::H512;
+use tw_keypair::ed25519;
+use tw_keypair::traits::KeyPairTrait;
+use tw_proto::Solana::Proto;
+use Proto::mod_RawMessage::OneOfmessage as ProtoMessageType;
+use Proto::mod_SigningInput::OneOftransaction_type as ProtoTransactionType;
+
+/// The size of a nonce account state in bytes.
+const NONCE_ACCOUNT_SPACE: u64 = 80;
+
+/// Builds a [`VersionedMessage`] from the given `Proto::SigningInput`.
+pub struct MessageBuilder<'a> {
+    input: Proto::SigningInput<'a>,
+}
+
+impl<'a> MessageBuilder<'a> {
+    pub fn new(input: Proto::SigningInput<'a>) -> Self {
+        MessageBuilder { input }
+    }
+
+    /// Returns the private keys that are required to sign the message.
+    /// The order doesn't matter as signatures are placed according to the message account keys.
+    pub fn signing_keys(&self) -> SigningResult<Vec<ed25519::sha512::KeyPair>> {
+        let mut signing_keys = Vec::default();
+
+        if !self.input.fee_payer_private_key.is_empty() {
+            let fee_payer_key =
+                ed25519::sha512::KeyPair::try_from(self.input.fee_payer_private_key.as_ref())?;
+            signing_keys.push(fee_payer_key);
+        }
+
+        if self.input.private_key.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_missing_private_key));
+        }
+        signing_keys.push(ed25519::sha512::KeyPair::try_from(
+            self.input.private_key.as_ref(),
+        )?);
+
+        // The new nonce account must sign the transaction as well.
+        if let ProtoTransactionType::create_nonce_account(ref create_nonce) =
+            self.input.transaction_type
+        {
+            if !create_nonce.nonce_account_private_key.is_empty() {
+                let nonce_key = ed25519::sha512::KeyPair::try_from(
+                    create_nonce.nonce_account_private_key.as_ref(),
+                )?;
+                signing_keys.push(nonce_key);
+            }
+        }
+
+        Ok(signing_keys)
+    }
+
+    /// Returns whether the transaction can be output without all the signatures,
+    /// so the rest signers can sign it later.
+    pub fn allows_partial_signing(&self) -> bool {
+        matches!(
+            self.input.transaction_type,
+            ProtoTransactionType::raw_message(_)
+        )
+    }
+
+    /// Returns the signatures of `raw_message` made earlier.
+    /// The signatures are discarded if the message is changed by the signing input parameters.
+    pub fn external_signatures(&self) -> SigningResult<HashMap<SolanaAddress, H512>> {
+        let ProtoTransactionType::raw_message(ref raw_message) = self.input.transaction_type else {
+            return Ok(HashMap::default());
+        };
+        if self.build()? != Self::message_from_raw(raw_message)? {
+            return Ok(HashMap::default());
+        }
+
+        raw_message
+            .signatures
+            .iter()
+            .map(|pubkey_signature| {
+                let pubkey = SolanaAddress::from_str(&pubkey_signature.pubkey)?;
+                let signature = base58::decode(&pubkey_signature.signature, Alphabet::BITCOIN)?;
+                let signature = H512::try_from(signature.as_slice())
+                    .map_err(|_| SigningError(SigningErrorType::Error_input_parse))?;
+                Ok((pubkey, signature))
+            })
+            .collect()
+    }
+
+    pub fn build(&self) -> SigningResult<VersionedMessage> {
+        if let ProtoTransactionType::raw_message(ref raw_message) = self.input.transaction_type {
+            return self.build_from_raw_message(raw_message);
+        }
+
+        let instructions = self.build_instructions()?;
+
+        let compiled_keys = CompiledKeys::compile(self.fee_payer()?, &instructions);
+        let (header, account_keys) = compiled_keys.try_into_message_components()?;
+        let compiled_instructions = compile_instructions(&instructions, &account_keys)?;
+        let recent_blockhash = self.recent_blockhash()?;
+
+        let message = if self.input.v0_msg {
+            VersionedMessage::V0(v0::Message {
+                header,
+                account_keys,
+                recent_blockhash,
+                instructions: compiled_instructions,
+                address_table_lookups: Vec::default(),
+            })
+        } else {
+            VersionedMessage::Legacy(legacy::Message {
+                header,
+                account_keys,
+                recent_blockhash,
+                instructions: compiled_instructions,
+            })
+        };
+        Ok(message)
+    }
+
+    fn build_instructions(&self) -> SigningResult<Vec<Instruction>> {
+        match self.input.transaction_type {
+            ProtoTransactionType::transfer_transaction(ref transfer) => {
+                self.transfer_instructions(transfer)
+            },
+            ProtoTransactionType::delegate_stake_transaction(ref delegate) => {
+                self.delegate_stake_instructions(delegate)
+            },
+            ProtoTransactionType::deactivate_stake_transaction(ref deactivate) => {
+                self.deactivate_stake_instructions(deactivate)
+            },
+            ProtoTransactionType::deactivate_all_stake_transaction(ref deactivate_all) => {
+                self.deactivate_all_stake_instructions(deactivate_all)
+            },
+            ProtoTransactionType::withdraw_transaction(ref withdraw) => {
+                self.withdraw_stake_instructions(withdraw)
+            },
+            ProtoTransactionType::withdraw_all_transaction(ref withdraw_all) => {
+                self.withdraw_all_stake_instructions(withdraw_all)
+            },
+            ProtoTransactionType::create_token_account_transaction(ref create_token_acc) => {
+                self.create_token_account_instructions(create_token_acc)
+            },
+            ProtoTransactionType::token_transfer_transaction(ref token_transfer) => {
+                self.token_transfer_instructions(token_transfer)
+            },
+            ProtoTransactionType::create_and_transfer_token_transaction(
+                ref create_and_transfer,
+            ) => self.create_and_transfer_token_instructions(create_and_transfer),
+            ProtoTransactionType::create_nonce_account(ref create_nonce) => {
+                self.create_nonce_account_instructions(create_nonce)
+            },
+            ProtoTransactionType::withdraw_nonce_account(ref withdraw_nonce) => {
+                self.withdraw_nonce_account_instructions(withdraw_nonce)
+            },
+            ProtoTransactionType::advance_nonce_account(ref advance_nonce) => {
+                self.advance_nonce_account_instructions(advance_nonce)
+            },
+            ProtoTransactionType::raw_message(_) | ProtoTransactionType::None => {
+                Err(SigningError(SigningErrorType::Error_invalid_params))
+            },
+        }
+    }
+
+    fn transfer_instructions(&self, transfer: &Proto::Transfer) -> SigningResult<Vec<Instruction>> {
+        let from = self.signer_address()?;
+        let to = SolanaAddress::from_str(&transfer.recipient)?;
+        let references = Self::parse_addresses(&transfer.references)?;
+
+        let transfer_ix = SystemInstructionBuilder::transfer(from, to, transfer.value)
+            .with_references(references);
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, from)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .maybe_memo(&transfer.memo)
+            .add_instruction(transfer_ix);
+        Ok(builder.output())
+    }
+
+    fn delegate_stake_instructions(
+        &self,
+        delegate: &Proto::DelegateStake,
+    ) -> SigningResult<Vec<Instruction>> {
+        let sender = self.signer_address()?;
+        let validator = SolanaAddress::from_str(&delegate.validator_pubkey)?;
+        let recent_blockhash = self.recent_blockhash()?;
+
+        let stake_account = match parse_optional_address(&delegate.stake_account)? {
+            Some(stake_account) => stake_account,
+            // No stake account specified, generate a new unique one.
+            None => StakeProgram::address_from_recent_blockhash(
+                sender,
+                recent_blockhash,
+                SolanaAddress::from(STAKE_PROGRAM_ID_ADDRESS),
+            ),
+        };
+        let seed = StakeProgram::seed_from_recent_blockhash(recent_blockhash);
+
+        let deposit_ixs = StakeInstructionBuilder::deposit_stake(
+            sender,
+            stake_account,
+            validator,
+            &seed,
+            delegate.value,
+        );
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, sender)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instructions(deposit_ixs);
+        Ok(builder.output())
+    }
+
+    fn deactivate_stake_instructions(
+        &self,
+        deactivate: &Proto::DeactivateStake,
+    ) -> SigningResult<Vec<Instruction>> {
+        let sender = self.signer_address()?;
+        let stake_account = SolanaAddress::from_str(&deactivate.stake_account)?;
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, sender)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(StakeInstructionBuilder::deactivate(stake_account, sender));
+        Ok(builder.output())
+    }
+
+    fn deactivate_all_stake_instructions(
+        &self,
+        deactivate_all: &Proto::DeactivateAllStake,
+    ) -> SigningResult<Vec<Instruction>> {
+        let sender = self.signer_address()?;
+        let deactivate_ixs = deactivate_all
+            .stake_accounts
+            .iter()
+            .map(|stake_account| {
+                let stake_account = SolanaAddress::from_str(stake_account)?;
+                Ok(StakeInstructionBuilder::deactivate(stake_account, sender))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, sender)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instructions(deactivate_ixs);
+        Ok(builder.output())
+    }
+
+    fn withdraw_stake_instructions(
+        &self,
+        withdraw: &Proto::WithdrawStake,
+    ) -> SigningResult<Vec<Instruction>> {
+        let sender = self.signer_address()?;
+        let stake_account = SolanaAddress::from_str(&withdraw.stake_account)?;
+
+        let withdraw_ix =
+            StakeInstructionBuilder::withdraw(stake_account, sender, sender, withdraw.value);
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, sender)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(withdraw_ix);
+        Ok(builder.output())
+    }
+
+    fn withdraw_all_stake_instructions(
+        &self,
+        withdraw_all: &Proto::WithdrawAllStake,
+    ) -> SigningResult<Vec<Instruction>> {
+        let sender = self.signer_address()?;
+        let withdraw_ixs = withdraw_all
+            .stake_accounts
+            .iter()
+            .map(|withdraw| {
+                let stake_account = SolanaAddress::from_str(&withdraw.stake_account)?;
+                Ok(StakeInstructionBuilder::withdraw(
+                    stake_account,
+                    sender,
+                    sender,
+                    withdraw.value,
+                ))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, sender)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instructions(withdraw_ixs);
+        Ok(builder.output())
+    }
+
+    fn create_token_account_instructions(
+        &self,
+        create_token_acc: &Proto::CreateTokenAccount,
+    ) -> SigningResult<Vec<Instruction>> {
+        let funding_account = self.signer_address()?;
+        let other_main_address = SolanaAddress::from_str(&create_token_acc.main_address)?;
+        let token_mint_address = SolanaAddress::from_str(&create_token_acc.token_mint_address)?;
+        let token_address = SolanaAddress::from_str(&create_token_acc.token_address)?;
+
+        let create_account_ix = TokenInstructionBuilder::create_account(
+            funding_account,
+            other_main_address,
+            token_address,
+            token_mint_address,
+        );
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, funding_account)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(create_account_ix);
+        Ok(builder.output())
+    }
+
+    fn token_transfer_instructions(
+        &self,
+        token_transfer: &Proto::TokenTransfer,
+    ) -> SigningResult<Vec<Instruction>> {
+        let signer = self.signer_address()?;
+        let token_mint_address = SolanaAddress::from_str(&token_transfer.token_mint_address)?;
+        let sender_token_address = SolanaAddress::from_str(&token_transfer.sender_token_address)?;
+        let recipient_token_address =
+            SolanaAddress::from_str(&token_transfer.recipient_token_address)?;
+        let decimals = Self::u8_param(token_transfer.decimals)?;
+        let references = Self::parse_addresses(&token_transfer.references)?;
+
+        let transfer_ix = TokenInstructionBuilder::transfer_checked(
+            sender_token_address,
+            token_mint_address,
+            recipient_token_address,
+            signer,
+            token_transfer.amount,
+            decimals,
+        )
+        .with_references(references);
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, signer)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .maybe_memo(&token_transfer.memo)
+            .add_instruction(transfer_ix);
+        Ok(builder.output())
+    }
+
+    fn create_and_transfer_token_instructions(
+        &self,
+        create_and_transfer: &Proto::CreateAndTransferToken,
+    ) -> SigningResult<Vec<Instruction>> {
+        let signer = self.signer_address()?;
+        let fee_payer = self.fee_payer()?;
+        let recipient_main_address =
+            SolanaAddress::from_str(&create_and_transfer.recipient_main_address)?;
+        let token_mint_address = SolanaAddress::from_str(&create_and_transfer.token_mint_address)?;
+        let recipient_token_address =
+            SolanaAddress::from_str(&create_and_transfer.recipient_token_address)?;
+        let sender_token_address =
+            SolanaAddress::from_str(&create_and_transfer.sender_token_address)?;
+        let decimals = Self::u8_param(create_and_transfer.decimals)?;
+        let references = Self::parse_addresses(&create_and_transfer.references)?;
+
+        // The recipient token account is funded by the external fee payer if it's specified.
+        let funding_account = fee_payer.unwrap_or(signer);
+        let create_account_ix = TokenInstructionBuilder::create_account(
+            funding_account,
+            recipient_main_address,
+            recipient_token_address,
+            token_mint_address,
+        );
+        let transfer_ix = TokenInstructionBuilder::transfer_checked(
+            sender_token_address,
+            token_mint_address,
+            recipient_token_address,
+            signer,
+            create_and_transfer.amount,
+            decimals,
+        )
+        .with_references(references);
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, signer)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(create_account_ix)
+            .maybe_memo(&create_and_transfer.memo)
+            .add_instruction(transfer_ix);
+        Ok(builder.output())
+    }
+
+    fn create_nonce_account_instructions(
+        &self,
+        create_nonce: &Proto::CreateNonceAccount,
+    ) -> SigningResult<Vec<Instruction>> {
+        let signer = self.signer_address()?;
+
+        let new_nonce_account = if create_nonce.nonce_account_private_key.is_empty() {
+            SolanaAddress::from_str(&create_nonce.nonce_account)?
+        } else {
+            let nonce_key = ed25519::sha512::KeyPair::try_from(
+                create_nonce.nonce_account_private_key.as_ref(),
+            )?;
+            SolanaAddress::with_public_key_ed25519(nonce_key.public())
+        };
+
+        let create_account_ix = SystemInstructionBuilder::create_account(
+            signer,
+            new_nonce_account,
+            create_nonce.rent,
+            NONCE_ACCOUNT_SPACE,
+            SolanaAddress::from(SYSTEM_PROGRAM_ID_ADDRESS),
+        );
+        let initialize_nonce_ix =
+            SystemInstructionBuilder::initialize_nonce_account(new_nonce_account, signer);
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, signer)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(create_account_ix)
+            .add_instruction(initialize_nonce_ix);
+        Ok(builder.output())
+    }
+
+    fn withdraw_nonce_account_instructions(
+        &self,
+        withdraw_nonce: &Proto::WithdrawNonceAccount,
+    ) -> SigningResult<Vec<Instruction>> {
+        let signer = self.signer_address()?;
+        let withdraw_from_nonce = SolanaAddress::from_str(&withdraw_nonce.nonce_account)?;
+        let recipient = SolanaAddress::from_str(&withdraw_nonce.recipient)?;
+
+        let withdraw_ix = SystemInstructionBuilder::withdraw_nonce_account(
+            withdraw_from_nonce,
+            signer,
+            recipient,
+            withdraw_nonce.value,
+        );
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, signer)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(withdraw_ix);
+        Ok(builder.output())
+    }
+
+    fn advance_nonce_account_instructions(
+        &self,
+        advance_nonce: &Proto::AdvanceNonceAccount,
+    ) -> SigningResult<Vec<Instruction>> {
+        let signer = self.signer_address()?;
+        let nonce_account = SolanaAddress::from_str(&advance_nonce.nonce_account)?;
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .add_instruction(SystemInstructionBuilder::advance_nonce_account(
+                nonce_account,
+                signer,
+            ))
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit());
+        Ok(builder.output())
+    }
+
+    fn build_from_raw_message(
+        &self,
+        raw_message: &Proto::RawMessage,
+    ) -> SigningResult<VersionedMessage> {
+        let mut message = Self::message_from_raw(raw_message)?;
+
+        if !self.input.recent_blockhash.is_empty() {
+            message.set_recent_blockhash(self.recent_blockhash()?);
+        }
+
+        // Each instruction is inserted at the beginning, so the limit goes after the price.
+        let mut modifier = MessageModifier::new(&mut message);
+        if let Some(limit) = self.priority_fee_limit() {
+            modifier.set_compute_unit_limit(limit)?;
+        }
+        if let Some(price) = self.priority_fee_price() {
+            modifier.set_compute_unit_price(price)?;
+        }
+
+        Ok(message)
+    }
+
+    fn message_from_raw(raw_message: &Proto::RawMessage) -> SigningResult<VersionedMessage> {
+        let message = match raw_message.message {
+            ProtoMessageType::legacy(ref legacy) => VersionedMessage::Legacy(legacy::Message {
+                header: Self::header_from_raw(legacy.header.as_ref())?,
+                account_keys: Self::parse_addresses(&legacy.account_keys)?,
+                recent_blockhash: Blockhash::from_str(&legacy.recent_blockhash)?,
+                instructions: Self::instructions_from_raw(&legacy.instructions)?,
+            }),
+            ProtoMessageType::v0(ref v0) => VersionedMessage::V0(v0::Message {
+                header: Self::header_from_raw(v0.header.as_ref())?,
+                account_keys: Self::parse_addresses(&v0.account_keys)?,
+                recent_blockhash: Blockhash::from_str(&v0.recent_blockhash)?,
+                instructions: Self::instructions_from_raw(&v0.instructions)?,
+                address_table_lookups: v0
+                    .address_table_lookups
+                    .iter()
+                    .map(Self::lookup_from_raw)
+                    .collect::<SigningResult<Vec<_>>>()?,
+            }),
+            ProtoMessageType::None => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params))
+            },
+        };
+
+        let num_required_signatures = message.header().num_required_signatures as usize;
+        if num_required_signatures > message.account_keys().len() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        Ok(message)
+    }
+
+    fn header_from_raw(
+        header: Option<&Proto::mod_RawMessage::MessageHeader>,
+    ) -> SigningResult<MessageHeader> {
+        let header = header.ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        Ok(MessageHeader {
+            num_required_signatures: Self::u8_param(header.num_required_signatures)?,
+            num_readonly_signed_accounts: Self::u8_param(header.num_readonly_signed_accounts)?,
+            num_readonly_unsigned_accounts: Self::u8_param(header.num_readonly_unsigned_accounts)?,
+        })
+    }
+
+    fn instructions_from_raw(
+        instructions: &[Proto::mod_RawMessage::Instruction],
+    ) -> SigningResult<Vec<CompiledInstruction>> {
+        instructions
+            .iter()
+            .map(|instruction| {
+                Ok(CompiledInstruction {
+                    program_id_index: Self::u8_param(instruction.program_id)?,
+                    accounts: instruction.accounts.to_vec(),
+                    data: instruction.program_data.to_vec(),
+                })
+            })
+            .collect()
+    }
+
+    fn lookup_from_raw(
+        lookup: &Proto::mod_RawMessage::MessageAddressTableLookup,
+    ) -> SigningResult<v0::MessageAddressTableLookup> {
+        Ok(v0::MessageAddressTableLookup {
+            account_key: SolanaAddress::from_str(&lookup.account_key)?,
+            writable_indexes: lookup.writable_indexes.to_vec(),
+            readonly_indexes: lookup.readonly_indexes.to_vec(),
+        })
+    }
+
+    /// Returns the address of the main signer.
+    /// The address is derived from the private key if it's specified, otherwise `sender` is used.
+    fn signer_address(&self) -> SigningResult<SolanaAddress> {
+        if self.input.private_key.is_empty() {
+            SolanaAddress::from_str(&self.input.sender).map_err(SigningError::from)
+        } else {
+            let key_pair = ed25519::sha512::KeyPair::try_from(self.input.private_key.as_ref())?;
+            Ok(SolanaAddress::with_public_key_ed25519(key_pair.public()))
+        }
+    }
+
+    /// Returns an external fee payer if it's specified.
+    fn fee_payer(&self) -> SigningResult<Option<SolanaAddress>> {
+        if let Some(fee_payer) = parse_optional_address(&self.input.fee_payer)? {
+            return Ok(Some(fee_payer));
+        }
+        if self.input.fee_payer_private_key.is_empty() {
+            return Ok(None);
+        }
+        let key_pair =
+            ed25519::sha512::KeyPair::try_from(self.input.fee_payer_private_key.as_ref())?;
+        Ok(Some(SolanaAddress::with_public_key_ed25519(
+            key_pair.public(),
+        )))
+    }
+
+    fn nonce_account(&self) -> SigningResult<Option<SolanaAddress>> {
+        parse_optional_address(&self.input.nonce_account).map_err(SigningError::from)
+    }
+
+    fn recent_blockhash(&self) -> SigningResult<Blockhash> {
+        Blockhash::from_str(&self.input.recent_blockhash)
+    }
+
+    fn priority_fee_price(&self) -> Option<u64> {
+        self.input
+            .priority_fee_price
+            .as_ref()
+            .map(|fee_price| fee_price.price)
+    }
+
+    fn priority_fee_limit(&self) -> Option<u32> {
+        self.input
+            .priority_fee_limit
+            .as_ref()
+            .map(|fee_limit| fee_limit.limit)
+    }
+
+    fn parse_addresses<S: AsRef<str>>(addresses: &[S]) -> SigningResult<Vec<SolanaAddress>> {
+        addresses
+            .iter()
+            .map(|address| SolanaAddress::from_str(address.as_ref()).map_err(SigningError::from))
+            .collect()
+    }
+
+    fn u8_param(value: u32) -> SigningResult<u8> {
+        u8::try_from(value).map_err(|_| SigningError(SigningErrorType::Error_invalid_params))
+    }
+}
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::{COMPUTE_BUDGET_PROGRAM_ID_ADDRESS, SYSTEM_PROGRAM_ID_ADDRESS};
+use crate::modules::instruction_builder::compute_budget_instruction::{
+    ComputeBudgetInstruction, ComputeBudgetInstructionBuilder,
+};
+use crate::modules::instruction_builder::system_instruction::SystemInstruction;
+use crate::transaction::versioned::VersionedMessage;
+use crate::transaction::CompiledInstruction;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_memory::Data;
+
+/// Changes a compiled message, e.g. one that has been prepared by a dApp.
+pub struct MessageModifier<'a> {
+    message: &'a mut VersionedMessage,
+}
+
+impl<'a> MessageModifier<'a> {
+    pub fn new(message: &'a mut VersionedMessage) -> MessageModifier<'a> {
+        MessageModifier { message }
+    }
+
+    /// Replaces the data of an existing `SetComputeUnitPrice` instruction,
+    /// or inserts a new one at the beginning of the message.
+    pub fn set_compute_unit_price(&mut self, price: u64) -> SigningResult<()> {
+        self.set_compute_budget_instruction(
+            ComputeBudgetInstruction::SetComputeUnitPrice,
+            ComputeBudgetInstructionBuilder::set_compute_unit_price_data(price),
+        )
+    }
+
+    /// Replaces the data of an existing `SetComputeUnitLimit` instruction,
+    /// or inserts a new one at the beginning of the message.
+    pub fn set_compute_unit_limit(&mut self, limit: u32) -> SigningResult<()> {
+        self.set_compute_budget_instruction(
+            ComputeBudgetInstruction::SetComputeUnitLimit,
+            ComputeBudgetInstructionBuilder::set_compute_unit_limit_data(limit),
+        )
+    }
+
+    fn set_compute_budget_instruction(
+        &mut self,
+        instruction_type: ComputeBudgetInstruction,
+        data: Data,
+    ) -> SigningResult<()> {
+        let program_id = SolanaAddress::from(COMPUTE_BUDGET_PROGRAM_ID_ADDRESS);
+        let existing_program_index = self.account_index(&program_id);
+
+        if let Some(program_index) = existing_program_index {
+            let existing_instruction =
+                self.message
+                    .instructions_mut()
+                    .iter_mut()
+                    .find(|instruction| {
+                        instruction.program_id_index == program_index
+                            && instruction.data.first() == Some(&instruction_type.discriminant())
+                    });
+            if let Some(instruction) = existing_instruction {
+                instruction.data = data;
+                return Ok(());
+            }
+        }
+
+        let program_id_index = match existing_program_index {
+            Some(program_index) => program_index,
+            None => self
+                .message
+                .push_readonly_unsigned_account(program_id)
+                .ok_or(SigningError(SigningErrorType::Error_tx_too_big))?,
+        };
+
+        // `AdvanceNonceAccount` must remain the first instruction of a durable transaction.
+        let position = if self.starts_with_advance_nonce() {
+            1
+        } else {
+            0
+        };
+        self.message.instructions_mut().insert(
+            position,
+            CompiledInstruction {
+                program_id_index,
+                accounts: Vec::default(),
+                data,
+            },
+        );
+        Ok(())
+    }
+
+    fn starts_with_advance_nonce(&self) -> bool {
+        let Some(first) = self.message.instructions().first() else {
+            return false;
+        };
+        let system_program = SolanaAddress::from(SYSTEM_PROGRAM_ID_ADDRESS);
+        self.account_index(&system_program) == Some(first.program_id_index)
+            && first.data == SystemInstruction::AdvanceNonceAccount.encode()
+    }
+
+    /// Returns an index of the given `account` within the static account keys.
+    fn account_index(&self, account: &SolanaAddress) -> Option<u8> {
+        let index = self
+            .message
+            .account_keys()
+            .iter()
+            .position(|key| key == account)?;
+        u8::try_from(index).ok()
+    }
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base58::{self, Alphabet};
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+use tw_proto::Solana::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// Signs and verifies arbitrary off-chain messages with an `ed25519` key.
+#[derive(Default)]
+pub struct SolanaMessageSigner;
+
+impl MessageSigner for SolanaMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        // Ed25519 signs the message itself rather than its hash.
+        let message = input.message.as_bytes().to_vec();
+        CompilerProto::PreSigningOutput {
+            data: Cow::Owned(message.clone()),
+            data_hash: Cow::Owned(message),
+            ..CompilerProto::PreSigningOutput::default()
+        }
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl SolanaMessageSigner {
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let signature = key_pair.private().sign(input.message.as_bytes().to_vec())?;
+
+        let signature = base58::encode(signature.to_bytes().as_slice(), Alphabet::BITCOIN);
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(signature),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())?;
+        let signature = base58::decode(&input.signature, Alphabet::BITCOIN)?;
+        let signature = ed25519::Signature::try_from(signature.as_slice())?;
+        Ok(public_key.verify(signature, input.message.as_bytes().to_vec()))
+    }
+}
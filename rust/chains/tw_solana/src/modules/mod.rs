@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod compiled_instructions;
+pub mod compiled_keys;
+pub mod decoder;
+pub mod instruction_builder;
+pub mod instruction_decoder;
+pub mod message_builder;
+pub mod message_modifier;
+pub mod message_signer;
+pub mod proto_builder;
+pub mod tx_signer;
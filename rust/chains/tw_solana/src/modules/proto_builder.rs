@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::versioned::{VersionedMessage, VersionedTransaction};
+use crate::transaction::{v0, CompiledInstruction, MessageHeader};
+use std::borrow::Cow;
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::H512;
+use tw_proto::Solana::Proto;
+use Proto::mod_RawMessage::OneOfmessage as ProtoMessageType;
+
+/// Converts transactions into their Protobuf representation.
+pub struct ProtoBuilder;
+
+impl ProtoBuilder {
+    pub fn raw_message_from_tx(tx: &VersionedTransaction) -> Proto::RawMessage<'static> {
+        Proto::RawMessage {
+            signatures: Self::pubkey_signatures(tx),
+            message: Self::message_to_proto(&tx.message),
+        }
+    }
+
+    /// Returns the signatures that have been made, along with their public keys.
+    pub fn pubkey_signatures(tx: &VersionedTransaction) -> Vec<Proto::PubkeySignature<'static>> {
+        let not_signed = H512::default();
+        tx.message
+            .signers()
+            .iter()
+            .zip(tx.signatures.iter())
+            .filter(|(_, signature)| **signature != not_signed)
+            .map(|(pubkey, signature)| Proto::PubkeySignature {
+                pubkey: Cow::from(pubkey.to_string()),
+                signature: Cow::from(base58::encode(signature.as_slice(), Alphabet::BITCOIN)),
+            })
+            .collect()
+    }
+
+    fn message_to_proto(message: &VersionedMessage) -> ProtoMessageType<'static> {
+        let account_keys = message
+            .account_keys()
+            .iter()
+            .map(|account| Cow::from(account.to_string()))
+            .collect();
+        let header = Some(Self::header_to_proto(message.header()));
+        let recent_blockhash = Cow::from(message.recent_blockhash().to_string());
+        let instructions = message
+            .instructions()
+            .iter()
+            .map(Self::instruction_to_proto)
+            .collect();
+
+        match message {
+            VersionedMessage::Legacy(_) => {
+                ProtoMessageType::legacy(Proto::mod_RawMessage::MessageLegacy {
+                    header,
+                    account_keys,
+                    recent_blockhash,
+                    instructions,
+                })
+            },
+            VersionedMessage::V0(v0) => ProtoMessageType::v0(Proto::mod_RawMessage::MessageV0 {
+                header,
+                account_keys,
+                recent_blockhash,
+                instructions,
+                address_table_lookups: v0
+                    .address_table_lookups
+                    .iter()
+                    .map(Self::lookup_to_proto)
+                    .collect(),
+            }),
+        }
+    }
+
+    fn header_to_proto(header: &MessageHeader) -> Proto::mod_RawMessage::MessageHeader {
+        Proto::mod_RawMessage::MessageHeader {
+            num_required_signatures: header.num_required_signatures as u32,
+            num_readonly_signed_accounts: header.num_readonly_signed_accounts as u32,
+            num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts as u32,
+        }
+    }
+
+    fn instruction_to_proto(
+        instruction: &CompiledInstruction,
+    ) -> Proto::mod_RawMessage::Instruction<'static> {
+        Proto::mod_RawMessage::Instruction {
+            program_id: instruction.program_id_index as u32,
+            accounts: Cow::from(instruction.accounts.clone()),
+            program_data: Cow::from(instruction.data.clone()),
+        }
+    }
+
+    fn lookup_to_proto(
+        lookup: &v0::MessageAddressTableLookup,
+    ) -> Proto::mod_RawMessage::MessageAddressTableLookup<'static> {
+        Proto::mod_RawMessage::MessageAddressTableLookup {
+            account_key: Cow::from(lookup.account_key.to_string()),
+            writable_indexes: Cow::from(lookup.writable_indexes.clone()),
+            readonly_indexes: Cow::from(lookup.readonly_indexes.clone()),
+        }
+    }
+}
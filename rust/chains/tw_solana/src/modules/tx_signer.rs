@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::transaction::versioned::{VersionedMessage, VersionedTransaction};
+use std::collections::HashMap;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_hash::H512;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+
+pub struct TxSigner;
+
+impl TxSigner {
+    /// Signs the given `message` with each of the `keys`, and adds the `external_signatures` made earlier.
+    /// Every key must correspond to a signer of the message.
+    pub fn sign_versioned(
+        message: VersionedMessage,
+        keys: &[ed25519::sha512::KeyPair],
+        external_signatures: HashMap<SolanaAddress, H512>,
+    ) -> SigningResult<VersionedTransaction> {
+        let message_encoded = message.serialize();
+
+        let mut key_signs = external_signatures;
+        for key in keys {
+            let signature = key.sign(message_encoded.clone())?;
+            let address = SolanaAddress::with_public_key_ed25519(key.public());
+            key_signs.insert(address, signature.to_bytes());
+        }
+
+        Self::compile_versioned(message, key_signs)
+    }
+
+    /// Places the given `key_signs` at the positions of the corresponding message signers.
+    /// Please note the transaction may be signed partially.
+    pub fn compile_versioned(
+        message: VersionedMessage,
+        key_signs: HashMap<SolanaAddress, H512>,
+    ) -> SigningResult<VersionedTransaction> {
+        let mut tx = VersionedTransaction::unsigned(message);
+
+        for (signing_pubkey, ed25519_signature) in key_signs {
+            let index = tx
+                .message
+                .signer_index(&signing_pubkey)
+                .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+            tx.signatures[index] = ed25519_signature;
+        }
+
+        Ok(tx)
+    }
+
+    /// Makes sure that every signer has signed the transaction.
+    pub fn check_fully_signed(tx: &VersionedTransaction) -> SigningResult<()> {
+        let not_signed = H512::default();
+        if tx.signatures.contains(&not_signed) {
+            return Err(SigningError(SigningErrorType::Error_signatures_count));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::blockhash::Blockhash;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_memory::Data;
+
+/// The length of a seed used to derive a stake account address.
+const SEED_LEN: usize = 32;
+
+pub struct StakeProgram;
+
+impl StakeProgram {
+    /// Derives a unique stake account address from the `from` account and a recent blockhash.
+    /// The address is equal to `sha256(from || seed || program_id)`.
+    pub fn address_from_recent_blockhash(
+        from: SolanaAddress,
+        recent_blockhash: Blockhash,
+        program_id: SolanaAddress,
+    ) -> SolanaAddress {
+        let seed = Self::seed_from_recent_blockhash(recent_blockhash);
+
+        let mut extended = Data::with_capacity(SolanaAddress::LEN * 2 + SEED_LEN);
+        extended.extend_from_slice(from.as_slice());
+        extended.extend_from_slice(&seed);
+        extended.extend_from_slice(program_id.as_slice());
+
+        let hash = H256::try_from(sha256(&extended).as_slice())
+            .expect("sha256 must return exactly 32 bytes");
+        SolanaAddress::with_public_key_bytes(hash)
+    }
+
+    /// Returns the Base58-encoded `recent_blockhash` resized to 32 bytes.
+    pub fn seed_from_recent_blockhash(recent_blockhash: Blockhash) -> Data {
+        let mut seed = recent_blockhash.to_string().into_bytes();
+        seed.resize(SEED_LEN, 0);
+        seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::defined_addresses::STAKE_PROGRAM_ID_ADDRESS;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_address_from_recent_blockhash() {
+        let user = SolanaAddress::from("zVSpQnbBZ7dyUWzXhrUQRsTYYNzoAdJWHsHSqhPj3Xu");
+        let program_id = SolanaAddress::from(STAKE_PROGRAM_ID_ADDRESS);
+
+        let blockhash = Blockhash::from_str("11111111111111111111111111111111").unwrap();
+        let actual = StakeProgram::address_from_recent_blockhash(user, blockhash, program_id);
+        assert_eq!(
+            actual.to_string(),
+            "GQDDc5EVGJZFC7AvpEJ8eoCQ75Yy4gr7eu17frCjvQRQ"
+        );
+
+        let blockhash =
+            Blockhash::from_str("9ipJh5xfyoyDaiq8trtrdqQeAhQbQkWy2eANizKvx75K").unwrap();
+        let actual = StakeProgram::address_from_recent_blockhash(user, blockhash, program_id);
+        assert_eq!(
+            actual.to_string(),
+            "2Kos1xJRBq3Ae1GnVNBx7HgJhq8KvdUe2bXE4QGdNaXb"
+        );
+    }
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::{ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS, TOKEN_PROGRAM_ID_ADDRESS};
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_keypair::ed25519;
+use tw_memory::Data;
+
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+pub struct TokenProgram;
+
+impl TokenProgram {
+    /// Returns the associated token account address of the `main_address` for the given token mint.
+    /// https://github.com/solana-labs/solana-program-library/blob/master/associated-token-account/program/src/lib.rs
+    pub fn associated_token_address(
+        main_address: SolanaAddress,
+        token_mint_address: SolanaAddress,
+    ) -> Option<SolanaAddress> {
+        let token_program_id = SolanaAddress::from(TOKEN_PROGRAM_ID_ADDRESS);
+        let seeds = [
+            main_address.as_slice(),
+            token_program_id.as_slice(),
+            token_mint_address.as_slice(),
+        ];
+        Self::find_program_address(
+            &seeds,
+            SolanaAddress::from(ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS),
+        )
+    }
+
+    /// Finds a valid program derived address, trying bump seeds from 255 down to 0.
+    /// https://github.com/solana-labs/solana/blob/master/sdk/program/src/pubkey.rs
+    pub fn find_program_address(
+        seeds: &[&[u8]],
+        program_id: SolanaAddress,
+    ) -> Option<SolanaAddress> {
+        (0..=u8::MAX).rev().find_map(|bump| {
+            let bump = [bump];
+            let seeds_with_bump: Vec<&[u8]> = seeds
+                .iter()
+                .copied()
+                .chain(std::iter::once(&bump[..]))
+                .collect();
+
+            let address = Self::create_program_address(&seeds_with_bump, program_id);
+            // A program derived address must not lie on the ed25519 curve.
+            let on_curve = ed25519::sha512::PublicKey::try_from(address.as_slice()).is_ok();
+            (!on_curve).then_some(address)
+        })
+    }
+
+    /// Creates a program address as `sha256(seeds || program_id || "ProgramDerivedAddress")`.
+    pub fn create_program_address(seeds: &[&[u8]], program_id: SolanaAddress) -> SolanaAddress {
+        let mut hash_input = Data::new();
+        for seed in seeds {
+            hash_input.extend_from_slice(seed);
+        }
+        hash_input.extend_from_slice(program_id.as_slice());
+        hash_input.extend_from_slice(PDA_MARKER);
+
+        let hash = H256::try_from(sha256(&hash_input).as_slice())
+            .expect("sha256 must return exactly 32 bytes");
+        SolanaAddress::with_public_key_bytes(hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::defined_addresses::MEMO_PROGRAM_ID_ADDRESS;
+
+    #[test]
+    fn test_associated_token_address() {
+        let main_address = SolanaAddress::from("HBYC51YrGFAZ8rM7Sj8e9uqKggpSrDYrinQDZzvMtqQp");
+        let serum_token = SolanaAddress::from("SRMuApVNdxXokk5GT7XD5cUUgXMBCoAz2LHeuAoKWRt");
+        let actual = TokenProgram::associated_token_address(main_address, serum_token).unwrap();
+        assert_eq!(
+            actual.to_string(),
+            "6X4X1Ae24mkoWeCEpktevySVG9jzeCufut5vtUW3wFrD"
+        );
+    }
+
+    #[test]
+    fn test_find_program_address() {
+        let main_address = SolanaAddress::from("B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V");
+        let token_program_id = SolanaAddress::from(TOKEN_PROGRAM_ID_ADDRESS);
+        let serum_token = SolanaAddress::from("SRMuApVNdxXokk5GT7XD5cUUgXMBCoAz2LHeuAoKWRt");
+        let seeds = [
+            main_address.as_slice(),
+            token_program_id.as_slice(),
+            serum_token.as_slice(),
+        ];
+
+        let actual = TokenProgram::find_program_address(
+            &seeds,
+            SolanaAddress::from(ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS),
+        )
+        .unwrap();
+        assert_eq!(
+            actual.to_string(),
+            "EDNd1ycsydWYwVmrYZvqYazFqwk1QjBgAUKFjBoz1jKP"
+        );
+
+        let actual = TokenProgram::find_program_address(
+            &seeds,
+            SolanaAddress::from(MEMO_PROGRAM_ID_ADDRESS),
+        )
+        .unwrap();
+        assert_eq!(
+            actual.to_string(),
+            "CuS1kE1wvGTmwGk3FYNQK85g4jU7gMySWwFRQQ9LFunp"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::message_builder::MessageBuilder;
+use crate::modules::proto_builder::ProtoBuilder;
+use crate::modules::tx_signer::TxSigner;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base58::{self, Alphabet};
+use tw_proto::Solana::Proto;
+
+pub struct SolanaSigner;
+
+impl SolanaSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let builder = MessageBuilder::new(input);
+        let signing_keys = builder.signing_keys()?;
+        let external_signatures = builder.external_signatures()?;
+        let unsigned_msg = builder.build()?;
+
+        let encoded_unsigned = unsigned_msg.serialize();
+        let encoded_unsigned = base58::encode(&encoded_unsigned, Alphabet::BITCOIN);
+
+        let signed_tx = TxSigner::sign_versioned(unsigned_msg, &signing_keys, external_signatures)?;
+        if !builder.allows_partial_signing() {
+            TxSigner::check_fully_signed(&signed_tx)?;
+        }
+
+        Ok(Proto::SigningOutput {
+            encoded: Cow::from(signed_tx.to_base58()),
+            unsigned_tx: Cow::from(encoded_unsigned),
+            signatures: ProtoBuilder::pubkey_signatures(&signed_tx),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::blockhash::Blockhash;
+use crate::transaction::reader::TxReader;
+use crate::transaction::{short_vec, CompiledInstruction, MessageHeader};
+use tw_encoding::EncodingResult;
+use tw_memory::Data;
+
+/// A legacy transaction message.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Message {
+    /// The message header, identifying signed and read-only `account_keys`.
+    pub header: MessageHeader,
+    /// All the account keys used by this transaction.
+    pub account_keys: Vec<SolanaAddress>,
+    /// The id of a recent ledger entry.
+    pub recent_blockhash: Blockhash,
+    /// Programs that will be executed in sequence and committed in one atomic transaction if all succeed.
+    pub instructions: Vec<CompiledInstruction>,
+}
+
+impl Message {
+    pub fn encode(&self, out: &mut Data) {
+        self.header.encode(out);
+
+        short_vec::encode_length(self.account_keys.len(), out);
+        for account_key in self.account_keys.iter() {
+            out.extend_from_slice(account_key.as_slice());
+        }
+
+        out.extend_from_slice(self.recent_blockhash.to_bytes().as_slice());
+
+        short_vec::encode_length(self.instructions.len(), out);
+        for instruction in self.instructions.iter() {
+            instruction.encode(out);
+        }
+    }
+
+    pub fn decode(reader: &mut TxReader) -> EncodingResult<Message> {
+        Ok(Message {
+            header: MessageHeader::decode(reader)?,
+            account_keys: reader.read_short_vec(TxReader::read_address)?,
+            recent_blockhash: Blockhash::with_bytes(reader.read_h256()?),
+            instructions: reader.read_short_vec(CompiledInstruction::decode)?,
+        })
+    }
+}
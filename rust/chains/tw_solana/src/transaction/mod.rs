@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::reader::TxReader;
+use tw_encoding::EncodingResult;
+use tw_memory::Data;
+
+pub mod legacy;
+pub mod reader;
+pub mod short_vec;
+pub mod v0;
+pub mod versioned;
+
+/// The message header, identifying signed and read-only account keys.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MessageHeader {
+    /// The number of signatures required for this message to be considered valid.
+    /// The signatures must match the first `num_required_signatures` of `account_keys`.
+    pub num_required_signatures: u8,
+    /// The last `num_readonly_signed_accounts` of the signed keys are read-only accounts.
+    pub num_readonly_signed_accounts: u8,
+    /// The last `num_readonly_unsigned_accounts` of the unsigned keys are read-only accounts.
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+impl MessageHeader {
+    pub fn encode(&self, out: &mut Data) {
+        out.push(self.num_required_signatures);
+        out.push(self.num_readonly_signed_accounts);
+        out.push(self.num_readonly_unsigned_accounts);
+    }
+
+    pub fn decode(reader: &mut TxReader) -> EncodingResult<MessageHeader> {
+        Ok(MessageHeader {
+            num_required_signatures: reader.read_u8()?,
+            num_readonly_signed_accounts: reader.read_u8()?,
+            num_readonly_unsigned_accounts: reader.read_u8()?,
+        })
+    }
+}
+
+/// An instruction with its program ID and accounts replaced by indexes into the message account keys.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CompiledInstruction {
+    /// Index into the message account keys indicating the program account that executes this instruction.
+    pub program_id_index: u8,
+    /// Ordered indices into the message account keys indicating which accounts to pass to the program.
+    pub accounts: Vec<u8>,
+    /// The program input data.
+    pub data: Data,
+}
+
+impl CompiledInstruction {
+    pub fn encode(&self, out: &mut Data) {
+        out.push(self.program_id_index);
+        short_vec::encode_bytes(&self.accounts, out);
+        short_vec::encode_bytes(&self.data, out);
+    }
+
+    pub fn decode(reader: &mut TxReader) -> EncodingResult<CompiledInstruction> {
+        Ok(CompiledInstruction {
+            program_id_index: reader.read_u8()?,
+            accounts: reader.read_short_vec_bytes()?.to_vec(),
+            data: reader.read_short_vec_bytes()?.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::transaction::short_vec;
+use tw_encoding::{EncodingError, EncodingResult};
+use tw_hash::{H256, H512};
+
+/// A cursor over a transaction or message in the wire format.
+pub struct TxReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> TxReader<'a> {
+    pub fn new(data: &'a [u8]) -> TxReader<'a> {
+        TxReader { data }
+    }
+
+    /// Returns the next byte without advancing the cursor.
+    pub fn peek_u8(&self) -> EncodingResult<u8> {
+        self.data
+            .first()
+            .copied()
+            .ok_or(EncodingError::InvalidInput)
+    }
+
+    pub fn read_u8(&mut self) -> EncodingResult<u8> {
+        let byte = self.peek_u8()?;
+        self.data = &self.data[1..];
+        Ok(byte)
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> EncodingResult<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(EncodingError::InvalidInput);
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    pub fn read_h256(&mut self) -> EncodingResult<H256> {
+        let bytes = self.read_bytes(H256::len())?;
+        H256::try_from(bytes).map_err(|_| EncodingError::InvalidInput)
+    }
+
+    pub fn read_h512(&mut self) -> EncodingResult<H512> {
+        let bytes = self.read_bytes(H512::len())?;
+        H512::try_from(bytes).map_err(|_| EncodingError::InvalidInput)
+    }
+
+    pub fn read_address(&mut self) -> EncodingResult<SolanaAddress> {
+        self.read_h256().map(SolanaAddress::with_public_key_bytes)
+    }
+
+    /// Reads bytes prefixed with a compact-u16 length.
+    pub fn read_short_vec_bytes(&mut self) -> EncodingResult<&'a [u8]> {
+        let len = short_vec::decode_length(self)?;
+        self.read_bytes(len)
+    }
+
+    /// Reads a vector of items prefixed with a compact-u16 length.
+    pub fn read_short_vec<T, F>(&mut self, mut read_item: F) -> EncodingResult<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> EncodingResult<T>,
+    {
+        let len = short_vec::decode_length(self)?;
+        (0..len).map(|_| read_item(self)).collect()
+    }
+
+    /// Checks that there are no bytes left.
+    pub fn finish(self) -> EncodingResult<()> {
+        if !self.data.is_empty() {
+            return Err(EncodingError::InvalidInput);
+        }
+        Ok(())
+    }
+}
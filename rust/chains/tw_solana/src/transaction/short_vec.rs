@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Compact-u16 length prefixes used to serialize Solana vectors.
+//! https://docs.solana.com/developing/programming-model/transactions#compact-array-format
+
+use crate::transaction::reader::TxReader;
+use tw_encoding::{EncodingError, EncodingResult};
+use tw_memory::Data;
+
+/// The maximum number of bytes a compact-u16 value can take.
+const MAX_ENCODING_LENGTH: usize = 3;
+
+/// Encodes the given `len` as a compact-u16 value.
+pub fn encode_length(len: usize, out: &mut Data) {
+    let mut rem_len = len;
+    loop {
+        let mut elem = (rem_len & 0x7f) as u8;
+        rem_len >>= 7;
+        if rem_len == 0 {
+            out.push(elem);
+            break;
+        }
+        elem |= 0x80;
+        out.push(elem);
+    }
+}
+
+/// Encodes the given `bytes` prefixed with its compact-u16 length.
+pub fn encode_bytes(bytes: &[u8], out: &mut Data) {
+    encode_length(bytes.len(), out);
+    out.extend_from_slice(bytes);
+}
+
+/// Decodes a compact-u16 value.
+/// Alias encodings with redundant trailing zero bytes are not allowed.
+pub fn decode_length(reader: &mut TxReader) -> EncodingResult<usize> {
+    let mut len = 0_usize;
+    for i in 0..MAX_ENCODING_LENGTH {
+        let elem = reader.read_u8()?;
+        len |= ((elem & 0x7f) as usize) << (i * 7);
+
+        if elem & 0x80 == 0 {
+            if i > 0 && elem == 0 {
+                return Err(EncodingError::InvalidInput);
+            }
+            if len > u16::MAX as usize {
+                return Err(EncodingError::InvalidInput);
+            }
+            return Ok(len);
+        }
+    }
+    Err(EncodingError::InvalidInput)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoded_length(len: usize) -> Data {
+        let mut out = Data::new();
+        encode_length(len, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_encode_length() {
+        assert_eq!(encoded_length(0), [0x00]);
+        assert_eq!(encoded_length(5), [0x05]);
+        assert_eq!(encoded_length(0x7f), [0x7f]);
+        assert_eq!(encoded_length(0x80), [0x80, 0x01]);
+        assert_eq!(encoded_length(0xff), [0xff, 0x01]);
+        assert_eq!(encoded_length(0x100), [0x80, 0x02]);
+        assert_eq!(encoded_length(0x7fff), [0xff, 0xff, 0x01]);
+        assert_eq!(encoded_length(0x200000), [0x80, 0x80, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn test_decode_length() {
+        for len in [0, 5, 0x7f, 0x80, 0xff, 0x100, 0x7fff, 0xffff] {
+            let encoded = encoded_length(len);
+            let mut reader = TxReader::new(&encoded);
+            assert_eq!(decode_length(&mut reader), Ok(len));
+            reader.finish().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_decode_length_invalid() {
+        let invalid: [&[u8]; 5] = [
+            // Empty input.
+            &[],
+            // Alias encodings.
+            &[0x80, 0x00],
+            &[0xff, 0x80, 0x00],
+            // Exceeds `u16::MAX`.
+            &[0x80, 0x80, 0x04],
+            // Too long.
+            &[0x80, 0x80, 0x80, 0x01],
+        ];
+        for encoded in invalid {
+            decode_length(&mut TxReader::new(encoded)).unwrap_err();
+        }
+    }
+}
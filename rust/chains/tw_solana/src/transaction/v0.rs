@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::blockhash::Blockhash;
+use crate::transaction::reader::TxReader;
+use crate::transaction::{short_vec, CompiledInstruction, MessageHeader};
+use tw_encoding::EncodingResult;
+use tw_memory::Data;
+
+/// Address table lookups describe an on-chain address lookup table to use
+/// for loading more readonly and writable accounts in a single tx.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MessageAddressTableLookup {
+    /// Address lookup table account key.
+    pub account_key: SolanaAddress,
+    /// List of indexes used to load writable account addresses.
+    pub writable_indexes: Vec<u8>,
+    /// List of indexes used to load readonly account addresses.
+    pub readonly_indexes: Vec<u8>,
+}
+
+impl MessageAddressTableLookup {
+    pub fn encode(&self, out: &mut Data) {
+        out.extend_from_slice(self.account_key.as_slice());
+        short_vec::encode_bytes(&self.writable_indexes, out);
+        short_vec::encode_bytes(&self.readonly_indexes, out);
+    }
+
+    pub fn decode(reader: &mut TxReader) -> EncodingResult<MessageAddressTableLookup> {
+        Ok(MessageAddressTableLookup {
+            account_key: reader.read_address()?,
+            writable_indexes: reader.read_short_vec_bytes()?.to_vec(),
+            readonly_indexes: reader.read_short_vec_bytes()?.to_vec(),
+        })
+    }
+}
+
+/// A versioned transaction message that is able to load accounts from on-chain address lookup tables.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Message {
+    /// The message header, identifying signed and read-only `account_keys`.
+    pub header: MessageHeader,
+    /// List of accounts loaded by this transaction.
+    pub account_keys: Vec<SolanaAddress>,
+    /// The blockhash of a recent block.
+    pub recent_blockhash: Blockhash,
+    /// Instructions that invoke a designated program, are executed in sequence,
+    /// and committed in one atomic transaction if all succeed.
+    pub instructions: Vec<CompiledInstruction>,
+    /// List of address table lookups used to load additional accounts for this transaction.
+    pub address_table_lookups: Vec<MessageAddressTableLookup>,
+}
+
+impl Message {
+    pub fn encode(&self, out: &mut Data) {
+        self.header.encode(out);
+
+        short_vec::encode_length(self.account_keys.len(), out);
+        for account_key in self.account_keys.iter() {
+            out.extend_from_slice(account_key.as_slice());
+        }
+
+        out.extend_from_slice(self.recent_blockhash.to_bytes().as_slice());
+
+        short_vec::encode_length(self.instructions.len(), out);
+        for instruction in self.instructions.iter() {
+            instruction.encode(out);
+        }
+
+        short_vec::encode_length(self.address_table_lookups.len(), out);
+        for lookup in self.address_table_lookups.iter() {
+            lookup.encode(out);
+        }
+    }
+
+    pub fn decode(reader: &mut TxReader) -> EncodingResult<Message> {
+        Ok(Message {
+            header: MessageHeader::decode(reader)?,
+            account_keys: reader.read_short_vec(TxReader::read_address)?,
+            recent_blockhash: Blockhash::with_bytes(reader.read_h256()?),
+            instructions: reader.read_short_vec(CompiledInstruction::decode)?,
+            address_table_lookups: reader.read_short_vec(MessageAddressTableLookup::decode)?,
+        })
+    }
+}
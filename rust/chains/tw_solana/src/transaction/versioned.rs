@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::blockhash::Blockhash;
+use crate::transaction::reader::TxReader;
+use crate::transaction::{legacy, short_vec, v0, CompiledInstruction, MessageHeader};
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::{EncodingError, EncodingResult};
+use tw_hash::H512;
+use tw_memory::Data;
+
+/// Bit mask that indicates whether a serialized message is versioned.
+/// https://github.com/solana-labs/solana/blob/master/sdk/program/src/message/versions/mod.rs#L24
+pub const MESSAGE_VERSION_PREFIX: u8 = 0x80;
+
+/// Either a legacy message or a v0 message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedMessage {
+    Legacy(legacy::Message),
+    V0(v0::Message),
+}
+
+impl VersionedMessage {
+    pub fn header(&self) -> &MessageHeader {
+        match self {
+            VersionedMessage::Legacy(legacy) => &legacy.header,
+            VersionedMessage::V0(v0) => &v0.header,
+        }
+    }
+
+    pub fn account_keys(&self) -> &[SolanaAddress] {
+        match self {
+            VersionedMessage::Legacy(legacy) => &legacy.account_keys,
+            VersionedMessage::V0(v0) => &v0.account_keys,
+        }
+    }
+
+    pub fn recent_blockhash(&self) -> Blockhash {
+        match self {
+            VersionedMessage::Legacy(legacy) => legacy.recent_blockhash,
+            VersionedMessage::V0(v0) => v0.recent_blockhash,
+        }
+    }
+
+    pub fn set_recent_blockhash(&mut self, recent_blockhash: Blockhash) {
+        match self {
+            VersionedMessage::Legacy(legacy) => legacy.recent_blockhash = recent_blockhash,
+            VersionedMessage::V0(v0) => v0.recent_blockhash = recent_blockhash,
+        }
+    }
+
+    pub fn instructions(&self) -> &[CompiledInstruction] {
+        match self {
+            VersionedMessage::Legacy(legacy) => &legacy.instructions,
+            VersionedMessage::V0(v0) => &v0.instructions,
+        }
+    }
+
+    pub fn instructions_mut(&mut self) -> &mut Vec<CompiledInstruction> {
+        match self {
+            VersionedMessage::Legacy(legacy) => &mut legacy.instructions,
+            VersionedMessage::V0(v0) => &mut v0.instructions,
+        }
+    }
+
+    /// Appends the given `account` to the static account keys as a read-only non-signer account.
+    /// Returns the index of the account, or `None` if there are too many accounts already.
+    pub fn push_readonly_unsigned_account(&mut self, account: SolanaAddress) -> Option<u8> {
+        let (header, account_keys, instructions) = match self {
+            VersionedMessage::Legacy(legacy) => (
+                &mut legacy.header,
+                &mut legacy.account_keys,
+                &mut legacy.instructions,
+            ),
+            VersionedMessage::V0(v0) => {
+                (&mut v0.header, &mut v0.account_keys, &mut v0.instructions)
+            },
+        };
+
+        let new_index = u8::try_from(account_keys.len()).ok()?;
+        header.num_readonly_unsigned_accounts =
+            header.num_readonly_unsigned_accounts.checked_add(1)?;
+        account_keys.push(account);
+
+        // Accounts loaded from address lookup tables follow the static account keys,
+        // so their indexes are shifted by the new account.
+        for instruction in instructions.iter_mut() {
+            for account_index in instruction.accounts.iter_mut() {
+                if *account_index >= new_index {
+                    *account_index = account_index.checked_add(1)?;
+                }
+            }
+        }
+        Some(new_index)
+    }
+
+    /// Returns the accounts that are required to sign the message, in the order of signatures.
+    pub fn signers(&self) -> &[SolanaAddress] {
+        let num_required_signatures = self.header().num_required_signatures as usize;
+        let account_keys = self.account_keys();
+        &account_keys[..num_required_signatures.min(account_keys.len())]
+    }
+
+    /// Returns an index of the signature that should be signed by the given `account`,
+    /// or `None` if the account is not required to sign the message.
+    pub fn signer_index(&self, account: &SolanaAddress) -> Option<usize> {
+        self.signers().iter().position(|signer| signer == account)
+    }
+
+    pub fn decode(reader: &mut TxReader) -> EncodingResult<VersionedMessage> {
+        let first_byte = reader.peek_u8()?;
+        // A legacy message starts with `num_required_signatures` that never has the version bit set.
+        if first_byte & MESSAGE_VERSION_PREFIX == 0 {
+            return legacy::Message::decode(reader).map(VersionedMessage::Legacy);
+        }
+
+        reader.read_u8()?;
+        match first_byte & !MESSAGE_VERSION_PREFIX {
+            0 => v0::Message::decode(reader).map(VersionedMessage::V0),
+            // Other message versions are not supported.
+            _ => Err(EncodingError::InvalidInput),
+        }
+    }
+
+    pub fn serialize(&self) -> Data {
+        let mut out = Data::new();
+        match self {
+            VersionedMessage::Legacy(legacy) => legacy.encode(&mut out),
+            VersionedMessage::V0(v0) => {
+                out.push(MESSAGE_VERSION_PREFIX);
+                v0.encode(&mut out);
+            },
+        }
+        out
+    }
+}
+
+/// A transaction that consists of a versioned message and its signatures.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionedTransaction {
+    /// List of signatures. The order matches the first `num_required_signatures` of the message account keys.
+    pub signatures: Vec<H512>,
+    /// Message to sign.
+    pub message: VersionedMessage,
+}
+
+impl VersionedTransaction {
+    /// Creates a transaction with the given `message` and zeroed signatures.
+    pub fn unsigned(message: VersionedMessage) -> VersionedTransaction {
+        let num_required_signatures = message.header().num_required_signatures as usize;
+        VersionedTransaction {
+            signatures: vec![H512::default(); num_required_signatures],
+            message,
+        }
+    }
+
+    /// Decodes a transaction in the wire format.
+    pub fn deserialize(data: &[u8]) -> EncodingResult<VersionedTransaction> {
+        let mut reader = TxReader::new(data);
+        let signatures = reader.read_short_vec(TxReader::read_h512)?;
+        let message = VersionedMessage::decode(&mut reader)?;
+        reader.finish()?;
+
+        if signatures.len() != message.header().num_required_signatures as usize {
+            return Err(EncodingError::InvalidInput);
+        }
+        Ok(VersionedTransaction {
+            signatures,
+            message,
+        })
+    }
+
+    pub fn serialize(&self) -> Data {
+        let mut out = Data::new();
+        short_vec::encode_length(self.signatures.len(), &mut out);
+        for signature in self.signatures.iter() {
+            out.extend_from_slice(signature.as_slice());
+        }
+        out.extend(self.message.serialize());
+        out
+    }
+
+    /// Returns the Base58-encoded transaction.
+    pub fn to_base58(&self) -> String {
+        base58::encode(&self.serialize(), Alphabet::BITCOIN)
+    }
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::signature::SignatureScheme;
+use move_core_types::account_address::AccountAddress;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::hex;
+use tw_hash::blake2::blake2_b;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_memory::Data;
+
+/// Sui address or object ID: 32 bytes, serialized as is.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub struct SuiAddress(AccountAddress);
+
+impl SuiAddress {
+    pub const LENGTH: usize = AccountAddress::LENGTH;
+
+    pub const fn new(bytes: [u8; Self::LENGTH]) -> SuiAddress {
+        SuiAddress(AccountAddress::new(bytes))
+    }
+
+    /// Initializes an address with an `ed25519` public key.
+    pub fn with_ed25519_pubkey(pubkey: &ed25519::sha512::PublicKey) -> AddressResult<SuiAddress> {
+        Self::with_scheme(SignatureScheme::Ed25519, pubkey.as_slice())
+    }
+
+    /// Initializes an address with a compressed `secp256k1` public key.
+    pub fn with_secp256k1_pubkey(pubkey: &secp256k1::PublicKey) -> AddressResult<SuiAddress> {
+        Self::with_scheme(SignatureScheme::Secp256k1, pubkey.compressed().as_slice())
+    }
+
+    /// Address is `blake2b_256(flag || public_key)`.
+    fn with_scheme(scheme: SignatureScheme, pubkey: &[u8]) -> AddressResult<SuiAddress> {
+        let mut to_hash = Vec::with_capacity(pubkey.len() + 1);
+        to_hash.push(scheme as u8);
+        to_hash.extend_from_slice(pubkey);
+
+        let hash = blake2_b(&to_hash, Self::LENGTH).map_err(|_| AddressError::InvalidInput)?;
+        let addr = AccountAddress::from_bytes(hash).map_err(|_| AddressError::InvalidInput)?;
+        Ok(SuiAddress(addr))
+    }
+
+    pub fn inner(&self) -> AccountAddress {
+        self.0
+    }
+}
+
+impl CoinAddress for SuiAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.0.to_vec()
+    }
+}
+
+impl FromStr for SuiAddress {
+    type Err = AddressError;
+
+    /// Parses a hex address with an optional `0x` prefix.
+    /// Single-digit system addresses like `0x2` are padded with zeros.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const SHORT_LEN: usize = 1;
+        const NUM_CHARS: usize = SuiAddress::LENGTH * 2;
+
+        let working = s.strip_prefix("0x").unwrap_or(s);
+        let padded;
+        let working = if working.len() == SHORT_LEN {
+            padded = format!("{working:0>NUM_CHARS$}");
+            padded.as_str()
+        } else {
+            working
+        };
+
+        if working.len() != NUM_CHARS {
+            return Err(AddressError::InvalidInput);
+        }
+        let bytes = hex::decode(working).map_err(|_| AddressError::FromHexError)?;
+        let addr = AccountAddress::from_bytes(bytes).map_err(|_| AddressError::InvalidInput)?;
+        Ok(SuiAddress(addr))
+    }
+}
+
+impl fmt::Display for SuiAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0.as_ref(), true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_public_key() {
+        let private = ed25519::sha512::PrivateKey::try_from(
+            "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266",
+        )
+        .unwrap();
+        let addr = SuiAddress::with_ed25519_pubkey(&private.public()).unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = "0x259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015";
+        let without_prefix = "259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015";
+        assert_eq!(
+            SuiAddress::from_str(expected).unwrap().to_string(),
+            expected
+        );
+        assert_eq!(
+            SuiAddress::from_str(without_prefix).unwrap().to_string(),
+            expected
+        );
+
+        assert_eq!(
+            SuiAddress::from_str("0x5").unwrap().to_string(),
+            "0x0000000000000000000000000000000000000000000000000000000000000005"
+        );
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        // 20 bytes.
+        SuiAddress::from_str("0xb1dc06bd64d4e179a482b97bb68243f6c02c1b92").unwrap_err();
+        // 33 bytes.
+        SuiAddress::from_str("d575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d502")
+            .unwrap_err();
+        // Only one-digit short addresses are allowed.
+        SuiAddress::from_str("0x11").unwrap_err();
+        SuiAddress::from_str("0xS59ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015")
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_builder::TransactionBuilder;
+use crate::modules::tx_signer::TxSigner;
+use crate::signature::SuiSignatureInfo;
+use crate::signer::SuiSigner;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::Sui::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct SuiCompiler;
+
+impl SuiCompiler {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let tx_bytes = TransactionBuilder::unsigned_tx_bytes(&input)?;
+        let intent_message = TxSigner::preimage(tx_bytes.clone());
+        let data_hash = match input.signature_scheme {
+            Proto::SignatureScheme::Ed25519 => intent_message.hash().to_vec(),
+            Proto::SignatureScheme::Secp256k1 => {
+                TxSigner::secp256k1_preimage_hash(tx_bytes).to_vec()
+            },
+        };
+
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(intent_message.to_vec()),
+            data_hash: Cow::from(data_hash),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn compile_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let SingleSignaturePubkey {
+            signature,
+            public_key,
+        } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
+
+        let tx_bytes = TransactionBuilder::unsigned_tx_bytes(&input)?;
+
+        let signature_info = match input.signature_scheme {
+            Proto::SignatureScheme::Ed25519 => {
+                let public_key = ed25519::sha512::PublicKey::try_from(public_key.as_slice())?;
+                let signature = ed25519::Signature::try_from(signature.as_slice())?;
+
+                let hash = TxSigner::preimage(tx_bytes.clone()).hash();
+                let signature_info = SuiSignatureInfo::ed25519(&signature, &public_key);
+                if !public_key.verify(signature, hash.to_vec()) {
+                    return Err(SigningError(SigningErrorType::Error_signing));
+                }
+                signature_info
+            },
+            Proto::SignatureScheme::Secp256k1 => {
+                let public_key = secp256k1::PublicKey::try_from(public_key.as_slice())?;
+                let signature = secp256k1::VerifySignature::try_from(signature.as_slice())?;
+
+                let hash = TxSigner::secp256k1_preimage_hash(tx_bytes.clone());
+                let signature_info = SuiSignatureInfo::secp256k1(&signature, &public_key);
+                if !public_key.verify(signature, hash) {
+                    return Err(SigningError(SigningErrorType::Error_signing));
+                }
+                signature_info
+            },
+        };
+
+        Ok(SuiSigner::output_from_signed(&tx_bytes, &signature_info))
+    }
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::transaction::sui_types::{ObjectID, SequenceNumber};
+use move_core_types::ident_str;
+use move_core_types::identifier::IdentStr;
+
+/// `0x3`, the package of the Sui system contracts.
+pub const SUI_SYSTEM_PACKAGE_ID: ObjectID = short_object_id(0x3);
+/// `0x5`, the shared `SuiSystemState` object.
+pub const SUI_SYSTEM_STATE_OBJECT_ID: ObjectID = short_object_id(0x5);
+pub const SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION: SequenceNumber = 1;
+
+pub const SUI_SYSTEM_MODULE_NAME: &IdentStr = ident_str!("sui_system");
+pub const ADD_STAKE_MUL_COIN_FUN_NAME: &IdentStr = ident_str!("request_add_stake_mul_coin");
+pub const WITHDRAW_STAKE_FUN_NAME: &IdentStr = ident_str!("request_withdraw_stake");
+
+const fn short_object_id(last_byte: u8) -> ObjectID {
+    let mut bytes = [0; SuiAddress::LENGTH];
+    bytes[SuiAddress::LENGTH - 1] = last_byte;
+    SuiAddress::new(bytes)
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::blake2::blake2_b;
+use tw_hash::H256;
+use tw_memory::Data;
+
+/// The version of the intent, the only one supported is `V0`.
+const INTENT_VERSION_V0: u8 = 0;
+/// The application the intent is for, `Sui` is `0`.
+const APP_ID_SUI: u8 = 0;
+
+/// The type of the message being signed.
+/// Prevents a signature of one kind of the data from being reused for another.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum IntentScope {
+    TransactionData = 0,
+    PersonalMessage = 3,
+}
+
+impl IntentScope {
+    /// Returns the 3-byte intent: `scope || version || app_id`.
+    pub fn intent(self) -> [u8; 3] {
+        [self as u8, INTENT_VERSION_V0, APP_ID_SUI]
+    }
+}
+
+/// An intent message: `intent || bcs(value)`.
+pub struct IntentMessage {
+    scope: IntentScope,
+    bcs_value: Data,
+}
+
+impl IntentMessage {
+    /// Creates an intent message with the already BCS-encoded `bcs_value`.
+    pub fn new(scope: IntentScope, bcs_value: Data) -> IntentMessage {
+        IntentMessage { scope, bcs_value }
+    }
+
+    pub fn to_vec(&self) -> Data {
+        let mut bytes = Vec::with_capacity(3 + self.bcs_value.len());
+        bytes.extend_from_slice(&self.scope.intent());
+        bytes.extend_from_slice(&self.bcs_value);
+        bytes
+    }
+
+    /// Returns the `blake2b_256` hash of the intent message, the data to be signed.
+    pub fn hash(&self) -> H256 {
+        let hash = blake2_b(&self.to_vec(), H256::LEN).expect("Expected a valid hash size");
+        H256::try_from(hash.as_slice()).expect("Expected a valid hash length")
+    }
+}
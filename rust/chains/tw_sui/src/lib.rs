@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod address;
+pub mod compiler;
+pub mod constants;
+pub mod entry;
+pub mod intent;
+pub mod modules;
+pub mod signature;
+pub mod signer;
+pub mod transaction;
@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod personal_message_signer;
+pub mod tx_builder;
+pub mod tx_signer;
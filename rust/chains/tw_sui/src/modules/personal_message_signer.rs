@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::intent::{IntentMessage, IntentScope};
+use crate::signature::{SignatureScheme, SuiSignatureInfo};
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::bcs;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+use tw_proto::Sui::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// Signs and verifies personal messages.
+/// The message is BCS-encoded as `vector<u8>` and wrapped into an intent message
+/// with the `PersonalMessage` scope.
+#[derive(Default)]
+pub struct SuiPersonalMessageSigner;
+
+impl MessageSigner for SuiPersonalMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl SuiPersonalMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let intent_message = Self::intent_message(&input.message)?;
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::Owned(intent_message.to_vec()),
+            data_hash: Cow::Owned(intent_message.hash().to_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let hash = Self::intent_message(&input.message)?.hash();
+        let signature = key_pair.sign(hash.to_vec())?;
+
+        let signature_info = SuiSignatureInfo::ed25519(&signature, key_pair.public());
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(signature_info.to_base64()),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())?;
+        let signature_info = SuiSignatureInfo::from_base64(&input.signature)?;
+        // The signature must be made by the given public key.
+        if signature_info.scheme != SignatureScheme::Ed25519
+            || signature_info.public_key != public_key.as_slice()
+        {
+            return Ok(false);
+        }
+
+        let signature = ed25519::Signature::try_from(signature_info.signature.as_slice())?;
+        let hash = Self::intent_message(&input.message)?.hash();
+        Ok(public_key.verify(signature, hash.to_vec()))
+    }
+
+    fn intent_message(message: &str) -> SigningResult<IntentMessage> {
+        let bcs_message = bcs::encode(message.as_bytes())?;
+        Ok(IntentMessage::new(
+            IntentScope::PersonalMessage,
+            bcs_message,
+        ))
+    }
+}
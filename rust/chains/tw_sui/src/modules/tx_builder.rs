@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::constants::{
+    ADD_STAKE_MUL_COIN_FUN_NAME, SUI_SYSTEM_MODULE_NAME, SUI_SYSTEM_PACKAGE_ID,
+    WITHDRAW_STAKE_FUN_NAME,
+};
+use crate::transaction::command::Command;
+use crate::transaction::programmable_transaction::{
+    CallArg, ObjectArg, ProgrammableTransaction, ProgrammableTransactionBuilder,
+};
+use crate::transaction::sui_types::{object_ref_from_proto, ObjectRef};
+use crate::transaction::transaction_data::TransactionData;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::base64;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_keypair::traits::KeyPairTrait;
+use tw_memory::Data;
+use tw_proto::Sui::Proto;
+use tw_proto::Sui::Proto::mod_SigningInput::OneOftransaction_payload as TransactionType;
+
+/// Builds a BCS-encoded unsigned transaction from the signing input.
+pub struct TransactionBuilder;
+
+impl TransactionBuilder {
+    pub fn unsigned_tx_bytes(input: &Proto::SigningInput<'_>) -> SigningResult<Data> {
+        let tx_data = match input.transaction_payload {
+            // The transaction is already built and BCS-encoded.
+            TransactionType::sign_direct_message(ref direct) => {
+                return Ok(base64::decode(&direct.unsigned_tx_msg, false)?);
+            },
+            TransactionType::pay(ref pay) => Self::pay(input, pay)?,
+            TransactionType::pay_sui(ref pay_sui) => Self::pay_sui(input, pay_sui)?,
+            TransactionType::pay_all_sui(ref pay_all_sui) => Self::pay_all_sui(input, pay_all_sui)?,
+            TransactionType::request_add_stake(ref add_stake) => Self::add_stake(input, add_stake)?,
+            TransactionType::request_withdraw_stake(ref withdraw_stake) => {
+                Self::withdraw_stake(input, withdraw_stake)?
+            },
+            TransactionType::None => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params))
+            },
+        };
+        tx_data.to_bcs()
+    }
+
+    fn pay(
+        input: &Proto::SigningInput<'_>,
+        pay: &Proto::Pay<'_>,
+    ) -> SigningResult<TransactionData> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.pay(
+            Self::object_refs(&pay.input_coins)?,
+            Self::addresses(&pay.recipients)?,
+            pay.amounts.clone(),
+        )?;
+
+        let gas = Self::required_object_ref(pay.gas.as_ref())?;
+        Self::programmable(input, vec![gas], builder.finish())
+    }
+
+    /// The input coins are used as the gas payment,
+    /// so the amounts are split off the gas coin.
+    fn pay_sui(
+        input: &Proto::SigningInput<'_>,
+        pay_sui: &Proto::PaySui<'_>,
+    ) -> SigningResult<TransactionData> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.pay_sui(
+            Self::addresses(&pay_sui.recipients)?,
+            pay_sui.amounts.clone(),
+        )?;
+
+        let gas_payment = Self::non_empty_object_refs(&pay_sui.input_coins)?;
+        Self::programmable(input, gas_payment, builder.finish())
+    }
+
+    /// The input coins are used as the gas payment,
+    /// so the whole gas coin is transferred after the gas is charged.
+    fn pay_all_sui(
+        input: &Proto::SigningInput<'_>,
+        pay_all_sui: &Proto::PayAllSui<'_>,
+    ) -> SigningResult<TransactionData> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.pay_all_sui(SuiAddress::from_str(&pay_all_sui.recipient)?)?;
+
+        let gas_payment = Self::non_empty_object_refs(&pay_all_sui.input_coins)?;
+        Self::programmable(input, gas_payment, builder.finish())
+    }
+
+    /// Calls `0x3::sui_system::request_add_stake_mul_coin`.
+    fn add_stake(
+        input: &Proto::SigningInput<'_>,
+        add_stake: &Proto::RequestAddStake<'_>,
+    ) -> SigningResult<TransactionData> {
+        let coins = Self::non_empty_object_refs(&add_stake.coins)?;
+        let amount = add_stake.amount.as_ref().map(|amount| amount.amount);
+        let validator = SuiAddress::from_str(&add_stake.validator)?;
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let arguments = vec![
+            builder.input(CallArg::SUI_SYSTEM_MUT)?,
+            builder.make_obj_vec(coins)?,
+            builder.pure(amount)?,
+            builder.pure(validator)?,
+        ];
+        builder.command(Command::move_call(
+            SUI_SYSTEM_PACKAGE_ID,
+            SUI_SYSTEM_MODULE_NAME.to_owned(),
+            ADD_STAKE_MUL_COIN_FUN_NAME.to_owned(),
+            Vec::new(),
+            arguments,
+        ))?;
+
+        let gas = Self::required_object_ref(add_stake.gas.as_ref())?;
+        Self::programmable(input, vec![gas], builder.finish())
+    }
+
+    /// Calls `0x3::sui_system::request_withdraw_stake`.
+    fn withdraw_stake(
+        input: &Proto::SigningInput<'_>,
+        withdraw_stake: &Proto::RequestWithdrawStake<'_>,
+    ) -> SigningResult<TransactionData> {
+        let staked_sui = Self::required_object_ref(withdraw_stake.staked_sui.as_ref())?;
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.move_call(
+            SUI_SYSTEM_PACKAGE_ID,
+            SUI_SYSTEM_MODULE_NAME.to_owned(),
+            WITHDRAW_STAKE_FUN_NAME.to_owned(),
+            Vec::new(),
+            vec![
+                CallArg::SUI_SYSTEM_MUT,
+                CallArg::Object(ObjectArg::ImmOrOwnedObject(staked_sui)),
+            ],
+        )?;
+
+        let gas = Self::required_object_ref(withdraw_stake.gas.as_ref())?;
+        Self::programmable(input, vec![gas], builder.finish())
+    }
+
+    fn programmable(
+        input: &Proto::SigningInput<'_>,
+        gas_payment: Vec<ObjectRef>,
+        pt: ProgrammableTransaction,
+    ) -> SigningResult<TransactionData> {
+        Ok(TransactionData::new_programmable(
+            Self::sender(input)?,
+            gas_payment,
+            pt,
+            input.gas_budget,
+            input.reference_gas_price,
+        ))
+    }
+
+    /// Returns the `signer` address if set, otherwise derives it from the private key.
+    fn sender(input: &Proto::SigningInput<'_>) -> SigningResult<SuiAddress> {
+        if !input.signer.is_empty() {
+            return Ok(SuiAddress::from_str(&input.signer)?);
+        }
+        if input.private_key.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        let address = match input.signature_scheme {
+            Proto::SignatureScheme::Ed25519 => {
+                let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+                SuiAddress::with_ed25519_pubkey(key_pair.public())?
+            },
+            Proto::SignatureScheme::Secp256k1 => {
+                let key_pair = secp256k1::KeyPair::try_from(input.private_key.as_ref())?;
+                SuiAddress::with_secp256k1_pubkey(key_pair.public())?
+            },
+        };
+        Ok(address)
+    }
+
+    fn addresses(addresses: &[Cow<'_, str>]) -> SigningResult<Vec<SuiAddress>> {
+        addresses
+            .iter()
+            .map(|addr| SuiAddress::from_str(addr).map_err(SigningError::from))
+            .collect()
+    }
+
+    fn object_refs(objects: &[Proto::ObjectRef<'_>]) -> SigningResult<Vec<ObjectRef>> {
+        objects.iter().map(object_ref_from_proto).collect()
+    }
+
+    fn non_empty_object_refs(objects: &[Proto::ObjectRef<'_>]) -> SigningResult<Vec<ObjectRef>> {
+        if objects.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        Self::object_refs(objects)
+    }
+
+    fn required_object_ref(object: Option<&Proto::ObjectRef<'_>>) -> SigningResult<ObjectRef> {
+        let object = object.ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        object_ref_from_proto(object)
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::intent::{IntentMessage, IntentScope};
+use crate::signature::SuiSignatureInfo;
+use tw_coin_entry::error::SigningResult;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_memory::Data;
+
+pub struct TxSigner;
+
+impl TxSigner {
+    /// Wraps the BCS-encoded transaction data into an intent message.
+    pub fn preimage(tx_bytes: Data) -> IntentMessage {
+        IntentMessage::new(IntentScope::TransactionData, tx_bytes)
+    }
+
+    /// Returns the hash that is signed with `secp256k1`:
+    /// the SHA-256 hash of the `blake2b_256` intent message digest.
+    pub fn secp256k1_preimage_hash(tx_bytes: Data) -> H256 {
+        let digest = Self::preimage(tx_bytes).hash();
+        H256::try_from(sha256(digest.as_slice()).as_slice()).expect("Expected a valid hash length")
+    }
+
+    /// Signs the `blake2b_256` hash of the transaction intent message with `ed25519`.
+    pub fn sign_ed25519(
+        key_pair: &ed25519::sha512::KeyPair,
+        tx_bytes: Data,
+    ) -> SigningResult<SuiSignatureInfo> {
+        let hash = Self::preimage(tx_bytes).hash();
+        let signature = key_pair.sign(hash.to_vec())?;
+        Ok(SuiSignatureInfo::ed25519(&signature, key_pair.public()))
+    }
+
+    /// Signs the transaction intent message with `secp256k1`, see [`TxSigner::secp256k1_preimage_hash`].
+    pub fn sign_secp256k1(
+        key_pair: &secp256k1::KeyPair,
+        tx_bytes: Data,
+    ) -> SigningResult<SuiSignatureInfo> {
+        let hash = Self::secp256k1_preimage_hash(tx_bytes);
+        let signature = secp256k1::VerifySignature::from(key_pair.sign(hash)?);
+        Ok(SuiSignatureInfo::secp256k1(&signature, key_pair.public()))
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::base64;
+use tw_hash::{H256, H264, H512};
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_memory::Data;
+
+/// Signature scheme flag, the first byte of the serialized signature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum SignatureScheme {
+    Ed25519 = 0x00,
+    Secp256k1 = 0x01,
+}
+
+impl SignatureScheme {
+    /// Returns the length of the public key in the serialized signature.
+    fn public_key_len(self) -> usize {
+        match self {
+            SignatureScheme::Ed25519 => H256::LEN,
+            SignatureScheme::Secp256k1 => H264::LEN,
+        }
+    }
+}
+
+/// Signature in the Sui serialized format: `flag || signature || public_key`.
+pub struct SuiSignatureInfo {
+    pub scheme: SignatureScheme,
+    pub signature: H512,
+    /// `ed25519` or compressed `secp256k1` public key.
+    pub public_key: Data,
+}
+
+impl SuiSignatureInfo {
+    pub fn ed25519(
+        signature: &ed25519::Signature,
+        public_key: &ed25519::sha512::PublicKey,
+    ) -> Self {
+        SuiSignatureInfo {
+            scheme: SignatureScheme::Ed25519,
+            signature: signature.to_bytes(),
+            public_key: public_key.as_slice().to_vec(),
+        }
+    }
+
+    pub fn secp256k1(
+        signature: &secp256k1::VerifySignature,
+        public_key: &secp256k1::PublicKey,
+    ) -> Self {
+        SuiSignatureInfo {
+            scheme: SignatureScheme::Secp256k1,
+            signature: signature.to_bytes(),
+            public_key: public_key.compressed().into_vec(),
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + H512::LEN + self.public_key.len());
+        bytes.push(self.scheme as u8);
+        bytes.extend_from_slice(self.signature.as_slice());
+        bytes.extend_from_slice(&self.public_key);
+        bytes
+    }
+
+    pub fn to_base64(&self) -> String {
+        base64::encode(&self.to_vec(), false)
+    }
+
+    pub fn from_base64(s: &str) -> SigningResult<Self> {
+        let bytes = base64::decode(s, false)?;
+        let (flag, signature_public_key) = bytes
+            .split_first()
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        let scheme = match *flag {
+            flag if flag == SignatureScheme::Ed25519 as u8 => SignatureScheme::Ed25519,
+            flag if flag == SignatureScheme::Secp256k1 as u8 => SignatureScheme::Secp256k1,
+            _ => return Err(SigningError(SigningErrorType::Error_invalid_params)),
+        };
+        if signature_public_key.len() != H512::LEN + scheme.public_key_len() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        let (signature, public_key) = signature_public_key.split_at(H512::LEN);
+        Ok(SuiSignatureInfo {
+            scheme,
+            signature: H512::try_from(signature).expect("Expected a valid signature length"),
+            public_key: public_key.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_builder::TransactionBuilder;
+use crate::modules::tx_signer::TxSigner;
+use crate::signature::SuiSignatureInfo;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_proto::Sui::Proto;
+
+pub struct SuiSigner;
+
+impl SuiSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let tx_bytes = TransactionBuilder::unsigned_tx_bytes(&input)?;
+        let signature = match input.signature_scheme {
+            Proto::SignatureScheme::Ed25519 => {
+                let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+                TxSigner::sign_ed25519(&key_pair, tx_bytes.clone())?
+            },
+            Proto::SignatureScheme::Secp256k1 => {
+                let key_pair = secp256k1::KeyPair::try_from(input.private_key.as_ref())?;
+                TxSigner::sign_secp256k1(&key_pair, tx_bytes.clone())?
+            },
+        };
+
+        Ok(Self::output_from_signed(&tx_bytes, &signature))
+    }
+
+    pub(crate) fn output_from_signed(
+        tx_bytes: &[u8],
+        signature: &SuiSignatureInfo,
+    ) -> Proto::SigningOutput<'static> {
+        Proto::SigningOutput {
+            unsigned_tx: Cow::from(base64::encode(tx_bytes, false)),
+            signature: Cow::from(signature.to_base64()),
+            ..Proto::SigningOutput::default()
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::sui_types::ObjectID;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::TypeTag;
+use serde::Serialize;
+
+/// An argument to a programmable transaction command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum Argument {
+    /// The gas coin. The gas coin can only be used by-ref, except for with
+    /// `TransferObjects`, which can use it by-value.
+    GasCoin,
+    /// One of the input objects or primitive values (from `ProgrammableTransaction::inputs`).
+    Input(u16),
+    /// The result of another command (from `ProgrammableTransaction::commands`).
+    Result(u16),
+    /// Like a `Result` but it accesses a nested result. Currently, the only usage
+    /// of this is to access a value from a Move call with multiple return values.
+    NestedResult(u16, u16),
+}
+
+/// The command for calling a Move function, either an entry function or a public
+/// function (which cannot return references).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ProgrammableMoveCall {
+    /// The package containing the module and function.
+    pub package: ObjectID,
+    /// The specific module in the package containing the function.
+    pub module: Identifier,
+    /// The function to be called.
+    pub function: Identifier,
+    /// The type arguments to the function.
+    pub type_arguments: Vec<TypeTag>,
+    /// The arguments to the function.
+    pub arguments: Vec<Argument>,
+}
+
+/// A single command in a programmable transaction.
+/// The order of the variants defines their BCS tags and must not be changed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub enum Command {
+    /// A call to either an entry or a public Move function.
+    MoveCall(Box<ProgrammableMoveCall>),
+    /// `(Vec<forall T:key+store. T>, address)`.
+    /// It sends n-objects to the specified address.
+    TransferObjects(Vec<Argument>, Argument),
+    /// `(&mut Coin<T>, Vec<u64>)` -> `Vec<Coin<T>>`.
+    /// It splits off some amounts into a new coins with those amounts.
+    SplitCoins(Argument, Vec<Argument>),
+    /// `(&mut Coin<T>, Vec<Coin<T>>)`.
+    /// It merges n-coins into the first coin.
+    MergeCoins(Argument, Vec<Argument>),
+    /// Publishes a Move package. It takes the package bytes and a list of the package's transitive
+    /// dependencies to link against on-chain.
+    Publish(Vec<Vec<u8>>, Vec<ObjectID>),
+    /// `forall T: Vec<T> -> vector<T>`.
+    /// Given n-values of the same type, it constructs a vector. For non objects or an empty vector,
+    /// the type tag must be specified.
+    MakeMoveVec(Option<TypeTag>, Vec<Argument>),
+}
+
+impl Command {
+    pub fn move_call(
+        package: ObjectID,
+        module: Identifier,
+        function: Identifier,
+        type_arguments: Vec<TypeTag>,
+        arguments: Vec<Argument>,
+    ) -> Self {
+        Command::MoveCall(Box::new(ProgrammableMoveCall {
+            package,
+            module,
+            function,
+            type_arguments,
+            arguments,
+        }))
+    }
+}
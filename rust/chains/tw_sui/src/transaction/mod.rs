@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod command;
+pub mod programmable_transaction;
+pub mod sui_types;
+pub mod transaction_data;
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::constants::{SUI_SYSTEM_STATE_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION};
+use crate::transaction::command::{Argument, Command};
+use crate::transaction::sui_types::{ObjectID, ObjectRef, SequenceNumber};
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::TypeTag;
+use serde::Serialize;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::bcs;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub enum CallArg {
+    /// Contains no structs or objects.
+    Pure(Vec<u8>),
+    /// An object.
+    Object(ObjectArg),
+}
+
+impl CallArg {
+    /// The mutable `SuiSystemState` shared object.
+    pub const SUI_SYSTEM_MUT: Self = Self::Object(ObjectArg::SUI_SYSTEM_MUT);
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub enum ObjectArg {
+    /// A Move object, either immutable, or owned mutable.
+    ImmOrOwnedObject(ObjectRef),
+    /// A Move object that's shared.
+    /// `SharedObject::mutable` controls whether caller asks for a mutable reference to shared object.
+    SharedObject {
+        id: ObjectID,
+        initial_shared_version: SequenceNumber,
+        mutable: bool,
+    },
+}
+
+impl ObjectArg {
+    pub const SUI_SYSTEM_MUT: Self = Self::SharedObject {
+        id: SUI_SYSTEM_STATE_OBJECT_ID,
+        initial_shared_version: SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION,
+        mutable: true,
+    };
+}
+
+/// A series of commands where the results of one command can be used in future commands.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ProgrammableTransaction {
+    /// Input objects or primitive values.
+    pub inputs: Vec<CallArg>,
+    /// The commands to be executed sequentially. A failure in any command will
+    /// result in the failure of the entire transaction.
+    pub commands: Vec<Command>,
+}
+
+/// Builds a [`ProgrammableTransaction`] deduplicating the equal inputs.
+#[derive(Default)]
+pub struct ProgrammableTransactionBuilder {
+    inputs: Vec<CallArg>,
+    commands: Vec<Command>,
+}
+
+impl ProgrammableTransactionBuilder {
+    pub fn new() -> Self {
+        ProgrammableTransactionBuilder::default()
+    }
+
+    pub fn finish(self) -> ProgrammableTransaction {
+        ProgrammableTransaction {
+            inputs: self.inputs,
+            commands: self.commands,
+        }
+    }
+
+    /// Adds a BCS-encoded primitive value as an input.
+    pub fn pure<T: Serialize>(&mut self, value: T) -> SigningResult<Argument> {
+        let bytes = bcs::encode(&value)?;
+        self.input(CallArg::Pure(bytes))
+    }
+
+    pub fn obj(&mut self, obj_arg: ObjectArg) -> SigningResult<Argument> {
+        self.input(CallArg::Object(obj_arg))
+    }
+
+    /// Adds the input if it hasn't been added yet, and returns its argument.
+    pub fn input(&mut self, call_arg: CallArg) -> SigningResult<Argument> {
+        let idx = match self.inputs.iter().position(|input| *input == call_arg) {
+            Some(idx) => idx,
+            None => {
+                self.inputs.push(call_arg);
+                self.inputs.len() - 1
+            },
+        };
+        let idx =
+            u16::try_from(idx).map_err(|_| SigningError(SigningErrorType::Error_tx_too_big))?;
+        Ok(Argument::Input(idx))
+    }
+
+    /// Adds the command and returns its result argument.
+    pub fn command(&mut self, command: Command) -> SigningResult<Argument> {
+        let idx = u16::try_from(self.commands.len())
+            .map_err(|_| SigningError(SigningErrorType::Error_tx_too_big))?;
+        self.commands.push(command);
+        Ok(Argument::Result(idx))
+    }
+
+    /// Makes a Move vector of the given owned objects.
+    pub fn make_obj_vec(&mut self, objs: Vec<ObjectRef>) -> SigningResult<Argument> {
+        let args = objs
+            .into_iter()
+            .map(|obj| self.obj(ObjectArg::ImmOrOwnedObject(obj)))
+            .collect::<SigningResult<Vec<_>>>()?;
+        self.command(Command::MakeMoveVec(None, args))
+    }
+
+    pub fn move_call(
+        &mut self,
+        package: ObjectID,
+        module: Identifier,
+        function: Identifier,
+        type_arguments: Vec<TypeTag>,
+        call_args: Vec<CallArg>,
+    ) -> SigningResult<Argument> {
+        let arguments = call_args
+            .into_iter()
+            .map(|arg| self.input(arg))
+            .collect::<SigningResult<Vec<_>>>()?;
+        self.command(Command::move_call(
+            package,
+            module,
+            function,
+            type_arguments,
+            arguments,
+        ))
+    }
+
+    /// Transfers the whole gas coin to the `recipient`.
+    pub fn pay_all_sui(&mut self, recipient: SuiAddress) -> SigningResult<()> {
+        let rec_arg = self.pure(recipient)?;
+        self.command(Command::TransferObjects(vec![Argument::GasCoin], rec_arg))?;
+        Ok(())
+    }
+
+    /// Splits the `amounts` off the gas coin and transfers them to the `recipients`.
+    pub fn pay_sui(&mut self, recipients: Vec<SuiAddress>, amounts: Vec<u64>) -> SigningResult<()> {
+        self.pay_impl(recipients, amounts, Argument::GasCoin)
+    }
+
+    /// Merges the `coins` into the first one, splits the `amounts` off it
+    /// and transfers them to the `recipients`.
+    pub fn pay(
+        &mut self,
+        coins: Vec<ObjectRef>,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<u64>,
+    ) -> SigningResult<()> {
+        let mut coins = coins.into_iter();
+        let Some(coin) = coins.next() else {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        };
+        let coin_arg = self.obj(ObjectArg::ImmOrOwnedObject(coin))?;
+        let merge_args = coins
+            .map(|coin| self.obj(ObjectArg::ImmOrOwnedObject(coin)))
+            .collect::<SigningResult<Vec<_>>>()?;
+        if !merge_args.is_empty() {
+            self.command(Command::MergeCoins(coin_arg, merge_args))?;
+        }
+        self.pay_impl(recipients, amounts, coin_arg)
+    }
+
+    fn pay_impl(
+        &mut self,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<u64>,
+        coin: Argument,
+    ) -> SigningResult<()> {
+        if recipients.len() != amounts.len() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        // Group the split coins by recipients keeping the order of their first occurrence.
+        let mut recipient_map: Vec<(SuiAddress, Vec<u16>)> = Vec::new();
+        let mut amt_args = Vec::with_capacity(amounts.len());
+        for (idx, (recipient, amount)) in recipients.into_iter().zip(amounts).enumerate() {
+            let idx =
+                u16::try_from(idx).map_err(|_| SigningError(SigningErrorType::Error_tx_too_big))?;
+            match recipient_map.iter_mut().find(|(rec, _)| *rec == recipient) {
+                Some((_, indexes)) => indexes.push(idx),
+                None => recipient_map.push((recipient, vec![idx])),
+            }
+            amt_args.push(self.pure(amount)?);
+        }
+
+        let Argument::Result(split_primary) = self.command(Command::SplitCoins(coin, amt_args))?
+        else {
+            return Err(SigningError(SigningErrorType::Error_internal));
+        };
+        for (recipient, split_secondaries) in recipient_map {
+            let rec_arg = self.pure(recipient)?;
+            let coins = split_secondaries
+                .into_iter()
+                .map(|j| Argument::NestedResult(split_primary, j))
+                .collect();
+            self.command(Command::TransferObjects(coins, rec_arg))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use tw_encoding::hex;
+
+    #[test]
+    fn test_pay_sui_dedup_inputs() {
+        let recipient = SuiAddress::from_str(
+            "0x259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015",
+        )
+        .unwrap();
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder
+            .pay_sui(vec![recipient, recipient], vec![10, 10])
+            .unwrap();
+        let pt = builder.finish();
+
+        // Equal amounts and recipients are added as inputs once.
+        assert_eq!(pt.inputs.len(), 2);
+        assert_eq!(
+            pt.commands,
+            vec![
+                Command::SplitCoins(
+                    Argument::GasCoin,
+                    vec![Argument::Input(0), Argument::Input(0)]
+                ),
+                Command::TransferObjects(
+                    vec![Argument::NestedResult(0, 0), Argument::NestedResult(0, 1)],
+                    Argument::Input(1)
+                ),
+            ]
+        );
+        assert_eq!(
+            hex::encode(&bcs::encode(&pt.commands).unwrap(), false),
+            "02020002010000010000010203000000000300000100010100"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use serde::{Serialize, Serializer};
+use std::str::FromStr;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::H256;
+use tw_proto::Sui::Proto;
+
+pub type ObjectID = SuiAddress;
+pub type SequenceNumber = u64;
+pub type EpochId = u64;
+
+/// `(object_id, version, digest)` tuple that uniquely identifies an object version.
+pub type ObjectRef = (ObjectID, SequenceNumber, ObjectDigest);
+
+/// Digest of an object, Base58-encoded in the JSON RPC.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ObjectDigest(H256);
+
+impl ObjectDigest {
+    pub fn new(digest: H256) -> ObjectDigest {
+        ObjectDigest(digest)
+    }
+}
+
+impl FromStr for ObjectDigest {
+    type Err = SigningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base58::decode(s, Alphabet::BITCOIN)?;
+        H256::try_from(bytes.as_slice())
+            .map(ObjectDigest)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))
+    }
+}
+
+/// The digest is serialized as a byte vector, i.e. prefixed with its length.
+impl Serialize for ObjectDigest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.0.as_slice())
+    }
+}
+
+pub fn object_ref_from_proto(object: &Proto::ObjectRef<'_>) -> SigningResult<ObjectRef> {
+    let object_id = ObjectID::from_str(&object.object_id)?;
+    let digest = ObjectDigest::from_str(&object.object_digest)?;
+    Ok((object_id, object.version, digest))
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::transaction::programmable_transaction::ProgrammableTransaction;
+use crate::transaction::sui_types::{EpochId, ObjectRef};
+use serde::Serialize;
+use tw_coin_entry::error::SigningResult;
+use tw_encoding::bcs;
+use tw_memory::Data;
+
+#[derive(Debug, Serialize)]
+pub enum TransactionData {
+    V1(TransactionDataV1),
+}
+
+impl TransactionData {
+    pub fn new_programmable(
+        sender: SuiAddress,
+        gas_payment: Vec<ObjectRef>,
+        pt: ProgrammableTransaction,
+        gas_budget: u64,
+        gas_price: u64,
+    ) -> TransactionData {
+        TransactionData::V1(TransactionDataV1 {
+            kind: TransactionKind::ProgrammableTransaction(pt),
+            sender,
+            gas_data: GasData {
+                payment: gas_payment,
+                owner: sender,
+                price: gas_price,
+                budget: gas_budget,
+            },
+            expiration: TransactionExpiration::None,
+        })
+    }
+
+    pub fn to_bcs(&self) -> SigningResult<Data> {
+        Ok(bcs::encode(self)?)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionDataV1 {
+    pub kind: TransactionKind,
+    pub sender: SuiAddress,
+    pub gas_data: GasData,
+    pub expiration: TransactionExpiration,
+}
+
+/// Only the programmable transactions can be built by the users,
+/// the rest of the kinds are system transactions.
+#[derive(Debug, Serialize)]
+pub enum TransactionKind {
+    ProgrammableTransaction(ProgrammableTransaction),
+}
+
+#[derive(Debug, Serialize)]
+pub struct GasData {
+    pub payment: Vec<ObjectRef>,
+    pub owner: SuiAddress,
+    pub price: u64,
+    pub budget: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub enum TransactionExpiration {
+    /// The transaction has no expiration.
+    None,
+    /// Validators won't sign a transaction unless the expiration Epoch
+    /// is greater than or equal to the current epoch.
+    Epoch(EpochId),
+}
@@ -4,5 +4,7 @@
 
 pub mod compiler;
 pub mod entry;
+pub mod memo;
 pub mod signer;
 pub mod signing_input;
+pub mod swap;
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+
+const LAYER1_SEPARATOR: char = '.';
+const SYNTH_SEPARATOR: char = '/';
+const TRADE_SEPARATOR: char = '~';
+
+/// Shortened asset notations.
+/// https://dev.thorchain.org/concepts/memos.html#shortened-asset-names
+const SHORTENED_ASSETS: [(&str, &str, &str); 10] = [
+    ("a", "AVAX", "AVAX"),
+    ("b", "BTC", "BTC"),
+    ("c", "BCH", "BCH"),
+    ("n", "BNB", "BNB"),
+    ("s", "BSC", "BNB"),
+    ("d", "DOGE", "DOGE"),
+    ("e", "ETH", "ETH"),
+    ("g", "GAIA", "ATOM"),
+    ("l", "LTC", "LTC"),
+    ("r", "THOR", "RUNE"),
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AssetKind {
+    /// Native asset on its own chain, e.g. `BTC.BTC`.
+    Layer1,
+    /// Synthetic asset on THORChain, e.g. `BTC/BTC`.
+    Synth,
+    /// Trade account asset on THORChain, e.g. `BTC~BTC`.
+    Trade,
+}
+
+impl AssetKind {
+    fn separator(self) -> char {
+        match self {
+            AssetKind::Layer1 => LAYER1_SEPARATOR,
+            AssetKind::Synth => SYNTH_SEPARATOR,
+            AssetKind::Trade => TRADE_SEPARATOR,
+        }
+    }
+}
+
+/// An asset in the THORChain memo notation: `CHAIN.SYMBOL`, `CHAIN/SYMBOL` or `CHAIN~SYMBOL`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemoAsset {
+    pub chain: String,
+    /// Symbol including an optional token address, e.g. `USDT-0XDAC17F958D2EE523A2206206994597C13D831EC7`.
+    pub symbol: String,
+    pub kind: AssetKind,
+}
+
+impl MemoAsset {
+    pub fn new(chain: &str, symbol: &str, kind: AssetKind) -> MemoAsset {
+        MemoAsset {
+            chain: chain.to_string(),
+            symbol: symbol.to_string(),
+            kind,
+        }
+    }
+
+    /// Parses an asset in the full or shortened notation.
+    pub fn parse(asset: &str) -> SigningResult<MemoAsset> {
+        if let Some((_, chain, symbol)) = SHORTENED_ASSETS
+            .iter()
+            .find(|(short, _, _)| short.eq_ignore_ascii_case(asset))
+        {
+            return Ok(MemoAsset::new(chain, symbol, AssetKind::Layer1));
+        }
+
+        let Some(separator_idx) = asset.find([LAYER1_SEPARATOR, SYNTH_SEPARATOR, TRADE_SEPARATOR])
+        else {
+            return Err(SigningError(SigningErrorType::Error_invalid_memo));
+        };
+        let kind = match asset[separator_idx..].chars().next() {
+            Some(LAYER1_SEPARATOR) => AssetKind::Layer1,
+            Some(SYNTH_SEPARATOR) => AssetKind::Synth,
+            _ => AssetKind::Trade,
+        };
+
+        let chain = &asset[..separator_idx];
+        let symbol = &asset[separator_idx + 1..];
+        if chain.is_empty() || symbol.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_invalid_memo));
+        }
+        Ok(MemoAsset::new(chain, symbol, kind))
+    }
+}
+
+impl fmt::Display for MemoAsset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.chain, self.kind.separator(), self.symbol)
+    }
+}
@@ -0,0 +1,431 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! THORChain transaction memo builder and parser.
+//! https://dev.thorchain.org/concepts/memos.html
+
+use crate::memo::asset::MemoAsset;
+use std::str::FromStr;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+
+pub mod asset;
+
+const FIELD_SEPARATOR: char = ':';
+const STREAMING_SEPARATOR: char = '/';
+const AFFILIATE_SEPARATOR: char = '/';
+
+/// Maximum affiliate fee allowed by THORChain.
+pub const MAX_AFFILIATE_FEE_BPS: u64 = 1000;
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+
+/// `SWAP:ASSET:DESTADDR:LIM/INTERVAL/QUANTITY:AFFILIATE1/AFFILIATE2:FEE1/FEE2`
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SwapMemo {
+    pub asset: Option<MemoAsset>,
+    /// The sender address is used if not set.
+    pub destination: Option<String>,
+    /// Minimum output amount (1e8 precision).
+    pub limit: Option<u128>,
+    pub streaming: Option<StreamingParams>,
+    /// Affiliate THORNames or addresses.
+    pub affiliates: Vec<String>,
+    /// Either a single fee applied to every affiliate, or one fee per affiliate.
+    pub affiliate_fees_bps: Vec<u64>,
+    /// Any extra fields following the affiliate fee, e.g. DEX aggregator parameters.
+    pub extra: Option<String>,
+}
+
+impl SwapMemo {
+    /// Checks that there is either a single affiliate fee, or one fee per affiliate.
+    pub fn check_affiliate_fees(&self) -> SigningResult<()> {
+        let fees_len = self.affiliate_fees_bps.len();
+        if fees_len > 1 && fees_len != self.affiliates.len() {
+            return Err(SigningError(SigningErrorType::Error_invalid_memo));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StreamingParams {
+    /// Number of blocks between sub-swaps.
+    pub interval: u64,
+    /// Number of sub-swaps. Zero lets THORChain choose the optimal quantity.
+    pub quantity: u64,
+}
+
+/// `ADD:POOL:PAIREDADDR:AFFILIATE:FEE`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddLiquidityMemo {
+    /// Synth pool such as `BTC/BTC` is used to add to a savers vault.
+    pub pool: MemoAsset,
+    pub paired_address: Option<String>,
+    pub affiliate: Option<String>,
+    pub affiliate_fee_bps: Option<u64>,
+}
+
+/// `WITHDRAW:POOL:BASISPOINTS:ASSET`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawLiquidityMemo {
+    /// Synth pool such as `BTC/BTC` is used to withdraw from a savers vault.
+    pub pool: MemoAsset,
+    pub basis_points: u64,
+    /// Withdraw asymmetrically to the given asset.
+    pub asset: Option<MemoAsset>,
+}
+
+/// `LOAN+:ASSET:DESTADDR:MINOUT:AFFILIATE:FEE`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpenLoanMemo {
+    /// Target debt asset.
+    pub asset: MemoAsset,
+    pub destination: String,
+    pub min_out: Option<u128>,
+    pub affiliate: Option<String>,
+    pub affiliate_fee_bps: Option<u64>,
+}
+
+/// `LOAN-:ASSET:DESTADDR:MINOUT`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RepayLoanMemo {
+    /// Collateral asset.
+    pub asset: MemoAsset,
+    /// Collateral owner address.
+    pub destination: String,
+    pub min_out: Option<u128>,
+}
+
+/// `DONATE:POOL`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonateMemo {
+    pub pool: MemoAsset,
+}
+
+/// `BOND:NODEADDR:PROVIDER:FEE`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondMemo {
+    pub node_address: String,
+    pub provider: Option<String>,
+    /// Node operator fee in basis points.
+    pub operator_fee_bps: Option<u64>,
+}
+
+/// `UNBOND:NODEADDR:AMOUNT:PROVIDER`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnbondMemo {
+    pub node_address: String,
+    pub amount: u128,
+    pub provider: Option<String>,
+}
+
+/// `LEAVE:NODEADDR`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaveMemo {
+    pub node_address: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ThorchainMemo {
+    Swap(SwapMemo),
+    AddLiquidity(AddLiquidityMemo),
+    WithdrawLiquidity(WithdrawLiquidityMemo),
+    OpenLoan(OpenLoanMemo),
+    RepayLoan(RepayLoanMemo),
+    Donate(DonateMemo),
+    Bond(BondMemo),
+    Unbond(UnbondMemo),
+    Leave(LeaveMemo),
+}
+
+impl ThorchainMemo {
+    /// Parses a memo. Both full and shortened function names and asset notations are supported.
+    pub fn parse(memo: &str) -> SigningResult<ThorchainMemo> {
+        let fields = MemoFields::new(memo);
+
+        let memo = match fields.required(0)?.to_ascii_uppercase().as_str() {
+            "SWAP" | "=" | "S" => {
+                let swap = SwapMemo {
+                    asset: fields.optional(1).map(MemoAsset::parse).transpose()?,
+                    destination: fields.optional_string(2),
+                    limit: fields.optional(3).map(parse_limit).transpose()?.flatten(),
+                    streaming: fields
+                        .optional(3)
+                        .map(parse_streaming)
+                        .transpose()?
+                        .flatten(),
+                    affiliates: fields
+                        .optional(4)
+                        .map(parse_affiliates)
+                        .transpose()?
+                        .unwrap_or_default(),
+                    affiliate_fees_bps: fields
+                        .optional(5)
+                        .map(parse_affiliate_fees)
+                        .transpose()?
+                        .unwrap_or_default(),
+                    extra: fields.remaining(6),
+                };
+                swap.check_affiliate_fees()?;
+                ThorchainMemo::Swap(swap)
+            },
+            "ADD" | "+" | "A" => ThorchainMemo::AddLiquidity(AddLiquidityMemo {
+                pool: MemoAsset::parse(fields.required(1)?)?,
+                paired_address: fields.optional_string(2),
+                affiliate: fields.optional_string(3),
+                affiliate_fee_bps: fields.optional_affiliate_fee(4)?,
+            }),
+            "WITHDRAW" | "-" | "WD" => ThorchainMemo::WithdrawLiquidity(WithdrawLiquidityMemo {
+                pool: MemoAsset::parse(fields.required(1)?)?,
+                basis_points: parse_basis_points(fields.required(2)?, MAX_BASIS_POINTS)?,
+                asset: fields.optional(3).map(MemoAsset::parse).transpose()?,
+            }),
+            "LOAN+" | "$+" => ThorchainMemo::OpenLoan(OpenLoanMemo {
+                asset: MemoAsset::parse(fields.required(1)?)?,
+                destination: fields.required(2)?.to_string(),
+                min_out: fields.optional(3).map(parse_amount).transpose()?,
+                affiliate: fields.optional_string(4),
+                affiliate_fee_bps: fields.optional_affiliate_fee(5)?,
+            }),
+            "LOAN-" | "$-" => ThorchainMemo::RepayLoan(RepayLoanMemo {
+                asset: MemoAsset::parse(fields.required(1)?)?,
+                destination: fields.required(2)?.to_string(),
+                min_out: fields.optional(3).map(parse_amount).transpose()?,
+            }),
+            "DONATE" | "%" | "D" => ThorchainMemo::Donate(DonateMemo {
+                pool: MemoAsset::parse(fields.required(1)?)?,
+            }),
+            "BOND" => ThorchainMemo::Bond(BondMemo {
+                node_address: fields.required(1)?.to_string(),
+                provider: fields.optional_string(2),
+                operator_fee_bps: fields
+                    .optional(3)
+                    .map(|fee| parse_basis_points(fee, MAX_BASIS_POINTS))
+                    .transpose()?,
+            }),
+            "UNBOND" => ThorchainMemo::Unbond(UnbondMemo {
+                node_address: fields.required(1)?.to_string(),
+                amount: parse_amount(fields.required(2)?)?,
+                provider: fields.optional_string(3),
+            }),
+            "LEAVE" => ThorchainMemo::Leave(LeaveMemo {
+                node_address: fields.required(1)?.to_string(),
+            }),
+            _ => return Err(SigningError(SigningErrorType::Error_invalid_memo)),
+        };
+        Ok(memo)
+    }
+
+    /// Builds the memo string.
+    /// `shortened` - whether to use the shortened function name if there is one, e.g. `=` instead of `SWAP`.
+    pub fn build(&self, shortened: bool) -> String {
+        let function = |full: &str, short: &str| {
+            if shortened {
+                short.to_string()
+            } else {
+                full.to_string()
+            }
+        };
+
+        let fields = match self {
+            ThorchainMemo::Swap(swap) => {
+                let limit = match swap.streaming {
+                    Some(streaming) => format!(
+                        "{}{STREAMING_SEPARATOR}{}{STREAMING_SEPARATOR}{}",
+                        swap.limit.unwrap_or_default(),
+                        streaming.interval,
+                        streaming.quantity
+                    ),
+                    None => optional_to_string(swap.limit),
+                };
+                vec![
+                    function("SWAP", "="),
+                    optional_to_string(swap.asset.as_ref()),
+                    optional_to_string(swap.destination.as_ref()),
+                    limit,
+                    swap.affiliates.join(&AFFILIATE_SEPARATOR.to_string()),
+                    join_to_string(&swap.affiliate_fees_bps, AFFILIATE_SEPARATOR),
+                    optional_to_string(swap.extra.as_ref()),
+                ]
+            },
+            ThorchainMemo::AddLiquidity(add) => vec![
+                function("ADD", "+"),
+                add.pool.to_string(),
+                optional_to_string(add.paired_address.as_ref()),
+                optional_to_string(add.affiliate.as_ref()),
+                optional_to_string(add.affiliate_fee_bps),
+            ],
+            ThorchainMemo::WithdrawLiquidity(withdraw) => vec![
+                function("WITHDRAW", "-"),
+                withdraw.pool.to_string(),
+                withdraw.basis_points.to_string(),
+                optional_to_string(withdraw.asset.as_ref()),
+            ],
+            ThorchainMemo::OpenLoan(loan) => vec![
+                function("LOAN+", "$+"),
+                loan.asset.to_string(),
+                loan.destination.clone(),
+                optional_to_string(loan.min_out),
+                optional_to_string(loan.affiliate.as_ref()),
+                optional_to_string(loan.affiliate_fee_bps),
+            ],
+            ThorchainMemo::RepayLoan(loan) => vec![
+                function("LOAN-", "$-"),
+                loan.asset.to_string(),
+                loan.destination.clone(),
+                optional_to_string(loan.min_out),
+            ],
+            ThorchainMemo::Donate(donate) => vec![function("DONATE", "%"), donate.pool.to_string()],
+            ThorchainMemo::Bond(bond) => vec![
+                "BOND".to_string(),
+                bond.node_address.clone(),
+                optional_to_string(bond.provider.as_ref()),
+                optional_to_string(bond.operator_fee_bps),
+            ],
+            ThorchainMemo::Unbond(unbond) => vec![
+                "UNBOND".to_string(),
+                unbond.node_address.clone(),
+                unbond.amount.to_string(),
+                optional_to_string(unbond.provider.as_ref()),
+            ],
+            ThorchainMemo::Leave(leave) => vec!["LEAVE".to_string(), leave.node_address.clone()],
+        };
+        join_fields(fields)
+    }
+}
+
+struct MemoFields<'a> {
+    fields: Vec<&'a str>,
+}
+
+impl<'a> MemoFields<'a> {
+    fn new(memo: &'a str) -> MemoFields<'a> {
+        MemoFields {
+            fields: memo.split(FIELD_SEPARATOR).collect(),
+        }
+    }
+
+    /// Returns a non-empty field at the given index.
+    fn optional(&self, idx: usize) -> Option<&'a str> {
+        self.fields
+            .get(idx)
+            .copied()
+            .filter(|field| !field.is_empty())
+    }
+
+    fn optional_string(&self, idx: usize) -> Option<String> {
+        self.optional(idx).map(str::to_string)
+    }
+
+    fn optional_affiliate_fee(&self, idx: usize) -> SigningResult<Option<u64>> {
+        self.optional(idx)
+            .map(|fee| parse_basis_points(fee, MAX_AFFILIATE_FEE_BPS))
+            .transpose()
+    }
+
+    fn required(&self, idx: usize) -> SigningResult<&'a str> {
+        self.optional(idx)
+            .ok_or(SigningError(SigningErrorType::Error_invalid_memo))
+    }
+
+    /// Returns all fields starting from the given index joined back with the separator.
+    fn remaining(&self, idx: usize) -> Option<String> {
+        let remaining = self.fields.get(idx..)?.join(&FIELD_SEPARATOR.to_string());
+        if remaining.is_empty() {
+            return None;
+        }
+        Some(remaining)
+    }
+}
+
+fn optional_to_string<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn join_to_string<T: ToString>(values: &[T], separator: char) -> String {
+    values
+        .iter()
+        .map(T::to_string)
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+}
+
+/// Joins the fields skipping the trailing empty ones.
+fn join_fields(mut fields: Vec<String>) -> String {
+    while matches!(fields.last(), Some(last) if last.is_empty()) {
+        fields.pop();
+    }
+    fields.join(&FIELD_SEPARATOR.to_string())
+}
+
+/// Parses an amount in the integer or scientific notation, e.g. `1000000` or `1e6`.
+fn parse_amount(amount: &str) -> SigningResult<u128> {
+    let invalid_memo = || SigningError(SigningErrorType::Error_invalid_memo);
+
+    let Some((mantissa, exponent)) = amount.split_once(['e', 'E']) else {
+        return u128::from_str(amount).map_err(|_| invalid_memo());
+    };
+    let mantissa = u128::from_str(mantissa).map_err(|_| invalid_memo())?;
+    let exponent = u32::from_str(exponent).map_err(|_| invalid_memo())?;
+    10_u128
+        .checked_pow(exponent)
+        .and_then(|multiplier| mantissa.checked_mul(multiplier))
+        .ok_or_else(invalid_memo)
+}
+
+fn parse_basis_points(bps: &str, max: u64) -> SigningResult<u64> {
+    match u64::from_str(bps) {
+        Ok(bps) if bps <= max => Ok(bps),
+        _ => Err(SigningError(SigningErrorType::Error_invalid_memo)),
+    }
+}
+
+/// Parses the `/`-separated list of affiliates.
+fn parse_affiliates(field: &str) -> SigningResult<Vec<String>> {
+    field
+        .split(AFFILIATE_SEPARATOR)
+        .map(|affiliate| {
+            if affiliate.is_empty() {
+                return Err(SigningError(SigningErrorType::Error_invalid_memo));
+            }
+            Ok(affiliate.to_string())
+        })
+        .collect()
+}
+
+/// Parses the `/`-separated list of affiliate fees.
+fn parse_affiliate_fees(field: &str) -> SigningResult<Vec<u64>> {
+    field
+        .split(AFFILIATE_SEPARATOR)
+        .map(|fee| parse_basis_points(fee, MAX_AFFILIATE_FEE_BPS))
+        .collect()
+}
+
+/// Parses the limit from the `LIM/INTERVAL/QUANTITY` field.
+fn parse_limit(field: &str) -> SigningResult<Option<u128>> {
+    let limit = field.split(STREAMING_SEPARATOR).next().unwrap_or_default();
+    if limit.is_empty() {
+        return Ok(None);
+    }
+    parse_amount(limit).map(Some)
+}
+
+/// Parses the streaming parameters from the `LIM/INTERVAL/QUANTITY` field.
+fn parse_streaming(field: &str) -> SigningResult<Option<StreamingParams>> {
+    let mut parts = field.split(STREAMING_SEPARATOR).skip(1);
+    let Some(interval) = parts.next() else {
+        return Ok(None);
+    };
+
+    let interval =
+        u64::from_str(interval).map_err(|_| SigningError(SigningErrorType::Error_invalid_memo))?;
+    let quantity = match parts.next() {
+        Some(quantity) if !quantity.is_empty() => u64::from_str(quantity)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_memo))?,
+        _ => 0,
+    };
+    if parts.next().is_some() {
+        return Err(SigningError(SigningErrorType::Error_invalid_memo));
+    }
+    Ok(Some(StreamingParams { interval, quantity }))
+}
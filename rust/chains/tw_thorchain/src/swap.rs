@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::memo::asset::MemoAsset;
+use crate::memo::{StreamingParams, SwapMemo, ThorchainMemo, MAX_AFFILIATE_FEE_BPS};
+use std::str::FromStr;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_proto::Cosmos::Proto as CosmosProto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+use tw_proto::THORChain::Proto;
+
+pub struct ThorchainSwapBuilder;
+
+impl ThorchainSwapBuilder {
+    /// Converts a typed swap request to `thorchain/MsgDeposit` with the corresponding `SWAP` memo.
+    pub fn build_deposit(input: Proto::SwapDepositInput<'_>) -> Proto::SwapDepositOutput<'_> {
+        Self::build_deposit_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SwapDepositOutput, e))
+    }
+
+    fn build_deposit_impl(
+        input: Proto::SwapDepositInput<'_>,
+    ) -> SigningResult<Proto::SwapDepositOutput<'_>> {
+        let coin = input
+            .coin
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+
+        let limit = if input.to_amount_limit.is_empty() {
+            None
+        } else {
+            let limit = u128::from_str(&input.to_amount_limit)
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+            Some(limit)
+        };
+
+        let streaming = match (input.stream_interval, input.stream_quantity) {
+            (0, 0) => None,
+            // Streaming quantity makes no sense without an interval.
+            (0, _) => return Err(SigningError(SigningErrorType::Error_invalid_params)),
+            (interval, quantity) => Some(StreamingParams { interval, quantity }),
+        };
+
+        let (affiliates, affiliate_fees_bps) = input
+            .affiliates
+            .iter()
+            .map(|affiliate| {
+                let fee_bps = u64::from(affiliate.fee_bps);
+                if affiliate.name.is_empty() || fee_bps > MAX_AFFILIATE_FEE_BPS {
+                    return Err(SigningError(SigningErrorType::Error_invalid_params));
+                }
+                Ok((affiliate.name.to_string(), fee_bps))
+            })
+            .collect::<SigningResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let memo = ThorchainMemo::Swap(SwapMemo {
+            asset: Some(MemoAsset::parse(&input.to_asset)?),
+            destination: Some(input.to_address.to_string()),
+            limit,
+            streaming,
+            affiliates,
+            affiliate_fees_bps,
+            extra: None,
+        })
+        .build(true);
+
+        let deposit = CosmosProto::mod_Message::THORChainDeposit {
+            coins: vec![coin],
+            memo: memo.clone().into(),
+            signer: input.signer,
+        };
+        Ok(Proto::SwapDepositOutput {
+            message: Some(CosmosProto::Message {
+                message_oneof: MessageEnum::thorchain_deposit_message(deposit),
+            }),
+            memo: memo.into(),
+            ..Proto::SwapDepositOutput::default()
+        })
+    }
+}
@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::SigningErrorType;
+use tw_thorchain::memo::asset::{AssetKind, MemoAsset};
+use tw_thorchain::memo::{
+    AddLiquidityMemo, BondMemo, LeaveMemo, StreamingParams, SwapMemo, ThorchainMemo, UnbondMemo,
+    WithdrawLiquidityMemo,
+};
+
+fn test_memo_round_trip(memo_str: &str, shortened: bool, expected: ThorchainMemo) {
+    let memo = ThorchainMemo::parse(memo_str).unwrap();
+    assert_eq!(memo, expected);
+    assert_eq!(memo.build(shortened), memo_str);
+}
+
+fn test_memo_invalid(memo_str: &str) {
+    let error = ThorchainMemo::parse(memo_str).unwrap_err();
+    assert_eq!(error.0, SigningErrorType::Error_invalid_memo);
+}
+
+#[test]
+fn test_thorchain_memo_swap() {
+    // https://viewblock.io/thorchain/tx/0162213E7F9D85965B1C57FA3BF9603C655B542F358318303A7B00661AE42510
+    test_memo_round_trip(
+        "=:DOGE.DOGE:DNhRF1h8J4ZnB1bxp9kaqhVLYetkx1nSJ5::tr:0",
+        true,
+        ThorchainMemo::Swap(SwapMemo {
+            asset: Some(MemoAsset::new("DOGE", "DOGE", AssetKind::Layer1)),
+            destination: Some("DNhRF1h8J4ZnB1bxp9kaqhVLYetkx1nSJ5".to_string()),
+            affiliates: vec!["tr".to_string()],
+            affiliate_fees_bps: vec![0],
+            ..SwapMemo::default()
+        }),
+    );
+
+    test_memo_round_trip(
+        "SWAP:ETH.USDT-0XDAC17F958D2EE523A2206206994597C13D831EC7:0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7:1000000",
+        false,
+        ThorchainMemo::Swap(SwapMemo {
+            asset: Some(MemoAsset::new(
+                "ETH",
+                "USDT-0XDAC17F958D2EE523A2206206994597C13D831EC7",
+                AssetKind::Layer1,
+            )),
+            destination: Some("0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7".to_string()),
+            limit: Some(1_000_000),
+            ..SwapMemo::default()
+        }),
+    );
+}
+
+#[test]
+fn test_thorchain_memo_swap_streaming() {
+    test_memo_round_trip(
+        "=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8:1234/3/10:t:15:extra:data",
+        true,
+        ThorchainMemo::Swap(SwapMemo {
+            asset: Some(MemoAsset::new("BTC", "BTC", AssetKind::Layer1)),
+            destination: Some("bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8".to_string()),
+            limit: Some(1234),
+            streaming: Some(StreamingParams {
+                interval: 3,
+                quantity: 10,
+            }),
+            affiliates: vec!["t".to_string()],
+            affiliate_fees_bps: vec![15],
+            extra: Some("extra:data".to_string()),
+        }),
+    );
+}
+
+#[test]
+fn test_thorchain_memo_swap_multiple_affiliates() {
+    // One fee per affiliate.
+    test_memo_round_trip(
+        "=:ETH.ETH:0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7:0/1/0:t/ss:10/5",
+        true,
+        ThorchainMemo::Swap(SwapMemo {
+            asset: Some(MemoAsset::new("ETH", "ETH", AssetKind::Layer1)),
+            destination: Some("0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7".to_string()),
+            limit: Some(0),
+            streaming: Some(StreamingParams {
+                interval: 1,
+                quantity: 0,
+            }),
+            affiliates: vec!["t".to_string(), "ss".to_string()],
+            affiliate_fees_bps: vec![10, 5],
+            ..SwapMemo::default()
+        }),
+    );
+
+    // A single fee is applied to every affiliate.
+    test_memo_round_trip(
+        "=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8::t/ss/tr:20",
+        true,
+        ThorchainMemo::Swap(SwapMemo {
+            asset: Some(MemoAsset::new("BTC", "BTC", AssetKind::Layer1)),
+            destination: Some("bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8".to_string()),
+            affiliates: vec!["t".to_string(), "ss".to_string(), "tr".to_string()],
+            affiliate_fees_bps: vec![20],
+            ..SwapMemo::default()
+        }),
+    );
+}
+
+#[test]
+fn test_thorchain_memo_swap_shortened() {
+    // Shortened function name, asset and the scientific notation are parsed, but the full notation is built.
+    let memo =
+        ThorchainMemo::parse("s:b:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8:3e6/1").unwrap();
+    let expected = ThorchainMemo::Swap(SwapMemo {
+        asset: Some(MemoAsset::new("BTC", "BTC", AssetKind::Layer1)),
+        destination: Some("bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8".to_string()),
+        limit: Some(3_000_000),
+        streaming: Some(StreamingParams {
+            interval: 1,
+            quantity: 0,
+        }),
+        ..SwapMemo::default()
+    });
+    assert_eq!(memo, expected);
+    assert_eq!(
+        memo.build(false),
+        "SWAP:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8:3000000/1/0"
+    );
+}
+
+#[test]
+fn test_thorchain_memo_liquidity() {
+    test_memo_round_trip(
+        "+:BTC/BTC",
+        true,
+        ThorchainMemo::AddLiquidity(AddLiquidityMemo {
+            pool: MemoAsset::new("BTC", "BTC", AssetKind::Synth),
+            paired_address: None,
+            affiliate: None,
+            affiliate_fee_bps: None,
+        }),
+    );
+
+    test_memo_round_trip(
+        "ADD:ETH.ETH:thor1e2ryt8asq4gu0h6z2sx9u7rfrykgxwkmr9upxn:tr:10",
+        false,
+        ThorchainMemo::AddLiquidity(AddLiquidityMemo {
+            pool: MemoAsset::new("ETH", "ETH", AssetKind::Layer1),
+            paired_address: Some("thor1e2ryt8asq4gu0h6z2sx9u7rfrykgxwkmr9upxn".to_string()),
+            affiliate: Some("tr".to_string()),
+            affiliate_fee_bps: Some(10),
+        }),
+    );
+
+    test_memo_round_trip(
+        "WITHDRAW:BTC.BTC:10000:THOR.RUNE",
+        false,
+        ThorchainMemo::WithdrawLiquidity(WithdrawLiquidityMemo {
+            pool: MemoAsset::new("BTC", "BTC", AssetKind::Layer1),
+            basis_points: 10_000,
+            asset: Some(MemoAsset::new("THOR", "RUNE", AssetKind::Layer1)),
+        }),
+    );
+}
+
+#[test]
+fn test_thorchain_memo_bond() {
+    let node_address = "thor1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r";
+
+    test_memo_round_trip(
+        &format!("BOND:{node_address}::2000"),
+        false,
+        ThorchainMemo::Bond(BondMemo {
+            node_address: node_address.to_string(),
+            provider: None,
+            operator_fee_bps: Some(2000),
+        }),
+    );
+    test_memo_round_trip(
+        &format!("UNBOND:{node_address}:100000000"),
+        true,
+        ThorchainMemo::Unbond(UnbondMemo {
+            node_address: node_address.to_string(),
+            amount: 100_000_000,
+            provider: None,
+        }),
+    );
+    test_memo_round_trip(
+        &format!("LEAVE:{node_address}"),
+        true,
+        ThorchainMemo::Leave(LeaveMemo {
+            node_address: node_address.to_string(),
+        }),
+    );
+}
+
+#[test]
+fn test_thorchain_memo_invalid() {
+    test_memo_invalid("");
+    test_memo_invalid("UNKNOWN:BTC.BTC");
+    test_memo_invalid("=:BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8");
+    test_memo_invalid("=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8:1e40");
+    test_memo_invalid("=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8:0/1/2/3");
+    // Affiliate fee is greater than 10%.
+    test_memo_invalid("=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8::tr:1001");
+    test_memo_invalid("=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8::t/ss:10/1001");
+    // The number of fees must be either one or the number of affiliates.
+    test_memo_invalid("=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8::t/ss/tr:10/5");
+    test_memo_invalid("=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8::t//tr:10");
+    test_memo_invalid("=:BTC.BTC:bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8::t/ss:10/");
+    test_memo_invalid("WITHDRAW:BTC.BTC:10001");
+    test_memo_invalid("UNBOND:thor1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r");
+}
@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::{base64, hex};
+use tw_hash::H256;
+use tw_memory::Data;
+
+/// Basechain, the workchain where the most of the accounts live.
+pub const BASECHAIN: i32 = 0;
+/// Masterchain, the workchain of the validators and the system contracts.
+pub const MASTERCHAIN: i32 = -1;
+
+const BOUNCEABLE_TAG: u8 = 0x11;
+const NON_BOUNCEABLE_TAG: u8 = 0x51;
+const TEST_ONLY_FLAG: u8 = 0x80;
+
+/// Length of the user-friendly address: tag, workchain, account hash and CRC16 checksum.
+const USER_FRIENDLY_LEN: usize = 36;
+/// Length of the Base64-encoded user-friendly address.
+const USER_FRIENDLY_BASE64_LEN: usize = 48;
+
+/// TON account address.
+/// Can be represented either in the raw form `<workchain>:<account hash hex>`,
+/// or in the user-friendly Base64 form that additionally encodes the bounceable and testnet flags.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TonAddress {
+    workchain: i32,
+    hash: H256,
+    user_friendly: bool,
+    bounceable: bool,
+    testnet: bool,
+}
+
+impl TonAddress {
+    /// Creates a user-friendly bounceable mainnet address.
+    pub fn new(workchain: i32, hash: H256) -> TonAddress {
+        TonAddress {
+            workchain,
+            hash,
+            user_friendly: true,
+            bounceable: true,
+            testnet: false,
+        }
+    }
+
+    pub fn workchain(&self) -> i32 {
+        self.workchain
+    }
+
+    pub fn hash(&self) -> H256 {
+        self.hash
+    }
+
+    pub fn is_user_friendly(&self) -> bool {
+        self.user_friendly
+    }
+
+    pub fn is_bounceable(&self) -> bool {
+        self.bounceable
+    }
+
+    pub fn is_testnet(&self) -> bool {
+        self.testnet
+    }
+
+    /// Returns the same address with the given `bounceable` flag.
+    pub fn with_bounceable(mut self, bounceable: bool) -> TonAddress {
+        self.bounceable = bounceable;
+        self
+    }
+
+    /// Returns the same address in the normalized form: user-friendly, bounceable and not test-only.
+    pub fn normalized(self) -> TonAddress {
+        TonAddress::new(self.workchain, self.hash)
+    }
+
+    /// Returns the raw `<workchain>:<account hash hex>` representation.
+    pub fn to_raw_string(&self) -> String {
+        format!(
+            "{}:{}",
+            self.workchain,
+            hex::encode(self.hash.as_slice(), false)
+        )
+    }
+
+    /// Returns the user-friendly URL-safe Base64 representation.
+    pub fn to_user_friendly_string(&self, bounceable: bool, testnet: bool) -> String {
+        let mut tag = if bounceable {
+            BOUNCEABLE_TAG
+        } else {
+            NON_BOUNCEABLE_TAG
+        };
+        if testnet {
+            tag |= TEST_ONLY_FLAG;
+        }
+
+        let mut data = Vec::with_capacity(USER_FRIENDLY_LEN);
+        data.push(tag);
+        // Workchain is stored as `int8`.
+        data.push(self.workchain as u8);
+        data.extend_from_slice(self.hash.as_slice());
+        data.extend_from_slice(&crc16(&data).to_be_bytes());
+
+        base64::encode(&data, true)
+    }
+
+    fn from_raw_str(s: &str) -> AddressResult<TonAddress> {
+        let (workchain, hash) = s.split_once(':').ok_or(AddressError::InvalidInput)?;
+
+        let workchain = i8::from_str(workchain).map_err(|_| AddressError::InvalidInput)?;
+        let workchain = check_workchain(workchain as i32)?;
+
+        if hash.len() != H256::len() * 2 {
+            return Err(AddressError::InvalidInput);
+        }
+        let hash = H256::from_str(hash).map_err(|_| AddressError::FromHexError)?;
+
+        Ok(TonAddress {
+            workchain,
+            hash,
+            user_friendly: false,
+            bounceable: false,
+            testnet: false,
+        })
+    }
+
+    fn from_user_friendly_str(s: &str) -> AddressResult<TonAddress> {
+        if s.len() != USER_FRIENDLY_BASE64_LEN {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let is_url = s.contains(['-', '_']);
+        let data = base64::decode(s, is_url).map_err(|_| AddressError::InvalidInput)?;
+        if data.len() != USER_FRIENDLY_LEN {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let (payload, checksum) = data.split_at(USER_FRIENDLY_LEN - 2);
+        if crc16(payload).to_be_bytes() != checksum {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let testnet = payload[0] & TEST_ONLY_FLAG != 0;
+        let bounceable = match payload[0] & !TEST_ONLY_FLAG {
+            BOUNCEABLE_TAG => true,
+            NON_BOUNCEABLE_TAG => false,
+            _ => return Err(AddressError::InvalidInput),
+        };
+        let workchain = check_workchain(payload[1] as i8 as i32)?;
+        let hash = H256::try_from(&payload[2..]).map_err(|_| AddressError::InvalidInput)?;
+
+        Ok(TonAddress {
+            workchain,
+            hash,
+            user_friendly: true,
+            bounceable,
+            testnet,
+        })
+    }
+}
+
+impl CoinAddress for TonAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.hash.to_vec()
+    }
+}
+
+impl FromStr for TonAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            TonAddress::from_raw_str(s)
+        } else {
+            TonAddress::from_user_friendly_str(s)
+        }
+    }
+}
+
+/// Displays the address in the same format it was parsed from.
+impl fmt::Display for TonAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.user_friendly {
+            write!(
+                f,
+                "{}",
+                self.to_user_friendly_string(self.bounceable, self.testnet)
+            )
+        } else {
+            write!(f, "{}", self.to_raw_string())
+        }
+    }
+}
+
+fn check_workchain(workchain: i32) -> AddressResult<i32> {
+    match workchain {
+        BASECHAIN | MASTERCHAIN => Ok(workchain),
+        _ => Err(AddressError::InvalidInput),
+    }
+}
+
+/// CRC-16/XMODEM checksum used by user-friendly addresses.
+fn crc16(data: &[u8]) -> u16 {
+    const POLY: u16 = 0x1021;
+
+    let mut crc = 0_u16;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ POLY
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW: &str = "0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae";
+
+    #[test]
+    fn test_address_formats() {
+        let raw = TonAddress::from_str(RAW).unwrap();
+        assert!(!raw.is_user_friendly());
+        assert_eq!(raw.to_string(), RAW);
+
+        let uppercase = TonAddress::from_str(
+            "0:8A8627861A5DD96C9DB3CE0807B122DA5ED473934CE7568A5B4B1C361CBB28AE",
+        )
+        .unwrap();
+        assert_eq!(uppercase.to_string(), RAW);
+
+        let test_cases = [
+            (
+                "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl",
+                true,
+                false,
+            ),
+            (
+                "UQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorhqg",
+                false,
+                false,
+            ),
+            (
+                "kQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorvzv",
+                true,
+                true,
+            ),
+            (
+                "0QCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorqEq",
+                false,
+                true,
+            ),
+        ];
+        for (s, bounceable, testnet) in test_cases {
+            let addr = TonAddress::from_str(s).unwrap();
+            assert!(addr.is_user_friendly());
+            assert_eq!(addr.is_bounceable(), bounceable);
+            assert_eq!(addr.is_testnet(), testnet);
+            assert_eq!(addr.to_string(), s);
+            assert_eq!(addr.to_raw_string(), RAW);
+            assert_eq!(addr.hash(), raw.hash());
+        }
+    }
+
+    #[test]
+    fn test_address_masterchain() {
+        let addr =
+            TonAddress::from_str("Ef_drj6m7jcME0fWTA-OwFC-6F0Le2SuOUQ6ibRc3Vz8HL8H").unwrap();
+        assert_eq!(addr.workchain(), MASTERCHAIN);
+        assert_eq!(
+            TonAddress::from_str(
+                "-1:ddae3ea6ee370c1347d64c0f8ec050bee85d0b7b64ae39443a89b45cdd5cfc1c"
+            )
+            .unwrap()
+            .normalized()
+            .to_string(),
+            "Ef_drj6m7jcME0fWTA-OwFC-6F0Le2SuOUQ6ibRc3Vz8HL8H"
+        );
+        assert_eq!(
+            TonAddress::from_str(&addr.to_raw_string())
+                .unwrap()
+                .workchain(),
+            MASTERCHAIN
+        );
+    }
+
+    #[test]
+    fn test_address_invalid() {
+        let invalid = [
+            // Unsupported workchain.
+            "1:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae",
+            // Hash is too short.
+            "0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28",
+            // Invalid length.
+            "EQIcIZpPoMnWXd8FbC1KaLtcyIgVUlwsbFK_3P6f5uf_YyzoE",
+            // Invalid workchain.
+            "EQEMzVEZ8n9_5GFEdsNPfl6Tx64Jjld88gEvi4BDFly4CSyl",
+            // Invalid checksum.
+            "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsormVH",
+            "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorpcF",
+        ];
+        for s in invalid {
+            TonAddress::from_str(s).unwrap_err();
+        }
+    }
+}
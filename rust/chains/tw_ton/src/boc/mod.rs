@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::boc::raw::{RawBagOfCells, RawCell};
+use crate::cell::{Cell, CellArc, CellError, CellResult};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tw_encoding::{base64, hex};
+use tw_hash::H256;
+
+mod raw;
+
+/// Whether the serialized Bag of Cells should end with a CRC32C checksum by default.
+pub const HAS_CRC32: bool = true;
+
+/// Bag of Cells: a tree (or several trees) of cells serialized into bytes.
+#[derive(Debug, PartialEq)]
+pub struct BagOfCells {
+    roots: Vec<CellArc>,
+}
+
+impl BagOfCells {
+    pub fn from_root(root: Cell) -> BagOfCells {
+        BagOfCells {
+            roots: vec![Arc::new(root)],
+        }
+    }
+
+    pub fn roots(&self) -> &[CellArc] {
+        &self.roots
+    }
+
+    /// Returns the only root of the bag.
+    pub fn single_root(&self) -> CellResult<&CellArc> {
+        match self.roots.as_slice() {
+            [root] => Ok(root),
+            _ => Err(CellError::InvalidBoc),
+        }
+    }
+
+    pub fn parse(data: &[u8]) -> CellResult<BagOfCells> {
+        let raw = RawBagOfCells::parse(data)?;
+
+        // Cells reference the cells that follow them only,
+        // so build the cells starting from the end of the list.
+        let mut cells: Vec<Option<CellArc>> = vec![None; raw.cells.len()];
+        for (idx, raw_cell) in raw.cells.into_iter().enumerate().rev() {
+            let references = raw_cell
+                .references
+                .iter()
+                .map(|child| cells[*child].clone().ok_or(CellError::InvalidBoc))
+                .collect::<CellResult<Vec<_>>>()?;
+            let cell = Cell::new(raw_cell.data, raw_cell.bit_len, references)?;
+            cells[idx] = Some(Arc::new(cell));
+        }
+
+        let roots = raw
+            .roots
+            .iter()
+            .map(|root| cells[*root].clone().ok_or(CellError::InvalidBoc))
+            .collect::<CellResult<Vec<_>>>()?;
+        Ok(BagOfCells { roots })
+    }
+
+    pub fn parse_hex(s: &str) -> CellResult<BagOfCells> {
+        let data = hex::decode(s).map_err(|_| CellError::InvalidBoc)?;
+        BagOfCells::parse(&data)
+    }
+
+    pub fn parse_base64(s: &str) -> CellResult<BagOfCells> {
+        let data = base64::decode(s, false).map_err(|_| CellError::InvalidBoc)?;
+        BagOfCells::parse(&data)
+    }
+
+    /// Serializes the bag with the minimum possible reference and offset sizes and without an index.
+    pub fn serialize(&self, has_crc32: bool) -> CellResult<Vec<u8>> {
+        self.to_raw().serialize(has_crc32)
+    }
+
+    pub fn to_base64(&self, has_crc32: bool) -> CellResult<String> {
+        self.serialize(has_crc32)
+            .map(|data| base64::encode(&data, false))
+    }
+
+    /// Orders the cells so that every cell is followed by its children,
+    /// deduplicating identical cells by their hashes.
+    fn to_raw(&self) -> RawBagOfCells {
+        let mut visited = HashMap::new();
+        let mut post_order = Vec::new();
+        for root in self.roots.iter() {
+            visit_cell(root, &mut visited, &mut post_order);
+        }
+
+        // Reverse the post-order to put the parents before their children.
+        let count = post_order.len();
+        let index_of = |hash: &H256| count - 1 - visited[hash];
+
+        let cells = post_order
+            .iter()
+            .rev()
+            .map(|cell| RawCell {
+                data: cell.data().to_vec(),
+                bit_len: cell.bit_len(),
+                references: cell
+                    .references()
+                    .iter()
+                    .map(|child| index_of(&child.hash()))
+                    .collect(),
+            })
+            .collect();
+        let roots = self
+            .roots
+            .iter()
+            .map(|root| index_of(&root.hash()))
+            .collect();
+
+        RawBagOfCells { cells, roots }
+    }
+}
+
+fn visit_cell(cell: &CellArc, visited: &mut HashMap<H256, usize>, post_order: &mut Vec<CellArc>) {
+    if visited.contains_key(&cell.hash()) {
+        return;
+    }
+    for child in cell.references() {
+        visit_cell(child, visited, post_order);
+    }
+    visited.insert(cell.hash(), post_order.len());
+    post_order.push(CellArc::clone(cell));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::CellBuilder;
+
+    #[test]
+    fn test_boc_serialize_parse() {
+        let mut child = CellBuilder::new();
+        child
+            .store_u32(0xDEADBEEF)
+            .unwrap()
+            .store_bit(true)
+            .unwrap();
+        let child = Arc::new(child.build().unwrap());
+
+        let mut root = CellBuilder::new();
+        root.store_uint(3, 0b101)
+            .unwrap()
+            .store_reference(child.clone())
+            .unwrap()
+            // The same cell is serialized once.
+            .store_reference(child)
+            .unwrap();
+        let root = root.build().unwrap();
+        let root_hash = root.hash();
+
+        let boc = BagOfCells::from_root(root);
+        let encoded = boc.serialize(HAS_CRC32).unwrap();
+        assert_eq!(
+            hex::encode(&encoded, false),
+            "b5ee9c7241010201000c000201b001010009deadbeefc016468749"
+        );
+
+        let decoded = BagOfCells::parse(&encoded).unwrap();
+        assert_eq!(decoded.single_root().unwrap().hash(), root_hash);
+        assert_eq!(decoded, boc);
+
+        let without_crc = boc.serialize(false).unwrap();
+        assert_eq!(without_crc.len(), encoded.len() - 4);
+        assert_eq!(BagOfCells::parse(&without_crc).unwrap(), boc);
+    }
+
+    #[test]
+    fn test_boc_parse_invalid() {
+        let mut encoded =
+            hex::decode("b5ee9c7241010201000c000201b001010009deadbeefc016468749").unwrap();
+        // Corrupt the checksum.
+        *encoded.last_mut().unwrap() ^= 1;
+        assert_eq!(BagOfCells::parse(&encoded), Err(CellError::InvalidBoc));
+
+        // Unknown magic.
+        assert_eq!(
+            BagOfCells::parse_hex("b5ee9c7341010201000c000201b001010009deadbeefc016468749"),
+            Err(CellError::InvalidBoc)
+        );
+    }
+}
@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::cell::{CellError, CellResult};
+use tw_hash::crc32::crc32c;
+
+pub(crate) const BOC_MAGIC: u32 = 0xb5ee9c72;
+
+const HAS_INDEX_FLAG: u8 = 0x80;
+const HAS_CRC32_FLAG: u8 = 0x40;
+const HAS_CACHE_BITS_FLAG: u8 = 0x20;
+const SIZE_MASK: u8 = 0x07;
+
+const EXOTIC_FLAG: u8 = 0x08;
+const STORE_HASHES_FLAG: u8 = 0x10;
+const REFS_MASK: u8 = 0x07;
+const LEVEL_SHIFT: u8 = 5;
+
+/// A cell as it is stored in a Bag of Cells: references are indexes within the bag.
+#[derive(Debug, PartialEq)]
+pub(crate) struct RawCell {
+    pub data: Vec<u8>,
+    pub bit_len: usize,
+    pub references: Vec<usize>,
+}
+
+/// The `serialized_boc` TL-B structure with a list of cells in the topological order,
+/// i.e. every cell is followed by the cells it references.
+#[derive(Debug, PartialEq)]
+pub(crate) struct RawBagOfCells {
+    pub cells: Vec<RawCell>,
+    pub roots: Vec<usize>,
+}
+
+impl RawBagOfCells {
+    pub fn parse(data: &[u8]) -> CellResult<RawBagOfCells> {
+        let mut reader = BocReader::new(data);
+
+        if reader.read_uint(4)? as u32 != BOC_MAGIC {
+            return Err(CellError::InvalidBoc);
+        }
+
+        let flags = reader.read_u8()?;
+        let has_index = flags & HAS_INDEX_FLAG != 0;
+        let has_crc32 = flags & HAS_CRC32_FLAG != 0;
+        let has_cache_bits = flags & HAS_CACHE_BITS_FLAG != 0;
+        let ref_size = (flags & SIZE_MASK) as usize;
+        if has_cache_bits && !has_index {
+            return Err(CellError::InvalidBoc);
+        }
+        if ref_size == 0 || ref_size > 4 {
+            return Err(CellError::InvalidBoc);
+        }
+
+        let offset_size = reader.read_u8()? as usize;
+        if offset_size == 0 || offset_size > 8 {
+            return Err(CellError::InvalidBoc);
+        }
+
+        if has_crc32 {
+            let payload_len = data.len().checked_sub(4).ok_or(CellError::InvalidBoc)?;
+            let (payload, checksum) = data.split_at(payload_len);
+            if checksum != crc32c(payload).to_le_bytes().as_slice() {
+                return Err(CellError::InvalidBoc);
+            }
+        }
+
+        let cells_count = reader.read_uint(ref_size)?;
+        let roots_count = reader.read_uint(ref_size)?;
+        let absent_count = reader.read_uint(ref_size)?;
+        if roots_count == 0 || roots_count > cells_count {
+            return Err(CellError::InvalidBoc);
+        }
+        if absent_count > 0 {
+            return Err(CellError::UnsupportedCell);
+        }
+        // Total size of the serialized cells.
+        let _total_cells_size = reader.read_uint(offset_size)?;
+
+        let roots = (0..roots_count)
+            .map(|_| reader.read_uint(ref_size))
+            .collect::<CellResult<Vec<_>>>()?;
+        if roots.iter().any(|root| *root >= cells_count) {
+            return Err(CellError::InvalidBoc);
+        }
+
+        if has_index {
+            let index_size = cells_count
+                .checked_mul(offset_size)
+                .ok_or(CellError::InvalidBoc)?;
+            reader.read_bytes(index_size)?;
+        }
+
+        let cells = (0..cells_count)
+            .map(|idx| Self::read_cell(&mut reader, idx, cells_count, ref_size))
+            .collect::<CellResult<Vec<_>>>()?;
+
+        if has_crc32 {
+            reader.read_bytes(4)?;
+        }
+        reader.finish()?;
+
+        Ok(RawBagOfCells { cells, roots })
+    }
+
+    pub fn serialize(&self, has_crc32: bool) -> CellResult<Vec<u8>> {
+        let ref_size = min_bytes_len(self.cells.len() as u64);
+
+        let mut cells_data = Vec::new();
+        for cell in self.cells.iter() {
+            Self::write_cell(&mut cells_data, cell, ref_size)?;
+        }
+        let offset_size = min_bytes_len(cells_data.len() as u64);
+
+        let mut flags = ref_size as u8;
+        if has_crc32 {
+            flags |= HAS_CRC32_FLAG;
+        }
+
+        let mut out = Vec::with_capacity(cells_data.len() + 32);
+        out.extend_from_slice(&BOC_MAGIC.to_be_bytes());
+        out.push(flags);
+        out.push(offset_size as u8);
+        write_uint(&mut out, self.cells.len() as u64, ref_size);
+        write_uint(&mut out, self.roots.len() as u64, ref_size);
+        // No absent cells.
+        write_uint(&mut out, 0, ref_size);
+        write_uint(&mut out, cells_data.len() as u64, offset_size);
+        for root in self.roots.iter() {
+            write_uint(&mut out, *root as u64, ref_size);
+        }
+        out.extend_from_slice(&cells_data);
+
+        if has_crc32 {
+            let checksum = crc32c(&out);
+            out.extend_from_slice(&checksum.to_le_bytes());
+        }
+        Ok(out)
+    }
+
+    fn read_cell(
+        reader: &mut BocReader,
+        idx: usize,
+        cells_count: usize,
+        ref_size: usize,
+    ) -> CellResult<RawCell> {
+        let d1 = reader.read_u8()?;
+        let d2 = reader.read_u8()?;
+
+        let refs_count = (d1 & REFS_MASK) as usize;
+        let is_exotic = d1 & EXOTIC_FLAG != 0;
+        let level = d1 >> LEVEL_SHIFT;
+        if is_exotic || level != 0 || refs_count > 4 {
+            return Err(CellError::UnsupportedCell);
+        }
+        if d1 & STORE_HASHES_FLAG != 0 {
+            return Err(CellError::UnsupportedCell);
+        }
+
+        let is_aligned = d2 % 2 == 0;
+        let data_len = (d2 as usize + 1) / 2;
+        let mut data = reader.read_bytes(data_len)?.to_vec();
+
+        let bit_len = if is_aligned {
+            data_len * 8
+        } else {
+            // Remove the completion tag: the lowest set bit of the last byte.
+            let last = data.last_mut().ok_or(CellError::InvalidBoc)?;
+            if *last == 0 {
+                return Err(CellError::InvalidBoc);
+            }
+            let tag_position = last.trailing_zeros() as usize;
+            *last &= !(1 << tag_position);
+            data_len * 8 - tag_position - 1
+        };
+
+        let references = (0..refs_count)
+            .map(|_| {
+                let child = reader.read_uint(ref_size)?;
+                // A cell can reference the cells that follow it only.
+                if child <= idx || child >= cells_count {
+                    return Err(CellError::InvalidBoc);
+                }
+                Ok(child)
+            })
+            .collect::<CellResult<Vec<_>>>()?;
+
+        Ok(RawCell {
+            data,
+            bit_len,
+            references,
+        })
+    }
+
+    fn write_cell(out: &mut Vec<u8>, cell: &RawCell, ref_size: usize) -> CellResult<()> {
+        if cell.data.len() != (cell.bit_len + 7) / 8 || cell.references.len() > 4 {
+            return Err(CellError::InvalidBoc);
+        }
+
+        let d1 = cell.references.len() as u8;
+        let d2 = (cell.bit_len / 8 + (cell.bit_len + 7) / 8) as u8;
+        out.push(d1);
+        out.push(d2);
+
+        let data_start = out.len();
+        out.extend_from_slice(&cell.data);
+        if cell.bit_len % 8 != 0 {
+            // Append the completion tag.
+            if let Some(last) = out[data_start..].last_mut() {
+                *last |= 0x80 >> (cell.bit_len % 8);
+            }
+        }
+
+        for child in cell.references.iter() {
+            write_uint(out, *child as u64, ref_size);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the minimum number of bytes required to store the `value` (at least 1).
+fn min_bytes_len(value: u64) -> usize {
+    let bits = (u64::BITS - value.leading_zeros()) as usize;
+    ((bits + 7) / 8).max(1)
+}
+
+fn write_uint(out: &mut Vec<u8>, value: u64, bytes_len: usize) {
+    let bytes = value.to_be_bytes();
+    out.extend_from_slice(&bytes[bytes.len() - bytes_len..]);
+}
+
+/// A cursor over a serialized Bag of Cells.
+struct BocReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BocReader<'a> {
+    fn new(data: &'a [u8]) -> BocReader<'a> {
+        BocReader { data }
+    }
+
+    fn read_u8(&mut self) -> CellResult<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    /// Reads a big-endian unsigned integer of `bytes_len` bytes.
+    fn read_uint(&mut self, bytes_len: usize) -> CellResult<usize> {
+        let bytes = self.read_bytes(bytes_len)?;
+        let value = bytes
+            .iter()
+            .fold(0_u64, |acc, byte| (acc << 8) | *byte as u64);
+        usize::try_from(value).map_err(|_| CellError::InvalidBoc)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> CellResult<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(CellError::InvalidBoc);
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn finish(self) -> CellResult<()> {
+        if !self.data.is_empty() {
+            return Err(CellError::InvalidBoc);
+        }
+        Ok(())
+    }
+}
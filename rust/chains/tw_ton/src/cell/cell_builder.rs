@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::cell::{Cell, CellArc, CellError, CellResult};
+use std::sync::Arc;
+
+/// A TL-B cell builder that writes data bit by bit, most significant bit first.
+#[derive(Default)]
+pub struct CellBuilder {
+    data: Vec<u8>,
+    bit_len: usize,
+    references: Vec<CellArc>,
+}
+
+impl CellBuilder {
+    pub fn new() -> CellBuilder {
+        CellBuilder::default()
+    }
+
+    pub fn store_bit(&mut self, bit: bool) -> CellResult<&mut Self> {
+        self.check_bits_capacity(1)?;
+        self.push_bit(bit);
+        Ok(self)
+    }
+
+    /// Stores the lowest `bit_len` bits of the `value`.
+    pub fn store_uint(&mut self, bit_len: usize, value: u128) -> CellResult<&mut Self> {
+        if bit_len > u128::BITS as usize || (bit_len < u128::BITS as usize && value >> bit_len != 0)
+        {
+            return Err(CellError::InvalidValue);
+        }
+        self.check_bits_capacity(bit_len)?;
+        for i in (0..bit_len).rev() {
+            self.push_bit((value >> i) & 1 == 1);
+        }
+        Ok(self)
+    }
+
+    /// Stores the `value` as a `bit_len`-bit two's complement integer.
+    pub fn store_int(&mut self, bit_len: usize, value: i64) -> CellResult<&mut Self> {
+        if bit_len == 0 || bit_len > i64::BITS as usize {
+            return Err(CellError::InvalidValue);
+        }
+        let min = -(1_i128 << (bit_len - 1));
+        let max = (1_i128 << (bit_len - 1)) - 1;
+        if !(min..=max).contains(&(value as i128)) {
+            return Err(CellError::InvalidValue);
+        }
+        let mask = u128::MAX >> (u128::BITS as usize - bit_len);
+        self.store_uint(bit_len, value as i128 as u128 & mask)
+    }
+
+    pub fn store_u8(&mut self, value: u8) -> CellResult<&mut Self> {
+        self.store_uint(8, value as u128)
+    }
+
+    pub fn store_u32(&mut self, value: u32) -> CellResult<&mut Self> {
+        self.store_uint(32, value as u128)
+    }
+
+    pub fn store_u64(&mut self, value: u64) -> CellResult<&mut Self> {
+        self.store_uint(64, value as u128)
+    }
+
+    /// Stores the bytes as is.
+    pub fn store_slice(&mut self, bytes: &[u8]) -> CellResult<&mut Self> {
+        self.check_bits_capacity(bytes.len() * 8)?;
+        for byte in bytes {
+            self.store_u8(*byte)?;
+        }
+        Ok(self)
+    }
+
+    /// Stores the bytes as `SnakeData`: as many whole bytes as fit into the current cell,
+    /// and the rest in a chain of referenced cells.
+    pub fn store_snake_bytes(&mut self, bytes: &[u8]) -> CellResult<&mut Self> {
+        let capacity = (Cell::MAX_BITS - self.bit_len) / 8;
+        let (head, tail) = bytes.split_at(bytes.len().min(capacity));
+        self.store_slice(head)?;
+
+        if !tail.is_empty() {
+            let mut tail_builder = CellBuilder::new();
+            tail_builder.store_snake_bytes(tail)?;
+            self.store_reference(Arc::new(tail_builder.build()?))?;
+        }
+        Ok(self)
+    }
+
+    /// Stores the amount as `VarUInteger 16`, also known as `Grams` or `Coins`:
+    /// 4 bits of the byte length followed by the big-endian value.
+    pub fn store_coins(&mut self, value: u128) -> CellResult<&mut Self> {
+        let byte_len = ((u128::BITS - value.leading_zeros() + 7) / 8) as usize;
+        if byte_len > 15 {
+            return Err(CellError::InvalidValue);
+        }
+        self.store_uint(4, byte_len as u128)?
+            .store_uint(byte_len * 8, value)
+    }
+
+    /// Stores `MsgAddressInt` as `addr_std$10 anycast:(Maybe Anycast) workchain_id:int8 address:bits256`.
+    pub fn store_address(&mut self, address: &TonAddress) -> CellResult<&mut Self> {
+        self.store_uint(2, 0b10)?
+            // No anycast.
+            .store_bit(false)?
+            .store_int(8, address.workchain() as i64)?
+            .store_slice(address.hash().as_slice())
+    }
+
+    /// Stores `addr_none$00`.
+    pub fn store_address_none(&mut self) -> CellResult<&mut Self> {
+        self.store_uint(2, 0)
+    }
+
+    pub fn store_reference(&mut self, cell: CellArc) -> CellResult<&mut Self> {
+        if self.references.len() >= Cell::MAX_REFS {
+            return Err(CellError::RefsOverflow);
+        }
+        self.references.push(cell);
+        Ok(self)
+    }
+
+    /// Stores the data bits and the references of the given `cell`.
+    pub fn store_cell(&mut self, cell: &Cell) -> CellResult<&mut Self> {
+        self.check_bits_capacity(cell.bit_len())?;
+        for i in 0..cell.bit_len() {
+            let bit = cell.data()[i / 8] & (0x80 >> (i % 8)) != 0;
+            self.push_bit(bit);
+        }
+        for child in cell.references() {
+            self.store_reference(CellArc::clone(child))?;
+        }
+        Ok(self)
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    pub fn build(&self) -> CellResult<Cell> {
+        Cell::new(self.data.clone(), self.bit_len, self.references.clone())
+    }
+
+    fn check_bits_capacity(&self, bits: usize) -> CellResult<()> {
+        if self.bit_len + bits > Cell::MAX_BITS {
+            return Err(CellError::DataOverflow);
+        }
+        Ok(())
+    }
+
+    fn push_bit(&mut self, bit: bool) {
+        if self.bit_len % 8 == 0 {
+            self.data.push(0);
+        }
+        if bit {
+            let last = self.data.len() - 1;
+            self.data[last] |= 0x80 >> (self.bit_len % 8);
+        }
+        self.bit_len += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store_bits() {
+        let mut builder = CellBuilder::new();
+        builder
+            .store_bit(true)
+            .unwrap()
+            .store_uint(3, 0b010)
+            .unwrap()
+            .store_int(8, -1)
+            .unwrap();
+        let cell = builder.build().unwrap();
+        assert_eq!(cell.bit_len(), 12);
+        assert_eq!(cell.data(), &[0xAF, 0xF0]);
+        assert_eq!(cell.augmented_data(), vec![0xAF, 0xF8]);
+    }
+
+    #[test]
+    fn test_store_coins() {
+        let mut builder = CellBuilder::new();
+        builder.store_coins(0).unwrap();
+        assert_eq!(builder.bit_len(), 4);
+
+        let mut builder = CellBuilder::new();
+        builder.store_coins(1_000_000_000).unwrap();
+        let cell = builder.build().unwrap();
+        // 4 bytes: 0x3B9ACA00.
+        assert_eq!(cell.bit_len(), 36);
+        assert_eq!(cell.data(), &[0x43, 0xB9, 0xAC, 0xA0, 0x00]);
+    }
+
+    #[test]
+    fn test_store_invalid_value() {
+        let mut builder = CellBuilder::new();
+        assert_eq!(
+            builder.store_uint(3, 8).err(),
+            Some(CellError::InvalidValue)
+        );
+        assert_eq!(
+            builder.store_int(8, 128).err(),
+            Some(CellError::InvalidValue)
+        );
+        assert_eq!(builder.bit_len(), 0);
+    }
+
+    #[test]
+    fn test_store_snake_bytes() {
+        let bytes: Vec<u8> = (0..=255).collect();
+
+        let mut builder = CellBuilder::new();
+        builder
+            .store_u32(0)
+            .unwrap()
+            .store_snake_bytes(&bytes)
+            .unwrap();
+        let cell = builder.build().unwrap();
+
+        // 4 bytes of the prefix and 123 bytes of the data.
+        assert_eq!(cell.data().len(), 127);
+        assert_eq!(&cell.data()[4..], &bytes[..123]);
+
+        let second = &cell.references()[0];
+        assert_eq!(second.data(), &bytes[123..250]);
+        let third = &second.references()[0];
+        assert_eq!(third.data(), &bytes[250..]);
+        assert!(third.references().is_empty());
+    }
+
+    #[test]
+    fn test_data_overflow() {
+        let mut builder = CellBuilder::new();
+        builder.store_slice(&[0; 127]).unwrap();
+        builder.store_uint(7, 0).unwrap();
+        assert_eq!(builder.store_bit(true).err(), Some(CellError::DataOverflow));
+    }
+}
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::sync::Arc;
+use tw_coin_entry::error::{SigningError, SigningErrorType};
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+
+pub mod cell_builder;
+
+pub use cell_builder::CellBuilder;
+
+pub type CellArc = Arc<Cell>;
+pub type CellResult<T> = Result<T, CellError>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CellError {
+    /// Cell data doesn't fit into [`Cell::MAX_BITS`].
+    DataOverflow,
+    /// Cell has more than [`Cell::MAX_REFS`] references.
+    RefsOverflow,
+    /// Value doesn't fit into the requested number of bits.
+    InvalidValue,
+    /// Bag of Cells cannot be deserialized.
+    InvalidBoc,
+    /// Bag of Cells contains an unsupported (exotic, pruned or absent) cell.
+    UnsupportedCell,
+}
+
+impl From<CellError> for SigningError {
+    fn from(err: CellError) -> Self {
+        match err {
+            CellError::DataOverflow | CellError::RefsOverflow | CellError::InvalidValue => {
+                SigningError(SigningErrorType::Error_invalid_params)
+            },
+            CellError::InvalidBoc | CellError::UnsupportedCell => {
+                SigningError(SigningErrorType::Error_input_parse)
+            },
+        }
+    }
+}
+
+/// An ordinary TVM cell: up to 1023 bits of data and up to 4 references to other cells.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Cell {
+    /// Data bytes. The bits that follow `bit_len` are always zeroed.
+    data: Vec<u8>,
+    bit_len: usize,
+    references: Vec<CellArc>,
+    hash: H256,
+    depth: u16,
+}
+
+impl Cell {
+    pub const MAX_BITS: usize = 1023;
+    pub const MAX_REFS: usize = 4;
+
+    /// Creates a cell and computes its representation hash.
+    /// `data` must contain exactly `ceil(bit_len / 8)` bytes.
+    pub fn new(mut data: Vec<u8>, bit_len: usize, references: Vec<CellArc>) -> CellResult<Cell> {
+        if bit_len > Self::MAX_BITS || data.len() != (bit_len + 7) / 8 {
+            return Err(CellError::DataOverflow);
+        }
+        if references.len() > Self::MAX_REFS {
+            return Err(CellError::RefsOverflow);
+        }
+
+        // Make sure the unused bits of the last byte are zeroed.
+        if bit_len % 8 != 0 {
+            if let Some(last) = data.last_mut() {
+                *last &= 0xFF << (8 - bit_len % 8);
+            }
+        }
+
+        let depth = references
+            .iter()
+            .map(|child| child.depth + 1)
+            .max()
+            .unwrap_or_default();
+
+        let mut cell = Cell {
+            data,
+            bit_len,
+            references,
+            hash: H256::default(),
+            depth,
+        };
+        cell.hash = cell.compute_hash();
+        Ok(cell)
+    }
+
+    /// Returns the representation hash of the cell.
+    pub fn hash(&self) -> H256 {
+        self.hash
+    }
+
+    pub fn depth(&self) -> u16 {
+        self.depth
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn references(&self) -> &[CellArc] {
+        &self.references
+    }
+
+    /// Returns `d1` and `d2` descriptor bytes of an ordinary cell.
+    pub(crate) fn descriptors(&self) -> [u8; 2] {
+        let d1 = self.references.len() as u8;
+        let d2 = (self.bit_len / 8 + (self.bit_len + 7) / 8) as u8;
+        [d1, d2]
+    }
+
+    /// Returns the data with a completion tag appended if the data is not byte-aligned.
+    pub(crate) fn augmented_data(&self) -> Vec<u8> {
+        let mut data = self.data.clone();
+        if self.bit_len % 8 != 0 {
+            if let Some(last) = data.last_mut() {
+                *last |= 0x80 >> (self.bit_len % 8);
+            }
+        }
+        data
+    }
+
+    fn compute_hash(&self) -> H256 {
+        let mut repr = Vec::with_capacity(2 + self.data.len() + self.references.len() * 34);
+        repr.extend_from_slice(&self.descriptors());
+        repr.extend_from_slice(&self.augmented_data());
+        for child in self.references.iter() {
+            repr.extend_from_slice(&child.depth.to_be_bytes());
+        }
+        for child in self.references.iter() {
+            repr.extend_from_slice(child.hash.as_slice());
+        }
+
+        H256::try_from(sha256(&repr).as_slice()).expect("sha256 must return 32 bytes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_hash() {
+        let empty = Cell::new(Vec::new(), 0, Vec::new()).unwrap();
+        assert_eq!(
+            empty.hash(),
+            H256::from("96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7")
+        );
+        assert_eq!(empty.depth(), 0);
+
+        let parent = Cell::new(vec![0xFE], 7, vec![Arc::new(empty)]).unwrap();
+        assert_eq!(parent.descriptors(), [1, 1]);
+        assert_eq!(parent.augmented_data(), vec![0xFF]);
+        assert_eq!(parent.depth(), 1);
+    }
+
+    #[test]
+    fn test_cell_overflow() {
+        let data = vec![0; 128];
+        assert_eq!(
+            Cell::new(data, 1024, Vec::new()),
+            Err(CellError::DataOverflow)
+        );
+
+        let empty = Arc::new(Cell::new(Vec::new(), 0, Vec::new()).unwrap());
+        let references = vec![empty; Cell::MAX_REFS + 1];
+        assert_eq!(
+            Cell::new(Vec::new(), 0, references),
+            Err(CellError::RefsOverflow)
+        );
+    }
+}
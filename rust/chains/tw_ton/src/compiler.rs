@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::signer::TheOpenNetworkSigner;
+use crate::signing_request::builder::SigningRequestBuilder;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
+use tw_keypair::ed25519;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct TheOpenNetworkCompiler;
+
+impl TheOpenNetworkCompiler {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let public_key = H256::try_from(input.public_key.as_ref())
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        let request = SigningRequestBuilder::build(&input, public_key)?;
+
+        // The wallet contract verifies the signature of the signing message cell hash.
+        let signing_message_hash = request.wallet.signing_message(&request.transfer)?.hash();
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(signing_message_hash.to_vec()),
+            data_hash: Cow::from(signing_message_hash.to_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn compile_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let SingleSignaturePubkey {
+            signature,
+            public_key,
+        } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
+
+        let public_key = ed25519::sha512::PublicKey::try_from(public_key.as_slice())?;
+        let signature = ed25519::Signature::try_from(signature.as_slice())?;
+
+        let request = SigningRequestBuilder::build(&input, public_key.to_bytes())?;
+        let signing_message = request.wallet.signing_message(&request.transfer)?;
+
+        let signature_bytes = signature.to_bytes();
+        if !public_key.verify(signature, signing_message.hash().to_vec()) {
+            return Err(SigningError(SigningErrorType::Error_signing));
+        }
+
+        TheOpenNetworkSigner::output_from_signed(&request, &signing_message, &signature_bytes)
+    }
+}
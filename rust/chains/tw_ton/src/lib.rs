@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod address;
+pub mod boc;
+pub mod cell;
+pub mod compiler;
+pub mod entry;
+pub mod message;
+pub mod signer;
+pub mod signing_request;
+pub mod wallet;
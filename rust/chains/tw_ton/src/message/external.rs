@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::cell::{Cell, CellArc, CellBuilder, CellResult};
+use crate::message::state_init::StateInit;
+use std::sync::Arc;
+
+/// An inbound external message, i.e. a message sent from outside the blockchain to a wallet contract.
+#[derive(Clone, Debug)]
+pub struct ExternalMessage {
+    pub dest: TonAddress,
+    /// Must be set to deploy the wallet contract along with the first message.
+    pub state_init: Option<StateInit>,
+    pub body: CellArc,
+}
+
+impl ExternalMessage {
+    /// Serializes `message$_ info:CommonMsgInfo init:(Maybe (Either StateInit ^StateInit))
+    /// body:(Either X ^X) = Message X` with the `ext_in_msg_info$10` header.
+    pub fn to_cell(&self) -> CellResult<Cell> {
+        let mut builder = CellBuilder::new();
+        builder
+            // `ext_in_msg_info$10` tag.
+            .store_uint(2, 0b10)?
+            // Source address is always empty.
+            .store_address_none()?
+            .store_address(&self.dest)?
+            // `import_fee`.
+            .store_coins(0)?;
+
+        match self.state_init {
+            Some(ref state_init) => {
+                builder
+                    .store_bit(true)?
+                    // `StateInit` is stored as a reference.
+                    .store_bit(true)?
+                    .store_reference(Arc::new(state_init.to_cell()?))?;
+            },
+            None => {
+                builder.store_bit(false)?;
+            },
+        }
+
+        builder
+            // Body is stored as a reference.
+            .store_bit(true)?
+            .store_reference(CellArc::clone(&self.body))?;
+        builder.build()
+    }
+}
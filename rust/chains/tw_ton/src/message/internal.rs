@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::cell::{Cell, CellArc, CellBuilder, CellResult};
+
+/// An internal message sent by a wallet contract to the destination account.
+#[derive(Clone, Debug)]
+pub struct InternalMessage {
+    /// Whether the message should bounce back if the destination fails to process it.
+    pub bounce: bool,
+    pub dest: TonAddress,
+    /// Amount of nanotons attached to the message.
+    pub value: u128,
+    pub body: Option<CellArc>,
+}
+
+impl InternalMessage {
+    /// Serializes `message$_ info:CommonMsgInfo init:(Maybe (Either StateInit ^StateInit))
+    /// body:(Either X ^X) = Message X` with the `int_msg_info$0` header.
+    /// The fields that are rewritten by the validators are left zeroed.
+    pub fn to_cell(&self) -> CellResult<Cell> {
+        let mut builder = CellBuilder::new();
+        builder
+            // `int_msg_info$0` tag.
+            .store_bit(false)?
+            // `ihr_disabled`.
+            .store_bit(true)?
+            .store_bit(self.bounce)?
+            // `bounced`.
+            .store_bit(false)?
+            // Source address is filled by the validators.
+            .store_address_none()?
+            .store_address(&self.dest)?
+            .store_coins(self.value)?
+            // No extra currencies.
+            .store_bit(false)?
+            // `ihr_fee` and `fwd_fee`.
+            .store_coins(0)?
+            .store_coins(0)?
+            // `created_lt` and `created_at`.
+            .store_u64(0)?
+            .store_u32(0)?
+            // No `init`.
+            .store_bit(false)?;
+
+        match self.body {
+            Some(ref body) => {
+                builder
+                    .store_bit(true)?
+                    .store_reference(CellArc::clone(body))?;
+            },
+            None => {
+                builder.store_bit(false)?;
+            },
+        }
+        builder.build()
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod external;
+pub mod internal;
+pub mod payload;
+pub mod state_init;
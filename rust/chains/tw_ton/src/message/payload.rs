@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::cell::{Cell, CellBuilder, CellResult};
+
+/// `text_comment` operation code.
+pub const COMMENT_OP_CODE: u32 = 0;
+/// TEP-74 `transfer` operation code.
+pub const JETTON_TRANSFER_OP_CODE: u32 = 0x0f8a7ea5;
+
+/// Builds the message body with a text comment.
+/// An empty comment results in an empty cell.
+pub fn comment_payload(comment: &str) -> CellResult<Cell> {
+    let mut builder = CellBuilder::new();
+    if !comment.is_empty() {
+        store_comment(&mut builder, comment)?;
+    }
+    builder.build()
+}
+
+/// TEP-74 jetton transfer message body, sent to the sender's jetton wallet.
+/// https://github.com/ton-blockchain/TEPs/blob/master/text/0074-jettons-standard.md
+pub struct JettonTransferPayload {
+    pub query_id: u64,
+    /// Amount of jettons in elementary units.
+    pub amount: u128,
+    /// New owner of the jettons.
+    pub destination: TonAddress,
+    /// Address where the excess of nanotons is returned to.
+    pub response_destination: TonAddress,
+    /// Amount of nanotons to forward to the new owner along with the notification.
+    pub forward_amount: u128,
+    /// Optional comment that is attached to the transfer notification.
+    pub comment: String,
+}
+
+impl JettonTransferPayload {
+    /// Serializes `transfer#0f8a7ea5 query_id:uint64 amount:(VarUInteger 16) destination:MsgAddress
+    /// response_destination:MsgAddress custom_payload:(Maybe ^Cell) forward_ton_amount:(VarUInteger 16)
+    /// forward_payload:(Either Cell ^Cell) = InternalMsgBody`.
+    pub fn to_cell(&self) -> CellResult<Cell> {
+        let mut builder = CellBuilder::new();
+        builder
+            .store_u32(JETTON_TRANSFER_OP_CODE)?
+            .store_u64(self.query_id)?
+            .store_coins(self.amount)?
+            .store_address(&self.destination)?
+            .store_address(&self.response_destination)?
+            // No custom payload.
+            .store_bit(false)?
+            .store_coins(self.forward_amount)?
+            // Forward payload is stored in the same cell.
+            .store_bit(false)?;
+
+        if !self.comment.is_empty() {
+            store_comment(&mut builder, &self.comment)?;
+        }
+        builder.build()
+    }
+}
+
+fn store_comment(builder: &mut CellBuilder, comment: &str) -> CellResult<()> {
+    builder
+        .store_u32(COMMENT_OP_CODE)?
+        .store_snake_bytes(comment.as_bytes())?;
+    Ok(())
+}
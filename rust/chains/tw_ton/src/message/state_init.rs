@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::cell::{Cell, CellArc, CellBuilder, CellResult};
+
+/// `StateInit` of a contract that is deployed along with the first message.
+#[derive(Clone, Debug)]
+pub struct StateInit {
+    pub code: CellArc,
+    pub data: CellArc,
+}
+
+impl StateInit {
+    /// Serializes `_ split_depth:(Maybe (## 5)) special:(Maybe TickTock)
+    /// code:(Maybe ^Cell) data:(Maybe ^Cell) library:(HashmapE 256 SimpleLib) = StateInit`.
+    pub fn to_cell(&self) -> CellResult<Cell> {
+        let mut builder = CellBuilder::new();
+        builder
+            // No split depth.
+            .store_bit(false)?
+            // Not special.
+            .store_bit(false)?
+            .store_bit(true)?
+            .store_reference(CellArc::clone(&self.code))?
+            .store_bit(true)?
+            .store_reference(CellArc::clone(&self.data))?
+            // No libraries.
+            .store_bit(false)?;
+        builder.build()
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::boc::{BagOfCells, HAS_CRC32};
+use crate::cell::Cell;
+use crate::signing_request::builder::SigningRequestBuilder;
+use crate::signing_request::SigningRequest;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::signing_output_error;
+use tw_hash::H512;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_proto::TheOpenNetwork::Proto;
+
+pub struct TheOpenNetworkSigner;
+
+impl TheOpenNetworkSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let request = SigningRequestBuilder::build(&input, key_pair.public().to_bytes())?;
+
+        let signing_message = request.wallet.signing_message(&request.transfer)?;
+        let signature = key_pair.sign(signing_message.hash().to_vec())?.to_bytes();
+
+        Self::output_from_signed(&request, &signing_message, &signature)
+    }
+
+    /// Builds the external message with the signed `signing_message` and serializes it.
+    pub(crate) fn output_from_signed(
+        request: &SigningRequest,
+        signing_message: &Cell,
+        signature: &H512,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let external_message = request.wallet.external_message(
+            signing_message,
+            signature,
+            request.transfer.sequence_number,
+        )?;
+        let external_message = external_message.to_cell()?;
+        let hash = external_message.hash();
+
+        let encoded = BagOfCells::from_root(external_message).to_base64(HAS_CRC32)?;
+        Ok(Proto::SigningOutput {
+            encoded: Cow::from(encoded),
+            hash: Cow::from(hash.to_vec()),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::{TonAddress, BASECHAIN};
+use crate::cell::CellArc;
+use crate::message::payload::{comment_payload, JettonTransferPayload};
+use crate::signing_request::{SigningRequest, TransferRequest};
+use crate::wallet::{TonWallet, WalletVersion};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_hash::H256;
+use tw_proto::TheOpenNetwork::Proto;
+
+/// Default lifetime of a message if `expire_at` is not specified, in seconds.
+const DEFAULT_EXPIRATION_SECS: u64 = 60;
+
+pub struct SigningRequestBuilder;
+
+impl SigningRequestBuilder {
+    pub fn build(
+        input: &Proto::SigningInput<'_>,
+        public_key: H256,
+    ) -> SigningResult<SigningRequest> {
+        use Proto::mod_SigningInput::OneOfaction_oneof as ActionType;
+
+        let (transfer, payload) = match input.action_oneof {
+            ActionType::transfer(ref transfer) => {
+                let payload = comment_payload(&transfer.comment)?;
+                (transfer, payload)
+            },
+            ActionType::jetton_transfer(ref jetton) => {
+                let transfer = jetton
+                    .transfer
+                    .as_ref()
+                    .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+                let payload = JettonTransferPayload {
+                    query_id: jetton.query_id,
+                    amount: jetton.jetton_amount as u128,
+                    destination: TonAddress::from_str(&jetton.to_owner)?,
+                    response_destination: TonAddress::from_str(&jetton.response_address)?,
+                    forward_amount: jetton.forward_amount as u128,
+                    comment: transfer.comment.to_string(),
+                }
+                .to_cell()?;
+                (transfer, payload)
+            },
+            ActionType::None => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params));
+            },
+        };
+
+        let version = match transfer.wallet_version {
+            Proto::WalletVersion::WALLET_V4_R2 => WalletVersion::V4R2,
+            Proto::WalletVersion::WALLET_V5_R1 => WalletVersion::V5R1,
+            _ => return Err(SigningError(SigningErrorType::Error_invalid_params)),
+        };
+        let wallet = TonWallet::new(version, public_key, BASECHAIN);
+
+        let transfer = Self::transfer_from_proto(transfer, CellArc::new(payload))?;
+        Ok(SigningRequest { wallet, transfer })
+    }
+
+    fn transfer_from_proto(
+        transfer: &Proto::Transfer<'_>,
+        payload: CellArc,
+    ) -> SigningResult<TransferRequest> {
+        let dest = TonAddress::from_str(&transfer.dest)?.with_bounceable(transfer.bounceable);
+        let mode = u8::try_from(transfer.mode)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        let expire_at = match transfer.expire_at {
+            0 => Self::default_expire_at()?,
+            expire_at => expire_at,
+        };
+
+        Ok(TransferRequest {
+            dest,
+            amount: transfer.amount as u128,
+            mode,
+            sequence_number: transfer.sequence_number,
+            expire_at,
+            payload,
+        })
+    }
+
+    fn default_expire_at() -> SigningResult<u32> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+        u32::try_from(now.as_secs() + DEFAULT_EXPIRATION_SECS)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))
+    }
+}
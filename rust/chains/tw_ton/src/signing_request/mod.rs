@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::cell::CellArc;
+use crate::message::internal::InternalMessage;
+use crate::wallet::TonWallet;
+
+pub mod builder;
+
+/// A transaction to be signed: the sender's wallet and the message it sends.
+pub struct SigningRequest {
+    pub wallet: TonWallet,
+    pub transfer: TransferRequest,
+}
+
+/// An outgoing internal message of the wallet.
+pub struct TransferRequest {
+    /// Destination address. Its bounceable flag is used as the message `bounce` flag.
+    pub dest: TonAddress,
+    /// Amount of nanotons to send.
+    pub amount: u128,
+    /// Send mode, see `SendMode` in the protobuf.
+    pub mode: u8,
+    pub sequence_number: u32,
+    /// Unix timestamp the message is valid until.
+    pub expire_at: u32,
+    /// Message body, for example a text comment or a jetton transfer.
+    pub payload: CellArc,
+}
+
+impl TransferRequest {
+    pub fn internal_message(&self) -> InternalMessage {
+        InternalMessage {
+            bounce: self.dest.is_bounceable(),
+            dest: self.dest.clone(),
+            value: self.amount,
+            body: Some(CellArc::clone(&self.payload)),
+        }
+    }
+}
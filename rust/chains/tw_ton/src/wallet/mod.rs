@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::cell::{Cell, CellArc, CellBuilder, CellResult};
+use crate::message::external::ExternalMessage;
+use crate::message::state_init::StateInit;
+use crate::signing_request::TransferRequest;
+use std::sync::Arc;
+use tw_hash::{H256, H512};
+
+pub mod wallet_v4r2;
+pub mod wallet_v5r1;
+
+/// Default `subwallet_id` of the V4R2 wallets. The workchain is added to it.
+pub const STANDARD_WALLET_ID: u32 = 698983191;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalletVersion {
+    V4R2,
+    V5R1,
+}
+
+/// A standard wallet contract owned by a single ed25519 key.
+#[derive(Clone, Debug)]
+pub struct TonWallet {
+    version: WalletVersion,
+    public_key: H256,
+    workchain: i32,
+}
+
+impl TonWallet {
+    pub fn new(version: WalletVersion, public_key: H256, workchain: i32) -> TonWallet {
+        TonWallet {
+            version,
+            public_key,
+            workchain,
+        }
+    }
+
+    pub fn version(&self) -> WalletVersion {
+        self.version
+    }
+
+    pub fn public_key(&self) -> H256 {
+        self.public_key
+    }
+
+    pub fn workchain(&self) -> i32 {
+        self.workchain
+    }
+
+    pub fn wallet_id(&self) -> u32 {
+        match self.version {
+            WalletVersion::V4R2 => STANDARD_WALLET_ID.wrapping_add(self.workchain as u32),
+            WalletVersion::V5R1 => wallet_v5r1::wallet_id(self.workchain),
+        }
+    }
+
+    /// Returns the `StateInit` the wallet contract is deployed with.
+    pub fn state_init(&self) -> CellResult<StateInit> {
+        let (code, data) = match self.version {
+            WalletVersion::V4R2 => (wallet_v4r2::code()?, wallet_v4r2::data(self)?),
+            WalletVersion::V5R1 => (wallet_v5r1::code()?, wallet_v5r1::data(self)?),
+        };
+        Ok(StateInit {
+            code,
+            data: Arc::new(data),
+        })
+    }
+
+    /// Returns the address of the wallet contract, that is the hash of its `StateInit`.
+    pub fn address(&self) -> CellResult<TonAddress> {
+        let state_init = self.state_init()?.to_cell()?;
+        Ok(TonAddress::new(self.workchain, state_init.hash()))
+    }
+
+    /// Returns the cell whose hash has to be signed by the wallet owner.
+    pub fn signing_message(&self, transfer: &TransferRequest) -> CellResult<Cell> {
+        match self.version {
+            WalletVersion::V4R2 => wallet_v4r2::signing_message(self, transfer),
+            WalletVersion::V5R1 => wallet_v5r1::signing_message(self, transfer),
+        }
+    }
+
+    /// Returns the external message that carries the signed `signing_message` to the wallet.
+    /// The wallet contract is deployed along with the message if `sequence_number` is 0.
+    pub fn external_message(
+        &self,
+        signing_message: &Cell,
+        signature: &H512,
+        sequence_number: u32,
+    ) -> CellResult<ExternalMessage> {
+        let mut body = CellBuilder::new();
+        match self.version {
+            // The signature precedes the signed message.
+            WalletVersion::V4R2 => {
+                body.store_slice(signature.as_slice())?
+                    .store_cell(signing_message)?;
+            },
+            // The signature follows the signed message.
+            WalletVersion::V5R1 => {
+                body.store_cell(signing_message)?
+                    .store_slice(signature.as_slice())?;
+            },
+        }
+
+        let state_init = if sequence_number == 0 {
+            Some(self.state_init()?)
+        } else {
+            None
+        };
+
+        Ok(ExternalMessage {
+            dest: self.address()?,
+            state_init,
+            body: CellArc::new(body.build()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::BASECHAIN;
+    use tw_encoding::hex::DecodeHex;
+
+    const PUBLIC_KEY: &str = "c2036a1ca901059e1d1ab38cd7a7a4709b5e8f9d85b387f0514d7adae70b6afe";
+
+    fn wallet(version: WalletVersion) -> TonWallet {
+        let public_key = H256::try_from(PUBLIC_KEY.decode_hex().unwrap().as_slice()).unwrap();
+        TonWallet::new(version, public_key, BASECHAIN)
+    }
+
+    #[test]
+    fn test_wallet_v4r2_address() {
+        let address = wallet(WalletVersion::V4R2).address().unwrap();
+        assert_eq!(
+            address.to_string(),
+            "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl"
+        );
+    }
+
+    #[test]
+    fn test_wallet_v5r1_address() {
+        let wallet = wallet(WalletVersion::V5R1);
+        assert_eq!(wallet.wallet_id(), 2147483409);
+        assert_eq!(
+            wallet_v5r1::code().unwrap().hash().to_string(),
+            "20834b7b72b112147e1b2fb457b84e74d1a30f04f737d4f62a668e9552d2b72f"
+        );
+        assert_eq!(
+            wallet.address().unwrap().to_string(),
+            "EQDXmOghLNTxes_3BjG-ikg5p8JU0jha-YQ322ILan4Yridz"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Wallet V5R1 contract.
+//! Contract source: https://github.com/ton-blockchain/wallet-contract-v5/blob/main/contracts/wallet_v5.fc
+
+use crate::boc::BagOfCells;
+use crate::cell::{Cell, CellArc, CellBuilder, CellResult};
+use crate::signing_request::TransferRequest;
+use crate::wallet::TonWallet;
+use std::sync::Arc;
+
+/// Serialized contract code. The hash of the root cell is
+/// `20834b7b72b112147e1b2fb457b84e74d1a30f04f737d4f62a668e9552d2b72f`.
+const WALLET_V5R1_CODE: &str = "B5EE9C7241021401000281000114FF00F4A413F4BCF2C80B01020120020D020148030402DCD020D749C120915B8F6320D70B1F2082106578746EBD21821073696E74BDB0925F03E082106578746EBA8EB48020D72101D074D721FA4030FA44F828FA443058BD915BE0ED44D0810141D721F4058307F40E6FA1319130E18040D721707FDB3CE03120D749810280B99130E070E2100F020120050C020120060902016E07080019ADCE76A2684020EB90EB85FFC00019AF1DF6A2684010EB90EB858FC00201480A0B0017B325FB51341C75C875C2C7E00011B262FB513435C280200019BE5F0F6A2684080A0EB90FA02C0102F20E011E20D70B1F82107369676EBAF2E08A7F0F01E68EF0EDA2EDFB218308D722028308D723208020D721D31FD31FD31FED44D0D200D31F20D31FD3FFD70A000AF90140CCF9109A28945F0ADB31E1F2C087DF02B35007B0F2D0845125BAF2E0855036BAF2E086F823BBF2D0882292F800DE01A47FC8CA00CB1F01CF16C9ED542092F80FDE70DB3CD81003F6EDA2EDFB02F404216E926C218E4C0221D73930709421C700B38E2D01D72820761E436C20D749C008F2E09320D74AC002F2E09320D71D06C712C2005230B0F2D089D74CD7393001A4E86C128407BBF2E093D74AC000F2E093ED55E2D20001C000915BE0EBD72C08142091709601D72C081C12E25210B1E30F20D74A111213009601FA4001FA44F828FA443058BAF2E091ED44D0810141D718F405049D7FC8CA0040048307F453F2E08B8E14038307F45BF2E08C22D70A00216E01B3B0F2D090E2C85003CF1612F400C9ED54007230D72C08248E2D21F2E092D200ED44D0D2005113BAF2D08F54503091319C01810140D721D70A00F2E08EE2C8CA0058CF16C9ED5493F2C08DE20010935BDB31E1D74CD0B4D6C35E";
+
+/// Global ID of the TON mainnet.
+const MAINNET_GLOBAL_ID: i32 = -239;
+/// `wallet_version` field of the wallet ID context.
+const WALLET_V5R1_VERSION: u8 = 0;
+/// `valid_until` value used for the first message that deploys the contract.
+const NO_EXPIRATION: u32 = u32::MAX;
+/// `signed_external` prefix of the requests sent in external messages.
+const SIGNED_EXTERNAL_PREFIX: u32 = 0x7369676e;
+/// `action_send_msg` tag of the out action.
+const ACTION_SEND_MSG_TAG: u32 = 0x0ec3c86d;
+
+pub fn code() -> CellResult<CellArc> {
+    BagOfCells::parse_hex(WALLET_V5R1_CODE)?
+        .single_root()
+        .cloned()
+}
+
+/// Returns `network_global_id ^ context`, where the client context is
+/// `1:uint1 workchain:int8 wallet_version:uint8 subwallet_number:uint15` with the first subwallet.
+pub fn wallet_id(workchain: i32) -> u32 {
+    let context =
+        (1 << 31) | ((workchain as i8 as u8 as u32) << 23) | ((WALLET_V5R1_VERSION as u32) << 15);
+    (MAINNET_GLOBAL_ID as u32) ^ context
+}
+
+/// Initial contract data: `is_signature_allowed:bool seqno:uint32 wallet_id:uint32
+/// public_key:bits256 extensions_dict:(HashmapE 256 int1)`.
+pub fn data(wallet: &TonWallet) -> CellResult<Cell> {
+    let mut builder = CellBuilder::new();
+    builder
+        .store_bit(true)?
+        .store_u32(0)?
+        .store_u32(wallet.wallet_id())?
+        .store_slice(wallet.public_key().as_slice())?
+        // No extensions.
+        .store_bit(false)?;
+    builder.build()
+}
+
+/// Message that is signed by the wallet owner:
+/// `signed_external#7369676e wallet_id:uint32 valid_until:uint32 seqno:uint32 inner:InnerRequest`,
+/// where `InnerRequest` carries a single `action_send_msg` and no extended actions.
+pub fn signing_message(wallet: &TonWallet, transfer: &TransferRequest) -> CellResult<Cell> {
+    let valid_until = if transfer.sequence_number == 0 {
+        NO_EXPIRATION
+    } else {
+        transfer.expire_at
+    };
+
+    let internal_message = transfer.internal_message().to_cell()?;
+
+    // `out_list$_ prev:^(OutList 0) action:OutAction = OutList 1`.
+    let mut out_list = CellBuilder::new();
+    out_list
+        .store_reference(Arc::new(CellBuilder::new().build()?))?
+        .store_u32(ACTION_SEND_MSG_TAG)?
+        .store_u8(transfer.mode)?
+        .store_reference(Arc::new(internal_message))?;
+
+    let mut builder = CellBuilder::new();
+    builder
+        .store_u32(SIGNED_EXTERNAL_PREFIX)?
+        .store_u32(wallet.wallet_id())?
+        .store_u32(valid_until)?
+        .store_u32(transfer.sequence_number)?
+        // `out_actions:(Maybe ^OutList)`.
+        .store_bit(true)?
+        .store_reference(Arc::new(out_list.build()?))?
+        // No extended actions.
+        .store_bit(false)?;
+    builder.build()
+}
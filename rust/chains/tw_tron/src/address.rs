@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::base58::{self, Alphabet};
+use tw_evm::address::Address as EvmAddress;
+use tw_hash::sha2::sha256;
+use tw_hash::H160;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+
+/// Every Tron mainnet address starts with this byte, which makes it start with `T` in Base58.
+pub const ADDRESS_PREFIX: u8 = 0x41;
+const CHECKSUM_LEN: usize = 4;
+/// Prefix byte + 20 bytes of the account.
+const ADDRESS_LEN: usize = 1 + H160::LEN;
+
+/// Tron address: Base58Check(`0x41` + the last 20 bytes of `keccak256(pubkey)`).
+/// The account bytes are the same as in the Ethereum address derived from the same key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TronAddress {
+    bytes: H160,
+}
+
+impl TronAddress {
+    pub fn with_secp256k1_pubkey(public_key: &secp256k1::PublicKey) -> TronAddress {
+        TronAddress {
+            bytes: EvmAddress::with_secp256k1_pubkey(public_key).bytes(),
+        }
+    }
+
+    /// Parses `0x41`-prefixed 21 bytes as used in Tron transactions.
+    pub fn from_prefixed_bytes(bytes: &[u8]) -> AddressResult<TronAddress> {
+        if bytes.len() != ADDRESS_LEN {
+            return Err(AddressError::InvalidInput);
+        }
+        if bytes[0] != ADDRESS_PREFIX {
+            return Err(AddressError::UnexpectedAddressPrefix);
+        }
+        let bytes = H160::try_from(&bytes[1..]).map_err(|_| AddressError::InvalidInput)?;
+        Ok(TronAddress { bytes })
+    }
+
+    /// Returns `0x41`-prefixed 21 bytes as used in Tron transactions.
+    pub fn to_prefixed_bytes(&self) -> Data {
+        let mut bytes = Vec::with_capacity(ADDRESS_LEN);
+        bytes.push(ADDRESS_PREFIX);
+        bytes.extend_from_slice(self.bytes.as_slice());
+        bytes
+    }
+
+    /// Returns the same account as an EVM address, e.g. to be used in TRC-20 calldata.
+    pub fn to_evm_address(&self) -> EvmAddress {
+        EvmAddress::from_bytes(self.bytes)
+    }
+}
+
+impl CoinAddress for TronAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.to_prefixed_bytes()
+    }
+}
+
+impl FromStr for TronAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decoded =
+            base58::decode(s, Alphabet::BITCOIN).map_err(|_| AddressError::InvalidInput)?;
+        if decoded.len() != ADDRESS_LEN + CHECKSUM_LEN {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let (payload, checksum) = decoded.split_at(ADDRESS_LEN);
+        if checksum != address_checksum(payload).as_slice() {
+            return Err(AddressError::InvalidInput);
+        }
+        TronAddress::from_prefixed_bytes(payload)
+    }
+}
+
+impl fmt::Display for TronAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = self.to_prefixed_bytes();
+        let checksum = address_checksum(&bytes);
+        bytes.extend_from_slice(&checksum);
+        write!(f, "{}", base58::encode(&bytes, Alphabet::BITCOIN))
+    }
+}
+
+/// The first 4 bytes of double `sha256`.
+fn address_checksum(payload: &[u8]) -> Data {
+    let mut hash = sha256(&sha256(payload));
+    hash.truncate(CHECKSUM_LEN);
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    #[test]
+    fn test_address_from_public_key() {
+        let private = secp256k1::PrivateKey::try_from(
+            "2d8f68944bdbfbc0769542fba8fc2d2a3de67393334471624364c7006da2aa54",
+        )
+        .unwrap();
+        let address = TronAddress::with_secp256k1_pubkey(&private.public());
+        assert_eq!(address.to_string(), "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeC");
+        assert_eq!(
+            address.to_prefixed_bytes(),
+            "415cd0fb0ab3ce40f3051414c604b27756e69e43db"
+                .decode_hex()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_address_invalid_checksum() {
+        // The last character has been changed.
+        TronAddress::from_str("TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeD").unwrap_err();
+    }
+
+    #[test]
+    fn test_address_unexpected_prefix() {
+        // Base58Check of the same account bytes with a valid checksum, but prefixed with `0x42`.
+        assert_eq!(
+            TronAddress::from_str("ThmaW3Z9s7v85zp8L2BpLqXP1ATrNmd5Qe"),
+            Err(AddressError::UnexpectedAddressPrefix)
+        );
+
+        let bytes = "425cd0fb0ab3ce40f3051414c604b27756e69e43db"
+            .decode_hex()
+            .unwrap();
+        assert_eq!(
+            TronAddress::from_prefixed_bytes(&bytes),
+            Err(AddressError::UnexpectedAddressPrefix)
+        );
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod address;
+pub mod compiler;
+pub mod entry;
+pub mod modules;
+pub mod signer;
+pub mod transaction;
+
+#[allow(non_snake_case)]
+#[rustfmt::skip]
+pub mod proto {
+    use tw_proto::google;
+
+    include!(concat!(env!("OUT_DIR"), "/proto/mod.rs"));
+}
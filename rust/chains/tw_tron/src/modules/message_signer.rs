@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::SigningResult;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex::ToHex;
+use tw_evm::message::signature::{MessageSignature, SignatureType};
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_proto::Tron::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// cbindgen:ignore
+pub const TRON_PREFIX: u8 = 0x19;
+/// cbindgen:ignore
+pub const TRON_MESSAGE_PREFIX: &str = "TRON Signed Message:\n";
+/// The legacy scheme always uses `32` as the message length regardless of the actual message.
+/// cbindgen:ignore
+pub const TRON_LEGACY_MESSAGE_LEN: &str = "32";
+
+/// Signs and verifies off-chain messages according to TIP-191.
+/// [`Proto::MessageType::MessageType_legacy`] is compatible with TronWeb `signMessage`,
+/// [`Proto::MessageType::MessageType_v2`] is compatible with TronWeb `signMessageV2`.
+/// https://github.com/tronprotocol/tips/blob/master/tip-191.md
+#[derive(Default)]
+pub struct TronMessageSigner;
+
+impl MessageSigner for TronMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        let hash = Self::message_hash(&input.message, input.message_type).to_vec();
+        CompilerProto::PreSigningOutput {
+            data: Cow::Owned(hash.clone()),
+            data_hash: Cow::Owned(hash),
+            ..CompilerProto::PreSigningOutput::default()
+        }
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl TronMessageSigner {
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+
+        let hash_to_sign = Self::message_hash(&input.message, input.message_type);
+        let secp_sign = private_key.sign(hash_to_sign)?;
+        // `v` is encoded as `27 + recovery_id`.
+        let prepared_sign = MessageSignature::prepared(secp_sign, SignatureType::Legacy)?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(prepared_sign.to_bytes().to_hex()),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = secp256k1::PublicKey::try_from(input.public_key.as_ref())?;
+
+        let msg_hash = Self::message_hash(&input.message, input.message_type);
+        let secp_signature =
+            MessageSignature::from_str(&input.signature)?.to_secp256k1_signature()?;
+
+        let actual_public_key = secp256k1::PublicKey::recover(secp_signature.clone(), msg_hash)?;
+        let valid = actual_public_key == public_key
+            && public_key.verify(secp256k1::VerifySignature::from(secp_signature), msg_hash);
+
+        Ok(valid)
+    }
+
+    /// Returns `keccak256(0x19 + "TRON Signed Message:\n" + len + message)`,
+    /// where `len` is `32` for the legacy scheme and the actual message byte length for V2.
+    fn message_hash(message: &str, message_type: Proto::MessageType) -> H256 {
+        let message_len = match message_type {
+            Proto::MessageType::MessageType_legacy => TRON_LEGACY_MESSAGE_LEN.to_string(),
+            Proto::MessageType::MessageType_v2 => message.len().to_string(),
+        };
+
+        let mut data =
+            Vec::with_capacity(1 + TRON_MESSAGE_PREFIX.len() + message_len.len() + message.len());
+        data.push(TRON_PREFIX);
+        data.extend_from_slice(TRON_MESSAGE_PREFIX.as_bytes());
+        data.extend_from_slice(message_len.as_bytes());
+        data.extend_from_slice(message.as_bytes());
+
+        let hash = keccak256(&data);
+        H256::try_from(hash.as_slice()).expect("Expected 32 byte hash")
+    }
+}
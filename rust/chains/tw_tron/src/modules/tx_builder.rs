@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TronAddress;
+use crate::proto::protocol;
+use crate::transaction::contract::Contract;
+use crate::transaction::Transaction;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_evm::abi::prebuild::erc20::Erc20;
+use tw_hash::sha2::sha256;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Tron::Proto;
+use tw_proto::Tron::Proto::mod_Transaction::OneOfcontract_oneof as ProtoContract;
+
+/// The default transaction lifetime is 10 hours.
+const DEFAULT_EXPIRATION_MS: i64 = 10 * 60 * 60 * 1000;
+/// The reference block hash is the 8 bytes of the block hash starting at this position.
+const REF_BLOCK_HASH_RANGE: std::ops::Range<usize> = 8..16;
+/// The reference block bytes are the last 2 bytes of the big-endian block number.
+const REF_BLOCK_BYTES_RANGE: std::ops::Range<usize> = 6..8;
+
+pub struct TransactionBuilder;
+
+impl TransactionBuilder {
+    pub fn build(input: &Proto::SigningInput<'_>) -> SigningResult<Transaction> {
+        let tx = input
+            .transaction
+            .as_ref()
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+
+        let contract = Self::contract_from_proto(&tx.contract_oneof)?;
+
+        let block_header = tx
+            .block_header
+            .as_ref()
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        let block_hash = Self::block_hash(block_header)?;
+        let block_number = block_header.number.to_be_bytes();
+
+        let timestamp = match tx.timestamp {
+            0 => Self::now_ms()?,
+            timestamp => timestamp,
+        };
+        let expiration = match tx.expiration {
+            0 => timestamp + DEFAULT_EXPIRATION_MS,
+            expiration => expiration,
+        };
+
+        Ok(Transaction {
+            ref_block_bytes: block_number[REF_BLOCK_BYTES_RANGE].to_vec(),
+            ref_block_hash: block_hash[REF_BLOCK_HASH_RANGE].to_vec(),
+            expiration,
+            timestamp,
+            fee_limit: tx.fee_limit,
+            contract,
+        })
+    }
+
+    fn contract_from_proto(contract: &ProtoContract<'_>) -> SigningResult<Contract> {
+        match contract {
+            ProtoContract::transfer(transfer) => {
+                Ok(Contract::Transfer(protocol::TransferContract {
+                    owner_address: parse_address(&transfer.owner_address)?,
+                    to_address: parse_address(&transfer.to_address)?,
+                    amount: transfer.amount,
+                }))
+            },
+            ProtoContract::transfer_asset(transfer) => {
+                Ok(Contract::TransferAsset(protocol::TransferAssetContract {
+                    asset_name: transfer.asset_name.as_bytes().to_vec(),
+                    owner_address: parse_address(&transfer.owner_address)?,
+                    to_address: parse_address(&transfer.to_address)?,
+                    amount: transfer.amount,
+                }))
+            },
+            ProtoContract::freeze_balance(freeze) => {
+                Ok(Contract::FreezeBalance(protocol::FreezeBalanceContract {
+                    owner_address: parse_address(&freeze.owner_address)?,
+                    frozen_balance: freeze.frozen_balance,
+                    frozen_duration: freeze.frozen_duration,
+                    resource: parse_resource(&freeze.resource)?,
+                    receiver_address: parse_optional_address(&freeze.receiver_address)?,
+                }))
+            },
+            ProtoContract::unfreeze_balance(unfreeze) => Ok(Contract::UnfreezeBalance(
+                protocol::UnfreezeBalanceContract {
+                    owner_address: parse_address(&unfreeze.owner_address)?,
+                    resource: parse_resource(&unfreeze.resource)?,
+                    receiver_address: parse_optional_address(&unfreeze.receiver_address)?,
+                },
+            )),
+            ProtoContract::unfreeze_asset(unfreeze) => {
+                Ok(Contract::UnfreezeAsset(protocol::UnfreezeAssetContract {
+                    owner_address: parse_address(&unfreeze.owner_address)?,
+                }))
+            },
+            ProtoContract::withdraw_balance(withdraw) => Ok(Contract::WithdrawBalance(
+                protocol::WithdrawBalanceContract {
+                    owner_address: parse_address(&withdraw.owner_address)?,
+                },
+            )),
+            ProtoContract::vote_asset(vote) => {
+                let vote_address = vote
+                    .vote_address
+                    .iter()
+                    .map(|address| parse_address(address))
+                    .collect::<SigningResult<_>>()?;
+                Ok(Contract::VoteAsset(protocol::VoteAssetContract {
+                    owner_address: parse_address(&vote.owner_address)?,
+                    vote_address,
+                    support: vote.support,
+                    count: vote.count,
+                }))
+            },
+            ProtoContract::vote_witness(vote) => {
+                let votes = vote
+                    .votes
+                    .iter()
+                    .map(|vote| {
+                        Ok(protocol::mod_VoteWitnessContract::Vote {
+                            vote_address: parse_address(&vote.vote_address)?,
+                            vote_count: vote.vote_count,
+                        })
+                    })
+                    .collect::<SigningResult<_>>()?;
+                Ok(Contract::VoteWitness(protocol::VoteWitnessContract {
+                    owner_address: parse_address(&vote.owner_address)?,
+                    votes,
+                    support: vote.support,
+                }))
+            },
+            ProtoContract::trigger_smart_contract(trigger) => Ok(Contract::TriggerSmartContract(
+                protocol::TriggerSmartContract {
+                    owner_address: parse_address(&trigger.owner_address)?,
+                    contract_address: parse_address(&trigger.contract_address)?,
+                    call_value: trigger.call_value,
+                    data: trigger.data.to_vec(),
+                    call_token_value: trigger.call_token_value,
+                    token_id: trigger.token_id,
+                },
+            )),
+            ProtoContract::transfer_trc20_contract(transfer) => {
+                let to = TronAddress::from_str(&transfer.to_address)?;
+                let amount = U256::from_big_endian_slice(&transfer.amount)?;
+                let data = Erc20::transfer(to.to_evm_address(), amount)?;
+
+                Ok(Contract::TriggerSmartContract(
+                    protocol::TriggerSmartContract {
+                        owner_address: parse_address(&transfer.owner_address)?,
+                        contract_address: parse_address(&transfer.contract_address)?,
+                        data,
+                        ..protocol::TriggerSmartContract::default()
+                    },
+                ))
+            },
+            ProtoContract::freeze_balance_v2(freeze) => Ok(Contract::FreezeBalanceV2(
+                protocol::FreezeBalanceV2Contract {
+                    owner_address: parse_address(&freeze.owner_address)?,
+                    frozen_balance: freeze.frozen_balance,
+                    resource: parse_resource(&freeze.resource)?,
+                },
+            )),
+            ProtoContract::unfreeze_balance_v2(unfreeze) => Ok(Contract::UnfreezeBalanceV2(
+                protocol::UnfreezeBalanceV2Contract {
+                    owner_address: parse_address(&unfreeze.owner_address)?,
+                    unfreeze_balance: unfreeze.unfreeze_balance,
+                    resource: parse_resource(&unfreeze.resource)?,
+                },
+            )),
+            ProtoContract::withdraw_expire_unfreeze(withdraw) => Ok(
+                Contract::WithdrawExpireUnfreeze(protocol::WithdrawExpireUnfreezeContract {
+                    owner_address: parse_address(&withdraw.owner_address)?,
+                }),
+            ),
+            ProtoContract::delegate_resource(delegate) => Ok(Contract::DelegateResource(
+                protocol::DelegateResourceContract {
+                    owner_address: parse_address(&delegate.owner_address)?,
+                    resource: parse_resource(&delegate.resource)?,
+                    balance: delegate.balance,
+                    receiver_address: parse_address(&delegate.receiver_address)?,
+                    lock: delegate.lock,
+                },
+            )),
+            ProtoContract::undelegate_resource(undelegate) => Ok(Contract::UnDelegateResource(
+                protocol::UnDelegateResourceContract {
+                    owner_address: parse_address(&undelegate.owner_address)?,
+                    resource: parse_resource(&undelegate.resource)?,
+                    balance: undelegate.balance,
+                    receiver_address: parse_address(&undelegate.receiver_address)?,
+                },
+            )),
+            ProtoContract::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
+        }
+    }
+
+    /// Block hash is `sha256` of the serialized block header `raw_data`.
+    fn block_hash(header: &Proto::BlockHeader<'_>) -> SigningResult<Data> {
+        let raw = protocol::mod_BlockHeader::raw {
+            timestamp: header.timestamp,
+            tx_trie_root: header.tx_trie_root.to_vec(),
+            parent_hash: header.parent_hash.to_vec(),
+            number: header.number,
+            witness_address: header.witness_address.to_vec(),
+            version: header.version,
+            ..protocol::mod_BlockHeader::raw::default()
+        };
+        Ok(sha256(&tw_proto::serialize(&raw)?))
+    }
+
+    fn now_ms() -> SigningResult<i64> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+        i64::try_from(now.as_millis()).map_err(|_| SigningError(SigningErrorType::Error_internal))
+    }
+}
+
+/// Returns `0x41`-prefixed address bytes.
+fn parse_address(address: &str) -> SigningResult<Data> {
+    Ok(TronAddress::from_str(address)?.to_prefixed_bytes())
+}
+
+/// Returns `0x41`-prefixed address bytes or an empty array if the address is not set.
+fn parse_optional_address(address: &str) -> SigningResult<Data> {
+    if address.is_empty() {
+        return Ok(Data::default());
+    }
+    parse_address(address)
+}
+
+/// Resource defaults to `BANDWIDTH` if not set.
+fn parse_resource(resource: &str) -> SigningResult<protocol::ResourceCode> {
+    match resource {
+        "" | "BANDWIDTH" => Ok(protocol::ResourceCode::BANDWIDTH),
+        "ENERGY" => Ok(protocol::ResourceCode::ENERGY),
+        _ => Err(SigningError(SigningErrorType::Error_invalid_params)),
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_builder::TransactionBuilder;
+use crate::transaction::Transaction;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_proto::Tron::Proto;
+
+pub struct TronSigner;
+
+impl TronSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+
+        if !input.txId.is_empty() {
+            return Self::sign_tx_id(&private_key, &input.txId);
+        }
+
+        let unsigned_tx = TransactionBuilder::build(&input)?;
+        let signature = private_key.sign(unsigned_tx.id()?)?;
+
+        Self::output_from_signed(&unsigned_tx, &signature)
+    }
+
+    /// Signs a transaction ID returned by a DApp as is.
+    fn sign_tx_id(
+        private_key: &secp256k1::PrivateKey,
+        tx_id: &str,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let hash = H256::from_str(tx_id)
+            .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))?;
+        let signature = private_key.sign(hash)?;
+
+        Ok(Proto::SigningOutput {
+            id: Cow::from(hash.to_vec()),
+            signature: Cow::from(signature.to_bytes().to_vec()),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    pub(crate) fn output_from_signed(
+        unsigned_tx: &Transaction,
+        signature: &secp256k1::Signature,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        Ok(Proto::SigningOutput {
+            id: Cow::from(unsigned_tx.id()?.to_vec()),
+            signature: Cow::from(signature.to_bytes().to_vec()),
+            ref_block_bytes: Cow::from(unsigned_tx.ref_block_bytes.clone()),
+            ref_block_hash: Cow::from(unsigned_tx.ref_block_hash.clone()),
+            json: Cow::from(unsigned_tx.to_json(signature)?),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::proto::protocol;
+use crate::proto::protocol::mod_Transaction::mod_Contract::ContractType;
+use serde_json::{json, Value as Json};
+use tw_coin_entry::error::SigningResult;
+use tw_encoding::hex::ToHex;
+use tw_proto::{google, MessageWrite};
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/protocol.";
+
+/// A single contract (operation) of a Tron transaction.
+pub enum Contract {
+    Transfer(protocol::TransferContract),
+    TransferAsset(protocol::TransferAssetContract),
+    VoteAsset(protocol::VoteAssetContract),
+    VoteWitness(protocol::VoteWitnessContract),
+    FreezeBalance(protocol::FreezeBalanceContract),
+    UnfreezeBalance(protocol::UnfreezeBalanceContract),
+    WithdrawBalance(protocol::WithdrawBalanceContract),
+    UnfreezeAsset(protocol::UnfreezeAssetContract),
+    TriggerSmartContract(protocol::TriggerSmartContract),
+    FreezeBalanceV2(protocol::FreezeBalanceV2Contract),
+    UnfreezeBalanceV2(protocol::UnfreezeBalanceV2Contract),
+    WithdrawExpireUnfreeze(protocol::WithdrawExpireUnfreezeContract),
+    DelegateResource(protocol::DelegateResourceContract),
+    UnDelegateResource(protocol::UnDelegateResourceContract),
+}
+
+impl Contract {
+    pub fn contract_type(&self) -> ContractType {
+        match self {
+            Contract::Transfer(_) => ContractType::TransferContract,
+            Contract::TransferAsset(_) => ContractType::TransferAssetContract,
+            Contract::VoteAsset(_) => ContractType::VoteAssetContract,
+            Contract::VoteWitness(_) => ContractType::VoteWitnessContract,
+            Contract::FreezeBalance(_) => ContractType::FreezeBalanceContract,
+            Contract::UnfreezeBalance(_) => ContractType::UnfreezeBalanceContract,
+            Contract::WithdrawBalance(_) => ContractType::WithdrawBalanceContract,
+            Contract::UnfreezeAsset(_) => ContractType::UnfreezeAssetContract,
+            Contract::TriggerSmartContract(_) => ContractType::TriggerSmartContract,
+            Contract::FreezeBalanceV2(_) => ContractType::FreezeBalanceV2Contract,
+            Contract::UnfreezeBalanceV2(_) => ContractType::UnfreezeBalanceV2Contract,
+            Contract::WithdrawExpireUnfreeze(_) => ContractType::WithdrawExpireUnfreezeContract,
+            Contract::DelegateResource(_) => ContractType::DelegateResourceContract,
+            Contract::UnDelegateResource(_) => ContractType::UnDelegateResourceContract,
+        }
+    }
+
+    /// Returns the contract type name as it's used in the JSON representation and the `type_url`.
+    pub fn type_name(&self) -> &'static str {
+        match self.contract_type() {
+            ContractType::AccountCreateContract => "AccountCreateContract",
+            ContractType::TransferContract => "TransferContract",
+            ContractType::TransferAssetContract => "TransferAssetContract",
+            ContractType::VoteAssetContract => "VoteAssetContract",
+            ContractType::VoteWitnessContract => "VoteWitnessContract",
+            ContractType::FreezeBalanceContract => "FreezeBalanceContract",
+            ContractType::UnfreezeBalanceContract => "UnfreezeBalanceContract",
+            ContractType::WithdrawBalanceContract => "WithdrawBalanceContract",
+            ContractType::UnfreezeAssetContract => "UnfreezeAssetContract",
+            ContractType::TriggerSmartContract => "TriggerSmartContract",
+            ContractType::FreezeBalanceV2Contract => "FreezeBalanceV2Contract",
+            ContractType::UnfreezeBalanceV2Contract => "UnfreezeBalanceV2Contract",
+            ContractType::WithdrawExpireUnfreezeContract => "WithdrawExpireUnfreezeContract",
+            ContractType::DelegateResourceContract => "DelegateResourceContract",
+            ContractType::UnDelegateResourceContract => "UnDelegateResourceContract",
+        }
+    }
+
+    pub fn type_url(&self) -> String {
+        format!("{TYPE_URL_PREFIX}{}", self.type_name())
+    }
+
+    pub fn to_proto(&self) -> SigningResult<protocol::mod_Transaction::Contract> {
+        let value = match self {
+            Contract::Transfer(c) => serialize(c)?,
+            Contract::TransferAsset(c) => serialize(c)?,
+            Contract::VoteAsset(c) => serialize(c)?,
+            Contract::VoteWitness(c) => serialize(c)?,
+            Contract::FreezeBalance(c) => serialize(c)?,
+            Contract::UnfreezeBalance(c) => serialize(c)?,
+            Contract::WithdrawBalance(c) => serialize(c)?,
+            Contract::UnfreezeAsset(c) => serialize(c)?,
+            Contract::TriggerSmartContract(c) => serialize(c)?,
+            Contract::FreezeBalanceV2(c) => serialize(c)?,
+            Contract::UnfreezeBalanceV2(c) => serialize(c)?,
+            Contract::WithdrawExpireUnfreeze(c) => serialize(c)?,
+            Contract::DelegateResource(c) => serialize(c)?,
+            Contract::UnDelegateResource(c) => serialize(c)?,
+        };
+
+        Ok(protocol::mod_Transaction::Contract {
+            type_pb: self.contract_type(),
+            parameter: Some(google::protobuf::Any {
+                type_url: self.type_url(),
+                value,
+            }),
+            ..protocol::mod_Transaction::Contract::default()
+        })
+    }
+
+    /// Returns the JSON representation as accepted by TronGrid `broadcasttransaction`.
+    pub fn to_json(&self) -> Json {
+        json!({
+            "parameter": {
+                "type_url": self.type_url(),
+                "value": self.value_json(),
+            },
+            "type": self.type_name(),
+        })
+    }
+
+    fn value_json(&self) -> Json {
+        match self {
+            Contract::Transfer(c) => json!({
+                "amount": c.amount,
+                "owner_address": c.owner_address.to_hex(),
+                "to_address": c.to_address.to_hex(),
+            }),
+            Contract::TransferAsset(c) => json!({
+                "amount": c.amount,
+                "asset_name": c.asset_name.to_hex(),
+                "owner_address": c.owner_address.to_hex(),
+                "to_address": c.to_address.to_hex(),
+            }),
+            Contract::VoteAsset(c) => json!({
+                "count": c.count,
+                "owner_address": c.owner_address.to_hex(),
+                "support": c.support,
+                "vote_address": c.vote_address.iter().map(ToHex::to_hex).collect::<Vec<_>>(),
+            }),
+            Contract::VoteWitness(c) => {
+                let votes: Vec<_> = c
+                    .votes
+                    .iter()
+                    .map(|vote| {
+                        json!({
+                            "vote_address": vote.vote_address.to_hex(),
+                            "vote_count": vote.vote_count,
+                        })
+                    })
+                    .collect();
+                json!({
+                    "owner_address": c.owner_address.to_hex(),
+                    "support": c.support,
+                    "votes": votes,
+                })
+            },
+            Contract::FreezeBalance(c) => json!({
+                "frozen_balance": c.frozen_balance,
+                "frozen_duration": c.frozen_duration,
+                "owner_address": c.owner_address.to_hex(),
+                "receiver_address": c.receiver_address.to_hex(),
+                "resource": resource_name(c.resource),
+            }),
+            Contract::UnfreezeBalance(c) => json!({
+                "owner_address": c.owner_address.to_hex(),
+                "receiver_address": c.receiver_address.to_hex(),
+                "resource": resource_name(c.resource),
+            }),
+            Contract::WithdrawBalance(c) => json!({
+                "owner_address": c.owner_address.to_hex(),
+            }),
+            Contract::UnfreezeAsset(c) => json!({
+                "owner_address": c.owner_address.to_hex(),
+            }),
+            Contract::TriggerSmartContract(c) => {
+                let mut value = json!({
+                    "contract_address": c.contract_address.to_hex(),
+                    "data": c.data.to_hex(),
+                    "owner_address": c.owner_address.to_hex(),
+                });
+                // Optional fields are omitted if not set.
+                if c.call_value > 0 {
+                    value["call_value"] = json!(c.call_value);
+                }
+                if c.call_token_value > 0 {
+                    value["call_token_value"] = json!(c.call_token_value);
+                }
+                if c.token_id > 0 {
+                    value["token_id"] = json!(c.token_id);
+                }
+                value
+            },
+            Contract::FreezeBalanceV2(c) => json!({
+                "frozen_balance": c.frozen_balance,
+                "owner_address": c.owner_address.to_hex(),
+                "resource": resource_name(c.resource),
+            }),
+            Contract::UnfreezeBalanceV2(c) => json!({
+                "owner_address": c.owner_address.to_hex(),
+                "resource": resource_name(c.resource),
+                "unfreeze_balance": c.unfreeze_balance,
+            }),
+            Contract::WithdrawExpireUnfreeze(c) => json!({
+                "owner_address": c.owner_address.to_hex(),
+            }),
+            Contract::DelegateResource(c) => json!({
+                "balance": c.balance,
+                "lock": c.lock,
+                "owner_address": c.owner_address.to_hex(),
+                "receiver_address": c.receiver_address.to_hex(),
+                "resource": resource_name(c.resource),
+            }),
+            Contract::UnDelegateResource(c) => json!({
+                "balance": c.balance,
+                "owner_address": c.owner_address.to_hex(),
+                "receiver_address": c.receiver_address.to_hex(),
+                "resource": resource_name(c.resource),
+            }),
+        }
+    }
+}
+
+fn serialize<T: MessageWrite>(message: &T) -> SigningResult<Vec<u8>> {
+    Ok(tw_proto::serialize(message)?)
+}
+
+fn resource_name(resource: protocol::ResourceCode) -> &'static str {
+    match resource {
+        protocol::ResourceCode::BANDWIDTH => "BANDWIDTH",
+        protocol::ResourceCode::ENERGY => "ENERGY",
+    }
+}
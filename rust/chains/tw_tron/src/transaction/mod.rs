@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::proto::protocol;
+use crate::transaction::contract::Contract;
+use serde::Serialize;
+use serde_json::Value as Json;
+use tw_coin_entry::error::SigningResult;
+use tw_encoding::hex::ToHex;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+
+pub mod contract;
+
+/// Unsigned Tron transaction, i.e. its `raw_data`.
+pub struct Transaction {
+    /// The last 2 bytes of the reference block number.
+    pub ref_block_bytes: Data,
+    /// 8 bytes of the reference block hash.
+    pub ref_block_hash: Data,
+    /// Transaction expiration time in milliseconds.
+    pub expiration: i64,
+    /// Transaction timestamp in milliseconds.
+    pub timestamp: i64,
+    pub fee_limit: i64,
+    pub contract: Contract,
+}
+
+impl Transaction {
+    pub fn to_raw_proto(&self) -> SigningResult<protocol::mod_Transaction::raw> {
+        Ok(protocol::mod_Transaction::raw {
+            ref_block_bytes: self.ref_block_bytes.clone(),
+            ref_block_hash: self.ref_block_hash.clone(),
+            expiration: self.expiration,
+            contract: vec![self.contract.to_proto()?],
+            timestamp: self.timestamp,
+            fee_limit: self.fee_limit,
+            ..protocol::mod_Transaction::raw::default()
+        })
+    }
+
+    /// Returns the serialized `raw_data` that needs to be signed.
+    pub fn preimage(&self) -> SigningResult<Data> {
+        Ok(tw_proto::serialize(&self.to_raw_proto()?)?)
+    }
+
+    /// Returns the transaction ID, which is also the hash to be signed.
+    pub fn id(&self) -> SigningResult<H256> {
+        let hash = sha256(&self.preimage()?);
+        Ok(H256::try_from(hash.as_slice()).expect("sha256 must return 32 bytes"))
+    }
+
+    /// Returns the signed transaction in the JSON format accepted by TronGrid `broadcasttransaction`.
+    pub fn to_json(&self, signature: &secp256k1::Signature) -> SigningResult<String> {
+        let signed = SignedTransactionJson {
+            raw_data: RawDataJson {
+                contract: vec![self.contract.to_json()],
+                expiration: self.expiration,
+                fee_limit: self.fee_limit,
+                ref_block_bytes: self.ref_block_bytes.to_hex(),
+                ref_block_hash: self.ref_block_hash.to_hex(),
+                timestamp: self.timestamp,
+            },
+            signature: vec![signature.to_bytes().to_hex()],
+            tx_id: self.id()?.to_hex(),
+        };
+        Ok(serde_json::to_string(&signed)?)
+    }
+}
+
+/// Fields are declared in the alphabetical order to keep the established JSON layout.
+#[derive(Serialize)]
+struct SignedTransactionJson {
+    raw_data: RawDataJson,
+    signature: Vec<String>,
+    #[serde(rename = "txID")]
+    tx_id: String,
+}
+
+#[derive(Serialize)]
+struct RawDataJson {
+    contract: Vec<Json>,
+    expiration: i64,
+    #[serde(skip_serializing_if = "is_zero")]
+    fee_limit: i64,
+    ref_block_bytes: String,
+    ref_block_hash: String,
+    timestamp: i64,
+}
+
+fn is_zero(value: &i64) -> bool {
+    *value == 0
+}
@@ -57,6 +57,27 @@ pub unsafe extern "C" fn tw_any_address_is_valid_bech32(
     AnyAddress::is_valid(coin, string, Some(prefix))
 }
 
+/// Determines if the string is a valid Any address with the given SS58 network prefix.
+///
+/// \param string address to validate.
+/// \param coin coin type of the address.
+/// \param ss58Prefix SS58 network prefix of the given address.
+/// \return bool indicating if the address is valid.
+#[no_mangle]
+pub unsafe extern "C" fn tw_any_address_is_valid_ss58(
+    string: *const TWString,
+    coin: u32,
+    ss58_prefix: u32,
+) -> bool {
+    let string = try_or_false!(TWString::from_ptr_as_ref(string));
+    let string = try_or_false!(string.as_str());
+    let coin = try_or_false!(CoinType::try_from(coin));
+    let ss58_prefix = try_or_false!(u16::try_from(ss58_prefix));
+
+    let prefix = AddressPrefix::SS58(ss58_prefix);
+    AnyAddress::is_valid(coin, string, Some(prefix))
+}
+
 /// Creates an address from a string representation and a coin type. Must be deleted with `TWAnyAddressDelete` after use.
 ///
 /// \param string address to create.
@@ -126,6 +147,57 @@ pub unsafe extern "C" fn tw_any_address_create_bech32_with_public_key(
     .unwrap_or_else(|_| std::ptr::null_mut())
 }
 
+/// Creates an SS58 address from a string representation, a coin type and the given SS58 network prefix.
+/// Must be deleted with `TWAnyAddressDelete` after use.
+///
+/// \param string address to create.
+/// \param coin coin type of the address.
+/// \param ss58Prefix SS58 network prefix of the address.
+/// \return TWAnyAddress pointer or nullptr if address and coin are invalid.
+#[no_mangle]
+pub unsafe extern "C" fn tw_any_address_create_ss58_with_string(
+    string: *const TWString,
+    coin: u32,
+    ss58_prefix: u32,
+) -> *mut TWAnyAddress {
+    let string = try_or_else!(TWString::from_ptr_as_ref(string), std::ptr::null_mut);
+    let string = try_or_else!(string.as_str(), std::ptr::null_mut);
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let ss58_prefix = try_or_else!(u16::try_from(ss58_prefix), std::ptr::null_mut);
+
+    let prefix = AddressPrefix::SS58(ss58_prefix);
+    AnyAddress::with_string(coin, string, Some(prefix))
+        .map(|any_address| TWAnyAddress(any_address).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Creates an SS58 address from a public key and the given SS58 network prefix.
+///
+/// \param public_key derivates the address from the public key.
+/// \param coin coin type of the address.
+/// \param ss58Prefix SS58 network prefix of the address.
+/// \return TWAnyAddress pointer or nullptr if public key is invalid.
+#[no_mangle]
+pub unsafe extern "C" fn tw_any_address_create_ss58_with_public_key(
+    public_key: *mut TWPublicKey,
+    coin: u32,
+    ss58_prefix: u32,
+) -> *mut TWAnyAddress {
+    let public_key = try_or_else!(TWPublicKey::from_ptr_as_ref(public_key), std::ptr::null_mut);
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let ss58_prefix = try_or_else!(u16::try_from(ss58_prefix), std::ptr::null_mut);
+
+    let prefix = AddressPrefix::SS58(ss58_prefix);
+    AnyAddress::with_public_key(
+        coin,
+        public_key.as_ref().clone(),
+        Derivation::default(),
+        Some(prefix),
+    )
+    .map(|any_address| TWAnyAddress(any_address).into_ptr())
+    .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
 /// Deletes an address.
 ///
 /// \param address address to delete.
@@ -3,9 +3,10 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ffi::tw_any_address::{
-    tw_any_address_create_bech32_with_public_key, tw_any_address_create_with_string,
-    tw_any_address_data, tw_any_address_delete, tw_any_address_description,
-    tw_any_address_is_valid, tw_any_address_is_valid_bech32, TWAnyAddress,
+    tw_any_address_create_bech32_with_public_key, tw_any_address_create_ss58_with_public_key,
+    tw_any_address_create_with_string, tw_any_address_data, tw_any_address_delete,
+    tw_any_address_description, tw_any_address_is_valid, tw_any_address_is_valid_bech32,
+    tw_any_address_is_valid_ss58, TWAnyAddress,
 };
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::{DecodeHex, ToHex};
@@ -101,3 +102,47 @@ pub fn test_address_bech32_is_valid(input: AddressBech32IsValid<'_>) {
         unsafe { tw_any_address_is_valid_bech32(address_str.ptr(), input.coin as u32, hrp.ptr()) };
     assert!(result);
 }
+
+pub struct AddressCreateSS58WithPublicKey<'a> {
+    pub coin: CoinType,
+    pub private_key: &'a str,
+    pub public_key_type: PublicKeyType,
+    pub ss58_prefix: u16,
+    pub expected: &'a str,
+}
+
+pub fn test_address_create_ss58_with_public_key(input: AddressCreateSS58WithPublicKey<'_>) {
+    let private_key = TWPrivateKeyHelper::with_hex(input.private_key);
+    let public_key = TWPublicKeyHelper::wrap(unsafe {
+        tw_private_key_get_public_key_by_type(private_key.ptr(), input.public_key_type as u32)
+    });
+
+    let any_address = TWAnyAddressHelper::wrap(unsafe {
+        tw_any_address_create_ss58_with_public_key(
+            public_key.ptr(),
+            input.coin as u32,
+            input.ss58_prefix as u32,
+        )
+    });
+
+    let actual = TWStringHelper::wrap(unsafe { tw_any_address_description(any_address.ptr()) });
+    assert_eq!(actual.to_string(), Some(input.expected.to_string()));
+}
+
+pub struct AddressSS58IsValid<'a> {
+    pub coin: CoinType,
+    pub address: &'a str,
+    pub ss58_prefix: u16,
+}
+
+pub fn test_address_ss58_is_valid(input: AddressSS58IsValid<'_>) {
+    let address_str = TWStringHelper::create(input.address);
+    let result = unsafe {
+        tw_any_address_is_valid_ss58(
+            address_str.ptr(),
+            input.coin as u32,
+            input.ss58_prefix as u32,
+        )
+    };
+    assert!(result);
+}
@@ -2,7 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::ffi::tw_any_signer::tw_any_signer_sign;
+use crate::ffi::tw_any_signer::{tw_any_signer_plan, tw_any_signer_sign};
 use crate::ffi::tw_transaction_compiler::{
     tw_transaction_compiler_compile, tw_transaction_compiler_pre_image_hashes,
 };
@@ -31,6 +31,18 @@ impl<'a, Output: MessageRead<'a>> AnySignerHelper<'a, Output> {
         let output: Output = deserialize(&self.output_data).unwrap();
         output
     }
+
+    pub fn plan<Input: MessageWrite>(&'a mut self, coin_type: CoinType, input: Input) -> Output {
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+        self.output_data =
+            TWDataHelper::wrap(unsafe { tw_any_signer_plan(input_data.ptr(), coin_type as u32) })
+                .to_vec()
+                .expect("!tw_any_signer_plan returned nullptr");
+
+        let output: Output = deserialize(&self.output_data).unwrap();
+        output
+    }
 }
 
 #[derive(Default)]
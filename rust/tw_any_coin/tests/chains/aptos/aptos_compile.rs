@@ -6,12 +6,18 @@ use crate::chains::aptos::test_cases::transfer_b4d62afd::{
     aptos_sign_transfer_input, expected_json, DATA_TO_SIGN, ENCODED, PRIVATE_KEY, RAW_TXN,
     SIGNATURE,
 };
+use crate::chains::aptos::test_cases::{
+    fee_payer_secp256k1_transfer, multi_agent_transfer, multi_ed25519_transfer,
+    single_key_secp256k1_transfer,
+};
 use crate::chains::aptos::APTOS_COIN_TYPE;
 use tw_any_coin::ffi::tw_transaction_compiler::{
     tw_transaction_compiler_compile, tw_transaction_compiler_pre_image_hashes,
 };
 use tw_coin_entry::error::SigningErrorType;
-use tw_encoding::hex::ToHex;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::ed25519;
 use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
@@ -82,3 +88,307 @@ fn test_any_signer_compile_aptos() {
 
     assert_eq_json!(output.json, expected_json());
 }
+
+#[test]
+fn test_any_signer_compile_aptos_multi_agent() {
+    use multi_agent_transfer::*;
+
+    let input = aptos_sign_multi_agent_input();
+
+    // Step 2: Obtain preimage hash
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let preimage_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_pre_image_hashes(APTOS_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
+
+    let preimage: CompilerProto::PreSigningOutput =
+        deserialize(&preimage_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    assert!(preimage.error_message.is_empty());
+    assert_eq!(preimage.data.to_hex(), multi_agent_transfer::DATA_TO_SIGN);
+
+    // Step 3: Sign the data "externally" by both the sender and the secondary signer.
+
+    let sender_key = ed25519::sha512::KeyPair::try_from(PRIVATE_KEY).unwrap();
+    let sender_signature = sender_key.sign(preimage.data.to_vec()).unwrap().to_vec();
+    assert_eq!(sender_signature.to_hex(), multi_agent_transfer::SIGNATURE);
+
+    let secondary_key = ed25519::sha512::KeyPair::try_from(SECONDARY_SIGNER_PRIVATE_KEY).unwrap();
+    let secondary_signature = secondary_key.sign(preimage.data.to_vec()).unwrap().to_vec();
+    assert_eq!(secondary_signature.to_hex(), SECONDARY_SIGNER_SIGNATURE);
+
+    // Step 4: Compile transaction info
+
+    let signatures = TWDataVectorHelper::create([sender_signature, secondary_signature]);
+    let public_keys = TWDataVectorHelper::create([
+        sender_key.public().to_vec(),
+        secondary_key.public().to_vec(),
+    ]);
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_compile(
+            APTOS_COIN_TYPE,
+            input_data.ptr(),
+            signatures.ptr(),
+            public_keys.ptr(),
+        )
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_compile returned nullptr");
+
+    let output: Proto::SigningOutput =
+        deserialize(&output_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), multi_agent_transfer::ENCODED);
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"]["type"], "multi_agent_signature");
+    assert_eq!(
+        json["signature"]["secondary_signer_addresses"][0],
+        SECONDARY_SIGNER_ADDRESS
+    );
+}
+
+#[test]
+fn test_any_signer_compile_aptos_fee_payer_secp256k1() {
+    use fee_payer_secp256k1_transfer::*;
+
+    let input = aptos_sign_fee_payer_input();
+
+    // Step 2: Obtain preimage hash
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let preimage_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_pre_image_hashes(APTOS_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
+
+    let preimage: CompilerProto::PreSigningOutput =
+        deserialize(&preimage_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    assert!(preimage.error_message.is_empty());
+    assert_eq!(
+        preimage.data.to_hex(),
+        fee_payer_secp256k1_transfer::DATA_TO_SIGN
+    );
+    assert_eq!(preimage.data_hash.to_hex(), DATA_HASH);
+
+    // Step 3: Sign the data by the ed25519 sender and the data hash by the secp256k1 fee payer "externally".
+
+    let sender_key = ed25519::sha512::KeyPair::try_from(PRIVATE_KEY).unwrap();
+    let sender_signature = sender_key.sign(preimage.data.to_vec()).unwrap().to_vec();
+    assert_eq!(
+        sender_signature.to_hex(),
+        fee_payer_secp256k1_transfer::SIGNATURE
+    );
+
+    let fee_payer_key =
+        secp256k1::KeyPair::try_from(single_key_secp256k1_transfer::PRIVATE_KEY).unwrap();
+    let data_hash = H256::try_from(preimage.data_hash.as_ref()).unwrap();
+    let fee_payer_signature = fee_payer_key.sign(data_hash).unwrap().to_vec();
+
+    // Step 4: Compile transaction info
+
+    let signatures = TWDataVectorHelper::create([sender_signature, fee_payer_signature]);
+    let public_keys = TWDataVectorHelper::create([
+        sender_key.public().to_vec(),
+        fee_payer_key.public().to_vec(),
+    ]);
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_compile(
+            APTOS_COIN_TYPE,
+            input_data.ptr(),
+            signatures.ptr(),
+            public_keys.ptr(),
+        )
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_compile returned nullptr");
+
+    let output: Proto::SigningOutput =
+        deserialize(&output_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(
+        output.encoded.to_hex(),
+        fee_payer_secp256k1_transfer::ENCODED
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"]["type"], "fee_payer_signature");
+    assert_eq!(
+        json["signature"]["fee_payer_signer"],
+        expected_fee_payer_signer_json()
+    );
+}
+
+#[test]
+fn test_any_signer_compile_aptos_single_key_secp256k1() {
+    use single_key_secp256k1_transfer::*;
+
+    let input = aptos_sign_single_key_input();
+
+    // Step 2: Obtain preimage hash
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let preimage_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_pre_image_hashes(APTOS_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
+
+    let preimage: CompilerProto::PreSigningOutput =
+        deserialize(&preimage_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    assert!(preimage.error_message.is_empty());
+    assert_eq!(preimage.data.to_hex(), DATA_TO_SIGN);
+    assert_eq!(preimage.data_hash.to_hex(), DATA_HASH);
+
+    // Step 3: Sign the data hash "externally"
+
+    let key_pair =
+        secp256k1::KeyPair::try_from(single_key_secp256k1_transfer::PRIVATE_KEY).unwrap();
+    let data_hash = H256::try_from(preimage.data_hash.as_ref()).unwrap();
+    // The signature contains the recovery byte that must be skipped by the compiler.
+    let signature = key_pair
+        .sign(data_hash)
+        .expect("Error signing data")
+        .to_vec();
+    // Compressed public key is accepted as well.
+    let public_key = key_pair.public().to_vec();
+
+    // Step 4: Compile transaction info
+
+    let signatures = TWDataVectorHelper::create([signature]);
+    let public_keys = TWDataVectorHelper::create([public_key]);
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_compile(
+            APTOS_COIN_TYPE,
+            input_data.ptr(),
+            signatures.ptr(),
+            public_keys.ptr(),
+        )
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_compile returned nullptr");
+
+    let output: Proto::SigningOutput =
+        deserialize(&output_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let authenticator = output.authenticator.unwrap();
+    assert_eq!(
+        authenticator.signature.to_hex(),
+        single_key_secp256k1_transfer::SIGNATURE
+    );
+    assert_eq!(authenticator.public_key.to_hex(), PUBLIC_KEY);
+    assert_eq!(
+        output.encoded.to_hex(),
+        single_key_secp256k1_transfer::ENCODED
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"], expected_signature_json());
+}
+
+#[test]
+fn test_any_signer_compile_aptos_multi_ed25519() {
+    use multi_ed25519_transfer::*;
+
+    let input = aptos_sign_multi_ed25519_input();
+
+    // Step 2: Obtain preimage hash
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let preimage_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_pre_image_hashes(APTOS_COIN_TYPE, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
+
+    let preimage: CompilerProto::PreSigningOutput =
+        deserialize(&preimage_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    assert_eq!(preimage.data.to_hex(), DATA_TO_SIGN);
+    // ed25519 keys sign the data itself.
+    assert!(preimage.data_hash.is_empty());
+
+    // Step 3: Compile transaction info with the first and the third signatures.
+    // The signatures can be passed in any order.
+
+    let signatures = TWDataVectorHelper::create([
+        THIRD_SIGNATURE.decode_hex().unwrap(),
+        SIGNATURE.decode_hex().unwrap(),
+    ]);
+    let public_keys = TWDataVectorHelper::create([
+        PUBLIC_KEYS[2].decode_hex().unwrap(),
+        PUBLIC_KEYS[0].decode_hex().unwrap(),
+    ]);
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_compile(
+            APTOS_COIN_TYPE,
+            input_data.ptr(),
+            signatures.ptr(),
+            public_keys.ptr(),
+        )
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_compile returned nullptr");
+
+    let output: Proto::SigningOutput =
+        deserialize(&output_data).expect("Coin entry returned an invalid output");
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), multi_ed25519_transfer::ENCODED);
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"]["type"], "multi_ed25519_signature");
+    assert_eq!(json["signature"]["bitmap"], "0xa0000000");
+    assert_eq!(json["signature"]["threshold"], 2);
+}
+
+#[test]
+fn test_any_signer_compile_aptos_multi_ed25519_not_enough_signatures() {
+    use multi_ed25519_transfer::*;
+
+    let input = aptos_sign_multi_ed25519_input();
+
+    let signatures = TWDataVectorHelper::create([SIGNATURE.decode_hex().unwrap()]);
+    let public_keys = TWDataVectorHelper::create([PUBLIC_KEYS[0].decode_hex().unwrap()]);
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_transaction_compiler_compile(
+            APTOS_COIN_TYPE,
+            input_data.ptr(),
+            signatures.ptr(),
+            public_keys.ptr(),
+        )
+    })
+    .to_vec()
+    .expect("!tw_transaction_compiler_compile returned nullptr");
+
+    let output: Proto::SigningOutput =
+        deserialize(&output_data).expect("Coin entry returned an invalid output");
+    assert_eq!(output.error, SigningErrorType::Error_signatures_count);
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::aptos::APTOS_COIN_TYPE;
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Aptos::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec";
+const PUBLIC_KEY: &str = "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c";
+const FULL_MESSAGE: &str = "APTOS\naddress: 0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30\napplication: https://example.com\nchainId: 1\nmessage: Welcome to Aptos!\nnonce: 1234034";
+const SIGNATURE: &str = "f8d9dd3bf602e3331c00bd93de111d851da42e699547747f26db55d7346ce8cf4a0cf881aab0131cc0be570b01d19ec5c1d1b184324cd7e2cb6249932d4c2a04";
+
+fn message_signing_input() -> Proto::MessageSigningInput<'static> {
+    Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Welcome to Aptos!".into(),
+        nonce: "1234034".into(),
+        address: "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30".into(),
+        application: "https://example.com".into(),
+        chain_id: 1,
+    }
+}
+
+#[test]
+fn test_aptos_sign_message() {
+    let input_data = TWDataHelper::create(serialize(&message_signing_input()).unwrap());
+    let output =
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.full_message, FULL_MESSAGE);
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+}
+
+#[test]
+fn test_aptos_sign_message_minimal() {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Hello, world!".into(),
+        nonce: "1".into(),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output =
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.full_message,
+        "APTOS\nmessage: Hello, world!\nnonce: 1"
+    );
+    assert_eq!(
+        output.signature.to_hex(),
+        "b175487bf91fd28ebbbcae6f87bb96dd3608a4f3d5ae1d62dc15f3f030dff666af439b06999be27a0629dd800ddc7e077d2fa3b12de1faa7e81b84be7cf93700"
+    );
+}
+
+#[test]
+fn test_aptos_message_pre_image_hashes() {
+    let input_data = TWDataHelper::create(serialize(&message_signing_input()).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), APTOS_COIN_TYPE)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    // Ed25519 signs the full message itself.
+    assert_eq!(output.data.as_ref(), FULL_MESSAGE.as_bytes());
+    assert_eq!(output.data_hash.as_ref(), FULL_MESSAGE.as_bytes());
+}
+
+#[test]
+fn test_aptos_verify_message() {
+    let input = Proto::MessageVerifyingInput {
+        full_message: FULL_MESSAGE.into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.decode_hex().unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    assert!(unsafe { tw_message_signer_verify(input_data.ptr(), APTOS_COIN_TYPE) });
+
+    // The nonce is changed.
+    let input = Proto::MessageVerifyingInput {
+        full_message: FULL_MESSAGE.replace("1234034", "1234035").into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.decode_hex().unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    assert!(!unsafe { tw_message_signer_verify(input_data.ptr(), APTOS_COIN_TYPE) });
+}
@@ -5,6 +5,10 @@
 use crate::chains::aptos::test_cases::transfer_b4d62afd::{
     aptos_sign_transfer_input, expected_json, ENCODED, PRIVATE_KEY, RAW_TXN, SIGNATURE,
 };
+use crate::chains::aptos::test_cases::{
+    fee_payer_secp256k1_transfer, fee_payer_transfer, multi_agent_transfer, multi_ed25519_transfer,
+    multi_key_transfer, single_key_secp256k1_transfer,
+};
 use crate::chains::aptos::APTOS_COIN_TYPE;
 use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
 use tw_coin_entry::error::SigningErrorType;
@@ -39,3 +43,211 @@ fn test_any_signer_sign_aptos() {
 
     assert_eq_json!(output.json, expected_json());
 }
+
+#[test]
+fn test_any_signer_sign_aptos_fee_payer() {
+    use fee_payer_transfer::*;
+
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        fee_payer_signer: Some(Proto::AccountSignature {
+            public_key: FEE_PAYER_PUBLIC_KEY.decode_hex().unwrap().into(),
+            signature: FEE_PAYER_SIGNATURE.decode_hex().unwrap().into(),
+            ..Proto::AccountSignature::default()
+        }),
+        ..aptos_sign_fee_payer_input()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let authenticator = output.authenticator.unwrap();
+    assert_eq!(
+        authenticator.signature.to_hex(),
+        fee_payer_transfer::SIGNATURE
+    );
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), fee_payer_transfer::ENCODED);
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"], expected_signature_json());
+}
+
+#[test]
+fn test_any_signer_sign_aptos_fee_payer_secp256k1() {
+    use fee_payer_secp256k1_transfer::*;
+
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        fee_payer_signer: Some(Proto::AccountSignature {
+            public_key: single_key_secp256k1_transfer::PUBLIC_KEY
+                .decode_hex()
+                .unwrap()
+                .into(),
+            signature: FEE_PAYER_SIGNATURE.decode_hex().unwrap().into(),
+            key_scheme: Proto::KeyScheme::SingleKeySecp256k1,
+            ..Proto::AccountSignature::default()
+        }),
+        ..aptos_sign_fee_payer_input()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let authenticator = output.authenticator.unwrap();
+    assert_eq!(
+        authenticator.signature.to_hex(),
+        fee_payer_secp256k1_transfer::SIGNATURE
+    );
+    assert_eq!(
+        output.encoded.to_hex(),
+        fee_payer_secp256k1_transfer::ENCODED
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(
+        json["signature"]["fee_payer_signer"],
+        expected_fee_payer_signer_json()
+    );
+}
+
+#[test]
+fn test_any_signer_sign_aptos_fee_payer_missing_signature() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..fee_payer_transfer::aptos_sign_fee_payer_input()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_signatures_count);
+}
+
+#[test]
+fn test_any_signer_sign_aptos_single_key_secp256k1() {
+    use single_key_secp256k1_transfer::*;
+
+    let input = Proto::SigningInput {
+        private_key: single_key_secp256k1_transfer::PRIVATE_KEY
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..aptos_sign_single_key_input()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let authenticator = output.authenticator.unwrap();
+    assert_eq!(
+        authenticator.signature.to_hex(),
+        single_key_secp256k1_transfer::SIGNATURE
+    );
+    assert_eq!(authenticator.public_key.to_hex(), PUBLIC_KEY);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(
+        output.encoded.to_hex(),
+        single_key_secp256k1_transfer::ENCODED
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"], expected_signature_json());
+}
+
+#[test]
+fn test_any_signer_sign_aptos_multi_key_secp256k1() {
+    let input = Proto::SigningInput {
+        private_key: single_key_secp256k1_transfer::PRIVATE_KEY
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..multi_key_transfer::aptos_sign_multi_key_input()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.encoded.to_hex(), multi_key_transfer::ENCODED);
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"]["type"], "single_sender");
+    assert_eq!(json["signature"]["signatures_required"], 1);
+    assert_eq!(json["signature"]["signatures"][0]["index"], 1);
+}
+
+#[test]
+fn test_any_signer_sign_aptos_multi_key_unknown_private_key() {
+    let input = Proto::SigningInput {
+        private_key: multi_agent_transfer::SECONDARY_SIGNER_PRIVATE_KEY
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..multi_key_transfer::aptos_sign_multi_key_input()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_any_signer_sign_aptos_multi_ed25519_2_of_3_not_supported() {
+    // The private key matches the first public key of the 2-of-3 account.
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..multi_ed25519_transfer::aptos_sign_multi_ed25519_input()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+}
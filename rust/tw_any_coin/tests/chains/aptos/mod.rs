@@ -4,6 +4,7 @@
 
 mod aptos_address;
 mod aptos_compile;
+mod aptos_message_sign;
 mod aptos_sign;
 mod test_cases;
 
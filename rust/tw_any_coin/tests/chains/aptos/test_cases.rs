@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use serde_json::{json, Value as Json};
+use tw_encoding::hex::DecodeHex;
 use tw_proto::Aptos::Proto;
 use tw_proto::Aptos::Proto::mod_SigningInput::OneOftransaction_payload as TransactionPayloadEnum;
 
@@ -60,3 +61,220 @@ pub(super) mod transfer_b4d62afd {
         })
     }
 }
+
+/// Sponsored version of the [`transfer_b4d62afd`] transaction.
+pub(super) mod fee_payer_transfer {
+    use super::*;
+
+    pub const FEE_PAYER_ADDRESS: &str =
+        "0x98c121c136b9a47a42dab4c0e678b67db6bdcc9091216f36feb5d5f2cbdf4d22";
+    pub const FEE_PAYER_PUBLIC_KEY: &str =
+        "694194dc897927e7cd503f64e0321690bda3761f04906d06ac6fe192cb66830c";
+    /// The fee payer's signature of the [`DATA_TO_SIGN`].
+    pub const FEE_PAYER_SIGNATURE: &str = "f01c5b9e968a8f18a97f2546cb509f8bb3fbd5d8205989f8207347eb99a2d8f18d3728b66a22ba58f53c643a88f0f84861640fe9f007d986dd474b9ed543a304";
+    /// The sender's signature of the [`DATA_TO_SIGN`].
+    pub const SIGNATURE: &str = "0f7bb9b2146450d1f83500f6750ac314d74348a444fb650ab9168ccf41c433ff9a71434ee94b9e49a110e74c712c2fe83d7e9be26314e2f971cb2ddc07a8170b";
+    /// Expected preimage data to be signed by the sender and the fee payer.
+    pub const DATA_TO_SIGN: &str = "5efa3c4f02f83a0f4b2d69fc95c607cc02825cc4e7be536ef0992df050d9e67c0107968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210098c121c136b9a47a42dab4c0e678b67db6bdcc9091216f36feb5d5f2cbdf4d22";
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021030020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c400f7bb9b2146450d1f83500f6750ac314d74348a444fb650ab9168ccf41c433ff9a71434ee94b9e49a110e74c712c2fe83d7e9be26314e2f971cb2ddc07a8170b000098c121c136b9a47a42dab4c0e678b67db6bdcc9091216f36feb5d5f2cbdf4d220020694194dc897927e7cd503f64e0321690bda3761f04906d06ac6fe192cb66830c40f01c5b9e968a8f18a97f2546cb509f8bb3fbd5d8205989f8207347eb99a2d8f18d3728b66a22ba58f53c643a88f0f84861640fe9f007d986dd474b9ed543a304";
+
+    pub fn aptos_sign_fee_payer_input() -> Proto::SigningInput<'static> {
+        Proto::SigningInput {
+            fee_payer_address: FEE_PAYER_ADDRESS.into(),
+            ..transfer_b4d62afd::aptos_sign_transfer_input()
+        }
+    }
+
+    pub fn expected_signature_json() -> Json {
+        json!({
+            "fee_payer_address": FEE_PAYER_ADDRESS,
+            "fee_payer_signer": {
+                "public_key": format!("0x{FEE_PAYER_PUBLIC_KEY}"),
+                "signature": format!("0x{FEE_PAYER_SIGNATURE}"),
+                "type": "ed25519_signature"
+            },
+            "secondary_signer_addresses": [],
+            "secondary_signers": [],
+            "sender": {
+                "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                "signature": format!("0x{SIGNATURE}"),
+                "type": "ed25519_signature"
+            },
+            "type": "fee_payer_signature"
+        })
+    }
+}
+
+/// Sponsored version of the [`transfer_b4d62afd`] transaction,
+/// where the fee payer is a secp256k1 account of the `SingleKey` scheme.
+pub(super) mod fee_payer_secp256k1_transfer {
+    use super::*;
+
+    /// Derived from the [`single_key_secp256k1_transfer::PUBLIC_KEY`].
+    pub const FEE_PAYER_ADDRESS: &str =
+        "0x0ea218899c673ab3d443f41a6b7408bbbfe852a8bea8c0c3d65a189ae9d84646";
+    /// The fee payer's signature of the [`DATA_HASH`].
+    pub const FEE_PAYER_SIGNATURE: &str = "b9f5679a51e7181b4ce98a157a4ef169a967189f439f36db390ff9077f15cd523b317c55c05c4015312135412a3c339c499ff88639a1f1a376d86408f827534d";
+    /// The sender's signature of the [`DATA_TO_SIGN`].
+    pub const SIGNATURE: &str = "c23825161669c2222cfc0be93a7479781fd37b171412ceb95ee8ce03ff964f457882bdebb1a300cc4ab97d856a378127e72af9b94a9f9459201faad37714800d";
+    /// Expected preimage data to be signed by the sender.
+    pub const DATA_TO_SIGN: &str = "5efa3c4f02f83a0f4b2d69fc95c607cc02825cc4e7be536ef0992df050d9e67c0107968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021000ea218899c673ab3d443f41a6b7408bbbfe852a8bea8c0c3d65a189ae9d84646";
+    /// SHA3-256 hash of the [`DATA_TO_SIGN`] to be signed by the fee payer.
+    pub const DATA_HASH: &str = "60244cb47fa964816dd1f5f0d87bd5dc1926f539b7fc2f919a7f5fa3e167ed2a";
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021030020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40c23825161669c2222cfc0be93a7479781fd37b171412ceb95ee8ce03ff964f457882bdebb1a300cc4ab97d856a378127e72af9b94a9f9459201faad37714800d00000ea218899c673ab3d443f41a6b7408bbbfe852a8bea8c0c3d65a189ae9d8464602014104671f4e424abeb34333acd15be4cacc7aec5b339a68a2b99ed454fece8e26fd0f47f124979fd1e5669be86864570ac0f944c991815429b1e3c4b42b044b000d5e0140b9f5679a51e7181b4ce98a157a4ef169a967189f439f36db390ff9077f15cd523b317c55c05c4015312135412a3c339c499ff88639a1f1a376d86408f827534d";
+
+    pub fn aptos_sign_fee_payer_input() -> Proto::SigningInput<'static> {
+        Proto::SigningInput {
+            fee_payer_address: FEE_PAYER_ADDRESS.into(),
+            // The compiler only needs the fee payer's key scheme.
+            fee_payer_signer: Some(Proto::AccountSignature {
+                key_scheme: Proto::KeyScheme::SingleKeySecp256k1,
+                ..Proto::AccountSignature::default()
+            }),
+            ..transfer_b4d62afd::aptos_sign_transfer_input()
+        }
+    }
+
+    pub fn expected_fee_payer_signer_json() -> Json {
+        json!({
+            "public_key": {
+                "type": "secp256k1_ecdsa",
+                "value": format!("0x{}", single_key_secp256k1_transfer::PUBLIC_KEY)
+            },
+            "signature": {
+                "type": "secp256k1_ecdsa",
+                "value": format!("0x{FEE_PAYER_SIGNATURE}")
+            },
+            "type": "single_key_signature"
+        })
+    }
+}
+
+/// Multi-agent version of the [`transfer_b4d62afd`] transaction.
+pub(super) mod multi_agent_transfer {
+    use super::*;
+
+    pub const SECONDARY_SIGNER_ADDRESS: &str =
+        "0x5dcfa687ff18a005927332aeaa51f2708eb647243a4aea5f41407539f60f6ea8";
+    pub const SECONDARY_SIGNER_PRIVATE_KEY: &str =
+        "7f2634c0e2414a621e96e39c41d09021700cee12ee43328ed094c5580cd0bd6f";
+    /// The secondary signer's signature of the [`DATA_TO_SIGN`].
+    pub const SECONDARY_SIGNER_SIGNATURE: &str = "4674fe80716672b79ebf582bde7e5dd14873e20e649eea71086bd20d3c58da32bf3add7accdc734e1c1378bbcdd0ee15207683a05e2b05bf4b9b8dba8212420d";
+    /// The sender's signature of the [`DATA_TO_SIGN`].
+    pub const SIGNATURE: &str = "b2b1c62f64d7e49035b1270ff120fcf144f924e689801ae024b1e1ee8dad8b6a69b4ae493bccae168733d83ce93dfe0b576e0902856e4fb0ed188c05bd5f390d";
+    /// Expected preimage data to be signed by the sender and the secondary signer.
+    pub const DATA_TO_SIGN: &str = "5efa3c4f02f83a0f4b2d69fc95c607cc02825cc4e7be536ef0992df050d9e67c0007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021015dcfa687ff18a005927332aeaa51f2708eb647243a4aea5f41407539f60f6ea8";
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021020020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40b2b1c62f64d7e49035b1270ff120fcf144f924e689801ae024b1e1ee8dad8b6a69b4ae493bccae168733d83ce93dfe0b576e0902856e4fb0ed188c05bd5f390d015dcfa687ff18a005927332aeaa51f2708eb647243a4aea5f41407539f60f6ea8010020633e5c7e355bdd484706436ce1f06fdf280bd7c2229a7f9b6489684412c6967c404674fe80716672b79ebf582bde7e5dd14873e20e649eea71086bd20d3c58da32bf3add7accdc734e1c1378bbcdd0ee15207683a05e2b05bf4b9b8dba8212420d";
+
+    pub fn aptos_sign_multi_agent_input() -> Proto::SigningInput<'static> {
+        Proto::SigningInput {
+            secondary_signer_addresses: vec![SECONDARY_SIGNER_ADDRESS.into()],
+            ..transfer_b4d62afd::aptos_sign_transfer_input()
+        }
+    }
+}
+
+/// [`transfer_b4d62afd`] transaction signed by a secp256k1 account of the `SingleKey` scheme.
+pub(super) mod single_key_secp256k1_transfer {
+    use super::*;
+
+    pub const PRIVATE_KEY: &str =
+        "bd9d0ac9b4aa3c8b2b1a0f0e3fd0cf8e4d9dcd3b1f4c5b1a2a3e7c8f0f2d1c3e";
+    pub const PUBLIC_KEY: &str = "04671f4e424abeb34333acd15be4cacc7aec5b339a68a2b99ed454fece8e26fd0f47f124979fd1e5669be86864570ac0f944c991815429b1e3c4b42b044b000d5e";
+    /// SHA3-256 hash of the [`transfer_b4d62afd::DATA_TO_SIGN`].
+    pub const DATA_HASH: &str = "1beb93e3a231a09a12e9a5940d1c981a9788afad9eca17e49b4b59c5215ab05a";
+    pub const SIGNATURE: &str = "dcdee8e3459539d432c4ad246cf7afdf055fc4722fa433a3f7b7f71dc4870aba6dc76a3972f191f830d136187ee6ebb4be755ba41925b7d7801a323ca345098e";
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210402014104671f4e424abeb34333acd15be4cacc7aec5b339a68a2b99ed454fece8e26fd0f47f124979fd1e5669be86864570ac0f944c991815429b1e3c4b42b044b000d5e0140dcdee8e3459539d432c4ad246cf7afdf055fc4722fa433a3f7b7f71dc4870aba6dc76a3972f191f830d136187ee6ebb4be755ba41925b7d7801a323ca345098e";
+
+    pub fn aptos_sign_single_key_input() -> Proto::SigningInput<'static> {
+        Proto::SigningInput {
+            sender_key_scheme: Proto::KeyScheme::SingleKeySecp256k1,
+            ..transfer_b4d62afd::aptos_sign_transfer_input()
+        }
+    }
+
+    pub fn expected_signature_json() -> Json {
+        json!({
+            "public_key": {
+                "type": "secp256k1_ecdsa",
+                "value": format!("0x{PUBLIC_KEY}")
+            },
+            "signature": {
+                "type": "secp256k1_ecdsa",
+                "value": format!("0x{SIGNATURE}")
+            },
+            "type": "single_sender"
+        })
+    }
+}
+
+/// [`transfer_b4d62afd`] transaction signed by a 2-of-3 MultiEd25519 account.
+pub(super) mod multi_ed25519_transfer {
+    use super::*;
+
+    /// The first key is the [`transfer_b4d62afd::PRIVATE_KEY`] one.
+    pub const PUBLIC_KEYS: [&str; 3] = [
+        "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+        "633e5c7e355bdd484706436ce1f06fdf280bd7c2229a7f9b6489684412c6967c",
+        "694194dc897927e7cd503f64e0321690bda3761f04906d06ac6fe192cb66830c",
+    ];
+    /// Signature of the [`transfer_b4d62afd::DATA_TO_SIGN`] by the third key.
+    pub const THIRD_SIGNATURE: &str = "721754db3775dfdb5940e08492bd1aafffbd924c3f494080c0e0b0673914b720e5f36829f9d41d38a65f1cf586f08cac322cc6cab7133bb152d61c6f5c9e7600";
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210161ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c633e5c7e355bdd484706436ce1f06fdf280bd7c2229a7f9b6489684412c6967c694194dc897927e7cd503f64e0321690bda3761f04906d06ac6fe192cb66830c0284015707246db31e2335edc4316a7a656a11691d1d1647f6e864d1ab12f43428aaaf806cf02120d0b608cdd89c5c904af7b137432aacdd60cc53f9fad7bd33578e01721754db3775dfdb5940e08492bd1aafffbd924c3f494080c0e0b0673914b720e5f36829f9d41d38a65f1cf586f08cac322cc6cab7133bb152d61c6f5c9e7600a0000000";
+
+    pub fn aptos_sign_multi_ed25519_input() -> Proto::SigningInput<'static> {
+        let public_keys = PUBLIC_KEYS
+            .iter()
+            .map(|public_key| Proto::AnyPublicKey {
+                type_pb: Proto::mod_AnyPublicKey::KeyType::Ed25519,
+                public_key: public_key.decode_hex().unwrap().into(),
+            })
+            .collect();
+
+        Proto::SigningInput {
+            sender_key_scheme: Proto::KeyScheme::MultiEd25519,
+            multi_key_account: Some(Proto::MultiKeyAccount {
+                public_keys,
+                threshold: 2,
+            }),
+            ..transfer_b4d62afd::aptos_sign_transfer_input()
+        }
+    }
+}
+
+/// [`transfer_b4d62afd`] transaction signed by a 1-of-2 MultiKey account
+/// consisting of the [`transfer_b4d62afd::PRIVATE_KEY`] ed25519 key
+/// and the [`single_key_secp256k1_transfer::PRIVATE_KEY`] secp256k1 key.
+pub(super) mod multi_key_transfer {
+    use super::*;
+
+    /// Signed by the secp256k1 key.
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210403020020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c014104671f4e424abeb34333acd15be4cacc7aec5b339a68a2b99ed454fece8e26fd0f47f124979fd1e5669be86864570ac0f944c991815429b1e3c4b42b044b000d5e01010140dcdee8e3459539d432c4ad246cf7afdf055fc4722fa433a3f7b7f71dc4870aba6dc76a3972f191f830d136187ee6ebb4be755ba41925b7d7801a323ca345098e0140";
+
+    pub fn aptos_sign_multi_key_input() -> Proto::SigningInput<'static> {
+        let ed25519_key = Proto::AnyPublicKey {
+            type_pb: Proto::mod_AnyPublicKey::KeyType::Ed25519,
+            public_key: multi_ed25519_transfer::PUBLIC_KEYS[0]
+                .decode_hex()
+                .unwrap()
+                .into(),
+        };
+        // Compressed keys are accepted as well.
+        let secp256k1_key = Proto::AnyPublicKey {
+            type_pb: Proto::mod_AnyPublicKey::KeyType::Secp256k1Ecdsa,
+            public_key: "02671f4e424abeb34333acd15be4cacc7aec5b339a68a2b99ed454fece8e26fd0f"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        };
+
+        Proto::SigningInput {
+            sender_key_scheme: Proto::KeyScheme::MultiKey,
+            multi_key_account: Some(Proto::MultiKeyAccount {
+                public_keys: vec![ed25519_key, secp256k1_key],
+                threshold: 1,
+            }),
+            ..transfer_b4d62afd::aptos_sign_transfer_input()
+        }
+    }
+}
@@ -2,7 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::chains::binance::{make_token, ACCOUNT_19_PRIVATE_KEY};
+use crate::chains::binance::{
+    make_token, ACCOUNT_12_PRIVATE_KEY, ACCOUNT_15_PRIVATE_KEY, ACCOUNT_19_PRIVATE_KEY,
+};
 use std::borrow::Cow;
 use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
 use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
@@ -13,6 +15,42 @@ use tw_proto::Common::Proto::SigningError;
 use tw_proto::WalletConnect::Proto as WCProto;
 
 const WC_SIGN_REQUEST_CASE_1: &str = include_str!("data/wc_sign_request_case_1.json");
+const WC_BNB_SIGN_TRADE_ORDER: &str = include_str!("data/wc_bnb_sign_trade_order.json");
+const WC_BNB_SIGN_CANCEL_TRADE_ORDER: &str =
+    include_str!("data/wc_bnb_sign_cancel_trade_order.json");
+const WC_BNB_SIGN_HTLT_ORDER: &str = include_str!("data/wc_bnb_sign_htlt_order.json");
+const WC_BNB_SIGN_SIDE_DELEGATE_ORDER: &str =
+    include_str!("data/wc_bnb_sign_side_delegate_order.json");
+
+fn parse_bnb_sign_request<'a>(
+    parser: &'a mut WalletConnectRequestHelper,
+    payload: &str,
+) -> Proto::SigningInput<'a> {
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::BnbSign,
+        payload: payload.to_string().into(),
+    };
+
+    let parsing_output = parser.parse(CoinType::Binance, &input);
+
+    match parsing_output.signing_input_oneof {
+        WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof::binance(input) => input,
+        _ => unreachable!(),
+    }
+}
+
+fn sign<'a>(
+    signer: &'a mut AnySignerHelper<'a, Proto::SigningOutput<'a>>,
+    mut signing_input: Proto::SigningInput<'_>,
+    private_key: &str,
+) -> Proto::SigningOutput<'a> {
+    signing_input.private_key = private_key.decode_hex().unwrap().into();
+
+    let signing_output = signer.sign(CoinType::Binance, signing_input);
+    assert_eq!(signing_output.error, SigningError::OK);
+    signing_output
+}
 
 #[test]
 fn test_binance_sign_wallet_connect_case_1() {
@@ -67,3 +105,91 @@ fn test_binance_sign_wallet_connect_case_1() {
     let expected_signature_json = r#"{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"Amo1kgCI2Yw4iMpoxT38k/RWRgJgbLuH8P5e5TPbOOUC"},"signature":"PCTHhMa7+Z1U/6uxU+3LbTxKd0k231xypdMolyVvjgYvMg+0dTMC+wqW8IxHWXTSDt/Ronu+7ac1h/WN3JWJdQ=="}"#;
     assert_eq!(signing_output.signature_json, expected_signature_json);
 }
+
+#[test]
+fn test_binance_wallet_connect_bnb_sign_trade_order() {
+    let mut parser = WalletConnectRequestHelper::default();
+    let signing_input = parse_bnb_sign_request(&mut parser, WC_BNB_SIGN_TRADE_ORDER);
+
+    let expected_trade_order = Proto::TradeOrder {
+        // bnb1hgm0p7khfk85zpz5v0j8wnej3a90w709vhkdfu
+        sender: "ba36f0fad74d8f41045463e4774f328f4af779e5"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        id: "BA36F0FAD74D8F41045463E4774F328F4AF779E5-36".into(),
+        symbol: "NNB-338_BNB".into(),
+        ordertype: 2,
+        side: 1,
+        price: 136350000,
+        quantity: 100000000,
+        timeinforce: 1,
+    };
+    let expected_signing_input = Proto::SigningInput {
+        chain_id: "chain-bnb".into(),
+        account_number: 12,
+        sequence: 35,
+        source: 1,
+        order_oneof: MessageEnum::trade_order(expected_trade_order),
+        ..Proto::SigningInput::default()
+    };
+    assert_eq!(signing_input, expected_signing_input);
+
+    let mut signer = AnySignerHelper::default();
+    let signing_output = sign(&mut signer, signing_input, ACCOUNT_12_PRIVATE_KEY);
+    assert_eq!(
+        signing_output.signature.to_hex(),
+        "9123cb6906bb20aeb753f4a121d4d88ff0e9750ba75b0c4e10d76caee1e7d2481290fa3b9887a6225d6997f5f939ef834ea61d596a314237c48e560da9e17b5a"
+    );
+}
+
+#[test]
+fn test_binance_wallet_connect_bnb_sign_cancel_trade_order() {
+    let mut parser = WalletConnectRequestHelper::default();
+    let signing_input = parse_bnb_sign_request(&mut parser, WC_BNB_SIGN_CANCEL_TRADE_ORDER);
+    assert!(matches!(
+        signing_input.order_oneof,
+        MessageEnum::cancel_trade_order(_)
+    ));
+
+    let mut signer = AnySignerHelper::default();
+    let signing_output = sign(&mut signer, signing_input, ACCOUNT_12_PRIVATE_KEY);
+    assert_eq!(
+        signing_output.signature.to_hex(),
+        "3df6603426b991f7040bce22ce0137c12137df01e1d4d425cf3d9104103aec6335ac05c825e08ba26b9f72aa4cc45aa75cacfb6082df86b00692fef9701eb0f5"
+    );
+}
+
+#[test]
+fn test_binance_wallet_connect_bnb_sign_htlt_order() {
+    let mut parser = WalletConnectRequestHelper::default();
+    let signing_input = parse_bnb_sign_request(&mut parser, WC_BNB_SIGN_HTLT_ORDER);
+    assert!(matches!(
+        signing_input.order_oneof,
+        MessageEnum::htlt_order(_)
+    ));
+
+    let mut signer = AnySignerHelper::default();
+    let signing_output = sign(&mut signer, signing_input, ACCOUNT_15_PRIVATE_KEY);
+    assert_eq!(
+        signing_output.signature.to_hex(),
+        "51439de2da19fe9fd22137c903cfc5dc87553bf05dca0bb202c0e07c47f9b51269efa27243eb7b55888f5384a84ac1eac6d325c830d1be0ed042838e2dc0f6a9"
+    );
+}
+
+#[test]
+fn test_binance_wallet_connect_bnb_sign_side_delegate_order() {
+    let mut parser = WalletConnectRequestHelper::default();
+    let signing_input = parse_bnb_sign_request(&mut parser, WC_BNB_SIGN_SIDE_DELEGATE_ORDER);
+    assert!(matches!(
+        signing_input.order_oneof,
+        MessageEnum::side_delegate_order(_)
+    ));
+
+    let mut signer = AnySignerHelper::default();
+    let signing_output = sign(&mut signer, signing_input, ACCOUNT_15_PRIVATE_KEY);
+    assert_eq!(
+        signing_output.signature.to_hex(),
+        "39302c9975fb2a09ac2b6b6fb1d3b9fb5b4c03630d3d7a7da42b1c6736d6127142a3fcdca0b70a3d065da8d4f4df8b5d9d8f46aeb3627a7d7aa901fe186af34c"
+    );
+}
@@ -45,3 +45,82 @@ fn test_bitcoin_address_get_data() {
         "314d725a4e474e376d66575a695a4e517474727a486a667737326a6e4a43324a4e78",
     );
 }
+
+#[test]
+fn test_litecoin_address_is_valid() {
+    test_address_valid(CoinType::Litecoin, "LTq7ZzNBwnyrsysS4znUePsxmveSaWLyGF");
+    test_address_valid(
+        CoinType::Litecoin,
+        "ltc1qten42eesehw0ktddcp0fws7d3ycsqez3dzhsum",
+    );
+}
+
+#[test]
+fn test_litecoin_address_invalid() {
+    // Bitcoin addresses are not valid Litecoin addresses.
+    test_address_invalid(CoinType::Litecoin, "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X");
+    test_address_invalid(
+        CoinType::Litecoin,
+        "bc1qten42eesehw0ktddcp0fws7d3ycsqez3f7d5yt",
+    );
+}
+
+#[test]
+fn test_dogecoin_address_is_valid() {
+    test_address_valid(CoinType::Dogecoin, "DDkFr311AYe6ABMsdSnjv8yoSr1Tppokp8");
+}
+
+#[test]
+fn test_dogecoin_address_invalid() {
+    test_address_invalid(CoinType::Dogecoin, "LTq7ZzNBwnyrsysS4znUePsxmveSaWLyGF");
+    // Dogecoin does not support Segwit addresses.
+    test_address_invalid(
+        CoinType::Dogecoin,
+        "bc1qten42eesehw0ktddcp0fws7d3ycsqez3f7d5yt",
+    );
+}
+
+#[test]
+fn test_bitcoin_cash_address_normalization() {
+    test_address_normalization(
+        CoinType::BitcoinCash,
+        "qp0xw4t8xrxae7ed4hq9a96rekynzqry2ydzeh0jgs",
+        "bitcoincash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2ydzeh0jgs",
+    );
+    test_address_normalization(
+        CoinType::BitcoinCash,
+        "BITCOINCASH:QP0XW4T8XRXAE7ED4HQ9A96REKYNZQRY2YDZEH0JGS",
+        "bitcoincash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2ydzeh0jgs",
+    );
+}
+
+#[test]
+fn test_bitcoin_cash_address_is_valid() {
+    test_address_valid(
+        CoinType::BitcoinCash,
+        "bitcoincash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2ydzeh0jgs",
+    );
+    // Legacy addresses are still supported.
+    test_address_valid(CoinType::BitcoinCash, "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X");
+    test_address_valid(
+        CoinType::eCash,
+        "ecash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2y50du5gw8",
+    );
+}
+
+#[test]
+fn test_bitcoin_cash_address_invalid() {
+    test_address_invalid(
+        CoinType::BitcoinCash,
+        "ecash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2y50du5gw8",
+    );
+    // Bitcoin Cash does not support Segwit addresses.
+    test_address_invalid(
+        CoinType::BitcoinCash,
+        "bc1qten42eesehw0ktddcp0fws7d3ycsqez3f7d5yt",
+    );
+    test_address_invalid(
+        CoinType::Bitcoin,
+        "bitcoincash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2ydzeh0jgs",
+    );
+}
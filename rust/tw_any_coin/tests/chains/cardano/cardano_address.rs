@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_get_data, test_address_invalid, test_address_normalization, test_address_valid,
+};
+use tw_coin_registry::coin_type::CoinType;
+
+#[test]
+fn test_cardano_address_normalization() {
+    test_address_normalization(
+        CoinType::Cardano,
+        "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23",
+        "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23",
+    );
+    test_address_normalization(
+        CoinType::Cardano,
+        "Ae2tdPwUPEZ18ZjTLnLVr9CEvUEUX4eW1LBHbxxxJgxdAYHrDeSCSbCxrvx",
+        "Ae2tdPwUPEZ18ZjTLnLVr9CEvUEUX4eW1LBHbxxxJgxdAYHrDeSCSbCxrvx",
+    );
+}
+
+#[test]
+fn test_cardano_address_is_valid() {
+    // Base addresses.
+    test_address_valid(
+        CoinType::Cardano,
+        "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23",
+    );
+    test_address_valid(
+        CoinType::Cardano,
+        "addr1q92cmkgzv9h4e5q7mnrzsuxtgayvg4qr7y3gyx97ukmz3dfx7r9fu73vqn25377ke6r0xk97zw07dqr9y5myxlgadl2s0dgke5",
+    );
+    // Enterprise address.
+    test_address_valid(
+        CoinType::Cardano,
+        "addr1vyuca7esanpgs4ke0um3ft6f4yaeuz3ftpfqx9nxpct2uyqu7dvlp",
+    );
+    // Reward address.
+    test_address_valid(
+        CoinType::Cardano,
+        "stake1uy9ggsc9qls4pu9qvyyacwnmr9tt0gzcdt5s0zj4au8qkqc65geks",
+    );
+    // Byron addresses.
+    test_address_valid(
+        CoinType::Cardano,
+        "Ae2tdPwUPEZ6RUCnjGHFqi59k5WZLiv3HoCCNGCW8SYc5H9srdTzn1bec4W",
+    );
+    test_address_valid(
+        CoinType::Cardano,
+        "DdzFFzCqrht7HGoJ87gznLktJGywK1LbAJT2sbd4txmgS7FcYLMQFhawb18ojS9Hx55mrbsHPr7PTraKh14TSQbGBPJHbDZ9QVh6Z6Di",
+    );
+}
+
+#[test]
+fn test_cardano_address_invalid() {
+    // Invalid network.
+    test_address_invalid(
+        CoinType::Cardano,
+        "addr1sna05l45z33zpkm8z44q8f0h57wxvm0c86e34wlmua7gtcrdgrdrzy8ny3walyfjanhe33nsyuh088qr5gepqaen6jsa9r94xvvd7fh6jc3e6x",
+    );
+    // Invalid prefix.
+    test_address_invalid(
+        CoinType::Cardano,
+        "prefix1qxxe304qg9py8hyyqu8evfj4wln7dnms943wsugpdzzsxnkvvjljtzuwxvx0pnwelkcruy95ujkq3aw6rl0vvg32x35q3hm7lv",
+    );
+    // Invalid length.
+    test_address_invalid(
+        CoinType::Cardano,
+        "addr1qxxe304qg9py8hyyqu8evfj4wln7dnms943wsugpdzzsxnkvvjljtzuwxvx0pnwelkcruy95ujkq3aw6rl0vvg32xsmpqws7",
+    );
+    // Invalid Byron checksum.
+    test_address_invalid(
+        CoinType::Cardano,
+        "Ae2tdPwUPEZ18ZjTLnLVr9CEvUEUX4eW1LBHbxxxJgxdAYHrDeSCSbCxrvm",
+    );
+    test_address_invalid(CoinType::Cardano, "73Fig6QU8N");
+    test_address_invalid(CoinType::Cardano, "hasoiusaodiuhsaijnnsajnsaiussai");
+    test_address_invalid(CoinType::Cardano, "");
+    test_address_invalid(
+        CoinType::Cardano,
+        "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk2",
+    );
+}
+
+#[test]
+fn test_cardano_address_get_data() {
+    test_address_get_data(
+        CoinType::Cardano,
+        "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23",
+        "01df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b",
+    );
+    test_address_get_data(
+        CoinType::Cardano,
+        "addr1vyuca7esanpgs4ke0um3ft6f4yaeuz3ftpfqx9nxpct2uyqu7dvlp",
+        "61398efb30ecc28856d97f3714af49a93b9e0a2958520316660e16ae10",
+    );
+}
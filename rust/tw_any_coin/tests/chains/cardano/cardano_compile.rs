@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::cardano::utxo;
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Cardano::Proto;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const FROM_ADDRESS: &str = "addr1v8mv75d2evhr4kt048cx7m3f97x363ajadha8xv8dp96nuggpv8rn";
+const TX_HASH: &str = "d87f6e99c8d3a0fb22b1ea4de477f5a6d1f0e419450c2a194304371cada0ebb9";
+const POLICY_ID: &str = "122d15a15dc753d2b3ca9ee46c1c6ca41dda38d735942d9d259c785b";
+const PUBLIC_KEY: &str = "17c55d712152ccabf28215fe2d008d615f94796e098a97f1aa43d986ac3cb946";
+const SIGNATURE: &str = "1096ddcfb2ad21a4c0d861ef3fabe18841e8de88105b0d8e36430d7992c588634ead4100c32b2800b31b65e014d54a8238bdda63118d829bf0bcf1b631e86f0e";
+
+fn token(asset_name: &str, asset_name_hex: &str, amount: &str) -> Proto::TokenAmount<'static> {
+    Proto::TokenAmount {
+        policy_id: POLICY_ID.into(),
+        asset_name: asset_name.to_string().into(),
+        asset_name_hex: asset_name_hex.to_string().into(),
+        amount: amount.decode_hex().unwrap().into(),
+    }
+}
+
+fn compile_input() -> Proto::SigningInput<'static> {
+    let mut utxo2 = utxo(TX_HASH, 1, FROM_ADDRESS, 4_040_957);
+    utxo2.token_amount = vec![
+        token("", "5454546f6b656e2d31", "2dc6c0"),
+        token("TTToken-2", "", "2dc6c0"),
+        token("", "5454546f6b656e2d33", "4c4b40"),
+    ];
+
+    Proto::SigningInput {
+        utxos: vec![utxo(TX_HASH, 0, FROM_ADDRESS, 1_000_000), utxo2],
+        transfer_message: Some(Proto::Transfer {
+            to_address: "addr1qxxe304qg9py8hyyqu8evfj4wln7dnms943wsugpdzzsxnkvvjljtzuwxvx0pnwelkcruy95ujkq3aw6rl0vvg32x35qc92xkq".into(),
+            change_address: FROM_ADDRESS.into(),
+            amount: 1_850_000,
+            ..Proto::Transfer::default()
+        }),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_cardano_compile() {
+    let input = compile_input();
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Cardano, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "3e5a7c1d1afbc7e3ca783daba1beb12010fc4ecc748722558697509212c9f186"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = SIGNATURE.decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Cardano, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a40082825820d87f6e99c8d3a0fb22b1ea4de477f5a6d1f0e419450c2a194304371cada0ebb901825820d87f6e99c8d3a0fb22b1ea4de477f5a6d1f0e419450c2a194304371cada0ebb9000182825839018d98bea0414243dc84070f96265577e7e6cf702d62e871016885034ecc64bf258b8e330cf0cdd9fdb03e10b4e4ac08f5da1fdec6222a34681a001c3a9082581d61f6cf51aacb2e3ad96fa9f06f6e292f8d1d47b2eb6fd39987684ba9f1821a002e0feea1581c122d15a15dc753d2b3ca9ee46c1c6ca41dda38d735942d9d259c785ba3495454546f6b656e2d311a002dc6c0495454546f6b656e2d321a002dc6c0495454546f6b656e2d331a004c4b40021a0002a0bf0300a1008182582017c55d712152ccabf28215fe2d008d615f94796e098a97f1aa43d986ac3cb94658401096ddcfb2ad21a4c0d861ef3fabe18841e8de88105b0d8e36430d7992c588634ead4100c32b2800b31b65e014d54a8238bdda63118d829bf0bcf1b631e86f0ef6");
+    assert_eq!(output.tx_id.to_hex(), preimage_output.data_hash.to_hex());
+}
+
+#[test]
+fn test_cardano_compile_error() {
+    let input = compile_input();
+    let signature = SIGNATURE.decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    // Inconsistent signatures and public keys.
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Cardano,
+        &input,
+        vec![signature.clone(), signature],
+        vec![public_key],
+    );
+    assert_eq!(output.error, SigningError::Error_no_support_n2n);
+    assert!(output.encoded.is_empty());
+
+    // No signatures.
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Cardano, &input, Vec::default(), Vec::default());
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+    assert!(output.encoded.is_empty());
+}
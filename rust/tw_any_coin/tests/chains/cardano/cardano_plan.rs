@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::cardano::{sample_input, TO_ADDRESS};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::ToHex;
+use tw_proto::serialize;
+use tw_proto::Cardano::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+#[test]
+fn test_cardano_plan_transfer() {
+    let input = sample_input(7_000_000);
+
+    let mut planner = AnySignerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Cardano, input);
+
+    assert_eq!(plan.error, SigningError::OK);
+    assert_eq!(plan.amount, 7_000_000);
+    assert_eq!(plan.available_amount, 8_000_000);
+    assert_eq!(plan.fee, 170_196);
+    assert_eq!(plan.change, 829_804);
+    assert_eq!(plan.utxos.len(), 2);
+    assert_eq!(plan.utxos[0].amount, 6_500_000);
+    assert_eq!(plan.utxos[1].amount, 1_500_000);
+
+    assert_eq!(serialize(&plan).unwrap().to_hex(), "0880a4e80310c09fab0318d4b10a20ecd2324292010a220a20554f2fd942a23d06835d26bbd78f0106fa94c8a551114a0bef81927f66467af01267616464723171383034336d356865656179646e76746d6d6b7975686536717635686176766873663064323671336a7967737370786c796670796b3679716b77307968747976747230666c656b6a3834753634617a38326375666d716e36357a6473796c7a6b323318a0dd8c034293010a240a20f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e76710011267616464723171383034336d356865656179646e76746d6d6b7975686536717635686176766873663064323671336a7967737370786c796670796b3679716b77307968747976747230666c656b6a3834753634617a38326375666d716e36357a6473796c7a6b323318e0c65b");
+}
+
+#[test]
+fn test_cardano_plan_small_amounts() {
+    // (amount, use_max_amount, utxos, available_amount, output amount, fee)
+    let test_cases = [
+        (1, false, 1, 6_500_000, 1, 168_435),
+        (2_000_000, false, 1, 6_500_000, 2_000_000, 168_611),
+        (2_000_000, true, 2, 8_000_000, 7_832_667, 167_333),
+    ];
+
+    for (amount, use_max_amount, utxos, available_amount, output_amount, fee) in test_cases {
+        let mut input = sample_input(amount);
+        input.transfer_message.as_mut().unwrap().use_max_amount = use_max_amount;
+
+        let mut planner = AnySignerHelper::<Proto::TransactionPlan>::default();
+        let plan = planner.plan(CoinType::Cardano, input);
+
+        assert_eq!(plan.error, SigningError::OK);
+        assert_eq!(plan.utxos.len(), utxos);
+        assert_eq!(plan.available_amount, available_amount);
+        assert_eq!(plan.amount, output_amount);
+        assert_eq!(plan.fee, fee);
+        assert_eq!(plan.amount + plan.change + plan.fee, plan.available_amount);
+    }
+}
+
+#[test]
+fn test_cardano_plan_force_fee() {
+    // (force_fee, use_max_amount, output amount, fee)
+    let test_cases = [
+        (170_147, false, 6_500_000, 170_147),
+        (100, false, 6_500_000, 100),
+        (1_200_000, false, 6_500_000, 1_200_000),
+        // The fee is capped by the available amount.
+        (3_000_000, false, 6_500_000, 1_500_000),
+        (160_000, true, 7_840_000, 160_000),
+    ];
+
+    for (force_fee, use_max_amount, output_amount, fee) in test_cases {
+        let mut input = sample_input(6_500_000);
+        let transfer = input.transfer_message.as_mut().unwrap();
+        transfer.force_fee = force_fee;
+        transfer.use_max_amount = use_max_amount;
+
+        let mut planner = AnySignerHelper::<Proto::TransactionPlan>::default();
+        let plan = planner.plan(CoinType::Cardano, input);
+
+        assert_eq!(plan.error, SigningError::OK);
+        assert_eq!(plan.available_amount, 8_000_000);
+        assert_eq!(plan.amount, output_amount);
+        assert_eq!(plan.fee, fee);
+        assert_eq!(plan.amount + plan.change + plan.fee, plan.available_amount);
+    }
+}
+
+#[test]
+fn test_cardano_plan_missing_private_key() {
+    let input = Proto::SigningInput {
+        private_key: Vec::default(),
+        ..sample_input(7_000_000)
+    };
+
+    let mut planner = AnySignerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Cardano, input);
+
+    assert_eq!(plan.error, SigningError::OK);
+    assert_eq!(plan.utxos.len(), 2);
+    assert_eq!(plan.fee, 170_196);
+    assert_eq!(plan.change, 829_804);
+}
+
+#[test]
+fn test_cardano_plan_extra_output() {
+    let mut input = Proto::SigningInput {
+        private_key: Vec::default(),
+        ..sample_input(2_000_000)
+    };
+    input.extra_outputs.push(Proto::TxOutput {
+        address: TO_ADDRESS.into(),
+        amount: 2_000_000,
+        ..Proto::TxOutput::default()
+    });
+
+    let mut planner = AnySignerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Cardano, input);
+
+    assert_eq!(plan.error, SigningError::OK);
+    assert_eq!(plan.utxos.len(), 1);
+    assert_eq!(plan.available_amount, 6_500_000);
+    assert_eq!(plan.amount, 2_000_000);
+    assert_eq!(plan.fee, 171_474);
+    assert_eq!(plan.change, 2_328_526);
+    assert_eq!(plan.extra_outputs.len(), 1);
+    assert_eq!(plan.extra_outputs[0].amount, 2_000_000);
+}
+
+#[test]
+fn test_cardano_plan_error() {
+    let no_utxos = Proto::SigningInput {
+        utxos: Vec::default(),
+        ..sample_input(2_000_000)
+    };
+    let mut planner = AnySignerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Cardano, no_utxos);
+    assert_eq!(plan.error, SigningError::Error_missing_input_utxos);
+
+    let mut low_balance = sample_input(9_000_000);
+    low_balance.utxos.truncate(1);
+    let mut planner = AnySignerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Cardano, low_balance);
+    assert_eq!(plan.error, SigningError::Error_low_balance);
+}
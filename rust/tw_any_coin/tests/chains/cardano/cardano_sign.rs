@@ -0,0 +1,361 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::cardano::{sample_input, utxo, OWN_ADDRESS_1, PRIVATE_KEY_1, STAKING_ADDRESS_1};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Cardano::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+const POOL_ID_NUFI: &str = "7d7ac07a2f2a25b7a4db868a40720621c4939cf6aefbb9a11464f1a6";
+
+/// Sends all the funds of [`OWN_ADDRESS_1`] back to itself, used for staking operations.
+fn staking_input(utxos: Vec<Proto::TxInput<'static>>, ttl: u64) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        utxos,
+        private_key: vec![PRIVATE_KEY_1.decode_hex().unwrap().into()],
+        transfer_message: Some(Proto::Transfer {
+            to_address: OWN_ADDRESS_1.into(),
+            change_address: OWN_ADDRESS_1.into(),
+            // Not relevant as the max amount is used.
+            amount: 5_000_000,
+            use_max_amount: true,
+            ..Proto::Transfer::default()
+        }),
+        ttl,
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_cardano_sign_transfer() {
+    let input = sample_input(7_000_000);
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a40082825820554f2fd942a23d06835d26bbd78f0106fa94c8a551114a0bef81927f66467af000825820f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e76701018282583901558dd902616f5cd01edcc62870cb4748c45403f1228218bee5b628b526f0ca9e7a2c04d548fbd6ce86f358be139fe680652536437d1d6fd51a006acfc082583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a000ca96c021a000298d4031a032dcd55a100818258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df29058407cf591599852b5f5e007fdc241062405c47e519266c0d884b0767c1d4f5eacce00db035998e53ed10ca4ba5ce4aac8693798089717ce6cf4415f345cc764200ef6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "9b5b15e133cd73ccaa85307d2986aebc846505118a2eb4e6111e6b4b67d1f389"
+    );
+}
+
+#[test]
+fn test_cardano_sign_transfer_max_amount() {
+    let mut input = sample_input(7_000_000);
+    input.transfer_message.as_mut().unwrap().use_max_amount = true;
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a40082825820f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e76701825820554f2fd942a23d06835d26bbd78f0106fa94c8a551114a0bef81927f66467af000018182583901558dd902616f5cd01edcc62870cb4748c45403f1228218bee5b628b526f0ca9e7a2c04d548fbd6ce86f358be139fe680652536437d1d6fd51a0077845b021a00028da5031a032dcd55a100818258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df29058403e64473e08adc863953c0e9f820b658dda0b8a423d6172fdccff73fcd5559956c9df8ed93ff67405331d368a0c11fd18c69781046384946582e1555e9e8ec70bf6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "ca0f1e12f20c95011da7d686d206a1eb98df94accd74c4df4ef403c5ce836057"
+    );
+}
+
+#[test]
+fn test_cardano_sign_transfer_with_plan() {
+    let mut input = sample_input(6_000_000);
+    // Use only one input.
+    input.plan = Some(Proto::TransactionPlan {
+        available_amount: 6_500_000,
+        amount: 6_000_000,
+        fee: 165_489,
+        change: 17_191_988,
+        utxos: vec![input.utxos[0].clone()],
+        ..Proto::TransactionPlan::default()
+    });
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a40081825820f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e76701018282583901558dd902616f5cd01edcc62870cb4748c45403f1228218bee5b628b526f0ca9e7a2c04d548fbd6ce86f358be139fe680652536437d1d6fd51a005b8d8082583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a01065434021a00028671031a032dcd55a100818258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df29058408311a058035d75545a47b844fea401aa9c23e99fe7bc8136b554396eef135d4cd93062c5df38e613185c21bb1c98b881d1e0fd1024d3539b163c8e14d1a6e40df6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "e319c0bfc99cdb79d64f00b7e8fb8bfbf29fa70554c84f101e92b7dfed172448"
+    );
+}
+
+#[test]
+fn test_cardano_sign_transfer_to_legacy() {
+    let mut input = sample_input(7_000_000);
+    input.transfer_message.as_mut().unwrap().to_address = "DdzFFzCqrhssmYoG5Eca1bKZFdGS8d6iag1mU4wbLeYcSPVvBNF2wRG8yhjzQqErbg63N6KJA4DHqha113tjKDpGEwS5x1dT2KfLSbSJ".into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a40082825820554f2fd942a23d06835d26bbd78f0106fa94c8a551114a0bef81927f66467af000825820f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e76701018282584c82d818584283581c6aebd89cf88271c3ee76339930d8956b03f018b2f4871522f88eb8f9a101581e581c692a37dae3bc63dfc3e1463f12011f26655ab1d1e0f4ed4b8fc63708001ad8a9555b1a006acfc082583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a000ca627021a00029c19031a032dcd55a100818258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df2905840db9becdc733f4c08c0e7abc29b5cc6469f9339d32f565df8bf77455439ae1f949facc9b831754e74d3fbb42e99647eedd6c28de1461d18c315485f5d24b5b90af6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "f9b713e9987ec1377ac223f50d63c7a5e155915302de43f40d7b2627accabf69"
+    );
+}
+
+#[test]
+fn test_cardano_sign_transfer_from_legacy() {
+    let from_address = "Ae2tdPwUPEZ6vkqxSjJxaQYmDxHf5DTnxtZ67pFLJGTb9LTnCGkDP6ca3f8";
+    let input = Proto::SigningInput {
+        utxos: vec![
+            utxo(
+                "8316e5007d61fb90652cabb41141972a38b5bc60954d602cf843476aa3f67f63",
+                0,
+                from_address,
+                2_500_000,
+            ),
+            utxo(
+                "e29392c59c903fefb905730587d22cae8bda30bd8d9aeec3eca082ae77675946",
+                0,
+                from_address,
+                1_700_000,
+            ),
+        ],
+        private_key: vec!["98f266d1aac660179bc2f456033941238ee6b2beb8ed0f9f34c9902816781f5a9903d1d395d6ab887b65ea5e344ef09b449507c21a75f0ce8c59d0ed1c6764eba7f484aa383806735c46fd769c679ee41f8952952036a6e2338ada940b8a91f4e890ca4eb6bec44bf751b5a843174534af64d6ad1f44e0613db78a7018781f5aa151d2997f52059466b715d8eefab30a78b874ae6ef4931fa58bb21ef8ce2423d46f19d0fbf75afb0b9a24e31d533f4fd74cee3b56e162568e8defe37123afc4".decode_hex().unwrap().into()],
+        transfer_message: Some(Proto::Transfer {
+            to_address: "addr1q90uh2eawrdc9vaemftgd50l28yrh9lqxtjjh4z6dnn0u7ggasexxdyyk9f05atygnjlccsjsggtc87hhqjna32fpv5qeq96ls".into(),
+            change_address: "addr1qx55ymlqemndq8gluv40v58pu76a2tp4mzjnyx8n6zrp2vtzrs43a0057y0edkn8lh9su8vh5lnhs4npv6l9tuvncv8swc7t08".into(),
+            amount: 3_000_000,
+            ..Proto::Transfer::default()
+        }),
+        ttl: 190_000_000,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a400828258208316e5007d61fb90652cabb41141972a38b5bc60954d602cf843476aa3f67f6300825820e29392c59c903fefb905730587d22cae8bda30bd8d9aeec3eca082ae77675946000182825839015fcbab3d70db82b3b9da5686d1ff51c83b97e032e52bd45a6ce6fe7908ec32633484b152fa756444e5fc62128210bc1fd7b8253ec5490b281a002dc6c082583901a9426fe0cee6d01d1fe32af650e1e7b5d52c35d8a53218f3d0861531621c2b1ebdf4f11f96da67fdcb0e1d97a7e778566166be55f193c30f1a000f9ec1021a0002b0bf031a0b532b80a20081825820d163c8c4f0be7c22cd3a1152abb013c855ea614b92201497a568c5d93ceeb41e58406a23ab9267867fbf021c1cb2232bc83d2cdd663d651d22d59b6cddbca5cb106d4db99da50672f69a2309ca8a329a3f9576438afe4538b013de4591a6dfcd4d090281845820d163c8c4f0be7c22cd3a1152abb013c855ea614b92201497a568c5d93ceeb41e58406a23ab9267867fbf021c1cb2232bc83d2cdd663d651d22d59b6cddbca5cb106d4db99da50672f69a2309ca8a329a3f9576438afe4538b013de4591a6dfcd4d095820a7f484aa383806735c46fd769c679ee41f8952952036a6e2338ada940b8a91f441a0f6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "0203ce2c91f59f169a26e9ef91254639d2b7911afac9c7c0ae64539f88ba46a5"
+    );
+}
+
+/// Successfully broadcasted:
+/// https://cardanoscan.io/transaction/87ca43a36b09c0b140f0ef2b71fbdcfcf1fdc88f7aa378b861e8eed3e8974628
+#[test]
+fn test_cardano_sign_nft_transfer() {
+    let from_address = "addr1qy5eme9r6frr0m6q2qpncg282jtrhq5lg09uxy2j0545hj8rv7v2ntdxuv6p4s3eq4lqzg39lewgvt6fk5kmpa0zppesufzjud";
+    let nft = Proto::TokenAmount {
+        policy_id: "219820e6cb04316f41a337fea356480f412e7acc147d28f175f21b5e".into(),
+        asset_name: "coolcatssociety4567".into(),
+        amount: vec![1].into(),
+        ..Proto::TokenAmount::default()
+    };
+    // 1.20249 ADA locked by the NFT.
+    let nft_input_amount = 1_202_490;
+
+    let mut nft_utxo = utxo(
+        "aba499ec2f23529e70bb256ceaffcc6274a882cf02f29e5670c75ee980d7c2b8",
+        0,
+        from_address,
+        nft_input_amount,
+    );
+    nft_utxo.token_amount.push(nft.clone());
+
+    let input = Proto::SigningInput {
+        utxos: vec![
+            nft_utxo,
+            // Additional UTXOs to pay the fee.
+            utxo(
+                "ee414d635b3bc67831907354d274a31174664777c57c21ae923b9459e5644840",
+                0,
+                from_address,
+                1_000_000,
+            ),
+            utxo(
+                "6a7221dcc28353ed69b733391ffeb984a34c1e72293af111d59f9ddfa8639167",
+                0,
+                from_address,
+                2_000_000,
+            ),
+        ],
+        private_key: vec!["d09831a668db6b36ffb747600cb1cd3e3d34f36e1e6feefc11b5f988719b7557a7029ab80d3e6fe4180ad07a59ddf742ea9730f3c4145df6365fa4ae2ee49c3392e19444caf461567727b7fefec40a3763bdb6ce5e0e8c05f5e340355a8fef4528dfe7502cfbda49e38f5a0021962d52dc3dee82834a23abb6750981799b75577d1ed9af9853707f0ef74264274e71b2f12e86e3c91314b6efa75ef750d9711b84cedd742ab873ef2f9566ad20b3fc702232c6d2f5d83ff425019234037d1e58".decode_hex().unwrap().into()],
+        transfer_message: Some(Proto::Transfer {
+            to_address: "addr1qy9wjfn6nd8kak6dd8z53u7t5wt9f4lx0umll40px5hnq05avwcsq5r3ytdp36wttzv4558jaq8lvhgqhe3y8nuf5xrquju7z4".into(),
+            change_address: from_address.into(),
+            amount: nft_input_amount,
+            token_amount: Some(Proto::TokenBundle { token: vec![nft] }),
+            ..Proto::Transfer::default()
+        }),
+        ttl: 89_130_965,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a400838258206a7221dcc28353ed69b733391ffeb984a34c1e72293af111d59f9ddfa863916700825820aba499ec2f23529e70bb256ceaffcc6274a882cf02f29e5670c75ee980d7c2b800825820ee414d635b3bc67831907354d274a31174664777c57c21ae923b9459e5644840000182825839010ae9267a9b4f6edb4d69c548f3cba39654d7e67f37ffd5e1352f303e9d63b100507122da18e9cb58995a50f2e80ff65d00be6243cf89a186821a0012593aa1581c219820e6cb04316f41a337fea356480f412e7acc147d28f175f21b5ea153636f6f6c63617473736f6369657479343536370182583901299de4a3d24637ef4050033c214754963b829f43cbc311527d2b4bc8e36798a9ada6e3341ac239057e012225fe5c862f49b52db0f5e208731a002b1525021a0002b19b031a055007d5a1008182582088bd26e8656fa7dead846c3373588f0192da5bfb90bf5d3fb877decfb3b3fd085840da8656aca0dacc57d4c2d957fc7dff03908f6dcf60c48f1e40b3006e2fd0cfacfa4c24fa02e35a310572526586d4ce0d30bf660ba274c8efd507848cbe177d09f6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "87ca43a36b09c0b140f0ef2b71fbdcfcf1fdc88f7aa378b861e8eed3e8974628"
+    );
+}
+
+#[test]
+fn test_cardano_sign_register_staking_key() {
+    let mut input = staking_input(
+        vec![utxo(
+            "cba84549f07f2128410c0a22731f2c57f2a617746e8edc61b295cd8792638dca",
+            1,
+            OWN_ADDRESS_1,
+            10_000_000,
+        )],
+        69_986_091,
+    );
+    // 2 ADA deposit.
+    input.register_staking_key = Some(Proto::RegisterStakingKey {
+        staking_address: STAKING_ADDRESS_1.into(),
+        deposit_amount: 2_000_000,
+    });
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a50081825820cba84549f07f2128410c0a22731f2c57f2a617746e8edc61b295cd8792638dca01018182583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a007772fa021a00029f06031a042be72b048182008200581cdf22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba100828258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df2905840d08ed71da87d0928090edd9e226496ab109f2eee7926ac2ce51e7abe89a4f513c4afe2b85b71595e862e7f6fc992d14d2416a6e53a1961da7d26d3cf3f823400825820e554163344aafc2bbefe778a6953ddce0583c2f8e0a0686929c020ca33e06932584079ed55400cebc70c56ca87ba09009dfc298c64768f90a9139bf2e7f134250927c614ee846253fac33e652f1b50373d349fdfe13c207968c2a10991824fe2a10ef6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "6a206fe4df76e12499b4fd9722f33429f4d93f8a996f9f523fa6c02a8301386b"
+    );
+}
+
+#[test]
+fn test_cardano_sign_deregister_staking_key() {
+    let mut input = staking_input(
+        vec![utxo(
+            "cba84549f07f2128410c0a22731f2c57f2a617746e8edc61b295cd8792638dca",
+            1,
+            OWN_ADDRESS_1,
+            10_000_000,
+        )],
+        69_986_091,
+    );
+    // Get back the 2 ADA deposit.
+    input.deregister_staking_key = Some(Proto::DeregisterStakingKey {
+        staking_address: STAKING_ADDRESS_1.into(),
+        undeposit_amount: 2_000_000,
+    });
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a50081825820cba84549f07f2128410c0a22731f2c57f2a617746e8edc61b295cd8792638dca01018182583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a00b47bfa021a00029f06031a042be72b048182018200581cdf22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba100828258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df290584056619a7d6192b6f68c31a43e927c893161fd994d5c1bcc16f3710cf5e5e652e01f118d55f0110e9de34edc050d509748bea637db5c34f4fe342ae262ccb5520d825820e554163344aafc2bbefe778a6953ddce0583c2f8e0a0686929c020ca33e069325840d23680fdd8aa63e10efccc550eb726743b653008952f9d731d076d1df8106b0401823ebb195127b211389f1bc2c3f6ededbcec04bc8f0de93607a2409421e006f6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "1caae2456e5471cc77e73410da475fb0a23874c18c1ea55f9267c59767caef0a"
+    );
+}
+
+#[test]
+fn test_cardano_sign_register_and_delegate() {
+    let tx_hash = "9b06de86b253549b99f6a050b61217d8824085ca5ed4eb107a5e7cce4f93802e";
+    let mut input = staking_input(
+        vec![
+            utxo(tx_hash, 0, OWN_ADDRESS_1, 4_000_000),
+            utxo(tx_hash, 1, OWN_ADDRESS_1, 26_651_312),
+        ],
+        69_885_081,
+    );
+    input.register_staking_key = Some(Proto::RegisterStakingKey {
+        staking_address: STAKING_ADDRESS_1.into(),
+        deposit_amount: 2_000_000,
+    });
+    input.delegate = Some(Proto::Delegate {
+        staking_address: STAKING_ADDRESS_1.into(),
+        pool_id: POOL_ID_NUFI.decode_hex().unwrap().into(),
+        deposit_amount: 0,
+    });
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a500828258209b06de86b253549b99f6a050b61217d8824085ca5ed4eb107a5e7cce4f93802e008258209b06de86b253549b99f6a050b61217d8824085ca5ed4eb107a5e7cce4f93802e01018182583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a01b27ef5021a0002b03b031a042a5c99048282008200581cdf22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b83028200581cdf22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b581c7d7ac07a2f2a25b7a4db868a40720621c4939cf6aefbb9a11464f1a6a100828258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df2905840677c901704be027d9a1734e8aa06f0700009476fa252baaae0de280331746a320a61456d842d948ea5c0e204fc36f3bd04c88ca7ee3d657d5a38014243c37c07825820e554163344aafc2bbefe778a6953ddce0583c2f8e0a0686929c020ca33e0693258401fa21bdc62b85ca217bf08cbacdeba2fadaf33dc09ee3af9cc25b40f24822a1a42cfbc03585cc31a370ef75aaec4d25db6edcf329e40a4e725ec8718c94f220af6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "96a781fd6481b6a7fd3926da110265e8c44b53947b81daa84da5b148825d02aa"
+    );
+}
+
+#[test]
+fn test_cardano_sign_withdraw() {
+    let mut input = staking_input(
+        vec![utxo(
+            "7dfd2c579794314b1f84efc9db932a098e440ccefb874945591f1d4e85a9152a",
+            0,
+            OWN_ADDRESS_1,
+            6_305_913,
+        )],
+        71_678_326,
+    );
+    input.withdraw = Some(Proto::Withdraw {
+        staking_address: STAKING_ADDRESS_1.into(),
+        withdraw_amount: 3468,
+    });
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cardano, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), "83a500818258207dfd2c579794314b1f84efc9db932a098e440ccefb874945591f1d4e85a9152a00018182583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b1a005da6ff021a00029f06031a0445b97605a1581de1df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b190d8ca100828258206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df29058401ebaca2876fd17122404912a2558a98109cdf0f990a938d2917fa2c3b8c4e55e18a2cbabfa82fff03fa0d7ab8b88ca01ed18e42af3bfc4cda7f423a3aa30c00b825820e554163344aafc2bbefe778a6953ddce0583c2f8e0a0686929c020ca33e069325840777f04fa8f083fe562aecf78898aaaaac36e2cc6ca962f6ffb01e84a421cae1860496db79b2c5fb2879524c3d5121060b9ea1e693336230c6e5338e14c4c3303f6");
+    assert_eq!(
+        output.tx_id.to_hex(),
+        "6dcf3956232953fc25b8355fb1ded1e912b5802090fd21434d789087d6329683"
+    );
+}
+
+#[test]
+fn test_cardano_sign_error() {
+    let test_cases = [
+        // Zero amount requested.
+        (sample_input(0), SigningError::Error_zero_amount_requested),
+        // Low balance.
+        (sample_input(7_000_000_000), SigningError::Error_low_balance),
+        // No UTXOs.
+        (
+            Proto::SigningInput {
+                utxos: Vec::default(),
+                ..sample_input(7_000_000)
+            },
+            SigningError::Error_missing_input_utxos,
+        ),
+        // No private key.
+        (
+            Proto::SigningInput {
+                private_key: Vec::default(),
+                ..sample_input(7_000_000)
+            },
+            SigningError::Error_missing_private_key,
+        ),
+        // Plan with an error.
+        (
+            Proto::SigningInput {
+                plan: Some(Proto::TransactionPlan {
+                    error: SigningError::Error_invalid_memo,
+                    ..Proto::TransactionPlan::default()
+                }),
+                ..sample_input(7_000_000)
+            },
+            SigningError::Error_invalid_memo,
+        ),
+    ];
+
+    for (input, expected_error) in test_cases {
+        let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+        let output = signer.sign(CoinType::Cardano, input);
+        assert_eq!(output.error, expected_error);
+        assert!(output.encoded.is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Cardano::Proto;
+
+mod cardano_address;
+mod cardano_compile;
+mod cardano_plan;
+mod cardano_sign;
+
+const PRIVATE_KEY_1: &str = "089b68e458861be0c44bf9f7967f05cc91e51ede86dc679448a3566990b7785bd48c330875b1e0d03caaed0e67cecc42075dce1c7a13b1c49240508848ac82f603391c68824881ae3fc23a56a1a75ada3b96382db502e37564e84a5413cfaf1290dbd508e5ec71afaea98da2df1533c22ef02a26bb87b31907d0b2738fb7785b38d53aa68fc01230784c9209b2b2a2faf28491b3b1f1d221e63e704bbd0403c4154425dfbb01a2c5c042da411703603f89af89e57faae2946e2a5c18b1c5ca0e";
+const OWN_ADDRESS_1: &str = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+const STAKING_ADDRESS_1: &str = "stake1u80jysjtdzqt88jt4jx93h5lumfr67d273r4vwyasfa2pxcwxllmx";
+const TO_ADDRESS: &str = "addr1q92cmkgzv9h4e5q7mnrzsuxtgayvg4qr7y3gyx97ukmz3dfx7r9fu73vqn25377ke6r0xk97zw07dqr9y5myxlgadl2s0dgke5";
+
+fn utxo(tx_hash: &str, output_index: u64, address: &str, amount: u64) -> Proto::TxInput<'static> {
+    Proto::TxInput {
+        out_point: Some(Proto::OutPoint {
+            tx_hash: tx_hash.decode_hex().unwrap().into(),
+            output_index,
+        }),
+        address: Cow::from(address.to_string()),
+        amount,
+        ..Proto::TxInput::default()
+    }
+}
+
+/// Two UTXOs of 1.5 ADA and 6.5 ADA owned by [`OWN_ADDRESS_1`].
+fn sample_input(amount: u64) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        utxos: vec![
+            utxo(
+                "f074134aabbfb13b8aec7cf5465b1e5a862bde5cb88532cc7e64619179b3e767",
+                1,
+                OWN_ADDRESS_1,
+                1_500_000,
+            ),
+            utxo(
+                "554f2fd942a23d06835d26bbd78f0106fa94c8a551114a0bef81927f66467af0",
+                0,
+                OWN_ADDRESS_1,
+                6_500_000,
+            ),
+        ],
+        private_key: vec![PRIVATE_KEY_1.decode_hex().unwrap().into()],
+        transfer_message: Some(Proto::Transfer {
+            to_address: TO_ADDRESS.into(),
+            change_address: OWN_ADDRESS_1.into(),
+            amount,
+            ..Proto::Transfer::default()
+        }),
+        ttl: 53_333_333,
+        ..Proto::SigningInput::default()
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::{deserialize, serialize, Cosmos, TxCompiler};
+
+const PRIVATE_KEY: &str = "80e81ea269e66a0a05b11236df7919fb7fbeedba87452d667489d7403a02f005";
+const PUBLIC_KEY: &str = "0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5";
+const SIGNATURE: &str = "48267e63d9a693282657dfcec8b59c825c0991d62fdd974162a6fb92ec35c4f34da2a13dc56ce6f0657b9a0240a8cf2022b38c91c499fc21b4e2d53725f71380";
+
+#[test]
+fn test_tw_message_signer_sign_adr036() {
+    let input = Cosmos::Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: b"Hello, world!".to_vec().into(),
+        ..Cosmos::Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Cosmos as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Cosmos::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+    assert_eq!(
+        output.signature_json,
+        r#"{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"AlcobsPzfTNVe7uqAAsndErJAjqplnyudaGB0f+R+p3F"},"signature":"SCZ+Y9mmkygmV9/OyLWcglwJkdYv3ZdBYqb7kuw1xPNNoqE9xWzm8GV7mgJAqM8gIrOMkcSZ/CG04tU3JfcTgA=="}"#
+    );
+}
+
+#[test]
+fn test_tw_message_signer_pre_image_hashes_adr036() {
+    // Only the public key is required to compute the preimage.
+    let input = Cosmos::Proto::MessageSigningInput {
+        message: b"Hello, world!".to_vec().into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        ..Cosmos::Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), CoinType::Cosmos as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        String::from_utf8(output.data.to_vec()).unwrap(),
+        r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"SGVsbG8sIHdvcmxkIQ==","signer":"cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02"}}],"sequence":"0"}"#
+    );
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "661a52c835faba7e5ac56e0944927c4a9019d60e1aea047a6da63a70c538dcbf"
+    );
+}
+
+#[test]
+fn test_tw_message_signer_verify_adr036() {
+    let input = Cosmos::Proto::MessageVerifyingInput {
+        message: b"Hello, world!".to_vec().into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.decode_hex().unwrap().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let verified = unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::Cosmos as u32) };
+    assert!(verified);
+}
+
+#[test]
+fn test_tw_message_signer_verify_adr036_invalid() {
+    let input = Cosmos::Proto::MessageVerifyingInput {
+        message: b"Hello, world?".to_vec().into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.decode_hex().unwrap().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let verified = unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::Cosmos as u32) };
+    assert!(!verified);
+}
@@ -3,4 +3,5 @@
 // Copyright © 2017 Trust Wallet.
 
 mod cosmos_address;
+mod cosmos_message_sign;
 mod cosmos_sign;
@@ -74,3 +74,46 @@ fn test_greenfield_compile() {
     assert_eq!(output.serialized, expected);
     assert_eq!(output.signature.to_hex(), protected_signature);
 }
+
+#[test]
+fn test_greenfield_compile_direct() {
+    let public_key_data = PUBLIC_KEY_15560.decode_hex().unwrap();
+
+    let send_order = Proto::mod_Message::Send {
+        from_address: "0x9d1d97aDFcd324Bbd603D3872BD78e04098510b1".into(),
+        to_address: "0x280b27f3676db1C4475EE10F75D510Eb527fd155".into(),
+        amounts: vec![make_amount("BNB", "1000000000000000")],
+        ..Proto::mod_Message::Send::default()
+    };
+
+    let input = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Direct,
+        account_number: 15560,
+        cosmos_chain_id: "greenfield_5600-1".into(),
+        fee: Some(Proto::Fee {
+            amounts: vec![make_amount("BNB", "2000000000000000")],
+            gas: 200000,
+        }),
+        sequence: 2,
+        messages: vec![Proto::Message {
+            message_oneof: MessageEnum::send_coins_message(send_order),
+        }],
+        mode: Proto::BroadcastMode::ASYNC,
+        public_key: public_key_data.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Greenfield, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    // Serialized `SignDoc` with `SIGN_MODE_DIRECT` signer info.
+    assert_eq!(
+        preimage_output.data.to_hex(),
+        "0a94010a91010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e6412710a2a307839643164393761444663643332344262643630334433383732424437386530343039383531306231122a3078323830623237663336373664623143343437354545313046373544353130456235323766643135351a170a03424e4212103130303030303030303030303030303012780a570a4d0a262f636f736d6f732e63727970746f2e6574682e657468736563703235366b312e5075624b657912230a210279ef34064da10db0463c70480616ba020703ec3a45026def7bebd2082f5d6fc812040a0208011802121d0a170a03424e4212103230303030303030303030303030303010c09a0c1a11677265656e6669656c645f353630302d3120c879"
+    );
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "e2d8866a7d895c0269620253d3aeed3abf53ab58c180e8624148f52c7fb6f1ce"
+    );
+}
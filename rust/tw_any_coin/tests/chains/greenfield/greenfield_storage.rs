@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::greenfield::{make_amount, PRIVATE_KEY_15560, PUBLIC_KEY_15560};
+use serde_json::Value as Json;
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Greenfield::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+const OPERATOR: &str = "0x9d1d97aDFcd324Bbd603D3872BD78e04098510b1";
+const PRIMARY_SP: &str = "0x280b27f3676db1C4475EE10F75D510Eb527fd155";
+
+fn make_input(messages: Vec<Proto::Message<'static>>) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Eip712,
+        account_number: 15560,
+        eth_chain_id: "5600".into(),
+        cosmos_chain_id: "greenfield_5600-1".into(),
+        fee: Some(Proto::Fee {
+            amounts: vec![make_amount("BNB", "6000000000000")],
+            gas: 1200,
+        }),
+        sequence: 21,
+        messages,
+        mode: Proto::BroadcastMode::SYNC,
+        public_key: PUBLIC_KEY_15560.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    }
+}
+
+fn make_approval() -> Proto::Approval<'static> {
+    Proto::Approval {
+        expired_height: 1_000_000,
+        global_virtual_group_family_id: 1,
+        sig: "c0ffee".decode_hex().unwrap().into(),
+    }
+}
+
+/// Returns the EIP712 message produced by `TWTransactionCompilerPreImageHashes`.
+fn eip712_preimage(input: &Proto::SigningInput<'_>) -> Json {
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let output = pre_imager.pre_image_hashes(CoinType::Greenfield, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    serde_json::from_slice(&output.data).unwrap()
+}
+
+/// Signs the given `input` by the `PRIVATE_KEY_15560` private key.
+fn sign(mut input: Proto::SigningInput<'static>) -> Proto::SigningOutput<'static> {
+    input.public_key = Default::default();
+    input.private_key = PRIVATE_KEY_15560.decode_hex().unwrap().into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Greenfield, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    output
+}
+
+fn property_names(eip712: &Json, type_name: &str) -> Vec<String> {
+    eip712["types"][type_name]
+        .as_array()
+        .unwrap_or_else(|| panic!("'{type_name}' type is not declared"))
+        .iter()
+        .map(|property| property["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_greenfield_create_bucket_eip712() {
+    let create_bucket = Proto::mod_Message::CreateBucket {
+        creator: OPERATOR.into(),
+        bucket_name: "trust-wallet-bucket".into(),
+        visibility: Proto::VisibilityType::VISIBILITY_TYPE_PRIVATE,
+        primary_sp_address: PRIMARY_SP.into(),
+        primary_sp_approval: Some(make_approval()),
+        charged_read_quota: 100,
+        ..Proto::mod_Message::CreateBucket::default()
+    };
+    let input = make_input(vec![Proto::Message {
+        message_oneof: MessageEnum::create_bucket(create_bucket),
+    }]);
+
+    let eip712 = eip712_preimage(&input);
+    assert_eq!(
+        property_names(&eip712, "Msg1"),
+        [
+            "bucket_name",
+            "charged_read_quota",
+            "creator",
+            "payment_address",
+            "primary_sp_address",
+            "primary_sp_approval",
+            "type",
+            "visibility"
+        ]
+    );
+    assert_eq!(
+        property_names(&eip712, "TypeMsg1PrimarySpApproval"),
+        ["expired_height", "global_virtual_group_family_id", "sig"]
+    );
+
+    let msg = &eip712["message"]["msg1"];
+    assert_eq!(msg["type"], "/greenfield.storage.MsgCreateBucket");
+    assert_eq!(msg["visibility"], "VISIBILITY_TYPE_PRIVATE");
+    assert_eq!(msg["payment_address"], "");
+    assert_eq!(msg["charged_read_quota"], "100");
+    assert_eq!(msg["primary_sp_approval"]["expired_height"], "1000000");
+
+    let output = sign(input);
+    let expected = r#"{"mode":"BROADCAST_MODE_SYNC","tx_bytes":"CqgBCqUBCiMvZ3JlZW5maWVsZC5zdG9yYWdlLk1zZ0NyZWF0ZUJ1Y2tldBJ+CioweDlkMWQ5N2FERmNkMzI0QmJkNjAzRDM4NzJCRDc4ZTA0MDk4NTEwYjESE3RydXN0LXdhbGxldC1idWNrZXQYAjIqMHgyODBiMjdmMzY3NmRiMUM0NDc1RUUxMEY3NUQ1MTBFYjUyN2ZkMTU1OgsIwIQ9EAEaA8D/7kBkEnUKWApNCiYvY29zbW9zLmNyeXB0by5ldGguZXRoc2VjcDI1NmsxLlB1YktleRIjCiECee80Bk2hDbBGPHBIBha6AgcD7DpFAm3ve+vSCC9db8gSBQoDCMgFGBUSGQoUCgNCTkISDTYwMDAwMDAwMDAwMDAQsAkaQdraeBYfjpQMe0IHP5NE/TVBbE7LOwAY3olL+KAIpPakGkUfjzA8jdmj5QgzHCm5a58meIGPNqYKVVDGFtNgyY4b"}"#;
+    assert_eq!(output.serialized, expected);
+    assert_eq!(output.signature.to_hex(), "dada78161f8e940c7b42073f9344fd35416c4ecb3b0018de894bf8a008a4f6a41a451f8f303c8dd9a3e508331c29b96b9f2678818f36a60a5550c616d360c98e1b");
+}
+
+#[test]
+fn test_greenfield_put_policy_eip712() {
+    let put_policy = Proto::mod_Message::PutPolicy {
+        operator: OPERATOR.into(),
+        principal: Some(Proto::Principal {
+            type_pb: Proto::PrincipalType::PRINCIPAL_TYPE_GNFD_ACCOUNT,
+            value: PRIMARY_SP.into(),
+        }),
+        resource: "grn:b::trust-wallet-bucket".into(),
+        statements: vec![Proto::Statement {
+            effect: Proto::Effect::EFFECT_ALLOW,
+            actions: vec![
+                Proto::ActionType::ACTION_GET_OBJECT,
+                Proto::ActionType::ACTION_LIST_OBJECT,
+            ],
+            resources: vec![],
+        }],
+        ..Proto::mod_Message::PutPolicy::default()
+    };
+    let input = make_input(vec![Proto::Message {
+        message_oneof: MessageEnum::put_policy(put_policy),
+    }]);
+
+    let eip712 = eip712_preimage(&input);
+    assert_eq!(
+        property_names(&eip712, "Msg1"),
+        ["operator", "principal", "resource", "statements", "type"]
+    );
+    assert_eq!(
+        property_names(&eip712, "TypeMsg1Principal"),
+        ["type", "value"]
+    );
+    assert_eq!(
+        property_names(&eip712, "TypeMsg1Statements"),
+        ["actions", "effect", "resources"]
+    );
+
+    let msg = &eip712["message"]["msg1"];
+    assert_eq!(msg["type"], "/greenfield.storage.MsgPutPolicy");
+    assert_eq!(msg["principal"]["type"], "PRINCIPAL_TYPE_GNFD_ACCOUNT");
+    assert_eq!(
+        msg["statements"][0]["actions"],
+        serde_json::json!(["ACTION_GET_OBJECT", "ACTION_LIST_OBJECT"])
+    );
+
+    let output = sign(input);
+    let expected = r#"{"mode":"BROADCAST_MODE_SYNC","tx_bytes":"CqgBCqUBCiAvZ3JlZW5maWVsZC5zdG9yYWdlLk1zZ1B1dFBvbGljeRKAAQoqMHg5ZDFkOTdhREZjZDMyNEJiZDYwM0QzODcyQkQ3OGUwNDA5ODUxMGIxEi4IARIqMHgyODBiMjdmMzY3NmRiMUM0NDc1RUUxMEY3NUQ1MTBFYjUyN2ZkMTU1Ghpncm46Yjo6dHJ1c3Qtd2FsbGV0LWJ1Y2tldCIGCAEQBhAIEnUKWApNCiYvY29zbW9zLmNyeXB0by5ldGguZXRoc2VjcDI1NmsxLlB1YktleRIjCiECee80Bk2hDbBGPHBIBha6AgcD7DpFAm3ve+vSCC9db8gSBQoDCMgFGBUSGQoUCgNCTkISDTYwMDAwMDAwMDAwMDAQsAkaQcGp2G7U2I4aQuK7RIy3QxcnW87ytXtxYhKo1qrDjlEdVH+lrkduG/2Mm3+eWOwKDfeqWuCwm8+g8NlpTG5SsfQb"}"#;
+    assert_eq!(output.serialized, expected);
+    assert_eq!(output.signature.to_hex(), "c1a9d86ed4d88e1a42e2bb448cb74317275bcef2b57b716212a8d6aac38e511d547fa5ae476e1bfd8c9b7f9e58ec0a0df7aa5ae0b09bcfa0f0d9694c6e52b1f41b");
+}
+
+#[test]
+fn test_greenfield_sign_group_messages() {
+    let create_group = Proto::mod_Message::CreateGroup {
+        creator: OPERATOR.into(),
+        group_name: "trust-wallet-group".into(),
+        ..Proto::mod_Message::CreateGroup::default()
+    };
+    let update_group_member = Proto::mod_Message::UpdateGroupMember {
+        operator: OPERATOR.into(),
+        group_owner: OPERATOR.into(),
+        group_name: "trust-wallet-group".into(),
+        members_to_add: vec![PRIMARY_SP.into()],
+        ..Proto::mod_Message::UpdateGroupMember::default()
+    };
+
+    let input = make_input(vec![
+        Proto::Message {
+            message_oneof: MessageEnum::create_group(create_group),
+        },
+        Proto::Message {
+            message_oneof: MessageEnum::update_group_member(update_group_member),
+        },
+    ]);
+
+    let eip712 = eip712_preimage(&input);
+    assert_eq!(property_names(&eip712, "TypeMsg2MembersToAdd"), ["member"]);
+    assert_eq!(
+        eip712["message"]["msg2"]["members_to_add"][0]["member"],
+        PRIMARY_SP
+    );
+
+    let output = sign(input);
+    let expected = r#"{"mode":"BROADCAST_MODE_SYNC","tx_bytes":"CrICCmYKIi9ncmVlbmZpZWxkLnN0b3JhZ2UuTXNnQ3JlYXRlR3JvdXASQAoqMHg5ZDFkOTdhREZjZDMyNEJiZDYwM0QzODcyQkQ3OGUwNDA5ODUxMGIxEhJ0cnVzdC13YWxsZXQtZ3JvdXAKxwEKKC9ncmVlbmZpZWxkLnN0b3JhZ2UuTXNnVXBkYXRlR3JvdXBNZW1iZXISmgEKKjB4OWQxZDk3YURGY2QzMjRCYmQ2MDNEMzg3MkJENzhlMDQwOTg1MTBiMRIqMHg5ZDFkOTdhREZjZDMyNEJiZDYwM0QzODcyQkQ3OGUwNDA5ODUxMGIxGhJ0cnVzdC13YWxsZXQtZ3JvdXAiLAoqMHgyODBiMjdmMzY3NmRiMUM0NDc1RUUxMEY3NUQ1MTBFYjUyN2ZkMTU1EnUKWApNCiYvY29zbW9zLmNyeXB0by5ldGguZXRoc2VjcDI1NmsxLlB1YktleRIjCiECee80Bk2hDbBGPHBIBha6AgcD7DpFAm3ve+vSCC9db8gSBQoDCMgFGBUSGQoUCgNCTkISDTYwMDAwMDAwMDAwMDAQsAkaQS4VoL6UyTn34n81KjbqBbWPvyaBtk4Zy1bKSYkS56jjGH5Dvb0aQA1il1FDvNY98d3mV7FZqrUtBSskQm1cvLcc"}"#;
+    assert_eq!(output.serialized, expected);
+    assert_eq!(output.signature.to_hex(), "2e15a0be94c939f7e27f352a36ea05b58fbf2681b64e19cb56ca498912e7a8e3187e43bdbd1a400d62975143bcd63df1dde657b159aab52d052b24426d5cbcb71c");
+}
+
+#[test]
+fn test_greenfield_create_bucket_missing_approval() {
+    let create_bucket = Proto::mod_Message::CreateBucket {
+        creator: OPERATOR.into(),
+        bucket_name: "trust-wallet-bucket".into(),
+        primary_sp_address: PRIMARY_SP.into(),
+        ..Proto::mod_Message::CreateBucket::default()
+    };
+    let input = make_input(vec![Proto::Message {
+        message_oneof: MessageEnum::create_bucket(create_bucket),
+    }]);
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let output = pre_imager.pre_image_hashes(CoinType::Greenfield, &input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
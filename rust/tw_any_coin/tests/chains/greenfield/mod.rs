@@ -7,6 +7,7 @@ use tw_proto::Greenfield::Proto;
 mod greenfield_address;
 mod greenfield_compile;
 mod greenfield_sign;
+mod greenfield_storage;
 
 const PRIVATE_KEY_15560: &str = "9066aa168c379a403becb235c15e7129c133c244e56a757ab07bc369288bcab0";
 const PUBLIC_KEY_15560: &str = "0279ef34064da10db0463c70480616ba020703ec3a45026def7bebd2082f5d6fc8";
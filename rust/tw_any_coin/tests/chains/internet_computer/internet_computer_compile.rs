@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_misc::traits::ToBytesVec;
+use tw_number::U256;
+use tw_proto::InternetComputer::Proto;
+use tw_proto::{deserialize, serialize};
+
+const PRIVATE_KEY: &str = "227102911bb99ce7285a55f952800912b7d22ebeeeee59d77fc33a5d7c7080be";
+const UPDATE_SIGNATURE: &str = "136fb91c98b3a865b261d49716b17deb58e0262f733b50cb5defd83964b332da242f4ea08027c090c9a0fdc4d99e98b3ef743b51e1a56459e7edc7896c2223ca";
+const READ_STATE_SIGNATURE: &str = "2d27208886ce7f0c2a7ad7e8dffbd3d411a56a4b344253d7e6e87a286c6a364614f333bda6a7bcd9c8b79d6e6584fffde4e97f1bd70548b27278d61c163343a2";
+const SIGNED_TRANSACTION: &str = "81826b5452414e53414354494f4e81a266757064617465a367636f6e74656e74a66c726571756573745f747970656463616c6c6e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d026b63616e69737465725f69644a000000000000000201016b6d6574686f645f6e616d656773656e645f706263617267583b0a0012070a050880c2d72f2a220a20943d12e762f43806782f524b8f90297298a6d79e4749b41b585ec427409c826a3a0a088090caa5a3a78abd176d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f736967984013186f18b9181c189818b318a8186518b2186118d418971618b1187d18eb185818e01826182f1873183b185018cb185d18ef18d81839186418b3183218da1824182f184e18a01880182718c0189018c918a018fd18c418d9189e189818b318ef1874183b185118e118a51864185918e718ed18c71889186c1822182318ca6a726561645f7374617465a367636f6e74656e74a46c726571756573745f747970656a726561645f73746174656e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d0265706174687381824e726571756573745f7374617475735820e8fbc2d5b0bf837b3a369249143e50d4476faafb2dd620e4e982586a51ebcf1e6d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f7369679840182d182718201888188618ce187f0c182a187a18d718e818df18fb18d318d41118a5186a184b18341842185318d718e618e8187a1828186c186a183618461418f3183318bd18a618a718bc18d918c818b7189d186e1865188418ff18fd18e418e9187f181b18d705184818b21872187818d6181c161833184318a2";
+
+fn transfer_input() -> Proto::SigningInput<'static> {
+    let transfer = Proto::mod_Transaction::Transfer {
+        to_account_identifier: "943d12e762f43806782f524b8f90297298a6d79e4749b41b585ec427409c826a"
+            .into(),
+        amount: 100_000_000,
+        memo: 0,
+        current_timestamp_nanos: 1_691_709_940_000_000_000,
+    };
+    Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        ..Proto::SigningInput::default()
+    }
+}
+
+fn pre_image_hashes<'a>(
+    pre_imager: &'a mut PreImageHelper<'a, Proto::PreSigningOutput<'a>>,
+    input: &Proto::SigningInput<'_>,
+) -> Proto::PreSigningOutput<'a> {
+    pre_imager.pre_image_hashes(CoinType::InternetComputer, input)
+}
+
+fn compile<'a>(
+    compiler: &'a mut CompilerHelper<'a, Proto::SigningOutput<'a>>,
+    input: &Proto::SigningInput<'_>,
+    signatures: Vec<Vec<u8>>,
+    public_keys: Vec<Vec<u8>>,
+) -> Proto::SigningOutput<'a> {
+    compiler.compile(CoinType::InternetComputer, input, signatures, public_keys)
+}
+
+#[test]
+fn test_internet_computer_compile_transfer() {
+    let key_pair = secp256k1::KeyPair::try_from(PRIVATE_KEY).unwrap();
+    // Uncompressed public key is accepted as well.
+    let public_key = key_pair.public().compressed().to_vec();
+
+    // Step 1: Prepare the input with the public key only.
+    let input = Proto::SigningInput {
+        public_key: public_key.clone().into(),
+        ..transfer_input()
+    };
+
+    // Step 2: Obtain preimage hashes.
+    let mut pre_imager = PreImageHelper::default();
+    let preimage = pre_image_hashes(&mut pre_imager, &input);
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    assert!(preimage.error_message.is_empty());
+
+    let data_hashes: Vec<_> = preimage
+        .data_hashes
+        .iter()
+        .map(|hash| hash.to_hex())
+        .collect();
+    assert_eq!(
+        data_hashes,
+        [
+            "990e58738c11a08458e832648f2853b165779f61bd9d2966788eb71bea966799",
+            "8580a19cbbaa6ba99b5ea8bae9238f459f67ad529b6d46fbeba34531d21c3b31",
+        ]
+    );
+    let request_ids: Vec<_> = preimage.request_ids.iter().map(|id| id.to_hex()).collect();
+    assert_eq!(
+        request_ids,
+        [
+            "e8fbc2d5b0bf837b3a369249143e50d4476faafb2dd620e4e982586a51ebcf1e",
+            "45075c7f405fad657405b40f6f5cfdfbafa90ac803cb6854c9a320994e05f9e1",
+        ]
+    );
+
+    // Step 3: Sign the hashes "externally".
+    let signatures: Vec<_> = preimage
+        .data_hashes
+        .iter()
+        .map(|hash| {
+            let hash = H256::try_from(hash.as_ref()).unwrap();
+            key_pair.sign(hash).unwrap().to_vec()
+        })
+        .collect();
+    assert_eq!(signatures[0][..64].to_vec().to_hex(), UPDATE_SIGNATURE);
+    assert_eq!(signatures[1][..64].to_vec().to_hex(), READ_STATE_SIGNATURE);
+
+    // Step 4: Compile the transaction.
+    let mut compiler = CompilerHelper::default();
+    let output = compile(&mut compiler, &input, signatures, vec![public_key]);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signed_transaction.to_hex(), SIGNED_TRANSACTION);
+}
+
+#[test]
+fn test_internet_computer_compile_transfer_invalid_signature() {
+    let public_key = secp256k1::PrivateKey::try_from(PRIVATE_KEY)
+        .unwrap()
+        .public()
+        .uncompressed()
+        .to_vec();
+
+    // The signatures are swapped.
+    let signatures = vec![
+        READ_STATE_SIGNATURE.decode_hex().unwrap(),
+        UPDATE_SIGNATURE.decode_hex().unwrap(),
+    ];
+    let mut compiler = CompilerHelper::default();
+    let output = compile(
+        &mut compiler,
+        &transfer_input(),
+        signatures,
+        vec![public_key.clone()],
+    );
+    assert_eq!(output.error, SigningErrorType::Error_signing);
+
+    // Only one signature is provided.
+    let signatures = vec![UPDATE_SIGNATURE.decode_hex().unwrap()];
+    let mut compiler = CompilerHelper::default();
+    let output = compile(
+        &mut compiler,
+        &transfer_input(),
+        signatures,
+        vec![public_key],
+    );
+    assert_eq!(output.error, SigningErrorType::Error_signatures_count);
+}
+
+#[test]
+fn test_internet_computer_compile_icrc1_transfer() {
+    let key_pair = secp256k1::KeyPair::try_from(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public().compressed().to_vec();
+
+    let transfer = Proto::mod_Transaction::Icrc1Transfer {
+        // ckBTC ledger.
+        ledger_canister_id: "mxzaz-hqaaa-aaaar-qaada-cai".into(),
+        to: "t4u4z-y3dur-j63pk-nw4rv-yxdbt-agtt6-nygn7-ywh6y-zm2f4-sdzle-3qe".into(),
+        amount: U256::from(100_000u64).to_big_endian_compact().into(),
+        fee: U256::from(10u64).to_big_endian_compact().into(),
+        current_timestamp_nanos: 1_691_709_940_000_000_000,
+        ..Proto::mod_Transaction::Icrc1Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::icrc1_transfer(
+                transfer,
+            ),
+        }),
+        public_key: public_key.clone().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut pre_imager = PreImageHelper::default();
+    let preimage = pre_image_hashes(&mut pre_imager, &input);
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    let request_ids: Vec<_> = preimage.request_ids.iter().map(|id| id.to_hex()).collect();
+    assert_eq!(
+        request_ids,
+        [
+            "2f63ccfb8021195760ee3d52f61d554e53c687c6bb8d9f0d3a9fa45b777c1256",
+            "e6411205c860dba401b0ece1b3f8295906af348def3517235845106f7eaac167",
+        ]
+    );
+    let data_hashes: Vec<_> = preimage
+        .data_hashes
+        .iter()
+        .map(|hash| hash.to_hex())
+        .collect();
+    assert_eq!(
+        data_hashes,
+        [
+            "5ec08c82d38d70749ea388b3efd7ab75212f8ab6e1d5065dd2d1ff09827dd375",
+            "324a9e0104073521c6d5f45db91d9ccdbfafbc10d52456656ce1b3d3e9811b65",
+        ]
+    );
+
+    let signatures: Vec<_> = preimage
+        .data_hashes
+        .iter()
+        .map(|hash| {
+            let hash = H256::try_from(hash.as_ref()).unwrap();
+            key_pair.sign(hash).unwrap().to_vec()
+        })
+        .collect();
+    let mut compiler = CompilerHelper::default();
+    let output = compile(&mut compiler, &input, signatures, vec![public_key]);
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    // The compiled transaction must be the same as the one signed with the private key.
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        public_key: Default::default(),
+        ..input
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let signed = TWDataHelper::wrap(unsafe {
+        tw_any_signer_sign(input_data.ptr(), CoinType::InternetComputer as u32)
+    })
+    .to_vec()
+    .expect("!tw_any_signer_sign returned nullptr");
+    let signed: Proto::SigningOutput = deserialize(&signed).unwrap();
+    assert_eq!(signed.error, SigningErrorType::OK);
+    assert_eq!(signed.signed_transaction, output.signed_transaction);
+}
+
+#[test]
+fn test_internet_computer_compile_register_vote() {
+    let key_pair = secp256k1::KeyPair::try_from(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public().compressed().to_vec();
+
+    let register_vote = Proto::mod_Transaction::RegisterVote {
+        neuron_id: 1_234_567,
+        proposal_id: 130_000,
+        vote: Proto::Vote::Yes,
+        current_timestamp_nanos: 1_691_709_940_000_000_000,
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::register_vote(
+                register_vote,
+            ),
+        }),
+        public_key: public_key.clone().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut pre_imager = PreImageHelper::default();
+    let preimage = pre_image_hashes(&mut pre_imager, &input);
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    let request_ids: Vec<_> = preimage.request_ids.iter().map(|id| id.to_hex()).collect();
+    assert_eq!(
+        request_ids,
+        [
+            "dfff73fa314e182b7a4741f133b797fca858f6f979d352c686709d36e963ec9b",
+            "09206ea30c2605e6312ef6efa765a0d328ba3e8e232d7ca5730da3ddeaae130a",
+        ]
+    );
+    let data_hashes: Vec<_> = preimage
+        .data_hashes
+        .iter()
+        .map(|hash| hash.to_hex())
+        .collect();
+    assert_eq!(
+        data_hashes,
+        [
+            "bbe6821d7f5e68aecb5055cba26c97e4a0abc2004fc09f35b6180753181c4348",
+            "02528d3dc01558e9abe1ba98dd71604dc100f26492d45b553fc9910c5adf1645",
+        ]
+    );
+
+    let signatures: Vec<_> = preimage
+        .data_hashes
+        .iter()
+        .map(|hash| {
+            let hash = H256::try_from(hash.as_ref()).unwrap();
+            key_pair.sign(hash).unwrap().to_vec()
+        })
+        .collect();
+    let mut compiler = CompilerHelper::default();
+    let output = compile(&mut compiler, &input, signatures, vec![public_key]);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(!output.signed_transaction.is_empty());
+}
+
+#[test]
+fn test_internet_computer_compile_register_vote_unspecified() {
+    let register_vote = Proto::mod_Transaction::RegisterVote {
+        neuron_id: 1_234_567,
+        proposal_id: 130_000,
+        vote: Proto::Vote::Unspecified,
+        current_timestamp_nanos: 1_691_709_940_000_000_000,
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::register_vote(
+                register_vote,
+            ),
+        }),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut pre_imager = PreImageHelper::default();
+    let preimage = pre_image_hashes(&mut pre_imager, &input);
+    assert_eq!(preimage.error, SigningErrorType::Error_invalid_params);
+}
@@ -3,3 +3,4 @@
 // Copyright © 2017 Trust Wallet.
 
 mod internet_computer_address;
+mod internet_computer_compile;
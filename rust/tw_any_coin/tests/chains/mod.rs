@@ -5,11 +5,18 @@
 mod aptos;
 mod binance;
 mod bitcoin;
+mod cardano;
 mod cosmos;
 mod ethereum;
 mod greenfield;
 mod internet_computer;
 mod native_evmos;
 mod native_injective;
+mod near;
+mod polkadot;
+mod solana;
+mod sui;
 mod tbinance;
 mod thorchain;
+mod ton;
+mod tron;
@@ -3,4 +3,5 @@
 // Copyright © 2017 Trust Wallet.
 
 mod native_evmos_address;
+mod native_evmos_message_sign;
 mod native_evmos_sign;
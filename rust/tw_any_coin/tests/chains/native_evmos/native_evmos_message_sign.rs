@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::{deserialize, serialize, Cosmos, TxCompiler};
+
+const PRIVATE_KEY: &str = "8d2a3bd62d300a148c89dc8635f87b7a24a951bd1c4e78675fe40e1a640d46ed";
+const SIGNATURE: &str = "b454b412a0270d99415a5f873a8c78b16e1a3071e07d60a92dea7bbc114fdfaf13c369ca35aba97658f9cc32680e300890b54a6ef62ad05e999a376814f13b9f";
+
+#[test]
+fn test_native_evmos_sign_message_adr036() {
+    let input = Cosmos::Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: b"Hello, world!".to_vec().into(),
+        ..Cosmos::Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::NativeEvmos as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Cosmos::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+    // Ethermint public key is always compressed.
+    assert_eq!(
+        output.signature_json,
+        r#"{"pub_key":{"type":"ethermint/PubKeyEthSecp256k1","value":"AoaOfhY0QX2yrf2f44IFv6D+oBiYp/0wVl0T9wVqN8Bl"},"signature":"tFS0EqAnDZlBWl+HOox4sW4aMHHgfWCpLep7vBFP368Tw2nKNaupdlj5zDJoDjAIkLVKbvYq0F6ZmjdoFPE7nw=="}"#
+    );
+}
+
+#[test]
+fn test_native_evmos_message_pre_image_hashes_adr036() {
+    let input = Cosmos::Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: b"Hello, world!".to_vec().into(),
+        ..Cosmos::Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), CoinType::NativeEvmos as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    // The signer address is derived using Keccak256, and the sign doc is hashed with Keccak256 as well.
+    assert_eq!(
+        String::from_utf8(output.data.to_vec()).unwrap(),
+        r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"SGVsbG8sIHdvcmxkIQ==","signer":"evmos14py36sx57ud82t9yrks9z6hdsrpn5x6k0r05np"}}],"sequence":"0"}"#
+    );
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "5521fb3964fe0f0d1e03c41cab9711d90334ec3913094afe8f13dc95e09a0455"
+    );
+}
+
+#[test]
+fn test_native_evmos_verify_message_adr036() {
+    // Both compressed and extended public keys are accepted.
+    let public_keys = [
+        "02868e7e1634417db2adfd9fe38205bfa0fea01898a7fd30565d13f7056a37c065",
+        "04868e7e1634417db2adfd9fe38205bfa0fea01898a7fd30565d13f7056a37c065211845f6e553524c2c1611af9712ac02b7a3b439c9f0cfcadfd81a2c86cc0ab8",
+    ];
+    for public_key in public_keys {
+        let input = Cosmos::Proto::MessageVerifyingInput {
+            message: b"Hello, world!".to_vec().into(),
+            public_key: public_key.decode_hex().unwrap().into(),
+            signature: SIGNATURE.decode_hex().unwrap().into(),
+        };
+
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+        let verified =
+            unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::NativeEvmos as u32) };
+        assert!(verified);
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+mod near_address;
+mod near_compile;
+mod near_message_sign;
+mod near_sign;
+
+const NEAR_COIN_TYPE: u32 = 397;
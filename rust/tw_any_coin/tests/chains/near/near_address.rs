@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_get_data, test_address_invalid, test_address_normalization, test_address_valid,
+};
+use tw_coin_registry::coin_type::CoinType;
+
+#[test]
+fn test_near_address_normalization() {
+    test_address_normalization(
+        CoinType::NEAR,
+        "917B3D268D4B58F7FEC1B150BD68D69BE3EE5D4CC39855E341538465BB77860D",
+        "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d",
+    );
+    // Legacy address.
+    test_address_normalization(
+        CoinType::NEAR,
+        "NEAR2758Nk7CMUcxTwXdjVdSxNEidiZQWMZN3USJzj76q5ia3v2v2v",
+        "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d",
+    );
+}
+
+#[test]
+fn test_near_address_is_valid() {
+    test_address_valid(
+        CoinType::NEAR,
+        "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d",
+    );
+    test_address_valid(
+        CoinType::NEAR,
+        "NEAR2758Nk7CMUcxTwXdjVdSxNEidiZQWMZN3USJzj76q5ia3v2v2v",
+    );
+}
+
+#[test]
+fn test_near_address_invalid() {
+    test_address_invalid(CoinType::NEAR, "abc");
+    test_address_invalid(
+        CoinType::NEAR,
+        "EOS65QzSGJ579GPNKtZoZkChTzsxR4B48RCfiS82m2ymJR6VZCjT",
+    );
+    test_address_invalid(CoinType::NEAR, "NEAR2fk7ax");
+    test_address_invalid(
+        CoinType::NEAR,
+        "NEAR2758Nk7CMUcxTwXdjVdSxNEidiZQWMZN3USJzj76q5ia3v2v",
+    );
+    test_address_invalid(
+        CoinType::NEAR,
+        "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb7786",
+    );
+    test_address_invalid(
+        CoinType::NEAR,
+        "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d1f",
+    );
+    // Named accounts are not addresses.
+    test_address_invalid(CoinType::NEAR, "test-trust.vlad.near");
+}
+
+#[test]
+fn test_near_address_get_data() {
+    test_address_get_data(
+        CoinType::NEAR,
+        "NEAR2758Nk7CMUcxTwXdjVdSxNEidiZQWMZN3USJzj76q5ia3v2v2v",
+        "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d",
+    );
+}
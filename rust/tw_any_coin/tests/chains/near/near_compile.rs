@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_proto::NEAR::Proto;
+use tw_proto::NEAR::Proto::mod_Action::OneOfpayload as ActionType;
+
+const PUBLIC_KEY: &str = "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d";
+
+fn transfer_input() -> Proto::SigningInput<'static> {
+    let transfer = Proto::Transfer {
+        deposit: "01000000000000000000000000000000"
+            .decode_hex()
+            .unwrap()
+            .into(),
+    };
+    Proto::SigningInput {
+        signer_id: "test.near".into(),
+        nonce: 1,
+        receiver_id: "whatever.near".into(),
+        block_hash: "0fa473fd26901df296be6adc4cc4df34d040efa2435224b6986910e630c2fef6"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        actions: vec![Proto::Action {
+            payload: ActionType::transfer(transfer),
+        }],
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_near_compile_transfer() {
+    let input = transfer_input();
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::NEAR, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data.to_hex(), "09000000746573742e6e65617200917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d01000000000000000d00000077686174657665722e6e6561720fa473fd26901df296be6adc4cc4df34d040efa2435224b6986910e630c2fef6010000000301000000000000000000000000000000");
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "eea6e680f3ea51a7f667e9a801d0bfadf66e03d41ed54975b3c6006351461b32"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = "969a83332186ee9755e4839325525806e189a3d2d2bb4b4760e94443e97e1c4f22deeef0059a8e9713100eda6e19144da7e8a0ef7e539b20708ba1d8d021bd01".decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::NEAR, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.signed_transaction.to_hex(), "09000000746573742e6e65617200917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d01000000000000000d00000077686174657665722e6e6561720fa473fd26901df296be6adc4cc4df34d040efa2435224b6986910e630c2fef601000000030100000000000000000000000000000000969a83332186ee9755e4839325525806e189a3d2d2bb4b4760e94443e97e1c4f22deeef0059a8e9713100eda6e19144da7e8a0ef7e539b20708ba1d8d021bd01");
+    assert_eq!(
+        output.hash.to_hex(),
+        "eea6e680f3ea51a7f667e9a801d0bfadf66e03d41ed54975b3c6006351461b32"
+    );
+}
+
+#[test]
+fn test_near_compile_invalid_signature() {
+    let input = transfer_input();
+
+    // The signature of another transaction.
+    let signature = "11fdbc234d4ce470ec7f2ac5e4d3d8f8fe1525f83e9a2425e7000aea52f7260ff4f5191beaa1a5ac29256e68c6acd368ada0d06ed033e9a204ee119f5ef1b104".decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::NEAR, &input, vec![signature], vec![public_key]);
+    assert_eq!(output.error, SigningError::Error_signing);
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::near::NEAR_COIN_TYPE;
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::NEAR::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "8737b99bf16fba78e1e753e23ba00c4b5423ac9c45d9b9caae9a519434786568";
+const PUBLIC_KEY: &str = "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d";
+const MESSAGE: &str = "Hello NEAR";
+const NONCE: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+const RECIPIENT: &str = "wallet.near";
+const SIGNATURE: &str = "d0a1bd8a92e292dcdcad8ec9ef1ef77a6b92210b0ff30c1c6cb236ac07d0b886687d26c473094746ffce0c6e741e53c7d3c5e009435b8b672bac9cb6c33d1904";
+
+fn message_signing_input(callback_url: &'static str) -> Proto::MessageSigningInput<'static> {
+    Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.into(),
+        nonce: NONCE.decode_hex().unwrap().into(),
+        recipient: RECIPIENT.into(),
+        callback_url: callback_url.into(),
+    }
+}
+
+/// Returns the serialized `MessageSigningOutput`.
+fn sign_message(input: Proto::MessageSigningInput<'_>) -> Vec<u8> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), NEAR_COIN_TYPE) })
+        .to_vec()
+        .expect("!tw_message_signer_sign returned nullptr")
+}
+
+fn verify_message(input: Proto::MessageVerifyingInput<'_>) -> bool {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), NEAR_COIN_TYPE) }
+}
+
+#[test]
+fn test_near_sign_message() {
+    let output = sign_message(message_signing_input(""));
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+
+    let output = sign_message(message_signing_input("https://example.com/callback"));
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.signature.to_hex(), "62b85c6027b912dc19c1bca5cc60c61a101147f5dbb070902315ec5fce1a2a66c4c11570af12e4cda0e9d7a88a5d5adc6f4795cfbb76fda03e86062720d51502");
+}
+
+#[test]
+fn test_near_sign_message_url_recipient() {
+    // NEP-413 recipient is an arbitrary string, not necessarily a valid account ID.
+    let recipient = "https://MyApp.com/login";
+    let input = Proto::MessageSigningInput {
+        recipient: recipient.into(),
+        ..message_signing_input("")
+    };
+    let output = sign_message(input);
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    let signature = "aac401e22f3f2b23b30e416782b11cf3f7f458b8bb458d792decb55ac0b71eaa65ca1946eeb65291d0dd3a49b04a50407e5c55f12da9eb00baf4b30839f14d0a";
+    assert_eq!(output.signature.to_hex(), signature);
+
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.into(),
+        nonce: NONCE.decode_hex().unwrap().into(),
+        recipient: recipient.into(),
+        callback_url: "".into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: signature.decode_hex().unwrap().into(),
+    };
+    assert!(verify_message(input));
+}
+
+#[test]
+fn test_near_sign_message_empty_recipient() {
+    let input = Proto::MessageSigningInput {
+        recipient: "".into(),
+        ..message_signing_input("")
+    };
+    let output = sign_message(input);
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_near_sign_message_invalid_nonce() {
+    let input = Proto::MessageSigningInput {
+        nonce: "0001020304".decode_hex().unwrap().into(),
+        ..message_signing_input("")
+    };
+    let output = sign_message(input);
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_near_message_pre_image_hashes() {
+    let input_data = TWDataHelper::create(serialize(&message_signing_input("")).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), NEAR_COIN_TYPE)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    // NEP-413 prefix followed by the Borsh-encoded payload.
+    assert_eq!(output.data.to_hex(), "9d0100800a00000048656c6c6f204e454152000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f0b00000077616c6c65742e6e65617200");
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "cd7cecaca60ffce91d9046b2ef76f59076f57aaf66469e4c86d9fad5da86eb41"
+    );
+}
+
+#[test]
+fn test_near_verify_message() {
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.into(),
+        nonce: NONCE.decode_hex().unwrap().into(),
+        recipient: RECIPIENT.into(),
+        callback_url: "".into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.decode_hex().unwrap().into(),
+    };
+    assert!(verify_message(input.clone()));
+
+    // The recipient is changed.
+    let input = Proto::MessageVerifyingInput {
+        recipient: "relayer.near".into(),
+        ..input
+    };
+    assert!(!verify_message(input));
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::NEAR::Proto;
+use tw_proto::NEAR::Proto::mod_Action::OneOfpayload as ActionType;
+
+const PRIVATE_KEY: &str = "8737b99bf16fba78e1e753e23ba00c4b5423ac9c45d9b9caae9a519434786568";
+const BLOCK_HASH: &str = "0fa473fd26901df296be6adc4cc4df34d040efa2435224b6986910e630c2fef6";
+
+fn transfer_action(deposit: &str) -> Proto::Action<'static> {
+    Proto::Action {
+        payload: ActionType::transfer(Proto::Transfer {
+            deposit: deposit.decode_hex().unwrap().into(),
+        }),
+    }
+}
+
+#[test]
+fn test_near_sign_transfer() {
+    let input = Proto::SigningInput {
+        signer_id: "test.near".into(),
+        nonce: 1,
+        receiver_id: "whatever.near".into(),
+        block_hash: BLOCK_HASH.decode_hex().unwrap().into(),
+        actions: vec![transfer_action("01000000000000000000000000000000")],
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::NEAR, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.signed_transaction.to_hex(), "09000000746573742e6e65617200917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d01000000000000000d00000077686174657665722e6e6561720fa473fd26901df296be6adc4cc4df34d040efa2435224b6986910e630c2fef601000000030100000000000000000000000000000000969a83332186ee9755e4839325525806e189a3d2d2bb4b4760e94443e97e1c4f22deeef0059a8e9713100eda6e19144da7e8a0ef7e539b20708ba1d8d021bd01");
+    assert_eq!(
+        output.hash.to_hex(),
+        "eea6e680f3ea51a7f667e9a801d0bfadf66e03d41ed54975b3c6006351461b32"
+    );
+}
+
+#[test]
+fn test_near_sign_stake() {
+    let stake = Proto::Stake {
+        stake: "000000fa4f3f757902ae0b0800000000"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        public_key: Some(Proto::PublicKey {
+            key_type: 0,
+            data: "a3cb23dbb9810abd4a6804328eec47a17236383b5c234cae903b064e9dc426da"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        }),
+    };
+    let input = Proto::SigningInput {
+        signer_id: "vdx.testnet".into(),
+        nonce: 93128451000005,
+        receiver_id: "vdx.testnet".into(),
+        block_hash: "a2fbdae8a769c636d109952e4fe760b03688e629933cbf693aedfd97a470c7a5"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        actions: vec![Proto::Action {
+            payload: ActionType::stake(stake),
+        }],
+        private_key: "d22149327ceb8e86f70962be0c7293f8308d85d0cbea2cc24e47c3033da7440f"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::NEAR, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.signed_transaction.to_hex(), "0b0000007664782e746573746e657400a3cb23dbb9810abd4a6804328eec47a17236383b5c234cae903b064e9dc426dac5863d28b35400000b0000007664782e746573746e6574a2fbdae8a769c636d109952e4fe760b03688e629933cbf693aedfd97a470c7a50100000004000000fa4f3f757902ae0b080000000000a3cb23dbb9810abd4a6804328eec47a17236383b5c234cae903b064e9dc426da0011fdbc234d4ce470ec7f2ac5e4d3d8f8fe1525f83e9a2425e7000aea52f7260ff4f5191beaa1a5ac29256e68c6acd368ada0d06ed033e9a204ee119f5ef1b104");
+    assert_eq!(
+        output.hash.to_hex(),
+        "c8aedbf75fcaa9b663a3959d27f1deae809e1923460791471e5219eafecc4ba8"
+    );
+}
+
+#[test]
+fn test_near_sign_function_call() {
+    let function_call = Proto::FunctionCall {
+        method_name: "deposit_and_stake".into(),
+        args: "{}".as_bytes().into(),
+        gas: 125000000000000,
+        deposit: "000080f64ae1c7022d15000000000000"
+            .decode_hex()
+            .unwrap()
+            .into(),
+    };
+    let input = Proto::SigningInput {
+        signer_id: "b8d5df25047841365008f30fb6b30dd820e9a84d869f05623d114e96831f2fbf".into(),
+        nonce: 77701544000004,
+        receiver_id: "avado.poolv1.near".into(),
+        block_hash: "e78680996127b7a0f3f2343502e442f24366cba5f79cb72f8bc6d0debb26ce24"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        actions: vec![Proto::Action {
+            payload: ActionType::function_call(function_call),
+        }],
+        private_key: "6cfe32a73c2954a11238d14c342df206c1d73db33dccbac6fcb72de92856e6df"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::NEAR, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(base64::encode(&output.signed_transaction, false), "QAAAAGI4ZDVkZjI1MDQ3ODQxMzY1MDA4ZjMwZmI2YjMwZGQ4MjBlOWE4NGQ4NjlmMDU2MjNkMTE0ZTk2ODMxZjJmYmYAzgCT6NK76nb1mB7pToefgkGUHfUe5BKvvr3gW/nq+MgEuu1Mq0YAABEAAABhdmFkby5wb29sdjEubmVhcueGgJlhJ7eg8/I0NQLkQvJDZsul95y3L4vG0N67Js4kAQAAAAIRAAAAZGVwb3NpdF9hbmRfc3Rha2UCAAAAe30A0JjUr3EAAAAAgPZK4ccCLRUAAAAAAAAALNrorr8qTL6u1nlxLpuPa45nFdYmjU96i7CmJP08mVHVzHUaw/bGN30Z3u3o1F2o2yefCBNqO9Ogn9fM25NGCg==");
+    assert_eq!(
+        output.hash.to_hex(),
+        "0b2ccef5040a56f683d23e271bcbfaef61033e03dbf4c5e7f21e8bdd24ae8828"
+    );
+}
+
+#[test]
+fn test_near_sign_token_transfer() {
+    let token_transfer = Proto::TokenTransfer {
+        token_amount: "100000000000000000".into(),
+        receiver_id: "c6d5e3e8f328436f595856a598239b691d3d136b24c05a4614f9e9716edc14fe".into(),
+        gas: 15000000000000,
+        deposit: "01000000000000000000000000000000"
+            .decode_hex()
+            .unwrap()
+            .into(),
+    };
+    let input = Proto::SigningInput {
+        signer_id: "105396228ac2e0ef144b93bcc5322fca1167d524422bb73d17440d35c714a58f".into(),
+        nonce: 93062928000003,
+        receiver_id: "token.paras.near".into(),
+        block_hash: "182eced235cdd9be121f55df32d3484849d4f1701328e859c31c74a4b2d9a931"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        actions: vec![Proto::Action {
+            payload: ActionType::token_transfer(token_transfer),
+        }],
+        private_key: "77006e227658c18da47546413926a26b839204b1b19e807c4a13d994d661c72e"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::NEAR, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(base64::encode(&output.signed_transaction, false), "QAAAADEwNTM5NjIyOGFjMmUwZWYxNDRiOTNiY2M1MzIyZmNhMTE2N2Q1MjQ0MjJiYjczZDE3NDQwZDM1YzcxNGE1OGYAEFOWIorC4O8US5O8xTIvyhFn1SRCK7c9F0QNNccUpY8D5MPmo1QAABAAAAB0b2tlbi5wYXJhcy5uZWFyGC7O0jXN2b4SH1XfMtNISEnU8XATKOhZwxx0pLLZqTEBAAAAAgsAAABmdF90cmFuc2ZlcnAAAAB7ImFtb3VudCI6IjEwMDAwMDAwMDAwMDAwMDAwMCIsInJlY2VpdmVyX2lkIjoiYzZkNWUzZThmMzI4NDM2ZjU5NTg1NmE1OTgyMzliNjkxZDNkMTM2YjI0YzA1YTQ2MTRmOWU5NzE2ZWRjMTRmZSJ9APCrdaQNAAABAAAAAAAAAAAAAAAAAAAAANUjO7fmnTebSNW9EcHHwYwPNlQJcReGWJfJUuxWzPDAGEeo4JTcLB8pLCkqxKKsI0NE1Szv2+GAt5mCBum5mQY=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "88639a577f94e1c760fd831f69439301cbcb6cbd08bc2f9e5f0eedfb022b78ec"
+    );
+}
+
+#[test]
+fn test_near_sign_delegate_action() {
+    let input = Proto::SigningInput {
+        signer_id: "test.near".into(),
+        nonce: 2,
+        receiver_id: "whatever.near".into(),
+        actions: vec![transfer_action("01")],
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        delegate_action: Some(Proto::DelegateActionParams {
+            max_block_height: 1000,
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::NEAR, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Borsh-encoded `SignedDelegateAction`.
+    assert_eq!(output.signed_transaction.to_hex(), "09000000746573742e6e6561720d00000077686174657665722e6e6561720100000003010000000000000000000000000000000200000000000000e80300000000000000917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d00affdaac6721fdc7b07ca31bd1f7f9c7904911e37269281751676d3128cb07d013a7b835f6d0d78114d7f8b0b0fe337817c165ec052e2895372efd5a0d1e03508");
+    assert_eq!(
+        output.hash.to_hex(),
+        "69f41d84c16ce90493a7bb7c0035435ad7310e15d27a52e2deac5547bc0aec3c"
+    );
+}
+
+#[test]
+fn test_near_sign_relay_delegate_action() {
+    let delegate_action = Proto::DelegateAction {
+        sender_id: "test.near".into(),
+        receiver_id: "whatever.near".into(),
+        actions: vec![transfer_action("01")],
+        nonce: 2,
+        max_block_height: 1000,
+        public_key: Some(Proto::PublicKey {
+            key_type: 0,
+            data: "917b3d268d4b58f7fec1b150bd68d69be3ee5d4cc39855e341538465bb77860d"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        }),
+    };
+    let signed_delegate = Proto::SignedDelegateAction {
+        delegate_action: Some(delegate_action),
+        signature: "affdaac6721fdc7b07ca31bd1f7f9c7904911e37269281751676d3128cb07d013a7b835f6d0d78114d7f8b0b0fe337817c165ec052e2895372efd5a0d1e03508".decode_hex().unwrap().into(),
+    };
+    // The relayer pays for the transaction.
+    let input = Proto::SigningInput {
+        signer_id: "relayer.near".into(),
+        nonce: 5,
+        receiver_id: "test.near".into(),
+        block_hash: BLOCK_HASH.decode_hex().unwrap().into(),
+        actions: vec![Proto::Action {
+            payload: ActionType::delegate(signed_delegate),
+        }],
+        private_key: "77006e227658c18da47546413926a26b839204b1b19e807c4a13d994d661c72e"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::NEAR, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(base64::encode(&output.signed_transaction, false), "DAAAAHJlbGF5ZXIubmVhcgAQU5YiisLg7xRLk7zFMi/KEWfVJEIrtz0XRA01xxSljwUAAAAAAAAACQAAAHRlc3QubmVhcg+kc/0mkB3ylr5q3EzE3zTQQO+iQ1IktphpEOYwwv72AQAAAAgJAAAAdGVzdC5uZWFyDQAAAHdoYXRldmVyLm5lYXIBAAAAAwEAAAAAAAAAAAAAAAAAAAACAAAAAAAAAOgDAAAAAAAAAJF7PSaNS1j3/sGxUL1o1pvj7l1Mw5hV40FThGW7d4YNAK/9qsZyH9x7B8oxvR9/nHkEkR43JpKBdRZ20xKMsH0BOnuDX20NeBFNf4sLD+M3gXwWXsBS4olTcu/VoNHgNQgAySclm1ruf+V0olk1MN0tzM8eTE4mKS7B1GkXH59DcvC5/aReQexKFdNVQXFY5qhhLCxsjeJPDSyKc9FL8nM8Cg==");
+    assert_eq!(
+        output.hash.to_hex(),
+        "d1efc1fd42d80c5c6a4c5c12ac7f83ab2385bb68a5a20f3e5303138fda56d5f9"
+    );
+}
+
+#[test]
+fn test_near_sign_invalid_account_id() {
+    let input = Proto::SigningInput {
+        signer_id: "Test.near".into(),
+        nonce: 1,
+        receiver_id: "whatever.near".into(),
+        block_hash: BLOCK_HASH.decode_hex().unwrap().into(),
+        actions: vec![transfer_action("01")],
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::NEAR, input);
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_number::U256;
+use tw_proto::Polkadot::Proto;
+
+mod polkadot_address;
+mod polkadot_compile;
+mod polkadot_sign;
+
+const GENESIS_HASH: &str = "91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3";
+const PRIVATE_KEY: &str = "abf8e5bdbe30c65656c0a3cbd181ff8a56294a69dfedd27982aace4a76909115";
+const PRIVATE_KEY_THROW2: &str = "70a794d4f1019c3ce002f33062f45029c4f930a56b3d20ec477f7668c6bbc37f";
+
+/// Returns the amount encoded as minimal big-endian bytes.
+fn amount(value: u128) -> Vec<u8> {
+    U256::from(value).to_big_endian_compact()
+}
+
+fn custom_call_indices(module_index: i32, method_index: i32) -> Option<Proto::CallIndices> {
+    Some(Proto::CallIndices {
+        variant: Proto::mod_CallIndices::OneOfvariant::custom(Proto::CustomCallIndices {
+            module_index,
+            method_index,
+        }),
+    })
+}
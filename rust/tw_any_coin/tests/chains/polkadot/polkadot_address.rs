@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_create_ss58_with_public_key, test_address_get_data, test_address_invalid,
+    test_address_normalization, test_address_ss58_is_valid, test_address_valid,
+    AddressCreateSS58WithPublicKey, AddressSS58IsValid,
+};
+use tw_coin_registry::coin_type::CoinType;
+use tw_keypair::tw::PublicKeyType;
+
+#[test]
+fn test_polkadot_address_normalization() {
+    test_address_normalization(
+        CoinType::Polkadot,
+        "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G2",
+        "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G2",
+    );
+}
+
+#[test]
+fn test_polkadot_address_is_valid() {
+    test_address_valid(
+        CoinType::Polkadot,
+        "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G2",
+    );
+    test_address_valid(
+        CoinType::Polkadot,
+        "15KRsCq9LLNmCxNFhGk55s5bEyazKefunDxUH24GFZwsTxyu",
+    );
+    test_address_valid(
+        CoinType::Kusama,
+        "FoQJpPyadYccjavVdTWxpxU7rUEaYhfLCPwXgkfD6Zat9QP",
+    );
+    test_address_valid(
+        CoinType::Acala,
+        "25Qqz3ARAvnZbahGZUzV3xpP1bB3eRrupEprK7f2FNbHbvsz",
+    );
+}
+
+#[test]
+fn test_polkadot_address_invalid() {
+    // Kusama address.
+    test_address_invalid(
+        CoinType::Polkadot,
+        "FoQJpPyadYccjavVdTWxpxU7rUEaYhfLCPwXgkfD6Zat9QP",
+    );
+    // Polkadot address.
+    test_address_invalid(
+        CoinType::Kusama,
+        "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G2",
+    );
+    // Checksum mismatch.
+    test_address_invalid(
+        CoinType::Polkadot,
+        "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G3",
+    );
+    // Bitcoin address.
+    test_address_invalid(CoinType::Polkadot, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+    test_address_invalid(CoinType::Polkadot, "");
+}
+
+#[test]
+fn test_polkadot_address_get_data() {
+    test_address_get_data(
+        CoinType::Polkadot,
+        "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G2",
+        "9dca538b7a925b8ea979cc546464a3c5f81d2398a3a272f6f93bdf4803f2f783",
+    );
+}
+
+#[test]
+fn test_polkadot_address_create_ss58_with_public_key() {
+    test_address_create_ss58_with_public_key(AddressCreateSS58WithPublicKey {
+        coin: CoinType::Polkadot,
+        private_key: "70a794d4f1019c3ce002f33062f45029c4f930a56b3d20ec477f7668c6bbc37f",
+        public_key_type: PublicKeyType::Ed25519,
+        ss58_prefix: 0,
+        expected: "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G2",
+    });
+    // Polkadot coin with the Parallel network prefix.
+    test_address_create_ss58_with_public_key(AddressCreateSS58WithPublicKey {
+        coin: CoinType::Polkadot,
+        private_key: "70a794d4f1019c3ce002f33062f45029c4f930a56b3d20ec477f7668c6bbc37f",
+        public_key_type: PublicKeyType::Ed25519,
+        ss58_prefix: 172,
+        expected: "p8EWT2UH9h3EWArjpDVhrGPP4zxR3ytrqSrid1baJTFnU8Cpi",
+    });
+    // `//Alice` sr25519 account.
+    test_address_create_ss58_with_public_key(AddressCreateSS58WithPublicKey {
+        coin: CoinType::Polkadot,
+        private_key: "e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a",
+        public_key_type: PublicKeyType::Sr25519,
+        ss58_prefix: 0,
+        expected: "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+    });
+}
+
+#[test]
+fn test_polkadot_address_ss58_is_valid() {
+    test_address_ss58_is_valid(AddressSS58IsValid {
+        coin: CoinType::Polkadot,
+        address: "FoQJpPyadYccjavVdTWxpxU7rUEaYhfLCPwXgkfD6Zat9QP",
+        ss58_prefix: 2,
+    });
+    test_address_ss58_is_valid(AddressSS58IsValid {
+        coin: CoinType::Polkadot,
+        address: "p8EGHjWt7e1MYoD7V6WXvbPZWK9GSJiiK85kv2R7Ur7FisPUL",
+        ss58_prefix: 172,
+    });
+}
@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::polkadot::{amount, custom_call_indices, GENESIS_HASH};
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Polkadot::Proto;
+use tw_proto::Polkadot::Proto::mod_Balance::OneOfmessage_oneof as BalanceVariant;
+use tw_proto::Polkadot::Proto::mod_Identity::OneOfmessage_oneof as IdentityVariant;
+use tw_proto::Polkadot::Proto::mod_PolymeshCall::OneOfmessage_oneof as PolymeshVariant;
+use tw_proto::Polkadot::Proto::mod_SigningInput::OneOfmessage_oneof as SigningVariant;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const STATEMINT_GENESIS_HASH: &str =
+    "68d56f15f85d3136970ec16946040bc1752654e906147f7e43e9d539d7c3de2f";
+const POLYMESH_GENESIS_HASH: &str =
+    "6fbd74e5e1d0a61d52ccfe9d4adaed16dd3a7caa37c6bc4d0c2fa12e8b2f4063";
+const POLYMESH_NETWORK: u32 = 12;
+
+fn transfer_input() -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        block_hash: "40cee3c3b7f8422f4c512e9ebebdeeff1c28e81cc678ee4864d945d641e05f9b"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 0,
+        spec_version: 25,
+        transaction_version: 5,
+        era: Some(Proto::Era {
+            block_number: 5898150,
+            period: 10000,
+        }),
+        network: 0,
+        message_oneof: SigningVariant::balance_call(Proto::Balance {
+            message_oneof: BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+                to_address: "15JWiQUmczAFU3hrZrD2gDyuJdL2BbFaX9yngivb1UWiBJWA".into(),
+                value: "210fdc0c00".decode_hex().unwrap().into(),
+                ..Proto::mod_Balance::Transfer::default()
+            }),
+        }),
+        ..Proto::SigningInput::default()
+    }
+}
+
+fn statemint_asset_transfer_input(
+    nonce: u64,
+    fee_asset_id: u32,
+    method_index: i32,
+) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        block_hash: STATEMINT_GENESIS_HASH.decode_hex().unwrap().into(),
+        genesis_hash: STATEMINT_GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce,
+        spec_version: 9320,
+        transaction_version: 9,
+        network: 0,
+        multi_address: true,
+        message_oneof: SigningVariant::balance_call(Proto::Balance {
+            message_oneof: BalanceVariant::asset_transfer(Proto::mod_Balance::AssetTransfer {
+                call_indices: custom_call_indices(0x32, method_index),
+                to_address: "12q4hq1dgqHZVGzHbwZmqq1cFwatN15Visfd7YmUiMB5ZWkH".into(),
+                value: amount(100_000).into(),
+                asset_id: 1984,
+                fee_asset_id,
+            }),
+        }),
+        ..Proto::SigningInput::default()
+    }
+}
+
+fn polymesh_input(
+    block_hash: &str,
+    nonce: u64,
+    block_number: u64,
+    identity_call: IdentityVariant<'static>,
+) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        block_hash: block_hash.decode_hex().unwrap().into(),
+        genesis_hash: POLYMESH_GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce,
+        spec_version: 3010,
+        transaction_version: 2,
+        era: Some(Proto::Era {
+            block_number,
+            period: 64,
+        }),
+        network: POLYMESH_NETWORK,
+        multi_address: true,
+        message_oneof: SigningVariant::polymesh_call(Proto::PolymeshCall {
+            message_oneof: PolymeshVariant::identity_call(Proto::Identity {
+                message_oneof: identity_call,
+            }),
+        }),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_polkadot_compile_transfer() {
+    let input = transfer_input();
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Polkadot, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data_hash.to_hex(), "0500be4c21aa92dcba057e9b719ce1de970f774f064c09b13a3ea3009affb8cb5ec707000cdc0f219dfe0000190000000500000091b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c340cee3c3b7f8422f4c512e9ebebdeeff1c28e81cc678ee4864d945d641e05f9b");
+
+    // Step 3: Compile transaction info
+    let signature = "fb43727477caaa12542b9060856816d42eedef6ebf2e98e4f8dff4355fe384751925833c4a26b2fed1707aebe655cb3317504a61ee59697c086f7baa6ca06a09".decode_hex().unwrap();
+    let public_key = "d84accbb64934815506288fafbfc7d275e64aa4e3cd9c5392db6e83b13256bf3"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature.clone()],
+        vec![public_key.clone()],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "390284d84accbb64934815506288fafbfc7d275e64aa4e3cd9c5392db6e83b13256bf300fb43727477caaa12542b9060856816d42eedef6ebf2e98e4f8dff4355fe384751925833c4a26b2fed1707aebe655cb3317504a61ee59697c086f7baa6ca06a099dfe00000500be4c21aa92dcba057e9b719ce1de970f774f064c09b13a3ea3009affb8cb5ec707000cdc0f21");
+
+    // Multiple signatures are not supported.
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature.clone(), signature.clone()],
+        vec![public_key.clone(), public_key],
+    );
+    assert_eq!(output.error, SigningError::Error_no_support_n2n);
+    assert!(output.encoded.is_empty());
+
+    // Public key is missing.
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Polkadot, &input, vec![signature], vec![]);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+    assert!(output.encoded.is_empty());
+}
+
+#[test]
+fn test_polkadot_compile_transfer_sr25519() {
+    let input = Proto::SigningInput {
+        key_type: Proto::KeyType::SR25519,
+        ..transfer_input()
+    };
+
+    let signature = "fb43727477caaa12542b9060856816d42eedef6ebf2e98e4f8dff4355fe384751925833c4a26b2fed1707aebe655cb3317504a61ee59697c086f7baa6ca06a09".decode_hex().unwrap();
+    let public_key = "d84accbb64934815506288fafbfc7d275e64aa4e3cd9c5392db6e83b13256bf3"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature],
+        vec![public_key],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    // The signature type is `0x01` (Sr25519).
+    assert_eq!(output.encoded.to_hex(), "390284d84accbb64934815506288fafbfc7d275e64aa4e3cd9c5392db6e83b13256bf301fb43727477caaa12542b9060856816d42eedef6ebf2e98e4f8dff4355fe384751925833c4a26b2fed1707aebe655cb3317504a61ee59697c086f7baa6ca06a099dfe00000500be4c21aa92dcba057e9b719ce1de970f774f064c09b13a3ea3009affb8cb5ec707000cdc0f21");
+}
+
+#[test]
+fn test_polkadot_preimage_signed_extensions() {
+    // `ChargeAssetTxPayment` with the native fee asset and `CheckMetadataHash` in the disabled mode.
+    let input = Proto::SigningInput {
+        charge_native_as_asset_tx_payment: true,
+        check_metadata_hash: Some(Proto::CheckMetadataHash::default()),
+        ..transfer_input()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Polkadot, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data_hash.to_hex(), "0500be4c21aa92dcba057e9b719ce1de970f774f064c09b13a3ea3009affb8cb5ec707000cdc0f219dfe00000000190000000500000091b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c340cee3c3b7f8422f4c512e9ebebdeeff1c28e81cc678ee4864d945d641e05f9b00");
+
+    // `CheckMetadataHash` in the enabled mode.
+    let metadata_hash = "0101010101010101010101010101010101010101010101010101010101010101";
+    let input = Proto::SigningInput {
+        check_metadata_hash: Some(Proto::CheckMetadataHash {
+            metadata_hash: metadata_hash.decode_hex().unwrap().into(),
+        }),
+        ..transfer_input()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Polkadot, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data_hash.to_hex(), format!("0500be4c21aa92dcba057e9b719ce1de970f774f064c09b13a3ea3009affb8cb5ec707000cdc0f219dfe000001190000000500000091b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c340cee3c3b7f8422f4c512e9ebebdeeff1c28e81cc678ee4864d945d641e05f9b01{metadata_hash}"));
+
+    // Metadata hash must be 32 bytes long.
+    let input = Proto::SigningInput {
+        check_metadata_hash: Some(Proto::CheckMetadataHash {
+            metadata_hash: "0101".decode_hex().unwrap().into(),
+        }),
+        ..transfer_input()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Polkadot, &input);
+    assert_eq!(preimage_output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_statemint_compile_asset_transfer() {
+    // https://statemint.subscan.io/extrinsic/2686030-2
+    let input = statemint_asset_transfer_input(0, 0, 0x05);
+
+    let signature = "e0ae36a5ceaaa7ff53fadfecc8a285a436b15e39c43ea09e8897f34fa3fe55133028eb7d8a9ea2cd42ff1c786e945cd47a02243454ecb39c81acc3409d96f903".decode_hex().unwrap();
+    let public_key = "81f5dd1432e5dd60aa71819e1141ad5e54d6f4277d7d128030154114444b8c91"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature],
+        vec![public_key],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "4102840081f5dd1432e5dd60aa71819e1141ad5e54d6f4277d7d128030154114444b8c9100e0ae36a5ceaaa7ff53fadfecc8a285a436b15e39c43ea09e8897f34fa3fe55133028eb7d8a9ea2cd42ff1c786e945cd47a02243454ecb39c81acc3409d96f903000000003205011f0050e47b3c8aef60bc4fc744d8d979cb0eb2d45fa25c2e9da74e1e5ebd9e117518821a0600");
+}
+
+#[test]
+fn test_statemint_compile_usdt_transfer_keep_alive() {
+    // https://statemint.subscan.io/extrinsic/2789377-2
+    let input = statemint_asset_transfer_input(8, 1984, 0x06);
+
+    let signature = "d22583408806c005a24caf16f2084691f4c6dcb6015e6645adc86fc1474369b0e0b7dbcc0ef25b17eae43844aff6fb42a0b279a19e822c76043cac015be5e40a".decode_hex().unwrap();
+    let public_key = "81f5dd1432e5dd60aa71819e1141ad5e54d6f4277d7d128030154114444b8c91"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature],
+        vec![public_key],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "5102840081f5dd1432e5dd60aa71819e1141ad5e54d6f4277d7d128030154114444b8c9100d22583408806c005a24caf16f2084691f4c6dcb6015e6645adc86fc1474369b0e0b7dbcc0ef25b17eae43844aff6fb42a0b279a19e822c76043cac015be5e40a00200001c00700003206011f0050e47b3c8aef60bc4fc744d8d979cb0eb2d45fa25c2e9da74e1e5ebd9e117518821a0600");
+}
+
+#[test]
+fn test_polymesh_compile_transfer_with_memo() {
+    // https://polymesh.subscan.io/extrinsic/0x9a4283cc38f7e769c53ad2d1c5cf292fc85a740ec1c1aa80c180847e51928650
+    let input = Proto::SigningInput {
+        block_hash: "898bba6413c38f79a284aec8749f297f6c8734c501f67517b5a6aadc338d1102"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: POLYMESH_GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 1,
+        spec_version: 3010,
+        transaction_version: 2,
+        era: Some(Proto::Era {
+            block_number: 4298130,
+            period: 64,
+        }),
+        network: POLYMESH_NETWORK,
+        multi_address: true,
+        message_oneof: SigningVariant::balance_call(Proto::Balance {
+            message_oneof: BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+                to_address: "2FSoQykVV3uWe5ChZuazMDHBoaZmCPPuoYx5KHL5VqXooDQW".into(),
+                value: amount(1_000_000).into(),
+                memo: "MEMO PADDED WITH SPACES".into(),
+                call_indices: custom_call_indices(0x05, 0x01),
+            }),
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Polkadot, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data_hash.to_hex(), "050100849e2f6b165d4b28b39ef3d98f86c0520d82bc349536324365c10af08f323f8302093d00014d454d4f2050414444454420574954482053504143455300000000000000000025010400c20b0000020000006fbd74e5e1d0a61d52ccfe9d4adaed16dd3a7caa37c6bc4d0c2fa12e8b2f4063898bba6413c38f79a284aec8749f297f6c8734c501f67517b5a6aadc338d1102");
+
+    let signature = "0791ee378775eaff34ef7e529ab742f0d81d281fdf20ace0aa765ca484f5909c4eea0a59c8dbbc534c832704924b424ba3230c38acd0ad5360cef023ca2a420f".decode_hex().unwrap();
+    let public_key = "4322cf71da08f9d56181a707af7c0c437dfcb93e6caac9825a5aba57548142ee"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature],
+        vec![public_key],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "bd0284004322cf71da08f9d56181a707af7c0c437dfcb93e6caac9825a5aba57548142ee000791ee378775eaff34ef7e529ab742f0d81d281fdf20ace0aa765ca484f5909c4eea0a59c8dbbc534c832704924b424ba3230c38acd0ad5360cef023ca2a420f25010400050100849e2f6b165d4b28b39ef3d98f86c0520d82bc349536324365c10af08f323f8302093d00014d454d4f20504144444544205749544820535041434553000000000000000000");
+}
+
+#[test]
+fn test_polymesh_compile_add_authorization() {
+    // https://polymesh.subscan.io/extrinsic/0x7d9b9109027b36b72d37ba0648cb70e5254524d3d6752cc6b41601f4bdfb1af0
+    let add_authorization = Proto::mod_Identity::AddAuthorization {
+        call_indices: custom_call_indices(0x07, 0x0d),
+        target: "2HEVN4PHYKj7B1krQ9bctAQXZxHQQkANVNCcfbdYk2gZ4cBR".into(),
+        ..Proto::mod_Identity::AddAuthorization::default()
+    };
+    let input = polymesh_input(
+        "ce0c2109db498e45abf8fd447580dcfa7b7a07ffc2bfb1a0fbdd1af3e8816d2b",
+        5,
+        4395451,
+        IdentityVariant::add_authorization(add_authorization),
+    );
+
+    let signature = "81e6561e4391862b5da961d7033baced1c4b25f0e27f938b02321af1118e0b859e1c2bd5607576a258f2c2befbc5f397ea4adb62938f30eb73c8060ab0eabf01".decode_hex().unwrap();
+    let public_key = "4322cf71da08f9d56181a707af7c0c437dfcb93e6caac9825a5aba57548142ee"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature],
+        vec![public_key],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "490284004322cf71da08f9d56181a707af7c0c437dfcb93e6caac9825a5aba57548142ee0081e6561e4391862b5da961d7033baced1c4b25f0e27f938b02321af1118e0b859e1c2bd5607576a258f2c2befbc5f397ea4adb62938f30eb73c8060ab0eabf01b5031400070d01d3b2f1c41b9b4522eb3e23329b81aca6cc0231167ecfa3580c5a71ff6d0610540501000100010000");
+}
+
+#[test]
+fn test_polymesh_compile_join_identity_as_key() {
+    // https://polymesh.subscan.io/extrinsic/0x9d7297d8b38af5668861996cb115f321ed681989e87024fda64eae748c2dc542
+    let join = Proto::mod_Identity::JoinIdentityAsKey {
+        call_indices: custom_call_indices(0x07, 0x05),
+        auth_id: 21435,
+    };
+    let input = polymesh_input(
+        "45c80153c47f5d16acc7a66d473870e8d4574437a7d8c813f47da74cae3812c2",
+        0,
+        4395527,
+        IdentityVariant::join_identity_as_key(join),
+    );
+
+    let signature = "7f5adbb2749e2f0ace29b409c41dd717681495b1f22dc5358311646a9fb8af8a173fc47f1b19748fb56831c2128773e2976986685adee83c741ab49934d80006".decode_hex().unwrap();
+    let public_key = "d3b2f1c41b9b4522eb3e23329b81aca6cc0231167ecfa3580c5a71ff6d061054"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Polkadot,
+        &input,
+        vec![signature],
+        vec![public_key],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "c5018400d3b2f1c41b9b4522eb3e23329b81aca6cc0231167ecfa3580c5a71ff6d061054007f5adbb2749e2f0ace29b409c41dd717681495b1f22dc5358311646a9fb8af8a173fc47f1b19748fb56831c2128773e2976986685adee83c741ab49934d80006750000000705bb53000000000000");
+}
@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::polkadot::{
+    amount, custom_call_indices, GENESIS_HASH, PRIVATE_KEY, PRIVATE_KEY_THROW2,
+};
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::tw::{PublicKey, PublicKeyType};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Polkadot::Proto;
+use tw_proto::Polkadot::Proto::mod_Balance::OneOfmessage_oneof as BalanceVariant;
+use tw_proto::Polkadot::Proto::mod_SigningInput::OneOfmessage_oneof as SigningVariant;
+use tw_proto::Polkadot::Proto::mod_Staking::OneOfmessage_oneof as StakingVariant;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const KUSAMA_GENESIS_HASH: &str =
+    "b0a8d493285c2df73290dfb7e61f870f17b41801197a149ca93654499ea3dafe";
+
+fn balance_call(variant: BalanceVariant) -> SigningVariant {
+    SigningVariant::balance_call(Proto::Balance {
+        message_oneof: variant,
+    })
+}
+
+fn staking_call(variant: StakingVariant) -> SigningVariant {
+    SigningVariant::staking_call(Proto::Staking {
+        message_oneof: variant,
+    })
+}
+
+#[test]
+fn test_polkadot_sign_transfer() {
+    let input = Proto::SigningInput {
+        block_hash: "5d2143bb808626d63ad7e1cda70fa8697059d670a992e82cd440fbb95ea40351"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 3,
+        spec_version: 26,
+        transaction_version: 5,
+        era: Some(Proto::Era {
+            block_number: 3541050,
+            period: 64,
+        }),
+        private_key: PRIVATE_KEY_THROW2.decode_hex().unwrap().into(),
+        network: 0,
+        message_oneof: balance_call(BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+            to_address: "13ZLCqJNPsRZYEbwjtZZFpWt9GyFzg5WahXCVWKpWdUJqrQ5".into(),
+            value: amount(2_000_000_000).into(),
+            ..Proto::mod_Balance::Transfer::default()
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    // https://polkadot.subscan.io/extrinsic/0x9fd06208a6023e489147d8d93f0182b0cb7e45a40165247319b87278e08362d8
+    assert_eq!(output.encoded.to_hex(), "3502849dca538b7a925b8ea979cc546464a3c5f81d2398a3a272f6f93bdf4803f2f7830073e59cef381aedf56d7af076bafff9857ffc1e3bd7d1d7484176ff5b58b73f1211a518e1ed1fd2ea201bd31869c0798bba4ffe753998c409d098b65d25dff801a5030c0005007120f76076bcb0efdf94c7219e116899d0163ea61cb428183d71324eb33b2bce0300943577");
+}
+
+#[test]
+fn test_polkadot_sign_transfer_sr25519() {
+    let input = Proto::SigningInput {
+        block_hash: "5d2143bb808626d63ad7e1cda70fa8697059d670a992e82cd440fbb95ea40351"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 3,
+        spec_version: 26,
+        transaction_version: 5,
+        era: Some(Proto::Era {
+            block_number: 3541050,
+            period: 64,
+        }),
+        // `subkey inspect //Alice`
+        private_key: "e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        network: 0,
+        key_type: Proto::KeyType::SR25519,
+        message_oneof: balance_call(BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+            to_address: "13ZLCqJNPsRZYEbwjtZZFpWt9GyFzg5WahXCVWKpWdUJqrQ5".into(),
+            value: amount(2_000_000_000).into(),
+            ..Proto::mod_Balance::Transfer::default()
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input.clone());
+    assert_eq!(output.error, SigningError::OK);
+
+    // `sr25519` signatures are randomized, so check the encoded extrinsic parts and verify the signature.
+    // Layout: compact length (2 bytes), version (1 byte), signer (32 bytes), signature type (1 byte), signature (64 bytes).
+    let encoded = output.encoded.to_vec();
+    assert_eq!(encoded[..3].to_vec().to_hex(), "350284");
+    assert_eq!(
+        encoded[3..35].to_vec().to_hex(),
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+    );
+    assert_eq!(encoded[35], 0x01);
+    assert_eq!(
+        encoded[100..].to_vec().to_hex(),
+        "a5030c0005007120f76076bcb0efdf94c7219e116899d0163ea61cb428183d71324eb33b2bce0300943577"
+    );
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Polkadot, &input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+
+    let public = PublicKey::new(encoded[3..35].to_vec(), PublicKeyType::Sr25519).unwrap();
+    assert!(public.verify(&encoded[36..100], &preimage_output.data_hash));
+}
+
+#[test]
+fn test_polkadot_sign_bond() {
+    let input = Proto::SigningInput {
+        block_hash: "f1eee612825f29abd3299b486e401299df2faa55b7ce1e34bf2243bd591905fc"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 0,
+        spec_version: 26,
+        transaction_version: 5,
+        era: Some(Proto::Era {
+            block_number: 3540912,
+            period: 64,
+        }),
+        private_key: PRIVATE_KEY_THROW2.decode_hex().unwrap().into(),
+        network: 0,
+        message_oneof: staking_call(StakingVariant::bond(Proto::mod_Staking::Bond {
+            controller: "14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G2".into(),
+            value: amount(11_000_000_000).into(),
+            reward_destination: Proto::RewardDestination::STASH,
+            call_indices: None,
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    // https://polkadot.subscan.io/extrinsic/0x8da66d3fe0f592cff714ec107289370365117a1abdb72a19ac91181fdcf62bba
+    assert_eq!(output.encoded.to_hex(), "3d02849dca538b7a925b8ea979cc546464a3c5f81d2398a3a272f6f93bdf4803f2f783009025843bc49c1c4fbc99dbbd290c92f9879665d55b02f110abfb4800f0e7630877d2cffd853deae7466c22fbc8616a609e1b92615bb365ea8adccba5ef7624050503000007009dca538b7a925b8ea979cc546464a3c5f81d2398a3a272f6f93bdf4803f2f7830700aea68f0201");
+}
+
+#[test]
+fn test_polkadot_sign_bond_and_nominate() {
+    let input = Proto::SigningInput {
+        block_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 4,
+        spec_version: 30,
+        transaction_version: 7,
+        private_key: "7f44b19b391a8015ca4c7d94097b3695867a448d1391e7f3243f06987bdb6858"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        network: 0,
+        message_oneof: staking_call(StakingVariant::bond_and_nominate(
+            Proto::mod_Staking::BondAndNominate {
+                controller: "13ZLCqJNPsRZYEbwjtZZFpWt9GyFzg5WahXCVWKpWdUJqrQ5".into(),
+                value: amount(10_000_000_000).into(),
+                reward_destination: Proto::RewardDestination::STASH,
+                nominators: vec![
+                    "1zugcavYA9yCuYwiEYeMHNJm9gXznYjNfXQjZsZukF1Mpow".into(),
+                    "15oKi7HoBQbwwdQc47k71q4sJJWnu5opn1pqoGx4NAEYZSHs".into(),
+                ],
+                call_indices: None,
+            },
+        )),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    // https://polkadot.subscan.io/extrinsic/4955314-2
+    assert_eq!(output.encoded.to_hex(), "6103840036092fac541e0e5feda19e537c679b487566d7101141c203ac8322c27e5f076a00a8b1f859d788f11a958e98b731358f89cf3fdd41a667ea992522e8d4f46915f4c03a1896f2ac54bdc5f16e2ce8a2a3bf233d02aad8192332afd2113ed6688e0d0010001a02080700007120f76076bcb0efdf94c7219e116899d0163ea61cb428183d71324eb33b2bce0700e40b540201070508002c2a55b5ffdca266bd0207df97565b03255f70783ca1a349be5ed9f44589c36000d44533a4d21fd9d6f5d57c8cd05c61a6f23f9131cec8ae386b6b437db399ec3d");
+}
+
+#[test]
+fn test_polkadot_sign_nominate_hashed_payload() {
+    let input = Proto::SigningInput {
+        block_hash: "d22a6b2e3e61325050718bd04a14da9efca1f41c9f0a525c375d36106e25af68"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 0,
+        spec_version: 17,
+        transaction_version: 3,
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        network: 0,
+        message_oneof: staking_call(StakingVariant::nominate(Proto::mod_Staking::Nominate {
+            // The payload is longer than 256 bytes, so it will be hashed.
+            nominators: vec![
+                "1zugcabYjgfQdMLC3cAzQ8tJZMo45tMnGpivpAzpxB4CZyK".into(),
+                "1REAJ1k691g5Eqqg9gL7vvZCBG7FCCZ8zgQkZWd4va5ESih".into(),
+                "1WG3jyNqniQMRZGQUc7QD2kVLT8hkRPGMSqAb5XYQM1UDxN".into(),
+                "16QFrtU6kDdBjxY8qEKz5EEfuDkHxqG8pix3wSGKQzRcuWHo".into(),
+                "14ShUZUYUR35RBZW6uVVt1zXDxmSQddkeDdXf1JkMA6P721N".into(),
+                "15MUBwP6dyVw5CXF9PjSSv7SdXQuDSwjX86v1kBodCSWVR7c".into(),
+            ],
+            call_indices: None,
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "a1048488dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee00135bbc68b67fffadaf7e98b6402c4fc60382765f543225083a024b0e0ff8071d4ec4ddd67a65828113cc76f3208765608be010d2fcfdcd47e8fe342872704c000000000705182c2a55b5a116a4c88aff57e8f2b70ba72dda72dda4b78630e16ad0ca69006f18127a30e486492921e58f2564b36ab1ca21ff630672f0e76920edd601f8f2b89a1650c532ed1a8641e8922aa24ade0ff411d03edd9ed1c6b7fe42f1a801cee37ceee9d5d071a418b51c02b456d5f5cefd6231041ad59b0e8379c59c11ba4a2439984e16482c99cfad1436111e321a86d87d0fac203bf64538f888e45d793b5413c08d5de7a5d97bea2c7ddf516d0635bddc43f326ae2f80e2595b49d4a08c4619");
+}
+
+#[test]
+fn test_polkadot_sign_chill() {
+    let input = Proto::SigningInput {
+        block_hash: "1d4a1ecc8b1c37bf0ba5d3e0bf14ec5402fbb035eeaf6d8042c07ca5f8c57429"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 0,
+        spec_version: 17,
+        transaction_version: 3,
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        network: 0,
+        message_oneof: staking_call(StakingVariant::chill(Proto::mod_Staking::Chill {
+            call_indices: None,
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "9d018488dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee0088b5e1cd93ba74b82e329f95e1b22660385970182172b2ae280801fdd1ee5652cf7bf319e5e176ccc299dd8eb1e7fccb0ea7717efaf4aacd7640789dd09c1e070000000706");
+}
+
+#[test]
+fn test_polkadot_sign_chill_and_unbond() {
+    let input = Proto::SigningInput {
+        block_hash: "35ba668bb19453e8da6334cadcef2a27c8d4141bfc8b49e78e853c3d73e1ecd0"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 6,
+        spec_version: 9200,
+        transaction_version: 12,
+        era: Some(Proto::Era {
+            block_number: 10541373,
+            period: 64,
+        }),
+        private_key: "298fcced2b497ed48367261d8340f647b3fca2d9415d57c2e3c5ef90482a2266"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        network: 0,
+        message_oneof: staking_call(StakingVariant::chill_and_unbond(
+            Proto::mod_Staking::ChillAndUnbond {
+                value: amount(100_500_000_000).into(),
+                call_indices: None,
+            },
+        )),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    // https://polkadot.subscan.io/extrinsic/10541383-2
+    assert_eq!(output.encoded.to_hex(), "d10184008361bd08ddca5fda28b5e2aa84dc2621de566e23e089e555a42194c3eaf2da7900c891ba102db672e378945d74cf7f399226a76b43cab502436971599255451597fc2599902e4b62c7ce85ecc3f653c693fef3232be620984b5bb5bcecbbd7b209d50318001a02080706070207004d446617");
+}
+
+#[test]
+fn test_kusama_sign_transfer() {
+    let input = Proto::SigningInput {
+        block_hash: "4955dd4813f3e91ef3fd5a825b928af2fc50a71380085f753ccef00bb1582891"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: KUSAMA_GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 0,
+        spec_version: 2019,
+        transaction_version: 2,
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        network: 2,
+        message_oneof: balance_call(BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+            to_address: "FoQJpPyadYccjavVdTWxpxU7rUEaYhfLCPwXgkfD6Zat9QP".into(),
+            value: amount(12345).into(),
+            ..Proto::mod_Balance::Transfer::default()
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Kusama, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded.to_hex(), "25028488dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee000765cfa76cfe19499f4f19ef7dc4527652ec5b2e6b5ecfaf68725dafd48ae2694ad52e61f44152a544784e847de10ddb2c56bee4406574dcbcfdb5e5d35b6d0300000004008eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48e5c0");
+}
+
+#[test]
+fn test_kusama_sign_bond_no_controller() {
+    let input = Proto::SigningInput {
+        block_hash: "beb02a3ee782f4bd60ffcfc3de473e3c5a00b2cf124dd302c559b0e77b4331eb"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: KUSAMA_GENESIS_HASH.decode_hex().unwrap().into(),
+        nonce: 3,
+        spec_version: 9430,
+        transaction_version: 23,
+        era: Some(Proto::Era {
+            block_number: 18672490,
+            period: 64,
+        }),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        network: 2,
+        // `controller` was removed from the `Staking::bond` function at `spec_version = 9430`.
+        message_oneof: staking_call(StakingVariant::bond(Proto::mod_Staking::Bond {
+            value: amount(120_000_000_000).into(),
+            reward_destination: Proto::RewardDestination::CONTROLLER,
+            ..Proto::mod_Staking::Bond::default()
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Kusama, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    // https://kusama.subscan.io/extrinsic/0x4e52e59b63910cbdb8c5430c2d100908934f473363c8994cddfd6d1501b017f5
+    assert_eq!(output.encoded.to_hex(), "c101840088dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee00bc4d7a166bd1e7e2bfe9b53e81239c9e340d5a326f17c0a3d2768fcc127f20f4f85d888ecb90aa3ed9a0943f8ae8116b9a19747e563c8d8151dfe3b1b5deb40ca5020c0006000700b08ef01b02");
+}
+
+#[test]
+fn test_acala_sign_transfer() {
+    let input = Proto::SigningInput {
+        block_hash: "707ffa05b7dc6cdb6356bd8bd51ff20b2757c3214a76277516080a10f1bc7537"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        genesis_hash: "fc41b9bd8ef8fe53d58c7ea67c794c7ec9a73daf05e6d54b14ff6342c99ba64c"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        nonce: 0,
+        spec_version: 2170,
+        transaction_version: 2,
+        era: Some(Proto::Era {
+            block_number: 3893613,
+            period: 64,
+        }),
+        private_key: "9066aa168c379a403becb235c15e7129c133c244e56a757ab07bc369288bcab0"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        network: 10,
+        multi_address: true,
+        message_oneof: balance_call(BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+            to_address: "25Qqz3ARAvnZbahGZUzV3xpP1bB3eRrupEprK7f2FNbHbvsz".into(),
+            value: amount(1_000_000_000_000).into(),
+            call_indices: custom_call_indices(0x0a, 0x00),
+            ..Proto::mod_Balance::Transfer::default()
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Acala, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    // https://acala.subscan.io/extrinsic/3893620-3
+    assert_eq!(output.encoded.to_hex(), "41028400e9590e4d99264a14a85e21e69537e4a64f66a875d38cb8f76b305f41fabe24a900dd54466dffd1e3c80b76013e9459fbdcd17805bd5fdbca0961a643bad1cbd2b7fe005c62c51c18b67f31eb9e61b187a911952fee172ef18402d07c703eec3100d50200000a0000c8c602ded977c56076ae38d98026fa669ca10d6a2b5a0bfc4086ae7668ed1c60070010a5d4e8");
+}
+
+#[test]
+fn test_polkadot_sign_missing_call_indices() {
+    // Call indices must be set explicitly for chains other than Polkadot and Kusama.
+    let input = Proto::SigningInput {
+        block_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        network: 10,
+        message_oneof: balance_call(BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+            to_address: "25Qqz3ARAvnZbahGZUzV3xpP1bB3eRrupEprK7f2FNbHbvsz".into(),
+            value: amount(1).into(),
+            ..Proto::mod_Balance::Transfer::default()
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Acala, input);
+
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
+
+#[test]
+fn test_polkadot_sign_invalid_address_network() {
+    // Kusama address cannot be used on Polkadot.
+    let input = Proto::SigningInput {
+        block_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        genesis_hash: GENESIS_HASH.decode_hex().unwrap().into(),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        network: 0,
+        message_oneof: balance_call(BalanceVariant::transfer(Proto::mod_Balance::Transfer {
+            to_address: "FoQJpPyadYccjavVdTWxpxU7rUEaYhfLCPwXgkfD6Zat9QP".into(),
+            value: amount(1).into(),
+            ..Proto::mod_Balance::Transfer::default()
+        })),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Polkadot, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
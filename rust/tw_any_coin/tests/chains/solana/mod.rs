@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+mod solana_address;
+mod solana_compile;
+mod solana_message_sign;
+mod solana_sign;
+
+const SOLANA_COIN_TYPE: u32 = 501;
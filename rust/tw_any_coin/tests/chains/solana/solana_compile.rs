@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Solana::Proto;
+use tw_proto::Solana::Proto::mod_RawMessage::OneOfmessage as RawMessageType;
+use tw_proto::Solana::Proto::mod_SigningInput::OneOftransaction_type as TransactionType;
+
+#[test]
+fn test_solana_compile_transfer() {
+    let transfer = Proto::Transfer {
+        recipient: "3UVYmECPPMZSCqWKfENfuoTv51fTDTWicX9xmBD2euKe".into(),
+        value: 1000,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        sender: "sp6VUqq1nDEuU83bU2hstmEYrJNipJYpwS7gZ7Jv7ZH".into(),
+        recent_blockhash: "TPJFTN4CjBn12HiBfAbGUhpD9zGvRSm2RcheFRA4Fyv".into(),
+        transaction_type: TransactionType::transfer_transaction(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Solana, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data.to_hex(), "010001030d044a62d0a4dfe5a037a15b59fa4d4d0d3ab81103a2c10a6da08a4d058611c024c255a8bc3e8496217a2cd2a1894b9b9dcace04fcd9c0d599acdaaea40a1b61000000000000000000000000000000000000000000000000000000000000000006c25012cc11a599a45b3b2f7f8a7c65b0547fa0bb67170d7a0cd1eda4e2c9e501020200010c02000000e803000000000000");
+    assert_eq!(preimage_output.signers.len(), 1);
+    assert_eq!(
+        preimage_output.signers[0].as_ref(),
+        b"sp6VUqq1nDEuU83bU2hstmEYrJNipJYpwS7gZ7Jv7ZH"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = "a8c610697087eaf8a34b3facbe06f8e9bb9603bb03270dad021ffcd2fc37b6e9efcdcb78b227401f000eb9231c67685240890962e44a17fd27fc2ff7b971df03".decode_hex().unwrap();
+    let public_key = "0d044a62d0a4dfe5a037a15b59fa4d4d0d3ab81103a2c10a6da08a4d058611c0"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Solana, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded, "5bWxVCP5fuzkKSGby9hnsLranszQJR2evJGTfBrpDQ4rJceW1WxKNrWqVPBsN2QCAGmE6W7VaYkyWjv39HhGrr1Ne2QSUuHZdyyn7hK4pxzLPMgPG8fY1XvXdppWMaKMLmhriLkckzGKJMaE3pWBRFBKzigXY28714uUNndb7S9hVakxa59hrLph39CMgAkcj6b8KYvJEkb1YdYytHSZNGi4kVVTNqiicNgPdf1gmG6qz9zVtnqj9JtaD2efdS8qxsKnvNWSgb8XxbT6dwyp7msUUi7d27cYaPTpK");
+}
+
+#[test]
+fn test_solana_compile_transfer_invalid_signature() {
+    let transfer = Proto::Transfer {
+        recipient: "3UVYmECPPMZSCqWKfENfuoTv51fTDTWicX9xmBD2euKe".into(),
+        value: 1000,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        sender: "sp6VUqq1nDEuU83bU2hstmEYrJNipJYpwS7gZ7Jv7ZH".into(),
+        recent_blockhash: "TPJFTN4CjBn12HiBfAbGUhpD9zGvRSm2RcheFRA4Fyv".into(),
+        transaction_type: TransactionType::transfer_transaction(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    // The signature of a different message.
+    let signature =
+        "3dbiGHLsFqnwA1PXx7xmoikzv6v9g9BXvZts2126qyE163BypurkvgbDiF5RmrEZRiT2MG88v6xwyJTkhhDRuFc9";
+    let signature = base58::decode(signature, Alphabet::BITCOIN).unwrap();
+    let public_key = "0d044a62d0a4dfe5a037a15b59fa4d4d0d3ab81103a2c10a6da08a4d058611c0"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Solana, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::Error_signing);
+    assert!(output.encoded.is_empty());
+}
+
+#[test]
+fn test_solana_compile_create_nonce_account() {
+    let create_nonce = Proto::CreateNonceAccount {
+        nonce_account: "6vNrYDm6EHcvBALY7HywuDWpTSc6uGt3y2nf5MuG1TmJ".into(),
+        rent: 10_000_000,
+        ..Proto::CreateNonceAccount::default()
+    };
+    let input = Proto::SigningInput {
+        sender: "sp6VUqq1nDEuU83bU2hstmEYrJNipJYpwS7gZ7Jv7ZH".into(),
+        recent_blockhash: "mFmK2xFMhzJJaUN5cctfdCizE9dtgcSASSEDh1Yzmat".into(),
+        transaction_type: TransactionType::create_nonce_account(create_nonce),
+        ..Proto::SigningInput::default()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Solana, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data.to_hex(), "020003050d044a62d0a4dfe5a037a15b59fa4d4d0d3ab81103a2c10a6da08a4d058611c057f6ed937bb447a6700c9684d2e182b1a6661838a86cca7d0aac18be2e098b2106a7d517192c568ee08a845f73d29788cf035c3145b21ab344d8062ea940000006a7d517192c5c51218cc94c3d4af17f58daee089ba1fd44e3dbd98a0000000000000000000000000000000000000000000000000000000000000000000000000b563fd13b46e844f12f54fa8a0e78c44d95dbae4953368b7135f1e0de111cb502040200013400000000809698000000000050000000000000000000000000000000000000000000000000000000000000000000000000000000040301020324060000000d044a62d0a4dfe5a037a15b59fa4d4d0d3ab81103a2c10a6da08a4d058611c0");
+    let signers: Vec<&[u8]> = preimage_output
+        .signers
+        .iter()
+        .map(|signer| signer.as_ref())
+        .collect();
+    assert_eq!(
+        signers,
+        [
+            b"sp6VUqq1nDEuU83bU2hstmEYrJNipJYpwS7gZ7Jv7ZH".as_slice(),
+            b"6vNrYDm6EHcvBALY7HywuDWpTSc6uGt3y2nf5MuG1TmJ".as_slice(),
+        ]
+    );
+
+    // Step 3: Compile transaction info
+    let signatures = [
+        "3dbiGHLsFqnwA1PXx7xmoikzv6v9g9BXvZts2126qyE163BypurkvgbDiF5RmrEZRiT2MG88v6xwyJTkhhDRuFc9",
+        "jFq4PbbEM1fuPbq5CkUYgzs7a21g6rvFkfLJAUUGP5QMKYhHBE6nB1dqtwaJsABgyUvrR8QjT2Ej73cXNz7Vur1",
+    ]
+    .iter()
+    .map(|sig| base58::decode(sig, Alphabet::BITCOIN).unwrap())
+    .collect();
+    let public_keys = [
+        "sp6VUqq1nDEuU83bU2hstmEYrJNipJYpwS7gZ7Jv7ZH",
+        "6vNrYDm6EHcvBALY7HywuDWpTSc6uGt3y2nf5MuG1TmJ",
+    ]
+    .iter()
+    .map(|addr| base58::decode(addr, Alphabet::BITCOIN).unwrap())
+    .collect();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Solana, &input, signatures, public_keys);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded, "3wu6xJSbb2NysVgi7pdfMgwVBT1knAdeCr9NR8EktJLoByzM4s9SMto2PPmrnbRqPtHwnpAKxXkC4vqyWY2dRBgdGGCC1bep6qN5nSLVzpPYAWUSq5cd4gfYMAVriFYRRNHmYUnEq8vMn4vjiECmZoHrpabBj8HpXGqYBo87sbZa8ZPCxUcB71hxXiHWZHj2rovx2kr75Uuv1buWXyW6M8uR4UNvQcPPvzVbwBG82RjDYTuancMSAxmrVNR8GLBQNhrCCYrZyte3EWgEyMQxxfW8T3xNXqnbgdfvFJ3UjRBxXj3hrmv17xEivTjfs81aG2AAi24yiYrk8ep7eQqwDHVSArsrynnwVKVNUcCQCnSy7fuiuS7FweFX8DEN1K9BrfecHyWrF15fYzhkmWSs64aH6ZTYHWPv5znhFKYmAuopGwbsBEb2j5p8NS3iJZ2skb2wi47n1rpLZfoCHWKxNiikkDUJTGQNcSDrGUMfeW5aGubJrCfecPKEo9Wo9kd36iSsxYPYSWNKrz2HTooa1rCRhqjXD8dyX3bXGV8TK6W2sEgf4JkcDnNoWQLbindcP8XR");
+}
+
+#[test]
+fn test_solana_compile_raw_message_with_fee_payer_signature() {
+    let message = Proto::mod_RawMessage::MessageLegacy {
+        header: Some(Proto::mod_RawMessage::MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        }),
+        account_keys: vec![
+            "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ".into(),
+            "B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V".into(),
+            "5sS5Z8GAdVHqZKRqEvpDauHvvLgbDveiyfi81uh25mrf".into(),
+            "AZapcpAZtEL1gQuC87F2L1hSfAZnAvNy1hHtJ8DJzySN".into(),
+            "4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU".into(),
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".into(),
+        ],
+        recent_blockhash: "H4gZ56AdmHfZj1F36oWrxDJMUJ8ph7XdTHtmsbtHZshG".into(),
+        instructions: vec![Proto::mod_RawMessage::Instruction {
+            program_id: 5,
+            accounts: vec![2, 4, 3, 1].into(),
+            program_data: "0ca00f00000000000006".decode_hex().unwrap().into(),
+        }],
+    };
+    // The fee payer has signed the transaction already.
+    let raw_message = Proto::RawMessage {
+        signatures: vec![Proto::PubkeySignature {
+            pubkey: "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ".into(),
+            signature: "3KbvREZUat76wgWMtnJfWbJL74Vzh4U2eabVJa3Z3bb2fPtW8AREP5pbmRwUrxZCESbTomWpL41PeKDcPGbojsej".into(),
+        }],
+        message: RawMessageType::legacy(message),
+    };
+    let input = Proto::SigningInput {
+        transaction_type: TransactionType::raw_message(raw_message),
+        ..Proto::SigningInput::default()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Solana, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data.to_hex(), "02000206cb2af089b56a557737bc1718e0cbf232cf5b02e14ee0aa7c6675233f5f6f9b5794c3890fa8d4bc04ab2a676d2cafea5cdc899ecd95a9cbe593e9df258759685a485a24ffb4070461bb6d7f1c8b758c6b2dc90029d551b5fd4eacd82d65e302208e12027e9261a6a276b5ff00ddecfda567ff3ae510a5b47045086ad1d50cab573b442cb3912157f13a933d0134282d032b5ffecd01a2dbf1b7790608df002ea706ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a9eeac74b5e25d20c82c4b737240a39237218927facd51a9318880139044d88a87010504020403010a0ca00f00000000000006");
+    // Only the token owner is yet to sign the transaction.
+    assert_eq!(preimage_output.signers.len(), 1);
+    assert_eq!(
+        preimage_output.signers[0].as_ref(),
+        b"B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = "699dc69780e545bfb3a06d12cc6ee0842473b5ab071d09bb47ef6573cb353ccb0f6caaa1c801a09413cc5a413813e99e48c997dec8c7b64062487ce8f3eb950f".decode_hex().unwrap();
+    let public_key = "94c3890fa8d4bc04ab2a676d2cafea5cdc899ecd95a9cbe593e9df258759685a"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Solana, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded, "ushDP6dNZWq32FASGqdnw7E8x14zFDAEZBViTyevUptV9yb4WSgpYzEGCxt9sXkrEtHVyww4F4GdcGZbPEaQTjAeyX5KGvHHDhWoPeFNnECzjUTuPj35dpF7zJ75Jx3ADfLQtUyzu5w7812fQvhwBwP8XDm3btqSG4VLWeQU5XuVqpg33Mq1L9zkGHQ8PZ4WkgNuSrC584EVnDcFE4rZsUtAv2jFTMjinQJB1qQEGTCHbjgdtJt8PzmXGXeczNyisPsEDrhZUw3g7RFYsgBDB1RFe1TxspzbWmxwr6CNPkGVsopmS6cbvSG9ejXY8xRYaswP7knAoPXwYk26yetoA824mzdv9vJ2RYpyK72EyCqFfFidm8MrJjFR49KwV3HRQKxZzYcvhYuJhR15GKBUWAQvYJTQQWArTi7pr7m84wNsV1mCUjrYsCVK47QtMAYvWU4toTxfgThngfF47awnpcSxfy8ggbwamq7qcaSH6cQVk1LPGo1iB5YxitSbaXP");
+    assert_eq!(output.signatures.len(), 2);
+}
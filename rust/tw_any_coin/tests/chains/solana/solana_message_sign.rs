@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::solana::SOLANA_COIN_TYPE;
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Solana::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "44f480ca27711895586074a14c552e58cc52e66a58edb6c58cf9b9b7295d4a2d";
+const PUBLIC_KEY: &str = "H3imdwa5VQMB5V6yBNyrWwPdSKs4k9Pcpsu4diogoAcg";
+const MESSAGE: &str = "Hello world";
+const SIGNATURE: &str =
+    "2iBZ6zrQRKHcbD8NWmm552gU5vGvh1dk3XV4jxnyEdRKm8up8AeQk1GFr9pJokSmchw7i9gMtNyFBdDt8tBxM1cG";
+
+fn message_signing_input() -> Proto::MessageSigningInput<'static> {
+    Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.into(),
+    }
+}
+
+#[test]
+fn test_solana_sign_message() {
+    let input_data = TWDataHelper::create(serialize(&message_signing_input()).unwrap());
+    let output =
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), SOLANA_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, SIGNATURE);
+}
+
+#[test]
+fn test_solana_message_pre_image_hashes() {
+    let input_data = TWDataHelper::create(serialize(&message_signing_input()).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), SOLANA_COIN_TYPE)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    // Ed25519 signs the message itself.
+    assert_eq!(output.data.as_ref(), MESSAGE.as_bytes());
+    assert_eq!(output.data_hash.as_ref(), MESSAGE.as_bytes());
+}
+
+#[test]
+fn test_solana_verify_message() {
+    let public_key = base58::decode(PUBLIC_KEY, Alphabet::BITCOIN).unwrap();
+
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.into(),
+        public_key: public_key.clone().into(),
+        signature: SIGNATURE.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    assert!(unsafe { tw_message_signer_verify(input_data.ptr(), SOLANA_COIN_TYPE) });
+
+    // The message is changed.
+    let input = Proto::MessageVerifyingInput {
+        message: "Hello world!".into(),
+        public_key: public_key.into(),
+        signature: SIGNATURE.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    assert!(!unsafe { tw_message_signer_verify(input_data.ptr(), SOLANA_COIN_TYPE) });
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+mod sui_address;
+mod sui_compile;
+mod sui_message_sign;
+mod sui_sign;
+
+const SUI_COIN_TYPE: u32 = 784;
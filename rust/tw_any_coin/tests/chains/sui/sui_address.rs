@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_get_data, test_address_invalid, test_address_normalization, test_address_valid,
+};
+use tw_coin_registry::coin_type::CoinType;
+
+#[test]
+fn test_sui_address_normalization() {
+    test_address_normalization(
+        CoinType::Sui,
+        "259FF8074AB425CBB489F236E18E08F03F1A7856BDF7C7A2877BD64F738B5015",
+        "0x259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015",
+    );
+    // System object ID.
+    test_address_normalization(
+        CoinType::Sui,
+        "0x5",
+        "0x0000000000000000000000000000000000000000000000000000000000000005",
+    );
+}
+
+#[test]
+fn test_sui_address_is_valid() {
+    test_address_valid(
+        CoinType::Sui,
+        "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50",
+    );
+    test_address_valid(
+        CoinType::Sui,
+        "259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015",
+    );
+}
+
+#[test]
+fn test_sui_address_invalid() {
+    // Too short.
+    test_address_invalid(CoinType::Sui, "0xd575ad7f18e948462a5cf698f564ef394a752a71");
+    // Too long.
+    test_address_invalid(
+        CoinType::Sui,
+        "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d5000",
+    );
+    // Not a hex.
+    test_address_invalid(
+        CoinType::Sui,
+        "0xz575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50",
+    );
+    test_address_invalid(CoinType::Sui, "");
+}
+
+#[test]
+fn test_sui_address_get_data() {
+    test_address_get_data(
+        CoinType::Sui,
+        "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50",
+        "d575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50",
+    );
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto;
+use tw_proto::Sui::Proto::mod_SigningInput::OneOftransaction_payload as TransactionType;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const PUBLIC_KEY: &str = "6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e";
+
+fn pay_sui_input() -> Proto::SigningInput<'static> {
+    let pay_sui = Proto::PaySui {
+        input_coins: vec![Proto::ObjectRef {
+            object_id: "0x06f2c2c8c1d8964df1019d6616e9705719bebabd931da2755cb948ceb7e68964".into(),
+            version: 748,
+            object_digest: "7UoYeVzREVT17ZyYbRTsKzRCec5xJWm6FMh8AKaDPdDx".into(),
+        }],
+        recipients: vec![
+            "0x259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015".into(),
+        ],
+        amounts: vec![10000],
+    };
+    // The private key is not known, so the sender must be specified explicitly.
+    Proto::SigningInput {
+        signer: "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50".into(),
+        transaction_payload: TransactionType::pay_sui(pay_sui),
+        gas_budget: 2000,
+        reference_gas_price: 1,
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_sui_compile_pay_sui() {
+    let input = pay_sui_input();
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Sui, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    // Intent message: `TransactionData` scope, `V0` version and `Sui` app ID.
+    assert!(preimage_output.data.starts_with(&[0, 0, 0]));
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "a2e2c096666756eacd5052292285df09b4ad92f4f6c14f91e1040b9c29564986"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = "fc4f76e355bc78f609c45c1ceb623f668147da8fdef3b609bd20d763c8eb84287c307a92cdf7a2a7bf8bf8824cb728a76ac1ae4733c2b6b266e24e762ee0bf03".decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Sui, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, "AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBANV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1QAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8/Vda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAEAAAAAAAAA0AcAAAAAAAAA");
+    assert_eq!(output.signature, "APxPduNVvHj2CcRcHOtiP2aBR9qP3vO2Cb0g12PI64QofDB6ks33oqe/i/iCTLcop2rBrkczwrayZuJOdi7gvwNqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_compile_pay_sui_secp256k1() {
+    let input = Proto::SigningInput {
+        signature_scheme: Proto::SignatureScheme::Secp256k1,
+        ..pay_sui_input()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Sui, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    // `sha256` of the `blake2b_256` intent message digest.
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "78d9ec70abc9115304567c479e8320925ba5ecf3b9061cff8db3039676d452e8"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = "4c337d058851133c451704faa50686014c2b96449ca501cc20fc038a2185cf3b5a68096274c9b26a86344afea614a37148440d6382a3d774167e11dca706a63d01".decode_hex().unwrap();
+    let public_key = "02f80b93ebf4a84ad10a92f4e83ab88a0a36de801c0b5ab2492b478fe48548ab76"
+        .decode_hex()
+        .unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Sui, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.signature, "AUwzfQWIURM8RRcE+qUGhgFMK5ZEnKUBzCD8A4ohhc87WmgJYnTJsmqGNEr+phSjcUhEDWOCo9d0Fn4R3KcGpj0C+AuT6/SoStEKkvToOriKCjbegBwLWrJJK0eP5IVIq3Y=");
+}
+
+#[test]
+fn test_sui_compile_invalid_signature() {
+    let input = pay_sui_input();
+
+    // The signature of another transaction.
+    let signature = "11a3e8ddd66189acb74b7f4a0040a51dd9fc757496a0f55dc6e6e6f864143bf0362aa6d1955b2696fbdc36a89ffe327e67ea79330a910b96dc53d1d6c59d8b09".decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Sui, &input, vec![signature], vec![public_key]);
+    assert_eq!(output.error, SigningError::Error_signing);
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::SUI_COIN_TYPE;
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Sui::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266";
+const PUBLIC_KEY: &str = "6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e";
+const MESSAGE: &str = "Hello world";
+const SIGNATURE: &str = "ABP6Ehj93OtWw6ob6Hd+ZgwmpqPQoG+U4/heXGrkQ/HT3GOZqWpLHdsn0bA52KoB3xpgtvhqU6zqEIYqDKLUpAxqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==";
+
+fn message_signing_input() -> Proto::MessageSigningInput<'static> {
+    Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.into(),
+    }
+}
+
+#[test]
+fn test_sui_sign_message() {
+    let input_data = TWDataHelper::create(serialize(&message_signing_input()).unwrap());
+    let output =
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), SUI_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, SIGNATURE);
+}
+
+#[test]
+fn test_sui_message_pre_image_hashes() {
+    let input_data = TWDataHelper::create(serialize(&message_signing_input()).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), SUI_COIN_TYPE)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    // `PersonalMessage` intent followed by the BCS-encoded message bytes.
+    assert_eq!(output.data.to_hex(), "0300000b48656c6c6f20776f726c64");
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "6b27c39ed22f5346dbce4eca17640e1d139012768746aaa42eafe103f2f9ede2"
+    );
+}
+
+#[test]
+fn test_sui_verify_message() {
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    assert!(unsafe { tw_message_signer_verify(input_data.ptr(), SUI_COIN_TYPE) });
+
+    // The message is changed.
+    let input = Proto::MessageVerifyingInput {
+        message: "Hello world!".into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    assert!(!unsafe { tw_message_signer_verify(input_data.ptr(), SUI_COIN_TYPE) });
+}
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto;
+use tw_proto::Sui::Proto::mod_SigningInput::OneOftransaction_payload as TransactionType;
+
+const PRIVATE_KEY: &str = "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266";
+const RECIPIENT: &str = "0x259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015";
+const SENDER: &str = "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50";
+
+fn coin1() -> Proto::ObjectRef<'static> {
+    Proto::ObjectRef {
+        object_id: "0x06f2c2c8c1d8964df1019d6616e9705719bebabd931da2755cb948ceb7e68964".into(),
+        version: 748,
+        object_digest: "7UoYeVzREVT17ZyYbRTsKzRCec5xJWm6FMh8AKaDPdDx".into(),
+    }
+}
+
+fn coin2() -> Proto::ObjectRef<'static> {
+    Proto::ObjectRef {
+        object_id: "0x9c0abe51c6e6655d81de2d044d4fb194931f058c0426c67c7285d8f5657ed64a".into(),
+        version: 13,
+        object_digest: "DLisHs8JN4zUUo4Vg8deidHGwXyaDCLVGDDuz2oMDCog".into(),
+    }
+}
+
+fn gas_coin() -> Proto::ObjectRef<'static> {
+    Proto::ObjectRef {
+        object_id: "0xcd0aa9856147b6c5b4ff2b7dfee5da20aa38253099ef1b4a64aced233c9afe29".into(),
+        version: 51,
+        object_digest: "4E1ZwTHKmXrZ2J57HCQXNqrVEF7R4MevPqL74pQdVCih".into(),
+    }
+}
+
+#[test]
+fn test_sui_sign_pay_sui() {
+    let pay_sui = Proto::PaySui {
+        input_coins: vec![coin1()],
+        recipients: vec![RECIPIENT.into()],
+        amounts: vec![10000],
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::pay_sui(pay_sui),
+        gas_budget: 2000,
+        reference_gas_price: 1,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, "AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBANV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1QAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8/Vda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAEAAAAAAAAA0AcAAAAAAAAA");
+    assert_eq!(output.signature, "APxPduNVvHj2CcRcHOtiP2aBR9qP3vO2Cb0g12PI64QofDB6ks33oqe/i/iCTLcop2rBrkczwrayZuJOdi7gvwNqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_sign_direct() {
+    let sign_direct = Proto::SignDirect {
+        unsigned_tx_msg: "AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBANV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1QAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8/Vda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAEAAAAAAAAA0AcAAAAAAAAA".into(),
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::sign_direct_message(sign_direct),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.signature, "APxPduNVvHj2CcRcHOtiP2aBR9qP3vO2Cb0g12PI64QofDB6ks33oqe/i/iCTLcop2rBrkczwrayZuJOdi7gvwNqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_sign_pay_sui_secp256k1() {
+    let pay_sui = Proto::PaySui {
+        input_coins: vec![coin1()],
+        recipients: vec![RECIPIENT.into()],
+        amounts: vec![10000],
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        signature_scheme: Proto::SignatureScheme::Secp256k1,
+        transaction_payload: TransactionType::pay_sui(pay_sui),
+        gas_budget: 2000,
+        reference_gas_price: 1,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // The sender is derived from the `secp256k1` public key:
+    // 0xbc6d9c914ecd51dee12657847cda0bea5459604a151b2d577108aa4cd2787871
+    assert_eq!(output.unsigned_tx, "AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBALxtnJFOzVHe4SZXhHzaC+pUWWBKFRstV3EIqkzSeHhxAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8+8bZyRTs1R3uEmV4R82gvqVFlgShUbLVdxCKpM0nh4cQEAAAAAAAAA0AcAAAAAAAAA");
+    assert_eq!(output.signature, "AdoOprgnUVqbZgKdy1uHVJthhaDhJ3f4B4dq3ukaniUSGOKqbVCBhyAcOA5Hk5kg+28RlKZfEPTkX8Lut6zJagYC+AuT6/SoStEKkvToOriKCjbegBwLWrJJK0eP5IVIq3Y=");
+}
+
+#[test]
+fn test_sui_sign_pay() {
+    // The second coin is merged into the first one, and the same recipient gets two coins.
+    let pay = Proto::Pay {
+        input_coins: vec![coin1(), coin2()],
+        recipients: vec![RECIPIENT.into(), SENDER.into(), RECIPIENT.into()],
+        amounts: vec![100, 200, 300],
+        gas: Some(gas_coin()),
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::pay(pay),
+        gas_budget: 3_000_000,
+        reference_gas_price: 1000,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, "AAAHAQAG8sLIwdiWTfEBnWYW6XBXGb66vZMdonVcuUjOt+aJZOwCAAAAAAAAIGBFbsZn9c0QRnaA6/lQ7TKSBRddrNlGuyNq7tV8hhfPAQCcCr5RxuZlXYHeLQRNT7GUkx8FjAQmxnxyhdj1ZX7WSg0AAAAAAAAAILdZniTR08L2q3zU1t8kaKLIQ8iGXzr3z2gBlWB6u22/AAhkAAAAAAAAAAAIyAAAAAAAAAAACCwBAAAAAAAAACAln/gHSrQly7SJ8jbhjgjwPxp4Vr33x6KHe9ZPc4tQFQAg1XWtfxjpSEYqXPaY9WTvOUp1KnH+xiSTr4oFXAEsDVAEAwEAAAEBAQACAQAAAwECAAEDAAEEAAECAwEAAAADAQACAAEFAAEBAwEAAQABBgDVda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAHNCqmFYUe2xbT/K33+5dogqjglMJnvG0pkrO0jPJr+KTMAAAAAAAAAIC/oQOEyRKnXSIg1dMHxt7HXAg6znQc1uPke9c9vNRc+1XWtfxjpSEYqXPaY9WTvOUp1KnH+xiSTr4oFXAEsDVDoAwAAAAAAAMDGLQAAAAAAAA==");
+    assert_eq!(output.signature, "ABGj6N3WYYmst0t/SgBApR3Z/HV0lqD1Xcbm5vhkFDvwNiqm0ZVbJpb73Daon/4yfmfqeTMKkQuW3FPR1sWdiwlqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_sign_pay_all_sui() {
+    let pay_all_sui = Proto::PayAllSui {
+        input_coins: vec![coin1(), coin2()],
+        recipient: RECIPIENT.into(),
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::pay_all_sui(pay_all_sui),
+        gas_budget: 2_000_000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, "AAABACAln/gHSrQly7SJ8jbhjgjwPxp4Vr33x6KHe9ZPc4tQFQEBAQABAADVda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAIG8sLIwdiWTfEBnWYW6XBXGb66vZMdonVcuUjOt+aJZOwCAAAAAAAAIGBFbsZn9c0QRnaA6/lQ7TKSBRddrNlGuyNq7tV8hhfPnAq+UcbmZV2B3i0ETU+xlJMfBYwEJsZ8coXY9WV+1koNAAAAAAAAACC3WZ4k0dPC9qt81NbfJGiiyEPIhl86989oAZVgerttv9V1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1Q7gIAAAAAAACAhB4AAAAAAAA=");
+    assert_eq!(output.signature, "AB0E5fQ5FA1vpvvcPcMrNZuwgqB2AGwaM+jvfu55/5He5M5KtBd/uf3+l2KkpmSHYbYy2zHInDo0GUzrTrC3HAJqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_sign_add_stake() {
+    let add_stake = Proto::RequestAddStake {
+        coins: vec![coin1(), coin2()],
+        amount: Some(Proto::Amount {
+            amount: 1_000_000_000,
+        }),
+        validator: "0xf82af32160bc53112ca118abbf57fa6fed47eb90291a1d1d92f438ae2ed74ef6".into(),
+        gas: Some(gas_coin()),
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::request_add_stake(add_stake),
+        gas_budget: 10_000_000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, "AAAFAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAAAQEABvLCyMHYlk3xAZ1mFulwVxm+ur2THaJ1XLlIzrfmiWTsAgAAAAAAACBgRW7GZ/XNEEZ2gOv5UO0ykgUXXazZRrsjau7VfIYXzwEAnAq+UcbmZV2B3i0ETU+xlJMfBYwEJsZ8coXY9WV+1koNAAAAAAAAACC3WZ4k0dPC9qt81NbfJGiiyEPIhl86989oAZVgerttvwAJAQDKmjsAAAAAACD4KvMhYLxTESyhGKu/V/pv7UfrkCkaHR2S9DiuLtdO9gIFAAIBAQABAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMKc3VpX3N5c3RlbRpyZXF1ZXN0X2FkZF9zdGFrZV9tdWxfY29pbgAEAQAAAgAAAQMAAQQA1XWtfxjpSEYqXPaY9WTvOUp1KnH+xiSTr4oFXAEsDVABzQqphWFHtsW0/yt9/uXaIKo4JTCZ7xtKZKztIzya/ikzAAAAAAAAACAv6EDhMkSp10iINXTB8bex1wIOs50HNbj5HvXPbzUXPtV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1Q7gIAAAAAAACAlpgAAAAAAAA=");
+    assert_eq!(output.signature, "AJ0VI7sHHrNVhsfuN4KK+e88lOAs3/sXGrpfsHsFr+BEroLP+yAF/FEAr60gscOc4G89a0Bmhj3BcLvajp6guwBqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_sign_withdraw_stake() {
+    let withdraw_stake = Proto::RequestWithdrawStake {
+        staked_sui: Some(Proto::ObjectRef {
+            object_id: "0x043a718774c572bd8a25adbeb1bfcd5c0256ae11cecf9f9c3f925d0e52beaf89".into(),
+            version: 7,
+            object_digest: "J6AHyDbHQQyT4YkmEeWfD7sCvxTqpDEDxmbiWMSm4PNE".into(),
+        }),
+        gas: Some(gas_coin()),
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::request_withdraw_stake(withdraw_stake),
+        gas_budget: 10_000_000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, "AAACAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAAAQEABDpxh3TFcr2KJa2+sb/NXAJWrhHOz5+cP5JdDlK+r4kHAAAAAAAAACD96RbEv/HgpiM6mV1n/2XlANtHc7zVjOr5gs1QjcaCPwEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMKc3VpX3N5c3RlbRZyZXF1ZXN0X3dpdGhkcmF3X3N0YWtlAAIBAAABAQDVda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAHNCqmFYUe2xbT/K33+5dogqjglMJnvG0pkrO0jPJr+KTMAAAAAAAAAIC/oQOEyRKnXSIg1dMHxt7HXAg6znQc1uPke9c9vNRc+1XWtfxjpSEYqXPaY9WTvOUp1KnH+xiSTr4oFXAEsDVDuAgAAAAAAAICWmAAAAAAAAA==");
+    assert_eq!(output.signature, "AJXXWNoZfj/RAJRp54GHVMXVrjjHO4Ri4qdck8XKg8CJVIp2UbpuOA79/JhI3LTDQgJ+c1H8q1n8HdW7gEiqMg9qfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_sign_invalid_params() {
+    // The number of recipients doesn't match the number of amounts.
+    let pay_sui = Proto::PaySui {
+        input_coins: vec![coin1()],
+        recipients: vec![RECIPIENT.into(), SENDER.into()],
+        amounts: vec![10000],
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::pay_sui(pay_sui),
+        gas_budget: 2000,
+        reference_gas_price: 1,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+
+    // The gas coin is required.
+    let withdraw_stake = Proto::RequestWithdrawStake {
+        staked_sui: Some(coin2()),
+        gas: None,
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionType::request_withdraw_stake(withdraw_stake),
+        gas_budget: 10_000_000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
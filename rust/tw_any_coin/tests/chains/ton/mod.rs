@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+mod ton_address;
+mod ton_compile;
+mod ton_sign;
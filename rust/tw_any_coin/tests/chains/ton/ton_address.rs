@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_get_data, test_address_invalid, test_address_normalization, test_address_valid,
+};
+use tw_coin_registry::coin_type::CoinType;
+
+#[test]
+fn test_ton_address_normalization() {
+    test_address_normalization(
+        CoinType::TON,
+        "0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae",
+        "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl",
+    );
+    test_address_normalization(
+        CoinType::TON,
+        "UQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorhqg",
+        "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl",
+    );
+    // Test-only address.
+    test_address_normalization(
+        CoinType::TON,
+        "kQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorvzv",
+        "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl",
+    );
+}
+
+#[test]
+fn test_ton_address_is_valid() {
+    test_address_valid(
+        CoinType::TON,
+        "-1:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae",
+    );
+    test_address_valid(
+        CoinType::TON,
+        "0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae",
+    );
+    test_address_valid(
+        CoinType::TON,
+        "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl",
+    );
+    test_address_valid(
+        CoinType::TON,
+        "Ef_drj6m7jcME0fWTA-OwFC-6F0Le2SuOUQ6ibRc3Vz8HL8H",
+    );
+    // Standard Base64 alphabet.
+    test_address_valid(
+        CoinType::TON,
+        "EQAN6Dr3vziti1Kp9D3aEFqJX4bBVfCaV57Z+9jwKTBXICv8",
+    );
+}
+
+#[test]
+fn test_ton_address_invalid() {
+    test_address_invalid(CoinType::TON, "random string");
+    // Invalid length.
+    test_address_invalid(
+        CoinType::TON,
+        "EQIcIZpPoMnWXd8FbC1KaLtcyIgVUlwsbFK_3P6f5uf_YyzoE",
+    );
+    // Padding is not allowed.
+    test_address_invalid(
+        CoinType::TON,
+        "EQIcIZpPoMnWXd8FbC1KaLtcyIgVUlwsbFK_3P6f5uf_Yyw=",
+    );
+    // Unsupported workchain.
+    test_address_invalid(
+        CoinType::TON,
+        "1:0ccd5119f27f7fe4614476c34f7e5e93c7ae098e577cf2012f8b8043165cb809",
+    );
+    test_address_invalid(
+        CoinType::TON,
+        "EQEMzVEZ8n9_5GFEdsNPfl6Tx64Jjld88gEvi4BDFly4CSyl",
+    );
+    // Invalid tag.
+    test_address_invalid(
+        CoinType::TON,
+        "MwCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorsn8",
+    );
+    // Invalid checksum.
+    test_address_invalid(
+        CoinType::TON,
+        "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorpcF",
+    );
+}
+
+#[test]
+fn test_ton_address_get_data() {
+    test_address_get_data(
+        CoinType::TON,
+        "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl",
+        "8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae",
+    );
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TheOpenNetwork::Proto::mod_SigningInput::OneOfaction_oneof as ActionType;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const PUBLIC_KEY: &str = "a039a97c0301b5af7ed005dfd7b57982bae6f72b8b7919e89790897d54182591";
+
+fn transfer_input() -> Proto::SigningInput<'static> {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q".into(),
+        amount: 10,
+        sequence_number: 6,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        expire_at: 1671132440,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    Proto::SigningInput {
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_ton_compile_transfer() {
+    let input = transfer_input();
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::TON, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "fafeef669d868af0b569fb0531d10f08d3184f0d32faaafdeb31c7d3773e0768"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = "1143e48a5d9a678b3c28aa5aad27a9fce287302f2fb9769f15b5b61c6a7ff4071346fd09e53e1dc325b51b42691f0fa0e4ebfa408dd7a343bd445af72a27480a".decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::TON, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // tx: https://tonscan.org/tx/3Z4tHpXNLyprecgu5aTQHWtY7dpHXEoo11MAX61Xyg0=
+    assert_eq!(output.encoded, "te6cckEBBAEArQABRYgBsLf6vJOEq42xW0AoyWX0K+uBMUcXFDLFqmkDg6k1Io4MAQGcEUPkil2aZ4s8KKparSep/OKHMC8vuXafFbW2HGp/9AcTRv0J5T4dwyW1G0JpHw+g5Ov6QI3Xo0O9RFr3KidICimpoxdjm3UYAAAABgADAgFiYgAzffHi4B365BPJfIJk/F+URKU1UekJ6g4QK02ypVb22YhQAAAAAAAAAAAAAAAAAQMAAA08Nzs=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "3908cf8b570c1d3d261c62620c9f368db11f6e821a07614cff64de2e7319f81b"
+    );
+}
+
+#[test]
+fn test_ton_compile_transfer_wallet_v5r1() {
+    let mut input = transfer_input();
+    if let ActionType::transfer(ref mut transfer) = input.action_oneof {
+        transfer.wallet_version = Proto::WalletVersion::WALLET_V5_R1;
+    }
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::TON, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "00ab94af1a460527afd8d7fcefd5fa36edf931966a669eea4c96f2f03ac1aa8b"
+    );
+}
+
+#[test]
+fn test_ton_compile_transfer_invalid_signature() {
+    let input = transfer_input();
+
+    // The signature of a different message.
+    let signature = "e7cacc51073fbbbf1b83e5adb7c113932334b9d7fb4be17bc1693b9673e26f6282867071f5d67b6bfccbce17cbabe5bd2e32d965fc7df79275efed2c6e4bc60b".decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::TON, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::Error_signing);
+    assert!(output.encoded.is_empty());
+}
@@ -0,0 +1,348 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TheOpenNetwork::Proto::mod_SigningInput::OneOfaction_oneof as ActionType;
+
+const PAY_FEES_SEPARATELY_IGNORE_ERRORS: u32 = Proto::SendMode::PAY_FEES_SEPARATELY as u32
+    | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32;
+const ALL_BALANCE_IGNORE_ERRORS: u32 = Proto::SendMode::ATTACH_ALL_CONTRACT_BALANCE as u32
+    | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32;
+
+#[test]
+fn test_ton_sign_transfer_and_deploy() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQDYW_1eScJVxtitoBRksvoV9cCYo4uKGWLVNIHB1JqRR3n0".into(),
+        amount: 10,
+        mode: PAY_FEES_SEPARATELY_IGNORE_ERRORS,
+        expire_at: 1671135440,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: "63474e5fe9511f1526a50567ce142befc343e71a49b865ac3908f58667319cb8"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://tonscan.org/tx/6ZzWOFKZt_m3kZjbwfbATwLaVwmUOdDp0xjhuY7PO3k=
+    assert_eq!(output.encoded, "te6cckECGgEAA7IAAkWIAM33x4uAd+uQTyXyCZPxflESlNVHpCeoOECtNsqVW9tmHgQBAZznxvGBhoRXhPogxNY8QmHlihJWxg5t6KptqcAIZlVks1r+Z+r1avCWNCeqeLC/oaiVN4mDx/E1+Zhi33G25rcIKamjF/////8AAAAAAAMCAWJiAGwt/q8k4SrjbFbQCjJZfQr64ExRxcUMsWqaQODqTUijiFAAAAAAAAAAAAAAAAABAwAAAgE0BgUAUQAAAAApqaMX9Cx3+TG+og7F0BUHMSdruy4oYJR2YSRbIxnvgTPujUFAART/APSkE/S88sgLBwIBIA0IBPjygwjXGCDTH9Mf0x8C+CO78mTtRNDTH9Mf0//0BNFRQ7ryoVFRuvKiBfkBVBBk+RDyo/gAJKTIyx9SQMsfUjDL/1IQ9ADJ7VT4DwHTByHAAJ9sUZMg10qW0wfUAvsA6DDgIcAB4wAhwALjAAHAA5Ew4w0DpMjLHxLLH8v/DAsKCQAK9ADJ7VQAbIEBCNcY+gDTPzBSJIEBCPRZ8qeCEGRzdHJwdIAYyMsFywJQBc8WUAP6AhPLassfEss/yXP7AABwgQEI1xj6ANM/yFQgR4EBCPRR8qeCEG5vdGVwdIAYyMsFywJQBs8WUAT6AhTLahLLH8s/yXP7AAIAbtIH+gDU1CL5AAXIygcVy//J0Hd0gBjIywXLAiLPFlAF+gIUy2sSzMzJc/sAyEAUgQEI9FHypwICAUgXDgIBIBAPAFm9JCtvaiaECAoGuQ+gIYRw1AgIR6STfSmRDOaQPp/5g3gSgBt4EBSJhxWfMYQCASASEQARuMl+1E0NcLH4AgFYFhMCASAVFAAZrx32omhAEGuQ64WPwAAZrc52omhAIGuQ64X/wAA9sp37UTQgQFA1yH0BDACyMoHy//J0AGBAQj0Cm+hMYALm0AHQ0wMhcbCSXwTgItdJwSCSXwTgAtMfIYIQcGx1Z70ighBkc3RyvbCSXwXgA/pAMCD6RAHIygfL/8nQ7UTQgQFA1yH0BDBcgQEI9ApvoTGzkl8H4AXTP8glghBwbHVnupI4MOMNA4IQZHN0crqSXwbjDRkYAIpQBIEBCPRZMO1E0IEBQNcgyAHPFvQAye1UAXKwjiOCEGRzdHKDHrFwgBhQBcsFUAPPFiP6AhPLassfyz/JgED7AJJfA+IAeAH6APQEMPgnbyIwUAqhIb7y4FCCEHBsdWeDHrFwgBhQBMsFJs8WWPoCGfQAy2kXyx9SYMs/IMmAQPsABkuguWU=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "b3d9462c13a8c67e19b62002447839c386de51415ace3ff6473b1e6294299819"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_ordinary() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q".into(),
+        amount: 10,
+        sequence_number: 6,
+        mode: PAY_FEES_SEPARATELY_IGNORE_ERRORS,
+        expire_at: 1671132440,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: "c38f49de2fb13223a9e7d37d5d0ffbdd89a5eb7c8b0ee4d1c299f2cefe7dc4a0"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://tonscan.org/tx/3Z4tHpXNLyprecgu5aTQHWtY7dpHXEoo11MAX61Xyg0=
+    assert_eq!(output.encoded, "te6cckEBBAEArQABRYgBsLf6vJOEq42xW0AoyWX0K+uBMUcXFDLFqmkDg6k1Io4MAQGcEUPkil2aZ4s8KKparSep/OKHMC8vuXafFbW2HGp/9AcTRv0J5T4dwyW1G0JpHw+g5Ov6QI3Xo0O9RFr3KidICimpoxdjm3UYAAAABgADAgFiYgAzffHi4B365BPJfIJk/F+URKU1UekJ6g4QK02ypVb22YhQAAAAAAAAAAAAAAAAAQMAAA08Nzs=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "3908cf8b570c1d3d261c62620c9f368db11f6e821a07614cff64de2e7319f81b"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_all_balance() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q".into(),
+        amount: 0,
+        sequence_number: 7,
+        mode: ALL_BALANCE_IGNORE_ERRORS,
+        expire_at: 1681102222,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: "c38f49de2fb13223a9e7d37d5d0ffbdd89a5eb7c8b0ee4d1c299f2cefe7dc4a0"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://tonscan.org/tx/cVcXgI9DWNWlN2iyTsteaWJckTswVqWZnRVvX5krXeA=
+    assert_eq!(output.encoded, "te6cckEBBAEArAABRYgBsLf6vJOEq42xW0AoyWX0K+uBMUcXFDLFqmkDg6k1Io4MAQGc58rMUQc/u78bg+Wtt8ETkyM0udf7S+F7wWk7lnPib2KChnBx9dZ7a/zLzhfLq+W9LjLZZfx995J17+0sbkvGCympoxdkM5WOAAAABwCCAgFgYgAzffHi4B365BPJfIJk/F+URKU1UekJ6g4QK02ypVb22YAAAAAAAAAAAAAAAAABAwAAo/WoAQ==");
+    assert_eq!(
+        output.hash.to_hex(),
+        "d5c5980c9083f697a7f114426effbbafac6d5c88554297d290eb65c8def3008e"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_all_balance_non_bounceable() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "UQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts4DV".into(),
+        amount: 0,
+        sequence_number: 8,
+        mode: ALL_BALANCE_IGNORE_ERRORS,
+        expire_at: 1681102222,
+        bounceable: false,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: "c38f49de2fb13223a9e7d37d5d0ffbdd89a5eb7c8b0ee4d1c299f2cefe7dc4a0"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://tonscan.org/tx/0sJkPKu6u6uObVRuSWGd_bVGiyy5lJuzEKDqSXifQEA=
+    assert_eq!(output.encoded, "te6cckEBBAEArAABRYgBsLf6vJOEq42xW0AoyWX0K+uBMUcXFDLFqmkDg6k1Io4MAQGcRQQvxdU1u4QoE2Pas0AsZQMc9lea3+wtSvaC6QfLUlyJ9oISMCFnaErpyFHelDhPu4iuZqhkoLwjkR1VYhFSCimpoxdkM5WOAAAACACCAgFgQgAzffHi4B365BPJfIJk/F+URKU1UekJ6g4QK02ypVb22YAAAAAAAAAAAAAAAAABAwAAcZzwMg==");
+    assert_eq!(
+        output.hash.to_hex(),
+        "e9c816780fa8e578bae309c2e098db8eb16aa25545b3ad2b61bb711ec9562795"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_with_ascii_comment() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q".into(),
+        amount: 10,
+        sequence_number: 10,
+        mode: PAY_FEES_SEPARATELY_IGNORE_ERRORS,
+        expire_at: 1681102222,
+        comment: "test comment".into(),
+        bounceable: true,
+    };
+    let input = Proto::SigningInput {
+        private_key: "c38f49de2fb13223a9e7d37d5d0ffbdd89a5eb7c8b0ee4d1c299f2cefe7dc4a0"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://tonscan.org/tx/9wjD-VrgEDpa0D9u1g03KSD7kvTNsxRocR7LEdQtCNQ=
+    assert_eq!(output.encoded, "te6cckEBBAEAvQABRYgBsLf6vJOEq42xW0AoyWX0K+uBMUcXFDLFqmkDg6k1Io4MAQGcY4XlvKqu7spxyjL6vyBSKjbskDgqkHhqBsdTe900RGrzExtpvwc04j94v8HOczEWSMCXjTXk0z+CVUXSL54qCimpoxdkM5WOAAAACgADAgFiYgAzffHi4B365BPJfIJk/F+URKU1UekJ6g4QK02ypVb22YhQAAAAAAAAAAAAAAAAAQMAIAAAAAB0ZXN0IGNvbW1lbnTRyqW6");
+    assert_eq!(
+        output.hash.to_hex(),
+        "a8c6943d5587f590c43fcdb0e894046f1965c615e19bcaf0c8407e9ccb74518d"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_with_utf8_comment() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q".into(),
+        amount: 10,
+        sequence_number: 11,
+        mode: PAY_FEES_SEPARATELY_IGNORE_ERRORS,
+        expire_at: 1681102222,
+        comment: "тестовый комментарий".into(),
+        bounceable: true,
+    };
+    let input = Proto::SigningInput {
+        private_key: "c38f49de2fb13223a9e7d37d5d0ffbdd89a5eb7c8b0ee4d1c299f2cefe7dc4a0"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://tonscan.org/tx/VOTt8HW6eRuWHmuM_P3aC-Dy4TMu4cCRePoTAiDfcoQ=
+    assert_eq!(output.encoded, "te6cckEBBAEA2AABRYgBsLf6vJOEq42xW0AoyWX0K+uBMUcXFDLFqmkDg6k1Io4MAQGchoDa7EdGQuPuehHy3+0X9WNVEvYxdBtaEWn15oYUX8PEKyzztYy94Xq0T2XdhVvj2H7PTSQ+D/Ny1IBRCxk0BimpoxdkM5WOAAAACwADAgFiYgAzffHi4B365BPJfIJk/F+URKU1UekJ6g4QK02ypVb22YhQAAAAAAAAAAAAAAAAAQMAVgAAAADRgtC10YHRgtC+0LLRi9C5INC60L7QvNC80LXQvdGC0LDRgNC40LmZDxDN");
+    assert_eq!(
+        output.hash.to_hex(),
+        "1091dfae81583d3972825633592c24eab0d3d74c91f60fda9d4afe7535103633"
+    );
+}
+
+#[test]
+fn test_ton_sign_jetton_transfer() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 100_000_000,
+        mode: PAY_FEES_SEPARATELY_IGNORE_ERRORS,
+        expire_at: 1787693046,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    let jetton_transfer = Proto::JettonTransfer {
+        transfer: Some(transfer),
+        query_id: 69,
+        // Transfer 1 testtwt (decimal precision is 9).
+        jetton_amount: 1_000_000_000,
+        to_owner: "EQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnDx8".into(),
+        // Send unused toncoins back to the sender.
+        response_address: "EQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llTXk".into(),
+        forward_amount: 1,
+    };
+    let input = Proto::SigningInput {
+        private_key: "c054900a527538c1b4325688a421c0469b171c29f23a62da216e90b0df2412ee"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::jetton_transfer(jetton_transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://testnet.tonscan.org/tx/2HOPGAXhez3v6sdfj-5p8mPHX4S4T0CgxVbm0E2swxE=
+    assert_eq!(output.encoded, "te6cckECGgEABAoAAkWIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqHgQBAZz3iNHD1z2mxbtpFAtmbVevYMnB4yHPkF3WAsL3KHcrqCw0SWezOg4lVz1zzSReeFDx98ByAqY9+eR5VF3xyugAKamjF/////8AAAAAAAMCAWhiADE0H4edqbg+3ilJg24an7WuHHVDEReutlMad886roPzIC+vCAAAAAAAAAAAAAAAAAABAwCqD4p+pQAAAAAAAABFQ7msoAgAC4GWcwteHQM+mcQoV+aZ+myCd1jYqUiawhCzuxMdEzkAFoogyrkCban+t9HUgVPCw1YfEGQ6ktObBRqKfkWPiWVCAgIBNAYFAFEAAAAAKamjF85qYGF49E2fH+mpBWIHNbR0MLL/7VYtXVWGBtVMvqLfQAEU/wD0pBP0vPLICwcCASANCAT48oMI1xgg0x/TH9MfAvgju/Jk7UTQ0x/TH9P/9ATRUUO68qFRUbryogX5AVQQZPkQ8qP4ACSkyMsfUkDLH1Iwy/9SEPQAye1U+A8B0wchwACfbFGTINdKltMH1AL7AOgw4CHAAeMAIcAC4wABwAORMOMNA6TIyx8Syx/L/wwLCgkACvQAye1UAGyBAQjXGPoA0z8wUiSBAQj0WfKnghBkc3RycHSAGMjLBcsCUAXPFlAD+gITy2rLHxLLP8lz+wAAcIEBCNcY+gDTP8hUIEeBAQj0UfKnghBub3RlcHSAGMjLBcsCUAbPFlAE+gIUy2oSyx/LP8lz+wACAG7SB/oA1NQi+QAFyMoHFcv/ydB3dIAYyMsFywIizxZQBfoCFMtrEszMyXP7AMhAFIEBCPRR8qcCAgFIFw4CASAQDwBZvSQrb2omhAgKBrkPoCGEcNQICEekk30pkQzmkD6f+YN4EoAbeBAUiYcVnzGEAgEgEhEAEbjJftRNDXCx+AIBWBYTAgEgFRQAGa8d9qJoQBBrkOuFj8AAGa3OdqJoQCBrkOuF/8AAPbKd+1E0IEBQNch9AQwAsjKB8v/ydABgQEI9ApvoTGAC5tAB0NMDIXGwkl8E4CLXScEgkl8E4ALTHyGCEHBsdWe9IoIQZHN0cr2wkl8F4AP6QDAg+kQByMoHy//J0O1E0IEBQNch9AQwXIEBCPQKb6Exs5JfB+AF0z/IJYIQcGx1Z7qSODDjDQOCEGRzdHK6kl8G4w0ZGACKUASBAQj0WTDtRNCBAUDXIMgBzxb0AMntVAFysI4jghBkc3Rygx6xcIAYUAXLBVADzxYj+gITy2rLH8s/yYBA+wCSXwPiAHgB+gD0BDD4J28iMFAKoSG+8uBQghBwbHVngx6xcIAYUATLBSbPFlj6Ahn0AMtpF8sfUmDLPyDJgED7AAaz0nfS");
+    assert_eq!(
+        output.hash.to_hex(),
+        "3e4dac37acdc99ca670b3747ab2730e818727d9d25c80d3987abe501356d0da0"
+    );
+}
+
+#[test]
+fn test_ton_sign_jetton_transfer_with_comment() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 100_000_000,
+        sequence_number: 1,
+        mode: PAY_FEES_SEPARATELY_IGNORE_ERRORS,
+        expire_at: 1787693046,
+        comment: "test comment".into(),
+        bounceable: true,
+    };
+    let jetton_transfer = Proto::JettonTransfer {
+        transfer: Some(transfer),
+        // Transfer 0.5 testtwt (decimal precision is 9).
+        jetton_amount: 500_000_000,
+        to_owner: "EQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnDx8".into(),
+        response_address: "EQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llTXk".into(),
+        forward_amount: 1,
+        ..Proto::JettonTransfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: "c054900a527538c1b4325688a421c0469b171c29f23a62da216e90b0df2412ee"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::jetton_transfer(jetton_transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    // tx: https://testnet.tonscan.org/tx/Er_oT5R3QK7D-qVPBKUGkJAOOq6ayVls-mgEphpI9Ck=
+    assert_eq!(output.encoded, "te6cckECBAEAARUAAUWIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqDAEBnGiFlaLItV573gJqBvctP5j3jVKlLuxmO+pnW0QGlXjXgzjw5YeTNwRG9upJHOl6GA3pFetKNojqGzfkxku+owUpqaMXao4H9gAAAAEAAwIBaGIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MgL68IAAAAAAAAAAAAAAAAAAEDAMoPin6lAAAAAAAAAABB3NZQCAALgZZzC14dAz6ZxChX5pn6bIJ3WNipSJrCELO7Ex0TOQAWiiDKuQJtqf630dSBU8LDVh8QZDqS05sFGop+RY+JZUICAAAAAHRlc3QgY29tbWVudG/bd5c=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "c98c205c8dd37d9a6ab5db6162f5b9d37cefa067de24a765154a5eb7a359f22f"
+    );
+}
+
+#[test]
+fn test_ton_sign_invalid_wallet_version() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V3_R2,
+        dest: "EQDYW_1eScJVxtitoBRksvoV9cCYo4uKGWLVNIHB1JqRR3n0".into(),
+        amount: 10,
+        mode: PAY_FEES_SEPARATELY_IGNORE_ERRORS,
+        expire_at: 1671135440,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: "63474e5fe9511f1526a50567ce142befc343e71a49b865ac3908f58667319cb8"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+    assert!(output.encoded.is_empty());
+}
+
+#[test]
+fn test_ton_sign_invalid_mode() {
+    let transfer = Proto::Transfer {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        dest: "EQDYW_1eScJVxtitoBRksvoV9cCYo4uKGWLVNIHB1JqRR3n0".into(),
+        amount: 10,
+        // Mode must fit into `uint8`.
+        mode: 256,
+        expire_at: 1671135440,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: "63474e5fe9511f1526a50567ce142befc343e71a49b865ac3908f58667319cb8"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        action_oneof: ActionType::transfer(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
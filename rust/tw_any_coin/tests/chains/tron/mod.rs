@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+mod tron_address;
+mod tron_compile;
+mod tron_message_sign;
+mod tron_sign;
+
+const TRON_COIN_TYPE: u32 = 195;
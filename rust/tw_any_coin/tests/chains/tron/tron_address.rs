@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_get_data, test_address_invalid, test_address_normalization, test_address_valid,
+};
+use tw_coin_registry::coin_type::CoinType;
+
+#[test]
+fn test_tron_address_normalization() {
+    test_address_normalization(
+        CoinType::Tron,
+        "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeC",
+        "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeC",
+    );
+}
+
+#[test]
+fn test_tron_address_is_valid() {
+    test_address_valid(CoinType::Tron, "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeC");
+    test_address_valid(CoinType::Tron, "THRF3GuPnvvPzKoaT8pJex5XHmo8NNbCb3");
+}
+
+#[test]
+fn test_tron_address_invalid() {
+    test_address_invalid(CoinType::Tron, "abc");
+    test_address_invalid(CoinType::Tron, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    test_address_invalid(CoinType::Tron, "175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W");
+    test_address_invalid(CoinType::Tron, "2MegQ6oqSda2tTagdEzBA");
+    // Invalid checksum.
+    test_address_invalid(CoinType::Tron, "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeD");
+}
+
+#[test]
+fn test_tron_address_get_data() {
+    test_address_get_data(
+        CoinType::Tron,
+        "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeC",
+        "415cd0fb0ab3ce40f3051414c604b27756e69e43db",
+    );
+}
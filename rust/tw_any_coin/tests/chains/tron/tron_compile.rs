@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Tron::Proto;
+use tw_proto::Tron::Proto::mod_Transaction::OneOfcontract_oneof as ContractType;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const PUBLIC_KEY: &str = "041f3ea174cafbe4e8f21893d0c4604ff79e2539c91fd2419e0b347a61a5020a06ff413d68cf6705b4ecfd1a9db131cff54bb614f8cbf6367f46c250267e541b49";
+const SIGNATURE: &str = "77f5eabde31e739d34a66914540f1756981dc7d782c9656f5e14e53b59a15371603a183aa12124adeee7991bf55acc8e488a6ca04fb393b1a8ac16610eeafdfc00";
+
+fn transfer_asset_input() -> Proto::SigningInput<'static> {
+    let transfer = Proto::TransferAssetContract {
+        asset_name: "1000959".into(),
+        owner_address: "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeC".into(),
+        to_address: "THTR75o8xXAgCTQqpiot2AFRAjvW1tSbVV".into(),
+        amount: 4,
+    };
+    let block_header = Proto::BlockHeader {
+        timestamp: 1541890116000,
+        tx_trie_root: "845ab51bf63c2c21ee71a4dc0ac3781619f07a7cd05e1e0bd8ba828979332ffa"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        parent_hash: "00000000003cb800a7e69e9144e3d16f0cf33f33a95c7ce274097822c67243c1"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        number: 3979265,
+        witness_address: "41b487cdc02de90f15ac89a68c82f44cbfe3d915ea"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        version: 3,
+    };
+    Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            timestamp: 1539295479000,
+            expiration: 1541890116000 + 10 * 60 * 60 * 1000,
+            block_header: Some(block_header),
+            contract_oneof: ContractType::transfer_asset(transfer),
+            ..Proto::Transaction::default()
+        }),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_tron_compile_transfer_asset() {
+    let input = transfer_asset_input();
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Tron, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data.to_hex(), "0a02b80122080e2bc08d550f5f5840a0b5d58ff02c5a730802126f0a32747970652e676f6f676c65617069732e636f6d2f70726f746f636f6c2e5472616e736665724173736574436f6e747261637412390a07313030303935391215415cd0fb0ab3ce40f3051414c604b27756e69e43db1a1541521ea197907927725ef36d70f25f850d1659c7c7200470d889a4a9e62c");
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "546a3d07164c624809cf4e564a083a7a7974bb3c4eff6bb3e278b0ca21083fcb"
+    );
+
+    // Step 3: Compile transaction info
+    let signature = SIGNATURE.decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Tron, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "546a3d07164c624809cf4e564a083a7a7974bb3c4eff6bb3e278b0ca21083fcb"
+    );
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+    assert_eq!(
+        output.json,
+        r#"{"raw_data":{"contract":[{"parameter":{"type_url":"type.googleapis.com/protocol.TransferAssetContract","value":{"amount":4,"asset_name":"31303030393539","owner_address":"415cd0fb0ab3ce40f3051414c604b27756e69e43db","to_address":"41521ea197907927725ef36d70f25f850d1659c7c7"}},"type":"TransferAssetContract"}],"expiration":1541926116000,"ref_block_bytes":"b801","ref_block_hash":"0e2bc08d550f5f58","timestamp":1539295479000},"signature":["77f5eabde31e739d34a66914540f1756981dc7d782c9656f5e14e53b59a15371603a183aa12124adeee7991bf55acc8e488a6ca04fb393b1a8ac16610eeafdfc00"],"txID":"546a3d07164c624809cf4e564a083a7a7974bb3c4eff6bb3e278b0ca21083fcb"}"#
+    );
+}
+
+#[test]
+fn test_tron_compile_inconsistent_signatures() {
+    let input = transfer_asset_input();
+
+    let signature = SIGNATURE.decode_hex().unwrap();
+    let public_key = PUBLIC_KEY.decode_hex().unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Tron,
+        &input,
+        vec![signature.clone(), signature],
+        vec![public_key],
+    );
+    assert_eq!(output.error, SigningError::Error_no_support_n2n);
+    assert!(output.json.is_empty());
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::tron::TRON_COIN_TYPE;
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::SigningErrorType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Tron::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "75065f100e38d3f3b4c5c4235834ba8216de62272a4f03532c44b31a5734360a";
+const PUBLIC_KEY: &str = "04a796f38e9219c63d5e454592a8b12d89a655685e8611c6a3a383b2f24679d009bf77ac21b7bd4515f1b9f7aa407211cf1ce8a4b5619bf4dc6de1084fc3570d5e";
+const MESSAGE: &str = "Hello World";
+const SIGNATURE: &str = "9bb6d11ec8a6a3fb686a8f55b123e7ec4e9746a26157f6f9e854dd72f5683b450397a7b0a9653865658de8f9243f877539882891bad30c7286c3bf5622b900471b";
+const SIGNATURE_V2: &str = "bc0753c070cc55693097df11bc11e1a7c4bd5e1a40b9dc94c75568e59bcc9d6b50a7873ef25b469e494490a54de37327b4bc7fc825c81a377b555e34fb7261ba1c";
+
+fn message_signing_input(message_type: Proto::MessageType) -> Proto::MessageSigningInput<'static> {
+    Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.into(),
+        message_type,
+    }
+}
+
+fn sign_message(input: Proto::MessageSigningInput<'_>) -> String {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output =
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), TRON_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    output.signature.to_string()
+}
+
+fn message_data_hash(input: Proto::MessageSigningInput<'_>) -> String {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), TRON_COIN_TYPE)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    output.data_hash.to_hex()
+}
+
+fn verify_message(input: Proto::MessageVerifyingInput<'_>) -> bool {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), TRON_COIN_TYPE) }
+}
+
+#[test]
+fn test_tron_sign_message() {
+    let input = message_signing_input(Proto::MessageType::MessageType_legacy);
+    assert_eq!(sign_message(input), SIGNATURE);
+}
+
+#[test]
+fn test_tron_sign_message_v2() {
+    let input = message_signing_input(Proto::MessageType::MessageType_v2);
+    assert_eq!(sign_message(input), SIGNATURE_V2);
+}
+
+#[test]
+fn test_tron_message_pre_image_hashes() {
+    let input = message_signing_input(Proto::MessageType::MessageType_legacy);
+    assert_eq!(
+        message_data_hash(input),
+        "0653f8eda7833e556736d2735220180b5e3bc16e2e3615cfe617c18264b1be4c"
+    );
+
+    // `keccak256("\x19TRON Signed Message:\n11Hello World")`.
+    let input = message_signing_input(Proto::MessageType::MessageType_v2);
+    assert_eq!(
+        message_data_hash(input),
+        "a8383a95afcc961b6c36437aff5c8e38a3e35a0ab36ec8630c42fd11f455eac5"
+    );
+}
+
+#[test]
+fn test_tron_verify_message() {
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE.into(),
+        message_type: Proto::MessageType::MessageType_legacy,
+    };
+    assert!(verify_message(input.clone()));
+
+    // The message is changed.
+    let input = Proto::MessageVerifyingInput {
+        message: "Hello Tron".into(),
+        ..input
+    };
+    assert!(!verify_message(input));
+}
+
+#[test]
+fn test_tron_verify_message_v2() {
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: SIGNATURE_V2.into(),
+        message_type: Proto::MessageType::MessageType_v2,
+    };
+    assert!(verify_message(input.clone()));
+
+    // The signature was produced with the V2 scheme.
+    let input = Proto::MessageVerifyingInput {
+        message_type: Proto::MessageType::MessageType_legacy,
+        ..input
+    };
+    assert!(!verify_message(input));
+}
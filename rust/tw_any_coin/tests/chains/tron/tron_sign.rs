@@ -0,0 +1,460 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_number::U256;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Tron::Proto;
+use tw_proto::Tron::Proto::mod_Transaction::OneOfcontract_oneof as ContractType;
+
+const PRIVATE_KEY: &str = "2d8f68944bdbfbc0769542fba8fc2d2a3de67393334471624364c7006da2aa54";
+const STAKING_PRIVATE_KEY: &str =
+    "75065f100e38d3f3b4c5c4235834ba8216de62272a4f03532c44b31a5734360a";
+const OWNER_ADDRESS: &str = "TJRyWwFs9wTFGZg3JbrVriFbNfCug5tDeC";
+const TO_ADDRESS: &str = "THTR75o8xXAgCTQqpiot2AFRAjvW1tSbVV";
+const STAKING_OWNER_ADDRESS: &str = "TWWb9EjUWai17YEVB7FR8hreupYJKG9sMR";
+const RECEIVER_ADDRESS: &str = "TPFfHr1CWfTcS9eugQXQmvqHNGufnjxjXP";
+const TIMESTAMP: i64 = 1539295479000;
+const EXPIRATION: i64 = TIMESTAMP + 10 * 60 * 60 * 1000;
+
+fn block_header(
+    timestamp: i64,
+    tx_trie_root: &str,
+    parent_hash: &str,
+    number: i64,
+    witness_address: &str,
+    version: i32,
+) -> Proto::BlockHeader<'static> {
+    Proto::BlockHeader {
+        timestamp,
+        tx_trie_root: tx_trie_root.decode_hex().unwrap().into(),
+        parent_hash: parent_hash.decode_hex().unwrap().into(),
+        number,
+        witness_address: witness_address.decode_hex().unwrap().into(),
+        version,
+    }
+}
+
+fn default_block_header() -> Proto::BlockHeader<'static> {
+    block_header(
+        1539295479000,
+        "64288c2db0641316762a99dbb02ef7c90f968b60f9f2e410835980614332f86d",
+        "00000000002f7b3af4f5f8b9e23a30c530f719f165b742e7358536b280eead2d",
+        3111739,
+        "415863f6091b8e71766da808b1dd3159790f61de7d",
+        3,
+    )
+}
+
+fn default_input(contract_oneof: ContractType<'static>) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            timestamp: TIMESTAMP,
+            expiration: EXPIRATION,
+            block_header: Some(default_block_header()),
+            contract_oneof,
+            ..Proto::Transaction::default()
+        }),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_tron_sign_transfer() {
+    let transfer = Proto::TransferContract {
+        owner_address: OWNER_ADDRESS.into(),
+        to_address: TO_ADDRESS.into(),
+        amount: 2000000,
+    };
+    let input = default_input(ContractType::transfer(transfer));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "dc6f6d9325ee44ab3c00528472be16e1572ab076aa161ccd12515029869d0451"
+    );
+    assert_eq!(output.signature.to_hex(), "ede769f6df28aefe6a846be169958c155e23e7e5c9621d2e8dce1719b4d952b63e8a8bf9f00e41204ac1bf69b1a663dacdf764367e48e4a5afcd6b055a747fb200");
+    assert_eq!(output.ref_block_bytes.to_hex(), "7b3b");
+    assert_eq!(output.ref_block_hash.to_hex(), "b21ace8d6ac20e7e");
+}
+
+#[test]
+fn test_tron_sign_transfer_with_fee_limit() {
+    let transfer = Proto::TransferContract {
+        owner_address: OWNER_ADDRESS.into(),
+        to_address: TO_ADDRESS.into(),
+        amount: 2000000,
+    };
+    let mut input = default_input(ContractType::transfer(transfer));
+    input.transaction.as_mut().unwrap().fee_limit = 10000000;
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "2d946d7bc4f139edeffca4c5ec13afa97c9004a665287e936c23c0b4eba245b9"
+    );
+    assert_eq!(
+        output.json,
+        r#"{"raw_data":{"contract":[{"parameter":{"type_url":"type.googleapis.com/protocol.TransferContract","value":{"amount":2000000,"owner_address":"415cd0fb0ab3ce40f3051414c604b27756e69e43db","to_address":"41521ea197907927725ef36d70f25f850d1659c7c7"}},"type":"TransferContract"}],"expiration":1539331479000,"fee_limit":10000000,"ref_block_bytes":"7b3b","ref_block_hash":"b21ace8d6ac20e7e","timestamp":1539295479000},"signature":["56e9d68619c00e44db36cdcddf4128de8fcc4ea68f841f9ab770735f97266712211597366ab3065079fc5f6dadac6ad42810e7aa426cc1da4d82f27523ddb17101"],"txID":"2d946d7bc4f139edeffca4c5ec13afa97c9004a665287e936c23c0b4eba245b9"}"#
+    );
+}
+
+#[test]
+fn test_tron_sign_transfer_asset() {
+    let transfer = Proto::TransferAssetContract {
+        asset_name: "1000959".into(),
+        owner_address: OWNER_ADDRESS.into(),
+        to_address: TO_ADDRESS.into(),
+        amount: 4,
+    };
+    let mut input = default_input(ContractType::transfer_asset(transfer));
+    let tx = input.transaction.as_mut().unwrap();
+    tx.expiration = 1541890116000 + 10 * 60 * 60 * 1000;
+    tx.block_header = Some(block_header(
+        1541890116000,
+        "845ab51bf63c2c21ee71a4dc0ac3781619f07a7cd05e1e0bd8ba828979332ffa",
+        "00000000003cb800a7e69e9144e3d16f0cf33f33a95c7ce274097822c67243c1",
+        3979265,
+        "41b487cdc02de90f15ac89a68c82f44cbfe3d915ea",
+        3,
+    ));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "546a3d07164c624809cf4e564a083a7a7974bb3c4eff6bb3e278b0ca21083fcb"
+    );
+    assert_eq!(output.signature.to_hex(), "77f5eabde31e739d34a66914540f1756981dc7d782c9656f5e14e53b59a15371603a183aa12124adeee7991bf55acc8e488a6ca04fb393b1a8ac16610eeafdfc00");
+    assert_eq!(
+        output.json,
+        r#"{"raw_data":{"contract":[{"parameter":{"type_url":"type.googleapis.com/protocol.TransferAssetContract","value":{"amount":4,"asset_name":"31303030393539","owner_address":"415cd0fb0ab3ce40f3051414c604b27756e69e43db","to_address":"41521ea197907927725ef36d70f25f850d1659c7c7"}},"type":"TransferAssetContract"}],"expiration":1541926116000,"ref_block_bytes":"b801","ref_block_hash":"0e2bc08d550f5f58","timestamp":1539295479000},"signature":["77f5eabde31e739d34a66914540f1756981dc7d782c9656f5e14e53b59a15371603a183aa12124adeee7991bf55acc8e488a6ca04fb393b1a8ac16610eeafdfc00"],"txID":"546a3d07164c624809cf4e564a083a7a7974bb3c4eff6bb3e278b0ca21083fcb"}"#
+    );
+}
+
+#[test]
+fn test_tron_sign_tx_id() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        txId: "546a3d07164c624809cf4e564a083a7a7974bb3c4eff6bb3e278b0ca21083fcb".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "546a3d07164c624809cf4e564a083a7a7974bb3c4eff6bb3e278b0ca21083fcb"
+    );
+    assert_eq!(output.signature.to_hex(), "77f5eabde31e739d34a66914540f1756981dc7d782c9656f5e14e53b59a15371603a183aa12124adeee7991bf55acc8e488a6ca04fb393b1a8ac16610eeafdfc00");
+    assert!(output.json.is_empty());
+}
+
+#[test]
+fn test_tron_sign_transfer_trc20() {
+    let transfer = Proto::TransferTRC20Contract {
+        contract_address: TO_ADDRESS.into(),
+        owner_address: OWNER_ADDRESS.into(),
+        to_address: "TW1dU4L3eNm7Lw8WvieLKEHpXWAussRG9Z".into(),
+        amount: U256::from(1000_u64).to_big_endian_compact().into(),
+    };
+    let input = default_input(ContractType::transfer_trc20_contract(transfer));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // The recipient is ABI-encoded as a 20-byte address without the `0x41` prefix.
+    assert_eq!(
+        output.id.to_hex(),
+        "9998912c827b1c69b26c26894dde1ba4b29e393e5c71d7686002cf02cdd6fe3b"
+    );
+    assert_eq!(
+        output.json,
+        r#"{"raw_data":{"contract":[{"parameter":{"type_url":"type.googleapis.com/protocol.TriggerSmartContract","value":{"contract_address":"41521ea197907927725ef36d70f25f850d1659c7c7","data":"a9059cbb000000000000000000000000dbd7c53729b3310e1843083000fa84abad99696100000000000000000000000000000000000000000000000000000000000003e8","owner_address":"415cd0fb0ab3ce40f3051414c604b27756e69e43db"}},"type":"TriggerSmartContract"}],"expiration":1539331479000,"ref_block_bytes":"7b3b","ref_block_hash":"b21ace8d6ac20e7e","timestamp":1539295479000},"signature":["2c2ba736b8f89b8d0d2d3ef2ed8a2ade0993cd946554cc43e9aef6d0b23ac3603839741d375a6683a7b4896f65a655a97d4fda8ea9ba4f6f0951d33dec521b8801"],"txID":"9998912c827b1c69b26c26894dde1ba4b29e393e5c71d7686002cf02cdd6fe3b"}"#
+    );
+}
+
+#[test]
+fn test_tron_sign_trigger_smart_contract() {
+    let trigger = Proto::TriggerSmartContract {
+        owner_address: OWNER_ADDRESS.into(),
+        contract_address: TO_ADDRESS.into(),
+        call_value: 0,
+        data: "736f6d652064617461".decode_hex().unwrap().into(),
+        call_token_value: 10000,
+        token_id: 1,
+    };
+    let input = default_input(ContractType::trigger_smart_contract(trigger));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "9927d3daae10ad001b25ef3c1bb03073c928cc0e0823f6f3ce404c2b03ce3570"
+    );
+    assert_eq!(output.signature.to_hex(), "21a99aafeabdddfdfae86538df048d120a83eb36bbcf5656595919ba6afddacd0a07d0ba051ae80337613174b109f36cb583b6e46ee5aecf6ffe3392fdbb8a2a01");
+}
+
+#[test]
+fn test_tron_sign_vote_witness() {
+    let vote_witness = Proto::VoteWitnessContract {
+        owner_address: OWNER_ADDRESS.into(),
+        votes: vec![Proto::mod_VoteWitnessContract::Vote {
+            vote_address: TO_ADDRESS.into(),
+            vote_count: 3,
+        }],
+        support: true,
+    };
+    let input = default_input(ContractType::vote_witness(vote_witness));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "3f923e9dd9571a66624fafeda27baa3e00aba1709d3fdc5c97c77b81fda18c1f"
+    );
+    assert_eq!(
+        output.json,
+        r#"{"raw_data":{"contract":[{"parameter":{"type_url":"type.googleapis.com/protocol.VoteWitnessContract","value":{"owner_address":"415cd0fb0ab3ce40f3051414c604b27756e69e43db","support":true,"votes":[{"vote_address":"41521ea197907927725ef36d70f25f850d1659c7c7","vote_count":3}]}},"type":"VoteWitnessContract"}],"expiration":1539331479000,"ref_block_bytes":"7b3b","ref_block_hash":"b21ace8d6ac20e7e","timestamp":1539295479000},"signature":["79ec1073ae1319ef9303a2f5a515876cfd67f8f0e155bdbde1115d391c05358a3c32f148bfafacf07e1619aaed728d9ffbc2c7e4a5046003c7b74feb86fc68e400"],"txID":"3f923e9dd9571a66624fafeda27baa3e00aba1709d3fdc5c97c77b81fda18c1f"}"#
+    );
+}
+
+#[test]
+fn test_tron_sign_freeze_balance_v2() {
+    // Successfully broadcasted https://nile.tronscan.org/#/transaction/3a46321487ce1fd115da38b3431006ea529f65ef2507f19233f5a23c05abd01d
+    let freeze = Proto::FreezeBalanceV2Contract {
+        owner_address: STAKING_OWNER_ADDRESS.into(),
+        frozen_balance: 10000000,
+        resource: "ENERGY".into(),
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            timestamp: 1676983541337,
+            expiration: 1676983599000,
+            block_header: Some(block_header(
+                1676983485000,
+                "9b54db7f84bd19bbad9ff1fccef894c1aade6879450e9e9e2accec751eaa1f52",
+                "00000000020cd4c13a67497a3a433a3105bc5a73a041ee3da98407d5a2a2bf1b",
+                34395330,
+                "4150d3765e4e670727ebac9d5b598f74b75a3d54a7",
+                26,
+            )),
+            contract_oneof: ContractType::freeze_balance_v2(freeze),
+            ..Proto::Transaction::default()
+        }),
+        private_key: STAKING_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "3a46321487ce1fd115da38b3431006ea529f65ef2507f19233f5a23c05abd01d"
+    );
+    assert_eq!(
+        output.json,
+        r#"{"raw_data":{"contract":[{"parameter":{"type_url":"type.googleapis.com/protocol.FreezeBalanceV2Contract","value":{"frozen_balance":10000000,"owner_address":"41e151e4937bca41df55a67697724d9a64efcffdd5","resource":"ENERGY"}},"type":"FreezeBalanceV2Contract"}],"expiration":1676983599000,"ref_block_bytes":"d4c2","ref_block_hash":"715b2f0ea141594f","timestamp":1676983541337},"signature":["d4b539a389f6721b4e9d0eb9f39b62a539069060e1af2a118f06b81737ad9cdb49d5b4fda85f10603012f8de3996da2a1234c21d74ac6ea5e60217d3c10b630900"],"txID":"3a46321487ce1fd115da38b3431006ea529f65ef2507f19233f5a23c05abd01d"}"#
+    );
+}
+
+#[test]
+fn test_tron_sign_unfreeze_balance_v2() {
+    // Successfully broadcasted https://nile.tronscan.org/#/transaction/3070adc1743e6fdd20e04a749cc2af691ca26d2ce70e40cc0886be03595f9eeb
+    let unfreeze = Proto::UnfreezeBalanceV2Contract {
+        owner_address: STAKING_OWNER_ADDRESS.into(),
+        unfreeze_balance: 510000000,
+        resource: "ENERGY".into(),
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            timestamp: 1676992267490,
+            expiration: 1676992326000,
+            block_header: Some(block_header(
+                1676992212000,
+                "4b1edc58d14a5c60c083365d8b77771ba626394b445c7a7b8b5d67330bb6c92d",
+                "00000000020ce000354fbb346d676de268b3f83124381f8496835afe88da4a01",
+                34398209,
+                "4194a21bec5d0e1dde2151475f72ed158a87eb4817",
+                26,
+            )),
+            contract_oneof: ContractType::unfreeze_balance_v2(unfreeze),
+            ..Proto::Transaction::default()
+        }),
+        private_key: STAKING_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "3070adc1743e6fdd20e04a749cc2af691ca26d2ce70e40cc0886be03595f9eeb"
+    );
+    assert_eq!(output.signature.to_hex(), "10bc05c47102f1db1a3a4c0b4a6aba028d5a35dda4e505563c3f0ccf95a562cf18b53f7f7053c485299cfc599a432d1f0ee5554a56cd5981ccfff31d79b9868b00");
+}
+
+#[test]
+fn test_tron_sign_withdraw_expire_unfreeze() {
+    // Successfully broadcasted https://nile.tronscan.org/#/transaction/65ff34192eebda9ba7013771ff2da1010615e348b70c046647f41afe865f00eb
+    let withdraw = Proto::WithdrawExpireUnfreezeContract {
+        owner_address: STAKING_OWNER_ADDRESS.into(),
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            timestamp: 1677574466457,
+            expiration: 1677574524000,
+            block_header: Some(block_header(
+                1677574410000,
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "00000000020fce45738ef00be07c350c03d027851308bc19d61c32312c673d3d",
+                34590278,
+                "41e7860196ad5b5718c1d6326babab039b70b8c1cd",
+                27,
+            )),
+            contract_oneof: ContractType::withdraw_expire_unfreeze(withdraw),
+            ..Proto::Transaction::default()
+        }),
+        private_key: STAKING_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "65ff34192eebda9ba7013771ff2da1010615e348b70c046647f41afe865f00eb"
+    );
+    assert_eq!(output.signature.to_hex(), "ef0361248c118b8afae9c4c8e6dfad1e63eec4fb6c182ae369fa3bbecc2ac29a292838949ad74300b2b7322a110ffd4458224e283181cf6d64df0324b068bb0001");
+}
+
+#[test]
+fn test_tron_sign_delegate_resource() {
+    // Successfully broadcasted https://nile.tronscan.org/#/transaction/ceabcd0f105854c13aae12ba35c0766945713c29cee540be1239bb0f1f0cde2c
+    let delegate = Proto::DelegateResourceContract {
+        owner_address: STAKING_OWNER_ADDRESS.into(),
+        resource: "ENERGY".into(),
+        balance: 68000000,
+        receiver_address: RECEIVER_ADDRESS.into(),
+        lock: false,
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            timestamp: 1676991607274,
+            expiration: 1676991660000,
+            block_header: Some(block_header(
+                1676991546000,
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "00000000020cdf260ff2357d814141106c375c101913c933c2b5c31a390db7fc",
+                34397991,
+                "417d3601dbd9d033b034c154868acc2904d9c45565",
+                26,
+            )),
+            contract_oneof: ContractType::delegate_resource(delegate),
+            ..Proto::Transaction::default()
+        }),
+        private_key: STAKING_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "ceabcd0f105854c13aae12ba35c0766945713c29cee540be1239bb0f1f0cde2c"
+    );
+    assert_eq!(output.signature.to_hex(), "664500a76466497a442cecc0e9282a9234483f047c12a997b6206d7f6a9030c70b700c879d7948c4cbdfe339c2c81a29dea18e00e9916504196c1b20cf045ca300");
+}
+
+#[test]
+fn test_tron_sign_undelegate_resource() {
+    let undelegate = Proto::UnDelegateResourceContract {
+        owner_address: STAKING_OWNER_ADDRESS.into(),
+        resource: "ENERGY".into(),
+        balance: 68000000,
+        receiver_address: RECEIVER_ADDRESS.into(),
+    };
+    let input = Proto::SigningInput {
+        transaction: Some(Proto::Transaction {
+            timestamp: 1676992063012,
+            expiration: 1676992122000,
+            block_header: Some(block_header(
+                1676992008000,
+                "85a47017a4380e92d09bac0f8991031e8de13b8b65767a6f5372d3f0992eabcd",
+                "00000000020cdfbe4d7f36fcbb3d96dd634987b897eaf885001dd62fd92eb263",
+                34398143,
+                "4196409f85790883057edf03286d08e4aa608c0d0a",
+                26,
+            )),
+            contract_oneof: ContractType::undelegate_resource(undelegate),
+            ..Proto::Transaction::default()
+        }),
+        private_key: STAKING_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.id.to_hex(),
+        "3609519cc700cf2446b5e048864abc4b45e2ba6b7f9f8890d471ba2876599d3b"
+    );
+    assert_eq!(output.signature.to_hex(), "b08e32a704d5a366df499d283d407c428dd50e60665f54ecf967226b75bec37157e6bc23312af07fad9dd3551cd668ce027cc280932fd4772af89d6f0fecf11900");
+}
+
+#[test]
+fn test_tron_sign_invalid_resource() {
+    let freeze = Proto::FreezeBalanceV2Contract {
+        owner_address: STAKING_OWNER_ADDRESS.into(),
+        frozen_balance: 10000000,
+        resource: "TRON_POWER".into(),
+    };
+    let input = default_input(ContractType::freeze_balance_v2(freeze));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_tron_sign_invalid_address() {
+    let transfer = Proto::TransferContract {
+        owner_address: OWNER_ADDRESS.into(),
+        to_address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".into(),
+        amount: 2000000,
+    };
+    let input = default_input(ContractType::transfer(transfer));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Tron, input);
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
@@ -27,6 +27,11 @@ fn test_coin_address_derivation() {
         if !coin_item.blockchain.is_supported() {
             continue;
         }
+        // Cardano extended public key cannot be derived from a 32-byte private key.
+        // See `tests/chains/cardano/cardano_address.rs` instead.
+        if coin == CoinType::Cardano {
+            continue;
+        }
 
         let public_key = TWPublicKeyHelper::wrap(unsafe {
             tw_private_key_get_public_key_by_type(
@@ -87,25 +92,23 @@ fn test_coin_address_derivation() {
             | CoinType::MantaPacific
             // end_of_evm_address_derivation_tests_marker_do_not_modify
                 => "0xAc1ec44E4f0ca7D172B7803f6836De87Fb72b309",
-            CoinType::Bitcoin
-            // TODO all Bitcoin-based blockchains should have different addresses.
-            // It should be fixed when Bitcoin is finalized.
-            | CoinType::Litecoin
-            | CoinType::Dogecoin
-            | CoinType::Dash
-            | CoinType::Viacoin
+            CoinType::Bitcoin => "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X",
+            CoinType::Litecoin => "LTq7ZzNBwnyrsysS4znUePsxmveSaWLyGF",
+            CoinType::Dogecoin
             | CoinType::DigiByte
-            | CoinType::Monacoin
-            | CoinType::Syscoin
             | CoinType::Pivx
-            | CoinType::Firo
-            | CoinType::BitcoinCash
-            | CoinType::BitcoinGold
-            | CoinType::Ravencoin
-            | CoinType::Qtum
-            | CoinType::eCash
-            | CoinType::Stratis
-            => "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X",
+            => "DDkFr311AYe6ABMsdSnjv8yoSr1Tppokp8",
+            CoinType::Dash => "XjJ192iFpqxPn7mrkk7QDuVzQ3rrY5yXJG",
+            CoinType::Viacoin => "VibzDVDpGwe1gx5RdeSooH94FXa8zSUsp4",
+            CoinType::Monacoin => "MGWKYCxmN9ucWr9c7qT7ceRY2wAKz2NnGf",
+            CoinType::Syscoin => "SVuALcqWbVw19UxjSHnFvGxmDVWbJrV7sf",
+            CoinType::Firo => "a9Kd3gVz5vjegicNuG7K8f8iB5QWkUuTxW",
+            CoinType::BitcoinGold => "GST5iuPJqzM6heUZpoTHo9A6Ut51XVU6wv",
+            CoinType::Ravencoin => "RHtMPHweTxYNhBYUN2nJTu9QKyjm7MRKsF",
+            CoinType::Qtum => "QVD9R5M53bcd4KGJKC7fVebq4yDsnEDwtt",
+            CoinType::Stratis => "XKxQ9vQy7fVWxgdmjKn5jnECmYbupb9Xhx",
+            CoinType::BitcoinCash => "bitcoincash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2ydzeh0jgs",
+            CoinType::eCash => "ecash:qp0xw4t8xrxae7ed4hq9a96rekynzqry2y50du5gw8",
             CoinType::Aptos => "0x9006fa46f038224e8004bdda97f2e7a60c2c3d135bce7cb15541e5c0aae907a4",
             CoinType::Cosmos => "cosmos1ten42eesehw0ktddcp0fws7d3ycsqez3lynlqx",
             CoinType::Stargaze => "stars1ten42eesehw0ktddcp0fws7d3ycsqez3tcyzth",
@@ -145,6 +148,14 @@ fn test_coin_address_derivation() {
             CoinType::InternetComputer => "290cc7c359f44c8516fc169c5ed4f0f3ae2e24bf5de0d4c51f5e7545b5474faa",
             CoinType::Binance => "bnb1ten42eesehw0ktddcp0fws7d3ycsqez3aqvnpg",
             CoinType::TBinance => "tbnb1ten42eesehw0ktddcp0fws7d3ycsqez3n49hpe",
+            CoinType::Solana => "5sn9QYhDaq61jLXJ8Li5BKqGL4DDMJQvU1rdN8XgVuwC",
+            CoinType::Polkadot => "12dyy3fArMPDXLsnRtapTqZsC2KCEimeqs1dop4AEERaKC6x",
+            CoinType::Kusama => "EDJV2jycw8fqTgiExLsDe6iUzbnM62hDk7u3BLm9wcYswkY",
+            CoinType::Acala => "22WaYy5ChG8V5vvRVDP4ErE7esk8nZ4rjGYwxeVArnNT8dU3",
+            CoinType::TON => "EQCj3jAU_Ec2kXdAqweKt4rYjiwTNwiCfaUnIDHGh7wTw0ID",
+            CoinType::Sui => "0x01a5c6c1b74cec4fbd12b3e17252b83448136065afcdf24954dc3a9c26df4905",
+            CoinType::NEAR => "4870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e867",
+            CoinType::Tron => "TRfJ5mcP4mCvixi53YawxKzr5QJNbN4cDv",
             // end_of_coin_address_derivation_tests_marker_do_not_modify
             _ => panic!("{:?} must be covered", coin),
         };
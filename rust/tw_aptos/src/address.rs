@@ -2,11 +2,13 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::authenticator::{AnyPublicKey, MultiEd25519PublicKey, MultiKey};
 use move_core_types::account_address::{AccountAddress, AccountAddressParseError};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use tw_coin_entry::coin_entry::CoinAddress;
 use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::bcs;
 use tw_hash::sha3::sha3_256;
 use tw_keypair::ed25519;
 use tw_memory::Data;
@@ -29,6 +31,9 @@ impl AptosAddress for Address {}
 #[repr(u8)]
 pub enum Scheme {
     Ed25519 = 0,
+    MultiEd25519 = 1,
+    SingleKey = 2,
+    MultiKey = 3,
 }
 
 #[derive(Clone)]
@@ -43,8 +48,29 @@ impl Address {
     pub fn with_ed25519_pubkey(
         pubkey: &ed25519::sha512::PublicKey,
     ) -> Result<Address, AddressError> {
-        let mut to_hash = pubkey.as_slice().to_vec();
-        to_hash.push(Scheme::Ed25519 as u8);
+        Self::with_scheme(pubkey.as_slice().to_vec(), Scheme::Ed25519)
+    }
+
+    /// Initializes an address with a K-of-N `ed25519` public key.
+    pub fn with_multi_ed25519(public_key: &MultiEd25519PublicKey) -> AddressResult<Address> {
+        Self::with_scheme(public_key.to_bytes(), Scheme::MultiEd25519)
+    }
+
+    /// Initializes an address with a single public key of any supported type.
+    pub fn with_single_key(public_key: &AnyPublicKey) -> AddressResult<Address> {
+        let bytes = bcs::encode(public_key).map_err(|_| AddressError::InvalidInput)?;
+        Self::with_scheme(bytes, Scheme::SingleKey)
+    }
+
+    /// Initializes an address with a K-of-N public key of any supported type.
+    pub fn with_multi_key(public_key: &MultiKey) -> AddressResult<Address> {
+        let bytes = bcs::encode(public_key).map_err(|_| AddressError::InvalidInput)?;
+        Self::with_scheme(bytes, Scheme::MultiKey)
+    }
+
+    /// Authentication key is `sha3_256(public_key_bytes || scheme)`.
+    fn with_scheme(mut to_hash: Data, scheme: Scheme) -> AddressResult<Address> {
+        to_hash.push(scheme as u8);
         let hashed = sha3_256(to_hash.as_slice());
         let addr = AccountAddress::from_bytes(hashed).map_err(from_account_error)?;
         Ok(Address { addr })
@@ -53,6 +79,11 @@ impl Address {
     pub fn inner(&self) -> AccountAddress {
         self.addr
     }
+
+    /// Returns the AIP-40 long form of the address, i.e. `0x` followed by 64 hex digits.
+    pub fn to_long_string(&self) -> String {
+        tw_encoding::hex::encode(self.addr.to_vec(), true)
+    }
 }
 
 impl Display for Address {
@@ -110,6 +141,7 @@ impl FromStr for Address {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tw_encoding::hex::DecodeHex;
     use tw_keypair::ed25519::sha512::PrivateKey;
 
     #[test]
@@ -127,6 +159,70 @@ mod tests {
         assert_eq!(addr.unwrap().data().len(), Address::LENGTH);
     }
 
+    #[test]
+    fn test_from_multi_ed25519_public_key() {
+        let public_keys = [
+            "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+            "633e5c7e355bdd484706436ce1f06fdf280bd7c2229a7f9b6489684412c6967c",
+            "694194dc897927e7cd503f64e0321690bda3761f04906d06ac6fe192cb66830c",
+        ]
+        .iter()
+        .map(|public_key| public_key.decode_hex().unwrap())
+        .collect();
+        let public_key = MultiEd25519PublicKey::new(public_keys, 2).unwrap();
+        let addr = Address::with_multi_ed25519(&public_key).unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "0xf03c8dbf718b666e983ad1a7ed0a5446c4127e3a3d17c80473e7a5a4cda94297"
+        );
+    }
+
+    #[test]
+    fn test_from_single_key_secp256k1() {
+        let public_key = AnyPublicKey::secp256k1(
+            &"02671f4e424abeb34333acd15be4cacc7aec5b339a68a2b99ed454fece8e26fd0f"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        let addr = Address::with_single_key(&public_key).unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "0x0ea218899c673ab3d443f41a6b7408bbbfe852a8bea8c0c3d65a189ae9d84646"
+        );
+    }
+
+    #[test]
+    fn test_from_multi_key() {
+        let ed25519 = AnyPublicKey::ed25519(
+            &"ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        let secp256k1 = AnyPublicKey::secp256k1(
+            &"02671f4e424abeb34333acd15be4cacc7aec5b339a68a2b99ed454fece8e26fd0f"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        let multi_key = MultiKey::new(vec![ed25519, secp256k1], 1).unwrap();
+        let addr = Address::with_multi_key(&multi_key).unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "0x9a56ddd89def9d2764acccd0c08bb09fd9403addf9a86cfa809c4dbd8c3c50f8"
+        );
+    }
+
+    #[test]
+    fn test_multi_key_invalid_threshold() {
+        let public_key = "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c"
+            .decode_hex()
+            .unwrap();
+        assert!(MultiEd25519PublicKey::new(vec![public_key.clone()], 0).is_err());
+        assert!(MultiEd25519PublicKey::new(vec![public_key], 2).is_err());
+    }
+
     #[test]
     fn test_from_account_error() {
         assert_eq!(
@@ -5,7 +5,8 @@
 use crate::transaction_payload::{EntryFunction, TransactionPayload};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
-use move_core_types::language_storage::{ModuleId, TypeTag};
+use move_core_types::identifier::IdentStr;
+use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
 use serde_json::json;
 use tw_coin_entry::error::SigningResult;
 use tw_encoding::bcs;
@@ -205,3 +206,82 @@ pub fn managed_coin_register(coin_type: TypeTag) -> TransactionPayload {
         json!([]),
     ))
 }
+
+/// Transfers a fungible asset of the Fungible Asset standard,
+/// creating the recipient's primary store if needed.
+pub fn primary_fungible_store_transfer(
+    metadata: AccountAddress,
+    to: AccountAddress,
+    amount: u64,
+) -> SigningResult<TransactionPayload> {
+    Ok(TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("primary_fungible_store").to_owned(),
+        ),
+        ident_str!("transfer").to_owned(),
+        vec![struct_type_tag(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("fungible_asset"),
+            ident_str!("Metadata"),
+        )],
+        vec![
+            bcs::encode(&metadata)?,
+            bcs::encode(&to)?,
+            bcs::encode(&amount)?,
+        ],
+        json!([
+            metadata.to_hex_literal(),
+            to.to_hex_literal(),
+            amount.to_string()
+        ]),
+    )))
+}
+
+/// Transfers an object of the given type, e.g. a Digital Asset (`0x4::token::Token`).
+pub fn object_transfer(
+    object_type: TypeTag,
+    object: AccountAddress,
+    to: AccountAddress,
+) -> SigningResult<TransactionPayload> {
+    Ok(TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("object").to_owned(),
+        ),
+        ident_str!("transfer").to_owned(),
+        vec![object_type],
+        vec![bcs::encode(&object)?, bcs::encode(&to)?],
+        json!([object.to_hex_literal(), to.to_hex_literal()]),
+    )))
+}
+
+/// Returns the `0x4::token::Token` type of Digital Assets.
+pub fn digital_asset_token_type() -> TypeTag {
+    struct_type_tag(
+        AccountAddress::new([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 4,
+        ]),
+        ident_str!("token"),
+        ident_str!("Token"),
+    )
+}
+
+fn struct_type_tag(address: AccountAddress, module: &IdentStr, name: &IdentStr) -> TypeTag {
+    TypeTag::Struct(Box::new(StructTag {
+        address,
+        module: module.to_owned(),
+        name: name.to_owned(),
+        type_params: vec![],
+    }))
+}
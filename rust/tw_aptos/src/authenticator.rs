@@ -0,0 +1,900 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use move_core_types::account_address::AccountAddress;
+use serde::ser::{SerializeStruct, SerializeStructVariant};
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::bcs;
+use tw_encoding::hex::encode;
+use tw_hash::sha3::sha3_256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_memory::Data;
+use tw_proto::Aptos::Proto;
+
+/// Maximum number of keys of a MultiEd25519 account.
+pub const MAX_NUM_OF_MULTI_ED25519_KEYS: usize = 32;
+/// Maximum number of keys of a MultiKey account.
+pub const MAX_NUM_OF_MULTI_KEYS: usize = 32;
+/// MultiEd25519 signature bitmap is always 4 bytes long.
+const MULTI_ED25519_BITMAP_LEN: usize = 4;
+const ED25519_SIGNATURE_LEN: usize = 64;
+const SECP256K1_SIGNATURE_LEN: usize = 64;
+
+/// A public key of the `SingleKey` and `MultiKey` schemes.
+#[derive(Clone, Serialize)]
+pub enum AnyPublicKey {
+    Ed25519 {
+        public_key: Data,
+    },
+    /// 65-byte uncompressed secp256k1 public key.
+    Secp256k1Ecdsa {
+        public_key: Data,
+    },
+}
+
+impl AnyPublicKey {
+    pub fn ed25519(public_key: &[u8]) -> SigningResult<Self> {
+        let public_key = ed25519::sha512::PublicKey::try_from(public_key)?;
+        Ok(AnyPublicKey::Ed25519 {
+            public_key: public_key.as_slice().to_vec(),
+        })
+    }
+
+    /// Accepts both compressed and uncompressed secp256k1 public keys.
+    pub fn secp256k1(public_key: &[u8]) -> SigningResult<Self> {
+        let public_key = secp256k1::PublicKey::try_from(public_key)?;
+        Ok(AnyPublicKey::Secp256k1Ecdsa {
+            public_key: public_key.uncompressed().to_vec(),
+        })
+    }
+
+    pub fn from_proto(public_key: &Proto::AnyPublicKey<'_>) -> SigningResult<Self> {
+        match public_key.type_pb {
+            Proto::mod_AnyPublicKey::KeyType::Ed25519 => Self::ed25519(&public_key.public_key),
+            Proto::mod_AnyPublicKey::KeyType::Secp256k1Ecdsa => {
+                Self::secp256k1(&public_key.public_key)
+            },
+        }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            AnyPublicKey::Ed25519 { public_key } | AnyPublicKey::Secp256k1Ecdsa { public_key } => {
+                public_key
+            },
+        }
+    }
+
+    /// Checks whether the given public key bytes represent the same key.
+    /// secp256k1 keys are compared regardless of whether they are compressed or not.
+    pub fn matches(&self, public_key: &[u8]) -> bool {
+        match self {
+            AnyPublicKey::Ed25519 { public_key: this } => this.as_slice() == public_key,
+            AnyPublicKey::Secp256k1Ecdsa { public_key: this } => {
+                secp256k1::PublicKey::try_from(public_key).map_or(false, |other| {
+                    other.uncompressed().as_slice() == this.as_slice()
+                })
+            },
+        }
+    }
+
+    /// Creates an [`AnySignature`] of the corresponding type.
+    pub fn signature(&self, signature: &[u8]) -> SigningResult<AnySignature> {
+        match self {
+            AnyPublicKey::Ed25519 { .. } => AnySignature::ed25519(signature),
+            AnyPublicKey::Secp256k1Ecdsa { .. } => AnySignature::secp256k1(signature),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            AnyPublicKey::Ed25519 { public_key } => {
+                json!({"type": "ed25519", "value": encode(public_key, true)})
+            },
+            AnyPublicKey::Secp256k1Ecdsa { public_key } => {
+                json!({"type": "secp256k1_ecdsa", "value": encode(public_key, true)})
+            },
+        }
+    }
+}
+
+/// A signature of the `SingleKey` and `MultiKey` schemes.
+#[derive(Clone, Serialize)]
+pub enum AnySignature {
+    Ed25519 {
+        signature: Data,
+    },
+    /// 64-byte `r || s` secp256k1 signature.
+    Secp256k1Ecdsa {
+        signature: Data,
+    },
+}
+
+impl AnySignature {
+    pub fn ed25519(signature: &[u8]) -> SigningResult<Self> {
+        if signature.len() != ED25519_SIGNATURE_LEN {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        Ok(AnySignature::Ed25519 {
+            signature: signature.to_vec(),
+        })
+    }
+
+    /// Accepts a signature with or without the recovery byte at the end.
+    pub fn secp256k1(signature: &[u8]) -> SigningResult<Self> {
+        if !(SECP256K1_SIGNATURE_LEN..=SECP256K1_SIGNATURE_LEN + 1).contains(&signature.len()) {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        Ok(AnySignature::Secp256k1Ecdsa {
+            signature: signature[..SECP256K1_SIGNATURE_LEN].to_vec(),
+        })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            AnySignature::Ed25519 { signature } | AnySignature::Secp256k1Ecdsa { signature } => {
+                signature
+            },
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            AnySignature::Ed25519 { signature } => {
+                json!({"type": "ed25519", "value": encode(signature, true)})
+            },
+            AnySignature::Secp256k1Ecdsa { signature } => {
+                json!({"type": "secp256k1_ecdsa", "value": encode(signature, true)})
+            },
+        }
+    }
+}
+
+/// K-of-N Ed25519 public keys.
+#[derive(Clone)]
+pub struct MultiEd25519PublicKey {
+    public_keys: Vec<Data>,
+    threshold: u8,
+}
+
+impl MultiEd25519PublicKey {
+    pub fn new(public_keys: Vec<Data>, threshold: u8) -> SigningResult<Self> {
+        check_threshold(public_keys.len(), threshold, MAX_NUM_OF_MULTI_ED25519_KEYS)?;
+        for public_key in public_keys.iter() {
+            ed25519::sha512::PublicKey::try_from(public_key.as_slice())?;
+        }
+        Ok(MultiEd25519PublicKey {
+            public_keys,
+            threshold,
+        })
+    }
+
+    /// Returns `public_key_1 || ... || public_key_N || threshold`.
+    pub fn to_bytes(&self) -> Data {
+        let mut bytes = self.public_keys.concat();
+        bytes.push(self.threshold);
+        bytes
+    }
+}
+
+/// K-of-N public keys of any supported type.
+#[derive(Clone, Serialize)]
+pub struct MultiKey {
+    public_keys: Vec<AnyPublicKey>,
+    signatures_required: u8,
+}
+
+impl MultiKey {
+    pub fn new(public_keys: Vec<AnyPublicKey>, signatures_required: u8) -> SigningResult<Self> {
+        check_threshold(
+            public_keys.len(),
+            signatures_required,
+            MAX_NUM_OF_MULTI_KEYS,
+        )?;
+        Ok(MultiKey {
+            public_keys,
+            signatures_required,
+        })
+    }
+
+    pub fn public_keys(&self) -> &[AnyPublicKey] {
+        &self.public_keys
+    }
+}
+
+#[derive(Clone)]
+pub struct MultiEd25519Authenticator {
+    public_key: MultiEd25519PublicKey,
+    /// Signatures along with the indexes of the signers' public keys, sorted by the index.
+    signatures: Vec<(u8, Data)>,
+}
+
+impl MultiEd25519Authenticator {
+    /// Creates an authenticator from the `(public_key, signature)` pairs.
+    /// Public keys must belong to the given MultiEd25519 public key.
+    pub fn new(
+        public_key: MultiEd25519PublicKey,
+        signatures: Vec<(Data, Data)>,
+    ) -> SigningResult<Self> {
+        let signatures = index_signatures(
+            &public_key.public_keys,
+            public_key.threshold,
+            signatures,
+            |key, other| key.as_slice() == other,
+        )?;
+        if signatures
+            .iter()
+            .any(|(_, signature)| signature.len() != ED25519_SIGNATURE_LEN)
+        {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        Ok(MultiEd25519Authenticator {
+            public_key,
+            signatures,
+        })
+    }
+
+    pub fn public_key_bytes(&self) -> Data {
+        self.public_key.to_bytes()
+    }
+
+    /// Returns `signature_1 || ... || signature_K || bitmap`.
+    pub fn signature_bytes(&self) -> Data {
+        let mut bytes: Data = self
+            .signatures
+            .iter()
+            .flat_map(|(_, signature)| signature.iter().copied())
+            .collect();
+        bytes.extend(bitmap(&self.signatures, MULTI_ED25519_BITMAP_LEN));
+        bytes
+    }
+
+    pub fn to_json(&self) -> Value {
+        let public_keys: Vec<_> = self
+            .public_key
+            .public_keys
+            .iter()
+            .map(|public_key| encode(public_key, true))
+            .collect();
+        let signatures: Vec<_> = self
+            .signatures
+            .iter()
+            .map(|(_, signature)| encode(signature, true))
+            .collect();
+        json!({"bitmap": encode(bitmap(&self.signatures, MULTI_ED25519_BITMAP_LEN), true),
+               "public_keys": public_keys,
+               "signatures": signatures,
+               "threshold": self.public_key.threshold,
+               "type": "multi_ed25519_signature"})
+    }
+}
+
+/// Serialized as `{ public_key: MultiEd25519PublicKey, signature: MultiEd25519Signature }`,
+/// where both the public key and the signature are BCS byte arrays.
+impl Serialize for MultiEd25519Authenticator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut authenticator = serializer.serialize_struct("MultiEd25519Authenticator", 2)?;
+        authenticator.serialize_field("public_key", &self.public_key_bytes())?;
+        authenticator.serialize_field("signature", &self.signature_bytes())?;
+        authenticator.end()
+    }
+}
+
+#[derive(Clone)]
+pub struct MultiKeyAuthenticator {
+    public_keys: MultiKey,
+    /// Signatures along with the indexes of the signers' public keys, sorted by the index.
+    signatures: Vec<(u8, AnySignature)>,
+}
+
+impl MultiKeyAuthenticator {
+    /// Creates an authenticator from the `(public_key, signature)` pairs.
+    /// Public keys must belong to the given MultiKey.
+    pub fn new(public_keys: MultiKey, signatures: Vec<(Data, Data)>) -> SigningResult<Self> {
+        let signatures = index_signatures(
+            &public_keys.public_keys,
+            public_keys.signatures_required,
+            signatures,
+            AnyPublicKey::matches,
+        )?
+        .into_iter()
+        .map(|(idx, signature)| {
+            let signature = public_keys.public_keys[idx as usize].signature(&signature)?;
+            Ok((idx, signature))
+        })
+        .collect::<SigningResult<_>>()?;
+
+        Ok(MultiKeyAuthenticator {
+            public_keys,
+            signatures,
+        })
+    }
+
+    pub fn to_json(&self) -> Value {
+        let public_keys: Vec<_> = self
+            .public_keys
+            .public_keys
+            .iter()
+            .map(AnyPublicKey::to_json)
+            .collect();
+        let signatures: Vec<_> = self
+            .signatures
+            .iter()
+            .map(|(idx, signature)| json!({"index": idx, "signature": signature.to_json()}))
+            .collect();
+        json!({"public_keys": public_keys,
+               "signatures": signatures,
+               "signatures_required": self.public_keys.signatures_required,
+               "type": "multi_key_signature"})
+    }
+
+    /// The bitmap has as many bytes as needed to fit all the public keys.
+    fn signatures_bitmap(&self) -> Data {
+        let len = self.public_keys.public_keys.len().saturating_add(7) / 8;
+        bitmap(&self.signatures, len)
+    }
+}
+
+/// Serialized as `{ public_keys: MultiKey, signatures: Vec<AnySignature>, signatures_bitmap: BitVec }`.
+impl Serialize for MultiKeyAuthenticator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let signatures: Vec<_> = self
+            .signatures
+            .iter()
+            .map(|(_, signature)| signature)
+            .collect();
+
+        let mut authenticator = serializer.serialize_struct("MultiKeyAuthenticator", 3)?;
+        authenticator.serialize_field("public_keys", &self.public_keys)?;
+        authenticator.serialize_field("signatures", &signatures)?;
+        authenticator.serialize_field("signatures_bitmap", &self.signatures_bitmap())?;
+        authenticator.end()
+    }
+}
+
+/// An authenticator of a single account participating in a transaction.
+#[derive(Clone, Serialize)]
+pub enum AccountAuthenticator {
+    /// Single Ed25519 signature
+    Ed25519 {
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    },
+    /// K-of-N Ed25519 signatures
+    MultiEd25519(MultiEd25519Authenticator),
+    /// Single signature of any supported type
+    SingleKey {
+        public_key: AnyPublicKey,
+        signature: AnySignature,
+    },
+    /// K-of-N signatures of any supported type
+    MultiKey(MultiKeyAuthenticator),
+}
+
+impl TryFrom<&Proto::AccountSignature<'_>> for AccountAuthenticator {
+    type Error = SigningError;
+
+    fn try_from(from: &Proto::AccountSignature<'_>) -> SigningResult<Self> {
+        KeyScheme::from_account_signature(from)?
+            .authenticator(vec![(from.public_key.to_vec(), from.signature.to_vec())])
+    }
+}
+
+impl AccountAuthenticator {
+    pub fn get_signature(&self) -> Vec<u8> {
+        match self {
+            AccountAuthenticator::Ed25519 { signature, .. } => signature.clone(),
+            AccountAuthenticator::MultiEd25519(multi) => multi.signature_bytes(),
+            AccountAuthenticator::SingleKey { signature, .. } => signature.as_slice().to_vec(),
+            // BCS encoding of the signatures and the bitmap cannot fail.
+            AccountAuthenticator::MultiKey(multi) => {
+                let signatures: Vec<_> = multi.signatures.iter().map(|(_, sig)| sig).collect();
+                let mut bytes = bcs::encode(&signatures).unwrap_or_default();
+                bytes.extend(bcs::encode(&multi.signatures_bitmap()).unwrap_or_default());
+                bytes
+            },
+        }
+    }
+
+    pub fn get_public_key(&self) -> Vec<u8> {
+        match self {
+            AccountAuthenticator::Ed25519 { public_key, .. } => public_key.clone(),
+            AccountAuthenticator::MultiEd25519(multi) => multi.public_key_bytes(),
+            AccountAuthenticator::SingleKey { public_key, .. } => public_key.as_slice().to_vec(),
+            // BCS encoding of the MultiKey cannot fail.
+            AccountAuthenticator::MultiKey(multi) => {
+                bcs::encode(&multi.public_keys).unwrap_or_default()
+            },
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => {
+                json!({"public_key": encode(public_key, true),
+                       "signature": encode(signature, true),
+                       "type": "ed25519_signature"})
+            },
+            AccountAuthenticator::MultiEd25519(multi) => multi.to_json(),
+            AccountAuthenticator::SingleKey {
+                public_key,
+                signature,
+            } => {
+                json!({"public_key": public_key.to_json(),
+                       "signature": signature.to_json(),
+                       "type": "single_key_signature"})
+            },
+            AccountAuthenticator::MultiKey(multi) => multi.to_json(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum TransactionAuthenticator {
+    /// Single Ed25519 signature
+    Ed25519 {
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    },
+    /// K-of-N Ed25519 signatures
+    MultiEd25519(MultiEd25519Authenticator),
+    /// Multi-agent transaction: the sender and the secondary signers.
+    MultiAgent {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+    },
+    /// Sponsored transaction: the sender, optional secondary signers and the fee payer.
+    FeePayer {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_address: AccountAddress,
+        fee_payer_signer: AccountAuthenticator,
+    },
+    /// Single sender of the `SingleKey` or `MultiKey` scheme.
+    SingleSender { sender: AccountAuthenticator },
+}
+
+impl From<AccountAuthenticator> for TransactionAuthenticator {
+    fn from(sender: AccountAuthenticator) -> Self {
+        match sender {
+            AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => TransactionAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            },
+            AccountAuthenticator::MultiEd25519(multi) => {
+                TransactionAuthenticator::MultiEd25519(multi)
+            },
+            sender @ (AccountAuthenticator::SingleKey { .. }
+            | AccountAuthenticator::MultiKey(_)) => {
+                TransactionAuthenticator::SingleSender { sender }
+            },
+        }
+    }
+}
+
+impl From<TransactionAuthenticator> for Proto::TransactionAuthenticator<'_> {
+    fn from(from: TransactionAuthenticator) -> Self {
+        Proto::TransactionAuthenticator {
+            signature: Cow::from(from.get_signature()),
+            public_key: Cow::from(from.get_public_key()),
+        }
+    }
+}
+
+impl TransactionAuthenticator {
+    /// BCS variant indexes as declared in `aptos_types::transaction::authenticator::TransactionAuthenticator`.
+    const ED25519_INDEX: u32 = 0;
+    const MULTI_ED25519_INDEX: u32 = 1;
+    const MULTI_AGENT_INDEX: u32 = 2;
+    const FEE_PAYER_INDEX: u32 = 3;
+    const SINGLE_SENDER_INDEX: u32 = 4;
+
+    /// Returns the sender's signature.
+    pub fn get_signature(&self) -> Vec<u8> {
+        match self {
+            TransactionAuthenticator::Ed25519 { signature, .. } => signature.clone(),
+            TransactionAuthenticator::MultiEd25519(multi) => multi.signature_bytes(),
+            TransactionAuthenticator::MultiAgent { sender, .. }
+            | TransactionAuthenticator::FeePayer { sender, .. }
+            | TransactionAuthenticator::SingleSender { sender } => sender.get_signature(),
+        }
+    }
+
+    /// Returns the sender's public key.
+    pub fn get_public_key(&self) -> Vec<u8> {
+        match self {
+            TransactionAuthenticator::Ed25519 { public_key, .. } => public_key.clone(),
+            TransactionAuthenticator::MultiEd25519(multi) => multi.public_key_bytes(),
+            TransactionAuthenticator::MultiAgent { sender, .. }
+            | TransactionAuthenticator::FeePayer { sender, .. }
+            | TransactionAuthenticator::SingleSender { sender } => sender.get_public_key(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            TransactionAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => {
+                json!({"public_key": encode(public_key, true),
+                       "signature": encode(signature, true),
+                       "type": "ed25519_signature"})
+            },
+            TransactionAuthenticator::MultiEd25519(multi) => multi.to_json(),
+            TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            } => {
+                json!({"secondary_signer_addresses": addresses_to_json(secondary_signer_addresses),
+                       "secondary_signers": authenticators_to_json(secondary_signers),
+                       "sender": sender.to_json(),
+                       "type": "multi_agent_signature"})
+            },
+            TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                fee_payer_signer,
+            } => {
+                json!({"fee_payer_address": fee_payer_address.to_hex_literal(),
+                       "fee_payer_signer": fee_payer_signer.to_json(),
+                       "secondary_signer_addresses": addresses_to_json(secondary_signer_addresses),
+                       "secondary_signers": authenticators_to_json(secondary_signers),
+                       "sender": sender.to_json(),
+                       "type": "fee_payer_signature"})
+            },
+            TransactionAuthenticator::SingleSender { sender } => {
+                // The sender's signature fields are flattened into the `single_sender` object.
+                let mut json = sender.to_json();
+                json["type"] = Value::String("single_sender".to_string());
+                json
+            },
+        }
+    }
+}
+
+/// [`TransactionAuthenticator`] can't derive `Serialize`
+/// as its BCS variant indexes must match the Aptos ones.
+impl Serialize for TransactionAuthenticator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "TransactionAuthenticator";
+
+        match self {
+            TransactionAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => {
+                let mut variant =
+                    serializer.serialize_struct_variant(NAME, Self::ED25519_INDEX, "Ed25519", 2)?;
+                variant.serialize_field("public_key", public_key)?;
+                variant.serialize_field("signature", signature)?;
+                variant.end()
+            },
+            TransactionAuthenticator::MultiEd25519(multi) => serializer.serialize_newtype_variant(
+                NAME,
+                Self::MULTI_ED25519_INDEX,
+                "MultiEd25519",
+                multi,
+            ),
+            TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            } => {
+                let mut variant = serializer.serialize_struct_variant(
+                    NAME,
+                    Self::MULTI_AGENT_INDEX,
+                    "MultiAgent",
+                    3,
+                )?;
+                variant.serialize_field("sender", sender)?;
+                variant
+                    .serialize_field("secondary_signer_addresses", secondary_signer_addresses)?;
+                variant.serialize_field("secondary_signers", secondary_signers)?;
+                variant.end()
+            },
+            TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                fee_payer_signer,
+            } => {
+                let mut variant = serializer.serialize_struct_variant(
+                    NAME,
+                    Self::FEE_PAYER_INDEX,
+                    "FeePayer",
+                    5,
+                )?;
+                variant.serialize_field("sender", sender)?;
+                variant
+                    .serialize_field("secondary_signer_addresses", secondary_signer_addresses)?;
+                variant.serialize_field("secondary_signers", secondary_signers)?;
+                variant.serialize_field("fee_payer_address", fee_payer_address)?;
+                variant.serialize_field("fee_payer_signer", fee_payer_signer)?;
+                variant.end()
+            },
+            TransactionAuthenticator::SingleSender { sender } => {
+                let mut variant = serializer.serialize_struct_variant(
+                    NAME,
+                    Self::SINGLE_SENDER_INDEX,
+                    "SingleSender",
+                    1,
+                )?;
+                variant.serialize_field("sender", sender)?;
+                variant.end()
+            },
+        }
+    }
+}
+
+/// Key scheme of the transaction sender's account.
+pub enum KeyScheme {
+    Ed25519,
+    MultiEd25519(MultiEd25519PublicKey),
+    SingleKeyEd25519,
+    SingleKeySecp256k1,
+    MultiKey(MultiKey),
+}
+
+impl KeyScheme {
+    pub fn from_proto(input: &Proto::SigningInput<'_>) -> SigningResult<Self> {
+        Self::new(input.sender_key_scheme, input.multi_key_account.as_ref())
+    }
+
+    /// Returns the key scheme of an additional signer, i.e. a secondary signer or a fee payer.
+    pub fn from_account_signature(signer: &Proto::AccountSignature<'_>) -> SigningResult<Self> {
+        Self::new(signer.key_scheme, signer.multi_key_account.as_ref())
+    }
+
+    fn new(
+        key_scheme: Proto::KeyScheme,
+        multi_key_account: Option<&Proto::MultiKeyAccount<'_>>,
+    ) -> SigningResult<Self> {
+        let multi_key_account =
+            || multi_key_account.ok_or(SigningError(SigningErrorType::Error_invalid_params));
+        let threshold = |account: &Proto::MultiKeyAccount| {
+            u8::try_from(account.threshold)
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_params))
+        };
+
+        match key_scheme {
+            Proto::KeyScheme::Ed25519 => Ok(KeyScheme::Ed25519),
+            Proto::KeyScheme::MultiEd25519 => {
+                let account = multi_key_account()?;
+                let public_keys = account
+                    .public_keys
+                    .iter()
+                    .map(|public_key| match public_key.type_pb {
+                        Proto::mod_AnyPublicKey::KeyType::Ed25519 => {
+                            Ok(public_key.public_key.to_vec())
+                        },
+                        Proto::mod_AnyPublicKey::KeyType::Secp256k1Ecdsa => {
+                            Err(SigningError(SigningErrorType::Error_invalid_params))
+                        },
+                    })
+                    .collect::<SigningResult<_>>()?;
+                MultiEd25519PublicKey::new(public_keys, threshold(account)?)
+                    .map(KeyScheme::MultiEd25519)
+            },
+            Proto::KeyScheme::SingleKeyEd25519 => Ok(KeyScheme::SingleKeyEd25519),
+            Proto::KeyScheme::SingleKeySecp256k1 => Ok(KeyScheme::SingleKeySecp256k1),
+            Proto::KeyScheme::MultiKey => {
+                let account = multi_key_account()?;
+                let public_keys = account
+                    .public_keys
+                    .iter()
+                    .map(AnyPublicKey::from_proto)
+                    .collect::<SigningResult<_>>()?;
+                MultiKey::new(public_keys, threshold(account)?).map(KeyScheme::MultiKey)
+            },
+        }
+    }
+
+    /// Whether the sender's keys may include secp256k1 ones.
+    /// secp256k1 keys sign the SHA3-256 hash of the preimage rather than the preimage itself.
+    pub fn has_secp256k1_keys(&self) -> bool {
+        match self {
+            KeyScheme::SingleKeySecp256k1 => true,
+            KeyScheme::MultiKey(multi_key) => multi_key
+                .public_keys
+                .iter()
+                .any(|public_key| matches!(public_key, AnyPublicKey::Secp256k1Ecdsa { .. })),
+            KeyScheme::Ed25519 | KeyScheme::MultiEd25519(_) | KeyScheme::SingleKeyEd25519 => false,
+        }
+    }
+
+    /// Builds the sender's authenticator from `(public_key, signature)` pairs.
+    pub fn authenticator(
+        &self,
+        mut signatures: Vec<(Data, Data)>,
+    ) -> SigningResult<AccountAuthenticator> {
+        let single_signature = |signatures: &mut Vec<(Data, Data)>| match signatures.len() {
+            1 => Ok(signatures.remove(0)),
+            _ => Err(SigningError(SigningErrorType::Error_signatures_count)),
+        };
+
+        match self {
+            KeyScheme::Ed25519 => {
+                let (public_key, signature) = single_signature(&mut signatures)?;
+                Ok(AccountAuthenticator::Ed25519 {
+                    public_key,
+                    signature,
+                })
+            },
+            KeyScheme::MultiEd25519(public_key) => {
+                MultiEd25519Authenticator::new(public_key.clone(), signatures)
+                    .map(AccountAuthenticator::MultiEd25519)
+            },
+            KeyScheme::SingleKeyEd25519 => {
+                let (public_key, signature) = single_signature(&mut signatures)?;
+                Ok(AccountAuthenticator::SingleKey {
+                    public_key: AnyPublicKey::ed25519(&public_key)?,
+                    signature: AnySignature::ed25519(&signature)?,
+                })
+            },
+            KeyScheme::SingleKeySecp256k1 => {
+                let (public_key, signature) = single_signature(&mut signatures)?;
+                Ok(AccountAuthenticator::SingleKey {
+                    public_key: AnyPublicKey::secp256k1(&public_key)?,
+                    signature: AnySignature::secp256k1(&signature)?,
+                })
+            },
+            KeyScheme::MultiKey(multi_key) => {
+                MultiKeyAuthenticator::new(multi_key.clone(), signatures)
+                    .map(AccountAuthenticator::MultiKey)
+            },
+        }
+    }
+
+    /// Number of signatures required to authorize a transaction.
+    pub fn signatures_required(&self) -> u8 {
+        match self {
+            KeyScheme::MultiEd25519(public_key) => public_key.threshold,
+            KeyScheme::MultiKey(multi_key) => multi_key.signatures_required,
+            KeyScheme::Ed25519 | KeyScheme::SingleKeyEd25519 | KeyScheme::SingleKeySecp256k1 => 1,
+        }
+    }
+
+    /// Signs the `preimage` on behalf of the sender.
+    /// If the scheme is `MultiEd25519` or `MultiKey`, the private key must match one of the public keys.
+    /// A single private key cannot satisfy a K-of-N account with K > 1,
+    /// so [`SigningErrorType::Error_not_supported`] is returned in that case.
+    /// Such transactions should be signed externally and compiled with all the signatures.
+    pub fn sign(&self, private_key: &[u8], preimage: &[u8]) -> SigningResult<AccountAuthenticator> {
+        if self.signatures_required() > 1 {
+            return Err(SigningError(SigningErrorType::Error_not_supported));
+        }
+
+        let signature = match self {
+            KeyScheme::Ed25519 | KeyScheme::MultiEd25519(_) | KeyScheme::SingleKeyEd25519 => {
+                sign_ed25519(private_key, preimage)?
+            },
+            KeyScheme::SingleKeySecp256k1 => sign_secp256k1(private_key, preimage)?,
+            KeyScheme::MultiKey(multi_key) => {
+                let ed25519_signature = sign_ed25519(private_key, preimage)?;
+                let is_ed25519_key = multi_key
+                    .public_keys
+                    .iter()
+                    .any(|public_key| public_key.matches(&ed25519_signature.0));
+                if is_ed25519_key {
+                    ed25519_signature
+                } else {
+                    sign_secp256k1(private_key, preimage)?
+                }
+            },
+        };
+        self.authenticator(vec![signature])
+    }
+}
+
+/// Returns `(public_key, signature)`.
+fn sign_ed25519(private_key: &[u8], preimage: &[u8]) -> SigningResult<(Data, Data)> {
+    let key_pair = ed25519::sha512::KeyPair::try_from(private_key)?;
+    let signature = key_pair.private().sign(preimage.to_vec())?;
+    Ok((
+        key_pair.public().as_slice().to_vec(),
+        signature.to_bytes().into_vec(),
+    ))
+}
+
+/// Returns `(public_key, signature)`, where the signature is `r || s` of the SHA3-256 preimage hash.
+fn sign_secp256k1(private_key: &[u8], preimage: &[u8]) -> SigningResult<(Data, Data)> {
+    let key_pair = secp256k1::KeyPair::try_from(private_key)?;
+    let hash = H256::try_from(sha3_256(preimage).as_slice())
+        .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+    let signature = key_pair.sign(hash)?;
+    let mut rs = signature.r().to_vec();
+    rs.extend_from_slice(signature.s().as_slice());
+    Ok((key_pair.public().uncompressed().to_vec(), rs))
+}
+
+fn check_threshold(num_of_keys: usize, threshold: u8, max_num_of_keys: usize) -> SigningResult<()> {
+    if num_of_keys > max_num_of_keys || threshold == 0 || threshold as usize > num_of_keys {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    }
+    Ok(())
+}
+
+/// Finds the indexes of the signers' public keys and sorts the signatures by the index.
+/// Returns an error if a public key is unknown or used twice, or there are fewer signatures than `threshold`.
+fn index_signatures<Key, F>(
+    public_keys: &[Key],
+    threshold: u8,
+    signatures: Vec<(Data, Data)>,
+    matches: F,
+) -> SigningResult<Vec<(u8, Data)>>
+where
+    F: Fn(&Key, &[u8]) -> bool,
+{
+    let mut indexed = signatures
+        .into_iter()
+        .map(|(public_key, signature)| {
+            let idx = public_keys
+                .iter()
+                .position(|key| matches(key, &public_key))
+                .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+            Ok((idx as u8, signature))
+        })
+        .collect::<SigningResult<Vec<_>>>()?;
+    indexed.sort_by_key(|(idx, _)| *idx);
+
+    if indexed.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    }
+    if indexed.len() < threshold as usize {
+        return Err(SigningError(SigningErrorType::Error_signatures_count));
+    }
+    Ok(indexed)
+}
+
+/// Sets a bit for every signer index, starting from the most significant bit of the first byte.
+fn bitmap<T>(signatures: &[(u8, T)], len: usize) -> Data {
+    let mut bitmap = vec![0; len];
+    for (idx, _) in signatures {
+        bitmap[*idx as usize / 8] |= 0b1000_0000 >> (idx % 8);
+    }
+    bitmap
+}
+
+fn addresses_to_json(addresses: &[AccountAddress]) -> Value {
+    addresses
+        .iter()
+        .map(|address| Value::String(address.to_hex_literal()))
+        .collect()
+}
+
+fn authenticators_to_json(authenticators: &[AccountAuthenticator]) -> Value {
+    authenticators
+        .iter()
+        .map(AccountAuthenticator::to_json)
+        .collect()
+}
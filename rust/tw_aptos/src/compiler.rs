@@ -1,9 +1,11 @@
 use crate::address::Address;
+use crate::authenticator::KeyScheme;
 use crate::transaction_builder;
 use std::str::FromStr;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_coin_entry::signing_output_error;
+use tw_hash::sha3::sha3_256;
 use tw_proto::Aptos::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
 
@@ -21,15 +23,30 @@ impl Compiler {
     fn preimage_hashes_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let key_scheme = KeyScheme::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
         let sender = Address::from_str(&input.sender)?;
-        let signed_tx = builder
+        let builder = builder
             .sender(sender.inner())
-            .sequence_number(input.sequence_number as u64)
-            .build()?
-            .pre_image()?;
+            .sequence_number(input.sequence_number as u64);
+
+        let pre_image = if builder.has_additional_signers() {
+            builder.build_with_data()?.pre_image()?
+        } else {
+            builder.build()?.pre_image()?
+        };
+        let additional_schemes = Self::additional_signer_schemes(&input)?;
+        let has_secp256k1_keys = key_scheme.has_secp256k1_keys()
+            || additional_schemes.iter().any(KeyScheme::has_secp256k1_keys);
+        // secp256k1 keys sign the SHA3-256 hash of the preimage.
+        let data_hash = if has_secp256k1_keys {
+            sha3_256(&pre_image)
+        } else {
+            Vec::default()
+        };
         Ok(CompilerProto::PreSigningOutput {
-            data: signed_tx.into(),
+            data: pre_image.into(),
+            data_hash: data_hash.into(),
             ..CompilerProto::PreSigningOutput::default()
         })
     }
@@ -49,20 +66,45 @@ impl Compiler {
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let key_scheme = KeyScheme::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
         let sender = Address::from_str(&input.sender)?;
-        let signature = signatures
-            .first()
-            .ok_or(SigningError(SigningErrorType::Error_signatures_count))?;
-        let public_key = public_keys
-            .first()
-            .ok_or(SigningError(SigningErrorType::Error_signatures_count))?;
+        if signatures.is_empty() || signatures.len() != public_keys.len() {
+            return Err(SigningError(SigningErrorType::Error_signatures_count));
+        }
 
-        let signed_tx = builder
+        let builder = builder
             .sender(sender.inner())
-            .sequence_number(input.sequence_number as u64)
-            .build()?
-            .compile(signature.to_vec(), public_key.to_vec())?;
+            .sequence_number(input.sequence_number as u64);
+
+        let mut signatures: Vec<_> = public_keys.into_iter().zip(signatures).collect();
+        let signed_tx = if builder.has_additional_signers() {
+            // Signatures are expected in the following order:
+            // the sender's ones, the secondary signers, the fee payer (if present).
+            let raw_txn = builder.build_with_data()?;
+            let num_of_sender_signatures = signatures
+                .len()
+                .checked_sub(raw_txn.num_of_additional_signers())
+                .ok_or(SigningError(SigningErrorType::Error_signatures_count))?;
+
+            let additional_schemes = Self::additional_signer_schemes(&input)?;
+            let mut additional_signers = signatures
+                .split_off(num_of_sender_signatures)
+                .into_iter()
+                .zip(additional_schemes)
+                .map(|(signature, scheme)| scheme.authenticator(vec![signature]));
+            let secondary_signers = additional_signers
+                .by_ref()
+                .take(raw_txn.secondary_signer_addresses().len())
+                .collect::<SigningResult<_>>()?;
+            let fee_payer_signer = additional_signers.next().transpose()?;
+
+            let sender = key_scheme.authenticator(signatures)?;
+            raw_txn.compile(sender, secondary_signers, fee_payer_signer)?
+        } else {
+            let sender = key_scheme.authenticator(signatures)?;
+            builder.build()?.compile(sender)?
+        };
         Ok(Proto::SigningOutput {
             raw_txn: signed_tx.raw_txn_bytes().clone().into(),
             encoded: signed_tx.encoded().clone().into(),
@@ -71,4 +113,19 @@ impl Compiler {
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// Returns the key schemes of the secondary signers followed by the fee payer's one.
+    /// Additional signers that are not specified in the input are considered to be legacy Ed25519 accounts.
+    fn additional_signer_schemes(input: &Proto::SigningInput<'_>) -> SigningResult<Vec<KeyScheme>> {
+        let num_of_secondary_signers = input.secondary_signer_addresses.len();
+        let secondary_signers =
+            (0..num_of_secondary_signers).map(|idx| input.secondary_signers.get(idx));
+        let fee_payer_signer =
+            (!input.fee_payer_address.is_empty()).then_some(input.fee_payer_signer.as_ref());
+
+        secondary_signers
+            .chain(fee_payer_signer)
+            .map(|signer| signer.map_or(Ok(KeyScheme::Ed25519), KeyScheme::from_account_signature))
+            .collect()
+    }
 }
@@ -3,7 +3,9 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::Address;
+use crate::authenticator::AnyPublicKey;
 use crate::compiler::Compiler;
+use crate::message_signer::AptosMessageSigner;
 use crate::signer::Signer;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
@@ -11,7 +13,6 @@ use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::{AddressError, AddressResult};
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
@@ -31,7 +32,7 @@ impl CoinEntry for AptosEntry {
     // Optional modules:
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = AptosMessageSigner;
     type WalletConnector = NoWalletConnector;
 
     #[inline]
@@ -60,6 +61,13 @@ impl CoinEntry for AptosEntry {
         _derivation: Derivation,
         _prefix: Option<Self::AddressPrefix>,
     ) -> AddressResult<Self::Address> {
+        // secp256k1 accounts are supported through the `SingleKey` scheme only.
+        if let Some(secp256k1) = public_key.to_secp256k1() {
+            let public_key = AnyPublicKey::secp256k1(secp256k1.compressed().as_slice())
+                .map_err(|_| AddressError::InvalidInput)?;
+            return Address::with_single_key(&public_key);
+        }
+
         let public_key = public_key
             .to_ed25519()
             .ok_or(AddressError::PublicKeyTypeMismatch)?;
@@ -98,6 +106,6 @@ impl CoinEntry for AptosEntry {
 
     #[inline]
     fn message_signer(&self) -> Option<Self::MessageSigner> {
-        None
+        Some(AptosMessageSigner)
     }
 }
@@ -4,6 +4,7 @@
 
 pub mod address;
 pub mod aptos_move_packages;
+pub mod authenticator;
 pub mod constants;
 pub mod entry;
 mod serde_helper;
@@ -12,6 +13,8 @@ pub mod nft;
 
 pub mod compiler;
 pub mod liquid_staking;
+pub mod message_signer;
+pub mod move_parameter;
 pub mod signer;
 pub mod transaction;
 pub mod transaction_builder;
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+use tw_proto::Aptos::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const FULL_MESSAGE_PREFIX: &str = "APTOS";
+
+/// Signs and verifies off-chain messages according to the Aptos wallet standard.
+/// https://github.com/aptos-foundation/AIPs/blob/main/aips/aip-62.md
+#[derive(Default)]
+pub struct AptosMessageSigner;
+
+impl MessageSigner for AptosMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl AptosMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        // Ed25519 signs the full message itself rather than its hash.
+        let full_message = Self::full_message(&input)?.into_bytes();
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::Owned(full_message.clone()),
+            data_hash: Cow::Owned(full_message),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let full_message = Self::full_message(&input)?;
+        let signature = key_pair.private().sign(full_message.as_bytes().to_vec())?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(signature.to_bytes().into_vec()),
+            full_message: Cow::Owned(full_message),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())?;
+        let signature = ed25519::Signature::try_from(input.signature.as_ref())?;
+        Ok(public_key.verify(signature, input.full_message.as_bytes().to_vec()))
+    }
+
+    /// Builds the full message that is shown to the user and signed.
+    fn full_message(input: &Proto::MessageSigningInput<'_>) -> SigningResult<String> {
+        let mut lines = vec![FULL_MESSAGE_PREFIX.to_string()];
+        if !input.address.is_empty() {
+            let address = Address::from_str(&input.address)
+                .map_err(|_| SigningError(SigningErrorType::Error_invalid_address))?;
+            lines.push(format!("address: {}", address.to_long_string()));
+        }
+        if !input.application.is_empty() {
+            lines.push(format!("application: {}", input.application));
+        }
+        if input.chain_id != 0 {
+            lines.push(format!("chainId: {}", input.chain_id));
+        }
+        lines.push(format!("message: {}", input.message));
+        lines.push(format!("nonce: {}", input.nonce));
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_message() {
+        let input = Proto::MessageSigningInput {
+            message: "Hello, world!".into(),
+            nonce: "1".into(),
+            ..Proto::MessageSigningInput::default()
+        };
+        assert_eq!(
+            AptosMessageSigner::full_message(&input).unwrap(),
+            "APTOS\nmessage: Hello, world!\nnonce: 1"
+        );
+
+        let input = Proto::MessageSigningInput {
+            message: "Welcome to Aptos!".into(),
+            nonce: "1234034".into(),
+            address: "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30".into(),
+            application: "https://example.com".into(),
+            chain_id: 1,
+            ..Proto::MessageSigningInput::default()
+        };
+        assert_eq!(
+            AptosMessageSigner::full_message(&input).unwrap(),
+            "APTOS\naddress: 0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30\napplication: https://example.com\nchainId: 1\nmessage: Welcome to Aptos!\nnonce: 1234034"
+        );
+
+        // Special addresses are zero-padded too.
+        let input = Proto::MessageSigningInput {
+            message: "Hello, world!".into(),
+            nonce: "1".into(),
+            address: "0x1".into(),
+            ..Proto::MessageSigningInput::default()
+        };
+        assert_eq!(
+            AptosMessageSigner::full_message(&input).unwrap(),
+            "APTOS\naddress: 0x0000000000000000000000000000000000000000000000000000000000000001\nmessage: Hello, world!\nnonce: 1"
+        );
+    }
+
+    #[test]
+    fn test_full_message_invalid_address() {
+        let input = Proto::MessageSigningInput {
+            message: "Hello, world!".into(),
+            nonce: "1".into(),
+            address: "0xzz".into(),
+            ..Proto::MessageSigningInput::default()
+        };
+        assert!(AptosMessageSigner::full_message(&input).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::transaction_payload::{EntryFunctionError, EntryFunctionResult};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use serde_json::Value;
+use std::str::FromStr;
+use tw_encoding::{bcs, hex};
+use tw_memory::Data;
+use tw_number::U256;
+
+/// Type of entry function parameter that can be encoded from a JSON value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MoveParameterType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    /// `0x1::string::String`
+    String,
+    /// `vector<T>`
+    Vector(Box<MoveParameterType>),
+    /// `0x1::option::Option<T>`
+    Option(Box<MoveParameterType>),
+    /// `0x1::object::Object<T>` is encoded as the address of the object.
+    Object,
+}
+
+impl MoveParameterType {
+    /// Parses the parameter types as they are declared in the function ABI.
+    /// Leading `signer` and `&signer` parameters are skipped as they are not passed as arguments.
+    pub fn parse_params<S: AsRef<str>>(params: &[S]) -> EntryFunctionResult<Vec<Self>> {
+        params
+            .iter()
+            .map(|param| param.as_ref())
+            .skip_while(|param| is_signer(param))
+            .map(MoveParameterType::from_str)
+            .collect()
+    }
+
+    /// Returns the BCS encoding of the given JSON `value`.
+    pub fn encode(&self, value: &Value) -> EntryFunctionResult<Data> {
+        let mut encoded = Data::new();
+        self.encode_to(value, &mut encoded)?;
+        Ok(encoded)
+    }
+
+    fn encode_to(&self, value: &Value, dest: &mut Data) -> EntryFunctionResult<()> {
+        match self {
+            MoveParameterType::Bool => {
+                let value = match value {
+                    Value::Bool(value) => *value,
+                    Value::String(value) => {
+                        bool::from_str(value).map_err(|_| EntryFunctionError::InvalidArguments)?
+                    },
+                    _ => return Err(EntryFunctionError::InvalidArguments),
+                };
+                dest.extend(bcs::encode(&value)?);
+            },
+            MoveParameterType::U8 => dest.extend(bcs::encode(&parse_uint::<u8>(value)?)?),
+            MoveParameterType::U16 => dest.extend(bcs::encode(&parse_uint::<u16>(value)?)?),
+            MoveParameterType::U32 => dest.extend(bcs::encode(&parse_uint::<u32>(value)?)?),
+            MoveParameterType::U64 => dest.extend(bcs::encode(&parse_uint::<u64>(value)?)?),
+            MoveParameterType::U128 => dest.extend(bcs::encode(&parse_uint::<u128>(value)?)?),
+            MoveParameterType::U256 => {
+                let value = match value {
+                    Value::Number(value) => U256::from_str(&value.to_string()),
+                    Value::String(value) => U256::from_str(value),
+                    _ => return Err(EntryFunctionError::InvalidArguments),
+                }
+                .map_err(|_| EntryFunctionError::InvalidArguments)?;
+                // BCS encodes `u256` as 32 little-endian bytes.
+                dest.extend_from_slice(value.to_little_endian().as_slice());
+            },
+            MoveParameterType::Address | MoveParameterType::Object => {
+                dest.extend(bcs::encode(&parse_address(value)?)?);
+            },
+            MoveParameterType::String => {
+                let value = value.as_str().ok_or(EntryFunctionError::InvalidArguments)?;
+                dest.extend(bcs::encode(value)?);
+            },
+            MoveParameterType::Vector(element_type) => {
+                // `vector<u8>` can be passed as a hex string.
+                if let (MoveParameterType::U8, Value::String(hex_str)) =
+                    (element_type.as_ref(), value)
+                {
+                    let bytes =
+                        hex::decode(hex_str).map_err(|_| EntryFunctionError::InvalidArguments)?;
+                    dest.extend(bcs::encode(&bytes)?);
+                    return Ok(());
+                }
+
+                let elements = value
+                    .as_array()
+                    .ok_or(EntryFunctionError::InvalidArguments)?;
+                encode_uleb128(elements.len(), dest);
+                for element in elements {
+                    element_type.encode_to(element, dest)?;
+                }
+            },
+            MoveParameterType::Option(inner_type) => {
+                // `Option<T>` is represented as a vector of zero or one element.
+                // Besides that, `null` and a plain value are accepted.
+                // An array is always considered as the value itself if `T` is a vector.
+                let is_vector = matches!(inner_type.as_ref(), MoveParameterType::Vector(_));
+                let inner = match value {
+                    Value::Null => None,
+                    Value::Array(elements) if elements.is_empty() => None,
+                    Value::Array(elements) if elements.len() == 1 && !is_vector => {
+                        Some(&elements[0])
+                    },
+                    value => Some(value),
+                };
+                match inner {
+                    Some(inner) => {
+                        dest.push(1);
+                        inner_type.encode_to(inner, dest)?;
+                    },
+                    None => dest.push(0),
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MoveParameterType {
+    type Err = EntryFunctionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let type_tag =
+            TypeTag::from_str(s.trim()).map_err(|_| EntryFunctionError::InvalidParameterTypes)?;
+        MoveParameterType::try_from(type_tag)
+    }
+}
+
+impl TryFrom<TypeTag> for MoveParameterType {
+    type Error = EntryFunctionError;
+
+    fn try_from(type_tag: TypeTag) -> Result<Self, Self::Error> {
+        match type_tag {
+            TypeTag::Bool => Ok(MoveParameterType::Bool),
+            TypeTag::U8 => Ok(MoveParameterType::U8),
+            TypeTag::U16 => Ok(MoveParameterType::U16),
+            TypeTag::U32 => Ok(MoveParameterType::U32),
+            TypeTag::U64 => Ok(MoveParameterType::U64),
+            TypeTag::U128 => Ok(MoveParameterType::U128),
+            TypeTag::U256 => Ok(MoveParameterType::U256),
+            TypeTag::Address => Ok(MoveParameterType::Address),
+            TypeTag::Vector(element_type) => Ok(MoveParameterType::Vector(Box::new(
+                MoveParameterType::try_from(*element_type)?,
+            ))),
+            TypeTag::Struct(struct_tag) => MoveParameterType::try_from(*struct_tag),
+            TypeTag::Signer => Err(EntryFunctionError::InvalidParameterTypes),
+        }
+    }
+}
+
+impl TryFrom<StructTag> for MoveParameterType {
+    type Error = EntryFunctionError;
+
+    fn try_from(mut struct_tag: StructTag) -> Result<Self, Self::Error> {
+        if struct_tag.address != AccountAddress::ONE {
+            return Err(EntryFunctionError::InvalidParameterTypes);
+        }
+
+        match (struct_tag.module.as_str(), struct_tag.name.as_str()) {
+            ("string", "String") => Ok(MoveParameterType::String),
+            ("object", "Object") => Ok(MoveParameterType::Object),
+            ("option", "Option") if struct_tag.type_params.len() == 1 => {
+                let inner_type = struct_tag.type_params.remove(0);
+                Ok(MoveParameterType::Option(Box::new(
+                    MoveParameterType::try_from(inner_type)?,
+                )))
+            },
+            _ => Err(EntryFunctionError::InvalidParameterTypes),
+        }
+    }
+}
+
+fn is_signer(param: &str) -> bool {
+    param.trim().trim_start_matches('&') == "signer"
+}
+
+/// Parses an unsigned integer given either as a JSON number or as a decimal string.
+fn parse_uint<T>(value: &Value) -> EntryFunctionResult<T>
+where
+    T: FromStr,
+{
+    match value {
+        Value::Number(number) => T::from_str(&number.to_string()),
+        Value::String(number) => T::from_str(number),
+        _ => return Err(EntryFunctionError::InvalidArguments),
+    }
+    .map_err(|_| EntryFunctionError::InvalidArguments)
+}
+
+fn parse_address(value: &Value) -> EntryFunctionResult<AccountAddress> {
+    let address = value.as_str().ok_or(EntryFunctionError::InvalidArguments)?;
+    Address::from_str(address)
+        .map(|address| address.inner())
+        .map_err(|_| EntryFunctionError::InvalidArguments)
+}
+
+fn encode_uleb128(mut value: usize, dest: &mut Data) {
+    while value >= 0x80 {
+        dest.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    dest.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn encode(param: &str, value: Value) -> String {
+        let encoded = MoveParameterType::from_str(param)
+            .unwrap()
+            .encode(&value)
+            .unwrap();
+        hex::encode(encoded, false)
+    }
+
+    #[test]
+    fn test_parse_params_skips_signer() {
+        let params = MoveParameterType::parse_params(&["&signer", "address", "u64"]).unwrap();
+        assert_eq!(
+            params,
+            vec![MoveParameterType::Address, MoveParameterType::U64]
+        );
+    }
+
+    #[test]
+    fn test_encode_numbers() {
+        assert_eq!(encode("bool", json!(true)), "01");
+        assert_eq!(encode("u8", json!(255)), "ff");
+        assert_eq!(encode("u16", json!("258")), "0201");
+        assert_eq!(encode("u32", json!(1)), "01000000");
+        assert_eq!(encode("u64", json!("1000")), "e803000000000000");
+        assert_eq!(
+            encode("u128", json!("340282366920938463463374607431768211455")),
+            "ffffffffffffffffffffffffffffffff"
+        );
+        assert_eq!(
+            encode("u256", json!("1")),
+            "0100000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_encode_address_and_object() {
+        let expected = "0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(encode("address", json!("0x1")), expected);
+        assert_eq!(
+            encode(
+                "0x1::object::Object<0x1::fungible_asset::Metadata>",
+                json!("0x1")
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_encode_string_and_vectors() {
+        assert_eq!(encode("0x1::string::String", json!("abc")), "03616263");
+        assert_eq!(encode("vector<u8>", json!("0x0102")), "020102");
+        assert_eq!(encode("vector<u8>", json!([1, 2])), "020102");
+        assert_eq!(
+            encode("vector<u64>", json!(["1", 2])),
+            "0201000000000000000200000000000000"
+        );
+        assert_eq!(
+            encode("vector<0x1::string::String>", json!(["a", "bc"])),
+            "020161026263"
+        );
+    }
+
+    #[test]
+    fn test_encode_option() {
+        assert_eq!(encode("0x1::option::Option<u8>", json!(null)), "00");
+        assert_eq!(encode("0x1::option::Option<u64>", json!([])), "00");
+        assert_eq!(
+            encode("0x1::option::Option<u64>", json!(["1"])),
+            "010100000000000000"
+        );
+        assert_eq!(
+            encode("0x1::option::Option<u64>", json!(1)),
+            "010100000000000000"
+        );
+        assert_eq!(
+            encode("0x1::option::Option<vector<u8>>", json!("0xff")),
+            "0101ff"
+        );
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        assert!(MoveParameterType::from_str("signer").is_err());
+        assert!(MoveParameterType::from_str("0x2::coin::Coin").is_err());
+        assert!(MoveParameterType::from_str("u64")
+            .unwrap()
+            .encode(&json!("-1"))
+            .is_err());
+        assert!(MoveParameterType::from_str("u8")
+            .unwrap()
+            .encode(&json!(256))
+            .is_err());
+    }
+}
@@ -3,11 +3,11 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::Address;
+use crate::authenticator::{AccountAuthenticator, KeyScheme};
 use crate::transaction_builder;
 use std::str::FromStr;
 use tw_coin_entry::error::SigningResult;
 use tw_coin_entry::signing_output_error;
-use tw_keypair::ed25519;
 use tw_proto::Aptos::Proto;
 
 pub struct Signer;
@@ -22,14 +22,33 @@ impl Signer {
     fn sign_proto_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
-        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let key_scheme = KeyScheme::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
         let sender = Address::from_str(&input.sender)?;
-        let signed_tx = builder
+        let builder = builder
             .sender(sender.inner())
-            .sequence_number(input.sequence_number as u64)
-            .build()?
-            .sign(key_pair)?;
+            .sequence_number(input.sequence_number as u64);
+
+        let signed_tx = if builder.has_additional_signers() {
+            let secondary_signers = input
+                .secondary_signers
+                .iter()
+                .map(AccountAuthenticator::try_from)
+                .collect::<SigningResult<_>>()?;
+            let fee_payer_signer = input
+                .fee_payer_signer
+                .as_ref()
+                .map(AccountAuthenticator::try_from)
+                .transpose()?;
+            builder.build_with_data()?.sign(
+                &key_scheme,
+                &input.private_key,
+                secondary_signers,
+                fee_payer_signer,
+            )?
+        } else {
+            builder.build()?.sign(&key_scheme, &input.private_key)?
+        };
         Ok(Proto::SigningOutput {
             raw_txn: signed_tx.raw_txn_bytes().clone().into(),
             encoded: signed_tx.encoded().clone().into(),
@@ -2,70 +2,15 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::constants::APTOS_SALT;
+use crate::authenticator::{AccountAuthenticator, KeyScheme, TransactionAuthenticator};
+use crate::constants::{APTOS_RAW_TX_WITH_DATA_SALT, APTOS_SALT};
 use crate::transaction_payload::TransactionPayload;
 use move_core_types::account_address::AccountAddress;
 use serde::Serialize;
 use serde_json::{json, Value};
-use std::borrow::Cow;
-use tw_coin_entry::error::SigningResult;
-use tw_encoding::hex::encode;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_encoding::{bcs, EncodingResult};
-use tw_keypair::ed25519::sha512::KeyPair;
-use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_memory::Data;
-use tw_proto::Aptos::Proto;
-
-#[derive(Clone, Serialize)]
-pub enum TransactionAuthenticator {
-    /// Single Ed25519 signature
-    Ed25519 {
-        public_key: Vec<u8>,
-        signature: Vec<u8>,
-    },
-}
-
-impl From<TransactionAuthenticator> for Proto::TransactionAuthenticator<'_> {
-    fn from(from: TransactionAuthenticator) -> Self {
-        Proto::TransactionAuthenticator {
-            signature: Cow::from(from.get_signature()),
-            public_key: Cow::from(from.get_public_key()),
-        }
-    }
-}
-
-impl TransactionAuthenticator {
-    pub fn get_signature(&self) -> Vec<u8> {
-        match self {
-            TransactionAuthenticator::Ed25519 {
-                public_key: _public_key,
-                signature,
-            } => signature.clone(),
-        }
-    }
-
-    pub fn get_public_key(&self) -> Vec<u8> {
-        match self {
-            TransactionAuthenticator::Ed25519 {
-                public_key,
-                signature: _signature,
-            } => public_key.clone(),
-        }
-    }
-
-    pub fn to_json(&self) -> Value {
-        match self {
-            TransactionAuthenticator::Ed25519 {
-                public_key,
-                signature,
-            } => {
-                json!({"public_key": encode(public_key, true),
-                       "signature": encode(signature, true),
-                       "type": "ed25519_signature"})
-            },
-        }
-    }
-}
 
 /// RawTransaction is the portion of a transaction that a client signs.
 #[derive(Clone, Serialize)]
@@ -138,16 +83,16 @@ impl RawTransaction {
         self.msg_to_sign()
     }
 
-    pub fn compile(
+    /// Compiles the transaction with the sender's authenticator.
+    pub fn compile(&self, sender: AccountAuthenticator) -> SigningResult<SignedTransaction> {
+        self.compile_with_authenticator(TransactionAuthenticator::from(sender))
+    }
+
+    fn compile_with_authenticator(
         &self,
-        signature: Vec<u8>,
-        public_key: Vec<u8>,
+        auth: TransactionAuthenticator,
     ) -> SigningResult<SignedTransaction> {
         let serialized = self.serialize()?;
-        let auth = TransactionAuthenticator::Ed25519 {
-            public_key,
-            signature,
-        };
         let mut encoded = serialized.clone();
         encoded.extend_from_slice(bcs::encode(&auth)?.as_slice());
         Ok(SignedTransaction {
@@ -158,11 +103,14 @@ impl RawTransaction {
         })
     }
 
-    pub fn sign(self, key_pair: KeyPair) -> SigningResult<SignedTransaction> {
+    pub fn sign(
+        self,
+        key_scheme: &KeyScheme,
+        private_key: &[u8],
+    ) -> SigningResult<SignedTransaction> {
         let to_sign = self.pre_image()?;
-        let signature = key_pair.private().sign(to_sign)?.to_bytes().into_vec();
-        let pubkey = key_pair.public().as_slice().to_vec();
-        self.compile(signature, pubkey)
+        let sender = key_scheme.sign(private_key, &to_sign)?;
+        self.compile(sender)
     }
 
     pub fn to_json(&self) -> Value {
@@ -177,6 +125,143 @@ impl RawTransaction {
     }
 }
 
+/// A [`RawTransaction`] that is signed by more than one account.
+///
+/// All the signers, i.e. the sender, the secondary signers and the fee payer,
+/// sign the same [`RawTransactionWithData::pre_image`].
+#[derive(Clone, Serialize)]
+pub enum RawTransactionWithData {
+    MultiAgent {
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+    },
+    MultiAgentWithFeePayer {
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        fee_payer_address: AccountAddress,
+    },
+}
+
+impl RawTransactionWithData {
+    pub fn new_multi_agent(
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+    ) -> Self {
+        RawTransactionWithData::MultiAgent {
+            raw_txn,
+            secondary_signer_addresses,
+        }
+    }
+
+    pub fn new_fee_payer(
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        fee_payer_address: AccountAddress,
+    ) -> Self {
+        RawTransactionWithData::MultiAgentWithFeePayer {
+            raw_txn,
+            secondary_signer_addresses,
+            fee_payer_address,
+        }
+    }
+
+    pub fn raw_txn(&self) -> &RawTransaction {
+        match self {
+            RawTransactionWithData::MultiAgent { raw_txn, .. }
+            | RawTransactionWithData::MultiAgentWithFeePayer { raw_txn, .. } => raw_txn,
+        }
+    }
+
+    pub fn secondary_signer_addresses(&self) -> &[AccountAddress] {
+        match self {
+            RawTransactionWithData::MultiAgent {
+                secondary_signer_addresses,
+                ..
+            }
+            | RawTransactionWithData::MultiAgentWithFeePayer {
+                secondary_signer_addresses,
+                ..
+            } => secondary_signer_addresses,
+        }
+    }
+
+    /// Returns the number of the secondary signers plus the fee payer, if present.
+    pub fn num_of_additional_signers(&self) -> usize {
+        match self {
+            RawTransactionWithData::MultiAgent {
+                secondary_signer_addresses,
+                ..
+            } => secondary_signer_addresses.len(),
+            RawTransactionWithData::MultiAgentWithFeePayer {
+                secondary_signer_addresses,
+                ..
+            } => secondary_signer_addresses.len() + 1,
+        }
+    }
+
+    pub fn pre_image(&self) -> SigningResult<Vec<u8>> {
+        let serialized = bcs::encode(&self)?;
+        let mut preimage = tw_hash::sha3::sha3_256(APTOS_RAW_TX_WITH_DATA_SALT);
+        preimage.extend_from_slice(serialized.as_slice());
+        Ok(preimage)
+    }
+
+    /// Compiles the transaction with the given authenticators.
+    /// The number of `secondary_signers` must match the number of secondary signer addresses,
+    /// and `fee_payer_signer` must be set if and only if the transaction is sponsored.
+    pub fn compile(
+        &self,
+        sender: AccountAuthenticator,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_signer: Option<AccountAuthenticator>,
+    ) -> SigningResult<SignedTransaction> {
+        if secondary_signers.len() != self.secondary_signer_addresses().len() {
+            return Err(SigningError(SigningErrorType::Error_signatures_count));
+        }
+
+        let auth = match self {
+            RawTransactionWithData::MultiAgent { .. } if fee_payer_signer.is_some() => {
+                return Err(SigningError(SigningErrorType::Error_signatures_count));
+            },
+            RawTransactionWithData::MultiAgent {
+                secondary_signer_addresses,
+                ..
+            } => TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses: secondary_signer_addresses.clone(),
+                secondary_signers,
+            },
+            RawTransactionWithData::MultiAgentWithFeePayer {
+                secondary_signer_addresses,
+                fee_payer_address,
+                ..
+            } => TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses: secondary_signer_addresses.clone(),
+                secondary_signers,
+                fee_payer_address: *fee_payer_address,
+                fee_payer_signer: fee_payer_signer
+                    .ok_or(SigningError(SigningErrorType::Error_signatures_count))?,
+            },
+        };
+        self.raw_txn().compile_with_authenticator(auth)
+    }
+
+    /// Signs the transaction on behalf of the sender,
+    /// and compiles it with the other signers' authenticators.
+    pub fn sign(
+        &self,
+        key_scheme: &KeyScheme,
+        private_key: &[u8],
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_signer: Option<AccountAuthenticator>,
+    ) -> SigningResult<SignedTransaction> {
+        let to_sign = self.pre_image()?;
+        let sender = key_scheme.sign(private_key, &to_sign)?;
+        self.compile(sender, secondary_signers, fee_payer_signer)
+    }
+}
+
 /// A transaction that has been signed.
 ///
 /// A `SignedTransaction` is a single transaction that can be atomically executed. Clients submit
@@ -2,10 +2,11 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::address::from_account_error;
+use crate::address::{from_account_error, Address, AptosAddress};
 use crate::aptos_move_packages::{
     aptos_account_create_account, aptos_account_transfer, aptos_account_transfer_coins,
-    coin_transfer, managed_coin_register, token_transfers_cancel_offer_script,
+    coin_transfer, digital_asset_token_type, managed_coin_register, object_transfer,
+    primary_fungible_store_transfer, token_transfers_cancel_offer_script,
     token_transfers_claim_script, token_transfers_offer_script,
 };
 use crate::constants::{GAS_UNIT_PRICE, MAX_GAS_AMOUNT};
@@ -13,7 +14,7 @@ use crate::liquid_staking::{
     tortuga_claim, tortuga_stake, tortuga_unstake, LiquidStakingOperation,
 };
 use crate::nft::NftOperation;
-use crate::transaction::RawTransaction;
+use crate::transaction::{RawTransaction, RawTransactionWithData};
 use crate::transaction_payload::{
     convert_proto_struct_tag_to_type_tag, EntryFunction, TransactionPayload,
 };
@@ -21,13 +22,15 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::TypeTag;
 use serde_json::Value;
 use std::str::FromStr;
-use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::error::{AddressResult, SigningError, SigningErrorType, SigningResult};
 use tw_proto::Aptos::Proto::mod_SigningInput::OneOftransaction_payload;
 use tw_proto::Aptos::Proto::SigningInput;
 
 pub struct TransactionBuilder {
     sender: Option<AccountAddress>,
     sequence_number: Option<u64>,
+    secondary_signer_addresses: Vec<AccountAddress>,
+    fee_payer_address: Option<AccountAddress>,
     payload: TransactionPayload,
     max_gas_amount: u64,
     gas_unit_price: u64,
@@ -46,6 +49,38 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn secondary_signers(mut self, secondary_signer_addresses: Vec<AccountAddress>) -> Self {
+        self.secondary_signer_addresses = secondary_signer_addresses;
+        self
+    }
+
+    pub fn fee_payer(mut self, fee_payer_address: Option<AccountAddress>) -> Self {
+        self.fee_payer_address = fee_payer_address;
+        self
+    }
+
+    /// Whether the transaction is signed by more than one account,
+    /// i.e. it should be built with [`TransactionBuilder::build_with_data`].
+    pub fn has_additional_signers(&self) -> bool {
+        !self.secondary_signer_addresses.is_empty() || self.fee_payer_address.is_some()
+    }
+
+    /// Builds a multi-agent or a fee payer transaction.
+    pub fn build_with_data(mut self) -> SigningResult<RawTransactionWithData> {
+        let secondary_signer_addresses = std::mem::take(&mut self.secondary_signer_addresses);
+        let fee_payer_address = self.fee_payer_address.take();
+        let raw_txn = self.build()?;
+
+        Ok(match fee_payer_address {
+            Some(fee_payer_address) => RawTransactionWithData::new_fee_payer(
+                raw_txn,
+                secondary_signer_addresses,
+                fee_payer_address,
+            ),
+            None => RawTransactionWithData::new_multi_agent(raw_txn, secondary_signer_addresses),
+        })
+    }
+
     pub fn build(self) -> SigningResult<RawTransaction> {
         let sender = self
             .sender
@@ -88,7 +123,15 @@ impl TransactionFactory {
             .with_gas_unit_price(input.gas_unit_price)
             .with_max_gas_amount(input.max_gas_amount)
             .with_transaction_expiration_time(input.expiration_timestamp_secs);
-        match input.transaction_payload {
+        let secondary_signer_addresses = input
+            .secondary_signer_addresses
+            .iter()
+            .map(|address| Address::from_str(address).map(|address| address.inner()))
+            .collect::<AddressResult<Vec<_>>>()?;
+        let fee_payer_address =
+            Address::from_str_optional(&input.fee_payer_address)?.map(|address| address.inner());
+
+        let builder = match input.transaction_payload {
             OneOftransaction_payload::transfer(transfer) => factory
                 .implicitly_create_user_account_and_transfer(
                     AccountAddress::from_str(&transfer.to).map_err(from_account_error)?,
@@ -132,6 +175,31 @@ impl TransactionFactory {
                     convert_proto_struct_tag_to_type_tag(func)?,
                 )
             },
+            OneOftransaction_payload::entry_function(entry_function) => {
+                let arguments = serde_json::from_str::<Value>(&entry_function.arguments)?;
+                let entry_function = EntryFunction::with_typed_arguments(
+                    &entry_function.function,
+                    &entry_function.type_arguments,
+                    arguments,
+                    &entry_function.parameter_types,
+                )?;
+                Ok(factory.payload(TransactionPayload::EntryFunction(entry_function)))
+            },
+            OneOftransaction_payload::fungible_asset_transfer(fungible_asset_transfer) => factory
+                .fungible_asset_transfer(
+                    AccountAddress::from_str(&fungible_asset_transfer.metadata_address)
+                        .map_err(from_account_error)?,
+                    AccountAddress::from_str(&fungible_asset_transfer.to)
+                        .map_err(from_account_error)?,
+                    fungible_asset_transfer.amount,
+                ),
+            OneOftransaction_payload::digital_asset_transfer(digital_asset_transfer) => factory
+                .digital_asset_transfer(
+                    AccountAddress::from_str(&digital_asset_transfer.token_address)
+                        .map_err(from_account_error)?,
+                    AccountAddress::from_str(&digital_asset_transfer.to)
+                        .map_err(from_account_error)?,
+                ),
             OneOftransaction_payload::None => {
                 let is_blind_sign = !input.any_encoded.is_empty();
                 let v = serde_json::from_str::<Value>(&input.any_encoded)?;
@@ -142,7 +210,11 @@ impl TransactionFactory {
                     Err(SigningError(SigningErrorType::Error_input_parse))
                 }
             },
-        }
+        }?;
+
+        Ok(builder
+            .secondary_signers(secondary_signer_addresses)
+            .fee_payer(fee_payer_address))
     }
 
     pub fn with_max_gas_amount(mut self, max_gas_amount: u64) -> Self {
@@ -243,10 +315,29 @@ impl TransactionFactory {
         Ok(self.payload(aptos_account_transfer_coins(coin_type, to, amount)?))
     }
 
+    pub fn fungible_asset_transfer(
+        &self,
+        metadata: AccountAddress,
+        to: AccountAddress,
+        amount: u64,
+    ) -> SigningResult<TransactionBuilder> {
+        Ok(self.payload(primary_fungible_store_transfer(metadata, to, amount)?))
+    }
+
+    pub fn digital_asset_transfer(
+        &self,
+        token: AccountAddress,
+        to: AccountAddress,
+    ) -> SigningResult<TransactionBuilder> {
+        Ok(self.payload(object_transfer(digital_asset_token_type(), token, to)?))
+    }
+
     fn transaction_builder(&self, payload: TransactionPayload) -> TransactionBuilder {
         TransactionBuilder {
             sender: None,
             sequence_number: None,
+            secondary_signer_addresses: Vec::new(),
+            fee_payer_address: None,
             payload,
             max_gas_amount: self.max_gas_amount,
             gas_unit_price: self.gas_unit_price,
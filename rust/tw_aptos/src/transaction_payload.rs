@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::move_parameter::MoveParameterType;
 use crate::serde_helper::vec_bytes;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
@@ -27,6 +28,7 @@ pub enum EntryFunctionError {
     EncodingError,
     MissingTypeArguments,
     InvalidTypeArguments,
+    InvalidParameterTypes,
 }
 
 impl From<EncodingError> for EntryFunctionError {
@@ -176,6 +178,51 @@ impl TransactionPayload {
 }
 
 impl EntryFunction {
+    /// Creates an entry function call with the arguments encoded against the given parameter types.
+    ///
+    /// * `function` - function identifier, e.g. `0x1::aptos_account::transfer`.
+    /// * `type_arguments` - type arguments, e.g. `0x1::aptos_coin::AptosCoin`.
+    /// * `arguments` - JSON array of the arguments.
+    /// * `parameter_types` - types of the function parameters, e.g. `&signer`, `address`, `u64`.
+    pub fn with_typed_arguments<S: AsRef<str>>(
+        function: &str,
+        type_arguments: &[S],
+        arguments: Value,
+        parameter_types: &[S],
+    ) -> EntryFunctionResult<Self> {
+        let tag =
+            StructTag::from_str(function).map_err(|_| EntryFunctionError::InvalidFunctionName)?;
+
+        let ty_args = type_arguments
+            .iter()
+            .map(|ty_arg| {
+                TypeTag::from_str(ty_arg.as_ref())
+                    .map_err(|_| EntryFunctionError::InvalidTypeArguments)
+            })
+            .collect::<EntryFunctionResult<Vec<TypeTag>>>()?;
+
+        let params = MoveParameterType::parse_params(parameter_types)?;
+        let json_args = arguments
+            .as_array()
+            .ok_or(EntryFunctionError::MissingArguments)?;
+        if json_args.len() != params.len() {
+            return Err(EntryFunctionError::InvalidArguments);
+        }
+        let args = params
+            .iter()
+            .zip(json_args)
+            .map(|(param, arg)| param.encode(arg))
+            .collect::<EntryFunctionResult<Vec<Data>>>()?;
+
+        Ok(EntryFunction {
+            module: tag.module_id(),
+            function: tag.name,
+            ty_args,
+            args,
+            json_args: arguments,
+        })
+    }
+
     pub fn new(
         module: ModuleId,
         function: Identifier,
@@ -148,6 +148,7 @@ fn setup_proto_transaction<'a>(
         private_key: private.into(),
         any_encoded: any_encoded.into(),
         transaction_payload: payload,
+        ..SigningInput::default()
     };
 
     input
@@ -869,3 +870,189 @@ fn test_aptos_blind_sign_unstaking() {
                             }
                     }"#);
 }
+
+// The same transaction as in `test_aptos_sign_transaction_transfer`, built from a generic entry function call.
+#[test]
+fn test_aptos_sign_entry_function() {
+    let input = SigningInput {
+        transaction_payload: Proto::mod_SigningInput::OneOftransaction_payload::entry_function(
+            Proto::EntryFunctionMessage {
+                function: "0x1::aptos_account::transfer".into(),
+                type_arguments: vec![],
+                arguments:
+                    r#"["0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", 1000]"#
+                        .into(),
+                parameter_types: vec!["&signer".into(), "address".into(), "u64".into()],
+            },
+        ),
+        ..setup_proto_transaction(
+            "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",
+            "entry_function",
+            99,
+            33,
+            3296766,
+            3664390082,
+            100,
+            "",
+            None,
+        )
+    };
+    let output = Signer::sign_proto(input);
+    test_tx_result(output,
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021",
+                   "5707246db31e2335edc4316a7a656a11691d1d1647f6e864d1ab12f43428aaaf806cf02120d0b608cdd89c5c904af7b137432aacdd60cc53f9fad7bd33578e01",
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c405707246db31e2335edc4316a7a656a11691d1d1647f6e864d1ab12f43428aaaf806cf02120d0b608cdd89c5c904af7b137432aacdd60cc53f9fad7bd33578e01",
+                   r#"{
+            "expiration_timestamp_secs": "3664390082",
+            "gas_unit_price": "100",
+            "max_gas_amount": "3296766",
+            "payload": {
+                "arguments": ["0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", 1000],
+                "function": "0x1::aptos_account::transfer",
+                "type": "entry_function_payload",
+                "type_arguments": []
+            },
+            "sender": "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "sequence_number": "99",
+            "signature": {
+                "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                "signature": "0x5707246db31e2335edc4316a7a656a11691d1d1647f6e864d1ab12f43428aaaf806cf02120d0b608cdd89c5c904af7b137432aacdd60cc53f9fad7bd33578e01",
+                "type": "ed25519_signature"
+            }
+        }"#);
+}
+
+#[test]
+fn test_aptos_sign_entry_function_arguments_mismatch() {
+    let input = SigningInput {
+        transaction_payload: Proto::mod_SigningInput::OneOftransaction_payload::entry_function(
+            Proto::EntryFunctionMessage {
+                function: "0x1::aptos_account::transfer".into(),
+                type_arguments: vec![],
+                arguments: r#"["0x1"]"#.into(),
+                parameter_types: vec!["&signer".into(), "address".into(), "u64".into()],
+            },
+        ),
+        ..setup_proto_transaction(
+            "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",
+            "entry_function",
+            99,
+            33,
+            3296766,
+            3664390082,
+            100,
+            "",
+            None,
+        )
+    };
+    let output = Signer::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_aptos_fungible_asset_transfer() {
+    let input = SigningInput {
+        transaction_payload:
+            Proto::mod_SigningInput::OneOftransaction_payload::fungible_asset_transfer(
+                Proto::FungibleAssetTransferMessage {
+                    metadata_address:
+                        "0x357b0b74bc833e95a115ad22604854d6b0fca151cecd94111770e5d6ffc9dc2b".into(),
+                    to: "0x2d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a4958".into(),
+                    amount: 1000000,
+                },
+            ),
+        ..setup_proto_transaction(
+            "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", // Sender's address
+            "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",   // Keypair
+            "fungible_asset_transfer",
+            100, // Sequence number
+            1,
+            2000,
+            3664390082,
+            100,
+            "",
+            None,
+        )
+    };
+    let output = Signer::sign_proto(input);
+    test_tx_result(output,
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f306400000000000000020000000000000000000000000000000000000000000000000000000000000001167072696d6172795f66756e6769626c655f73746f7265087472616e73666572010700000000000000000000000000000000000000000000000000000000000000010e66756e6769626c655f6173736574084d65746164617461000320357b0b74bc833e95a115ad22604854d6b0fca151cecd94111770e5d6ffc9dc2b202d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a49580840420f0000000000d0070000000000006400000000000000c2276ada0000000001", // Expected raw transaction bytes
+                   "a5484f59fd07621fe1c49ed0e4db4b2ffb53192576f7ed4ce24bdb8b617247897d6b25693749baf9c84859bf09433725d95e2f5e3680f9d4758adfd36ead580e", // Expected signature
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f306400000000000000020000000000000000000000000000000000000000000000000000000000000001167072696d6172795f66756e6769626c655f73746f7265087472616e73666572010700000000000000000000000000000000000000000000000000000000000000010e66756e6769626c655f6173736574084d65746164617461000320357b0b74bc833e95a115ad22604854d6b0fca151cecd94111770e5d6ffc9dc2b202d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a49580840420f0000000000d0070000000000006400000000000000c2276ada00000000010020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40a5484f59fd07621fe1c49ed0e4db4b2ffb53192576f7ed4ce24bdb8b617247897d6b25693749baf9c84859bf09433725d95e2f5e3680f9d4758adfd36ead580e", // Expected encoded transaction
+                   r#"{
+                    "expiration_timestamp_secs": "3664390082",
+                    "gas_unit_price": "100",
+                    "max_gas_amount": "2000",
+                    "payload": {
+                        "arguments": [
+                            "0x357b0b74bc833e95a115ad22604854d6b0fca151cecd94111770e5d6ffc9dc2b",
+                            "0x2d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a4958",
+                            "1000000"
+                        ],
+                        "function": "0x1::primary_fungible_store::transfer",
+                        "type": "entry_function_payload",
+                        "type_arguments": ["0x1::fungible_asset::Metadata"]
+                    },
+                    "sender": "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+                    "sequence_number": "100",
+                    "signature": {
+                        "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                        "signature": "0xa5484f59fd07621fe1c49ed0e4db4b2ffb53192576f7ed4ce24bdb8b617247897d6b25693749baf9c84859bf09433725d95e2f5e3680f9d4758adfd36ead580e",
+                        "type": "ed25519_signature"
+                    }
+                }"#);
+}
+
+#[test]
+fn test_aptos_digital_asset_transfer() {
+    let input = SigningInput {
+        transaction_payload:
+            Proto::mod_SigningInput::OneOftransaction_payload::digital_asset_transfer(
+                Proto::DigitalAssetTransferMessage {
+                    token_address:
+                        "0x8b3e0e0e7f7d4a5c1a9f3b2c6d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8091a2b3c4".into(),
+                    to: "0x2d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a4958".into(),
+                },
+            ),
+        ..setup_proto_transaction(
+            "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", // Sender's address
+            "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",   // Keypair
+            "digital_asset_transfer",
+            101, // Sequence number
+            1,
+            2000,
+            3664390082,
+            100,
+            "",
+            None,
+        )
+    };
+    let output = Signer::sign_proto(input);
+    test_tx_result(output,
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f306500000000000000020000000000000000000000000000000000000000000000000000000000000001066f626a656374087472616e736665720107000000000000000000000000000000000000000000000000000000000000000405746f6b656e05546f6b656e0002208b3e0e0e7f7d4a5c1a9f3b2c6d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8091a2b3c4202d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a4958d0070000000000006400000000000000c2276ada0000000001", // Expected raw transaction bytes
+                   "fb7e9f8c81c60b42517d682cda11f26689b18f8ec4ba12f21e587ea399b2e146224cc6245d3afc616f6557f37cd5dfe93bf042aecb88443be29f893957b7520a", // Expected signature
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f306500000000000000020000000000000000000000000000000000000000000000000000000000000001066f626a656374087472616e736665720107000000000000000000000000000000000000000000000000000000000000000405746f6b656e05546f6b656e0002208b3e0e0e7f7d4a5c1a9f3b2c6d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8091a2b3c4202d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a4958d0070000000000006400000000000000c2276ada00000000010020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40fb7e9f8c81c60b42517d682cda11f26689b18f8ec4ba12f21e587ea399b2e146224cc6245d3afc616f6557f37cd5dfe93bf042aecb88443be29f893957b7520a", // Expected encoded transaction
+                   r#"{
+                    "expiration_timestamp_secs": "3664390082",
+                    "gas_unit_price": "100",
+                    "max_gas_amount": "2000",
+                    "payload": {
+                        "arguments": [
+                            "0x8b3e0e0e7f7d4a5c1a9f3b2c6d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8091a2b3c4",
+                            "0x2d6bc4a4b6e6d5b1c0c8ff9e2f0e1c7a0a5b9e8f3d1c4b7a6e5f0d2c1b3a4958"
+                        ],
+                        "function": "0x1::object::transfer",
+                        "type": "entry_function_payload",
+                        "type_arguments": ["0x4::token::Token"]
+                    },
+                    "sender": "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+                    "sequence_number": "101",
+                    "signature": {
+                        "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                        "signature": "0xfb7e9f8c81c60b42517d682cda11f26689b18f8ec4ba12f21e587ea399b2e146224cc6245d3afc616f6557f37cd5dfe93bf042aecb88443be29f893957b7520a",
+                        "type": "ed25519_signature"
+                    }
+                }"#);
+}
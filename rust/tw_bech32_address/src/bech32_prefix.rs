@@ -15,6 +15,7 @@ impl TryFrom<AddressPrefix> for Bech32Prefix {
     fn try_from(prefix: AddressPrefix) -> Result<Self, Self::Error> {
         match prefix {
             AddressPrefix::Hrp(hrp) => Ok(Bech32Prefix { hrp }),
+            AddressPrefix::SS58(_) => Err(AddressError::UnexpectedAddressPrefix),
         }
     }
 }
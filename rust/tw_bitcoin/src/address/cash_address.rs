@@ -0,0 +1,175 @@
+//! CashAddr format used by Bitcoin Cash and eCash, see:
+//! https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/cashaddr.md
+
+use tw_coin_entry::error::{AddressError, AddressResult};
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const SEPARATOR: char = ':';
+const CHECKSUM_LEN: usize = 8;
+const GENERATORS: [u64; 5] = [
+    0x98f2bc8e61,
+    0x79b76d99e2,
+    0xf33e5fb3c4,
+    0xae2eabe2a8,
+    0x1e4f43e470,
+];
+/// Only 160-bit hashes are supported at this moment, so the size bits of the version byte are zero.
+const HASH_LEN: usize = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CashAddrType {
+    P2PKH = 0,
+    P2SH = 1,
+}
+
+/// Encodes the 160-bit hash as a CashAddr address with the given prefix, e.g. `bitcoincash:q...`.
+pub fn encode(prefix: &str, ty: CashAddrType, hash: &[u8; HASH_LEN]) -> String {
+    let version = (ty as u8) << 3;
+    let mut payload = convert_bits(&[&[version], hash.as_slice()].concat(), 8, 5, true)
+        .expect("Padding is allowed");
+
+    let checksum = polymod(&prefix_values(prefix), &payload, &[0; CHECKSUM_LEN]);
+    payload.extend((0..CHECKSUM_LEN).map(|i| ((checksum >> (5 * (7 - i))) & 0x1f) as u8));
+
+    let encoded: String = payload
+        .into_iter()
+        .map(|value| CHARSET[value as usize] as char)
+        .collect();
+    format!("{prefix}{SEPARATOR}{encoded}")
+}
+
+/// Decodes the CashAddr address. The `prefix` part of the address can be omitted.
+pub fn decode(prefix: &str, address: &str) -> AddressResult<(CashAddrType, [u8; HASH_LEN])> {
+    // Mixed case addresses are not allowed.
+    let lowercase = address.to_lowercase();
+    if lowercase != address && address.to_uppercase() != address {
+        return Err(AddressError::InvalidInput);
+    }
+
+    let payload_str = match lowercase.split_once(SEPARATOR) {
+        Some((actual_prefix, payload_str)) if actual_prefix == prefix => payload_str,
+        Some(_) => return Err(AddressError::InvalidHrp),
+        None => lowercase.as_str(),
+    };
+
+    let values = payload_str
+        .bytes()
+        .map(|ch| CHARSET.iter().position(|c| *c == ch).map(|pos| pos as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(AddressError::InvalidInput)?;
+    if values.len() <= CHECKSUM_LEN || polymod(&prefix_values(prefix), &values, &[]) != 0 {
+        return Err(AddressError::InvalidInput);
+    }
+
+    let payload = convert_bits(&values[..values.len() - CHECKSUM_LEN], 5, 8, false)
+        .ok_or(AddressError::InvalidInput)?;
+    let (version, hash) = payload.split_first().ok_or(AddressError::InvalidInput)?;
+
+    let ty = match version {
+        0x00 => CashAddrType::P2PKH,
+        0x08 => CashAddrType::P2SH,
+        _ => return Err(AddressError::UnexpectedAddressPrefix),
+    };
+    let hash = hash.try_into().map_err(|_| AddressError::InvalidInput)?;
+    Ok((ty, hash))
+}
+
+/// The lower 5 bits of each prefix character followed by the zero separator.
+fn prefix_values(prefix: &str) -> Vec<u8> {
+    prefix.bytes().map(|ch| ch & 0x1f).chain([0]).collect()
+}
+
+fn polymod(prefix: &[u8], payload: &[u8], template: &[u8]) -> u64 {
+    let mut c: u64 = 1;
+    for value in prefix.iter().chain(payload).chain(template) {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ (*value as u64);
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if c0 & (1 << i) != 0 {
+                c ^= generator;
+            }
+        }
+    }
+    c ^ 1
+}
+
+/// Regroups the bits of `data` from `from_bits` to `to_bits` groups.
+fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let max_value = (1 << to_bits) - 1;
+    let mut result = Vec::with_capacity(data.len() * from_bits as usize / to_bits as usize + 1);
+
+    for value in data {
+        acc = (acc << from_bits) | *value as u32;
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            result.push(((acc >> bits) & max_value) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            result.push(((acc << (to_bits - bits)) & max_value) as u8);
+        }
+    } else if bits >= from_bits || ((acc << (to_bits - bits)) & max_value) != 0 {
+        return None;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    const PUBKEY_HASH: &str = "76a04053bda0a88bda5177b86a15c3b29f559873";
+
+    #[test]
+    fn test_cash_address_encode() {
+        let hash: [u8; 20] = PUBKEY_HASH.decode_hex().unwrap().try_into().unwrap();
+        assert_eq!(
+            encode("bitcoincash", CashAddrType::P2PKH, &hash),
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"
+        );
+    }
+
+    #[test]
+    fn test_cash_address_decode() {
+        let expected: [u8; 20] = PUBKEY_HASH.decode_hex().unwrap().try_into().unwrap();
+
+        for address in [
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            "BITCOINCASH:QPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVY22GDX6A",
+        ] {
+            let (ty, hash) = decode("bitcoincash", address).unwrap();
+            assert_eq!(ty, CashAddrType::P2PKH);
+            assert_eq!(hash, expected);
+        }
+    }
+
+    #[test]
+    fn test_cash_address_decode_invalid() {
+        // Invalid checksum.
+        decode(
+            "bitcoincash",
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b",
+        )
+        .unwrap_err();
+        // Mixed case.
+        decode(
+            "bitcoincash",
+            "bitcoincash:Qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+        )
+        .unwrap_err();
+        // Another prefix.
+        decode(
+            "ecash",
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+        )
+        .unwrap_err();
+        decode("ecash", "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").unwrap_err();
+    }
+}
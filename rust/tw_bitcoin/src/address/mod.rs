@@ -0,0 +1,146 @@
+use crate::modules::network::NetworkParams;
+use bitcoin::address::{Payload, WitnessProgram, WitnessVersion};
+use bitcoin::bech32::{self, FromBase32, Variant};
+use bitcoin::hashes::Hash;
+use bitcoin::{PubkeyHash, ScriptHash};
+use cash_address::CashAddrType;
+use std::fmt::Display;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+
+pub mod cash_address;
+
+/// Length of a Base58Check decoded address: `prefix || hash160`.
+const BASE58_ADDRESS_LEN: usize = 21;
+
+/// Bitcoin-family address that keeps its string representation in the chain specific format,
+/// i.e. Base58Check, Segwit (bech32) or CashAddr.
+#[derive(Clone, Debug)]
+pub struct Address {
+    payload: Payload,
+    encoded: String,
+}
+
+impl Address {
+    /// Parses the address according to the given network parameters.
+    pub fn from_str_with_params(params: &NetworkParams, s: &str) -> AddressResult<Address> {
+        if let Some(prefix) = params.cash_addr_prefix() {
+            if let Ok(address) = Self::from_cash_addr(prefix, s) {
+                return Ok(address);
+            }
+        }
+
+        if let Some(hrp) = params.segwit_hrp() {
+            if let Ok(address) = Self::from_segwit(hrp, s) {
+                return Ok(address);
+            }
+        }
+
+        Self::from_base58(params, s)
+    }
+
+    /// Creates a P2PKH address.
+    /// CashAddr format is used for Bitcoin Cash and its forks.
+    pub fn p2pkh(params: &NetworkParams, pubkey_hash: PubkeyHash) -> Address {
+        let encoded = match params.cash_addr_prefix() {
+            Some(prefix) => {
+                cash_address::encode(prefix, CashAddrType::P2PKH, &pubkey_hash.to_byte_array())
+            },
+            None => encode_base58(params.p2pkh_prefix, &pubkey_hash.to_byte_array()),
+        };
+
+        Address {
+            payload: Payload::PubkeyHash(pubkey_hash),
+            encoded,
+        }
+    }
+
+    pub fn payload(&self) -> &Payload {
+        &self.payload
+    }
+
+    fn from_cash_addr(prefix: &str, s: &str) -> AddressResult<Address> {
+        let (ty, hash) = cash_address::decode(prefix, s)?;
+
+        let payload = match ty {
+            CashAddrType::P2PKH => Payload::PubkeyHash(PubkeyHash::from_byte_array(hash)),
+            CashAddrType::P2SH => Payload::ScriptHash(ScriptHash::from_byte_array(hash)),
+        };
+        // Always return the address in its normalized form.
+        let encoded = cash_address::encode(prefix, ty, &hash);
+
+        Ok(Address { payload, encoded })
+    }
+
+    fn from_segwit(hrp: &str, s: &str) -> AddressResult<Address> {
+        let (actual_hrp, data, variant) =
+            bech32::decode(s).map_err(|_| AddressError::InvalidInput)?;
+        if actual_hrp != hrp {
+            return Err(AddressError::InvalidHrp);
+        }
+
+        let (version, program) = data.split_first().ok_or(AddressError::InvalidInput)?;
+        let version = WitnessVersion::try_from(*version).map_err(|_| AddressError::InvalidInput)?;
+        let program = Vec::<u8>::from_base32(program).map_err(|_| AddressError::InvalidInput)?;
+
+        // BIP-350: Segwit v0 addresses use Bech32, and the later versions use Bech32m.
+        let expected_variant = match version {
+            WitnessVersion::V0 => Variant::Bech32,
+            _ => Variant::Bech32m,
+        };
+        if variant != expected_variant {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let program =
+            WitnessProgram::new(version, program).map_err(|_| AddressError::InvalidInput)?;
+        Ok(Address {
+            payload: Payload::WitnessProgram(program),
+            encoded: s.to_lowercase(),
+        })
+    }
+
+    fn from_base58(params: &NetworkParams, s: &str) -> AddressResult<Address> {
+        let data = bitcoin::base58::decode_check(s).map_err(|_| AddressError::InvalidInput)?;
+        if data.len() != BASE58_ADDRESS_LEN {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let (prefix, hash) = (data[0], &data[1..]);
+        let payload = if prefix == params.p2pkh_prefix {
+            Payload::PubkeyHash(
+                PubkeyHash::from_slice(hash).map_err(|_| AddressError::InvalidInput)?,
+            )
+        } else if prefix == params.p2sh_prefix {
+            Payload::ScriptHash(
+                ScriptHash::from_slice(hash).map_err(|_| AddressError::InvalidInput)?,
+            )
+        } else {
+            return Err(AddressError::UnexpectedAddressPrefix);
+        };
+
+        Ok(Address {
+            payload,
+            encoded: s.to_string(),
+        })
+    }
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.encoded)
+    }
+}
+
+impl CoinAddress for Address {
+    fn data(&self) -> tw_memory::Data {
+        self.encoded.clone().into_bytes()
+    }
+}
+
+fn encode_base58(prefix: u8, hash: &[u8]) -> String {
+    let mut data = Vec::with_capacity(BASE58_ADDRESS_LEN);
+    data.push(prefix);
+    data.extend_from_slice(hash);
+    bitcoin::base58::encode_check(&data)
+}
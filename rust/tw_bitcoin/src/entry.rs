@@ -1,12 +1,11 @@
+use crate::address::Address;
+use crate::modules::network::NetworkParams;
 use crate::modules::plan_builder::BitcoinPlanBuilder;
 use crate::modules::signer::Signer;
 use crate::{Error, Result};
-use bitcoin::address::NetworkChecked;
 use std::borrow::Cow;
-use std::fmt::Display;
-use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
-use tw_coin_entry::coin_entry::{CoinAddress, CoinEntry, PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::{AddressError, AddressResult};
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
@@ -19,20 +18,6 @@ use tw_misc::traits::ToBytesVec;
 use tw_proto::BitcoinV2::Proto;
 use tw_proto::Utxo::Proto as UtxoProto;
 
-pub struct Address(pub bitcoin::address::Address<NetworkChecked>);
-
-impl Display for Address {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
-
-impl CoinAddress for Address {
-    fn data(&self) -> tw_memory::Data {
-        self.0.to_string().into_bytes()
-    }
-}
-
 pub struct BitcoinEntry;
 
 impl CoinEntry for BitcoinEntry {
@@ -61,23 +46,16 @@ impl CoinEntry for BitcoinEntry {
     #[inline]
     fn parse_address_unchecked(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         address: &str,
     ) -> AddressResult<Self::Address> {
-        let address = bitcoin::address::Address::from_str(address)
-            .map_err(|_| AddressError::FromHexError)?
-            // At this moment, we support mainnet only.
-            // This check will be removed in coming PRs.
-            .require_network(bitcoin::Network::Bitcoin)
-            .map_err(|_| AddressError::InvalidInput)?;
-
-        Ok(Address(address))
+        Address::from_str_with_params(&NetworkParams::from_coin(coin), address)
     }
 
     #[inline]
     fn derive_address(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         public_key: PublicKey,
         _derivation: Derivation,
         _prefix: Option<Self::AddressPrefix>,
@@ -90,12 +68,10 @@ impl CoinEntry for BitcoinEntry {
         let pubkey = bitcoin::PublicKey::from_slice(pubkey.to_vec().as_ref())
             .map_err(|_| AddressError::InvalidInput)?;
 
-        let address: bitcoin::address::Address<NetworkChecked> = bitcoin::address::Address::new(
-            bitcoin::Network::Bitcoin,
-            bitcoin::address::Payload::PubkeyHash(pubkey.pubkey_hash()),
-        );
-
-        Ok(Address(address))
+        Ok(Address::p2pkh(
+            &NetworkParams::from_coin(coin),
+            pubkey.pubkey_hash(),
+        ))
     }
 
     #[inline]
@@ -135,10 +111,12 @@ impl CoinEntry for BitcoinEntry {
 impl BitcoinEntry {
     pub(crate) fn preimage_hashes_impl(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         proto: Proto::SigningInput<'_>,
     ) -> Result<Proto::PreSigningOutput<'static>> {
-        let proto = pre_processor(proto);
+        let network = NetworkParams::from_coin(coin);
+        network.check_fork_id()?;
+        let proto = pre_processor(&network, proto);
 
         // Convert input builders into Utxo inputs.
         let utxo_inputs = proto
@@ -151,7 +129,9 @@ impl BitcoinEntry {
         let mut utxo_outputs = proto
             .outputs
             .iter()
-            .map(crate::modules::transactions::OutputBuilder::utxo_from_proto)
+            .map(|output| {
+                crate::modules::transactions::OutputBuilder::utxo_from_proto(&network, output)
+            })
             .collect::<Result<Vec<_>>>()?;
 
         // If automatic change output is enabled, a change script must be provided.
@@ -160,6 +140,7 @@ impl BitcoinEntry {
         } else {
             // Convert output builder to Utxo output.
             let output = crate::modules::transactions::OutputBuilder::utxo_from_proto(
+                &network,
                 &proto
                     .change_output
                     .ok_or_else(|| Error::from(Proto::Error::Error_invalid_change_output))?,
@@ -220,12 +201,14 @@ impl BitcoinEntry {
 
     pub(crate) fn compile_impl(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         proto: Proto::SigningInput<'_>,
         signatures: Vec<SignatureBytes>,
         _public_keys: Vec<PublicKeyBytes>,
     ) -> Result<Proto::SigningOutput<'static>> {
-        let proto = pre_processor(proto);
+        let network = NetworkParams::from_coin(coin);
+        network.check_fork_id()?;
+        let proto = pre_processor(&network, proto);
 
         // There must be a signature for each input.
         if proto.inputs.len() != signatures.len() {
@@ -247,7 +230,8 @@ impl BitcoinEntry {
         // Process all the outputs.
         let mut utxo_outputs = vec![];
         for output in proto.outputs {
-            let utxo = crate::modules::transactions::OutputBuilder::utxo_from_proto(&output)?;
+            let utxo =
+                crate::modules::transactions::OutputBuilder::utxo_from_proto(&network, &output)?;
 
             utxo_outputs.push(utxo);
         }
@@ -322,7 +306,10 @@ impl BitcoinEntry {
 
 // Convenience function for pre-processing of certain fields that must be
 // executed on each `CoinEntry` call.
-pub(crate) fn pre_processor(mut proto: Proto::SigningInput<'_>) -> Proto::SigningInput<'_> {
+pub(crate) fn pre_processor<'a>(
+    network: &NetworkParams,
+    mut proto: Proto::SigningInput<'a>,
+) -> Proto::SigningInput<'a> {
     // We automatically set the transaction version to 2.
     if proto.version == 0 {
         proto.version = 2;
@@ -337,6 +324,15 @@ pub(crate) fn pre_processor(mut proto: Proto::SigningInput<'_>) -> Proto::Signin
         }
     });
 
+    // Chains with the replay protection require the `SIGHASH_FORKID` flag to be set.
+    if network.fork_id.is_some() {
+        proto.inputs.iter_mut().for_each(|txin| {
+            if txin.sighash_type == UtxoProto::SighashType::UseDefault {
+                txin.sighash_type = UtxoProto::SighashType::AllForkId;
+            }
+        });
+    }
+
     proto
 }
 
@@ -1,5 +1,6 @@
 extern crate serde;
 
+pub mod address;
 pub mod entry;
 pub mod modules;
 
@@ -215,6 +215,12 @@ fn input_from_legacy_utxo(
         129 => UtxoProto::SighashType::AllPlusAnyoneCanPay,
         130 => UtxoProto::SighashType::NonePlusAnyoneCanPay,
         131 => UtxoProto::SighashType::SinglePlusAnyoneCanPay,
+        65 => UtxoProto::SighashType::AllForkId,
+        66 => UtxoProto::SighashType::NoneForkId,
+        67 => UtxoProto::SighashType::SingleForkId,
+        193 => UtxoProto::SighashType::AllForkIdPlusAnyoneCanPay,
+        194 => UtxoProto::SighashType::NoneForkIdPlusAnyoneCanPay,
+        195 => UtxoProto::SighashType::SingleForkIdPlusAnyoneCanPay,
         _ => return Err(Error::from(Proto::Error::Error_utxo_invalid_sighash_type)),
     };
 
@@ -1,4 +1,5 @@
 pub mod legacy;
+pub mod network;
 pub mod plan_builder;
 pub mod signer;
 pub mod transactions;
@@ -0,0 +1,79 @@
+use crate::{Error, Result};
+use tw_coin_entry::coin_context::CoinContext;
+use tw_proto::BitcoinV2::Proto;
+
+/// Address and signing parameters of a Bitcoin-based chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkParams {
+    /// Base58Check version byte of P2PKH addresses.
+    pub p2pkh_prefix: u8,
+    /// Base58Check version byte of P2SH addresses.
+    pub p2sh_prefix: u8,
+    /// Segwit or CashAddr human-readable part, if supported by the chain.
+    /// It's treated as a CashAddr prefix if the chain uses the zero fork ID.
+    pub hrp: Option<String>,
+    /// The fork ID if the chain requires the `SIGHASH_FORKID` replay protection,
+    /// e.g. `0` for Bitcoin Cash, eCash and Bitcoin SV, `79` for Bitcoin Gold.
+    pub fork_id: Option<u32>,
+}
+
+impl NetworkParams {
+    /// Bitcoin mainnet parameters.
+    pub fn bitcoin() -> NetworkParams {
+        NetworkParams {
+            p2pkh_prefix: 0,
+            p2sh_prefix: 5,
+            hrp: Some("bc".to_string()),
+            fork_id: None,
+        }
+    }
+
+    /// Takes the parameters from the coin context.
+    /// Falls back to the Bitcoin mainnet if the address prefixes are not specified.
+    pub fn from_coin(coin: &dyn CoinContext) -> NetworkParams {
+        let (Some(p2pkh_prefix), Some(p2sh_prefix)) = (coin.p2pkh_prefix(), coin.p2sh_prefix())
+        else {
+            return NetworkParams::bitcoin();
+        };
+
+        NetworkParams {
+            p2pkh_prefix,
+            p2sh_prefix,
+            hrp: coin.hrp(),
+            fork_id: coin.fork_id(),
+        }
+    }
+
+    /// Returns the Segwit human-readable part if the chain supports Segwit addresses.
+    pub fn segwit_hrp(&self) -> Option<&str> {
+        if self.is_cash_addr_chain() {
+            return None;
+        }
+        self.hrp.as_deref()
+    }
+
+    /// Returns the CashAddr prefix if the chain supports CashAddr addresses.
+    pub fn cash_addr_prefix(&self) -> Option<&str> {
+        if !self.is_cash_addr_chain() {
+            return None;
+        }
+        self.hrp.as_deref()
+    }
+
+    /// Checks if the transactions of the chain can be signed.
+    /// Only the zero fork ID is supported by the `SIGHASH_FORKID` signature hash,
+    /// so chains like Bitcoin Gold are rejected explicitly.
+    pub fn check_fork_id(&self) -> Result<()> {
+        match self.fork_id {
+            Some(fork_id) if fork_id != 0 => {
+                Err(Error::from(Proto::Error::Error_unsupported_fork_id))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Bitcoin Cash and its forks use CashAddr addresses and keep the zero fork ID.
+    fn is_cash_addr_chain(&self) -> bool {
+        self.fork_id == Some(0)
+    }
+}
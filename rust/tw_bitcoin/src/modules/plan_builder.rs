@@ -1,3 +1,4 @@
+use crate::modules::network::NetworkParams;
 use crate::modules::utils::hard_clone_proto_output;
 use crate::{aliases::*, pre_processor, BitcoinEntry};
 use crate::{Error, Result};
@@ -145,7 +146,8 @@ impl BitcoinPlanBuilder {
             ..Default::default()
         };
 
-        let mut commit_signing = pre_processor(commit_signing);
+        let network = NetworkParams::from_coin(_coin);
+        let mut commit_signing = pre_processor(&network, commit_signing);
 
         // We now determine the Txid of the COMMIT transaction, which we will have
         // to use in the REVEAL transaction.
@@ -220,7 +222,7 @@ impl BitcoinPlanBuilder {
             ..Default::default()
         };
 
-        let reveal_signing = pre_processor(reveal_signing);
+        let reveal_signing = pre_processor(&network, reveal_signing);
 
         Ok(Proto::mod_TransactionPlan::Brc20Plan {
             commit: Some(commit_signing),
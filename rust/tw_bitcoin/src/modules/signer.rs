@@ -1,3 +1,4 @@
+use crate::modules::network::NetworkParams;
 use crate::{BitcoinEntry, Error, Result};
 use bitcoin::key::{TapTweak, TweakedKeyPair};
 use bitcoin::sighash::{EcdsaSighashType, TapSighashType};
@@ -8,19 +9,20 @@ use tw_coin_entry::coin_entry::{PrivateKeyBytes, SignatureBytes};
 use tw_misc::traits::ToBytesVec;
 use tw_proto::BitcoinV2::Proto;
 use tw_proto::Utxo::Proto as UtxoProto;
+use tw_utxo::sighash::has_fork_id;
 
 pub struct Signer;
 
 impl Signer {
     pub fn sign_proto(
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         proto: Proto::SigningInput<'_>,
     ) -> Result<Proto::SigningOutput<'static>> {
         // Technically not required here, since this gets called by
         // `preimage_hashes_impl` and `compile_impl`. But we're leaving this
         // here in case this methods gets extended and the pre-processing does
         // not get accidentally forgotten.
-        let proto = crate::entry::pre_processor(proto);
+        let proto = crate::entry::pre_processor(&NetworkParams::from_coin(coin), proto);
 
         // Collect individual private keys per input, if there are any.
         let mut individual_keys = HashMap::new();
@@ -31,7 +33,7 @@ impl Signer {
         }
 
         // Generate the sighashes.
-        let pre_signed = BitcoinEntry.preimage_hashes_impl(_coin, proto.clone())?;
+        let pre_signed = BitcoinEntry.preimage_hashes_impl(coin, proto.clone())?;
 
         // Check for error.
         if pre_signed.error != Proto::Error::OK {
@@ -47,7 +49,7 @@ impl Signer {
         )?;
 
         // Construct the final transaction.
-        BitcoinEntry.compile_impl(_coin, proto, signatures, vec![])
+        BitcoinEntry.compile_impl(coin, proto, signatures, vec![])
     }
     pub fn signatures_from_proto(
         input: &Proto::PreSigningOutput<'_>,
@@ -89,8 +91,19 @@ impl Signer {
                             EcdsaSighashType::from_consensus(entry.sighash_type as u32)
                         };
 
+                    let sig = keypair.secret_key().sign_ecdsa(sighash);
+
+                    // `EcdsaSighashType` does not support the `SIGHASH_FORKID`
+                    // flag, so the sighash type byte is appended as is.
+                    if has_fork_id(entry.sighash_type as u32) {
+                        let mut sig = sig.serialize_der().to_vec();
+                        sig.push(entry.sighash_type as u8);
+                        signatures.push(sig);
+                        continue;
+                    }
+
                     let sig = bitcoin::ecdsa::Signature {
-                        sig: keypair.secret_key().sign_ecdsa(sighash),
+                        sig,
                         hash_ty: sighash_type,
                     };
 
@@ -3,6 +3,7 @@ use super::OrdinalNftInscription;
 use crate::aliases::*;
 use crate::{Error, Result};
 use bitcoin::consensus::Decodable;
+use bitcoin::script::PushBytes;
 use bitcoin::taproot::{ControlBlock, LeafVersion};
 use bitcoin::{ScriptBuf, Witness};
 use std::borrow::Cow;
@@ -10,6 +11,7 @@ use tw_coin_entry::coin_entry::SignatureBytes;
 use tw_misc::traits::ToBytesVec;
 use tw_proto::BitcoinV2::Proto;
 use tw_proto::Utxo::Proto as UtxoProto;
+use tw_utxo::sighash::has_fork_id;
 
 pub struct InputClaimBuilder;
 
@@ -27,13 +29,15 @@ impl InputClaimBuilder {
                     Witness::new(),
                 ),
                 ProtoInputBuilder::p2pkh(pubkey) => {
-                    let sig = bitcoin::ecdsa::Signature::from_slice(signature.as_ref())?;
+                    let sig = ecdsa_signature_from_slice(signature.as_ref())?;
+                    let sig = <&PushBytes>::try_from(sig.as_slice())
+                        .map_err(|_| Error::from(Proto::Error::Error_invalid_ecdsa_signature))?;
                     let pubkey = bitcoin::PublicKey::from_slice(pubkey.as_ref())?;
 
                     // The spending script itself.
                     (
                         ScriptBuf::builder()
-                            .push_slice(sig.serialize())
+                            .push_slice(sig)
                             .push_key(&pubkey)
                             .into_script(),
                         Witness::new(),
@@ -170,3 +174,21 @@ impl InputClaimBuilder {
         Ok(claim)
     }
 }
+
+/// Parses the ECDSA signature followed by the sighash type byte.
+/// Unlike [`bitcoin::ecdsa::Signature::from_slice`], the `SIGHASH_FORKID`
+/// flag used by Bitcoin Cash and its forks is accepted.
+fn ecdsa_signature_from_slice(signature: &[u8]) -> Result<Vec<u8>> {
+    let (sighash_type, der) = signature
+        .split_last()
+        .ok_or_else(|| Error::from(Proto::Error::Error_invalid_ecdsa_signature))?;
+
+    if !has_fork_id(*sighash_type as u32) {
+        let sig = bitcoin::ecdsa::Signature::from_slice(signature)?;
+        return Ok(sig.serialize().to_vec());
+    }
+
+    secp256k1::ecdsa::Signature::from_der(der)
+        .map_err(|_| Error::from(Proto::Error::Error_invalid_ecdsa_signature))?;
+    Ok(signature.to_vec())
+}
@@ -1,13 +1,13 @@
-use std::str::FromStr;
-
 use super::brc20::{BRC20TransferInscription, Brc20Ticker};
 use super::OrdinalNftInscription;
+use crate::address::Address;
 use crate::aliases::*;
+use crate::modules::network::NetworkParams;
 use crate::{Error, Result};
 use bitcoin::address::{Payload, WitnessVersion};
 use bitcoin::key::TweakedPublicKey;
 use bitcoin::taproot::{LeafVersion, TapNodeHash};
-use bitcoin::{Address, PubkeyHash, ScriptBuf, ScriptHash, WPubkeyHash, WScriptHash};
+use bitcoin::{PubkeyHash, ScriptBuf, ScriptHash, WPubkeyHash, WScriptHash};
 use secp256k1::hashes::Hash;
 use secp256k1::XOnlyPublicKey;
 use tw_misc::traits::ToBytesVec;
@@ -22,6 +22,7 @@ const NO_TAPROOT_PAYLOAD: Option<Vec<u8>> = None;
 impl OutputBuilder {
     /// Creates the spending condition (_scriptPubkey_) for a given output.
     pub fn utxo_from_proto(
+        network: &NetworkParams,
         output: &Proto::Output<'_>,
     ) -> Result<Proto::mod_PreSigningOutput::TxOut<'static>> {
         let secp = secp256k1::Secp256k1::new();
@@ -173,10 +174,10 @@ impl OutputBuilder {
             },
             // We derive the transaction type from the address.
             ProtoOutputRecipient::from_address(addr) => {
-                let proto = output_from_address(output.value, addr.as_ref())?;
+                let proto = output_from_address(network, output.value, addr.as_ref())?;
 
                 // Recursive call, will initiate the appropraite builder.
-                return Self::utxo_from_proto(&proto);
+                return Self::utxo_from_proto(network, &proto);
             },
             ProtoOutputRecipient::None => {
                 return Err(Error::from(Proto::Error::Error_missing_recipient))
@@ -261,16 +262,15 @@ fn witness_pubkey_hash_from_proto(
 }
 
 // Derives the P2* output from the given address.
-fn output_from_address(value: u64, addr: &str) -> Result<Proto::Output<'static>> {
-    let string = String::from_utf8(addr.to_vec())
-        .map_err(|_| Error::from(Proto::Error::Error_bad_address_recipient))?;
-
-    let addr = Address::from_str(&string)
-        .map_err(|_| Error::from(Proto::Error::Error_bad_address_recipient))?
-        .require_network(bitcoin::Network::Bitcoin)
+fn output_from_address(
+    network: &NetworkParams,
+    value: u64,
+    addr: &str,
+) -> Result<Proto::Output<'static>> {
+    let addr = Address::from_str_with_params(network, addr)
         .map_err(|_| Error::from(Proto::Error::Error_bad_address_recipient))?;
 
-    let proto = match addr.payload {
+    let proto = match addr.payload() {
         // Identified a "PubkeyHash" address (i.e. P2PKH).
         Payload::PubkeyHash(pubkey_hash) => Proto::Output {
             value,
@@ -0,0 +1,154 @@
+mod common;
+
+use common::hex;
+use tw_bitcoin::aliases::*;
+use tw_bitcoin::entry::BitcoinEntry;
+use tw_coin_entry::coin_entry::CoinEntry;
+use tw_coin_entry::test_utils::test_context::TestCoinContext;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Utxo::Proto as UtxoProto;
+
+fn bitcoin_cash_context() -> TestCoinContext {
+    TestCoinContext::default()
+        .with_hrp("bitcoincash")
+        .with_p2pkh_prefix(0)
+        .with_p2sh_prefix(5)
+        .with_fork_id(0)
+}
+
+/// Bitcoin SV requires the replay protection, but doesn't support CashAddr addresses.
+fn bitcoin_sv_context() -> TestCoinContext {
+    TestCoinContext::default()
+        .with_p2pkh_prefix(0)
+        .with_p2sh_prefix(5)
+        .with_fork_id(0)
+}
+
+/// Bitcoin Gold commits to the non-zero fork ID that is not supported yet.
+fn bitcoin_gold_context() -> TestCoinContext {
+    TestCoinContext::default()
+        .with_hrp("btg")
+        .with_p2pkh_prefix(38)
+        .with_p2sh_prefix(23)
+        .with_fork_id(79)
+}
+
+fn sign_bitcoin_cash(sighash_type: UtxoProto::SighashType) -> Proto::SigningOutput<'static> {
+    sign_p2pkh(
+        &bitcoin_cash_context(),
+        sighash_type,
+        "bitcoincash:qpmfhhledgp0jy66r5vmwjwmdfu0up7ujqcp07ha9v",
+    )
+}
+
+fn sign_p2pkh(
+    coin: &TestCoinContext,
+    sighash_type: UtxoProto::SighashType,
+    recipient: &str,
+) -> Proto::SigningOutput<'static> {
+    let private_key = hex("7fdafb9db5bc501f2096e7d13d331dc7a75d9594af3d251313ba8b6200f4e384");
+    let pubkey = hex("038eab72ec78e639d02758e7860cdec018b49498c307791f785aa3019622f4ea5b");
+    let txid = hex("e28c2b955293159898e34c6840d99bf4d390e2ee1c6f606939f18ee1e2000d05");
+
+    let tx1 = Proto::Input {
+        txid: txid.as_slice().into(),
+        vout: 2,
+        value: 5151,
+        sighash_type,
+        to_recipient: ProtoInputRecipient::builder(Proto::mod_Input::InputBuilder {
+            variant: ProtoInputBuilder::p2pkh(pubkey.as_slice().into()),
+        }),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: 600,
+        to_recipient: ProtoOutputRecipient::from_address(recipient.to_string().into()),
+    };
+
+    // Legacy recipient.
+    let out2 = Proto::Output {
+        value: 4325,
+        to_recipient: ProtoOutputRecipient::from_address(
+            "1FQc5LdgGHMHEN9nwkjmz6tWkxhPpxBvBU".into(),
+        ),
+    };
+
+    let signing = Proto::SigningInput {
+        version: 1,
+        private_key: private_key.as_slice().into(),
+        inputs: vec![tx1],
+        outputs: vec![out1, out2],
+        input_selector: UtxoProto::InputSelector::UseAll,
+        disable_change_output: true,
+        ..Default::default()
+    };
+
+    BitcoinEntry.sign(coin, signing)
+}
+
+#[test]
+fn coin_entry_sign_bitcoin_cash_p2pkh() {
+    let signed = sign_bitcoin_cash(UtxoProto::SighashType::AllForkId);
+    assert_eq!(signed.error, Proto::Error::OK);
+
+    let encoded = tw_encoding::hex::encode(signed.encoded, false);
+    assert_eq!(encoded, "0100000001e28c2b955293159898e34c6840d99bf4d390e2ee1c6f606939f18ee1e2000d05020000006b483045022100b70d158b43cbcded60e6977e93f9a84966bc0cec6f2dfd1463d1223a90563f0d02207548d081069de570a494d0967ba388ff02641d91cadb060587ead95a98d4e3534121038eab72ec78e639d02758e7860cdec018b49498c307791f785aa3019622f4ea5bffffffff0258020000000000001976a914769bdff96a02f9135a1d19b749db6a78fe07dc9088ace5100000000000001976a9149e089b6889e032d46e3b915a3392edfd616fb1c488ac00000000");
+}
+
+#[test]
+fn coin_entry_sign_bitcoin_cash_default_sighash() {
+    // `SIGHASH_ALL | SIGHASH_FORKID` is used by default.
+    let default_signed = sign_bitcoin_cash(UtxoProto::SighashType::UseDefault);
+    assert_eq!(default_signed.error, Proto::Error::OK);
+
+    let signed = sign_bitcoin_cash(UtxoProto::SighashType::AllForkId);
+    assert_eq!(default_signed.encoded, signed.encoded);
+}
+
+#[test]
+fn coin_entry_sign_bitcoin_sv_default_sighash() {
+    // `SIGHASH_ALL | SIGHASH_FORKID` is used by default.
+    let recipient = "1Bp9U1ogV3A14FMvKbRJms7ctyso4Z4Tcx";
+    let default_signed = sign_p2pkh(
+        &bitcoin_sv_context(),
+        UtxoProto::SighashType::UseDefault,
+        recipient,
+    );
+    assert_eq!(default_signed.error, Proto::Error::OK);
+
+    let signed = sign_p2pkh(
+        &bitcoin_sv_context(),
+        UtxoProto::SighashType::AllForkId,
+        recipient,
+    );
+    assert_eq!(default_signed.encoded, signed.encoded);
+
+    // Bitcoin Cash produces the same transaction for the CashAddr form of the recipient address.
+    let bitcoin_cash_signed = sign_p2pkh(
+        &bitcoin_cash_context(),
+        UtxoProto::SighashType::AllForkId,
+        "bitcoincash:qpmfhhledgp0jy66r5vmwjwmdfu0up7ujqcp07ha9v",
+    );
+    assert_eq!(bitcoin_cash_signed.encoded, signed.encoded);
+}
+
+#[test]
+fn coin_entry_sign_bitcoin_sv_cash_addr_recipient() {
+    let signed = sign_p2pkh(
+        &bitcoin_sv_context(),
+        UtxoProto::SighashType::AllForkId,
+        "bitcoincash:qpmfhhledgp0jy66r5vmwjwmdfu0up7ujqcp07ha9v",
+    );
+    assert_eq!(signed.error, Proto::Error::Error_bad_address_recipient);
+}
+
+#[test]
+fn coin_entry_sign_bitcoin_gold_not_supported() {
+    let signed = sign_p2pkh(
+        &bitcoin_gold_context(),
+        UtxoProto::SighashType::AllForkId,
+        "GJjz2Du9BoJQ3CPcoyVTHUJZSj62i1693U",
+    );
+    assert_eq!(signed.error, Proto::Error::Error_unsupported_fork_id);
+}
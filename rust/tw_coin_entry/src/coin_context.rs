@@ -15,4 +15,17 @@ pub trait CoinContext {
 
     /// Optional chain property.
     fn hrp(&self) -> Option<String>;
+
+    /// Optional chain property.
+    fn p2pkh_prefix(&self) -> Option<u8>;
+
+    /// Optional chain property.
+    fn p2sh_prefix(&self) -> Option<u8>;
+
+    /// Optional chain property.
+    fn ss58_prefix(&self) -> Option<u16>;
+
+    /// Optional chain property.
+    /// The fork ID committed along with the `SIGHASH_FORKID` replay protection flag.
+    fn fork_id(&self) -> Option<u32>;
 }
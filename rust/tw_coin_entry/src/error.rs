@@ -79,6 +79,7 @@ impl From<KeyPairError> for SigningError {
             KeyPairError::InvalidPublicKey
             | KeyPairError::InvalidSignature
             | KeyPairError::InvalidSignMessage
+            | KeyPairError::InvalidDerivationPath
             | KeyPairError::SignatureVerifyError => {
                 SigningError(SigningErrorType::Error_invalid_params)
             },
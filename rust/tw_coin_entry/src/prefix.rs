@@ -4,11 +4,13 @@
 
 use crate::error::AddressError;
 
-/// An address prefix. It can contain a bech32 prefix that can be used by `Cosmos` based chains.
+/// An address prefix. It can contain a bech32 prefix that can be used by `Cosmos` based chains,
+/// or an SS58 network prefix that can be used by `Substrate` based chains.
 /// Extend when adding new blockchains.
 #[derive(Clone)]
 pub enum AddressPrefix {
     Hrp(String),
+    SS58(u16),
 }
 
 /// A blockchain's address prefix should be convertable from an `AddressPrefix`.
@@ -12,6 +12,10 @@ pub struct TestCoinContext {
     pub public_key_type: Option<PublicKeyType>,
     pub address_hasher: Option<Hasher>,
     pub hrp: Option<String>,
+    pub p2pkh_prefix: Option<u8>,
+    pub p2sh_prefix: Option<u8>,
+    pub ss58_prefix: Option<u16>,
+    pub fork_id: Option<u32>,
 }
 
 impl TestCoinContext {
@@ -24,6 +28,26 @@ impl TestCoinContext {
         self.hrp = Some(hrp.to_string());
         self
     }
+
+    pub fn with_p2pkh_prefix(mut self, p2pkh_prefix: u8) -> TestCoinContext {
+        self.p2pkh_prefix = Some(p2pkh_prefix);
+        self
+    }
+
+    pub fn with_p2sh_prefix(mut self, p2sh_prefix: u8) -> TestCoinContext {
+        self.p2sh_prefix = Some(p2sh_prefix);
+        self
+    }
+
+    pub fn with_ss58_prefix(mut self, ss58_prefix: u16) -> TestCoinContext {
+        self.ss58_prefix = Some(ss58_prefix);
+        self
+    }
+
+    pub fn with_fork_id(mut self, fork_id: u32) -> TestCoinContext {
+        self.fork_id = Some(fork_id);
+        self
+    }
 }
 
 impl CoinContext for TestCoinContext {
@@ -39,4 +63,20 @@ impl CoinContext for TestCoinContext {
     fn hrp(&self) -> Option<String> {
         self.hrp.clone()
     }
+
+    fn p2pkh_prefix(&self) -> Option<u8> {
+        self.p2pkh_prefix
+    }
+
+    fn p2sh_prefix(&self) -> Option<u8> {
+        self.p2sh_prefix
+    }
+
+    fn ss58_prefix(&self) -> Option<u16> {
+        self.ss58_prefix
+    }
+
+    fn fork_id(&self) -> Option<u32> {
+        self.fork_id
+    }
 }
@@ -12,14 +12,23 @@ pub enum BlockchainType {
     Aptos,
     Binance,
     Bitcoin,
+    Cardano,
     Cosmos,
     Ethereum,
     Greenfield,
     InternetComputer,
+    Kusama,
     NativeEvmos,
     NativeInjective,
+    #[serde(rename = "NEAR")]
+    Near,
+    Polkadot,
     Ronin,
+    Solana,
+    Sui,
+    TheOpenNetwork,
     Thorchain,
+    Tron,
     // end_of_blockchain_type - USED TO GENERATE CODE
     #[serde(other)]
     Unsupported,
@@ -33,4 +33,24 @@ impl CoinContext for CoinRegistryContext {
     fn hrp(&self) -> Option<String> {
         self.item.hrp.clone()
     }
+
+    #[inline]
+    fn p2pkh_prefix(&self) -> Option<u8> {
+        self.item.p2pkh_prefix
+    }
+
+    #[inline]
+    fn p2sh_prefix(&self) -> Option<u8> {
+        self.item.p2sh_prefix
+    }
+
+    #[inline]
+    fn ss58_prefix(&self) -> Option<u16> {
+        self.item.ss58_prefix
+    }
+
+    #[inline]
+    fn fork_id(&self) -> Option<u32> {
+        self.item.fork_id
+    }
 }
@@ -10,6 +10,7 @@ use crate::registry::get_coin_item;
 use tw_aptos::entry::AptosEntry;
 use tw_binance::entry::BinanceEntry;
 use tw_bitcoin::entry::BitcoinEntry;
+use tw_cardano::entry::CardanoEntry;
 use tw_coin_entry::coin_entry_ext::CoinEntryExt;
 use tw_cosmos::entry::CosmosEntry;
 use tw_ethereum::entry::EthereumEntry;
@@ -18,8 +19,14 @@ use tw_greenfield::entry::GreenfieldEntry;
 use tw_internet_computer::entry::InternetComputerEntry;
 use tw_native_evmos::entry::NativeEvmosEntry;
 use tw_native_injective::entry::NativeInjectiveEntry;
+use tw_near::entry::NearEntry;
+use tw_polkadot::entry::PolkadotEntry;
 use tw_ronin::entry::RoninEntry;
+use tw_solana::entry::SolanaEntry;
+use tw_sui::entry::SuiEntry;
 use tw_thorchain::entry::ThorchainEntry;
+use tw_ton::entry::TheOpenNetworkEntry;
+use tw_tron::entry::TronEntry;
 
 pub type CoinEntryExtStaticRef = &'static dyn CoinEntryExt;
 pub type EvmEntryExtStaticRef = &'static dyn EvmEntryExt;
@@ -28,14 +35,21 @@ pub type EvmEntryExtStaticRef = &'static dyn EvmEntryExt;
 const APTOS: AptosEntry = AptosEntry;
 const BINANCE: BinanceEntry = BinanceEntry;
 const BITCOIN: BitcoinEntry = BitcoinEntry;
+const CARDANO: CardanoEntry = CardanoEntry;
 const COSMOS: CosmosEntry = CosmosEntry;
 const ETHEREUM: EthereumEntry = EthereumEntry;
 const GREENFIELD: GreenfieldEntry = GreenfieldEntry;
 const INTERNET_COMPUTER: InternetComputerEntry = InternetComputerEntry;
 const NATIVE_EVMOS: NativeEvmosEntry = NativeEvmosEntry;
 const NATIVE_INJECTIVE: NativeInjectiveEntry = NativeInjectiveEntry;
+const NEAR: NearEntry = NearEntry;
+const POLKADOT: PolkadotEntry = PolkadotEntry;
 const RONIN: RoninEntry = RoninEntry;
+const SOLANA: SolanaEntry = SolanaEntry;
+const SUI: SuiEntry = SuiEntry;
+const THE_OPEN_NETWORK: TheOpenNetworkEntry = TheOpenNetworkEntry;
 const THORCHAIN: ThorchainEntry = ThorchainEntry;
+const TRON: TronEntry = TronEntry;
 // end_of_blockchain_entries - USED TO GENERATE CODE
 
 pub fn blockchain_dispatcher(blockchain: BlockchainType) -> RegistryResult<CoinEntryExtStaticRef> {
@@ -44,14 +58,22 @@ pub fn blockchain_dispatcher(blockchain: BlockchainType) -> RegistryResult<CoinE
         BlockchainType::Aptos => Ok(&APTOS),
         BlockchainType::Binance => Ok(&BINANCE),
         BlockchainType::Bitcoin => Ok(&BITCOIN),
+        BlockchainType::Cardano => Ok(&CARDANO),
         BlockchainType::Cosmos => Ok(&COSMOS),
         BlockchainType::Ethereum => Ok(&ETHEREUM),
         BlockchainType::Greenfield => Ok(&GREENFIELD),
         BlockchainType::InternetComputer => Ok(&INTERNET_COMPUTER),
+        BlockchainType::Kusama => Ok(&POLKADOT),
         BlockchainType::NativeEvmos => Ok(&NATIVE_EVMOS),
         BlockchainType::NativeInjective => Ok(&NATIVE_INJECTIVE),
+        BlockchainType::Near => Ok(&NEAR),
+        BlockchainType::Polkadot => Ok(&POLKADOT),
         BlockchainType::Ronin => Ok(&RONIN),
+        BlockchainType::Solana => Ok(&SOLANA),
+        BlockchainType::Sui => Ok(&SUI),
+        BlockchainType::TheOpenNetwork => Ok(&THE_OPEN_NETWORK),
         BlockchainType::Thorchain => Ok(&THORCHAIN),
+        BlockchainType::Tron => Ok(&TRON),
         // end_of_blockchain_dispatcher - USED TO GENERATE CODE
         BlockchainType::Unsupported => Err(RegistryError::Unsupported),
     }
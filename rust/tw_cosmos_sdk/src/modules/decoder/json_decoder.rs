@@ -0,0 +1,289 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::CosmosContext;
+use crate::hasher::CosmosHasher;
+use crate::modules::decoder::DecodedTx;
+use crate::modules::serializer::json_serializer::{FeeJson, UnsignedTxJson};
+use crate::modules::tx_builder::TxBuilder;
+use crate::transaction::Coin;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value as Json;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_number::U256;
+use tw_proto::Cosmos::Proto;
+
+/// Amino `StdSignDoc`. Please note that numbers are encoded as strings.
+#[derive(Deserialize)]
+struct StdSignDocJson {
+    account_number: String,
+    chain_id: String,
+    fee: StdFeeJson,
+    memo: String,
+    msgs: Vec<StdMsgJson>,
+    sequence: String,
+}
+
+#[derive(Deserialize)]
+struct StdFeeJson {
+    amount: Vec<CoinJson>,
+    gas: String,
+}
+
+#[derive(Deserialize)]
+struct StdMsgJson {
+    #[serde(rename = "type")]
+    msg_type: String,
+    value: Json,
+}
+
+#[derive(Deserialize)]
+struct CoinJson {
+    amount: String,
+    denom: String,
+}
+
+#[derive(Deserialize)]
+struct SendJson {
+    from_address: String,
+    to_address: String,
+    amount: Vec<CoinJson>,
+}
+
+#[derive(Deserialize)]
+struct DelegateJson {
+    delegator_address: String,
+    validator_address: String,
+    amount: CoinJson,
+}
+
+#[derive(Deserialize)]
+struct BeginRedelegateJson {
+    delegator_address: String,
+    validator_src_address: String,
+    validator_dst_address: String,
+    amount: CoinJson,
+}
+
+#[derive(Deserialize)]
+struct WithdrawDelegationRewardJson {
+    delegator_address: String,
+    validator_address: String,
+}
+
+#[derive(Deserialize)]
+struct SetWithdrawAddressJson {
+    delegator_address: String,
+    withdraw_address: String,
+}
+
+/// `JsonDecoder` decodes an amino `StdSignDoc` back to the [`Proto::SigningInput`].
+pub struct JsonDecoder<Context> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: CosmosContext> JsonDecoder<Context> {
+    /// Decodes an amino `StdSignDoc` JSON.
+    /// Please note that [`Proto::SigningInput::public_key`] is not set as `StdSignDoc` doesn't contain it.
+    pub fn decode_amino_sign_doc(
+        coin: &dyn CoinContext,
+        sign_doc: &str,
+    ) -> SigningResult<DecodedTx> {
+        let sign_doc_value: Json = serde_json::from_str(sign_doc)?;
+        let sign_doc: StdSignDocJson = serde_json::from_value(sign_doc_value.clone())?;
+
+        let account_number = u64::from_str(&sign_doc.account_number)
+            .map_err(|_| SigningError(SigningErrorType::Error_input_parse))?;
+        let sequence = u64::from_str(&sign_doc.sequence)
+            .map_err(|_| SigningError(SigningErrorType::Error_input_parse))?;
+        let gas = u64::from_str(&sign_doc.fee.gas)
+            .map_err(|_| SigningError(SigningErrorType::Error_wrong_fee))?;
+
+        let messages: Vec<_> = sign_doc
+            .msgs
+            .into_iter()
+            .map(|msg| Self::decode_message(coin, msg))
+            .collect();
+
+        let input = Proto::SigningInput {
+            signing_mode: Proto::SigningMode::JSON,
+            account_number,
+            chain_id: sign_doc.chain_id.clone().into(),
+            fee: Some(Proto::Fee {
+                amounts: sign_doc
+                    .fee
+                    .amount
+                    .iter()
+                    .map(Self::decode_amount)
+                    .collect(),
+                gas,
+            }),
+            memo: sign_doc.memo.clone().into(),
+            sequence,
+            messages,
+            ..Proto::SigningInput::default()
+        };
+
+        // Make sure the decoded signing input produces exactly the same transaction preimage.
+        let msgs = input
+            .messages
+            .iter()
+            .map(|msg| TxBuilder::<Context>::tx_message(coin, msg)?.to_json())
+            .collect::<SigningResult<_>>()?;
+        let fee_amounts = sign_doc
+            .fee
+            .amount
+            .iter()
+            .map(|amount| -> SigningResult<Coin> {
+                Ok(Coin {
+                    amount: U256::from_str(&amount.amount)?,
+                    denom: amount.denom.clone(),
+                })
+            })
+            .collect::<SigningResult<_>>()?;
+        let expected_sign_doc = UnsignedTxJson {
+            account_number: account_number.to_string(),
+            chain_id: sign_doc.chain_id,
+            fee: FeeJson {
+                amount: fee_amounts,
+                gas: gas.to_string(),
+            },
+            memo: sign_doc.memo,
+            msgs,
+            sequence: sequence.to_string(),
+        };
+
+        // Compare JSON values, so the original key ordering and formatting don't matter.
+        let expected_sign_doc_value = serde_json::to_value(&expected_sign_doc)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+        if expected_sign_doc_value != sign_doc_value {
+            return Err(SigningError(SigningErrorType::Error_not_supported));
+        }
+
+        // Encode the transaction preimage the same way as `JsonPreimager` does.
+        let encoded_tx = serde_json::to_string(&expected_sign_doc)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+
+        Ok(DecodedTx {
+            input,
+            tx_hash: Context::TxHasher::hash_json_tx(&encoded_tx),
+            signatures: Vec::default(),
+            unknown_messages: Vec::default(),
+        })
+    }
+
+    /// Maps a known amino message to the corresponding [`Proto::Message`].
+    /// Unknown or unsupported messages are left as [`Proto::mod_Message::RawJSON`].
+    fn decode_message(coin: &dyn CoinContext, msg: StdMsgJson) -> Proto::Message<'static> {
+        let candidate =
+            Self::decode_known_message(&msg).map(|message_oneof| Proto::Message { message_oneof });
+
+        // Make sure the typed message is serialized exactly the same way as the original one.
+        if let Some(candidate) = candidate {
+            let is_same = match TxBuilder::<Context>::tx_message(coin, &candidate)
+                .and_then(|tx_message| tx_message.to_json())
+            {
+                Ok(json) => json.msg_type == msg.msg_type && json.value == msg.value,
+                Err(_) => false,
+            };
+            if is_same {
+                return candidate;
+            }
+        }
+
+        Proto::Message {
+            message_oneof: Proto::mod_Message::OneOfmessage_oneof::raw_json_message(
+                Proto::mod_Message::RawJSON {
+                    type_pb: msg.msg_type.into(),
+                    value: msg.value.to_string().into(),
+                },
+            ),
+        }
+    }
+
+    fn decode_known_message(
+        msg: &StdMsgJson,
+    ) -> Option<Proto::mod_Message::OneOfmessage_oneof<'static>> {
+        use Proto::mod_Message::{self as message, OneOfmessage_oneof as MessageEnum};
+
+        // Amino message types may be prefixed with a chain specific module name,
+        // so keep the original type as the `type_prefix`.
+        let type_prefix: Cow<'static, str> = msg.msg_type.clone().into();
+        let (_, msg_name) = msg.msg_type.rsplit_once('/')?;
+
+        let message_oneof = match msg_name {
+            "MsgSend" => {
+                let send: SendJson = Self::decode_value(&msg.value)?;
+                MessageEnum::send_coins_message(message::Send {
+                    from_address: send.from_address.into(),
+                    to_address: send.to_address.into(),
+                    amounts: send.amount.iter().map(Self::decode_amount).collect(),
+                    type_prefix,
+                })
+            },
+            "MsgDelegate" => {
+                let delegate: DelegateJson = Self::decode_value(&msg.value)?;
+                MessageEnum::stake_message(message::Delegate {
+                    delegator_address: delegate.delegator_address.into(),
+                    validator_address: delegate.validator_address.into(),
+                    amount: Some(Self::decode_amount(&delegate.amount)),
+                    type_prefix,
+                })
+            },
+            "MsgUndelegate" => {
+                let undelegate: DelegateJson = Self::decode_value(&msg.value)?;
+                MessageEnum::unstake_message(message::Undelegate {
+                    delegator_address: undelegate.delegator_address.into(),
+                    validator_address: undelegate.validator_address.into(),
+                    amount: Some(Self::decode_amount(&undelegate.amount)),
+                    type_prefix,
+                })
+            },
+            "MsgBeginRedelegate" => {
+                let redelegate: BeginRedelegateJson = Self::decode_value(&msg.value)?;
+                MessageEnum::restake_message(message::BeginRedelegate {
+                    delegator_address: redelegate.delegator_address.into(),
+                    validator_src_address: redelegate.validator_src_address.into(),
+                    validator_dst_address: redelegate.validator_dst_address.into(),
+                    amount: Some(Self::decode_amount(&redelegate.amount)),
+                    type_prefix,
+                })
+            },
+            "MsgWithdrawDelegationReward" => {
+                let withdraw: WithdrawDelegationRewardJson = Self::decode_value(&msg.value)?;
+                MessageEnum::withdraw_stake_reward_message(message::WithdrawDelegationReward {
+                    delegator_address: withdraw.delegator_address.into(),
+                    validator_address: withdraw.validator_address.into(),
+                    type_prefix,
+                })
+            },
+            "MsgSetWithdrawAddress" => {
+                let set: SetWithdrawAddressJson = Self::decode_value(&msg.value)?;
+                MessageEnum::set_withdraw_address_message(message::SetWithdrawAddress {
+                    delegator_address: set.delegator_address.into(),
+                    withdraw_address: set.withdraw_address.into(),
+                    type_prefix,
+                })
+            },
+            _ => return None,
+        };
+        Some(message_oneof)
+    }
+
+    fn decode_value<T: DeserializeOwned>(value: &Json) -> Option<T> {
+        T::deserialize(value).ok()
+    }
+
+    fn decode_amount(coin: &CoinJson) -> Proto::Amount<'static> {
+        Proto::Amount {
+            denom: coin.denom.clone().into(),
+            amount: coin.amount.clone().into(),
+        }
+    }
+}
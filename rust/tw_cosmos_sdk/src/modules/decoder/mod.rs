@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::public_key::CosmosPublicKey;
+use tw_memory::Data;
+use tw_proto::Cosmos::Proto;
+
+pub mod json_decoder;
+pub mod protobuf_decoder;
+pub mod tw_decoder;
+
+/// A transaction decoded back to the [`Proto::SigningInput`].
+#[derive(Debug)]
+pub struct DecodedTx {
+    /// The signing input decoded from the transaction.
+    /// It produces exactly the same transaction preimage only if [`DecodedTx::unknown_messages`] is empty,
+    /// as unknown messages are dropped from the input.
+    /// Please note that [`Proto::SigningInput::private_key`] is never set.
+    pub input: Proto::SigningInput<'static>,
+    /// The transaction preimage hash.
+    pub tx_hash: Data,
+    /// Signatures attached to the transaction if any.
+    pub signatures: Vec<DecodedSignature>,
+    /// Messages that are not supported by wallet-core explicitly.
+    /// Please note that such messages are not included into [`DecodedTx::input`].
+    pub unknown_messages: Vec<UnknownMessage>,
+}
+
+/// A Protobuf message that cannot be mapped to any of the [`Proto::Message`] types.
+#[derive(Debug)]
+pub struct UnknownMessage {
+    /// Index of the message within the transaction.
+    pub index: usize,
+    /// Message type URL, e.g. "/cosmos.bank.v1beta1.MsgSend".
+    pub type_url: String,
+    /// Protobuf serialized message.
+    pub value: Data,
+}
+
+#[derive(Debug)]
+pub struct DecodedSignature {
+    pub public_key: Data,
+    pub signature: Data,
+    /// Whether the `signature` of the transaction preimage hash was produced by the `public_key`.
+    pub verified: bool,
+}
+
+impl DecodedSignature {
+    pub fn new<PublicKey: CosmosPublicKey>(
+        public_key: &PublicKey,
+        signature: Data,
+        tx_hash: &[u8],
+    ) -> DecodedSignature {
+        DecodedSignature {
+            public_key: public_key.to_bytes(),
+            verified: public_key.verify(&signature, tx_hash),
+            signature,
+        }
+    }
+}
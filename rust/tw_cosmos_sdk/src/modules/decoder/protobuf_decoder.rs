@@ -0,0 +1,388 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::CosmosContext;
+use crate::hasher::CosmosHasher;
+use crate::modules::decoder::{DecodedSignature, DecodedTx, UnknownMessage};
+use crate::modules::serializer::protobuf_serializer::ProtobufSerializer;
+use crate::modules::tx_builder::{TxBuilder, DEFAULT_TIMEOUT_HEIGHT};
+use crate::proto::cosmos::base::v1beta1 as base_proto;
+use crate::proto::cosmos::signing::v1beta1 as signing_proto;
+use crate::proto::cosmos::tx::v1beta1 as tx_proto;
+use crate::proto::{cosmos, cosmwasm, ibc};
+use crate::public_key::{CosmosPublicKey, ProtobufPublicKey};
+use crate::transaction::message::cosmos_generic_message::ProtobufRawMessage;
+use crate::transaction::message::{CosmosMessage, ProtobufMessage};
+use crate::transaction::{SignMode, SignerInfo, TxBody, UnsignedTransaction};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_proto::Cosmos::Proto;
+use tw_proto::{deserialize, serialize, type_url, MessageRead};
+
+/// `ProtobufDecoder` decodes Cosmos [`tx_proto::TxRaw`] and [`tx_proto::SignDoc`] messages
+/// back to the [`Proto::SigningInput`].
+pub struct ProtobufDecoder<Context> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: CosmosContext> ProtobufDecoder<Context> {
+    /// Decodes a serialized [`tx_proto::SignDoc`].
+    pub fn decode_sign_doc(coin: &dyn CoinContext, sign_doc: &[u8]) -> SigningResult<DecodedTx> {
+        let sign_doc: tx_proto::SignDoc = deserialize(sign_doc)?;
+        Self::decode_sign_doc_impl(coin, &sign_doc)
+    }
+
+    /// Decodes a serialized [`tx_proto::TxRaw`] and verifies its signatures.
+    /// Please note that `chain_id` and `account_number` are not a part of the transaction,
+    /// but they're required to restore the transaction preimage.
+    pub fn decode_tx_raw(
+        coin: &dyn CoinContext,
+        tx_raw: &[u8],
+        chain_id: &str,
+        account_number: u64,
+    ) -> SigningResult<DecodedTx> {
+        let tx_raw: tx_proto::TxRaw = deserialize(tx_raw)?;
+        let sign_doc = tx_proto::SignDoc {
+            body_bytes: tx_raw.body_bytes,
+            auth_info_bytes: tx_raw.auth_info_bytes,
+            chain_id: chain_id.to_string(),
+            account_number,
+        };
+        let mut decoded = Self::decode_sign_doc_impl(coin, &sign_doc)?;
+
+        // At this moment, we support transactions with one signer only.
+        let [signature] = <[_; 1]>::try_from(tx_raw.signatures)
+            .map_err(|_| SigningError(SigningErrorType::Error_signatures_count))?;
+        let public_key = Context::PublicKey::from_bytes(coin, &decoded.input.public_key)?;
+        decoded.signatures = vec![DecodedSignature::new(
+            &public_key,
+            signature,
+            &decoded.tx_hash,
+        )];
+        Ok(decoded)
+    }
+
+    fn decode_sign_doc_impl(
+        coin: &dyn CoinContext,
+        sign_doc: &tx_proto::SignDoc,
+    ) -> SigningResult<DecodedTx> {
+        let tx_body: tx_proto::TxBody = deserialize(&sign_doc.body_bytes)?;
+        if tx_body.messages.is_empty() {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+        let auth_info: tx_proto::AuthInfo = deserialize(&sign_doc.auth_info_bytes)?;
+
+        let [signer_info] = <[_; 1]>::try_from(auth_info.signer_infos)
+            .map_err(|_| SigningError(SigningErrorType::Error_signatures_count))?;
+        let public_key = Self::decode_signer_info(coin, &signer_info)?;
+        let public_key_bytes = public_key.to_bytes();
+
+        let fee = auth_info
+            .fee
+            .ok_or(SigningError(SigningErrorType::Error_wrong_fee))?;
+        let fee = Proto::Fee {
+            amounts: fee.amount.iter().map(Self::decode_amount).collect(),
+            gas: fee.gas_limit,
+        };
+
+        let mut messages = Vec::with_capacity(tx_body.messages.len());
+        let mut unknown_messages = Vec::default();
+        for (index, msg) in tx_body.messages.iter().enumerate() {
+            match Self::decode_message(coin, msg) {
+                Some(message) => messages.push(message),
+                None => unknown_messages.push(UnknownMessage {
+                    index,
+                    type_url: msg.type_url.clone(),
+                    value: msg.value.clone(),
+                }),
+            }
+        }
+
+        // Make sure the decoded transaction produces exactly the same transaction preimage.
+        // Otherwise, the transaction contains fields that are not supported by `TxBuilder` yet,
+        // like a timeout height, extension options, fee payer or granter.
+        // Please note that known messages are checked in [`ProtobufDecoder::decode_message`],
+        // so the original messages are used as is.
+        let unsigned = UnsignedTransaction::<Context> {
+            signer: SignerInfo {
+                public_key,
+                sequence: signer_info.sequence,
+                sign_mode: SignMode::Direct,
+            },
+            fee: TxBuilder::<Context>::fee_from_proto(&fee)?,
+            chain_id: sign_doc.chain_id.clone(),
+            account_number: sign_doc.account_number,
+            tx_body: TxBody {
+                messages: tx_body
+                    .messages
+                    .iter()
+                    .map(|msg| {
+                        ProtobufRawMessage {
+                            type_url: msg.type_url.clone(),
+                            value: msg.value.clone(),
+                        }
+                        .into_boxed()
+                    })
+                    .collect(),
+                memo: tx_body.memo.clone(),
+                timeout_height: DEFAULT_TIMEOUT_HEIGHT,
+            },
+        };
+        let expected_sign_doc = ProtobufSerializer::<Context>::build_sign_doc(&unsigned)?;
+        if expected_sign_doc.body_bytes != sign_doc.body_bytes
+            || expected_sign_doc.auth_info_bytes != sign_doc.auth_info_bytes
+        {
+            return Err(SigningError(SigningErrorType::Error_not_supported));
+        }
+
+        let input = Proto::SigningInput {
+            signing_mode: Proto::SigningMode::Protobuf,
+            account_number: sign_doc.account_number,
+            chain_id: sign_doc.chain_id.clone().into(),
+            fee: Some(fee),
+            memo: tx_body.memo.into(),
+            sequence: signer_info.sequence,
+            messages,
+            public_key: public_key_bytes.into(),
+            ..Proto::SigningInput::default()
+        };
+
+        let sign_doc_bytes =
+            serialize(sign_doc).expect("Unexpected error on sign_doc serialization");
+        Ok(DecodedTx {
+            input,
+            tx_hash: Context::TxHasher::hash_sign_doc(&sign_doc_bytes),
+            signatures: Vec::default(),
+            unknown_messages,
+        })
+    }
+
+    fn decode_signer_info(
+        coin: &dyn CoinContext,
+        signer_info: &tx_proto::SignerInfo,
+    ) -> SigningResult<Context::PublicKey> {
+        use tx_proto::mod_ModeInfo::OneOfsum as SumEnum;
+
+        // At this moment, we support the Direct signing mode only.
+        match signer_info
+            .mode_info
+            .as_ref()
+            .map(|mode_info| &mode_info.sum)
+        {
+            Some(SumEnum::single(single))
+                if single.mode == signing_proto::SignMode::SIGN_MODE_DIRECT => {},
+            _ => return Err(SigningError(SigningErrorType::Error_not_supported)),
+        }
+
+        let public_key_any = signer_info
+            .public_key
+            .as_ref()
+            .ok_or(SigningError(SigningErrorType::Error_invalid_params))?;
+        // All secp256k1 based public keys (including Ethermint, Injective and Greenfield ones)
+        // are represented as `{ bytes key = 1; }` Protobuf message.
+        let key: cosmos::crypto::secp256k1::PubKey = deserialize(&public_key_any.value)?;
+        let public_key = Context::PublicKey::from_bytes(coin, &key.key)?;
+
+        // The public key type must be expected by the chain.
+        if public_key.to_proto() != *public_key_any {
+            return Err(SigningError(SigningErrorType::Error_not_supported));
+        }
+        Ok(public_key)
+    }
+
+    /// Maps a known [`ProtobufMessage`] to the corresponding [`Proto::Message`].
+    /// Returns `None` if the message is unknown or cannot be re-serialized exactly the same way.
+    fn decode_message(
+        coin: &dyn CoinContext,
+        msg: &ProtobufMessage,
+    ) -> Option<Proto::Message<'static>> {
+        let candidate = Proto::Message {
+            message_oneof: Self::decode_known_message(msg)?,
+        };
+
+        // Make sure the typed message is serialized exactly the same way as the original one.
+        let proto = TxBuilder::<Context>::tx_message(coin, &candidate)
+            .and_then(|tx_message| tx_message.to_proto())
+            .ok()?;
+        (proto == *msg).then_some(candidate)
+    }
+
+    fn decode_known_message(
+        msg: &ProtobufMessage,
+    ) -> Option<Proto::mod_Message::OneOfmessage_oneof<'static>> {
+        use Proto::mod_Message::{self as message, OneOfmessage_oneof as MessageEnum};
+
+        let msg_type_url = msg.type_url.as_str();
+        let message_oneof = if msg_type_url == type_url::<cosmos::bank::v1beta1::MsgSend>() {
+            let send: cosmos::bank::v1beta1::MsgSend = Self::decode_any(msg)?;
+            MessageEnum::send_coins_message(message::Send {
+                from_address: send.from_address.into(),
+                to_address: send.to_address.into(),
+                amounts: send.amount.iter().map(Self::decode_amount).collect(),
+                type_prefix: Cow::default(),
+            })
+        } else if msg_type_url == type_url::<ibc::applications::transfer::v1::MsgTransfer>() {
+            let transfer: ibc::applications::transfer::v1::MsgTransfer = Self::decode_any(msg)?;
+            let height = transfer.timeout_height.unwrap_or_default();
+            MessageEnum::transfer_tokens_message(message::Transfer {
+                source_port: transfer.source_port.into(),
+                source_channel: transfer.source_channel.into(),
+                token: transfer.token.as_ref().map(Self::decode_amount),
+                sender: transfer.sender.into(),
+                receiver: transfer.receiver.into(),
+                timeout_height: Some(Proto::Height {
+                    revision_number: height.revision_number,
+                    revision_height: height.revision_height,
+                }),
+                timeout_timestamp: transfer.timeout_timestamp,
+            })
+        } else if msg_type_url == type_url::<cosmos::staking::v1beta1::MsgDelegate>() {
+            let delegate: cosmos::staking::v1beta1::MsgDelegate = Self::decode_any(msg)?;
+            MessageEnum::stake_message(message::Delegate {
+                delegator_address: delegate.delegator_address.into(),
+                validator_address: delegate.validator_address.into(),
+                amount: delegate.amount.as_ref().map(Self::decode_amount),
+                type_prefix: Cow::default(),
+            })
+        } else if msg_type_url == type_url::<cosmos::staking::v1beta1::MsgUndelegate>() {
+            let undelegate: cosmos::staking::v1beta1::MsgUndelegate = Self::decode_any(msg)?;
+            MessageEnum::unstake_message(message::Undelegate {
+                delegator_address: undelegate.delegator_address.into(),
+                validator_address: undelegate.validator_address.into(),
+                amount: undelegate.amount.as_ref().map(Self::decode_amount),
+                type_prefix: Cow::default(),
+            })
+        } else if msg_type_url == type_url::<cosmos::staking::v1beta1::MsgBeginRedelegate>() {
+            let redelegate: cosmos::staking::v1beta1::MsgBeginRedelegate = Self::decode_any(msg)?;
+            MessageEnum::restake_message(message::BeginRedelegate {
+                delegator_address: redelegate.delegator_address.into(),
+                validator_src_address: redelegate.validator_src_address.into(),
+                validator_dst_address: redelegate.validator_dst_address.into(),
+                amount: redelegate.amount.as_ref().map(Self::decode_amount),
+                type_prefix: Cow::default(),
+            })
+        } else if msg_type_url
+            == type_url::<cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward>()
+        {
+            let withdraw: cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward =
+                Self::decode_any(msg)?;
+            MessageEnum::withdraw_stake_reward_message(message::WithdrawDelegationReward {
+                delegator_address: withdraw.delegator_address.into(),
+                validator_address: withdraw.validator_address.into(),
+                type_prefix: Cow::default(),
+            })
+        } else if msg_type_url == type_url::<cosmos::distribution::v1beta1::MsgSetWithdrawAddress>()
+        {
+            let set: cosmos::distribution::v1beta1::MsgSetWithdrawAddress = Self::decode_any(msg)?;
+            MessageEnum::set_withdraw_address_message(message::SetWithdrawAddress {
+                delegator_address: set.delegator_address.into(),
+                withdraw_address: set.withdraw_address.into(),
+                type_prefix: Cow::default(),
+            })
+        } else if msg_type_url == type_url::<cosmwasm::wasm::v1::MsgExecuteContract>() {
+            let execute: cosmwasm::wasm::v1::MsgExecuteContract = Self::decode_any(msg)?;
+            MessageEnum::wasm_execute_contract_generic(message::WasmExecuteContractGeneric {
+                sender_address: execute.sender.into(),
+                contract_address: execute.contract.into(),
+                execute_msg: String::from_utf8(execute.msg).ok()?.into(),
+                coins: execute.funds.iter().map(Self::decode_amount).collect(),
+            })
+        } else if msg_type_url == type_url::<cosmwasm::wasm::v1::MsgStoreCode>() {
+            let store: cosmwasm::wasm::v1::MsgStoreCode = Self::decode_any(msg)?;
+            MessageEnum::wasm_store_code_message(message::WasmStoreCode {
+                sender_address: store.sender.into(),
+                wasm_byte_code: store.wasm_byte_code.into(),
+                instantiate_permission: store
+                    .instantiate_permission
+                    .map(Self::decode_access_config),
+            })
+        } else if msg_type_url == type_url::<cosmwasm::wasm::v1::MsgInstantiateContract>() {
+            let instantiate: cosmwasm::wasm::v1::MsgInstantiateContract = Self::decode_any(msg)?;
+            MessageEnum::wasm_instantiate_contract_message(message::WasmInstantiateContract {
+                sender_address: instantiate.sender.into(),
+                admin_address: instantiate.admin.into(),
+                code_id: instantiate.code_id,
+                label: instantiate.label.into(),
+                msg: String::from_utf8(instantiate.msg).ok()?.into(),
+                coins: instantiate.funds.iter().map(Self::decode_amount).collect(),
+            })
+        } else if msg_type_url == type_url::<cosmwasm::wasm::v1::MsgInstantiateContract2>() {
+            let instantiate: cosmwasm::wasm::v1::MsgInstantiateContract2 = Self::decode_any(msg)?;
+            MessageEnum::wasm_instantiate_contract2_message(message::WasmInstantiateContract2 {
+                sender_address: instantiate.sender.into(),
+                admin_address: instantiate.admin.into(),
+                code_id: instantiate.code_id,
+                label: instantiate.label.into(),
+                msg: String::from_utf8(instantiate.msg).ok()?.into(),
+                coins: instantiate.funds.iter().map(Self::decode_amount).collect(),
+                salt: instantiate.salt.into(),
+                fix_msg: instantiate.fix_msg,
+            })
+        } else if msg_type_url == type_url::<cosmwasm::wasm::v1::MsgMigrateContract>() {
+            let migrate: cosmwasm::wasm::v1::MsgMigrateContract = Self::decode_any(msg)?;
+            MessageEnum::wasm_migrate_contract_message(message::WasmMigrateContract {
+                sender_address: migrate.sender.into(),
+                contract_address: migrate.contract.into(),
+                code_id: migrate.code_id,
+                msg: String::from_utf8(migrate.msg).ok()?.into(),
+            })
+        } else if msg_type_url == type_url::<cosmwasm::wasm::v1::MsgUpdateAdmin>() {
+            let update: cosmwasm::wasm::v1::MsgUpdateAdmin = Self::decode_any(msg)?;
+            MessageEnum::wasm_update_admin_message(message::WasmUpdateAdmin {
+                sender_address: update.sender.into(),
+                new_admin_address: update.new_admin.into(),
+                contract_address: update.contract.into(),
+            })
+        } else if msg_type_url == type_url::<cosmwasm::wasm::v1::MsgClearAdmin>() {
+            let clear: cosmwasm::wasm::v1::MsgClearAdmin = Self::decode_any(msg)?;
+            MessageEnum::wasm_clear_admin_message(message::WasmClearAdmin {
+                sender_address: clear.sender.into(),
+                contract_address: clear.contract.into(),
+            })
+        } else if msg_type_url == type_url::<cosmos::gov::v1beta1::MsgVote>() {
+            let vote: cosmos::gov::v1beta1::MsgVote = Self::decode_any(msg)?;
+            MessageEnum::msg_vote(message::MsgVote {
+                proposal_id: vote.proposal_id,
+                voter: vote.voter.into(),
+                option: message::VoteOption::from(vote.option as i32),
+            })
+        } else {
+            return None;
+        };
+        Some(message_oneof)
+    }
+
+    fn decode_any<'a, T: MessageRead<'a>>(msg: &'a ProtobufMessage) -> Option<T> {
+        deserialize(&msg.value).ok()
+    }
+
+    fn decode_access_config(
+        access_config: cosmwasm::wasm::v1::AccessConfig,
+    ) -> Proto::mod_Message::WasmAccessConfig<'static> {
+        use cosmwasm::wasm::v1::AccessType;
+        use Proto::mod_Message::WasmAccessType as ProtoAccessType;
+
+        let permission = match access_config.permission {
+            AccessType::ACCESS_TYPE_UNSPECIFIED => ProtoAccessType::ACCESS_TYPE_UNSPECIFIED,
+            AccessType::ACCESS_TYPE_NOBODY => ProtoAccessType::ACCESS_TYPE_NOBODY,
+            AccessType::ACCESS_TYPE_EVERYBODY => ProtoAccessType::ACCESS_TYPE_EVERYBODY,
+            AccessType::ACCESS_TYPE_ANY_OF_ADDRESSES => {
+                ProtoAccessType::ACCESS_TYPE_ANY_OF_ADDRESSES
+            },
+        };
+        Proto::mod_Message::WasmAccessConfig {
+            permission,
+            addresses: access_config.addresses.into_iter().map(Cow::from).collect(),
+        }
+    }
+
+    fn decode_amount(coin: &base_proto::Coin) -> Proto::Amount<'static> {
+        Proto::Amount {
+            denom: coin.denom.clone().into(),
+            amount: coin.amount.clone().into(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::CosmosContext;
+use crate::modules::decoder::json_decoder::JsonDecoder;
+use crate::modules::decoder::protobuf_decoder::ProtobufDecoder;
+use crate::modules::decoder::DecodedTx;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::signing_output_error;
+use tw_proto::Cosmos::Proto;
+
+pub struct TWDecoder<Context> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: CosmosContext> TWDecoder<Context> {
+    /// Decodes a Cosmos transaction or its preimage back to the [`Proto::SigningInput`],
+    /// and verifies its signatures if any.
+    #[inline]
+    pub fn decode_transaction(
+        coin: &dyn CoinContext,
+        input: Proto::DecodingTransactionInput<'_>,
+    ) -> Proto::DecodingTransactionOutput<'static> {
+        Self::decode_transaction_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+
+    fn decode_transaction_impl(
+        coin: &dyn CoinContext,
+        input: Proto::DecodingTransactionInput<'_>,
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        use Proto::mod_DecodingTransactionInput::OneOftransaction_oneof as TransactionEnum;
+
+        let decoded = match input.transaction_oneof {
+            TransactionEnum::tx_raw(ref tx_raw) => ProtobufDecoder::<Context>::decode_tx_raw(
+                coin,
+                tx_raw,
+                &input.chain_id,
+                input.account_number,
+            )?,
+            TransactionEnum::sign_doc(ref sign_doc) => {
+                ProtobufDecoder::<Context>::decode_sign_doc(coin, sign_doc)?
+            },
+            TransactionEnum::amino_sign_doc(ref sign_doc) => {
+                JsonDecoder::<Context>::decode_amino_sign_doc(coin, sign_doc)?
+            },
+            TransactionEnum::None => {
+                return Err(SigningError(SigningErrorType::Error_invalid_params))
+            },
+        };
+        Self::decoded_tx_to_proto(decoded)
+    }
+
+    fn decoded_tx_to_proto(
+        decoded: DecodedTx,
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let unknown_messages = decoded
+            .unknown_messages
+            .into_iter()
+            .map(|msg| -> SigningResult<_> {
+                Ok(Proto::UnknownMessage {
+                    index: u32::try_from(msg.index)
+                        .map_err(|_| SigningError(SigningErrorType::Error_internal))?,
+                    type_url: msg.type_url.into(),
+                    value: msg.value.into(),
+                })
+            })
+            .collect::<SigningResult<_>>()?;
+        let signatures = decoded
+            .signatures
+            .into_iter()
+            .map(|sign| Proto::DecodedSignature {
+                public_key: sign.public_key.into(),
+                signature: sign.signature.into(),
+                verified: sign.verified,
+            })
+            .collect();
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(decoded.input),
+            unknown_messages,
+            tx_hash: decoded.tx_hash.into(),
+            signatures,
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+}
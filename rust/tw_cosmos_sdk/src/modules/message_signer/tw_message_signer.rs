@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::context::CosmosContext;
+use crate::hasher::CosmosHasher;
+use crate::modules::compiler::json_preimager::JsonTxPreimage;
+use crate::modules::serializer::json_serializer::{FeeJson, JsonSerializer, UnsignedTxJson};
+use crate::private_key::CosmosPrivateKey;
+use crate::public_key::secp256k1::prepare_secp256k1_public_key;
+use crate::public_key::CosmosPublicKey;
+use crate::transaction::message::adr036_message::SignDataMessage;
+use crate::transaction::message::CosmosMessage;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64::Base64Encoded;
+use tw_keypair::tw;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Cosmos::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// Signs and verifies arbitrary messages according to ADR-036.
+/// https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-036-arbitrary-signature.md
+pub struct TWMessageSigner<Context> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context> Default for TWMessageSigner<Context> {
+    fn default() -> Self {
+        TWMessageSigner {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Context: CosmosContext> MessageSigner for TWMessageSigner<Context> {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(coin, input).unwrap_or_default()
+    }
+}
+
+impl<Context: CosmosContext> TWMessageSigner<Context> {
+    fn message_preimage_hashes_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let public_key = if input.public_key.is_empty() {
+            let private_key = Context::PrivateKey::try_from(&input.private_key)?;
+            Context::PublicKey::from_private_key(coin, private_key.as_ref())?
+        } else {
+            Context::PublicKey::from_bytes(coin, &input.public_key)?
+        };
+
+        let preimage = Self::preimage_hash(coin, &public_key, input.message.to_vec())?;
+        Ok(CompilerProto::PreSigningOutput {
+            data: preimage.encoded_tx.into_bytes().into(),
+            data_hash: preimage.tx_hash.into(),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = Context::PrivateKey::try_from(&input.private_key)?;
+        let public_key = Context::PublicKey::from_private_key(coin, private_key.as_ref())?;
+
+        let preimage = Self::preimage_hash(coin, &public_key, input.message.to_vec())?;
+        let signature_data = private_key.sign_tx_hash(&preimage.tx_hash)?;
+        // Convert the signature to the chain specific format, e.g. `r || s` without the recovery byte.
+        let signature = Context::Signature::try_from(&signature_data)?.to_vec();
+
+        let signature_json =
+            JsonSerializer::<Context>::serialize_signature(&public_key, signature.clone());
+        let signature_json = serde_json::to_string(&signature_json)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: signature.into(),
+            signature_json: signature_json.into(),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<bool> {
+        let public_key = Context::PublicKey::from_bytes(coin, &input.public_key)?;
+        let preimage = Self::preimage_hash(coin, &public_key, input.message.to_vec())?;
+        Ok(public_key.verify(&input.signature, &preimage.tx_hash))
+    }
+
+    /// Builds an ADR-036 amino `StdSignDoc` with a single `sign/MsgSignData` message.
+    /// `chain_id`, `memo`, `account_number`, `sequence` and `fee` must be empty or zero.
+    fn preimage_hash(
+        coin: &dyn CoinContext,
+        public_key: &Context::PublicKey,
+        data: Data,
+    ) -> SigningResult<JsonTxPreimage> {
+        // The signer address is derived from the public key according to the coin configuration,
+        // so Ethermint-like chains get their Keccak-based addresses.
+        let public_key_bytes = prepare_secp256k1_public_key(coin, &public_key.to_bytes())?;
+        let tw_public_key = tw::PublicKey::new(public_key_bytes, coin.public_key_type())?;
+        let signer = Address::with_public_key_coin_context(coin, &tw_public_key, None)?;
+
+        let msg = SignDataMessage {
+            data: Base64Encoded(data),
+            signer,
+        };
+        let sign_doc = UnsignedTxJson {
+            account_number: "0".to_string(),
+            chain_id: String::default(),
+            fee: FeeJson {
+                amount: Vec::default(),
+                gas: "0".to_string(),
+            },
+            memo: String::default(),
+            msgs: vec![msg.to_json()?],
+            sequence: "0".to_string(),
+        };
+
+        let encoded_tx = serde_json::to_string(&sign_doc)
+            .map_err(|_| SigningError(SigningErrorType::Error_internal))?;
+        let tx_hash = Context::TxHasher::hash_json_tx(&encoded_tx);
+        Ok(JsonTxPreimage {
+            encoded_tx,
+            tx_hash,
+        })
+    }
+}
@@ -4,6 +4,8 @@
 
 pub mod broadcast_msg;
 pub mod compiler;
+pub mod decoder;
+pub mod message_signer;
 pub mod serializer;
 pub mod signer;
 pub mod tx_builder;
@@ -12,13 +12,14 @@ use crate::transaction::{Coin, Fee, SignMode, SignerInfo, TxBody, UnsignedTransa
 use std::marker::PhantomData;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
-use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_coin_entry::error::{AddressResult, SigningError, SigningErrorType, SigningResult};
+use tw_encoding::base64::Base64Encoded;
 use tw_misc::traits::{OptionalEmpty, ToBytesVec};
 use tw_number::U256;
 use tw_proto::Cosmos::Proto;
 use tw_proto::{google, serialize};
 
-const DEFAULT_TIMEOUT_HEIGHT: u64 = 0;
+pub const DEFAULT_TIMEOUT_HEIGHT: u64 = 0;
 
 pub struct TxBuilder<Context> {
     _phantom: PhantomData<Context>,
@@ -62,7 +63,7 @@ where
         })
     }
 
-    fn fee_from_proto(input: &Proto::Fee) -> SigningResult<Fee<Context::Address>> {
+    pub fn fee_from_proto(input: &Proto::Fee) -> SigningResult<Fee<Context::Address>> {
         let amounts = input
             .amounts
             .iter()
@@ -84,6 +85,14 @@ where
         })
     }
 
+    /// Parses an optional address. An empty string is considered as no address.
+    fn optional_address(coin: &dyn CoinContext, addr: &str) -> AddressResult<Option<Address>> {
+        if addr.is_empty() {
+            return Ok(None);
+        }
+        Address::from_str_with_coin(coin, addr).map(Some)
+    }
+
     fn tx_body_from_proto(
         coin: &dyn CoinContext,
         input: &Proto::SigningInput<'_>,
@@ -192,6 +201,24 @@ where
             MessageEnum::thorchain_deposit_message(ref deposit) => {
                 Self::thorchain_deposit_msg_from_proto(coin, deposit)
             },
+            MessageEnum::wasm_store_code_message(ref store) => {
+                Self::wasm_store_code_msg_from_proto(coin, store)
+            },
+            MessageEnum::wasm_instantiate_contract_message(ref instantiate) => {
+                Self::wasm_instantiate_contract_msg_from_proto(coin, instantiate)
+            },
+            MessageEnum::wasm_instantiate_contract2_message(ref instantiate) => {
+                Self::wasm_instantiate_contract2_msg_from_proto(coin, instantiate)
+            },
+            MessageEnum::wasm_migrate_contract_message(ref migrate) => {
+                Self::wasm_migrate_contract_msg_from_proto(coin, migrate)
+            },
+            MessageEnum::wasm_update_admin_message(ref update) => {
+                Self::wasm_update_admin_msg_from_proto(coin, update)
+            },
+            MessageEnum::wasm_clear_admin_message(ref clear) => {
+                Self::wasm_clear_admin_msg_from_proto(coin, clear)
+            },
             MessageEnum::None => Err(SigningError(SigningErrorType::Error_invalid_params)),
         }
     }
@@ -492,6 +519,151 @@ where
         Ok(msg.into_boxed())
     }
 
+    pub fn wasm_store_code_msg_from_proto(
+        coin: &dyn CoinContext,
+        store: &Proto::mod_Message::WasmStoreCode<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::wasm_message::{
+            is_valid_wasm_byte_code, WasmAccessConfig, WasmAccessType, WasmStoreCodeMessage,
+        };
+        use Proto::mod_Message::WasmAccessType as ProtoAccessType;
+
+        if !is_valid_wasm_byte_code(&store.wasm_byte_code) {
+            return Err(SigningError(SigningErrorType::Error_invalid_params));
+        }
+
+        let instantiate_permission = match store.instantiate_permission {
+            Some(ref access_config) => {
+                let permission = match access_config.permission {
+                    ProtoAccessType::ACCESS_TYPE_UNSPECIFIED => None,
+                    ProtoAccessType::ACCESS_TYPE_NOBODY => Some(WasmAccessType::Nobody),
+                    ProtoAccessType::ACCESS_TYPE_EVERYBODY => Some(WasmAccessType::Everybody),
+                    ProtoAccessType::ACCESS_TYPE_ANY_OF_ADDRESSES => {
+                        Some(WasmAccessType::AnyOfAddresses)
+                    },
+                };
+                let addresses: Vec<_> = access_config
+                    .addresses
+                    .iter()
+                    .map(|addr| Address::from_str_with_coin(coin, addr))
+                    .collect::<AddressResult<_>>()?;
+                match permission {
+                    Some(permission) => Some(WasmAccessConfig {
+                        permission,
+                        addresses,
+                    }),
+                    // Addresses can't be ignored silently if the permission is not specified.
+                    None if !addresses.is_empty() => {
+                        return Err(SigningError(SigningErrorType::Error_invalid_params))
+                    },
+                    None => None,
+                }
+            },
+            None => None,
+        };
+
+        let msg = WasmStoreCodeMessage {
+            sender: Address::from_str_with_coin(coin, &store.sender_address)?,
+            wasm_byte_code: Base64Encoded(store.wasm_byte_code.to_vec()),
+            instantiate_permission,
+        };
+        Ok(msg.into_boxed())
+    }
+
+    pub fn wasm_instantiate_contract_msg_from_proto(
+        coin: &dyn CoinContext,
+        instantiate: &Proto::mod_Message::WasmInstantiateContract<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::wasm_message::{
+            ExecuteMsg, WasmInstantiateContractMessage,
+        };
+
+        let funds = instantiate
+            .coins
+            .iter()
+            .map(Self::coin_from_proto)
+            .collect::<SigningResult<_>>()?;
+
+        let msg = WasmInstantiateContractMessage {
+            sender: Address::from_str_with_coin(coin, &instantiate.sender_address)?,
+            admin: Self::optional_address(coin, &instantiate.admin_address)?,
+            code_id: instantiate.code_id,
+            label: instantiate.label.to_string(),
+            msg: ExecuteMsg::String(instantiate.msg.to_string()),
+            funds,
+        };
+        Ok(msg.into_boxed())
+    }
+
+    pub fn wasm_instantiate_contract2_msg_from_proto(
+        coin: &dyn CoinContext,
+        instantiate: &Proto::mod_Message::WasmInstantiateContract2<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::wasm_message::{
+            ExecuteMsg, WasmInstantiateContract2Message,
+        };
+
+        let funds = instantiate
+            .coins
+            .iter()
+            .map(Self::coin_from_proto)
+            .collect::<SigningResult<_>>()?;
+
+        let msg = WasmInstantiateContract2Message {
+            sender: Address::from_str_with_coin(coin, &instantiate.sender_address)?,
+            admin: Self::optional_address(coin, &instantiate.admin_address)?,
+            code_id: instantiate.code_id,
+            label: instantiate.label.to_string(),
+            msg: ExecuteMsg::String(instantiate.msg.to_string()),
+            funds,
+            salt: instantiate.salt.to_vec(),
+            fix_msg: instantiate.fix_msg,
+        };
+        Ok(msg.into_boxed())
+    }
+
+    pub fn wasm_migrate_contract_msg_from_proto(
+        coin: &dyn CoinContext,
+        migrate: &Proto::mod_Message::WasmMigrateContract<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::wasm_message::{ExecuteMsg, WasmMigrateContractMessage};
+
+        let msg = WasmMigrateContractMessage {
+            sender: Address::from_str_with_coin(coin, &migrate.sender_address)?,
+            contract: Address::from_str_with_coin(coin, &migrate.contract_address)?,
+            code_id: migrate.code_id,
+            msg: ExecuteMsg::String(migrate.msg.to_string()),
+        };
+        Ok(msg.into_boxed())
+    }
+
+    pub fn wasm_update_admin_msg_from_proto(
+        coin: &dyn CoinContext,
+        update: &Proto::mod_Message::WasmUpdateAdmin<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::wasm_message::WasmUpdateAdminMessage;
+
+        let msg = WasmUpdateAdminMessage {
+            contract: Address::from_str_with_coin(coin, &update.contract_address)?,
+            new_admin: Address::from_str_with_coin(coin, &update.new_admin_address)?,
+            sender: Address::from_str_with_coin(coin, &update.sender_address)?,
+        };
+        Ok(msg.into_boxed())
+    }
+
+    pub fn wasm_clear_admin_msg_from_proto(
+        coin: &dyn CoinContext,
+        clear: &Proto::mod_Message::WasmClearAdmin<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::wasm_message::WasmClearAdminMessage;
+
+        let msg = WasmClearAdminMessage {
+            contract: Address::from_str_with_coin(coin, &clear.contract_address)?,
+            sender: Address::from_str_with_coin(coin, &clear.sender_address)?,
+        };
+        Ok(msg.into_boxed())
+    }
+
     pub fn thorchain_send_msg_from_proto(
         _coin: &dyn CoinContext,
         send: &Proto::mod_Message::THORChainSend<'_>,
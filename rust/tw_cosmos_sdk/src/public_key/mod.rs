@@ -18,6 +18,9 @@ pub trait CosmosPublicKey: JsonPublicKey + ProtobufPublicKey + Sized {
     fn from_bytes(coin: &dyn CoinContext, public_key_bytes: &[u8]) -> KeyPairResult<Self>;
 
     fn to_bytes(&self) -> Data;
+
+    /// Verifies if the given `signature` of the `message_hash` was produced by the corresponding private key.
+    fn verify(&self, signature: &[u8], message_hash: &[u8]) -> bool;
 }
 
 pub trait ProtobufPublicKey {
@@ -5,7 +5,9 @@
 use crate::proto::cosmos;
 use crate::public_key::{CosmosPublicKey, JsonPublicKey, ProtobufPublicKey};
 use tw_coin_entry::coin_context::CoinContext;
+use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::VerifyingKeyTrait;
 use tw_keypair::tw::{self, PublicKeyType};
 use tw_keypair::{KeyPairError, KeyPairResult};
 use tw_memory::Data;
@@ -46,6 +48,10 @@ impl CosmosPublicKey for Secp256PublicKey {
     fn to_bytes(&self) -> Data {
         self.public_key.clone()
     }
+
+    fn verify(&self, signature: &[u8], message_hash: &[u8]) -> bool {
+        verify_secp256k1_signature(&self.public_key, signature, message_hash)
+    }
 }
 
 impl ProtobufPublicKey for Secp256PublicKey {
@@ -74,3 +80,22 @@ pub fn prepare_secp256k1_public_key(
         _ => Err(KeyPairError::InvalidPublicKey),
     }
 }
+
+/// Verifies the `secp256k1` signature of the given `message_hash`.
+/// The `public_key` can be either compressed or uncompressed.
+pub fn verify_secp256k1_signature(
+    public_key: &[u8],
+    signature: &[u8],
+    message_hash: &[u8],
+) -> bool {
+    let Ok(public_key) = secp256k1::PublicKey::try_from(public_key) else {
+        return false;
+    };
+    let Ok(signature) = secp256k1::VerifySignature::try_from(signature) else {
+        return false;
+    };
+    let Ok(message_hash) = H256::try_from(message_hash) else {
+        return false;
+    };
+    public_key.verify(signature, message_hash)
+}
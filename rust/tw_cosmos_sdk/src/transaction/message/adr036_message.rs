@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::CosmosAddress;
+use crate::transaction::message::{message_to_json, CosmosMessage, JsonMessage};
+use serde::Serialize;
+use tw_coin_entry::error::SigningResult;
+use tw_encoding::base64::Base64Encoded;
+
+const DEFAULT_JSON_SIGN_DATA_TYPE: &str = "sign/MsgSignData";
+
+/// ADR-036 `sign/MsgSignData` used to sign arbitrary data off-chain.
+/// Supports JSON serialization only.
+///
+/// https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-036-arbitrary-signature.md
+#[derive(Clone, Serialize)]
+pub struct SignDataMessage<Address: CosmosAddress> {
+    pub data: Base64Encoded,
+    pub signer: Address,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for SignDataMessage<Address> {
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        message_to_json(DEFAULT_JSON_SIGN_DATA_TYPE, self)
+    }
+}
@@ -2,9 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::transaction::message::{CosmosMessage, JsonMessage};
+use crate::transaction::message::{CosmosMessage, JsonMessage, ProtobufMessage};
 use serde_json::Value as Json;
 use tw_coin_entry::error::SigningResult;
+use tw_memory::Data;
 
 /// Any raw JSON message.
 /// Supports JSON serialization only.
@@ -21,3 +22,19 @@ impl CosmosMessage for JsonRawMessage {
         })
     }
 }
+
+/// Any raw Protobuf message.
+/// Supports Protobuf serialization only.
+pub struct ProtobufRawMessage {
+    pub type_url: String,
+    pub value: Data,
+}
+
+impl CosmosMessage for ProtobufRawMessage {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        Ok(ProtobufMessage {
+            type_url: self.type_url.clone(),
+            value: self.value.clone(),
+        })
+    }
+}
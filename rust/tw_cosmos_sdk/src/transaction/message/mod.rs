@@ -8,6 +8,7 @@ use serde_json::Value as Json;
 use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
 use tw_proto::google;
 
+pub mod adr036_message;
 pub mod cosmos_auth_message;
 pub mod cosmos_bank_message;
 pub mod cosmos_generic_message;
@@ -5,16 +5,37 @@
 use crate::address::CosmosAddress;
 use crate::modules::serializer::protobuf_serializer::build_coin;
 use crate::proto::cosmwasm;
-use crate::transaction::message::{CosmosMessage, JsonMessage, ProtobufMessage};
+use crate::transaction::message::{message_to_json, CosmosMessage, JsonMessage, ProtobufMessage};
 use crate::transaction::Coin;
 use serde::Serialize;
 use serde_json::{json, Value as Json};
 use tw_coin_entry::error::{SigningError, SigningErrorType, SigningResult};
+use tw_encoding::base64::Base64Encoded;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
 use tw_memory::Data;
+use tw_misc::serde::as_string;
 use tw_number::U256;
 use tw_proto::to_any;
 
 const DEFAULT_JSON_MSG_TYPE: &str = "wasm/MsgExecuteContract";
+const DEFAULT_JSON_STORE_CODE_TYPE: &str = "wasm/MsgStoreCode";
+const DEFAULT_JSON_INSTANTIATE_TYPE: &str = "wasm/MsgInstantiateContract";
+const DEFAULT_JSON_INSTANTIATE2_TYPE: &str = "wasm/MsgInstantiateContract2";
+const DEFAULT_JSON_MIGRATE_TYPE: &str = "wasm/MsgMigrateContract";
+const DEFAULT_JSON_UPDATE_ADMIN_TYPE: &str = "wasm/MsgUpdateAdmin";
+const DEFAULT_JSON_CLEAR_ADMIN_TYPE: &str = "wasm/MsgClearAdmin";
+
+/// WASM binary magic bytes: `\0asm`.
+const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+/// GZIP magic bytes followed by the DEFLATE compression method.
+const GZIP_MAGIC: [u8; 3] = [0x1f, 0x8b, 0x08];
+
+/// The module name used to derive contract addresses.
+const WASM_MODULE_NAME: &str = "wasm";
+/// Size of the salt used to derive a predictable contract address.
+const SALT_MIN_LEN: usize = 1;
+const SALT_MAX_LEN: usize = 64;
 
 #[derive(Clone, Serialize)]
 #[serde(untagged)]
@@ -101,3 +122,300 @@ pub enum WasmExecutePayload {
         msg: String,
     },
 }
+
+/// Checks if the given byte code is either a raw WASM binary or a gzip compressed one.
+/// wasmd decompresses the byte code automatically if it starts with the gzip magic bytes.
+pub fn is_valid_wasm_byte_code(wasm_byte_code: &[u8]) -> bool {
+    wasm_byte_code.starts_with(&WASM_MAGIC) || wasm_byte_code.starts_with(&GZIP_MAGIC)
+}
+
+/// Computes a predictable contract address as it's done for `MsgInstantiateContract2`.
+/// https://github.com/CosmWasm/wasmd/blob/v0.45.0/x/wasm/keeper/addresses.go#L35-L57
+///
+/// * `checksum` - SHA-256 hash of the raw (uncompressed) WASM byte code.
+/// * `creator` - creator's address bytes (account key hash).
+/// * `salt` - an arbitrary value provided by the creator. Size can be 1 to 64.
+/// * `fix_msg` - an instantiate message if it should be included into the hash.
+pub fn instantiate2_address(
+    checksum: &[u8],
+    creator: &[u8],
+    salt: &[u8],
+    fix_msg: Option<&[u8]>,
+) -> SigningResult<H256> {
+    if checksum.len() != H256::LEN || !(SALT_MIN_LEN..=SALT_MAX_LEN).contains(&salt.len()) {
+        return Err(SigningError(SigningErrorType::Error_invalid_params));
+    }
+
+    fn length_prefixed(data: &[u8]) -> Data {
+        let mut res = (data.len() as u64).to_be_bytes().to_vec();
+        res.extend_from_slice(data);
+        res
+    }
+
+    // `address.Module(ModuleName, key)` where the module key is followed by a zero byte
+    // to avoid a potential clash between the module name and the derivation key.
+    let mut module_key = WASM_MODULE_NAME.as_bytes().to_vec();
+    module_key.push(0);
+    module_key.extend(length_prefixed(checksum));
+    module_key.extend(length_prefixed(creator));
+    module_key.extend(length_prefixed(salt));
+    module_key.extend(length_prefixed(fix_msg.unwrap_or_default()));
+
+    // `address.Hash("module", key)`
+    let mut preimage = sha256(b"module");
+    preimage.extend(module_key);
+
+    let address =
+        H256::try_from(sha256(&preimage).as_slice()).expect("sha256 must return exactly 32 bytes");
+    Ok(address)
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub enum WasmAccessType {
+    Nobody,
+    Everybody,
+    AnyOfAddresses,
+}
+
+impl WasmAccessType {
+    fn to_proto(self) -> cosmwasm::wasm::v1::AccessType {
+        use cosmwasm::wasm::v1::AccessType;
+
+        match self {
+            WasmAccessType::Nobody => AccessType::ACCESS_TYPE_NOBODY,
+            WasmAccessType::Everybody => AccessType::ACCESS_TYPE_EVERYBODY,
+            WasmAccessType::AnyOfAddresses => AccessType::ACCESS_TYPE_ANY_OF_ADDRESSES,
+        }
+    }
+}
+
+/// Access control to apply on contract creation.
+#[derive(Serialize)]
+pub struct WasmAccessConfig<Address: CosmosAddress> {
+    pub permission: WasmAccessType,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub addresses: Vec<Address>,
+}
+
+impl<Address: CosmosAddress> WasmAccessConfig<Address> {
+    fn to_proto(&self) -> cosmwasm::wasm::v1::AccessConfig {
+        cosmwasm::wasm::v1::AccessConfig {
+            permission: self.permission.to_proto(),
+            addresses: self.addresses.iter().map(|addr| addr.to_string()).collect(),
+        }
+    }
+}
+
+/// wasm/MsgStoreCode
+#[derive(Serialize)]
+pub struct WasmStoreCodeMessage<Address: CosmosAddress> {
+    pub sender: Address,
+    /// Raw or gzip compressed WASM byte code.
+    pub wasm_byte_code: Base64Encoded,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiate_permission: Option<WasmAccessConfig<Address>>,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for WasmStoreCodeMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmwasm::wasm::v1::MsgStoreCode {
+            sender: self.sender.to_string(),
+            wasm_byte_code: self.wasm_byte_code.0.clone(),
+            instantiate_permission: self
+                .instantiate_permission
+                .as_ref()
+                .map(WasmAccessConfig::to_proto),
+        };
+        Ok(to_any(&proto_msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        message_to_json(DEFAULT_JSON_STORE_CODE_TYPE, self)
+    }
+}
+
+/// wasm/MsgInstantiateContract
+pub struct WasmInstantiateContractMessage<Address: CosmosAddress> {
+    pub sender: Address,
+    pub admin: Option<Address>,
+    pub code_id: u64,
+    pub label: String,
+    pub msg: ExecuteMsg,
+    pub funds: Vec<Coin>,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for WasmInstantiateContractMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmwasm::wasm::v1::MsgInstantiateContract {
+            sender: self.sender.to_string(),
+            admin: self
+                .admin
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            code_id: self.code_id,
+            label: self.label.clone(),
+            msg: self.msg.to_bytes(),
+            funds: self.funds.iter().map(build_coin).collect(),
+        };
+        Ok(to_any(&proto_msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let value = InstantiateJson {
+            admin: self.admin.as_ref(),
+            code_id: self.code_id,
+            fix_msg: false,
+            funds: &self.funds,
+            label: &self.label,
+            msg: self.msg.try_to_json(),
+            salt: None,
+            sender: &self.sender,
+        };
+        message_to_json(DEFAULT_JSON_INSTANTIATE_TYPE, &value)
+    }
+}
+
+/// wasm/MsgInstantiateContract2
+pub struct WasmInstantiateContract2Message<Address: CosmosAddress> {
+    pub sender: Address,
+    pub admin: Option<Address>,
+    pub code_id: u64,
+    pub label: String,
+    pub msg: ExecuteMsg,
+    pub funds: Vec<Coin>,
+    pub salt: Data,
+    pub fix_msg: bool,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for WasmInstantiateContract2Message<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmwasm::wasm::v1::MsgInstantiateContract2 {
+            sender: self.sender.to_string(),
+            admin: self
+                .admin
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            code_id: self.code_id,
+            label: self.label.clone(),
+            msg: self.msg.to_bytes(),
+            funds: self.funds.iter().map(build_coin).collect(),
+            salt: self.salt.clone(),
+            fix_msg: self.fix_msg,
+        };
+        Ok(to_any(&proto_msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let value = InstantiateJson {
+            admin: self.admin.as_ref(),
+            code_id: self.code_id,
+            fix_msg: self.fix_msg,
+            funds: &self.funds,
+            label: &self.label,
+            msg: self.msg.try_to_json(),
+            salt: Some(Base64Encoded(self.salt.clone())),
+            sender: &self.sender,
+        };
+        message_to_json(DEFAULT_JSON_INSTANTIATE2_TYPE, &value)
+    }
+}
+
+/// Amino JSON representation of both `MsgInstantiateContract` and `MsgInstantiateContract2`.
+#[derive(Serialize)]
+struct InstantiateJson<'a, Address: CosmosAddress> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    admin: Option<&'a Address>,
+    #[serde(serialize_with = "as_string::serialize")]
+    code_id: u64,
+    #[serde(skip_serializing_if = "is_false")]
+    fix_msg: bool,
+    funds: &'a [Coin],
+    label: &'a str,
+    msg: ExecuteMsg,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    salt: Option<Base64Encoded>,
+    sender: &'a Address,
+}
+
+/// wasm/MsgMigrateContract
+pub struct WasmMigrateContractMessage<Address: CosmosAddress> {
+    pub sender: Address,
+    pub contract: Address,
+    pub code_id: u64,
+    pub msg: ExecuteMsg,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for WasmMigrateContractMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmwasm::wasm::v1::MsgMigrateContract {
+            sender: self.sender.to_string(),
+            contract: self.contract.to_string(),
+            code_id: self.code_id,
+            msg: self.msg.to_bytes(),
+        };
+        Ok(to_any(&proto_msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        let value = json!({
+            "code_id": self.code_id.to_string(),
+            "contract": self.contract,
+            "msg": self.msg.try_to_json(),
+            "sender": self.sender,
+        });
+        Ok(JsonMessage {
+            msg_type: DEFAULT_JSON_MIGRATE_TYPE.to_string(),
+            value,
+        })
+    }
+}
+
+/// wasm/MsgUpdateAdmin
+#[derive(Serialize)]
+pub struct WasmUpdateAdminMessage<Address: CosmosAddress> {
+    pub contract: Address,
+    pub new_admin: Address,
+    pub sender: Address,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for WasmUpdateAdminMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmwasm::wasm::v1::MsgUpdateAdmin {
+            sender: self.sender.to_string(),
+            new_admin: self.new_admin.to_string(),
+            contract: self.contract.to_string(),
+        };
+        Ok(to_any(&proto_msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        message_to_json(DEFAULT_JSON_UPDATE_ADMIN_TYPE, self)
+    }
+}
+
+/// wasm/MsgClearAdmin
+#[derive(Serialize)]
+pub struct WasmClearAdminMessage<Address: CosmosAddress> {
+    pub contract: Address,
+    pub sender: Address,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for WasmClearAdminMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmwasm::wasm::v1::MsgClearAdmin {
+            sender: self.sender.to_string(),
+            contract: self.contract.to_string(),
+        };
+        Ok(to_any(&proto_msg))
+    }
+
+    fn to_json(&self) -> SigningResult<JsonMessage> {
+        message_to_json(DEFAULT_JSON_CLEAR_ADMIN_TYPE, self)
+    }
+}
@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_entry::test_utils::test_context::TestCoinContext;
+use tw_cosmos_sdk::context::StandardCosmosContext;
+use tw_cosmos_sdk::modules::decoder::json_decoder::JsonDecoder;
+use tw_cosmos_sdk::modules::decoder::protobuf_decoder::ProtobufDecoder;
+use tw_cosmos_sdk::modules::serializer::protobuf_serializer::ProtobufSerializer;
+use tw_cosmos_sdk::modules::tx_builder::TxBuilder;
+use tw_cosmos_sdk::public_key::secp256k1::verify_secp256k1_signature;
+use tw_cosmos_sdk::test_utils::proto_utils::{make_amount, make_fee, make_message};
+use tw_cosmos_sdk::transaction::message::cosmos_generic_message::ProtobufRawMessage;
+use tw_cosmos_sdk::transaction::message::CosmosMessage;
+use tw_encoding::base64;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::tw::PublicKeyType;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+type ProtobufDecoderStd = ProtobufDecoder<StandardCosmosContext>;
+type JsonDecoderStd = JsonDecoder<StandardCosmosContext>;
+
+fn account_1037_public_key() -> Cow<'static, [u8]> {
+    "0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5"
+        .decode_hex()
+        .unwrap()
+        .into()
+}
+
+fn cosmos_coin() -> TestCoinContext {
+    TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos")
+}
+
+#[test]
+fn test_decode_tx_raw_coin_send() {
+    let coin = cosmos_coin();
+
+    // Taken from `sign.rs::test_sign_coin_send`.
+    let tx_bytes = base64::decode("CowBCokBChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEmkKLWNvc21vczFoc2s2anJ5eXFqZmhwNWRoYzU1dGM5anRja3lneDBlcGg2ZGQwMhItY29zbW9zMXp0NTBhenVwYW5xbGZhbTVhZmh2M2hleHd5dXRudWtlaDRjNTczGgkKBG11b24SATESZQpQCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAlcobsPzfTNVe7uqAAsndErJAjqplnyudaGB0f+R+p3FEgQKAggBGAgSEQoLCgRtdW9uEgMyMDAQwJoMGkD54fQAFlekIAnE62hZYl0uQelh/HLv0oQpCciY5Dn8H1SZFuTsrGdu41PH1Uxa4woptCELi/8Ov9yzdeEFAC9H", false).unwrap();
+    let decoded = ProtobufDecoderStd::decode_tx_raw(&coin, &tx_bytes, "gaia-13003", 1037).unwrap();
+
+    let send_msg = Proto::mod_Message::Send {
+        from_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        to_address: "cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573".into(),
+        amounts: vec![make_amount("muon", "1")],
+        ..Proto::mod_Message::Send::default()
+    };
+    let expected = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Protobuf,
+        account_number: 1037,
+        chain_id: "gaia-13003".into(),
+        sequence: 8,
+        fee: Some(make_fee(200000, make_amount("muon", "200"))),
+        messages: vec![make_message(MessageEnum::send_coins_message(send_msg))],
+        public_key: "0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::SigningInput::default()
+    };
+    assert_eq!(decoded.input, expected);
+
+    assert_eq!(decoded.signatures.len(), 1);
+    let signature = &decoded.signatures[0];
+    assert_eq!(signature.public_key, expected.public_key.to_vec());
+    assert_eq!(signature.signature.to_hex(), "f9e1f4001657a42009c4eb6859625d2e41e961fc72efd2842909c898e439fc1f549916e4ecac676ee353c7d54c5ae30a29b4210b8bff0ebfdcb375e105002f47");
+    assert!(signature.verified);
+
+    // The same transaction but with another account number has a different preimage.
+    let decoded = ProtobufDecoderStd::decode_tx_raw(&coin, &tx_bytes, "gaia-13003", 1038).unwrap();
+    assert!(!decoded.signatures[0].verified);
+}
+
+#[test]
+fn test_decode_sign_doc_unknown_message() {
+    let coin = cosmos_coin();
+
+    let send_msg = Proto::mod_Message::Send {
+        from_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        to_address: "cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573".into(),
+        amounts: vec![make_amount("muon", "1")],
+        ..Proto::mod_Message::Send::default()
+    };
+    let input = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Protobuf,
+        account_number: 1037,
+        chain_id: "gaia-13003".into(),
+        sequence: 8,
+        fee: Some(make_fee(200000, make_amount("muon", "200"))),
+        memo: "test memo".into(),
+        public_key: account_1037_public_key(),
+        messages: vec![make_message(MessageEnum::send_coins_message(send_msg))],
+        ..Proto::SigningInput::default()
+    };
+
+    let mut unsigned =
+        TxBuilder::<StandardCosmosContext>::unsigned_tx_from_proto(&coin, &input).unwrap();
+    // Prepend a message that is not supported by wallet-core explicitly.
+    let unknown_msg = ProtobufRawMessage {
+        type_url: "/cosmos.test.v1.MsgTest".to_string(),
+        value: "0a0568656c6c6f".decode_hex().unwrap(),
+    };
+    unsigned
+        .tx_body
+        .messages
+        .insert(0, unknown_msg.into_boxed());
+    let sign_doc = ProtobufSerializer::build_sign_doc(&unsigned).unwrap();
+    let sign_doc_bytes = tw_proto::serialize(&sign_doc).unwrap();
+
+    let decoded = ProtobufDecoderStd::decode_sign_doc(&coin, &sign_doc_bytes).unwrap();
+    // Only known messages are included into the signing input.
+    assert_eq!(decoded.input, input);
+    assert_eq!(decoded.unknown_messages.len(), 1);
+    let unknown = &decoded.unknown_messages[0];
+    assert_eq!(unknown.index, 0);
+    assert_eq!(unknown.type_url, "/cosmos.test.v1.MsgTest");
+    assert_eq!(unknown.value.to_hex(), "0a0568656c6c6f");
+}
+
+#[test]
+fn test_decode_sign_doc_wasm_messages() {
+    let coin = cosmos_coin();
+
+    let instantiate_msg = Proto::mod_Message::WasmInstantiateContract2 {
+        sender_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        admin_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        code_id: 1,
+        label: "counter".into(),
+        msg: r#"{"count":0}"#.into(),
+        coins: vec![make_amount("muon", "10")],
+        salt: "01020304".decode_hex().unwrap().into(),
+        fix_msg: false,
+    };
+    let migrate_msg = Proto::mod_Message::WasmMigrateContract {
+        sender_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        contract_address: "cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573".into(),
+        code_id: 2,
+        msg: r#"{}"#.into(),
+    };
+    let update_admin_msg = Proto::mod_Message::WasmUpdateAdmin {
+        sender_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        new_admin_address: "cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573".into(),
+        contract_address: "cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573".into(),
+    };
+    let input = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Protobuf,
+        account_number: 1037,
+        chain_id: "gaia-13003".into(),
+        sequence: 10,
+        fee: Some(make_fee(500000, make_amount("muon", "500"))),
+        public_key: account_1037_public_key(),
+        messages: vec![
+            make_message(MessageEnum::wasm_instantiate_contract2_message(
+                instantiate_msg,
+            )),
+            make_message(MessageEnum::wasm_migrate_contract_message(migrate_msg)),
+            make_message(MessageEnum::wasm_update_admin_message(update_admin_msg)),
+        ],
+        ..Proto::SigningInput::default()
+    };
+
+    let unsigned =
+        TxBuilder::<StandardCosmosContext>::unsigned_tx_from_proto(&coin, &input).unwrap();
+    let sign_doc = ProtobufSerializer::build_sign_doc(&unsigned).unwrap();
+    let sign_doc_bytes = tw_proto::serialize(&sign_doc).unwrap();
+
+    let decoded = ProtobufDecoderStd::decode_sign_doc(&coin, &sign_doc_bytes).unwrap();
+    assert_eq!(decoded.input, input);
+    assert!(decoded.unknown_messages.is_empty());
+}
+
+#[test]
+fn test_decode_sign_doc_round_trip() {
+    let coin = cosmos_coin();
+
+    let delegate_msg = Proto::mod_Message::Delegate {
+        delegator_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        validator_address: "cosmosvaloper1zkupr83hrzkn3up5elktzcq3tuft8nxsmwdqgp".into(),
+        amount: Some(make_amount("muon", "10")),
+        ..Proto::mod_Message::Delegate::default()
+    };
+    let vote_msg = Proto::mod_Message::MsgVote {
+        proposal_id: 245,
+        voter: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        option: Proto::mod_Message::VoteOption::YES,
+    };
+    let input = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Protobuf,
+        account_number: 1037,
+        chain_id: "gaia-13003".into(),
+        sequence: 9,
+        fee: Some(make_fee(300000, make_amount("muon", "300"))),
+        public_key: "0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        messages: vec![
+            make_message(MessageEnum::stake_message(delegate_msg)),
+            make_message(MessageEnum::msg_vote(vote_msg)),
+        ],
+        ..Proto::SigningInput::default()
+    };
+
+    let unsigned =
+        TxBuilder::<StandardCosmosContext>::unsigned_tx_from_proto(&coin, &input).unwrap();
+    let sign_doc = ProtobufSerializer::build_sign_doc(&unsigned).unwrap();
+    let sign_doc_bytes = tw_proto::serialize(&sign_doc).unwrap();
+
+    let decoded = ProtobufDecoderStd::decode_sign_doc(&coin, &sign_doc_bytes).unwrap();
+    assert_eq!(decoded.input, input);
+    assert!(decoded.signatures.is_empty());
+}
+
+#[test]
+fn test_decode_tx_raw_invalid() {
+    let coin = cosmos_coin();
+    let error =
+        ProtobufDecoderStd::decode_tx_raw(&coin, b"invalid tx", "gaia-13003", 1037).unwrap_err();
+    assert_eq!(error.0, SigningErrorType::Error_input_parse);
+}
+
+#[test]
+fn test_decode_amino_sign_doc_coin_send() {
+    let coin = cosmos_coin();
+
+    // Taken from `sign.rs::test_sign_coin_send`.
+    let sign_doc = r#"{"account_number":"1037","chain_id":"gaia-13003","fee":{"amount":[{"amount":"200","denom":"muon"}],"gas":"200000"},"memo":"","msgs":[{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"1","denom":"muon"}],"from_address":"cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02","to_address":"cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573"}}],"sequence":"8"}"#;
+    let decoded = JsonDecoderStd::decode_amino_sign_doc(&coin, sign_doc).unwrap();
+
+    let send_msg = Proto::mod_Message::Send {
+        from_address: "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02".into(),
+        to_address: "cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573".into(),
+        amounts: vec![make_amount("muon", "1")],
+        type_prefix: "cosmos-sdk/MsgSend".into(),
+    };
+    let expected = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::JSON,
+        account_number: 1037,
+        chain_id: "gaia-13003".into(),
+        sequence: 8,
+        fee: Some(make_fee(200000, make_amount("muon", "200"))),
+        messages: vec![make_message(MessageEnum::send_coins_message(send_msg))],
+        ..Proto::SigningInput::default()
+    };
+    assert_eq!(decoded.input, expected);
+
+    // Check if the signature from `sign.rs::test_sign_coin_send` is valid for the decoded preimage.
+    let is_valid = verify_secp256k1_signature(
+        &"0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5"
+            .decode_hex()
+            .unwrap(),
+        &"fc3ef899d206c88077fec42f21ba0b4df4bd3fd115fdf606ae01d9136fef363f57e9e33a7b9ec6ddab658cd07e3c0067470de94e4e75b979a1085a29f0efd926".decode_hex().unwrap(),
+        &decoded.tx_hash,
+    );
+    assert!(is_valid);
+}
+
+#[test]
+fn test_decode_amino_sign_doc_unknown_message() {
+    let coin = cosmos_coin();
+
+    // The keys are not sorted on purpose.
+    let sign_doc = r#"{"chain_id":"gaia-13003","account_number":"1037","fee":{"amount":[],"gas":"200000"},"memo":"","msgs":[{"type":"test","value":{"test":"hello"}}],"sequence":"8"}"#;
+    let decoded = JsonDecoderStd::decode_amino_sign_doc(&coin, sign_doc).unwrap();
+
+    let raw_json_msg = Proto::mod_Message::RawJSON {
+        type_pb: "test".into(),
+        value: r#"{"test":"hello"}"#.into(),
+    };
+    assert_eq!(
+        decoded.input.messages,
+        vec![make_message(MessageEnum::raw_json_message(raw_json_msg))]
+    );
+}
+
+#[test]
+fn test_decode_amino_sign_doc_invalid_number() {
+    let coin = cosmos_coin();
+
+    let sign_doc = r#"{"account_number":"-1","chain_id":"gaia-13003","fee":{"amount":[],"gas":"200000"},"memo":"","msgs":[],"sequence":"8"}"#;
+    let error = JsonDecoderStd::decode_amino_sign_doc(&coin, sign_doc).unwrap_err();
+    assert_eq!(error.0, SigningErrorType::Error_input_parse);
+}
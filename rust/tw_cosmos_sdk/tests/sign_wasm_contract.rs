@@ -4,13 +4,18 @@
 
 use serde_json::json;
 use std::borrow::Cow;
+use tw_coin_entry::error::SigningErrorType;
 use tw_coin_entry::test_utils::test_context::TestCoinContext;
 use tw_cosmos_sdk::context::StandardCosmosContext;
 use tw_cosmos_sdk::modules::tx_builder::TxBuilder;
 use tw_cosmos_sdk::test_utils::proto_utils::{make_amount, make_fee, make_message};
-use tw_cosmos_sdk::test_utils::sign_utils::{test_sign_json, test_sign_protobuf, TestInput};
+use tw_cosmos_sdk::test_utils::sign_utils::{
+    test_sign_json, test_sign_json_error, test_sign_protobuf, TestErrorInput, TestInput,
+};
+use tw_cosmos_sdk::transaction::message::wasm_message::instantiate2_address;
 use tw_encoding::base64;
-use tw_encoding::hex::DecodeHex;
+use tw_encoding::bech32;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_keypair::tw::PublicKeyType;
 use tw_number::U256;
 use tw_proto::Cosmos::Proto;
@@ -249,3 +254,305 @@ fn test_terra_transfer_payload() {
     });
     assert_eq!(actual.value, expected);
 }
+
+#[test]
+fn test_wasm_instantiate_contract_payload() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("juno");
+
+    let instantiate = Proto::mod_Message::WasmInstantiateContract {
+        sender_address: "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6".into(),
+        admin_address: "juno1qyqszqgpqyqszqgpqyqszqgpqyqszqgpypz92q".into(),
+        code_id: 1,
+        label: "my-counter".into(),
+        msg: r#"{"count":0}"#.into(),
+        coins: vec![make_amount("ujuno", "1000")],
+    };
+
+    let payload = TxBuilder::<StandardCosmosContext>::wasm_instantiate_contract_msg_from_proto(
+        &coin,
+        &instantiate,
+    )
+    .unwrap();
+
+    let actual = payload.to_proto().unwrap();
+    assert_eq!(actual.type_url, "/cosmwasm.wasm.v1.MsgInstantiateContract");
+    assert_eq!(actual.value.to_hex(), "0a2b6a756e6f31636d6134637a74326a6e79647672767a336c7263396a76636d68706a78746473393573336336122b6a756e6f31717971737a716770717971737a716770717971737a716770717971737a71677079707a3932711801220a6d792d636f756e7465722a0b7b22636f756e74223a307d320d0a05756a756e6f120431303030");
+
+    let actual = payload.to_json().unwrap();
+    assert_eq!(actual.msg_type, "wasm/MsgInstantiateContract");
+    let expected = json!({
+        "admin": "juno1qyqszqgpqyqszqgpqyqszqgpqyqszqgpypz92q",
+        "code_id": "1",
+        "funds": [{"amount": "1000", "denom": "ujuno"}],
+        "label": "my-counter",
+        "msg": {"count": 0},
+        "sender": "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6"
+    });
+    assert_eq!(actual.value, expected);
+}
+
+#[test]
+fn test_wasm_instantiate_contract2_payload() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("juno");
+
+    let instantiate = Proto::mod_Message::WasmInstantiateContract2 {
+        sender_address: "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6".into(),
+        code_id: 42,
+        label: "my-counter".into(),
+        msg: r#"{"count":0}"#.into(),
+        salt: b"salt".to_vec().into(),
+        fix_msg: true,
+        ..Proto::mod_Message::WasmInstantiateContract2::default()
+    };
+
+    let payload = TxBuilder::<StandardCosmosContext>::wasm_instantiate_contract2_msg_from_proto(
+        &coin,
+        &instantiate,
+    )
+    .unwrap();
+
+    let actual = payload.to_proto().unwrap();
+    assert_eq!(actual.type_url, "/cosmwasm.wasm.v1.MsgInstantiateContract2");
+
+    let actual = payload.to_json().unwrap();
+    assert_eq!(actual.msg_type, "wasm/MsgInstantiateContract2");
+    // `admin` must be omitted.
+    let expected = json!({
+        "code_id": "42",
+        "fix_msg": true,
+        "funds": [],
+        "label": "my-counter",
+        "msg": {"count": 0},
+        "salt": "c2FsdA==",
+        "sender": "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6"
+    });
+    assert_eq!(actual.value, expected);
+}
+
+#[test]
+fn test_wasm_migrate_and_admin_payloads() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("juno");
+
+    let sender = "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6";
+    let contract = "juno1qurswpc8qurswpc8qurswpc8qurswpc8qurswpc8qurswpc8qursaq28r5";
+    let new_admin = "juno1qgpqyqszqgpqyqszqgpqyqszqgpqyqsz49yqpk";
+
+    let migrate = Proto::mod_Message::WasmMigrateContract {
+        sender_address: sender.into(),
+        contract_address: contract.into(),
+        code_id: 2,
+        msg: "{}".into(),
+    };
+    let payload =
+        TxBuilder::<StandardCosmosContext>::wasm_migrate_contract_msg_from_proto(&coin, &migrate)
+            .unwrap();
+    assert_eq!(
+        payload.to_proto().unwrap().type_url,
+        "/cosmwasm.wasm.v1.MsgMigrateContract"
+    );
+    let actual = payload.to_json().unwrap();
+    assert_eq!(actual.msg_type, "wasm/MsgMigrateContract");
+    let expected = json!({
+        "code_id": "2",
+        "contract": contract,
+        "msg": {},
+        "sender": sender
+    });
+    assert_eq!(actual.value, expected);
+
+    let update = Proto::mod_Message::WasmUpdateAdmin {
+        sender_address: sender.into(),
+        new_admin_address: new_admin.into(),
+        contract_address: contract.into(),
+    };
+    let payload =
+        TxBuilder::<StandardCosmosContext>::wasm_update_admin_msg_from_proto(&coin, &update)
+            .unwrap();
+    assert_eq!(
+        payload.to_proto().unwrap().type_url,
+        "/cosmwasm.wasm.v1.MsgUpdateAdmin"
+    );
+    let actual = payload.to_json().unwrap();
+    assert_eq!(actual.msg_type, "wasm/MsgUpdateAdmin");
+    let expected = json!({
+        "contract": contract,
+        "new_admin": new_admin,
+        "sender": sender
+    });
+    assert_eq!(actual.value, expected);
+
+    let clear = Proto::mod_Message::WasmClearAdmin {
+        sender_address: sender.into(),
+        contract_address: contract.into(),
+    };
+    let payload =
+        TxBuilder::<StandardCosmosContext>::wasm_clear_admin_msg_from_proto(&coin, &clear).unwrap();
+    assert_eq!(
+        payload.to_proto().unwrap().type_url,
+        "/cosmwasm.wasm.v1.MsgClearAdmin"
+    );
+    let actual = payload.to_json().unwrap();
+    assert_eq!(actual.msg_type, "wasm/MsgClearAdmin");
+    let expected = json!({
+        "contract": contract,
+        "sender": sender
+    });
+    assert_eq!(actual.value, expected);
+}
+
+/// A minimal WASM module `(module)` compressed with gzip.
+/// The SHA-256 checksum of the raw module is
+/// `93a44bbb96c751218e4c00d479e4c14358122a389acca16205b1e4d0dc5f9476`.
+const GZIP_WASM_BYTE_CODE: &str = "1f8b080000000000020363482cce656460600000ce334b1c08000000";
+
+#[test]
+fn test_wasm_store_code_payload() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("juno");
+
+    let store = Proto::mod_Message::WasmStoreCode {
+        sender_address: "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6".into(),
+        wasm_byte_code: GZIP_WASM_BYTE_CODE.decode_hex().unwrap().into(),
+        instantiate_permission: Some(Proto::mod_Message::WasmAccessConfig {
+            permission: Proto::mod_Message::WasmAccessType::ACCESS_TYPE_ANY_OF_ADDRESSES,
+            addresses: vec!["juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6".into()],
+        }),
+    };
+
+    let payload =
+        TxBuilder::<StandardCosmosContext>::wasm_store_code_msg_from_proto(&coin, &store).unwrap();
+    assert_eq!(
+        payload.to_proto().unwrap().type_url,
+        "/cosmwasm.wasm.v1.MsgStoreCode"
+    );
+
+    let actual = payload.to_json().unwrap();
+    assert_eq!(actual.msg_type, "wasm/MsgStoreCode");
+    let expected = json!({
+        "instantiate_permission": {
+            "addresses": ["juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6"],
+            "permission": "AnyOfAddresses"
+        },
+        "sender": "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6",
+        "wasm_byte_code": "H4sIAAAAAAACA2NILM5lZGBgAADOM0scCAAAAA=="
+    });
+    assert_eq!(actual.value, expected);
+}
+
+#[test]
+fn test_wasm_store_code() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("juno");
+
+    let store = Proto::mod_Message::WasmStoreCode {
+        sender_address: "juno19h42zjnls2tpmg6yylcg6nr56cjxcx35jhvjf9".into(),
+        wasm_byte_code: GZIP_WASM_BYTE_CODE.decode_hex().unwrap().into(),
+        instantiate_permission: Some(Proto::mod_Message::WasmAccessConfig {
+            permission: Proto::mod_Message::WasmAccessType::ACCESS_TYPE_ANY_OF_ADDRESSES,
+            addresses: vec!["juno19h42zjnls2tpmg6yylcg6nr56cjxcx35jhvjf9".into()],
+        }),
+    };
+
+    let input = Proto::SigningInput {
+        account_number: 336,
+        chain_id: "juno-1".into(),
+        sequence: 0,
+        fee: Some(make_fee(2000000, make_amount("ujuno", "5000"))),
+        private_key: account_336_private_key(),
+        messages: vec![make_message(MessageEnum::wasm_store_code_message(store))],
+        ..Proto::SigningInput::default()
+    };
+
+    // This transaction hasn't been broadcasted.
+    test_sign_protobuf::<StandardCosmosContext>(TestInput {
+        coin: &coin,
+        input,
+        tx: r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CqEBCp4BCh4vY29zbXdhc20ud2FzbS52MS5Nc2dTdG9yZUNvZGUSfAoranVubzE5aDQyempubHMydHBtZzZ5eWxjZzZucjU2Y2p4Y3gzNWpodmpmORIcH4sIAAAAAAACA2NILM5lZGBgAADOM0scCAAAACovCAQaK2p1bm8xOWg0MnpqbmxzMnRwbWc2eXlsY2c2bnI1NmNqeGN4MzVqaHZqZjkSZQpOCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAqj8IaI4aVqQd7w6/EfqslJw5HCtwT8Ut+RfDqxz6uVsEgQKAggBEhMKDQoFdWp1bm8SBDUwMDAQgIl6GkDIANxg5vsLcSi8GoXC/a+JknsFVpJ4NFkj8++qXdmxTQZ2gwskJl/uxeQAMFTa3Nu1H517Eo/WDDCNvW45FMPf"}"#,
+        signature: "c800dc60e6fb0b7128bc1a85c2fdaf89927b05569278345923f3efaa5dd9b14d0676830b24265feec5e4003054dadcdbb51f9d7b128fd60c308dbd6e3914c3df",
+        signature_json: r#"[{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"Aqj8IaI4aVqQd7w6/EfqslJw5HCtwT8Ut+RfDqxz6uVs"},"signature":"yADcYOb7C3EovBqFwv2viZJ7BVaSeDRZI/Pvql3ZsU0GdoMLJCZf7sXkADBU2tzbtR+dexKP1gwwjb1uORTD3w=="}]"#,
+    });
+}
+
+#[test]
+fn test_wasm_store_code_unspecified_permission_with_addresses() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("juno");
+
+    let store = Proto::mod_Message::WasmStoreCode {
+        sender_address: "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6".into(),
+        wasm_byte_code: GZIP_WASM_BYTE_CODE.decode_hex().unwrap().into(),
+        instantiate_permission: Some(Proto::mod_Message::WasmAccessConfig {
+            permission: Proto::mod_Message::WasmAccessType::ACCESS_TYPE_UNSPECIFIED,
+            addresses: vec!["juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6".into()],
+        }),
+    };
+
+    let err = TxBuilder::<StandardCosmosContext>::wasm_store_code_msg_from_proto(&coin, &store)
+        .err()
+        .expect("Expected an error");
+    assert_eq!(err.0, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_wasm_store_code_invalid_byte_code() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("juno");
+
+    let store = Proto::mod_Message::WasmStoreCode {
+        sender_address: "juno1cma4czt2jnydvrvz3lrc9jvcmhpjxtds95s3c6".into(),
+        wasm_byte_code: b"not a wasm".to_vec().into(),
+        instantiate_permission: None,
+    };
+
+    let input = Proto::SigningInput {
+        account_number: 336,
+        chain_id: "juno-1".into(),
+        sequence: 0,
+        fee: Some(make_fee(2000000, make_amount("ujuno", "5000"))),
+        private_key: account_336_private_key(),
+        messages: vec![make_message(MessageEnum::wasm_store_code_message(store))],
+        ..Proto::SigningInput::default()
+    };
+
+    test_sign_json_error::<StandardCosmosContext>(TestErrorInput {
+        coin: &coin,
+        input,
+        error: SigningErrorType::Error_invalid_params,
+    });
+}
+
+/// https://github.com/CosmWasm/cosmwasm/blob/v1.5.0/packages/std/src/addresses.rs#L591-L601
+#[test]
+fn test_instantiate2_address() {
+    let checksum = "13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5"
+        .decode_hex()
+        .unwrap();
+    let creator = "9999999999aaaaaaaaaabbbbbbbbbbcccccccccc"
+        .decode_hex()
+        .unwrap();
+    let salt = "61".decode_hex().unwrap();
+
+    let address = instantiate2_address(&checksum, &creator, &salt, None).unwrap();
+    assert_eq!(
+        address.to_hex(),
+        "5e865d3e45ad3e961f77fd77d46543417ced44d924dc3e079b5415ff6775f847"
+    );
+    assert_eq!(
+        bech32::encode("purple", address.as_slice()).unwrap(),
+        "purple1t6r960j945lfv8mhl4mage2rg97w63xeynwrupum2s2l7em4lprs9ce5hk"
+    );
+
+    // Salt must be 1 to 64 bytes long.
+    instantiate2_address(&checksum, &creator, &[], None).unwrap_err();
+    instantiate2_address(&checksum, &creator, &[0; 65], None).unwrap_err();
+}
@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Borsh (Binary Object Representation Serializer for Hashing) encoding used by NEAR.
+//! https://borsh.io
+//!
+//! Any type implementing `serde::Serialize` is encoded according to the Borsh specification:
+//! * integers are little-endian;
+//! * strings, byte arrays, sequences and maps are prefixed with their `u32` length;
+//! * fixed size arrays, tuples and structs are encoded without a length prefix;
+//! * `Option` is prefixed with a `0` or `1` byte;
+//! * enum variants are prefixed with their `u8` index.
+
+use crate::{EncodingError, EncodingResult};
+use serde::{ser, Serialize};
+use std::fmt;
+use tw_memory::Data;
+
+pub fn encode<T>(value: &T) -> EncodingResult<Data>
+where
+    T: ?Sized + Serialize,
+{
+    let mut serializer = BorshSerializer { out: Data::new() };
+    value
+        .serialize(&mut serializer)
+        .map_err(|_| EncodingError::InvalidInput)?;
+    Ok(serializer.out)
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+type SerResult<T = ()> = Result<T, Error>;
+
+struct BorshSerializer {
+    out: Data,
+}
+
+impl BorshSerializer {
+    fn write_len(&mut self, len: usize) -> SerResult {
+        let len = u32::try_from(len).map_err(|_| Error("Length overflows u32".to_string()))?;
+        self.out.extend_from_slice(&len.to_le_bytes());
+        Ok(())
+    }
+
+    fn write_variant_index(&mut self, variant_index: u32) -> SerResult {
+        let index = u8::try_from(variant_index)
+            .map_err(|_| Error("Enum variant index overflows u8".to_string()))?;
+        self.out.push(index);
+        Ok(())
+    }
+}
+
+macro_rules! serialize_le {
+    ($($method:ident: $int:ty),+) => {
+        $(
+            fn $method(self, v: $int) -> SerResult {
+                self.out.extend_from_slice(&v.to_le_bytes());
+                Ok(())
+            }
+        )+
+    };
+}
+
+impl ser::Serializer for &mut BorshSerializer {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> SerResult {
+        self.out.push(v as u8);
+        Ok(())
+    }
+
+    serialize_le!(
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64
+    );
+
+    fn serialize_char(self, _v: char) -> SerResult {
+        Err(Error("char is not supported by Borsh".to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> SerResult {
+        self.serialize_bytes(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> SerResult {
+        self.write_len(v.len())?;
+        self.out.extend_from_slice(v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> SerResult {
+        self.out.push(0);
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> SerResult {
+        self.out.push(1);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> SerResult {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> SerResult {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> SerResult {
+        self.write_variant_index(variant_index)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> SerResult {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> SerResult {
+        self.write_variant_index(variant_index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> SerResult<Self::SerializeSeq> {
+        let len = len.ok_or_else(|| Error("Sequence length must be known".to_string()))?;
+        self.write_len(len)?;
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> SerResult<Self::SerializeTuple> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> SerResult<Self::SerializeTupleStruct> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerResult<Self::SerializeTupleVariant> {
+        self.write_variant_index(variant_index)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> SerResult<Self::SerializeMap> {
+        let len = len.ok_or_else(|| Error("Map length must be known".to_string()))?;
+        self.write_len(len)?;
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> SerResult<Self::SerializeStruct> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerResult<Self::SerializeStructVariant> {
+        self.write_variant_index(variant_index)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_serialize_compound {
+    ($($trait:ident: $method:ident),+) => {
+        $(
+            impl ser::$trait for &mut BorshSerializer {
+                type Ok = ();
+                type Error = Error;
+
+                fn $method<T: ?Sized + Serialize>(&mut self, value: &T) -> SerResult {
+                    value.serialize(&mut **self)
+                }
+
+                fn end(self) -> SerResult {
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+impl_serialize_compound!(
+    SerializeSeq: serialize_element,
+    SerializeTuple: serialize_element,
+    SerializeTupleStruct: serialize_field,
+    SerializeTupleVariant: serialize_field
+);
+
+impl ser::SerializeMap for &mut BorshSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> SerResult {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> SerResult {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> SerResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut BorshSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> SerResult {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> SerResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut BorshSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> SerResult {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> SerResult {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex::ToHex;
+
+    #[derive(Serialize)]
+    struct Transfer {
+        receiver_id: String,
+        amount: u128,
+        memo: Option<String>,
+    }
+
+    #[derive(Serialize)]
+    enum Action {
+        CreateAccount,
+        Transfer(Transfer),
+        DeleteKey { nonce: u64 },
+    }
+
+    #[test]
+    fn test_encode_primitives() {
+        assert_eq!(encode(&true).unwrap().to_hex(), "01");
+        assert_eq!(encode(&0x1234_u16).unwrap().to_hex(), "3412");
+        assert_eq!(encode(&-1_i32).unwrap().to_hex(), "ffffffff");
+        assert_eq!(
+            encode(&1_u128).unwrap().to_hex(),
+            "01000000000000000000000000000000"
+        );
+        assert_eq!(encode("near").unwrap().to_hex(), "040000006e656172");
+        assert_eq!(encode(&Some(1_u8)).unwrap().to_hex(), "0101");
+        assert_eq!(encode(&None::<u8>).unwrap().to_hex(), "00");
+        encode(&'a').unwrap_err();
+    }
+
+    #[test]
+    fn test_encode_collections() {
+        assert_eq!(encode(&vec![1_u8, 2]).unwrap().to_hex(), "020000000102");
+        // Fixed size arrays and tuples are not prefixed with their length.
+        assert_eq!(encode(&[1_u8, 2]).unwrap().to_hex(), "0102");
+        assert_eq!(encode(&(1_u8, 2_u16)).unwrap().to_hex(), "010200");
+    }
+
+    #[test]
+    fn test_encode_enum() {
+        assert_eq!(encode(&Action::CreateAccount).unwrap().to_hex(), "00");
+        let transfer = Action::Transfer(Transfer {
+            receiver_id: "a.near".to_string(),
+            amount: 10,
+            memo: None,
+        });
+        assert_eq!(
+            encode(&transfer).unwrap().to_hex(),
+            "0106000000612e6e6561720a00000000000000000000000000000000"
+        );
+        assert_eq!(
+            encode(&Action::DeleteKey { nonce: 1 }).unwrap().to_hex(),
+            "020100000000000000"
+        );
+    }
+}
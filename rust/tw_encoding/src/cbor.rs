@@ -1,6 +1,10 @@
 use ciborium::{de, ser};
 use serde::{de::DeserializeOwned, Serialize};
 
+/// A dynamically typed CBOR value.
+/// Useful when the encoding depends on the data, e.g. optional map entries or tagged items.
+pub use ciborium::value::Value;
+
 type CborResult<T> = Result<T, String>;
 
 pub fn encode<S: Serialize>(message: &S) -> CborResult<Vec<u8>> {
@@ -3,7 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 pub use hex::FromHexError;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use tw_memory::Data;
 
 pub type FromHexResult<T> = Result<T, FromHexError>;
@@ -73,6 +73,15 @@ where
     value.to_hex().serialize(serializer)
 }
 
+/// Deserializes a hex-encoded string into bytes.
+pub fn from_hex<'de, D>(deserializer: D) -> Result<Data, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex_str = String::deserialize(deserializer)?;
+    decode(&hex_str).map_err(serde::de::Error::custom)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,9 +7,11 @@ pub mod base58;
 pub mod base64;
 pub mod bcs;
 pub mod bech32;
+pub mod borsh;
 pub mod cbor;
 pub mod ffi;
 pub mod hex;
+pub mod scale;
 
 pub type EncodingResult<T> = Result<T, EncodingError>;
 
@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! SCALE (Simple Concatenated Aggregate Little-Endian) codec used by Substrate-based chains.
+//! https://docs.substrate.io/reference/scale-codec/
+
+use tw_memory::Data;
+
+/// Values less than the limit are encoded in a single byte, `0b00` mode.
+const COMPACT_SINGLE_BYTE_LIMIT: u128 = 1 << 6;
+/// Values less than the limit are encoded in two bytes, `0b01` mode.
+const COMPACT_TWO_BYTES_LIMIT: u128 = 1 << 14;
+/// Values less than the limit are encoded in four bytes, `0b10` mode.
+const COMPACT_FOUR_BYTES_LIMIT: u128 = 1 << 30;
+
+pub trait ToScale {
+    fn to_scale_into(&self, out: &mut Data);
+
+    fn to_scale(&self) -> Data {
+        let mut out = Data::new();
+        self.to_scale_into(&mut out);
+        out
+    }
+}
+
+/// Compact (general integer) encoding of an unsigned integer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Compact<T>(pub T);
+
+impl ToScale for Compact<u128> {
+    fn to_scale_into(&self, out: &mut Data) {
+        let value = self.0;
+        if value < COMPACT_SINGLE_BYTE_LIMIT {
+            out.push((value as u8) << 2);
+        } else if value < COMPACT_TWO_BYTES_LIMIT {
+            let encoded = ((value as u16) << 2) | 0b01;
+            out.extend_from_slice(&encoded.to_le_bytes());
+        } else if value < COMPACT_FOUR_BYTES_LIMIT {
+            let encoded = ((value as u32) << 2) | 0b10;
+            out.extend_from_slice(&encoded.to_le_bytes());
+        } else {
+            // Big-integer mode: the upper six bits of the prefix are the number of bytes following minus 4.
+            let bytes_len = (16 - value.leading_zeros() / 8) as usize;
+            out.push((((bytes_len - 4) as u8) << 2) | 0b11);
+            out.extend_from_slice(&value.to_le_bytes()[..bytes_len]);
+        }
+    }
+}
+
+macro_rules! impl_compact_via_u128 {
+    ($($int:ty),+) => {
+        $(
+            impl ToScale for Compact<$int> {
+                fn to_scale_into(&self, out: &mut Data) {
+                    Compact(self.0 as u128).to_scale_into(out)
+                }
+            }
+        )+
+    };
+}
+
+impl_compact_via_u128!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_fixed_width {
+    ($($int:ty),+) => {
+        $(
+            impl ToScale for $int {
+                fn to_scale_into(&self, out: &mut Data) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_width!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl ToScale for bool {
+    fn to_scale_into(&self, out: &mut Data) {
+        out.push(*self as u8);
+    }
+}
+
+impl<T: ToScale> ToScale for Option<T> {
+    fn to_scale_into(&self, out: &mut Data) {
+        match self {
+            Some(value) => {
+                out.push(0x01);
+                value.to_scale_into(out);
+            },
+            None => out.push(0x00),
+        }
+    }
+}
+
+/// Dynamically sized collections are prefixed with their compact length.
+impl<T: ToScale> ToScale for [T] {
+    fn to_scale_into(&self, out: &mut Data) {
+        Compact(self.len()).to_scale_into(out);
+        for item in self {
+            item.to_scale_into(out);
+        }
+    }
+}
+
+impl<T: ToScale> ToScale for Vec<T> {
+    fn to_scale_into(&self, out: &mut Data) {
+        self.as_slice().to_scale_into(out)
+    }
+}
+
+/// Fixed size arrays are encoded without a length prefix.
+impl<T: ToScale, const N: usize> ToScale for [T; N] {
+    fn to_scale_into(&self, out: &mut Data) {
+        for item in self {
+            item.to_scale_into(out);
+        }
+    }
+}
+
+impl<T: ToScale + ?Sized> ToScale for &T {
+    fn to_scale_into(&self, out: &mut Data) {
+        (*self).to_scale_into(out)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: ToScale),+> ToScale for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn to_scale_into(&self, out: &mut Data) {
+                let ($($name,)+) = self;
+                $($name.to_scale_into(out);)+
+            }
+        }
+    };
+}
+
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+
+/// Already SCALE-encoded bytes that are appended as they are, e.g. an encoded call.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RawOwned(pub Data);
+
+impl ToScale for RawOwned {
+    fn to_scale_into(&self, out: &mut Data) {
+        out.extend_from_slice(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex::ToHex;
+
+    #[test]
+    fn test_encode_compact() {
+        let encode = |value: u128| Compact(value).to_scale().to_hex();
+
+        assert_eq!(encode(0), "00");
+        assert_eq!(encode(18), "48");
+        assert_eq!(encode(63), "fc");
+        assert_eq!(encode(64), "0101");
+        assert_eq!(encode(12345), "e5c0");
+        assert_eq!(encode(16383), "fdff");
+        assert_eq!(encode(16384), "02000100");
+        assert_eq!(encode(1073741823), "feffffff");
+        assert_eq!(encode(1073741824), "0300000040");
+        assert_eq!(encode(4294967295), "03ffffffff");
+        assert_eq!(encode(4294967296), "070000000001");
+        assert_eq!(encode(1099511627776), "0b000000000001");
+        assert_eq!(encode(281474976710656), "0f00000000000001");
+        assert_eq!(encode(72057594037927935), "0fffffffffffffff");
+        assert_eq!(encode(72057594037927936), "130000000000000001");
+        assert_eq!(encode(u64::MAX as u128), "13ffffffffffffffff");
+        assert_eq!(encode(u128::MAX), "33ffffffffffffffffffffffffffffffff");
+    }
+
+    #[test]
+    fn test_encode_fixed_width() {
+        assert_eq!(1_u8.to_scale().to_hex(), "01");
+        assert_eq!(42_u16.to_scale().to_hex(), "2a00");
+        assert_eq!(16777215_u32.to_scale().to_hex(), "ffffff00");
+        assert_eq!(u64::MAX.to_scale().to_hex(), "ffffffffffffffff");
+        assert_eq!((-1_i32).to_scale().to_hex(), "ffffffff");
+        assert_eq!(true.to_scale().to_hex(), "01");
+        assert_eq!(false.to_scale().to_hex(), "00");
+    }
+
+    #[test]
+    fn test_encode_option() {
+        assert_eq!(Some(1_u32).to_scale().to_hex(), "0101000000");
+        assert_eq!(None::<u32>.to_scale().to_hex(), "00");
+    }
+
+    #[test]
+    fn test_encode_collections() {
+        assert_eq!(vec![4_u16, 8, 15].to_scale().to_hex(), "0c040008000f00");
+        assert_eq!(Vec::<u8>::new().to_scale().to_hex(), "00");
+        assert_eq!([1_u8, 2, 3].to_scale().to_hex(), "010203");
+        assert_eq!(
+            (1_u8, Compact(64_u32), RawOwned(vec![0xff]))
+                .to_scale()
+                .to_hex(),
+            "010101ff"
+        );
+    }
+}
@@ -39,10 +39,15 @@ pub struct AccountIdentifier {
 impl AccountIdentifier {
     /// Create a default account identifier from the given principal owner.
     pub fn new(owner: &Principal) -> Self {
+        Self::with_subaccount(owner, &H256::new())
+    }
+
+    /// Create an account identifier from the given principal owner and subaccount.
+    pub fn with_subaccount(owner: &Principal, subaccount: &H256) -> Self {
         let mut input = vec![];
         input.extend_from_slice(b"\x0Aaccount-id");
         input.extend_from_slice(owner.as_slice());
-        input.extend_from_slice(&H256::new()[..]);
+        input.extend_from_slice(&subaccount[..]);
 
         let hash = sha224(&input);
         let crc32_bytes = crc32(&hash).to_be_bytes();
@@ -116,6 +121,102 @@ impl AsRef<[u8]> for AccountIdentifier {
     }
 }
 
+/// An account of an ICRC-1 ledger, e.g. ckBTC, ckETH or an SNS token.
+/// https://github.com/dfinity/ICRC-1/blob/main/standards/ICRC-1/README.md#account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icrc1Account {
+    owner: Principal,
+    subaccount: Option<H256>,
+}
+
+impl Icrc1Account {
+    /// Create an account from the given principal owner and subaccount.
+    pub fn new(owner: Principal, subaccount: Option<H256>) -> Self {
+        Self { owner, subaccount }
+    }
+
+    #[inline]
+    pub fn owner(&self) -> Principal {
+        self.owner
+    }
+
+    #[inline]
+    pub fn subaccount(&self) -> Option<H256> {
+        self.subaccount
+    }
+
+    /// The default subaccount is all zeros, and it's equivalent to no subaccount.
+    fn non_default_subaccount(&self) -> Option<H256> {
+        self.subaccount
+            .filter(|subaccount| *subaccount != H256::new())
+    }
+
+    fn checksum(owner: &Principal, subaccount: &H256) -> AddressResult<String> {
+        let mut input = owner.as_slice().to_vec();
+        input.extend_from_slice(subaccount.as_slice());
+        let crc32_bytes = crc32(&input).to_be_bytes();
+
+        let checksum = tw_encoding::base32::encode(&crc32_bytes, None, false)
+            .map_err(|_| AddressError::InvalidInput)?;
+        Ok(checksum.to_ascii_lowercase())
+    }
+}
+
+impl std::str::FromStr for Icrc1Account {
+    type Err = AddressError;
+
+    /// Parses the textual encoding of an account:
+    /// `<owner>` or `<owner>-<checksum>.<subaccount hex without leading zeros>`.
+    /// https://github.com/dfinity/ICRC-1/blob/main/standards/ICRC-1/TextualEncoding.md
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((owner_and_checksum, subaccount_hex)) = s.rsplit_once('.') else {
+            let owner = Principal::from_text(s).map_err(|_| AddressError::InvalidInput)?;
+            return Ok(Icrc1Account::new(owner, None));
+        };
+        let (owner, checksum) = owner_and_checksum
+            .rsplit_once('-')
+            .ok_or(AddressError::InvalidInput)?;
+        let owner = Principal::from_text(owner).map_err(|_| AddressError::InvalidInput)?;
+
+        // The subaccount must be in the canonical form, i.e. without leading zeros.
+        if subaccount_hex.is_empty()
+            || subaccount_hex.len() > H256::len() * 2
+            || subaccount_hex.starts_with('0')
+        {
+            return Err(AddressError::InvalidInput);
+        }
+        let padded_hex = format!("{subaccount_hex:0>64}");
+        let subaccount = hex::decode(&padded_hex).map_err(|_| AddressError::FromHexError)?;
+        let subaccount =
+            H256::try_from(subaccount.as_slice()).map_err(|_| AddressError::FromHexError)?;
+
+        if Icrc1Account::checksum(&owner, &subaccount)? != checksum {
+            return Err(AddressError::InvalidInput);
+        }
+        Ok(Icrc1Account::new(owner, Some(subaccount)))
+    }
+}
+
+impl std::fmt::Display for Icrc1Account {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.non_default_subaccount() {
+            None => write!(f, "{}", self.owner),
+            Some(subaccount) => {
+                let checksum = Icrc1Account::checksum(&self.owner, &subaccount)
+                    .map_err(|_| std::fmt::Error)?;
+                let subaccount_hex = hex::encode(subaccount, false);
+                write!(
+                    f,
+                    "{}-{}.{}",
+                    self.owner,
+                    checksum,
+                    subaccount_hex.trim_start_matches('0')
+                )
+            },
+        }
+    }
+}
+
 fn is_check_sum_valid(hash: H256) -> bool {
     let found_checksum = &hash[0..4];
     let expected_checksum = crc32(&hash[4..]).to_be_bytes();
@@ -124,6 +225,8 @@ fn is_check_sum_valid(hash: H256) -> bool {
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
     use tw_keypair::ecdsa::secp256k1::PublicKey;
 
     use super::*;
@@ -170,6 +273,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn icrc1_account_textual_encoding() {
+        const OWNER: &str = "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae";
+        let owner = Principal::from_text(OWNER).unwrap();
+
+        let account = Icrc1Account::new(owner, None);
+        assert_eq!(account.to_string(), OWNER);
+        assert_eq!(Icrc1Account::from_str(OWNER).unwrap(), account);
+
+        // The default subaccount is omitted.
+        let account = Icrc1Account::new(owner, Some(H256::new()));
+        assert_eq!(account.to_string(), OWNER);
+
+        let mut subaccount = H256::new();
+        subaccount[31] = 1;
+        let text = "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1";
+        let account = Icrc1Account::new(owner, Some(subaccount));
+        assert_eq!(account.to_string(), text);
+        assert_eq!(Icrc1Account::from_str(text).unwrap(), account);
+
+        let subaccount: Vec<u8> = (1..=32).collect();
+        let subaccount = H256::try_from(subaccount.as_slice()).unwrap();
+        let text = "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-dfxgiyy.102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+        let account = Icrc1Account::new(owner, Some(subaccount));
+        assert_eq!(account.to_string(), text);
+        assert_eq!(Icrc1Account::from_str(text).unwrap(), account);
+    }
+
+    #[test]
+    fn icrc1_account_invalid() {
+        let invalid = [
+            // Invalid checksum.
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627j.1",
+            // Missing checksum.
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae.1",
+            // Leading zeros.
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.01",
+            // Empty subaccount.
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.",
+            // Invalid principal.
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6aa",
+        ];
+        for text in invalid {
+            assert!(Icrc1Account::from_str(text).is_err(), "{text}");
+        }
+    }
+
     #[test]
     fn from_public_key() {
         let public_key = PublicKey::try_from(PUBLIC_KEY_HEX).expect("Failed to populate key");
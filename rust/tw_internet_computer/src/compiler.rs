@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::marker::PhantomData;
+
+use tw_coin_entry::{
+    coin_entry::{PublicKeyBytes, SignatureBytes},
+    error::{SigningError, SigningResult},
+    signing_output_error,
+};
+use tw_keypair::{ecdsa::secp256k1, traits::VerifyingKeyTrait};
+use tw_proto::{Common::Proto::SigningError as CommonError, InternetComputer::Proto};
+
+use crate::{
+    context::InternetComputerContext,
+    protocol::principal::Principal,
+    transactions::{unsigned_transaction, UnsignedTransaction},
+};
+
+/// The update call and the read_state request are signed separately.
+const SIGNATURES_COUNT: usize = 2;
+
+pub struct Compiler<Context: InternetComputerContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: InternetComputerContext> Compiler<Context> {
+    #[inline]
+    pub fn preimage_hashes(input: Proto::SigningInput<'_>) -> Proto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::PreSigningOutput, e))
+    }
+
+    fn preimage_hashes_impl(
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::PreSigningOutput<'static>> {
+        let public_key = if input.public_key.is_empty() {
+            secp256k1::PrivateKey::try_from(input.private_key.as_ref())?.public()
+        } else {
+            secp256k1::PublicKey::try_from(input.public_key.as_ref())?
+        };
+
+        let unsigned_tx = Self::unsigned_transaction(&input, &public_key)?;
+        let (update_request_id, read_state_request_id) = unsigned_tx.request_ids();
+        let (update_sig_data, read_state_sig_data) = unsigned_tx.sig_data();
+
+        Ok(Proto::PreSigningOutput {
+            data_hashes: vec![
+                update_sig_data.to_vec().into(),
+                read_state_sig_data.to_vec().into(),
+            ],
+            request_ids: vec![
+                update_request_id.0.to_vec().into(),
+                read_state_request_id.0.to_vec().into(),
+            ],
+            ..Proto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn compile_impl(
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        if signatures.len() != SIGNATURES_COUNT {
+            return Err(SigningError(CommonError::Error_signatures_count));
+        }
+        // Both requests are signed by the sender, so either a single public key or the same key per signature is expected.
+        let Some(public_key) = public_keys.first() else {
+            return Err(SigningError(CommonError::Error_signatures_count));
+        };
+        if public_keys.iter().any(|key| key != public_key) {
+            return Err(SigningError(CommonError::Error_invalid_params));
+        }
+        let public_key = secp256k1::PublicKey::try_from(public_key.as_slice())?;
+
+        let unsigned_tx = Self::unsigned_transaction(&input, &public_key)?;
+        let (update_sig_data, read_state_sig_data) = unsigned_tx.sig_data();
+
+        // Signatures are expected as `r || s`, the recovery ID is accepted but dropped.
+        let update_signature = secp256k1::VerifySignature::try_from(signatures[0].as_slice())?;
+        let read_state_signature = secp256k1::VerifySignature::try_from(signatures[1].as_slice())?;
+        let update_signature_bytes = update_signature.to_bytes().to_vec();
+        let read_state_signature_bytes = read_state_signature.to_bytes().to_vec();
+
+        if !public_key.verify(update_signature, update_sig_data)
+            || !public_key.verify(read_state_signature, read_state_sig_data)
+        {
+            return Err(SigningError(CommonError::Error_signing));
+        }
+
+        let signed_transaction = unsigned_tx
+            .into_signed(
+                public_key.der_encoded(),
+                update_signature_bytes,
+                read_state_signature_bytes,
+            )
+            .map_err(SigningError::from)?;
+
+        let cbor_encoded_signed_transaction = tw_encoding::cbor::encode(&signed_transaction)
+            .map_err(|_| SigningError(CommonError::Error_internal))?;
+
+        Ok(Proto::SigningOutput {
+            signed_transaction: cbor_encoded_signed_transaction.into(),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    fn unsigned_transaction(
+        input: &Proto::SigningInput<'_>,
+        public_key: &secp256k1::PublicKey,
+    ) -> SigningResult<UnsignedTransaction> {
+        let Some(ref transaction) = input.transaction else {
+            return Err(SigningError(CommonError::Error_invalid_params));
+        };
+
+        let sender = Principal::self_authenticating(public_key.der_encoded());
+        unsigned_transaction::<Context>(sender, &transaction.transaction_oneof)
+            .map_err(SigningError::from)
+    }
+}
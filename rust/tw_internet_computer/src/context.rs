@@ -11,6 +11,8 @@ pub trait InternetComputerContext {
     type Address: IcpAddress;
 
     fn get_canister_id() -> Principal;
+
+    fn get_governance_canister_id() -> Principal;
 }
 
 #[derive(Default)]
@@ -23,6 +25,11 @@ impl InternetComputerContext for StandardInternetComputerContext {
         // ICP Ledger Canister
         Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap()
     }
+
+    fn get_governance_canister_id() -> Principal {
+        // NNS Governance Canister
+        Principal::from_text("rrkah-fqaaa-aaaaa-aaaaq-cai").unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -39,6 +46,7 @@ mod test {
     const TEST_TEXTUAL_ICP_ADDRESS: &str =
         "943d12e762f43806782f524b8f90297298a6d79e4749b41b585ec427409c826a";
     const TEXTUAL_ICP_LEDGER_CANISTER_ID: &str = "ryjl3-tyaaa-aaaaa-aaaba-cai";
+    const TEXTUAL_NNS_GOVERNANCE_CANISTER_ID: &str = "rrkah-fqaaa-aaaaa-aaaaq-cai";
 
     pub struct ContextTest<Context: InternetComputerContext> {
         _phantom: PhantomData<Context>,
@@ -49,6 +57,10 @@ mod test {
             Context::get_canister_id()
         }
 
+        fn get_governance_canister_id() -> Principal {
+            Context::get_governance_canister_id()
+        }
+
         fn account_identifier_optional(s: &str) -> AddressResult<Option<Context::Address>> {
             Context::Address::from_str_optional(s)
         }
@@ -72,4 +84,14 @@ mod test {
         let ledger_canister_id = ContextTest::<StandardInternetComputerContext>::get_canister_id();
         assert_eq!(ledger_canister_id.to_text(), TEXTUAL_ICP_LEDGER_CANISTER_ID);
     }
+
+    #[test]
+    fn standard_internet_computer_context_governance_canister_type() {
+        let governance_canister_id =
+            ContextTest::<StandardInternetComputerContext>::get_governance_canister_id();
+        assert_eq!(
+            governance_canister_id.to_text(),
+            TEXTUAL_NNS_GOVERNANCE_CANISTER_ID
+        );
+    }
 }
@@ -7,21 +7,20 @@ use std::str::FromStr;
 use tw_coin_entry::{
     coin_context::CoinContext,
     coin_entry::CoinEntry,
-    error::{AddressError, AddressResult, SigningError},
+    error::{AddressError, AddressResult},
     modules::{
         json_signer::NoJsonSigner, message_signer::NoMessageSigner, plan_builder::NoPlanBuilder,
         wallet_connector::NoWalletConnector,
     },
     prefix::NoPrefix,
-    signing_output_error,
 };
 
-use tw_proto::{
-    Common::Proto::SigningError as CommonError, InternetComputer::Proto,
-    TxCompiler::Proto as CompilerProto,
-};
+use tw_proto::InternetComputer::Proto;
 
-use crate::{address::AccountIdentifier, context::StandardInternetComputerContext, signer::Signer};
+use crate::{
+    address::AccountIdentifier, compiler::Compiler, context::StandardInternetComputerContext,
+    signer::Signer,
+};
 
 pub struct InternetComputerEntry;
 
@@ -34,7 +33,7 @@ impl CoinEntry for InternetComputerEntry {
 
     type SigningOutput = Proto::SigningOutput<'static>;
 
-    type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type PreSigningOutput = Proto::PreSigningOutput<'static>;
 
     type JsonSigner = NoJsonSigner;
 
@@ -86,27 +85,23 @@ impl CoinEntry for InternetComputerEntry {
         Signer::<StandardInternetComputerContext>::sign_proto(input)
     }
 
+    #[inline]
     fn preimage_hashes(
         &self,
         _coin: &dyn tw_coin_entry::coin_context::CoinContext,
-        _input: Self::SigningInput<'_>,
+        input: Self::SigningInput<'_>,
     ) -> Self::PreSigningOutput {
-        signing_output_error!(
-            CompilerProto::PreSigningOutput,
-            SigningError(CommonError::Error_not_supported)
-        )
+        Compiler::<StandardInternetComputerContext>::preimage_hashes(input)
     }
 
+    #[inline]
     fn compile(
         &self,
         _coin: &dyn tw_coin_entry::coin_context::CoinContext,
-        _input: Self::SigningInput<'_>,
-        _signatures: Vec<tw_coin_entry::coin_entry::SignatureBytes>,
-        _public_keys: Vec<tw_coin_entry::coin_entry::PublicKeyBytes>,
+        input: Self::SigningInput<'_>,
+        signatures: Vec<tw_coin_entry::coin_entry::SignatureBytes>,
+        public_keys: Vec<tw_coin_entry::coin_entry::PublicKeyBytes>,
     ) -> Self::SigningOutput {
-        signing_output_error!(
-            Proto::SigningOutput,
-            SigningError(CommonError::Error_not_supported)
-        )
+        Compiler::<StandardInternetComputerContext>::compile(input, signatures, public_keys)
     }
 }
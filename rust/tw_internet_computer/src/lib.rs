@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod address;
+pub mod compiler;
 pub mod context;
 pub mod entry;
 pub mod protocol;
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! A minimal Candid serializer that covers the argument types of the ICRC-1 and ICRC-2 ledger methods
+//! and the NNS governance `manage_neuron` method.
+//! See: https://github.com/dfinity/candid/blob/master/spec/Candid.md#binary-format
+
+use tw_memory::Data;
+use tw_number::U256;
+
+use super::principal::Principal;
+
+const MAGIC: &[u8; 4] = b"DIDL";
+
+const TYPE_NAT: i64 = -3;
+const TYPE_NAT8: i64 = -5;
+const TYPE_NAT32: i64 = -7;
+const TYPE_NAT64: i64 = -8;
+const TYPE_INT32: i64 = -11;
+const TYPE_OPT: i64 = -18;
+const TYPE_VEC: i64 = -19;
+const TYPE_RECORD: i64 = -20;
+const TYPE_VARIANT: i64 = -21;
+const TYPE_PRINCIPAL: i64 = -24;
+
+/// Candid types supported by the serializer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlType {
+    Nat,
+    Nat32,
+    Nat64,
+    Int32,
+    Principal,
+    /// `vec nat8`
+    Blob,
+    Opt(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Record(Vec<(&'static str, IdlType)>),
+    Variant(Vec<(&'static str, IdlType)>),
+}
+
+/// Candid values supported by the serializer.
+#[derive(Clone, Debug)]
+pub enum IdlValue {
+    Nat(U256),
+    Nat32(u32),
+    Nat64(u64),
+    Int32(i32),
+    Principal(Principal),
+    Blob(Data),
+    /// The inner type is required to encode `null`.
+    Opt(IdlType, Option<Box<IdlValue>>),
+    /// The element type is required to encode an empty vector.
+    Vec(IdlType, Vec<IdlValue>),
+    Record(Vec<(&'static str, IdlValue)>),
+    /// A variant value is encoded with a type that lists the given case only,
+    /// which is a subtype of any variant type that has the case.
+    Variant(&'static str, Box<IdlValue>),
+}
+
+impl IdlValue {
+    /// Creates an optional value of the given type.
+    pub fn opt(idl_type: IdlType, value: Option<IdlValue>) -> Self {
+        IdlValue::Opt(idl_type, value.map(Box::new))
+    }
+
+    /// Creates a non-null optional value.
+    pub fn some(value: IdlValue) -> Self {
+        IdlValue::opt(value.idl_type(), Some(value))
+    }
+
+    /// Creates a variant value with the given case.
+    pub fn variant(case: &'static str, value: IdlValue) -> Self {
+        IdlValue::Variant(case, Box::new(value))
+    }
+
+    /// Creates an optional `blob` that is `null` if `bytes` is empty.
+    pub fn opt_blob(bytes: Data) -> Self {
+        let value = (!bytes.is_empty()).then_some(IdlValue::Blob(bytes));
+        IdlValue::opt(IdlType::Blob, value)
+    }
+
+    /// Returns the type of the value.
+    pub fn idl_type(&self) -> IdlType {
+        match self {
+            IdlValue::Nat(_) => IdlType::Nat,
+            IdlValue::Nat32(_) => IdlType::Nat32,
+            IdlValue::Nat64(_) => IdlType::Nat64,
+            IdlValue::Int32(_) => IdlType::Int32,
+            IdlValue::Principal(_) => IdlType::Principal,
+            IdlValue::Blob(_) => IdlType::Blob,
+            IdlValue::Opt(inner, _) => IdlType::Opt(Box::new(inner.clone())),
+            IdlValue::Vec(element, _) => IdlType::Vec(Box::new(element.clone())),
+            IdlValue::Record(fields) => IdlType::Record(
+                fields
+                    .iter()
+                    .map(|(name, value)| (*name, value.idl_type()))
+                    .collect(),
+            ),
+            IdlValue::Variant(case, value) => IdlType::Variant(vec![(*case, value.idl_type())]),
+        }
+    }
+
+    fn encode_to(&self, dest: &mut Data) {
+        match self {
+            IdlValue::Nat(value) => encode_nat(value, dest),
+            IdlValue::Nat32(value) => dest.extend_from_slice(&value.to_le_bytes()),
+            IdlValue::Nat64(value) => dest.extend_from_slice(&value.to_le_bytes()),
+            IdlValue::Int32(value) => dest.extend_from_slice(&value.to_le_bytes()),
+            IdlValue::Principal(principal) => {
+                // The principal is transparent, i.e. it is not an opaque reference.
+                dest.push(1);
+                encode_uleb128(principal.as_slice().len() as u64, dest);
+                dest.extend_from_slice(principal.as_slice());
+            },
+            IdlValue::Blob(bytes) => {
+                encode_uleb128(bytes.len() as u64, dest);
+                dest.extend_from_slice(bytes);
+            },
+            IdlValue::Opt(_, None) => dest.push(0),
+            IdlValue::Opt(_, Some(value)) => {
+                dest.push(1);
+                value.encode_to(dest);
+            },
+            IdlValue::Vec(_, values) => {
+                encode_uleb128(values.len() as u64, dest);
+                for value in values {
+                    value.encode_to(dest);
+                }
+            },
+            IdlValue::Record(fields) => {
+                for (_, value) in sorted_by_hash(fields) {
+                    value.encode_to(dest);
+                }
+            },
+            IdlValue::Variant(_, value) => {
+                // The index of the case within the variant type, which lists a single case.
+                encode_uleb128(0, dest);
+                value.encode_to(dest);
+            },
+        }
+    }
+}
+
+/// Encodes the arguments of a canister method call.
+pub fn encode_args(args: &[IdlValue]) -> Data {
+    let mut type_table = TypeTable::default();
+    let arg_types: Vec<_> = args
+        .iter()
+        .map(|arg| type_table.type_ref(&arg.idl_type()))
+        .collect();
+
+    let mut encoded = MAGIC.to_vec();
+    encode_uleb128(type_table.entries.len() as u64, &mut encoded);
+    for entry in type_table.entries {
+        encoded.extend(entry);
+    }
+
+    encode_uleb128(arg_types.len() as u64, &mut encoded);
+    for arg_type in arg_types {
+        encode_sleb128(arg_type, &mut encoded);
+    }
+
+    for arg in args {
+        arg.encode_to(&mut encoded);
+    }
+    encoded
+}
+
+/// Returns the hash of a record field or a variant case name.
+pub fn idl_hash(name: &str) -> u32 {
+    name.bytes().fold(0u32, |hash, byte| {
+        hash.wrapping_mul(223).wrapping_add(byte as u32)
+    })
+}
+
+/// Composite types are defined once in the type table and referenced by their index.
+#[derive(Default)]
+struct TypeTable {
+    entries: Vec<Data>,
+}
+
+impl TypeTable {
+    fn type_ref(&mut self, idl_type: &IdlType) -> i64 {
+        match idl_type {
+            IdlType::Nat => TYPE_NAT,
+            IdlType::Nat32 => TYPE_NAT32,
+            IdlType::Nat64 => TYPE_NAT64,
+            IdlType::Int32 => TYPE_INT32,
+            IdlType::Principal => TYPE_PRINCIPAL,
+            IdlType::Blob => {
+                let mut entry = Data::new();
+                encode_sleb128(TYPE_VEC, &mut entry);
+                encode_sleb128(TYPE_NAT8, &mut entry);
+                self.add(entry)
+            },
+            IdlType::Opt(inner) => {
+                let inner_ref = self.type_ref(inner);
+                let mut entry = Data::new();
+                encode_sleb128(TYPE_OPT, &mut entry);
+                encode_sleb128(inner_ref, &mut entry);
+                self.add(entry)
+            },
+            IdlType::Vec(element) => {
+                let element_ref = self.type_ref(element);
+                let mut entry = Data::new();
+                encode_sleb128(TYPE_VEC, &mut entry);
+                encode_sleb128(element_ref, &mut entry);
+                self.add(entry)
+            },
+            IdlType::Record(fields) => self.fields_type_ref(TYPE_RECORD, fields),
+            IdlType::Variant(cases) => self.fields_type_ref(TYPE_VARIANT, cases),
+        }
+    }
+
+    /// Records and variants share the same type table layout.
+    fn fields_type_ref(&mut self, type_code: i64, fields: &[(&'static str, IdlType)]) -> i64 {
+        let mut entry = Data::new();
+        encode_sleb128(type_code, &mut entry);
+        encode_uleb128(fields.len() as u64, &mut entry);
+        for (name, field_type) in sorted_by_hash(fields) {
+            encode_uleb128(idl_hash(name) as u64, &mut entry);
+            let field_ref = self.type_ref(field_type);
+            encode_sleb128(field_ref, &mut entry);
+        }
+        self.add(entry)
+    }
+
+    fn add(&mut self, entry: Data) -> i64 {
+        let index = match self.entries.iter().position(|existing| *existing == entry) {
+            Some(index) => index,
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            },
+        };
+        index as i64
+    }
+}
+
+fn sorted_by_hash<'a, T>(fields: &'a [(&'static str, T)]) -> Vec<&'a (&'static str, T)> {
+    let mut sorted: Vec<_> = fields.iter().collect();
+    sorted.sort_by_key(|(name, _)| idl_hash(name));
+    sorted
+}
+
+/// Encodes an unbounded natural number as LEB128.
+fn encode_nat(value: &U256, dest: &mut Data) {
+    let bytes = value.to_little_endian();
+    let bit = |index: usize| (bytes[index / 8] >> (index % 8)) & 1;
+
+    let groups = (value.bits() + 6) / 7;
+    for group in 0..groups.max(1) {
+        let mut byte = (0..7)
+            .map(|offset| group * 7 + offset)
+            .filter(|index| *index < value.bits())
+            .fold(0u8, |byte, index| byte | (bit(index) << (index % 7)));
+        if group + 1 < groups {
+            byte |= 0x80;
+        }
+        dest.push(byte);
+    }
+}
+
+fn encode_uleb128(mut value: u64, dest: &mut Data) {
+    while value >= 0x80 {
+        dest.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    dest.push(value as u8);
+}
+
+fn encode_sleb128(mut value: i64, dest: &mut Data) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let is_last = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if is_last {
+            dest.push(byte);
+            return;
+        }
+        dest.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tw_encoding::hex;
+
+    use super::*;
+
+    fn encode_nat_hex(value: &str) -> String {
+        let mut encoded = Data::new();
+        encode_nat(&value.parse().unwrap(), &mut encoded);
+        hex::encode(encoded, false)
+    }
+
+    #[test]
+    fn field_hash() {
+        assert_eq!(idl_hash("owner"), 947296307);
+        assert_eq!(idl_hash("subaccount"), 1349681965);
+        assert_eq!(idl_hash("amount"), 3573748184);
+    }
+
+    #[test]
+    fn nat() {
+        assert_eq!(encode_nat_hex("0"), "00");
+        assert_eq!(encode_nat_hex("127"), "7f");
+        assert_eq!(encode_nat_hex("128"), "8001");
+        assert_eq!(encode_nat_hex("624485"), "e58e26");
+        assert_eq!(
+            encode_nat_hex("1000000000000000000000"),
+            "808080f5ddb8ebe4b56c"
+        );
+    }
+
+    #[test]
+    fn sleb128() {
+        let mut encoded = Data::new();
+        for value in [TYPE_NAT, TYPE_RECORD, TYPE_PRINCIPAL, 0, 64] {
+            encode_sleb128(value, &mut encoded);
+        }
+        assert_eq!(hex::encode(encoded, false), "7d6c6800c000");
+    }
+
+    #[test]
+    fn record_args() {
+        let account = IdlValue::Record(vec![
+            (
+                "owner",
+                IdlValue::Principal(Principal::from_slice(&[0, 0, 0, 0, 0, 0, 4, 210])),
+            ),
+            ("subaccount", IdlValue::opt_blob(Data::default())),
+        ]);
+        assert_eq!(
+            hex::encode(encode_args(&[account]), false),
+            "4449444c036d7b6e006c02b3b0dac30368ad86ca8305010102010800000000000004d200"
+        );
+    }
+
+    #[test]
+    fn variant_args() {
+        let vote = IdlValue::variant("Yes", IdlValue::Record(Vec::default()));
+        assert_eq!(
+            hex::encode(encode_args(&[vote]), false),
+            "4449444c026c006b0187c28f0200010100"
+        );
+    }
+
+    #[test]
+    fn vec_and_fixed_size_args() {
+        let args = [
+            IdlValue::Vec(IdlType::Nat64, vec![IdlValue::Nat64(1), IdlValue::Nat64(2)]),
+            IdlValue::Int32(-1),
+            IdlValue::Nat32(7),
+        ];
+        assert_eq!(
+            hex::encode(encode_args(&args), false),
+            "4449444c016d78030075790201000000000000000200000000000000ffffffff07000000"
+        );
+    }
+}
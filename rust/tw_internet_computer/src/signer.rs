@@ -20,14 +20,16 @@ use crate::{
 impl From<transactions::SignTransactionError> for SigningError {
     fn from(error: transactions::SignTransactionError) -> Self {
         match error {
-            transactions::SignTransactionError::InvalidArguments => {
+            transactions::SignTransactionError::InvalidArguments
+            | transactions::SignTransactionError::InvalidCanisterId => {
                 SigningError(CommonError::Error_invalid_params)
             },
             transactions::SignTransactionError::Identity(identity_error) => match identity_error {
                 identity::SigningError::Failed(_) => SigningError(CommonError::Error_signing),
             },
             transactions::SignTransactionError::InvalidEnvelopePair
-            | transactions::SignTransactionError::EncodingArgsFailed => {
+            | transactions::SignTransactionError::EncodingArgsFailed
+            | transactions::SignTransactionError::Internal => {
                 SigningError(CommonError::Error_internal)
             },
             transactions::SignTransactionError::InvalidToAccountIdentifier => {
@@ -60,9 +62,8 @@ impl<Context: InternetComputerContext> Signer<Context> {
             return Err(SigningError(CommonError::Error_invalid_params));
         };
 
-        let canister_id = Context::get_canister_id();
         let signed_transaction =
-            sign_transaction(private_key, canister_id, &transaction.transaction_oneof)
+            sign_transaction::<Context>(private_key, &transaction.transaction_oneof)
                 .map_err(SigningError::from)?;
 
         let cbor_encoded_signed_transaction = tw_encoding::cbor::encode(&signed_transaction)
@@ -0,0 +1,406 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::{sha2::sha256, H256};
+
+use crate::{
+    address::AccountIdentifier,
+    protocol::{
+        candid::{IdlType, IdlValue},
+        principal::Principal,
+    },
+};
+
+use super::{
+    transfer::{self, TransferArgs},
+    unsigned_call, SignTransactionError, UnsignedTransaction,
+};
+
+/// The endpoint on the NNS governance canister that is used to manage neurons.
+const MANAGE_NEURON_METHOD_NAME: &str = "manage_neuron";
+
+/// Domain separator of the neuron staking subaccount.
+const NEURON_STAKE_DOMAIN: &[u8] = b"neuron-stake";
+
+/// Computes the subaccount of the governance canister that holds the stake of the neuron
+/// identified by the controller and the nonce (memo).
+/// https://github.com/dfinity/ic/blob/master/rs/nervous_system/common/src/ledger.rs
+pub fn neuron_staking_subaccount(
+    controller: &Principal,
+    nonce: u64,
+) -> Result<H256, SignTransactionError> {
+    let mut input = vec![NEURON_STAKE_DOMAIN.len() as u8];
+    input.extend_from_slice(NEURON_STAKE_DOMAIN);
+    input.extend_from_slice(controller.as_slice());
+    input.extend_from_slice(&nonce.to_be_bytes());
+
+    H256::try_from(sha256(&input).as_slice()).map_err(|_| SignTransactionError::Internal)
+}
+
+/// Arguments to be used with [stake_neuron].
+#[derive(Clone, Debug)]
+pub struct StakeNeuronArgs {
+    /// The amount of ICP to stake as e8s.
+    pub amount: u64,
+    /// The nonce the neuron staking subaccount is derived from, also used as the transfer memo.
+    pub memo: u64,
+    /// The current timestamp in nanoseconds.
+    pub current_timestamp_nanos: u64,
+}
+
+/// Creates an unsigned ICP transfer to the neuron staking subaccount of the governance canister.
+/// The neuron has to be claimed with [NeuronCommand::ClaimOrRefresh] once the transfer is executed.
+pub fn stake_neuron(
+    sender: Principal,
+    ledger_canister_id: Principal,
+    governance_canister_id: Principal,
+    args: StakeNeuronArgs,
+) -> Result<UnsignedTransaction, SignTransactionError> {
+    let subaccount = neuron_staking_subaccount(&sender, args.memo)?;
+    let to = AccountIdentifier::with_subaccount(&governance_canister_id, &subaccount);
+
+    transfer::unsigned_transfer(
+        sender,
+        ledger_canister_id,
+        TransferArgs {
+            memo: args.memo,
+            amount: args.amount,
+            max_fee: None,
+            to: to.to_hex(),
+            current_timestamp_nanos: args.current_timestamp_nanos,
+        },
+    )
+}
+
+/// Commands of the governance `manage_neuron` method.
+#[derive(Clone, Debug)]
+pub enum NeuronCommand {
+    /// Claims a neuron staked by the sender with the given memo, or refreshes its stake.
+    ClaimOrRefresh {
+        memo: u64,
+    },
+    IncreaseDissolveDelay {
+        additional_dissolve_delay_seconds: u32,
+    },
+    StartDissolving,
+    StopDissolving,
+    /// Disburses the stake of a dissolved neuron.
+    /// The governance canister defaults to the whole stake and the default account of the sender.
+    Disburse {
+        to_account: Option<AccountIdentifier>,
+        amount: Option<u64>,
+    },
+    /// Sets the neurons the neuron follows on the given topic.
+    Follow {
+        topic: i32,
+        followees: Vec<u64>,
+    },
+    /// Votes on a proposal, where `vote` is 1 for yes and 2 for no.
+    RegisterVote {
+        proposal_id: u64,
+        vote: i32,
+    },
+}
+
+/// Arguments to be used with [manage_neuron].
+#[derive(Clone, Debug)]
+pub struct ManageNeuronArgs {
+    /// The neuron to manage. Not required to claim a neuron.
+    pub neuron_id: Option<u64>,
+    pub command: NeuronCommand,
+    /// The current timestamp in nanoseconds.
+    pub current_timestamp_nanos: u64,
+}
+
+/// Creates an unsigned `manage_neuron` call to the governance canister.
+pub fn manage_neuron(
+    sender: Principal,
+    governance_canister_id: Principal,
+    args: ManageNeuronArgs,
+) -> Result<UnsignedTransaction, SignTransactionError> {
+    let is_claim = matches!(args.command, NeuronCommand::ClaimOrRefresh { .. });
+    if args.neuron_id.is_none() && !is_claim {
+        return Err(SignTransactionError::InvalidArguments);
+    }
+
+    let arg = IdlValue::Record(vec![
+        (
+            "id",
+            IdlValue::opt(neuron_id_type(), args.neuron_id.map(neuron_id_value)),
+        ),
+        (
+            "command",
+            IdlValue::some(command_value(sender, args.command)),
+        ),
+        (
+            "neuron_id_or_subaccount",
+            IdlValue::opt(
+                IdlType::Variant(vec![
+                    ("Subaccount", IdlType::Blob),
+                    ("NeuronId", neuron_id_type()),
+                ]),
+                None,
+            ),
+        ),
+    ]);
+    Ok(unsigned_call(
+        sender,
+        governance_canister_id,
+        MANAGE_NEURON_METHOD_NAME,
+        arg,
+        args.current_timestamp_nanos,
+    ))
+}
+
+fn command_value(sender: Principal, command: NeuronCommand) -> IdlValue {
+    match command {
+        NeuronCommand::ClaimOrRefresh { memo } => {
+            let by = IdlValue::variant(
+                "MemoAndController",
+                IdlValue::Record(vec![
+                    ("controller", IdlValue::some(IdlValue::Principal(sender))),
+                    ("memo", IdlValue::Nat64(memo)),
+                ]),
+            );
+            IdlValue::variant(
+                "ClaimOrRefresh",
+                IdlValue::Record(vec![("by", IdlValue::some(by))]),
+            )
+        },
+        NeuronCommand::IncreaseDissolveDelay {
+            additional_dissolve_delay_seconds,
+        } => configure_value(IdlValue::variant(
+            "IncreaseDissolveDelay",
+            IdlValue::Record(vec![(
+                "additional_dissolve_delay_seconds",
+                IdlValue::Nat32(additional_dissolve_delay_seconds),
+            )]),
+        )),
+        NeuronCommand::StartDissolving => configure_value(IdlValue::variant(
+            "StartDissolving",
+            IdlValue::Record(Vec::default()),
+        )),
+        NeuronCommand::StopDissolving => configure_value(IdlValue::variant(
+            "StopDissolving",
+            IdlValue::Record(Vec::default()),
+        )),
+        NeuronCommand::Disburse { to_account, amount } => IdlValue::variant(
+            "Disburse",
+            IdlValue::Record(vec![
+                (
+                    "to_account",
+                    IdlValue::opt(
+                        IdlType::Record(vec![("hash", IdlType::Blob)]),
+                        to_account.map(|account| {
+                            IdlValue::Record(vec![(
+                                "hash",
+                                IdlValue::Blob(account.as_ref().to_vec()),
+                            )])
+                        }),
+                    ),
+                ),
+                (
+                    "amount",
+                    IdlValue::opt(
+                        IdlType::Record(vec![("e8s", IdlType::Nat64)]),
+                        amount.map(|e8s| IdlValue::Record(vec![("e8s", IdlValue::Nat64(e8s))])),
+                    ),
+                ),
+            ]),
+        ),
+        NeuronCommand::Follow { topic, followees } => IdlValue::variant(
+            "Follow",
+            IdlValue::Record(vec![
+                ("topic", IdlValue::Int32(topic)),
+                (
+                    "followees",
+                    IdlValue::Vec(
+                        neuron_id_type(),
+                        followees.into_iter().map(neuron_id_value).collect(),
+                    ),
+                ),
+            ]),
+        ),
+        NeuronCommand::RegisterVote { proposal_id, vote } => IdlValue::variant(
+            "RegisterVote",
+            IdlValue::Record(vec![
+                ("vote", IdlValue::Int32(vote)),
+                ("proposal", IdlValue::some(neuron_id_value(proposal_id))),
+            ]),
+        ),
+    }
+}
+
+/// `Configure : record { operation : opt Operation }`
+fn configure_value(operation: IdlValue) -> IdlValue {
+    IdlValue::variant(
+        "Configure",
+        IdlValue::Record(vec![("operation", IdlValue::some(operation))]),
+    )
+}
+
+/// `record { id : nat64 }`, the type of both neuron and proposal IDs.
+fn neuron_id_type() -> IdlType {
+    IdlType::Record(vec![("id", IdlType::Nat64)])
+}
+
+fn neuron_id_value(id: u64) -> IdlValue {
+    IdlValue::Record(vec![("id", IdlValue::Nat64(id))])
+}
+
+#[cfg(test)]
+mod test {
+    use tw_encoding::hex;
+
+    use super::*;
+
+    const SENDER: &str = "hpikg-6exdt-jn33w-ndty3-fc7jc-tl2lr-buih3-cs3y7-tftkp-sfp62-gqe";
+    const ICP_LEDGER: &str = "ryjl3-tyaaa-aaaaa-aaaba-cai";
+    const NNS_GOVERNANCE: &str = "rrkah-fqaaa-aaaaa-aaaaq-cai";
+    const NEURON_ID: u64 = 1_234_567;
+    const CURRENT_TIMESTAMP_NANOS: u64 = 1_691_709_940_000_000_000;
+
+    fn manage_neuron_arg(neuron_id: Option<u64>, command: NeuronCommand) -> String {
+        let unsigned_tx = manage_neuron(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(NNS_GOVERNANCE).unwrap(),
+            ManageNeuronArgs {
+                neuron_id,
+                command,
+                current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+            },
+        )
+        .unwrap();
+        assert_eq!(unsigned_tx.update_content.method_name, "manage_neuron");
+        assert_eq!(
+            unsigned_tx.update_content.canister_id.to_text(),
+            NNS_GOVERNANCE
+        );
+        hex::encode(unsigned_tx.update_content.arg, false)
+    }
+
+    #[test]
+    fn staking_subaccount() {
+        let controller = Principal::from_text(SENDER).unwrap();
+        let subaccount = neuron_staking_subaccount(&controller, 1234).unwrap();
+        assert_eq!(
+            hex::encode(subaccount, false),
+            "f6f55dd46e2cf0d4c91e27141961c3eb370d853ea228e6f4ad00ca3aa9ad1efb"
+        );
+
+        let governance = Principal::from_text(NNS_GOVERNANCE).unwrap();
+        assert_eq!(
+            AccountIdentifier::with_subaccount(&governance, &subaccount).to_hex(),
+            "744651cb346788c19a5eb1fedd5749ddb411b33ccab2f56386b14e7d84ddd7df"
+        );
+    }
+
+    #[test]
+    fn stake_neuron_transfer() {
+        let unsigned_tx = stake_neuron(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(ICP_LEDGER).unwrap(),
+            Principal::from_text(NNS_GOVERNANCE).unwrap(),
+            StakeNeuronArgs {
+                amount: 100_000_000,
+                memo: 1234,
+                current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+            },
+        )
+        .unwrap();
+        assert_eq!(unsigned_tx.update_content.method_name, "send_pb");
+        assert_eq!(unsigned_tx.update_content.canister_id.to_text(), ICP_LEDGER);
+        // The `send_pb` argument contains the neuron staking account identifier.
+        assert!(hex::encode(unsigned_tx.update_content.arg, false)
+            .contains("744651cb346788c19a5eb1fedd5749ddb411b33ccab2f56386b14e7d84ddd7df"));
+    }
+
+    #[test]
+    fn claim_or_refresh_arg() {
+        assert_eq!(
+            manage_neuron_arg(None, NeuronCommand::ClaimOrRefresh { memo: 1234 }),
+            "4449444c0d6c01dbb701786e006e686c029cb1fa2502ba89e5c204786b019992ccd001036e046c01d7ab01056b01fc9fc68305066e076d7b6b02cd8e8eb90409cebee1d308006e0a6c03dbb70101cbe2b58b0808f1bb8b880d0b010c000100010001011d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d02d20400000000000000"
+        );
+    }
+
+    #[test]
+    fn increase_dissolve_delay_arg() {
+        let command = NeuronCommand::IncreaseDissolveDelay {
+            additional_dissolve_delay_seconds: 15_778_800,
+        };
+        assert_eq!(
+            manage_neuron_arg(Some(NEURON_ID), command),
+            "4449444c0c6c01dbb701786e006c018dc3b2b303796b0190f29afe07026e036c01a78882820a046b01c6b3bb9106056e066d7b6b02cd8e8eb90408cebee1d308006e096c03dbb70101cbe2b58b0807f1bb8b880d0a010b0187d612000000000001000100f0c3f00000"
+        );
+    }
+
+    #[test]
+    fn start_and_stop_dissolving_arg() {
+        assert_eq!(
+            manage_neuron_arg(Some(NEURON_ID), NeuronCommand::StartDissolving),
+            "4449444c0c6c01dbb701786e006c006b01d0fb87af07026e036c01a78882820a046b01c6b3bb9106056e066d7b6b02cd8e8eb90408cebee1d308006e096c03dbb70101cbe2b58b0807f1bb8b880d0a010b0187d61200000000000100010000"
+        );
+        assert_eq!(
+            manage_neuron_arg(Some(NEURON_ID), NeuronCommand::StopDissolving),
+            "4449444c0c6c01dbb701786e006c006b01b09b9ba407026e036c01a78882820a046b01c6b3bb9106056e066d7b6b02cd8e8eb90408cebee1d308006e096c03dbb70101cbe2b58b0807f1bb8b880d0a010b0187d61200000000000100010000"
+        );
+    }
+
+    #[test]
+    fn disburse_arg() {
+        let to_account = AccountIdentifier::from_hex(
+            "943d12e762f43806782f524b8f90297298a6d79e4749b41b585ec427409c826a",
+        )
+        .unwrap();
+        let command = NeuronCommand::Disburse {
+            to_account: Some(to_account),
+            amount: None,
+        };
+        assert_eq!(
+            manage_neuron_arg(Some(NEURON_ID), command),
+            "4449444c0d6c01dbb701786e006d7b6c01cedfa0a804026e036c01e0a9b302786e056c02a9ddf49b0704d8a38ca80d066b01a3f3c0ad0f076e086b02cd8e8eb90402cebee1d308006e0a6c03dbb70101cbe2b58b0809f1bb8b880d0b010c0187d612000000000001000120943d12e762f43806782f524b8f90297298a6d79e4749b41b585ec427409c826a0000"
+        );
+    }
+
+    #[test]
+    fn follow_arg() {
+        let command = NeuronCommand::Follow {
+            topic: 4,
+            followees: vec![27, 28],
+        };
+        assert_eq!(
+            manage_neuron_arg(Some(NEURON_ID), command),
+            "4449444c0a6c01dbb701786e006d006c02afa3bda10175c2cee0d80c026b01918bacf102036e046d7b6b02cd8e8eb90406cebee1d308006e076c03dbb70101cbe2b58b0805f1bb8b880d0801090187d6120000000000010004000000021b000000000000001c0000000000000000"
+        );
+    }
+
+    #[test]
+    fn register_vote_arg() {
+        let command = NeuronCommand::RegisterVote {
+            proposal_id: 130_000,
+            vote: 1,
+        };
+        assert_eq!(
+            manage_neuron_arg(Some(NEURON_ID), command),
+            "4449444c096c01dbb701786e006c02ea99cff20475b2b8d4960b016b018db2d59209026e036d7b6b02cd8e8eb90405cebee1d308006e066c03dbb70101cbe2b58b0804f1bb8b880d0701080187d612000000000001000100000001d0fb01000000000000"
+        );
+    }
+
+    #[test]
+    fn manage_neuron_without_neuron_id() {
+        let result = manage_neuron(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(NNS_GOVERNANCE).unwrap(),
+            ManageNeuronArgs {
+                neuron_id: None,
+                command: NeuronCommand::StartDissolving,
+                current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(SignTransactionError::InvalidArguments)
+        ));
+    }
+}
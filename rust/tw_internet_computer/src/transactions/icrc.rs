@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_number::U256;
+
+use crate::{
+    address::Icrc1Account,
+    protocol::{
+        candid::{IdlType, IdlValue},
+        principal::Principal,
+    },
+};
+
+use super::{unsigned_call, SignTransactionError, UnsignedTransaction};
+
+/// ICRC-1 ledgers reject memos longer than 32 bytes by default.
+const MAX_MEMO_LEN: usize = 32;
+
+const ICRC1_TRANSFER_METHOD_NAME: &str = "icrc1_transfer";
+const ICRC2_APPROVE_METHOD_NAME: &str = "icrc2_approve";
+const ICRC2_TRANSFER_FROM_METHOD_NAME: &str = "icrc2_transfer_from";
+
+/// Arguments of the ICRC-1 `icrc1_transfer` method.
+#[derive(Clone, Debug)]
+pub struct Icrc1TransferArgs {
+    pub from_subaccount: Option<H256>,
+    pub to: Icrc1Account,
+    pub amount: U256,
+    pub fee: Option<U256>,
+    pub memo: Data,
+    /// The current timestamp in nanoseconds, used as `created_at_time` for deduplication.
+    pub current_timestamp_nanos: u64,
+}
+
+/// Arguments of the ICRC-2 `icrc2_approve` method.
+#[derive(Clone, Debug)]
+pub struct Icrc2ApproveArgs {
+    pub from_subaccount: Option<H256>,
+    pub spender: Icrc1Account,
+    pub amount: U256,
+    pub expected_allowance: Option<U256>,
+    pub expires_at: Option<u64>,
+    pub fee: Option<U256>,
+    pub memo: Data,
+    /// The current timestamp in nanoseconds, used as `created_at_time` for deduplication.
+    pub current_timestamp_nanos: u64,
+}
+
+/// Arguments of the ICRC-2 `icrc2_transfer_from` method.
+#[derive(Clone, Debug)]
+pub struct Icrc2TransferFromArgs {
+    pub spender_subaccount: Option<H256>,
+    pub from: Icrc1Account,
+    pub to: Icrc1Account,
+    pub amount: U256,
+    pub fee: Option<U256>,
+    pub memo: Data,
+    /// The current timestamp in nanoseconds, used as `created_at_time` for deduplication.
+    pub current_timestamp_nanos: u64,
+}
+
+/// Creates an unsigned `icrc1_transfer` call to the given ledger canister.
+pub fn icrc1_transfer(
+    sender: Principal,
+    ledger_canister_id: Principal,
+    args: Icrc1TransferArgs,
+) -> Result<UnsignedTransaction, SignTransactionError> {
+    if args.amount.is_zero() {
+        return Err(SignTransactionError::InvalidAmount);
+    }
+
+    let arg = IdlValue::Record(vec![
+        ("from_subaccount", opt_subaccount(args.from_subaccount)),
+        ("to", account_value(&args.to)),
+        ("amount", IdlValue::Nat(args.amount)),
+        ("fee", opt_nat(args.fee)),
+        ("memo", opt_memo(args.memo)?),
+        (
+            "created_at_time",
+            IdlValue::opt(
+                IdlType::Nat64,
+                Some(IdlValue::Nat64(args.current_timestamp_nanos)),
+            ),
+        ),
+    ]);
+    Ok(unsigned_call(
+        sender,
+        ledger_canister_id,
+        ICRC1_TRANSFER_METHOD_NAME,
+        arg,
+        args.current_timestamp_nanos,
+    ))
+}
+
+/// Creates an unsigned `icrc2_approve` call to the given ledger canister.
+pub fn icrc2_approve(
+    sender: Principal,
+    ledger_canister_id: Principal,
+    args: Icrc2ApproveArgs,
+) -> Result<UnsignedTransaction, SignTransactionError> {
+    let arg = IdlValue::Record(vec![
+        ("from_subaccount", opt_subaccount(args.from_subaccount)),
+        ("spender", account_value(&args.spender)),
+        ("amount", IdlValue::Nat(args.amount)),
+        ("expected_allowance", opt_nat(args.expected_allowance)),
+        (
+            "expires_at",
+            IdlValue::opt(IdlType::Nat64, args.expires_at.map(IdlValue::Nat64)),
+        ),
+        ("fee", opt_nat(args.fee)),
+        ("memo", opt_memo(args.memo)?),
+        (
+            "created_at_time",
+            IdlValue::opt(
+                IdlType::Nat64,
+                Some(IdlValue::Nat64(args.current_timestamp_nanos)),
+            ),
+        ),
+    ]);
+    Ok(unsigned_call(
+        sender,
+        ledger_canister_id,
+        ICRC2_APPROVE_METHOD_NAME,
+        arg,
+        args.current_timestamp_nanos,
+    ))
+}
+
+/// Creates an unsigned `icrc2_transfer_from` call to the given ledger canister.
+pub fn icrc2_transfer_from(
+    sender: Principal,
+    ledger_canister_id: Principal,
+    args: Icrc2TransferFromArgs,
+) -> Result<UnsignedTransaction, SignTransactionError> {
+    if args.amount.is_zero() {
+        return Err(SignTransactionError::InvalidAmount);
+    }
+
+    let arg = IdlValue::Record(vec![
+        (
+            "spender_subaccount",
+            opt_subaccount(args.spender_subaccount),
+        ),
+        ("from", account_value(&args.from)),
+        ("to", account_value(&args.to)),
+        ("amount", IdlValue::Nat(args.amount)),
+        ("fee", opt_nat(args.fee)),
+        ("memo", opt_memo(args.memo)?),
+        (
+            "created_at_time",
+            IdlValue::opt(
+                IdlType::Nat64,
+                Some(IdlValue::Nat64(args.current_timestamp_nanos)),
+            ),
+        ),
+    ]);
+    Ok(unsigned_call(
+        sender,
+        ledger_canister_id,
+        ICRC2_TRANSFER_FROM_METHOD_NAME,
+        arg,
+        args.current_timestamp_nanos,
+    ))
+}
+
+/// `record { owner : principal; subaccount : opt blob }`
+fn account_value(account: &Icrc1Account) -> IdlValue {
+    IdlValue::Record(vec![
+        ("owner", IdlValue::Principal(account.owner())),
+        ("subaccount", opt_subaccount(account.subaccount())),
+    ])
+}
+
+fn opt_subaccount(subaccount: Option<H256>) -> IdlValue {
+    IdlValue::opt(
+        IdlType::Blob,
+        subaccount.map(|subaccount| IdlValue::Blob(subaccount.to_vec())),
+    )
+}
+
+fn opt_nat(value: Option<U256>) -> IdlValue {
+    IdlValue::opt(IdlType::Nat, value.map(IdlValue::Nat))
+}
+
+fn opt_memo(memo: Data) -> Result<IdlValue, SignTransactionError> {
+    if memo.len() > MAX_MEMO_LEN {
+        return Err(SignTransactionError::InvalidArguments);
+    }
+    Ok(IdlValue::opt_blob(memo))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tw_encoding::hex;
+
+    use super::*;
+
+    const SENDER: &str = "hpikg-6exdt-jn33w-ndty3-fc7jc-tl2lr-buih3-cs3y7-tftkp-sfp62-gqe";
+    const CKBTC_LEDGER: &str = "mxzaz-hqaaa-aaaar-qaada-cai";
+    const TO: &str = "t4u4z-y3dur-j63pk-nw4rv-yxdbt-agtt6-nygn7-ywh6y-zm2f4-sdzle-3qe";
+
+    const ICRC1_TRANSFER_ARG: &str = "4449444c066d7b6e006c02b3b0dac30368ad86ca8305016e7d6e786c06fbca0102c6fcb60203ba89e5c20401a2de94eb060182f3f3910c04d8a38ca80d7d0105011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e487959370200010a0000010088b2343a297a17a08d06";
+    const ICRC2_APPROVE_ARG: &str = "4449444c066e7d6d7b6e016e786c02b3b0dac30368ad86ca8305026c08c6fcb60200ba89e5c20402a2de94eb060282f3f3910c03d8a38ca80d7d919c9cbf0d00dea7f7da0d03cb96dcb40e040105000107617070726f766500010088b2343a297a17a0c21e0001008001ef227b7a17011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e487959370200";
+    const ICRC2_TRANSFER_FROM_ARG: &str = "4449444c066d7b6e006c02b3b0dac30368ad86ca8305016e7d6e786c07fbca0102c6fcb60203e185c1940201eaca8a9e0402ba89e5c2040182f3f3910c04d8a38ca80d7d0105011d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d02000000011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e48795937020000010088b2343a297a1790a10f";
+
+    fn encoded_arg(unsigned_tx: UnsignedTransaction) -> String {
+        hex::encode(unsigned_tx.update_content.arg, false)
+    }
+
+    #[test]
+    fn icrc1_transfer_arg() {
+        let args = Icrc1TransferArgs {
+            from_subaccount: None,
+            to: Icrc1Account::from_str(TO).unwrap(),
+            amount: U256::from(100_000u64),
+            fee: Some(U256::from(10u64)),
+            memo: Data::default(),
+            current_timestamp_nanos: 1_691_709_940_000_000_000,
+        };
+        let unsigned_tx = icrc1_transfer(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(CKBTC_LEDGER).unwrap(),
+            args,
+        )
+        .unwrap();
+        assert_eq!(unsigned_tx.update_content.method_name, "icrc1_transfer");
+        assert_eq!(encoded_arg(unsigned_tx), ICRC1_TRANSFER_ARG);
+    }
+
+    #[test]
+    fn icrc1_transfer_zero_amount() {
+        let args = Icrc1TransferArgs {
+            from_subaccount: None,
+            to: Icrc1Account::from_str(TO).unwrap(),
+            amount: U256::zero(),
+            fee: None,
+            memo: Data::default(),
+            current_timestamp_nanos: 1_691_709_940_000_000_000,
+        };
+        let result = icrc1_transfer(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(CKBTC_LEDGER).unwrap(),
+            args,
+        );
+        assert!(matches!(result, Err(SignTransactionError::InvalidAmount)));
+    }
+
+    #[test]
+    fn icrc1_transfer_memo_too_long() {
+        let args = Icrc1TransferArgs {
+            from_subaccount: None,
+            to: Icrc1Account::from_str(TO).unwrap(),
+            amount: U256::from(1u64),
+            fee: None,
+            memo: vec![1; 33],
+            current_timestamp_nanos: 1_691_709_940_000_000_000,
+        };
+        let result = icrc1_transfer(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(CKBTC_LEDGER).unwrap(),
+            args,
+        );
+        assert!(matches!(
+            result,
+            Err(SignTransactionError::InvalidArguments)
+        ));
+    }
+
+    #[test]
+    fn icrc2_approve_arg() {
+        let args = Icrc2ApproveArgs {
+            from_subaccount: None,
+            spender: Icrc1Account::from_str(TO).unwrap(),
+            amount: U256::from(500_000u64),
+            expected_allowance: None,
+            expires_at: Some(1_691_800_000_000_000_000),
+            fee: None,
+            memo: b"approve".to_vec(),
+            current_timestamp_nanos: 1_691_709_940_000_000_000,
+        };
+        let unsigned_tx = icrc2_approve(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(CKBTC_LEDGER).unwrap(),
+            args,
+        )
+        .unwrap();
+        assert_eq!(unsigned_tx.update_content.method_name, "icrc2_approve");
+        assert_eq!(encoded_arg(unsigned_tx), ICRC2_APPROVE_ARG);
+    }
+
+    #[test]
+    fn icrc2_transfer_from_arg() {
+        let args = Icrc2TransferFromArgs {
+            spender_subaccount: None,
+            from: Icrc1Account::from_str(TO).unwrap(),
+            to: Icrc1Account::from_str(SENDER).unwrap(),
+            amount: U256::from(250_000u64),
+            fee: None,
+            memo: Data::default(),
+            current_timestamp_nanos: 1_691_709_940_000_000_000,
+        };
+        let unsigned_tx = icrc2_transfer_from(
+            Principal::from_text(SENDER).unwrap(),
+            Principal::from_text(CKBTC_LEDGER).unwrap(),
+            args,
+        )
+        .unwrap();
+        assert_eq!(
+            unsigned_tx.update_content.method_name,
+            "icrc2_transfer_from"
+        );
+        assert_eq!(encoded_arg(unsigned_tx), ICRC2_TRANSFER_FROM_ARG);
+    }
+}
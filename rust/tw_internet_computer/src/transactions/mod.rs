@@ -2,16 +2,35 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod governance;
+pub mod icrc;
 pub mod transfer;
 
 pub mod proto {
     include!(concat!(env!("OUT_DIR"), "/proto/mod.rs"));
 }
 
+use std::{str::FromStr, time::Duration};
+
+use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1::PrivateKey;
-use tw_proto::InternetComputer::Proto::mod_Transaction::OneOftransaction_oneof as Tx;
+use tw_number::U256;
+use tw_proto::InternetComputer::Proto::{self, mod_Transaction::OneOftransaction_oneof as Tx};
 
-use crate::protocol::{identity, principal::Principal, rosetta};
+use crate::address::{AccountIdentifier, Icrc1Account};
+use crate::context::InternetComputerContext;
+use crate::protocol::{
+    candid::{encode_args, IdlValue},
+    envelope::{
+        Envelope, EnvelopeCallContent, EnvelopeReadStateContent, Label, RepresentationHashable,
+    },
+    get_ingress_expiry,
+    identity::{self, Identity},
+    principal::Principal,
+    request_id::RequestId,
+    rosetta,
+};
+use governance::{ManageNeuronArgs, NeuronCommand};
 
 #[derive(Debug)]
 pub enum SignTransactionError {
@@ -21,17 +40,27 @@ pub enum SignTransactionError {
     EncodingArgsFailed,
     InvalidToAccountIdentifier,
     InvalidEnvelopePair,
+    InvalidCanisterId,
+    Internal,
 }
 
-pub fn sign_transaction(
+pub fn sign_transaction<Context: InternetComputerContext>(
     private_key: PrivateKey,
-    canister_id: Principal,
     transaction: &Tx,
 ) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+    let identity = Identity::new(private_key);
+    unsigned_transaction::<Context>(identity.sender(), transaction)?.sign(&identity)
+}
+
+/// Creates the request contents of the given transaction to be signed by `sender`.
+pub fn unsigned_transaction<Context: InternetComputerContext>(
+    sender: Principal,
+    transaction: &Tx,
+) -> Result<UnsignedTransaction, SignTransactionError> {
     match transaction {
-        Tx::transfer(transfer_args) => transfer::transfer(
-            private_key,
-            canister_id,
+        Tx::transfer(transfer_args) => transfer::unsigned_transfer(
+            sender,
+            Context::get_canister_id(),
             transfer::TransferArgs {
                 memo: transfer_args.memo,
                 amount: transfer_args.amount,
@@ -40,6 +69,280 @@ pub fn sign_transaction(
                 current_timestamp_nanos: transfer_args.current_timestamp_nanos,
             },
         ),
+        Tx::icrc1_transfer(transfer_args) => icrc::icrc1_transfer(
+            sender,
+            parse_canister_id(&transfer_args.ledger_canister_id)?,
+            icrc::Icrc1TransferArgs {
+                from_subaccount: parse_subaccount(&transfer_args.from_subaccount)?,
+                to: parse_account(&transfer_args.to)?,
+                amount: parse_amount(&transfer_args.amount)?,
+                fee: parse_optional_amount(&transfer_args.fee)?,
+                memo: transfer_args.memo.to_vec(),
+                current_timestamp_nanos: transfer_args.current_timestamp_nanos,
+            },
+        ),
+        Tx::icrc2_approve(approve_args) => icrc::icrc2_approve(
+            sender,
+            parse_canister_id(&approve_args.ledger_canister_id)?,
+            icrc::Icrc2ApproveArgs {
+                from_subaccount: parse_subaccount(&approve_args.from_subaccount)?,
+                spender: parse_account(&approve_args.spender)?,
+                amount: parse_amount(&approve_args.amount)?,
+                expected_allowance: parse_optional_amount(&approve_args.expected_allowance)?,
+                expires_at: (approve_args.expires_at != 0).then_some(approve_args.expires_at),
+                fee: parse_optional_amount(&approve_args.fee)?,
+                memo: approve_args.memo.to_vec(),
+                current_timestamp_nanos: approve_args.current_timestamp_nanos,
+            },
+        ),
+        Tx::icrc2_transfer_from(transfer_from_args) => icrc::icrc2_transfer_from(
+            sender,
+            parse_canister_id(&transfer_from_args.ledger_canister_id)?,
+            icrc::Icrc2TransferFromArgs {
+                spender_subaccount: parse_subaccount(&transfer_from_args.spender_subaccount)?,
+                from: parse_account(&transfer_from_args.from)?,
+                to: parse_account(&transfer_from_args.to)?,
+                amount: parse_amount(&transfer_from_args.amount)?,
+                fee: parse_optional_amount(&transfer_from_args.fee)?,
+                memo: transfer_from_args.memo.to_vec(),
+                current_timestamp_nanos: transfer_from_args.current_timestamp_nanos,
+            },
+        ),
+        Tx::stake_neuron(stake_args) => governance::stake_neuron(
+            sender,
+            Context::get_canister_id(),
+            Context::get_governance_canister_id(),
+            governance::StakeNeuronArgs {
+                amount: stake_args.amount,
+                memo: stake_args.memo,
+                current_timestamp_nanos: stake_args.current_timestamp_nanos,
+            },
+        ),
+        Tx::claim_or_refresh_neuron(claim_args) => manage_neuron::<Context>(
+            sender,
+            None,
+            NeuronCommand::ClaimOrRefresh {
+                memo: claim_args.memo,
+            },
+            claim_args.current_timestamp_nanos,
+        ),
+        Tx::increase_dissolve_delay(delay_args) => manage_neuron::<Context>(
+            sender,
+            Some(delay_args.neuron_id),
+            NeuronCommand::IncreaseDissolveDelay {
+                additional_dissolve_delay_seconds: delay_args.additional_dissolve_delay_seconds,
+            },
+            delay_args.current_timestamp_nanos,
+        ),
+        Tx::start_dissolving(dissolve_args) => manage_neuron::<Context>(
+            sender,
+            Some(dissolve_args.neuron_id),
+            NeuronCommand::StartDissolving,
+            dissolve_args.current_timestamp_nanos,
+        ),
+        Tx::stop_dissolving(dissolve_args) => manage_neuron::<Context>(
+            sender,
+            Some(dissolve_args.neuron_id),
+            NeuronCommand::StopDissolving,
+            dissolve_args.current_timestamp_nanos,
+        ),
+        Tx::disburse(disburse_args) => {
+            let to_account = if disburse_args.to_account_identifier.is_empty() {
+                None
+            } else {
+                let to_account = AccountIdentifier::from_hex(&disburse_args.to_account_identifier)
+                    .map_err(|_| SignTransactionError::InvalidToAccountIdentifier)?;
+                Some(to_account)
+            };
+            manage_neuron::<Context>(
+                sender,
+                Some(disburse_args.neuron_id),
+                NeuronCommand::Disburse {
+                    to_account,
+                    amount: (disburse_args.amount != 0).then_some(disburse_args.amount),
+                },
+                disburse_args.current_timestamp_nanos,
+            )
+        },
+        Tx::follow(follow_args) => manage_neuron::<Context>(
+            sender,
+            Some(follow_args.neuron_id),
+            NeuronCommand::Follow {
+                topic: follow_args.topic,
+                followees: follow_args.followees.clone(),
+            },
+            follow_args.current_timestamp_nanos,
+        ),
+        Tx::register_vote(vote_args) => {
+            let vote = match vote_args.vote {
+                Proto::Vote::Yes => 1,
+                Proto::Vote::No => 2,
+                Proto::Vote::Unspecified => return Err(SignTransactionError::InvalidArguments),
+            };
+            manage_neuron::<Context>(
+                sender,
+                Some(vote_args.neuron_id),
+                NeuronCommand::RegisterVote {
+                    proposal_id: vote_args.proposal_id,
+                    vote,
+                },
+                vote_args.current_timestamp_nanos,
+            )
+        },
         Tx::None => Err(SignTransactionError::InvalidArguments),
     }
 }
+
+fn manage_neuron<Context: InternetComputerContext>(
+    sender: Principal,
+    neuron_id: Option<u64>,
+    command: NeuronCommand,
+    current_timestamp_nanos: u64,
+) -> Result<UnsignedTransaction, SignTransactionError> {
+    governance::manage_neuron(
+        sender,
+        Context::get_governance_canister_id(),
+        ManageNeuronArgs {
+            neuron_id,
+            command,
+            current_timestamp_nanos,
+        },
+    )
+}
+
+fn parse_canister_id(canister_id: &str) -> Result<Principal, SignTransactionError> {
+    Principal::from_text(canister_id).map_err(|_| SignTransactionError::InvalidCanisterId)
+}
+
+fn parse_account(account: &str) -> Result<Icrc1Account, SignTransactionError> {
+    Icrc1Account::from_str(account).map_err(|_| SignTransactionError::InvalidToAccountIdentifier)
+}
+
+/// An empty subaccount stands for the default one.
+fn parse_subaccount(subaccount: &[u8]) -> Result<Option<H256>, SignTransactionError> {
+    if subaccount.is_empty() {
+        return Ok(None);
+    }
+    H256::try_from(subaccount)
+        .map(Some)
+        .map_err(|_| SignTransactionError::InvalidArguments)
+}
+
+fn parse_amount(amount: &[u8]) -> Result<U256, SignTransactionError> {
+    U256::from_big_endian_slice(amount).map_err(|_| SignTransactionError::InvalidAmount)
+}
+
+fn parse_optional_amount(amount: &[u8]) -> Result<Option<U256>, SignTransactionError> {
+    if amount.is_empty() {
+        return Ok(None);
+    }
+    parse_amount(amount).map(Some)
+}
+
+/// Creates an unsigned update call of a canister method that takes a single Candid argument.
+fn unsigned_call(
+    sender: Principal,
+    canister_id: Principal,
+    method_name: &str,
+    arg: IdlValue,
+    current_timestamp_nanos: u64,
+) -> UnsignedTransaction {
+    let ingress_expiry = get_ingress_expiry(Duration::from_nanos(current_timestamp_nanos));
+    UnsignedTransaction::new(EnvelopeCallContent {
+        nonce: None,
+        ingress_expiry,
+        sender,
+        canister_id,
+        method_name: method_name.to_string(),
+        arg: encode_args(&[arg]),
+    })
+}
+
+/// An update call and the corresponding read_state request that are yet to be signed.
+pub struct UnsignedTransaction {
+    update_content: EnvelopeCallContent,
+    read_state_content: EnvelopeReadStateContent,
+}
+
+impl UnsignedTransaction {
+    /// Creates the read_state request that checks the status of the given update call.
+    pub fn new(update_content: EnvelopeCallContent) -> Self {
+        let read_state_content = EnvelopeReadStateContent {
+            ingress_expiry: update_content.ingress_expiry,
+            sender: update_content.sender,
+            paths: vec![vec![
+                Label::from("request_status"),
+                Label::from(update_content.request_id()),
+            ]],
+        };
+        UnsignedTransaction {
+            update_content,
+            read_state_content,
+        }
+    }
+
+    /// Returns the request IDs of the update call and the read_state request.
+    pub fn request_ids(&self) -> (RequestId, RequestId) {
+        (
+            self.update_content.request_id(),
+            self.read_state_content.request_id(),
+        )
+    }
+
+    /// Returns the hashes to be signed for the update call and the read_state request.
+    pub fn sig_data(&self) -> (H256, H256) {
+        let (update_request_id, read_state_request_id) = self.request_ids();
+        (
+            update_request_id.sig_data(),
+            read_state_request_id.sig_data(),
+        )
+    }
+
+    /// Signs both requests with the given identity.
+    pub fn sign(
+        self,
+        identity: &Identity,
+    ) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+        let (update_sig_data, read_state_sig_data) = self.sig_data();
+        let update_signature = identity
+            .sign(update_sig_data)
+            .map_err(SignTransactionError::Identity)?;
+        let read_state_signature = identity
+            .sign(read_state_sig_data)
+            .map_err(SignTransactionError::Identity)?;
+
+        self.into_signed(
+            update_signature.public_key,
+            update_signature.signature,
+            read_state_signature.signature,
+        )
+    }
+
+    /// Creates a signed transaction from the DER-encoded public key of the sender and
+    /// the `r || s` signatures of the update call and the read_state request.
+    pub fn into_signed(
+        self,
+        der_encoded_public_key: Vec<u8>,
+        update_signature: Vec<u8>,
+        read_state_signature: Vec<u8>,
+    ) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+        let update_envelope = Envelope {
+            content: self.update_content,
+            sender_pubkey: Some(der_encoded_public_key.clone()),
+            sender_sig: Some(update_signature),
+        };
+        let read_state_envelope = Envelope {
+            content: self.read_state_content,
+            sender_pubkey: Some(der_encoded_public_key),
+            sender_sig: Some(read_state_signature),
+        };
+
+        // Create a new EnvelopePair with the update call and read_state envelopes.
+        let envelope_pair = rosetta::EnvelopePair::new(update_envelope, read_state_envelope)
+            .map_err(|_| SignTransactionError::InvalidEnvelopePair)?;
+
+        // Create a signed transaction containing the envelope pair.
+        let request: rosetta::Request = (rosetta::RequestType::Send, vec![envelope_pair]);
+        Ok(vec![request])
+    }
+}
@@ -9,21 +9,15 @@ use tw_keypair::ecdsa::secp256k1::PrivateKey;
 use crate::{
     address::AccountIdentifier,
     protocol::{
-        envelope::{
-            Envelope, EnvelopeCallContent, EnvelopeReadStateContent, Label, RepresentationHashable,
-        },
-        get_ingress_expiry,
-        identity::Identity,
-        principal::Principal,
-        request_id::RequestId,
-        rosetta,
+        envelope::EnvelopeCallContent, get_ingress_expiry, identity::Identity,
+        principal::Principal, rosetta,
     },
     transactions::proto::ic_ledger::pb::v1::{
         AccountIdentifier as ProtoAccountIdentifier, Memo, Payment, SendRequest, TimeStamp, Tokens,
     },
 };
 
-use super::SignTransactionError;
+use super::{SignTransactionError, UnsignedTransaction};
 
 /// Arguments to be used with [transfer] to create a signed transaction enveloper pair.
 #[derive(Clone, Debug)]
@@ -77,44 +71,30 @@ pub fn transfer(
     canister_id: Principal,
     args: TransferArgs,
 ) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+    let identity = Identity::new(private_key);
+    unsigned_transfer(identity.sender(), canister_id, args)?.sign(&identity)
+}
+
+/// Given the principal of the sender, the canister ID of an ICP-based ledger canister, and the actual transfer args,
+/// this function creates the update call and read_state request contents to be signed by the sender.
+pub fn unsigned_transfer(
+    sender: Principal,
+    canister_id: Principal,
+    args: TransferArgs,
+) -> Result<UnsignedTransaction, SignTransactionError> {
     if args.amount < 1 {
         return Err(SignTransactionError::InvalidAmount);
     }
 
     let current_timestamp_duration = Duration::from_nanos(args.current_timestamp_nanos);
     let ingress_expiry = get_ingress_expiry(current_timestamp_duration);
-    let identity = Identity::new(private_key);
 
     // Encode the arguments for the ledger `send_pb` endpoint.
     let send_request = SendRequest::try_from(args)?;
     let arg =
         tw_proto::serialize(&send_request).map_err(|_| SignTransactionError::EncodingArgsFailed)?;
-    // Create the update envelope.
-    let (request_id, update_envelope) =
-        create_update_envelope(&identity, canister_id, arg, ingress_expiry)?;
 
-    // Create the read state envelope.
-    let (_, read_state_envelope) =
-        create_read_state_envelope(&identity, request_id, ingress_expiry)?;
-
-    // Create a new EnvelopePair with the update call and read_state envelopes.
-    let envelope_pair = rosetta::EnvelopePair::new(update_envelope, read_state_envelope)
-        .map_err(|_| SignTransactionError::InvalidEnvelopePair)?;
-
-    // Create a signed transaction containing the envelope pair.
-    let request: rosetta::Request = (rosetta::RequestType::Send, vec![envelope_pair]);
-    Ok(vec![request])
-}
-
-#[inline]
-fn create_update_envelope(
-    identity: &Identity,
-    canister_id: Principal,
-    arg: Vec<u8>,
-    ingress_expiry: u64,
-) -> Result<(RequestId, Envelope<EnvelopeCallContent>), SignTransactionError> {
-    let sender = identity.sender();
-    let content = EnvelopeCallContent {
+    let update_content = EnvelopeCallContent {
         nonce: None,
         ingress_expiry,
         sender,
@@ -122,48 +102,7 @@ fn create_update_envelope(
         method_name: METHOD_NAME.to_string(),
         arg,
     };
-
-    let request_id = content.request_id();
-    let signature = identity
-        .sign(request_id.sig_data())
-        .map_err(SignTransactionError::Identity)?;
-
-    let env = Envelope {
-        content,
-        sender_pubkey: Some(signature.public_key),
-        sender_sig: Some(signature.signature),
-    };
-    Ok((request_id, env))
-}
-
-#[inline]
-fn create_read_state_envelope(
-    identity: &Identity,
-    update_request_id: RequestId,
-    ingress_expiry: u64,
-) -> Result<(RequestId, Envelope<EnvelopeReadStateContent>), SignTransactionError> {
-    let sender = identity.sender();
-
-    let content = EnvelopeReadStateContent {
-        ingress_expiry,
-        sender,
-        paths: vec![vec![
-            Label::from("request_status"),
-            Label::from(update_request_id),
-        ]],
-    };
-
-    let request_id = content.request_id();
-    let signature = identity
-        .sign(request_id.sig_data())
-        .map_err(SignTransactionError::Identity)?;
-
-    let env = Envelope {
-        content,
-        sender_pubkey: Some(signature.public_key),
-        sender_sig: Some(signature.signature),
-    };
-    Ok((request_id, env))
+    Ok(UnsignedTransaction::new(update_content))
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ed25519::{private::PrivateKey, public::PublicKey, signature::Signature, Hasher512};
+use crate::substrate::DerivationPath;
 use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
 use crate::{KeyPairError, KeyPairResult};
 use tw_encoding::hex;
@@ -15,6 +16,15 @@ pub struct KeyPair<H: Hasher512> {
     public: PublicKey<H>,
 }
 
+impl<H: Hasher512> KeyPair<H> {
+    /// Derives a child key pair using the given Substrate `path`.
+    pub fn derive(&self, path: &DerivationPath) -> KeyPairResult<Self> {
+        let private = self.private.derive(path)?;
+        let public = private.public();
+        Ok(KeyPair { private, public })
+    }
+}
+
 impl<H: Hasher512> KeyPairTrait for KeyPair<H> {
     type Private = PrivateKey<H>;
     type Public = PublicKey<H>;
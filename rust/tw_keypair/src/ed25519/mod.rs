@@ -44,7 +44,9 @@ impl<T> Hasher512 for T where T: Digest<OutputSize = U64> {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::substrate::DerivationPath;
     use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+    use std::str::FromStr;
     use tw_encoding::hex;
     use tw_hash::sha2::sha256;
     use tw_hash::sha3::keccak256;
@@ -247,6 +249,45 @@ mod tests {
         let _ = sha512::PublicKey::try_from(&invalid[..]).unwrap_err();
     }
 
+    #[test]
+    fn test_derive_substrate() {
+        // `subkey inspect --scheme ed25519 "bottom drive obey lake curtain smoke basket hold race lonely fit walk"`
+        let private = sha512::PrivateKey::try_from(
+            "fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e",
+        )
+        .unwrap();
+
+        // `subkey inspect --scheme ed25519 //Alice`
+        let path = DerivationPath::from_str("//Alice").unwrap();
+        let alice = private.derive(&path).unwrap();
+        assert_eq!(
+            alice.to_zeroizing_vec().as_slice(),
+            H256::from("abf8e5bdbe30c65656c0a3cbd181ff8a56294a69dfedd27982aace4a76909115")
+                .as_slice()
+        );
+        assert_eq!(
+            alice.public().to_bytes(),
+            H256::from("88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee")
+        );
+
+        // Numeric junctions are encoded as `u64`.
+        let path = DerivationPath::from_str("//Alice//5").unwrap();
+        let keypair = sha512::KeyPair::try_from(
+            "fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e",
+        )
+        .unwrap();
+        let derived = keypair.derive(&path).unwrap();
+        assert_eq!(
+            derived.private().to_zeroizing_vec().as_slice(),
+            H256::from("191c5109f00f77f94369b28d75d09f1eea0c60589f6b41c02c8036bb60dbb76e")
+                .as_slice()
+        );
+
+        // Soft junctions are not supported by `ed25519`.
+        let path = DerivationPath::from_str("//Alice/soft").unwrap();
+        private.derive(&path).unwrap_err();
+    }
+
     #[test]
     fn test_debug() {
         let secret = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
@@ -6,10 +6,13 @@ use crate::ed25519::public::PublicKey;
 use crate::ed25519::secret::ExpandedSecretKey;
 use crate::ed25519::signature::Signature;
 use crate::ed25519::Hasher512;
+use crate::substrate::{DerivationPath, DeriveJunction};
 use crate::traits::SigningKeyTrait;
 use crate::{KeyPairError, KeyPairResult};
 use std::fmt;
 use tw_encoding::hex;
+use tw_encoding::scale::ToScale;
+use tw_hash::blake2::blake2_b;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesZeroizing;
 use zeroize::{ZeroizeOnDrop, Zeroizing};
@@ -37,6 +40,26 @@ impl<H: Hasher512> PrivateKey<H> {
         PublicKey::with_expanded_secret(&self.expanded_key)
     }
 
+    /// Derives a child private key using the given Substrate `path`.
+    /// Only hard junctions are supported by `ed25519`.
+    ///
+    /// Ported: https://github.com/paritytech/polkadot-sdk/blob/master/substrate/primitives/core/src/ed25519.rs
+    pub fn derive(&self, path: &DerivationPath) -> KeyPairResult<Self> {
+        const HDKD_PREFIX: &[u8] = b"Ed25519HDKD";
+
+        let mut secret = self.secret;
+        for junction in path.junctions() {
+            let DeriveJunction::Hard(cc) = junction else {
+                return Err(KeyPairError::InvalidDerivationPath);
+            };
+            let encoded = Zeroizing::new((HDKD_PREFIX, secret.take(), cc.take()).to_scale());
+            let hash =
+                Zeroizing::new(blake2_b(&encoded, H256::LEN).expect("Expected a valid hash size"));
+            secret = H256::try_from(hash.as_slice()).expect("Expected a 32 byte hash");
+        }
+        Self::try_from(secret.as_slice())
+    }
+
     /// `ed25519` signing uses a public key associated with the private key.
     pub(crate) fn sign_with_public_key(
         &self,
@@ -5,9 +5,12 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::ffi::pubkey::TWPublicKey;
+use crate::substrate::DerivationPath;
 use crate::tw::{Curve, PrivateKey, PublicKeyType};
+use std::str::FromStr;
 use tw_memory::ffi::c_byte_array::CByteArray;
 use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
+use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::{try_or_else, try_or_false};
 
@@ -106,6 +109,32 @@ pub unsafe extern "C" fn tw_private_key_get_public_key_by_type(
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
 
+/// Derives a child private key using the given Substrate derivation path, e.g. `//hard/soft`.
+///
+/// \param key *non-null* pointer to the private key.
+/// \param path *non-null* derivation path string.
+/// \param curve Eliptic curve of the private key. Only `ed25519` and `sr25519` are supported.
+/// \note Should be deleted with \tw_private_key_delete.
+/// \return Nullable pointer to the derived private key.
+#[no_mangle]
+pub unsafe extern "C" fn tw_private_key_derive_substrate(
+    key: *mut TWPrivateKey,
+    path: *const TWString,
+    curve: u32,
+) -> *mut TWPrivateKey {
+    let curve = try_or_else!(Curve::from_raw(curve), std::ptr::null_mut);
+    let private = try_or_else!(TWPrivateKey::from_ptr_as_ref(key), std::ptr::null_mut);
+    let path = try_or_else!(TWString::from_ptr_as_ref(path), std::ptr::null_mut);
+    let path = try_or_else!(path.as_str(), std::ptr::null_mut);
+    let path = try_or_else!(DerivationPath::from_str(path), std::ptr::null_mut);
+
+    private
+        .0
+        .derive_substrate(&path, curve)
+        .map(|derived| TWPrivateKey(derived).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
 // #[no_mangle]
 // pub unsafe extern "C" fn tw_private_key_get_shared_key(
 //     key: *mut TWPrivateKey,
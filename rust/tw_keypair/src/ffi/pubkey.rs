@@ -4,9 +4,12 @@
 
 #![allow(clippy::missing_safety_doc)]
 
+use crate::substrate::DerivationPath;
 use crate::tw::{PublicKey, PublicKeyType};
+use std::str::FromStr;
 use tw_memory::ffi::c_byte_array::CByteArray;
 use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
+use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::{try_or_else, try_or_false};
 
@@ -82,6 +85,30 @@ pub unsafe extern "C" fn tw_public_key_data(key: *mut TWPublicKey) -> CByteArray
     CByteArray::from(public.0.to_bytes())
 }
 
+/// Derives a child public key using the given Substrate derivation path, e.g. `/soft/0`.
+/// Only `sr25519` public keys and soft junctions are supported.
+///
+/// \param key *non-null* pointer to a public key.
+/// \param path *non-null* derivation path string.
+/// \note Should be deleted with \tw_public_key_delete.
+/// \return Nullable pointer to the derived public key.
+#[no_mangle]
+pub unsafe extern "C" fn tw_public_key_derive_substrate(
+    key: *mut TWPublicKey,
+    path: *const TWString,
+) -> *mut TWPublicKey {
+    let public = try_or_else!(TWPublicKey::from_ptr_as_ref(key), std::ptr::null_mut);
+    let path = try_or_else!(TWString::from_ptr_as_ref(path), std::ptr::null_mut);
+    let path = try_or_else!(path.as_str(), std::ptr::null_mut);
+    let path = try_or_else!(DerivationPath::from_str(path), std::ptr::null_mut);
+
+    public
+        .0
+        .derive_substrate(&path)
+        .map(|derived| TWPublicKey(derived).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
 // #[no_mangle]
 // pub unsafe extern "C" fn tw_public_key_is_valid(
 //     pubkey: *const u8,
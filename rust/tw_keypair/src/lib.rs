@@ -45,7 +45,9 @@
 pub mod ecdsa;
 pub mod ed25519;
 pub mod ffi;
+pub mod sr25519;
 pub mod starkex;
+pub mod substrate;
 pub mod traits;
 pub mod tw;
 
@@ -60,6 +62,7 @@ pub enum KeyPairError {
     InvalidPublicKey,
     InvalidSignature,
     InvalidSignMessage,
+    InvalidDerivationPath,
     SignatureVerifyError,
     SigningError,
 }
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::sr25519::private::PrivateKey;
+use crate::sr25519::public::PublicKey;
+use crate::sr25519::signature::Signature;
+use crate::substrate::DerivationPath;
+use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+use crate::{KeyPairError, KeyPairResult};
+use tw_encoding::hex;
+use zeroize::Zeroizing;
+
+/// Represents a pair of `sr25519` private and public keys.
+pub struct KeyPair {
+    private: PrivateKey,
+    public: PublicKey,
+}
+
+impl KeyPair {
+    /// Derives a child key pair using the given `path`.
+    pub fn derive(&self, path: &DerivationPath) -> KeyPairResult<KeyPair> {
+        let private = self.private.derive(path)?;
+        let public = private.public();
+        Ok(KeyPair { private, public })
+    }
+}
+
+impl KeyPairTrait for KeyPair {
+    type Private = PrivateKey;
+    type Public = PublicKey;
+
+    fn public(&self) -> &Self::Public {
+        &self.public
+    }
+
+    fn private(&self) -> &Self::Private {
+        &self.private
+    }
+}
+
+impl SigningKeyTrait for KeyPair {
+    type SigningMessage = Vec<u8>;
+    type Signature = Signature;
+
+    fn sign(&self, message: Self::SigningMessage) -> KeyPairResult<Self::Signature> {
+        self.private.sign(message)
+    }
+}
+
+impl VerifyingKeyTrait for KeyPair {
+    type SigningMessage = Vec<u8>;
+    type VerifySignature = Signature;
+
+    fn verify(&self, signature: Self::VerifySignature, message: Self::SigningMessage) -> bool {
+        self.public.verify(signature, message)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for KeyPair {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let private = PrivateKey::try_from(bytes)?;
+        let public = private.public();
+        Ok(KeyPair { private, public })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for KeyPair {
+    type Error = KeyPairError;
+
+    fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
+        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        Self::try_from(bytes.as_slice())
+    }
+}
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! `sr25519` (Schnorrkel over Ristretto25519) keys and signatures used by Substrate-based chains.
+
+mod keypair;
+mod private;
+mod public;
+mod signature;
+
+pub use keypair::KeyPair;
+pub use private::PrivateKey;
+pub use public::PublicKey;
+pub use signature::Signature;
+
+/// The signing context used by Substrate.
+pub const SIGNING_CONTEXT: &[u8] = b"substrate";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::substrate::DerivationPath;
+    use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+    use std::str::FromStr;
+    use tw_encoding::hex;
+    use tw_hash::{H256, H512};
+    use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
+
+    /// The seed of the `bottom drive obey lake curtain smoke basket hold race lonely fit walk` dev phrase.
+    const DEV_SEED: &str = "fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e";
+
+    #[test]
+    fn test_private_to_public() {
+        let private = PrivateKey::try_from(DEV_SEED).unwrap();
+        let expected =
+            H256::from("46ebddef8cd9bb167dc30878d7113b7e168e6f0646beffd77d69d39bad76b47a");
+        assert_eq!(private.public().to_bytes(), expected);
+        assert_eq!(
+            private.to_zeroizing_vec().as_slice(),
+            hex::decode(DEV_SEED).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_derive_hard() {
+        let private = PrivateKey::try_from(DEV_SEED).unwrap();
+        let path = DerivationPath::from_str("//Alice").unwrap();
+        let derived = private.derive(&path).unwrap();
+
+        // `subkey inspect //Alice`
+        assert_eq!(
+            derived.to_zeroizing_vec().as_slice(),
+            hex::decode("e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a")
+                .unwrap()
+        );
+        assert_eq!(
+            derived.public().to_bytes(),
+            H256::from("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+        );
+
+        // Hard junctions cannot be applied to a public key.
+        private.public().derive(&path).unwrap_err();
+    }
+
+    #[test]
+    fn test_derive_soft() {
+        let private = PrivateKey::try_from(DEV_SEED).unwrap();
+        let path = DerivationPath::from_str("/Alice").unwrap();
+
+        let derived = private.derive(&path).unwrap();
+        let expected =
+            H256::from("d6c71059dbbe9ad2b0ed3f289738b800836eb425544ce694825285b958ca755e");
+        assert_eq!(derived.public().to_bytes(), expected);
+        // The seed is unknown after a soft derivation.
+        assert_eq!(derived.to_zeroizing_vec().len(), PrivateKey::EXPANDED_LEN);
+
+        // Soft derivation of the public key gives the same result.
+        let derived_public = private.public().derive(&path).unwrap();
+        assert_eq!(derived_public.to_bytes(), expected);
+
+        // The expanded secret key can be restored.
+        let restored = PrivateKey::try_from(derived.to_zeroizing_vec().as_slice()).unwrap();
+        assert_eq!(restored.public().to_bytes(), expected);
+    }
+
+    #[test]
+    fn test_derive_mixed() {
+        let keypair = KeyPair::try_from(DEV_SEED).unwrap();
+        let path = DerivationPath::from_str("//Alice/0//polkadot").unwrap();
+        let derived = keypair.derive(&path).unwrap();
+
+        let step_by_step = ["//Alice", "/0", "//polkadot"].into_iter().fold(
+            PrivateKey::try_from(DEV_SEED).unwrap(),
+            |key, path| {
+                key.derive(&DerivationPath::from_str(path).unwrap())
+                    .unwrap()
+            },
+        );
+        assert_eq!(derived.public(), &step_by_step.public());
+        // The last junction is hard, so the seed is known.
+        assert_eq!(
+            derived.private().to_zeroizing_vec().len(),
+            PrivateKey::SEED_LEN
+        );
+    }
+
+    #[test]
+    fn test_keypair_sign_verify() {
+        let keypair = KeyPair::try_from(DEV_SEED).unwrap();
+        let message = b"Hello, Substrate!".to_vec();
+
+        let signature = keypair.sign(message.clone()).unwrap();
+        assert!(keypair.verify(signature.clone(), message.clone()));
+
+        // Signatures are randomized, but every signature must be valid.
+        let another = keypair.sign(message.clone()).unwrap();
+        assert_ne!(signature.to_bytes(), another.to_bytes());
+        assert!(keypair.public().verify(another, message));
+
+        assert!(!keypair.verify(signature, b"Another message".to_vec()));
+    }
+
+    #[test]
+    fn test_verify_with_other_key() {
+        let keypair = KeyPair::try_from(DEV_SEED).unwrap();
+        let other =
+            PublicKey::try_from("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                .unwrap();
+
+        let message = b"Hello, Substrate!".to_vec();
+        let signature = keypair.sign(message.clone()).unwrap();
+        let signature = Signature::try_from(signature.to_vec().as_slice()).unwrap();
+        assert!(!other.verify(signature, message));
+    }
+
+    #[test]
+    fn test_signature_without_marker() {
+        // Schnorrkel signatures must have the highest bit of the last byte set.
+        assert!(Signature::try_from(H512::default().as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_keypair_from_invalid_bytes() {
+        let invalid = [0; 1];
+        assert!(KeyPair::try_from(&invalid[..]).is_err());
+        assert!(PrivateKey::try_from(&invalid[..]).is_err());
+        assert!(PublicKey::try_from(&invalid[..]).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::sr25519::public::PublicKey;
+use crate::sr25519::signature::Signature;
+use crate::sr25519::SIGNING_CONTEXT;
+use crate::substrate::{DerivationPath, DeriveJunction};
+use crate::traits::SigningKeyTrait;
+use crate::{KeyPairError, KeyPairResult};
+use schnorrkel::derive::{ChainCode, Derivation};
+use schnorrkel::{ExpansionMode, MiniSecretKey, SecretKey};
+use tw_encoding::hex;
+use tw_misc::traits::ToBytesZeroizing;
+use zeroize::Zeroizing;
+
+/// Represents an `sr25519` (Schnorrkel) private key.
+///
+/// The key can be created from either a 32 byte seed (aka mini secret key), the way `subkey` and `Polkadot.js` do,
+/// or a 64 byte expanded secret key. Note a key derived through a soft junction can only be represented as an expanded one.
+pub struct PrivateKey {
+    /// The seed the [`PrivateKey::secret`] is expanded from, if known.
+    seed: Option<MiniSecretKey>,
+    secret: SecretKey,
+}
+
+/// cbindgen:ignore
+impl PrivateKey {
+    /// The number of bytes in a seed.
+    pub const SEED_LEN: usize = 32;
+    /// The number of bytes in an expanded secret key.
+    pub const EXPANDED_LEN: usize = 64;
+
+    /// Returns an associated `sr25519` public key.
+    pub fn public(&self) -> PublicKey {
+        PublicKey::new(self.secret.to_public())
+    }
+
+    /// Derives a child private key using the given `path`.
+    ///
+    /// Ported: https://github.com/paritytech/polkadot-sdk/blob/master/substrate/primitives/core/src/sr25519.rs
+    pub fn derive(&self, path: &DerivationPath) -> KeyPairResult<PrivateKey> {
+        let mut seed = self.seed.clone();
+        let mut secret = self.secret.clone();
+
+        for junction in path.junctions() {
+            match junction {
+                DeriveJunction::Soft(cc) => {
+                    secret = secret.derived_key_simple(ChainCode(cc.take()), b"").0;
+                    seed = None;
+                },
+                DeriveJunction::Hard(cc) => {
+                    let mini = secret
+                        .hard_derive_mini_secret_key(Some(ChainCode(cc.take())), b"")
+                        .0;
+                    secret = mini.expand(ExpansionMode::Ed25519);
+                    seed = Some(mini);
+                },
+            }
+        }
+        Ok(PrivateKey { seed, secret })
+    }
+}
+
+impl SigningKeyTrait for PrivateKey {
+    type SigningMessage = Vec<u8>;
+    type Signature = Signature;
+
+    fn sign(&self, message: Self::SigningMessage) -> KeyPairResult<Self::Signature> {
+        let public = self.secret.to_public();
+        let signature = self.secret.sign_simple(SIGNING_CONTEXT, &message, &public);
+        Ok(Signature::new(signature))
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PrivateKey {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        match bytes.len() {
+            Self::SEED_LEN => {
+                let seed =
+                    MiniSecretKey::from_bytes(bytes).map_err(|_| KeyPairError::InvalidSecretKey)?;
+                let secret = seed.expand(ExpansionMode::Ed25519);
+                Ok(PrivateKey {
+                    seed: Some(seed),
+                    secret,
+                })
+            },
+            Self::EXPANDED_LEN => {
+                let secret =
+                    SecretKey::from_bytes(bytes).map_err(|_| KeyPairError::InvalidSecretKey)?;
+                Ok(PrivateKey { seed: None, secret })
+            },
+            _ => Err(KeyPairError::InvalidSecretKey),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PrivateKey {
+    type Error = KeyPairError;
+
+    fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
+        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl ToBytesZeroizing for PrivateKey {
+    /// Returns the seed if known, or the expanded secret key otherwise.
+    fn to_zeroizing_vec(&self) -> Zeroizing<Vec<u8>> {
+        match self.seed {
+            Some(ref seed) => Zeroizing::new(seed.to_bytes().to_vec()),
+            None => Zeroizing::new(self.secret.to_bytes().to_vec()),
+        }
+    }
+}
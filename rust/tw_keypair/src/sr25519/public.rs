@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::sr25519::signature::Signature;
+use crate::sr25519::SIGNING_CONTEXT;
+use crate::substrate::{DerivationPath, DeriveJunction};
+use crate::traits::VerifyingKeyTrait;
+use crate::{KeyPairError, KeyPairResult};
+use schnorrkel::derive::{ChainCode, Derivation};
+use tw_encoding::hex;
+use tw_hash::H256;
+use tw_misc::traits::ToBytesVec;
+
+/// Represents an `sr25519` (Schnorrkel) public key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKey {
+    public: schnorrkel::PublicKey,
+}
+
+/// cbindgen:ignore
+impl PublicKey {
+    /// The number of bytes in a serialized public key.
+    pub const LEN: usize = H256::LEN;
+
+    /// Creates a public key from the given [`schnorrkel::PublicKey`].
+    pub(crate) fn new(public: schnorrkel::PublicKey) -> PublicKey {
+        PublicKey { public }
+    }
+
+    /// Returns the raw data of the public key (32 bytes).
+    pub fn to_bytes(&self) -> H256 {
+        H256::from(self.public.to_bytes())
+    }
+
+    /// Derives a child public key using the given `path`.
+    /// Only soft junctions can be applied to a public key.
+    pub fn derive(&self, path: &DerivationPath) -> KeyPairResult<PublicKey> {
+        let mut public = self.public;
+        for junction in path.junctions() {
+            match junction {
+                DeriveJunction::Soft(cc) => {
+                    public = public.derived_key_simple(ChainCode(cc.take()), b"").0;
+                },
+                DeriveJunction::Hard(_) => return Err(KeyPairError::InvalidDerivationPath),
+            }
+        }
+        Ok(PublicKey { public })
+    }
+}
+
+impl VerifyingKeyTrait for PublicKey {
+    type SigningMessage = Vec<u8>;
+    type VerifySignature = Signature;
+
+    fn verify(&self, signature: Self::VerifySignature, message: Self::SigningMessage) -> bool {
+        self.public
+            .verify_simple(SIGNING_CONTEXT, &message, signature.inner())
+            .is_ok()
+    }
+}
+
+impl ToBytesVec for PublicKey {
+    fn to_vec(&self) -> Vec<u8> {
+        self.public.to_bytes().to_vec()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PublicKey {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let public =
+            schnorrkel::PublicKey::from_bytes(bytes).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        Ok(PublicKey { public })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PublicKey {
+    type Error = KeyPairError;
+
+    fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
+        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        Self::try_from(bytes.as_slice())
+    }
+}
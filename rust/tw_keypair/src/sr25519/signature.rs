@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::KeyPairError;
+use tw_hash::H512;
+use tw_misc::traits::ToBytesVec;
+
+/// Represents an `sr25519` (Schnorrkel) signature.
+#[derive(Clone, Debug)]
+pub struct Signature {
+    signature: schnorrkel::Signature,
+}
+
+/// cbindgen:ignore
+impl Signature {
+    /// The number of bytes for a serialized signature representation.
+    pub const LEN: usize = H512::LEN;
+
+    /// Creates a signature from the given [`schnorrkel::Signature`].
+    pub(crate) fn new(signature: schnorrkel::Signature) -> Signature {
+        Signature { signature }
+    }
+
+    /// Returns a reference to the inner [`schnorrkel::Signature`].
+    pub(crate) fn inner(&self) -> &schnorrkel::Signature {
+        &self.signature
+    }
+
+    /// Returns the signature data (64 bytes).
+    pub fn to_bytes(&self) -> H512 {
+        H512::from(self.signature.to_bytes())
+    }
+}
+
+impl ToBytesVec for Signature {
+    fn to_vec(&self) -> Vec<u8> {
+        self.to_bytes().into_vec()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Signature {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let signature =
+            schnorrkel::Signature::from_bytes(bytes).map_err(|_| KeyPairError::InvalidSignature)?;
+        Ok(Signature { signature })
+    }
+}
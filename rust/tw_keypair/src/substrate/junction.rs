@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::KeyPairError;
+use std::str::FromStr;
+use tw_encoding::scale::ToScale;
+use tw_hash::blake2::blake2_b;
+use tw_hash::H256;
+
+/// The length of a junction chain code.
+const CHAIN_CODE_LEN: usize = H256::LEN;
+
+/// A single step of the derivation path.
+/// Source: https://github.com/paritytech/polkadot-sdk/blob/master/substrate/primitives/core/src/crypto.rs
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeriveJunction {
+    /// Soft (vanilla) derivation. Public keys have a correspondent derivation.
+    Soft(H256),
+    /// Hard ("hardened") derivation. Public keys do not have a correspondent derivation.
+    Hard(H256),
+}
+
+impl DeriveJunction {
+    /// Creates a soft junction from the given SCALE-encodable `index`.
+    pub fn soft<T: ToScale + ?Sized>(index: &T) -> DeriveJunction {
+        DeriveJunction::Soft(chain_code(index))
+    }
+
+    /// Creates a hard junction from the given SCALE-encodable `index`.
+    pub fn hard<T: ToScale + ?Sized>(index: &T) -> DeriveJunction {
+        DeriveJunction::Hard(chain_code(index))
+    }
+
+    /// Returns the chain code of the junction.
+    pub fn chain_code(&self) -> H256 {
+        match self {
+            DeriveJunction::Soft(cc) | DeriveJunction::Hard(cc) => *cc,
+        }
+    }
+
+    /// Returns whether the junction is hard.
+    pub fn is_hard(&self) -> bool {
+        matches!(self, DeriveJunction::Hard(_))
+    }
+
+    /// Parses a junction code, i.e. the part of the path between slashes.
+    /// Numeric codes are encoded as `u64`, other codes are encoded as strings.
+    fn from_code(code: &str, hard: bool) -> DeriveJunction {
+        match (code.parse::<u64>(), hard) {
+            (Ok(index), true) => DeriveJunction::hard(&index),
+            (Ok(index), false) => DeriveJunction::soft(&index),
+            (Err(_), true) => DeriveJunction::hard(code.as_bytes()),
+            (Err(_), false) => DeriveJunction::soft(code.as_bytes()),
+        }
+    }
+}
+
+/// A sequence of junctions, e.g. `//polkadot//0/1`.
+///
+/// Note that the `///password` part of a secret URI is not supported.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DerivationPath {
+    junctions: Vec<DeriveJunction>,
+}
+
+impl DerivationPath {
+    /// Creates a derivation path from the given junctions.
+    pub fn new(junctions: Vec<DeriveJunction>) -> DerivationPath {
+        DerivationPath { junctions }
+    }
+
+    /// Returns the junctions of the path.
+    pub fn junctions(&self) -> &[DeriveJunction] {
+        &self.junctions
+    }
+
+    /// Returns whether the path consists of soft junctions only.
+    pub fn is_soft(&self) -> bool {
+        self.junctions.iter().all(|junction| !junction.is_hard())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = KeyPairError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut parts = path.split('/');
+        // The path must be empty or start with a slash.
+        if parts.next() != Some("") {
+            return Err(KeyPairError::InvalidDerivationPath);
+        }
+
+        let mut junctions = Vec::new();
+        let mut hard = false;
+        for part in parts {
+            if part.is_empty() {
+                // `///` (password) or `//` at the end of the path.
+                if hard {
+                    return Err(KeyPairError::InvalidDerivationPath);
+                }
+                hard = true;
+                continue;
+            }
+            junctions.push(DeriveJunction::from_code(part, hard));
+            hard = false;
+        }
+
+        // The path must not end with a slash.
+        if hard || path.ends_with('/') {
+            return Err(KeyPairError::InvalidDerivationPath);
+        }
+        Ok(DerivationPath { junctions })
+    }
+}
+
+/// Returns the encoded `index` if it fits the chain code, or its `blake2b-256` hash otherwise.
+fn chain_code<T: ToScale + ?Sized>(index: &T) -> H256 {
+    let encoded = index.to_scale();
+
+    let mut cc = H256::default();
+    if encoded.len() > CHAIN_CODE_LEN {
+        let hash = blake2_b(&encoded, CHAIN_CODE_LEN).expect("Expected a valid hash size");
+        cc.copy_from_slice(&hash);
+    } else {
+        cc[..encoded.len()].copy_from_slice(&encoded);
+    }
+    cc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_derivation_path() {
+        let path = DerivationPath::from_str("//Alice/0//polkadot").unwrap();
+        assert_eq!(
+            path.junctions(),
+            &[
+                DeriveJunction::Hard(H256::from(
+                    "14416c6963650000000000000000000000000000000000000000000000000000"
+                )),
+                DeriveJunction::Soft(H256::default()),
+                DeriveJunction::Hard(H256::from(
+                    "20706f6c6b61646f740000000000000000000000000000000000000000000000"
+                )),
+            ]
+        );
+        assert!(!path.is_soft());
+
+        let path = DerivationPath::from_str("/5").unwrap();
+        assert_eq!(
+            path.junctions(),
+            &[DeriveJunction::Soft(H256::from(
+                "0500000000000000000000000000000000000000000000000000000000000000"
+            ))]
+        );
+        assert!(path.is_soft());
+
+        let path = DerivationPath::from_str("").unwrap();
+        assert!(path.junctions().is_empty());
+    }
+
+    #[test]
+    fn test_parse_derivation_path_long_junction() {
+        let code = "a".repeat(32);
+        let path = DerivationPath::from_str(&format!("//{code}")).unwrap();
+        let expected = blake2_b(&code.as_bytes().to_scale(), 32).unwrap();
+        assert_eq!(path.junctions()[0].chain_code().as_slice(), expected);
+    }
+
+    #[test]
+    fn test_parse_derivation_path_invalid() {
+        for path in ["Alice", "//Alice///password", "//Alice/", "//", "/", "///"] {
+            DerivationPath::from_str(path).unwrap_err();
+        }
+    }
+}
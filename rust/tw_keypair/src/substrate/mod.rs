@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Substrate-compatible key derivation, i.e. `//hard/soft` junction paths used by `subkey` and `Polkadot.js`.
+
+mod junction;
+
+pub use junction::{DerivationPath, DeriveJunction};
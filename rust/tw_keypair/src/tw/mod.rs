@@ -25,6 +25,8 @@ pub enum Curve {
     /// Cardano blockchain specific `ed25519` extended key.
     Ed25519ExtendedCardano = 5,
     Starkex = 6,
+    /// Substrate `sr25519` (Schnorrkel) curve.
+    Sr25519 = 7,
 }
 
 impl Curve {
@@ -38,6 +40,7 @@ impl Curve {
             4 => Some(Curve::Nist256p1),
             5 => Some(Curve::Ed25519ExtendedCardano),
             6 => Some(Curve::Starkex),
+            7 => Some(Curve::Sr25519),
             _ => None,
         }
     }
@@ -67,6 +70,9 @@ pub enum PublicKeyType {
     Ed25519ExtendedCardano = 7,
     #[serde(rename = "starkex")]
     Starkex = 8,
+    /// Substrate `sr25519` (Schnorrkel) public key.
+    #[serde(rename = "sr25519")]
+    Sr25519 = 9,
 }
 
 impl PublicKeyType {
@@ -82,6 +88,7 @@ impl PublicKeyType {
             6 => Some(PublicKeyType::Curve25519Waves),
             7 => Some(PublicKeyType::Ed25519ExtendedCardano),
             8 => Some(PublicKeyType::Starkex),
+            9 => Some(PublicKeyType::Sr25519),
             _ => None,
         }
     }
@@ -101,7 +108,8 @@ mod tests {
             (4, Some(Curve::Nist256p1)),
             (5, Some(Curve::Ed25519ExtendedCardano)),
             (6, Some(Curve::Starkex)),
-            (7, None),
+            (7, Some(Curve::Sr25519)),
+            (8, None),
         ];
         for (raw, expected) in tests {
             assert_eq!(Curve::from_raw(raw), expected);
@@ -120,7 +128,8 @@ mod tests {
             (6, Some(PublicKeyType::Curve25519Waves)),
             (7, Some(PublicKeyType::Ed25519ExtendedCardano)),
             (8, Some(PublicKeyType::Starkex)),
-            (9, None),
+            (9, Some(PublicKeyType::Sr25519)),
+            (10, None),
         ];
         for (raw, expected) in tests {
             assert_eq!(PublicKeyType::from_raw(raw), expected);
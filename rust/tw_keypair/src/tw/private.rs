@@ -3,12 +3,13 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ecdsa::{nist256p1, secp256k1};
+use crate::substrate::DerivationPath;
 use crate::traits::SigningKeyTrait;
 use crate::tw::{Curve, PublicKey, PublicKeyType};
-use crate::{ed25519, starkex, KeyPairError, KeyPairResult};
+use crate::{ed25519, sr25519, starkex, KeyPairError, KeyPairResult};
 use std::ops::Range;
 use tw_hash::H256;
-use tw_misc::traits::ToBytesVec;
+use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
 use zeroize::ZeroizeOnDrop;
 
 /// Represents a private key that can be used to sign messages with different elliptic curves.
@@ -24,13 +25,15 @@ impl PrivateKey {
     /// The number of bytes in a private key.
     const SIZE: usize = 32;
     const CARDANO_SIZE: usize = ed25519::cardano::ExtendedPrivateKey::LEN;
+    const SR25519_EXPANDED_SIZE: usize = sr25519::PrivateKey::EXPANDED_LEN;
 
     const KEY_RANGE: Range<usize> = 0..Self::SIZE;
     const EXTENDED_CARDANO_RANGE: Range<usize> = 0..Self::CARDANO_SIZE;
 
     /// Validates the given `bytes` secret and creates a private key.
+    /// Please note that a 64 byte expanded `sr25519` secret can be used with [`Curve::Sr25519`] only.
     pub fn new(bytes: Vec<u8>) -> KeyPairResult<PrivateKey> {
-        if !Self::is_valid_general(&bytes) {
+        if !Self::is_valid_general(&bytes) && !Self::is_valid_sr25519_expanded(&bytes) {
             return Err(KeyPairError::InvalidSecretKey);
         }
         Ok(PrivateKey { bytes })
@@ -63,8 +66,16 @@ impl PrivateKey {
         !bytes.iter().all(|byte| *byte == 0)
     }
 
+    /// Checks if the given `bytes` secret is a valid 64 byte expanded `sr25519` secret key.
+    fn is_valid_sr25519_expanded(bytes: &[u8]) -> bool {
+        bytes.len() == Self::SR25519_EXPANDED_SIZE && !bytes.iter().all(|byte| *byte == 0)
+    }
+
     /// Checks if the given `bytes` secret is valid.
     pub fn is_valid(bytes: &[u8], curve: Curve) -> bool {
+        if matches!(curve, Curve::Sr25519) && Self::is_valid_sr25519_expanded(bytes) {
+            return sr25519::PrivateKey::try_from(bytes).is_ok();
+        }
         if !Self::is_valid_general(bytes) {
             return false;
         }
@@ -85,6 +96,7 @@ impl PrivateKey {
                     .is_ok()
             },
             Curve::Starkex => starkex::PrivateKey::try_from(&bytes[Self::KEY_RANGE]).is_ok(),
+            Curve::Sr25519 => sr25519::PrivateKey::try_from(&bytes[Self::KEY_RANGE]).is_ok(),
         }
     }
 
@@ -109,6 +121,7 @@ impl PrivateKey {
                 sign_impl(self.to_ed25519_extended_cardano()?, message)
             },
             Curve::Starkex => sign_impl(self.to_starkex_privkey()?, message),
+            Curve::Sr25519 => sign_impl(self.to_sr25519()?, message),
         }
     }
 
@@ -153,32 +166,68 @@ impl PrivateKey {
                 let privkey = self.to_starkex_privkey()?;
                 Ok(PublicKey::Starkex(privkey.public()))
             },
+            PublicKeyType::Sr25519 => {
+                let privkey = self.to_sr25519()?;
+                Ok(PublicKey::Sr25519(privkey.public()))
+            },
         }
     }
 
+    /// Derives a child private key using the given Substrate `path` and elliptic curve.
+    /// Only `ed25519` (hard junctions only) and `sr25519` curves are supported.
+    pub fn derive_substrate(
+        &self,
+        path: &DerivationPath,
+        curve: Curve,
+    ) -> KeyPairResult<PrivateKey> {
+        let derived = match curve {
+            Curve::Ed25519 => self.to_ed25519()?.derive(path)?.to_zeroizing_vec(),
+            Curve::Sr25519 => self.to_sr25519()?.derive(path)?.to_zeroizing_vec(),
+            _ => return Err(KeyPairError::InvalidDerivationPath),
+        };
+        PrivateKey::new(derived.to_vec())
+    }
+
+    /// Returns [`PrivateKey::key`] if the private key is not an expanded `sr25519` secret.
+    /// A 64 byte expanded `sr25519` secret cannot be used with other curves.
+    fn general_key(&self) -> KeyPairResult<H256> {
+        if self.bytes.len() == Self::SR25519_EXPANDED_SIZE {
+            return Err(KeyPairError::InvalidSecretKey);
+        }
+        Ok(self.key())
+    }
+
+    /// Returns the `sr25519` secret: either a 32 byte seed or a 64 byte expanded secret key.
+    fn sr25519_key(&self) -> &[u8] {
+        if self.bytes.len() == Self::SR25519_EXPANDED_SIZE {
+            return &self.bytes;
+        }
+        &self.bytes[Self::KEY_RANGE]
+    }
+
     /// Tries to convert [`PrivateKey::key`] to [`secp256k1::PrivateKey`].
     fn to_secp256k1_privkey(&self) -> KeyPairResult<secp256k1::PrivateKey> {
-        secp256k1::PrivateKey::try_from(self.key().as_slice())
+        secp256k1::PrivateKey::try_from(self.general_key()?.as_slice())
     }
 
     /// Tries to convert [`PrivateKey::key`] to [`nist256p1::PrivateKey`].
     fn to_nist256p1_privkey(&self) -> KeyPairResult<nist256p1::PrivateKey> {
-        nist256p1::PrivateKey::try_from(self.key().as_slice())
+        nist256p1::PrivateKey::try_from(self.general_key()?.as_slice())
     }
 
     /// Tries to convert [`PrivateKey::key`] to [`ed25519::sha512::PrivateKey`].
     fn to_ed25519(&self) -> KeyPairResult<ed25519::sha512::PrivateKey> {
-        ed25519::sha512::PrivateKey::try_from(self.key().as_slice())
+        ed25519::sha512::PrivateKey::try_from(self.general_key()?.as_slice())
     }
 
     /// Tries to convert [`PrivateKey::key`] to [`ed25519::blake2b::PrivateKey`].
     fn to_ed25519_blake2b(&self) -> KeyPairResult<ed25519::blake2b::PrivateKey> {
-        ed25519::blake2b::PrivateKey::try_from(self.key().as_slice())
+        ed25519::blake2b::PrivateKey::try_from(self.general_key()?.as_slice())
     }
 
     /// Tries to convert [`PrivateKey::key`] to [`ed25519::waves::PrivateKey`].
     fn to_curve25519_waves(&self) -> KeyPairResult<ed25519::waves::PrivateKey> {
-        ed25519::waves::PrivateKey::try_from(self.key().as_slice())
+        ed25519::waves::PrivateKey::try_from(self.general_key()?.as_slice())
     }
 
     /// Tries to convert [`PrivateKey::extended_cardano_key`] to [`ed25519::cardano::ExtendedPrivateKey`].
@@ -188,6 +237,11 @@ impl PrivateKey {
 
     /// Tries to convert [`PrivateKey::key`] to [`starkex::PrivateKey`].
     fn to_starkex_privkey(&self) -> KeyPairResult<starkex::PrivateKey> {
-        starkex::PrivateKey::try_from(self.key().as_slice())
+        starkex::PrivateKey::try_from(self.general_key()?.as_slice())
+    }
+
+    /// Tries to convert [`PrivateKey::bytes`] to [`sr25519::PrivateKey`].
+    fn to_sr25519(&self) -> KeyPairResult<sr25519::PrivateKey> {
+        sr25519::PrivateKey::try_from(self.sr25519_key())
     }
 }
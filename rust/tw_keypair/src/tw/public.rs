@@ -3,9 +3,10 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ecdsa::{nist256p1, secp256k1};
+use crate::substrate::DerivationPath;
 use crate::traits::VerifyingKeyTrait;
 use crate::tw::PublicKeyType;
-use crate::{ed25519, starkex, KeyPairError, KeyPairResult};
+use crate::{ed25519, sr25519, starkex, KeyPairError, KeyPairResult};
 use tw_misc::traits::ToBytesVec;
 use tw_misc::try_or_false;
 
@@ -21,6 +22,7 @@ pub enum PublicKey {
     Curve25519Waves(ed25519::waves::PublicKey),
     Ed25519ExtendedCardano(Box<ed25519::cardano::ExtendedPublicKey>),
     Starkex(starkex::PublicKey),
+    Sr25519(sr25519::PublicKey),
 }
 
 impl PublicKey {
@@ -69,6 +71,10 @@ impl PublicKey {
                 let pubkey = starkex::PublicKey::try_from(bytes.as_slice())?;
                 Ok(PublicKey::Starkex(pubkey))
             },
+            PublicKeyType::Sr25519 if sr25519::PublicKey::LEN == bytes.len() => {
+                let pubkey = sr25519::PublicKey::try_from(bytes.as_slice())?;
+                Ok(PublicKey::Sr25519(pubkey))
+            },
             _ => Err(KeyPairError::InvalidPublicKey),
         }
     }
@@ -106,6 +112,7 @@ impl PublicKey {
                 verify_impl(cardano.as_ref(), sig, message)
             },
             PublicKey::Starkex(stark) => verify_impl(stark, sig, message),
+            PublicKey::Sr25519(sr) => verify_impl(sr, sig, message),
         }
     }
 
@@ -121,6 +128,7 @@ impl PublicKey {
             PublicKey::Curve25519Waves(waves) => waves.to_vec(),
             PublicKey::Ed25519ExtendedCardano(cardano) => cardano.to_vec(),
             PublicKey::Starkex(stark) => stark.to_vec(),
+            PublicKey::Sr25519(sr) => sr.to_vec(),
         }
     }
 
@@ -141,6 +149,31 @@ impl PublicKey {
         }
     }
 
+    /// Returns a Cardano extended `ed25519` public key if the key type is matched.
+    pub fn to_ed25519_extended_cardano(&self) -> Option<&ed25519::cardano::ExtendedPublicKey> {
+        match self {
+            PublicKey::Ed25519ExtendedCardano(cardano) => Some(cardano),
+            _ => None,
+        }
+    }
+
+    /// Returns an `sr25519` public key if the key type is matched.
+    pub fn to_sr25519(&self) -> Option<&sr25519::PublicKey> {
+        match self {
+            PublicKey::Sr25519(sr25519) => Some(sr25519),
+            _ => None,
+        }
+    }
+
+    /// Derives a child public key using the given Substrate `path`.
+    /// Only `sr25519` public keys and soft junctions are supported.
+    pub fn derive_substrate(&self, path: &DerivationPath) -> KeyPairResult<PublicKey> {
+        match self {
+            PublicKey::Sr25519(sr25519) => Ok(PublicKey::Sr25519(sr25519.derive(path)?)),
+            _ => Err(KeyPairError::InvalidDerivationPath),
+        }
+    }
+
     /// Returns a public key type.
     pub fn public_key_type(&self) -> PublicKeyType {
         match self {
@@ -153,6 +186,7 @@ impl PublicKey {
             PublicKey::Curve25519Waves(_) => PublicKeyType::Curve25519Waves,
             PublicKey::Ed25519ExtendedCardano(_) => PublicKeyType::Ed25519ExtendedCardano,
             PublicKey::Starkex(_) => PublicKeyType::Starkex,
+            PublicKey::Sr25519(_) => PublicKeyType::Sr25519,
         }
     }
 }
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::str::FromStr;
+use tw_encoding::hex;
+use tw_keypair::ffi::privkey::{
+    tw_private_key_delete, tw_private_key_derive_substrate, tw_private_key_get_public_key_by_type,
+};
+use tw_keypair::ffi::pubkey::{
+    tw_public_key_data, tw_public_key_delete, tw_public_key_derive_substrate,
+};
+use tw_keypair::sr25519;
+use tw_keypair::substrate::DerivationPath;
+use tw_keypair::test_utils::tw_private_key_helper::TWPrivateKeyHelper;
+use tw_keypair::test_utils::tw_public_key_helper::TWPublicKeyHelper;
+use tw_keypair::tw::{Curve, PrivateKey, PublicKey, PublicKeyType};
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use tw_misc::traits::ToBytesZeroizing;
+
+/// The seed of the `bottom drive obey lake curtain smoke basket hold race lonely fit walk` dev phrase.
+const DEV_SEED: &str = "fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e";
+
+/// Derives a private key via FFI and returns its public key data, or `None` if the derivation failed.
+fn derive_private_to_public(path: &str, curve: Curve, ty: PublicKeyType) -> Option<String> {
+    let tw_privkey = TWPrivateKeyHelper::with_hex(DEV_SEED);
+    let path = TWStringHelper::create(path);
+
+    let derived =
+        unsafe { tw_private_key_derive_substrate(tw_privkey.ptr(), path.ptr(), curve as u32) };
+    if derived.is_null() {
+        return None;
+    }
+
+    let public = unsafe { tw_private_key_get_public_key_by_type(derived, ty as u32) };
+    let public_data = unsafe { tw_public_key_data(public).into_vec() };
+    unsafe {
+        tw_public_key_delete(public);
+        tw_private_key_delete(derived);
+    };
+    Some(hex::encode(public_data, false))
+}
+
+/// Derives a public key via FFI and returns its data, or `None` if the derivation failed.
+fn derive_public(public: &str, path: &str) -> Option<String> {
+    let tw_public = TWPublicKeyHelper::with_hex(public, PublicKeyType::Sr25519);
+    let path = TWStringHelper::create(path);
+
+    let derived = unsafe { tw_public_key_derive_substrate(tw_public.ptr(), path.ptr()) };
+    if derived.is_null() {
+        return None;
+    }
+
+    let public_data = unsafe { tw_public_key_data(derived).into_vec() };
+    unsafe { tw_public_key_delete(derived) };
+    Some(hex::encode(public_data, false))
+}
+
+#[test]
+fn test_sr25519_tw_private_key() {
+    let privkey = PrivateKey::new(hex::decode(DEV_SEED).unwrap()).unwrap();
+    let public = privkey
+        .get_public_key_by_type(PublicKeyType::Sr25519)
+        .unwrap();
+    assert_eq!(
+        hex::encode(public.to_bytes(), false),
+        "46ebddef8cd9bb167dc30878d7113b7e168e6f0646beffd77d69d39bad76b47a"
+    );
+}
+
+#[test]
+fn test_sr25519_tw_private_key_sign_verify() {
+    let privkey = PrivateKey::new(hex::decode(DEV_SEED).unwrap()).unwrap();
+    let public = privkey
+        .get_public_key_by_type(PublicKeyType::Sr25519)
+        .unwrap();
+
+    let message = b"Hello, Substrate!";
+    let signature = privkey.sign(message, Curve::Sr25519).unwrap();
+    assert_eq!(signature.len(), 64);
+    assert!(public.verify(&signature, message));
+    assert!(!public.verify(&signature, b"Another message"));
+
+    let public = PublicKey::new(public.to_bytes(), PublicKeyType::Sr25519).unwrap();
+    assert!(public.verify(&signature, message));
+}
+
+#[test]
+fn test_sr25519_tw_private_key_derive() {
+    let privkey = PrivateKey::new(hex::decode(DEV_SEED).unwrap()).unwrap();
+
+    // The soft-derived key is represented as a 64 byte expanded secret key.
+    let path = DerivationPath::from_str("/Alice").unwrap();
+    let derived = privkey.derive_substrate(&path, Curve::Sr25519).unwrap();
+    let public = derived
+        .get_public_key_by_type(PublicKeyType::Sr25519)
+        .unwrap();
+    assert_eq!(
+        hex::encode(public.to_bytes(), false),
+        "d6c71059dbbe9ad2b0ed3f289738b800836eb425544ce694825285b958ca755e"
+    );
+
+    let signature = derived.sign(b"message", Curve::Sr25519).unwrap();
+    assert!(public.verify(&signature, b"message"));
+}
+
+#[test]
+fn test_sr25519_tw_private_key_expanded_other_curves() {
+    let path = DerivationPath::from_str("/Alice").unwrap();
+    let expanded = sr25519::PrivateKey::try_from(DEV_SEED)
+        .unwrap()
+        .derive(&path)
+        .unwrap()
+        .to_zeroizing_vec();
+    assert_eq!(expanded.len(), 64);
+
+    // The expanded secret is valid for `sr25519` only.
+    assert!(PrivateKey::is_valid(&expanded, Curve::Sr25519));
+    assert!(!PrivateKey::is_valid(&expanded, Curve::Secp256k1));
+    assert!(!PrivateKey::is_valid(&expanded, Curve::Ed25519));
+    assert!(!PrivateKey::is_valid_general(&expanded));
+
+    // The expanded secret must not be truncated to be used with other curves.
+    let derived = PrivateKey::new(expanded.to_vec()).unwrap();
+    assert!(derived.sign(b"message", Curve::Sr25519).is_ok());
+    assert!(derived.sign(b"message", Curve::Ed25519).is_err());
+    assert!(derived
+        .get_public_key_by_type(PublicKeyType::Secp256k1)
+        .is_err());
+    assert!(derived.derive_substrate(&path, Curve::Ed25519).is_err());
+}
+
+#[test]
+fn test_tw_private_key_derive_substrate_ffi() {
+    // `subkey inspect //Alice`
+    assert_eq!(
+        derive_private_to_public("//Alice", Curve::Sr25519, PublicKeyType::Sr25519).unwrap(),
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+    );
+    // `subkey inspect --scheme ed25519 //Alice`
+    assert_eq!(
+        derive_private_to_public("//Alice", Curve::Ed25519, PublicKeyType::Ed25519).unwrap(),
+        "88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee"
+    );
+
+    // Soft junctions are not supported by `ed25519`.
+    assert!(
+        derive_private_to_public("//Alice/soft", Curve::Ed25519, PublicKeyType::Ed25519).is_none()
+    );
+    // Only `ed25519` and `sr25519` are supported.
+    assert!(
+        derive_private_to_public("//Alice", Curve::Secp256k1, PublicKeyType::Secp256k1).is_none()
+    );
+    // Invalid path.
+    assert!(derive_private_to_public("Alice", Curve::Sr25519, PublicKeyType::Sr25519).is_none());
+}
+
+#[test]
+fn test_tw_public_key_derive_substrate_ffi() {
+    let public = "46ebddef8cd9bb167dc30878d7113b7e168e6f0646beffd77d69d39bad76b47a";
+    assert_eq!(
+        derive_public(public, "/Alice").unwrap(),
+        "d6c71059dbbe9ad2b0ed3f289738b800836eb425544ce694825285b958ca755e"
+    );
+
+    // Hard junctions cannot be applied to a public key.
+    assert!(derive_public(public, "//Alice").is_none());
+}
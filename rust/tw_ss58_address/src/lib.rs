@@ -0,0 +1,305 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! SS58 address format used by Substrate-based chains.
+//! https://docs.substrate.io/reference/address-formats/
+
+use crate::ss58_prefix::SS58Prefix;
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::{AddressError, AddressResult};
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::blake2::blake2_b;
+use tw_hash::H256;
+use tw_keypair::tw::PublicKey;
+use tw_memory::Data;
+
+pub mod ss58_prefix;
+
+const CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+const CHECKSUM_SIZE: usize = 2;
+const CHECKSUM_HASH_SIZE: usize = 64;
+
+/// Networks 0..=63 are encoded in one byte: `00aaaaaa`.
+const NETWORK_SIMPLE_LIMIT: u16 = 64;
+/// Networks 64..=16383 are encoded in two bytes: network `00cccccc_aaaaaabb` is encoded as `01aaaaaa, bbcccccc`.
+/// https://github.com/paritytech/substrate/blob/master/primitives/core/src/crypto.rs
+const NETWORK_FULL_LIMIT: u16 = 16384;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SS58Address {
+    network: u16,
+    key: H256,
+}
+
+impl SS58Address {
+    pub fn new(network: u16, key: H256) -> AddressResult<SS58Address> {
+        if network >= NETWORK_FULL_LIMIT {
+            return Err(AddressError::UnexpectedAddressPrefix);
+        }
+        Ok(SS58Address { network, key })
+    }
+
+    /// Creates an address from either an `ed25519` or `sr25519` public key.
+    pub fn with_public_key(network: u16, public_key: &PublicKey) -> AddressResult<SS58Address> {
+        let key = match public_key {
+            PublicKey::Ed25519(ed25519) => ed25519.to_bytes(),
+            PublicKey::Sr25519(sr25519) => sr25519.to_bytes(),
+            _ => return Err(AddressError::PublicKeyTypeMismatch),
+        };
+        SS58Address::new(network, key)
+    }
+
+    pub fn with_public_key_coin_context(
+        coin: &dyn CoinContext,
+        public_key: &PublicKey,
+        prefix: Option<SS58Prefix>,
+    ) -> AddressResult<SS58Address> {
+        let network = match prefix {
+            Some(SS58Prefix { network }) => network,
+            None => coin.ss58_prefix().ok_or(AddressError::MissingPrefix)?,
+        };
+        SS58Address::with_public_key(network, public_key)
+    }
+
+    /// Parses an address and checks if it belongs to the network given by the `prefix`,
+    /// or to the default coin network if the `prefix` is not specified.
+    pub fn from_str_with_coin_and_prefix(
+        coin: &dyn CoinContext,
+        address_str: &str,
+        prefix: Option<SS58Prefix>,
+    ) -> AddressResult<SS58Address> {
+        let network = match prefix {
+            Some(SS58Prefix { network }) => network,
+            None => coin.ss58_prefix().ok_or(AddressError::MissingPrefix)?,
+        };
+        Self::from_str_checked(address_str, network)
+    }
+
+    pub fn from_str_checked(address_str: &str, network: u16) -> AddressResult<SS58Address> {
+        let address = SS58Address::from_str(address_str)?;
+        if address.network != network {
+            return Err(AddressError::UnexpectedAddressPrefix);
+        }
+        Ok(address)
+    }
+
+    pub fn network(&self) -> u16 {
+        self.network
+    }
+
+    pub fn key(&self) -> H256 {
+        self.key
+    }
+
+    fn encode_network(network: u16) -> Data {
+        if network < NETWORK_SIMPLE_LIMIT {
+            return vec![network as u8];
+        }
+        let first = 0b0100_0000 | ((network & 0b1111_1100) >> 2) as u8;
+        let second = (((network >> 8) & 0b0011_1111) | ((network & 0b0000_0011) << 6)) as u8;
+        vec![first, second]
+    }
+
+    /// Returns the network and the number of bytes it is encoded with.
+    fn decode_network(bytes: &[u8]) -> AddressResult<(u16, usize)> {
+        match bytes {
+            [first @ 0..=63, ..] => Ok((*first as u16, 1)),
+            [first @ 64..=127, second, ..] => {
+                let lower = ((first & 0b0011_1111) << 2) | (second >> 6);
+                let upper = second & 0b0011_1111;
+                Ok(((upper as u16) << 8 | lower as u16, 2))
+            },
+            _ => Err(AddressError::InvalidInput),
+        }
+    }
+
+    fn checksum(data: &[u8]) -> AddressResult<Data> {
+        let mut to_hash = CHECKSUM_PREFIX.to_vec();
+        to_hash.extend_from_slice(data);
+        let mut hash =
+            blake2_b(&to_hash, CHECKSUM_HASH_SIZE).map_err(|_| AddressError::InvalidInput)?;
+        hash.truncate(CHECKSUM_SIZE);
+        Ok(hash)
+    }
+}
+
+impl CoinAddress for SS58Address {
+    #[inline]
+    fn data(&self) -> Data {
+        self.key.to_vec()
+    }
+}
+
+impl FromStr for SS58Address {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base58::decode(s, Alphabet::BITCOIN).map_err(|_| AddressError::InvalidInput)?;
+        let (network, network_len) = SS58Address::decode_network(&bytes)?;
+
+        if bytes.len() != network_len + H256::LEN + CHECKSUM_SIZE {
+            return Err(AddressError::InvalidInput);
+        }
+        let (payload, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        if SS58Address::checksum(payload)? != checksum {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let key =
+            H256::try_from(&payload[network_len..]).map_err(|_| AddressError::InvalidInput)?;
+        SS58Address::new(network, key)
+    }
+}
+
+impl fmt::Display for SS58Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = SS58Address::encode_network(self.network);
+        bytes.extend_from_slice(self.key.as_slice());
+        let checksum = SS58Address::checksum(&bytes).map_err(|_| fmt::Error)?;
+        bytes.extend_from_slice(&checksum);
+        write!(f, "{}", base58::encode(&bytes, Alphabet::BITCOIN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::{DecodeHex, ToHex};
+    use tw_keypair::tw::{PrivateKey, PublicKeyType};
+
+    #[test]
+    fn test_ss58_address_from_str() {
+        let tests = [
+            ("15KRsCq9LLNmCxNFhGk55s5bEyazKefunDxUH24GFZwsTxyu", 0),
+            ("ZG2d3dH5zfqNchsqReS6x4nBJuJCW7Z6Fh5eLvdA3ZXGkPd", 5),
+            ("Fu3r514w83euSVV7q1MyFGWErUR2xDzXS2goHzimUn4S12D", 2),
+            ("cEYtw6AVMB27hFUs4gVukajLM7GqxwxUfJkbPY3rNToHMcCgb", 64),
+            ("p8EGHjWt7e1MYoD7V6WXvbPZWK9GSJiiK85kv2R7Ur7FisPUL", 172),
+            ("VDSyeURSP7ykE1zJPJGeqx6GcDZQF2DT3hAKhPMuwM5FuN9HE", 4096),
+            ("YDTv3GdhXPP3pQMqQtntGVg5hMno4jqanfYUgMPX2rLGJBKX6", 8219),
+        ];
+        for (address_str, network) in tests {
+            let address = SS58Address::from_str_checked(address_str, network).unwrap();
+            assert_eq!(address.network(), network);
+            assert_eq!(address.to_string(), address_str);
+        }
+    }
+
+    #[test]
+    fn test_ss58_address_network_mismatch() {
+        let tests = [
+            ("ZG2d3dH5zfqNchsqReS6x4nBJuJCW7Z6Fh5eLvdA3ZXGkPd", 6),
+            ("Fu3r514w83euSVV7q1MyFGWErUR2xDzXS2goHzimUn4S12D", 5),
+            ("cEYtw6AVMB27hFUs4gVukajLM7GqxwxUfJkbPY3rNToHMcCgb", 65),
+            ("JCViCkwMdGWKpf7Wogb8EFtDmaYTEZGEg6ah4svUPGnnpc7A", 64),
+            ("p8EGHjWt7e1MYoD7V6WXvbPZWK9GSJiiK85kv2R7Ur7FisPUL", 171),
+            ("VDSyeURSP7ykE1zJPJGeqx6GcDZQF2DT3hAKhPMuwM5FuN9HE", 64),
+            ("YDTv3GdhXPP3pQMqQtntGVg5hMno4jqanfYUgMPX2rLGJBKX6", 322),
+        ];
+        for (address_str, network) in tests {
+            SS58Address::from_str_checked(address_str, network).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn test_ss58_address_from_public_key() {
+        let key = H256::from("92fd9c237030356e26cfcc4568dc71055d5ec92dfe0ff903767e00611971bad3");
+
+        let tests = [
+            (0, "14KjL5vGAYJCbKgZJmFKDSjewtBpvaxx9YvRZvi7qmb5s8CC"),
+            (5, "ZG2d3dH5zfqNchsqReS6x4nBJuJCW7Z6Fh5eLvdA3ZXGkPd"),
+            (172, "p8EGHjWt7e1MYoD7V6WXvbPZWK9GSJiiK85kv2R7Ur7FisPUL"),
+        ];
+        for (network, expected) in tests {
+            let address = SS58Address::new(network, key).unwrap();
+            assert_eq!(address.to_string(), expected);
+            assert_eq!(address.data().to_hex(), key.to_hex());
+        }
+
+        assert_eq!(
+            SS58Address::new(32771, key),
+            Err(AddressError::UnexpectedAddressPrefix)
+        );
+    }
+
+    #[test]
+    fn test_ss58_address_with_public_key() {
+        // `subkey inspect //Alice`
+        let private = PrivateKey::new(
+            "e5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let sr25519 = private
+            .get_public_key_by_type(PublicKeyType::Sr25519)
+            .unwrap();
+        let address = SS58Address::with_public_key(42, &sr25519).unwrap();
+        assert_eq!(
+            address.to_string(),
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+        let address = SS58Address::with_public_key(0, &sr25519).unwrap();
+        assert_eq!(
+            address.to_string(),
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+
+        let secp256k1 = private
+            .get_public_key_by_type(PublicKeyType::Secp256k1)
+            .unwrap();
+        assert_eq!(
+            SS58Address::with_public_key(0, &secp256k1),
+            Err(AddressError::PublicKeyTypeMismatch)
+        );
+    }
+
+    #[test]
+    fn test_ss58_network_encoding() {
+        let tests = [
+            (0x00, "00"),
+            (0x01, "01"),
+            (0x3f, "3f"),
+            (0x40, "5000"),
+            (0x41, "5040"),
+            (0x80, "6000"),
+            (0x0100, "4001"),
+            (0x0123, "48c1"),
+            (0x0fff, "7fcf"),
+            (0x1000, "4010"),
+            (0x1003, "40d0"),
+            (0x3fff, "7fff"),
+        ];
+        for (network, encoded) in tests {
+            assert_eq!(SS58Address::encode_network(network).to_hex(), encoded);
+
+            let input = format!("{encoded}000102030405")
+                .as_str()
+                .decode_hex()
+                .unwrap();
+            let expected_len = if network < NETWORK_SIMPLE_LIMIT { 1 } else { 2 };
+            assert_eq!(
+                SS58Address::decode_network(&input),
+                Ok((network, expected_len))
+            );
+        }
+
+        // The first byte is from the reserved range.
+        SS58Address::decode_network(&"ab000102030405".decode_hex().unwrap()).unwrap_err();
+        SS58Address::decode_network(&"8000000102030405".decode_hex().unwrap()).unwrap_err();
+    }
+
+    #[test]
+    fn test_ss58_address_invalid() {
+        // Checksum mismatch.
+        SS58Address::from_str("14Ztd3KJDaB9xyJtRkREtSZDdhLSbm7UUKt8Z7AwSv7q85G3").unwrap_err();
+        // Bitcoin address.
+        SS58Address::from_str("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap_err();
+        SS58Address::from_str("").unwrap_err();
+    }
+}
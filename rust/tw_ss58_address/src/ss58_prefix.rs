@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::AddressError;
+use tw_coin_entry::prefix::AddressPrefix;
+
+pub struct SS58Prefix {
+    pub network: u16,
+}
+
+impl TryFrom<AddressPrefix> for SS58Prefix {
+    type Error = AddressError;
+
+    fn try_from(prefix: AddressPrefix) -> Result<Self, Self::Error> {
+        match prefix {
+            AddressPrefix::SS58(network) => Ok(SS58Prefix { network }),
+            AddressPrefix::Hrp(_) => Err(AddressError::UnexpectedAddressPrefix),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::sighash::{fork_id_signature_hash, has_fork_id};
 use crate::{Error, Result};
 use bitcoin::blockdata::locktime::absolute::{Height, LockTime, Time};
 use bitcoin::consensus::Encodable;
@@ -192,14 +193,24 @@ impl Compiler<StandardBitcoinContext> {
                     } else {
                         EcdsaSighashType::from_consensus(input.sighash_type as u32)
                     };
-                    let sighash =
-                        cache.legacy_signature_hash(index, script_pubkey, sighash_type.to_u32())?;
+                    // Bitcoin Cash and its forks commit to the input value
+                    // when the `SIGHASH_FORKID` flag is set.
+                    let sighash = if has_fork_id(input.sighash_type as u32) {
+                        fork_id_signature_hash(
+                            &tx,
+                            index,
+                            script_pubkey,
+                            input.value,
+                            input.sighash_type as u32,
+                        )?
+                    } else {
+                        cache
+                            .legacy_signature_hash(index, script_pubkey, sighash_type.to_u32())?
+                            .as_byte_array()
+                            .to_vec()
+                    };
 
-                    sighashes.push((
-                        sighash.as_byte_array().to_vec(),
-                        ProtoSigningMethod::Legacy,
-                        input.sighash_type,
-                    ));
+                    sighashes.push((sighash, ProtoSigningMethod::Legacy, input.sighash_type));
                 },
                 // Use the Segwit hashing mechanism (e.g. P2WSH, P2WPKH).
                 ProtoSigningMethod::Segwit => {
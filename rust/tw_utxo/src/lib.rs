@@ -1,6 +1,7 @@
 use tw_proto::Utxo::Proto;
 
 pub mod compiler;
+pub mod sighash;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
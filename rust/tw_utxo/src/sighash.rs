@@ -0,0 +1,85 @@
+use crate::{Error, Result};
+use bitcoin::consensus::encode::serialize;
+use bitcoin::hashes::{sha256d, Hash};
+use bitcoin::{Script, Transaction};
+use tw_proto::Utxo::Proto;
+
+/// The replay protection flag used by Bitcoin Cash and its forks (e.g. eCash).
+pub const SIGHASH_FORKID: u32 = 0x40;
+const SIGHASH_ANYONECANPAY: u32 = 0x80;
+const SIGHASH_BASE_TYPE_MASK: u32 = 0x1f;
+const SIGHASH_NONE: u32 = 0x02;
+const SIGHASH_SINGLE: u32 = 0x03;
+
+/// Returns whether the given sighash type has the [`SIGHASH_FORKID`] flag set.
+pub fn has_fork_id(sighash_type: u32) -> bool {
+    sighash_type & SIGHASH_FORKID != 0
+}
+
+/// Computes the BIP-143 style signature hash of a non-segwit input, as
+/// required when the [`SIGHASH_FORKID`] flag is set. See:
+/// https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/replay-protected-sighash.md
+///
+/// Note that only the zero fork ID is supported, so the `sighash_type` is committed as is.
+pub fn fork_id_signature_hash(
+    tx: &Transaction,
+    input_index: usize,
+    script_code: &Script,
+    value: u64,
+    sighash_type: u32,
+) -> Result<Vec<u8>> {
+    let txin = tx
+        .input
+        .get(input_index)
+        .ok_or_else(|| Error::from(Proto::Error::Error_sighash_failed))?;
+
+    let base_type = sighash_type & SIGHASH_BASE_TYPE_MASK;
+    let anyone_can_pay = sighash_type & SIGHASH_ANYONECANPAY != 0;
+    let zero_hash = [0; 32];
+
+    let hash_prevouts = if anyone_can_pay {
+        zero_hash
+    } else {
+        let prevouts: Vec<u8> = tx
+            .input
+            .iter()
+            .flat_map(|txin| serialize(&txin.previous_output))
+            .collect();
+        sha256d::Hash::hash(&prevouts).to_byte_array()
+    };
+
+    let hash_sequence =
+        if anyone_can_pay || base_type == SIGHASH_NONE || base_type == SIGHASH_SINGLE {
+            zero_hash
+        } else {
+            let sequences: Vec<u8> = tx
+                .input
+                .iter()
+                .flat_map(|txin| serialize(&txin.sequence))
+                .collect();
+            sha256d::Hash::hash(&sequences).to_byte_array()
+        };
+
+    let hash_outputs = if base_type != SIGHASH_NONE && base_type != SIGHASH_SINGLE {
+        let outputs: Vec<u8> = tx.output.iter().flat_map(serialize).collect();
+        sha256d::Hash::hash(&outputs).to_byte_array()
+    } else if base_type == SIGHASH_SINGLE && input_index < tx.output.len() {
+        sha256d::Hash::hash(&serialize(&tx.output[input_index])).to_byte_array()
+    } else {
+        zero_hash
+    };
+
+    let mut preimage = vec![];
+    preimage.extend(serialize(&tx.version));
+    preimage.extend(hash_prevouts);
+    preimage.extend(hash_sequence);
+    preimage.extend(serialize(&txin.previous_output));
+    preimage.extend(serialize(&script_code.to_owned()));
+    preimage.extend(serialize(&value));
+    preimage.extend(serialize(&txin.sequence));
+    preimage.extend(hash_outputs);
+    preimage.extend(serialize(&tx.lock_time));
+    preimage.extend(serialize(&sighash_type));
+
+    Ok(sha256d::Hash::hash(&preimage).to_byte_array().to_vec())
+}
@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#[cfg(feature = "binance-decoder")]
+pub mod decoder;
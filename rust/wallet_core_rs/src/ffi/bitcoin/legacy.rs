@@ -6,6 +6,7 @@
 
 use std::ffi::{c_char, CStr};
 use tw_bitcoin::aliases::*;
+use tw_bitcoin::modules::network::NetworkParams;
 use tw_bitcoin::native::consensus::Decodable;
 use tw_bitcoin::native::{PublicKey, Transaction};
 use tw_memory::ffi::c_byte_array::CByteArray;
@@ -43,7 +44,10 @@ pub unsafe extern "C" fn tw_bitcoin_legacy_build_p2pkh_script(
     };
 
     let res = try_or_else!(
-        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(&output),
+        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(
+            &NetworkParams::bitcoin(),
+            &output
+        ),
         CByteArray::null
     );
 
@@ -84,7 +88,10 @@ pub unsafe extern "C" fn tw_bitcoin_legacy_build_p2wpkh_script(
     };
 
     let res = try_or_else!(
-        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(&output),
+        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(
+            &NetworkParams::bitcoin(),
+            &output
+        ),
         CByteArray::null
     );
 
@@ -122,7 +129,10 @@ pub unsafe extern "C" fn tw_bitcoin_legacy_build_p2tr_key_path_script(
     };
 
     let res = try_or_else!(
-        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(&output),
+        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(
+            &NetworkParams::bitcoin(),
+            &output
+        ),
         CByteArray::null
     );
 
@@ -176,7 +186,10 @@ pub unsafe extern "C" fn tw_bitcoin_legacy_build_brc20_transfer_inscription(
     };
 
     let res = try_or_else!(
-        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(&output),
+        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(
+            &NetworkParams::bitcoin(),
+            &output
+        ),
         CByteArray::null
     );
 
@@ -237,7 +250,10 @@ pub unsafe extern "C" fn tw_bitcoin_legacy_build_nft_inscription(
     };
 
     let res = try_or_else!(
-        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(&output),
+        tw_bitcoin::modules::transactions::OutputBuilder::utxo_from_proto(
+            &NetworkParams::bitcoin(),
+            &output
+        ),
         CByteArray::null
     );
 
@@ -297,7 +313,7 @@ pub unsafe extern "C" fn tw_bitcoin_legacy_taproot_build_and_sign_transaction(
         };
 
         let serialized = tw_proto::serialize(&error).expect("failed to serialize error message");
-        return CByteArray::from(serialized)
+        return CByteArray::from(serialized);
     };
 
     // Serialize SigningOutput and return.
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_registry::blockchain_type::BlockchainType;
+use tw_coin_registry::coin_context::CoinRegistryContext;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::get_coin_item;
+use tw_cosmos_sdk::context::StandardCosmosContext;
+use tw_cosmos_sdk::modules::decoder::tw_decoder::TWDecoder;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+use tw_native_evmos::context::NativeEvmosContext;
+use tw_native_injective::context::NativeInjectiveContext;
+use tw_proto::Cosmos::Proto;
+
+/// Decodes a Cosmos transaction or its preimage back to the `SigningInput`, and verifies its signatures if any.
+///
+/// \param coin Cosmos-compatible coin type.
+/// \param input Non-null serialized `Cosmos::Proto::DecodingTransactionInput`.
+/// \return serialized `Cosmos::Proto::DecodingTransactionOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_cosmos_decode_transaction(
+    coin: u32,
+    input: *const TWData,
+) -> *mut TWData {
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let coin_item = try_or_else!(get_coin_item(coin), std::ptr::null_mut);
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let input: Proto::DecodingTransactionInput = try_or_else!(
+        tw_proto::deserialize(input_data.as_slice()),
+        std::ptr::null_mut
+    );
+
+    let blockchain = coin_item.blockchain;
+    let coin_context = CoinRegistryContext::with_coin_item(coin_item);
+    let coin_context: &dyn CoinContext = &coin_context;
+    let output = match blockchain {
+        BlockchainType::Cosmos | BlockchainType::Thorchain => {
+            TWDecoder::<StandardCosmosContext>::decode_transaction(coin_context, input)
+        },
+        BlockchainType::NativeEvmos => {
+            TWDecoder::<NativeEvmosContext>::decode_transaction(coin_context, input)
+        },
+        BlockchainType::NativeInjective => {
+            TWDecoder::<NativeInjectiveContext>::decode_transaction(coin_context, input)
+        },
+        _ => return std::ptr::null_mut(),
+    };
+    tw_proto::serialize(&output)
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
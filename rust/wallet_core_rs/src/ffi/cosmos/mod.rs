@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#[cfg(feature = "cosmos-decoder")]
+pub mod decoder;
+#[cfg(feature = "cosmos-wasm")]
+pub mod wasm;
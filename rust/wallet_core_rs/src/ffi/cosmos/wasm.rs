@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_cosmos_sdk::transaction::message::wasm_message::instantiate2_address;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Computes a predictable contract address as it's done for `MsgInstantiateContract2`.
+///
+/// \param checksum Non-null SHA-256 hash of the raw (uncompressed) WASM byte code.
+/// \param creator Non-null creator's address bytes (account key hash).
+/// \param salt Non-null salt provided by the creator, 1 to 64 bytes long.
+/// \param fix_msg Nullable instantiate message if it should be included into the hash.
+/// \return 32-byte contract address hash or null if the input is invalid.
+#[no_mangle]
+pub unsafe extern "C" fn tw_cosmos_wasm_instantiate2_address(
+    checksum: *const TWData,
+    creator: *const TWData,
+    salt: *const TWData,
+    fix_msg: *const TWData,
+) -> *mut TWData {
+    let checksum = try_or_else!(TWData::from_ptr_as_ref(checksum), std::ptr::null_mut);
+    let creator = try_or_else!(TWData::from_ptr_as_ref(creator), std::ptr::null_mut);
+    let salt = try_or_else!(TWData::from_ptr_as_ref(salt), std::ptr::null_mut);
+    let fix_msg = TWData::from_ptr_as_ref(fix_msg).map(TWData::as_slice);
+
+    instantiate2_address(
+        checksum.as_slice(),
+        creator.as_slice(),
+        salt.as_slice(),
+        fix_msg,
+    )
+    .map(|address| TWData::from(address.into_vec()).into_ptr())
+    .unwrap_or_else(|_| std::ptr::null_mut())
+}
@@ -2,5 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod binance;
 pub mod bitcoin;
+pub mod cosmos;
 pub mod ethereum;
+pub mod solana;
+pub mod thorchain;
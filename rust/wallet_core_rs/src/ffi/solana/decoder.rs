@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_coin_registry::blockchain_type::BlockchainType;
+use tw_coin_registry::coin_context::CoinRegistryContext;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::get_coin_item;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+use tw_proto::Solana::Proto;
+use tw_solana::modules::decoder::SolanaDecoder;
+
+/// Decodes a legacy or v0 transaction in the wire format, including the instructions of well-known programs.
+/// The decoded transaction can be passed to `Solana::Proto::SigningInput.raw_message` to be signed.
+///
+/// \param coin Solana-compatible coin type.
+/// \param input Non-null serialized `Solana::Proto::DecodingTransactionInput`.
+/// \return serialized `Solana::Proto::DecodingTransactionOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_solana_decode_transaction(
+    coin: u32,
+    input: *const TWData,
+) -> *mut TWData {
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let coin_item = try_or_else!(get_coin_item(coin), std::ptr::null_mut);
+    if coin_item.blockchain != BlockchainType::Solana {
+        return std::ptr::null_mut();
+    }
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let input: Proto::DecodingTransactionInput = try_or_else!(
+        tw_proto::deserialize(input_data.as_slice()),
+        std::ptr::null_mut
+    );
+
+    let coin_context = CoinRegistryContext::with_coin_item(coin_item);
+    let output = SolanaDecoder::decode_transaction(&coin_context, input);
+    tw_proto::serialize(&output)
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
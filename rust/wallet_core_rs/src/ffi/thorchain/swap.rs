@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+use tw_proto::THORChain::Proto;
+use tw_thorchain::swap::ThorchainSwapBuilder;
+
+/// Builds a `thorchain/MsgDeposit` message with the `SWAP` memo from a typed swap request.
+/// The message is supposed to be added to `Cosmos::Proto::SigningInput::messages`.
+///
+/// \param input Non-null serialized `THORChain::Proto::SwapDepositInput`.
+/// \return serialized `THORChain::Proto::SwapDepositOutput`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_thorchain_build_swap_deposit(input: *const TWData) -> *mut TWData {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let input: Proto::SwapDepositInput = try_or_else!(
+        tw_proto::deserialize(input_data.as_slice()),
+        std::ptr::null_mut
+    );
+
+    let output = ThorchainSwapBuilder::build_deposit(input);
+    tw_proto::serialize(&output)
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
@@ -4,13 +4,19 @@
 
 pub extern crate tw_any_coin;
 pub extern crate tw_aptos;
+pub extern crate tw_binance;
 pub extern crate tw_bitcoin;
 pub extern crate tw_coin_registry;
+pub extern crate tw_cosmos_sdk;
 pub extern crate tw_encoding;
 pub extern crate tw_ethereum;
 pub extern crate tw_hash;
 pub extern crate tw_keypair;
 pub extern crate tw_memory;
+pub extern crate tw_native_evmos;
+pub extern crate tw_native_injective;
 pub extern crate tw_proto;
+pub extern crate tw_solana;
+pub extern crate tw_thorchain;
 
 pub mod ffi;
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Binance::Proto;
+use tw_proto::Binance::Proto::mod_SigningInput::OneOforder_oneof as OrderEnum;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::binance::decoder::tw_binance_decode_transaction;
+
+const TRADE_ORDER_ENCODED: &str = "dc01f0625dee0a64ce6dc0430a14ba36f0fad74d8f41045463e4774f328f4af779e5122b424133364630464144373444384634313034353436334534373734463332384634414637373945352d33361a0b4e4e422d3333385f424e422002280130b09282413880c2d72f4001126e0a26eb5ae98721029729a52e4e3c2b4a4e52aa74033eedaf8ba1df5ab6d1f518fd69e67bbd309b0e12409123cb6906bb20aeb753f4a121d4d88ff0e9750ba75b0c4e10d76caee1e7d2481290fa3b9887a6225d6997f5f939ef834ea61d596a314237c48e560da9e17b5a180c20232001";
+const TRADE_ORDER_SIGNATURE: &str = "9123cb6906bb20aeb753f4a121d4d88ff0e9750ba75b0c4e10d76caee1e7d2481290fa3b9887a6225d6997f5f939ef834ea61d596a314237c48e560da9e17b5a";
+
+/// Returns serialized `Proto::DecodingTransactionOutput`.
+fn decode_transaction(coin: CoinType, encoded_tx: &str, chain_id: &str) -> Vec<u8> {
+    let input = Proto::DecodingTransactionInput {
+        encoded_tx: encoded_tx.decode_hex().unwrap().into(),
+        chain_id: chain_id.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    TWDataHelper::wrap(unsafe { tw_binance_decode_transaction(coin as u32, input_data.ptr()) })
+        .to_vec()
+        .expect("!tw_binance_decode_transaction returned nullptr")
+}
+
+#[test]
+fn test_binance_decode_trade_order() {
+    let output_data = decode_transaction(CoinType::Binance, TRADE_ORDER_ENCODED, "chain-bnb");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let tx = output.transaction.expect("Expected a decoded transaction");
+    assert_eq!(tx.chain_id, "chain-bnb");
+    assert_eq!(tx.account_number, 12);
+    assert_eq!(tx.sequence, 35);
+    assert_eq!(tx.source, 1);
+    assert!(tx.memo.is_empty());
+    assert!(tx.private_key.is_empty());
+
+    let OrderEnum::trade_order(order) = tx.order_oneof else {
+        panic!("Expected a trade order");
+    };
+    assert_eq!(
+        order.sender.to_hex(),
+        "ba36f0fad74d8f41045463e4774f328f4af779e5"
+    );
+    assert_eq!(order.id, "BA36F0FAD74D8F41045463E4774F328F4AF779E5-36");
+    assert_eq!(order.symbol, "NNB-338_BNB");
+    assert_eq!(order.ordertype, 2);
+    assert_eq!(order.side, 1);
+    assert_eq!(order.price, 136350000);
+    assert_eq!(order.quantity, 100000000);
+    assert_eq!(order.timeinforce, 1);
+
+    assert_eq!(output.signatures.len(), 1);
+    let signature = &output.signatures[0];
+    assert_eq!(
+        signature.public_key.to_hex(),
+        "029729a52e4e3c2b4a4e52aa74033eedaf8ba1df5ab6d1f518fd69e67bbd309b0e"
+    );
+    assert_eq!(signature.signature.to_hex(), TRADE_ORDER_SIGNATURE);
+    assert_eq!(signature.account_number, 12);
+    assert_eq!(signature.sequence, 35);
+}
+
+#[test]
+fn test_binance_decode_send_order() {
+    let encoded = concat!(
+        "cc01",
+        "f0625dee",
+        "0a4e",
+            "2a2c87fa",
+                "0a23", "0a1440c2979694bbc961023d1d27be6fc4d21a9febe6120b0a03424e4210c098a8dd03",
+                "1223", "0a1488b37d5e05f3699e2a1406468e5d87cb9dcceb95120b0a03424e4210c098a8dd03",
+            "126e",
+                "0a26",
+                "eb5ae987",
+                "21026a35920088d98c3888ca68c53dfc93f4564602606cbb87f0fe5ee533db38e502",
+                "1240", "c65a13440f18a155bd971ee40b9e0dd58586f5bf344e12ec4c76c439aebca8c7789bab7bfbfb4ce89aadc4a02df225b6b6efc861c13bbeb5f7a3eea2d7ffc80f",
+                "1813",
+                "2017",
+            "1a04", "74657374",
+            "2001",
+    );
+
+    let output_data = decode_transaction(CoinType::Binance, encoded, "chain-bnb");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    let tx = output.transaction.expect("Expected a decoded transaction");
+    assert_eq!(tx.account_number, 19);
+    assert_eq!(tx.sequence, 23);
+    assert_eq!(tx.memo, "test");
+
+    let OrderEnum::send_order(order) = tx.order_oneof else {
+        panic!("Expected a send order");
+    };
+    assert_eq!(order.inputs.len(), 1);
+    assert_eq!(
+        order.inputs[0].address.to_hex(),
+        "40c2979694bbc961023d1d27be6fc4d21a9febe6"
+    );
+    assert_eq!(order.inputs[0].coins[0].denom, "BNB");
+    assert_eq!(order.inputs[0].coins[0].amount, 1_001_000_000);
+    assert_eq!(order.outputs.len(), 1);
+    assert_eq!(
+        order.outputs[0].address.to_hex(),
+        "88b37d5e05f3699e2a1406468e5d87cb9dcceb95"
+    );
+    assert_eq!(order.outputs[0].coins[0].amount, 1_001_000_000);
+}
+
+#[test]
+fn test_binance_decode_invalid_signature() {
+    // The last byte of the signature is changed from `5a` to `5b`.
+    let encoded = TRADE_ORDER_ENCODED.replace(
+        TRADE_ORDER_SIGNATURE,
+        &format!("{}5b", &TRADE_ORDER_SIGNATURE[..126]),
+    );
+    let output_data = decode_transaction(CoinType::Binance, &encoded, "chain-bnb");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_signing);
+    assert!(output.transaction.is_none());
+}
+
+#[test]
+fn test_binance_decode_wrong_chain_id() {
+    // The chain ID is a part of the signed message.
+    let output_data = decode_transaction(
+        CoinType::Binance,
+        TRADE_ORDER_ENCODED,
+        "Binance-Chain-Tigris",
+    );
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_signing);
+}
+
+#[test]
+fn test_binance_decode_unknown_message() {
+    // The `NewTradeOrder` prefix is replaced with an unknown one.
+    let encoded = TRADE_ORDER_ENCODED.replacen("ce6dc043", "ce6dc044", 1);
+    let output_data = decode_transaction(CoinType::Binance, &encoded, "chain-bnb");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_binance_decode_invalid_size_prefix() {
+    // The transaction is truncated.
+    let encoded = &TRADE_ORDER_ENCODED[..TRADE_ORDER_ENCODED.len() - 2];
+    let output_data = decode_transaction(CoinType::Binance, encoded, "chain-bnb");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+}
+
+#[test]
+fn test_binance_decode_unsupported_coin() {
+    let input = Proto::DecodingTransactionInput {
+        encoded_tx: TRADE_ORDER_ENCODED.decode_hex().unwrap().into(),
+        chain_id: "chain-bnb".into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_binance_decode_transaction(CoinType::Ethereum as u32, input_data.ptr())
+    });
+    assert!(output_data.is_null());
+}
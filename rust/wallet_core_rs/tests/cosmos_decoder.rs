@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+use tw_encoding::hex::ToHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_DecodingTransactionInput::OneOftransaction_oneof as TransactionEnum;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::cosmos::decoder::tw_cosmos_decode_transaction;
+
+/// Taken from `tw_cosmos_sdk/tests/sign.rs::test_sign_coin_send`.
+const COIN_SEND_TX_RAW: &str = "CowBCokBChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEmkKLWNvc21vczFoc2s2anJ5eXFqZmhwNWRoYzU1dGM5anRja3lneDBlcGg2ZGQwMhItY29zbW9zMXp0NTBhenVwYW5xbGZhbTVhZmh2M2hleHd5dXRudWtlaDRjNTczGgkKBG11b24SATESZQpQCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAlcobsPzfTNVe7uqAAsndErJAjqplnyudaGB0f+R+p3FEgQKAggBGAgSEQoLCgRtdW9uEgMyMDAQwJoMGkD54fQAFlekIAnE62hZYl0uQelh/HLv0oQpCciY5Dn8H1SZFuTsrGdu41PH1Uxa4woptCELi/8Ov9yzdeEFAC9H";
+
+/// Returns serialized `Proto::DecodingTransactionOutput` or `None` if the decoder returned nullptr.
+fn decode_transaction(
+    coin: CoinType,
+    input: Proto::DecodingTransactionInput<'_>,
+) -> Option<Vec<u8>> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    TWDataHelper::wrap(unsafe { tw_cosmos_decode_transaction(coin as u32, input_data.ptr()) })
+        .to_vec()
+}
+
+fn coin_send_input(account_number: u64) -> Proto::DecodingTransactionInput<'static> {
+    Proto::DecodingTransactionInput {
+        transaction_oneof: TransactionEnum::tx_raw(
+            base64::decode(COIN_SEND_TX_RAW, false).unwrap().into(),
+        ),
+        chain_id: "gaia-13003".into(),
+        account_number,
+    }
+}
+
+#[test]
+fn test_cosmos_decode_tx_raw() {
+    let output_data = decode_transaction(CoinType::Cosmos, coin_send_input(1037))
+        .expect("!tw_cosmos_decode_transaction returned nullptr");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert!(output.unknown_messages.is_empty());
+
+    let tx = output.transaction.expect("Expected a decoded transaction");
+    assert_eq!(tx.chain_id, "gaia-13003");
+    assert_eq!(tx.account_number, 1037);
+    assert_eq!(tx.sequence, 8);
+    assert!(tx.private_key.is_empty());
+    assert_eq!(tx.messages.len(), 1);
+    let MessageEnum::send_coins_message(ref send) = tx.messages[0].message_oneof else {
+        panic!("Expected a send message");
+    };
+    assert_eq!(
+        send.from_address,
+        "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02"
+    );
+    assert_eq!(
+        send.to_address,
+        "cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573"
+    );
+
+    assert_eq!(output.signatures.len(), 1);
+    let signature = &output.signatures[0];
+    assert_eq!(
+        signature.public_key.to_hex(),
+        "0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5"
+    );
+    assert_eq!(signature.signature.to_hex(), "f9e1f4001657a42009c4eb6859625d2e41e961fc72efd2842909c898e439fc1f549916e4ecac676ee353c7d54c5ae30a29b4210b8bff0ebfdcb375e105002f47");
+    assert!(signature.verified);
+}
+
+#[test]
+fn test_cosmos_decode_tx_raw_wrong_account_number() {
+    // The account number is a part of the signed preimage.
+    let output_data = decode_transaction(CoinType::Cosmos, coin_send_input(1038))
+        .expect("!tw_cosmos_decode_transaction returned nullptr");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(!output.signatures[0].verified);
+}
+
+#[test]
+fn test_cosmos_decode_amino_sign_doc() {
+    let input = Proto::DecodingTransactionInput {
+        transaction_oneof: TransactionEnum::amino_sign_doc(r#"{"account_number":"1037","chain_id":"gaia-13003","fee":{"amount":[{"amount":"200","denom":"muon"}],"gas":"200000"},"memo":"","msgs":[{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"1","denom":"muon"}],"from_address":"cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02","to_address":"cosmos1zt50azupanqlfam5afhv3hexwyutnukeh4c573"}}],"sequence":"8"}"#.into()),
+        ..Proto::DecodingTransactionInput::default()
+    };
+    let output_data = decode_transaction(CoinType::Cosmos, input)
+        .expect("!tw_cosmos_decode_transaction returned nullptr");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    let tx = output.transaction.expect("Expected a decoded transaction");
+    assert_eq!(tx.signing_mode, Proto::SigningMode::JSON);
+    assert_eq!(tx.account_number, 1037);
+    assert!(output.signatures.is_empty());
+}
+
+#[test]
+fn test_cosmos_decode_invalid_tx_raw() {
+    let input = Proto::DecodingTransactionInput {
+        transaction_oneof: TransactionEnum::tx_raw(b"invalid tx".to_vec().into()),
+        chain_id: "gaia-13003".into(),
+        account_number: 1037,
+    };
+    let output_data = decode_transaction(CoinType::Cosmos, input)
+        .expect("!tw_cosmos_decode_transaction returned nullptr");
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
+
+#[test]
+fn test_cosmos_decode_unsupported_coin() {
+    let output_data = decode_transaction(CoinType::Ethereum, coin_send_input(1037));
+    assert!(output_data.is_none());
+}
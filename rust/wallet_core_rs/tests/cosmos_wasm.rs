@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use wallet_core_rs::ffi::cosmos::wasm::tw_cosmos_wasm_instantiate2_address;
+
+const CHECKSUM: &str = "13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5";
+const CREATOR: &str = "9999999999aaaaaaaaaabbbbbbbbbbcccccccccc";
+
+fn instantiate2_address(salt: &[u8], fix_msg: Option<&[u8]>) -> Option<Vec<u8>> {
+    let checksum = TWDataHelper::create(CHECKSUM.decode_hex().unwrap());
+    let creator = TWDataHelper::create(CREATOR.decode_hex().unwrap());
+    let salt = TWDataHelper::create(salt.to_vec());
+    let fix_msg = fix_msg.map(|msg| TWDataHelper::create(msg.to_vec()));
+    let fix_msg_ptr = fix_msg
+        .as_ref()
+        .map_or_else(std::ptr::null_mut, TWDataHelper::ptr);
+
+    TWDataHelper::wrap(unsafe {
+        tw_cosmos_wasm_instantiate2_address(checksum.ptr(), creator.ptr(), salt.ptr(), fix_msg_ptr)
+    })
+    .to_vec()
+}
+
+/// Taken from `instantiate2_address_works` in
+/// https://github.com/CosmWasm/cosmwasm/blob/v1.5.0/packages/std/src/addresses.rs
+#[test]
+fn test_cosmos_wasm_instantiate2_address() {
+    let address = instantiate2_address(b"a", None)
+        .expect("!tw_cosmos_wasm_instantiate2_address returned nullptr");
+    assert_eq!(
+        address.to_hex(),
+        "5e865d3e45ad3e961f77fd77d46543417ced44d924dc3e079b5415ff6775f847"
+    );
+
+    let address = instantiate2_address(b"a", Some(b"{}"))
+        .expect("!tw_cosmos_wasm_instantiate2_address returned nullptr");
+    assert_eq!(
+        address.to_hex(),
+        "0995499608947a5281e2c7ebd71bdb26a1ad981946dad57f6c4d3ee35de77835"
+    );
+}
+
+#[test]
+fn test_cosmos_wasm_instantiate2_address_invalid_salt() {
+    assert!(instantiate2_address(&[], None).is_none());
+    assert!(instantiate2_address(&[0; 65], None).is_none());
+}
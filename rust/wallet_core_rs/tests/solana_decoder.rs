@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Solana::Proto;
+use tw_proto::Solana::Proto::mod_DecodedInstruction::OneOfinstruction as InstructionType;
+use tw_proto::Solana::Proto::mod_RawMessage::OneOfmessage as MessageType;
+use tw_proto::Solana::Proto::mod_SigningInput::OneOftransaction_type as TransactionType;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::solana::decoder::tw_solana_decode_transaction;
+
+/// A v0 transaction that transfers SOL to an account loaded from an address lookup table.
+/// It is signed by the second signer `Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ` only.
+const PARTIALLY_SIGNED_V0: &str = "0200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ca8dba373ad1f5e0be5e1e074fe04dbf969f205adc130a103d3a44f578fe41a7f6e0ea786bdfb0fc0fdad5cb3588c47ea85bb69365f33143be991d0c39978900800201010494c3890fa8d4bc04ab2a676d2cafea5cdc899ecd95a9cbe593e9df258759685acb2af089b56a557737bc1718e0cbf232cf5b02e14ee0aa7c6675233f5f6f9b5724c255a8bc3e8496217a2cd2a1894b9b9dcace04fcd9c0d599acdaaea40a1b610000000000000000000000000000000000000000000000000000000000000000395bf727f9aac5e80911591073fcf9c826f428804131ca089beba3869421749a02030200040c0200000040420f0000000000030200020c0200000088130000000000000129a1af58c138274076260b2d9c83b8378cc951a68d9b4de13794b853d695e431010700";
+
+/// Returns serialized `Proto::DecodingTransactionOutput`.
+fn decode_transaction(coin: CoinType, encoded_tx: Vec<u8>) -> Vec<u8> {
+    let input = Proto::DecodingTransactionInput {
+        encoded_tx: encoded_tx.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    TWDataHelper::wrap(unsafe { tw_solana_decode_transaction(coin as u32, input_data.ptr()) })
+        .to_vec()
+        .expect("!tw_solana_decode_transaction returned nullptr")
+}
+
+fn b58(s: &str) -> Vec<u8> {
+    base58::decode(s, Alphabet::BITCOIN).unwrap()
+}
+
+#[test]
+fn test_solana_decode_transfer_legacy() {
+    let encoded = b58("3p2kzZ1DvquqC6LApPuxpTg5CCDVPqJFokGSnGhnBHrta4uq7S2EyehV1XNUVXp51D69GxGzQZUjikfDzbWBG2aFtG3gHT1QfLzyFKHM4HQtMQMNXqay1NAeiiYZjNhx9UvMX4uAQZ4Q6rx6m2AYfQ7aoMUrejq298q1wBFdtS9XVB5QTiStnzC7zs97FUEK2T4XapjF1519EyFBViTfHpGpnf5bfizDzsW9kYUtRDW1UC2LgHr7npgq5W9TBmHf9hSmRgM9XXucjXLqubNWE7HUMhbKjuBqkirRM");
+    let output_data = decode_transaction(CoinType::Solana, encoded);
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let tx = output.transaction.expect("Expected a decoded transaction");
+    assert_eq!(tx.signatures.len(), 1);
+    assert_eq!(
+        tx.signatures[0].pubkey,
+        "7v91N7iZ9mNicL8WfG6cgSCKyRXydQjLh6UYBWwm6y1Q"
+    );
+    assert_eq!(
+        tx.signatures[0].signature,
+        "5T6uZBHnHFd8uWErDBTFRVkbKuhbcm94K5MJ2beTYDruzqv4FjS7EMKvC94ZfxNAiWUXZ6bZxS3WXUbhJwYNPWn"
+    );
+
+    let MessageType::legacy(message) = tx.message else {
+        panic!("Expected a legacy message");
+    };
+    let header = message.header.expect("Expected a message header");
+    assert_eq!(header.num_required_signatures, 1);
+    assert_eq!(header.num_readonly_signed_accounts, 0);
+    assert_eq!(header.num_readonly_unsigned_accounts, 1);
+    assert_eq!(
+        message.account_keys,
+        [
+            "7v91N7iZ9mNicL8WfG6cgSCKyRXydQjLh6UYBWwm6y1Q",
+            "EN2sCsJ1WDV8UFqsiTXHcUPUxQ4juE71eCknHYYMifkd",
+            "11111111111111111111111111111111",
+        ]
+    );
+    assert_eq!(message.recent_blockhash, "11111111111111111111111111111111");
+    assert_eq!(message.instructions.len(), 1);
+    assert_eq!(message.instructions[0].program_id, 2);
+    assert_eq!(message.instructions[0].accounts.as_ref(), [0, 1]);
+    assert_eq!(
+        message.instructions[0].program_data.to_hex(),
+        "020000002a00000000000000"
+    );
+
+    assert_eq!(output.instructions.len(), 1);
+    let instruction = &output.instructions[0];
+    assert_eq!(instruction.program_id, "11111111111111111111111111111111");
+    let InstructionType::system_transfer(ref transfer) = instruction.instruction else {
+        panic!("Expected a system transfer");
+    };
+    assert_eq!(
+        transfer.from,
+        "7v91N7iZ9mNicL8WfG6cgSCKyRXydQjLh6UYBWwm6y1Q"
+    );
+    assert_eq!(transfer.to, "EN2sCsJ1WDV8UFqsiTXHcUPUxQ4juE71eCknHYYMifkd");
+    assert_eq!(transfer.lamports, 42);
+}
+
+#[test]
+fn test_solana_decode_partially_signed_v0() {
+    let encoded = PARTIALLY_SIGNED_V0.decode_hex().unwrap();
+    let output_data = decode_transaction(CoinType::Solana, encoded);
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    let tx = output.transaction.expect("Expected a decoded transaction");
+    // The fee payer hasn't signed the transaction yet.
+    assert_eq!(tx.signatures.len(), 1);
+    assert_eq!(
+        tx.signatures[0].pubkey,
+        "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ"
+    );
+    assert_eq!(
+        tx.signatures[0].signature,
+        "53tBE46sWseVkMbBfbrUNeVaAe3vXDXSZw6TUM8KDcgGLqJjijJgky5bbPiG1z5PyYYy2WitRkhSKoBGvNVeHGSj"
+    );
+
+    let MessageType::v0(message) = tx.message else {
+        panic!("Expected a v0 message");
+    };
+    let header = message.header.expect("Expected a message header");
+    assert_eq!(header.num_required_signatures, 2);
+    assert_eq!(header.num_readonly_signed_accounts, 1);
+    assert_eq!(header.num_readonly_unsigned_accounts, 1);
+    assert_eq!(
+        message.account_keys,
+        [
+            "B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V",
+            "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ",
+            "3UVYmECPPMZSCqWKfENfuoTv51fTDTWicX9xmBD2euKe",
+            "11111111111111111111111111111111",
+        ]
+    );
+    assert_eq!(
+        message.recent_blockhash,
+        "4ruaGCyaofHWGxPFXFVjuEJCdfBGZ2wCtEx6LzdzVqtV"
+    );
+    assert_eq!(message.address_table_lookups.len(), 1);
+    let lookup = &message.address_table_lookups[0];
+    assert_eq!(
+        lookup.account_key,
+        "3oWiDSnfWp9JES3Zm6C62R8YnVQpSR4vugdGe6s2TBtU"
+    );
+    assert_eq!(lookup.writable_indexes.as_ref(), [7]);
+    assert!(lookup.readonly_indexes.is_empty());
+
+    assert_eq!(output.instructions.len(), 2);
+
+    // The recipient is loaded from the address lookup table.
+    let instruction = &output.instructions[0];
+    assert_eq!(
+        instruction.accounts,
+        ["B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V", ""]
+    );
+    let InstructionType::system_transfer(ref transfer) = instruction.instruction else {
+        panic!("Expected a system transfer");
+    };
+    assert_eq!(
+        transfer.from,
+        "B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V"
+    );
+    assert!(transfer.to.is_empty());
+    assert_eq!(transfer.lamports, 1_000_000);
+
+    let InstructionType::system_transfer(ref transfer) = output.instructions[1].instruction else {
+        panic!("Expected a system transfer");
+    };
+    assert_eq!(transfer.to, "3UVYmECPPMZSCqWKfENfuoTv51fTDTWicX9xmBD2euKe");
+    assert_eq!(transfer.lamports, 5000);
+}
+
+#[test]
+fn test_solana_decode_create_and_transfer_token() {
+    let encoded = b58("5sxFkQYd2FvqRU64N79A6xjJKNkgUsEEg2wKgai2NiK7A7hF3q5GYEbjQsYBG9S2MejwTENbwHzvypaa3D3cEkxvVTg19aJFWdCtXQiz42QF5fN2MuAb6eJR4KHFnzCtxxnYGtN9swZ5B5cMSPCffCRZeUTe3kooRmbTYPvSaemU6reVSM7X2beoFKPd2svrLFa8XnvhBwL9EiFWQ9WhHB2cDV7KozCnJAW9kdNDR4RbfFQxboANGo3ZGE5ddcZ6YdomATKze1TtHj2qzJEJRwxsRr3iM3iNFb4Eav5Q2n71KUriRf73mo44GQUPbQ2LvpZKf4V6M2PzxJwzBo7FiFZurPmsanT3U5efEsKnnueddbiLHedc8JXc1d3Z53sFxVGJpsGA8RR6thse9wUvaEWqXVtPbNA6NMao9DFGD6Dudza9pJXSobPc7mDHZmVmookf5vi6Lb9Y1Q4EgcEPQmbaDnKGGB6uGfZe629i3iKXRzAd2dB7mKfffhDadZ8S1eYGT3dhddV3ExRxcqDP9BAGQT3rkRw1JpeSSi7ziYMQ3vn4t3okdgQSq6rrpbPDUNG8tLSHFMAq3ydnh4Cb4ECKkYoz9SFAnXACUu4mWETxijuKMK9kHrTqPGk9weHTzobzCC8q8fcPWV3TcyUyMxsbVxh5q1p5h5tWfD9td5TZJ2HEUbTop2dA53ZF");
+    let output_data = decode_transaction(CoinType::Solana, encoded);
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+
+    let tx = output.transaction.expect("Expected a decoded transaction");
+    assert_eq!(tx.signatures.len(), 2);
+    assert_eq!(
+        tx.signatures[0].pubkey,
+        "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ"
+    );
+    assert_eq!(
+        tx.signatures[1].pubkey,
+        "B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V"
+    );
+
+    assert_eq!(output.instructions.len(), 2);
+
+    let instruction = &output.instructions[0];
+    assert_eq!(
+        instruction.program_id,
+        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+    );
+    let InstructionType::create_associated_token_account(ref create) = instruction.instruction
+    else {
+        panic!("Expected an associated token account creation");
+    };
+    assert_eq!(
+        create.funding_account,
+        "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ"
+    );
+    assert_eq!(
+        create.associated_account,
+        "Ay7G7Yb7ZCebCQdG39FxD1nFNDtqFWJCBgfd5Ek7DDcS"
+    );
+    assert_eq!(
+        create.wallet,
+        "E54BymfdhXQtzDSGtgiJayauEMdB2gJjPqoDjzfbCXwj"
+    );
+    assert_eq!(create.mint, "4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU");
+    assert!(!create.idempotent);
+
+    let instruction = &output.instructions[1];
+    assert_eq!(
+        instruction.program_id,
+        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+    );
+    let InstructionType::token_transfer_checked(ref transfer) = instruction.instruction else {
+        panic!("Expected a token transfer");
+    };
+    assert_eq!(
+        transfer.source,
+        "5sS5Z8GAdVHqZKRqEvpDauHvvLgbDveiyfi81uh25mrf"
+    );
+    assert_eq!(
+        transfer.mint,
+        "4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"
+    );
+    assert_eq!(
+        transfer.destination,
+        "Ay7G7Yb7ZCebCQdG39FxD1nFNDtqFWJCBgfd5Ek7DDcS"
+    );
+    assert_eq!(
+        transfer.owner,
+        "B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V"
+    );
+    assert_eq!(transfer.amount, 4000);
+    assert_eq!(transfer.decimals, 6);
+}
+
+#[test]
+fn test_solana_decode_and_sign() {
+    let encoded = PARTIALLY_SIGNED_V0.decode_hex().unwrap();
+    let output_data = decode_transaction(CoinType::Solana, encoded);
+    let decoded: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(decoded.error, SigningErrorType::OK);
+
+    // Sign the transaction as the fee payer `B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V`.
+    let input = Proto::SigningInput {
+        private_key: b58("9YtuoD4sH4h88CVM8DSnkfoAaLY7YeGC2TarDJ8eyMS5").into(),
+        transaction_type: TransactionType::raw_message(decoded.transaction.unwrap()),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.encoded, "4sQC2vErivBMzvwS14LQ7sL3FjrFLviFjqxLFDTAqi8C9SrUtD6z79ed5ADEQpax8pDoJdvwwnFTFEtkc5U8QeR1uJ8SC8KNP9wux6T5aMFEgwbV32FF6cbabPKzuVWraYPiLbEphx6UpbN3qjG7Rw4sMrjPYX1JSB8bsgYjRythtYf7b25JuYAFNSK9eh2bTtjh7yNi9SdB1aCxJETZvsAwGSLBC7DA8hQwrPye4z9AUEfkyQZe7hHvgpRrhp5QTyK6yxqizEtoruXc655SNegvnqHMdXtvZqcxm4kHeUSExTDw6qc3MnYn4MWAnttdEwnSuM7teuqY2Zn96LBs7oQA64QrkHjXB1Fm8PRQ3sdPRQyxeq5M9tgsHV9x4BxmtR389QpRXm2mCLacnuBtp6z9ZqY2Errr8EQx4veeVzLpm4DFpwmVS1xZ5R3fAdXCEZb6crz7bXFBNQrxCAHGTZj38V8GXQ4vBNNqerdJSdndB3ttP1");
+    assert_eq!(output.signatures.len(), 2);
+    assert_eq!(
+        output.signatures[0].pubkey,
+        "B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V"
+    );
+    assert_eq!(
+        output.signatures[0].signature,
+        "3ynA3vuVpqHWP471jnYpGdiMpPjF19aLmMiCAf6P7x5TsJhNmNdfwYWmxj8webvrZC5WNRwRGrc8uJa9c3nWpX3K"
+    );
+    assert_eq!(
+        output.signatures[1].pubkey,
+        "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ"
+    );
+    assert_eq!(
+        output.signatures[1].signature,
+        "53tBE46sWseVkMbBfbrUNeVaAe3vXDXSZw6TUM8KDcgGLqJjijJgky5bbPiG1z5PyYYy2WitRkhSKoBGvNVeHGSj"
+    );
+}
+
+#[test]
+fn test_solana_decode_unknown_version() {
+    // The message version prefix `0x80` is replaced with `0x81`.
+    let mut encoded = PARTIALLY_SIGNED_V0.decode_hex().unwrap();
+    encoded[129] = 0x81;
+    let output_data = decode_transaction(CoinType::Solana, encoded);
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
+
+#[test]
+fn test_solana_decode_truncated() {
+    let mut encoded = PARTIALLY_SIGNED_V0.decode_hex().unwrap();
+    encoded.pop();
+    let output_data = decode_transaction(CoinType::Solana, encoded);
+    let output: Proto::DecodingTransactionOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+}
+
+#[test]
+fn test_solana_decode_unsupported_coin() {
+    let input = Proto::DecodingTransactionInput {
+        encoded_tx: PARTIALLY_SIGNED_V0.decode_hex().unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_solana_decode_transaction(CoinType::Ethereum as u32, input_data.ptr())
+    });
+    assert!(output_data.is_null());
+}
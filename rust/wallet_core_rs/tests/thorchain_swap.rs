@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_entry::error::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Cosmos::Proto as CosmosProto;
+use tw_proto::THORChain::Proto;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::thorchain::swap::tw_thorchain_build_swap_deposit;
+
+fn rune_coin(amount: &str) -> CosmosProto::THORChainCoin<'_> {
+    CosmosProto::THORChainCoin {
+        asset: Some(CosmosProto::THORChainAsset {
+            chain: "THOR".into(),
+            symbol: "RUNE".into(),
+            ticker: "RUNE".into(),
+            ..CosmosProto::THORChainAsset::default()
+        }),
+        amount: amount.into(),
+        decimals: 0,
+    }
+}
+
+/// Returns serialized `Proto::SwapDepositOutput`.
+fn build_swap_deposit(input: &Proto::SwapDepositInput<'_>) -> Vec<u8> {
+    let input_data = TWDataHelper::create(serialize(input).unwrap());
+    TWDataHelper::wrap(unsafe { tw_thorchain_build_swap_deposit(input_data.ptr()) })
+        .to_vec()
+        .expect("!tw_thorchain_build_swap_deposit returned nullptr")
+}
+
+#[test]
+fn test_thorchain_build_swap_deposit_and_sign() {
+    let input = Proto::SwapDepositInput {
+        coin: Some(rune_coin("150000000")),
+        to_asset: "DOGE.DOGE".into(),
+        to_address: "DNhRF1h8J4ZnB1bxp9kaqhVLYetkx1nSJ5".into(),
+        affiliates: vec![Proto::Affiliate {
+            name: "tr".into(),
+            fee_bps: 0,
+        }],
+        // thor14j5lwl8ulexrqp5x39kmkctv2937694z3jn2dz
+        signer: "aca9f77cfcfe4c300686896dbb616c5163ed16a2"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        ..Proto::SwapDepositInput::default()
+    };
+
+    let output_data = build_swap_deposit(&input);
+    let output: Proto::SwapDepositOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.memo,
+        "=:DOGE.DOGE:DNhRF1h8J4ZnB1bxp9kaqhVLYetkx1nSJ5::tr:0"
+    );
+
+    let input = CosmosProto::SigningInput {
+        signing_mode: CosmosProto::SigningMode::Protobuf,
+        account_number: 75247,
+        chain_id: "thorchain-mainnet-v1".into(),
+        sequence: 7,
+        fee: Some(CosmosProto::Fee {
+            gas: 50000000,
+            ..CosmosProto::Fee::default()
+        }),
+        private_key: "2659e41d54ebd449d68b9d58510d8eeeb837ee00d6ecc760b7a731238d8c3113"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        messages: vec![output.message.expect("Expected a deposit message")],
+        ..CosmosProto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<CosmosProto::SigningOutput>::default();
+    let output = signer.sign(CoinType::THORChain, input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    // https://viewblock.io/thorchain/tx/0162213E7F9D85965B1C57FA3BF9603C655B542F358318303A7B00661AE42510
+    assert_eq!(
+        output.serialized,
+        r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CoUBCoIBChEvdHlwZXMuTXNnRGVwb3NpdBJtCh8KEgoEVEhPUhIEUlVORRoEUlVORRIJMTUwMDAwMDAwEjQ9OkRPR0UuRE9HRTpETmhSRjFoOEo0Wm5CMWJ4cDlrYXFoVkxZZXRreDFuU0o1Ojp0cjowGhSsqfd8/P5MMAaGiW27YWxRY+0WohJZClAKRgofL2Nvc21vcy5jcnlwdG8uc2VjcDI1NmsxLlB1YktleRIjCiEDuZVDlIFW3DtSEBa6aUBJ0DrQHlQ+2g7lIt5ekAM25SkSBAoCCAEYBxIFEIDh6xcaQAxKMZMKbM8gdLwn23GDXfbwyCkgqWzFMFlnrqFm0u54F8T32wmsoJQAdoLIyOskYmi7nb1rhryfabeeULwRhiw="}"#
+    );
+    assert_eq!(output.signature.to_hex(), "0c4a31930a6ccf2074bc27db71835df6f0c82920a96cc5305967aea166d2ee7817c4f7db09aca094007682c8c8eb246268bb9dbd6b86bc9f69b79e50bc11862c");
+}
+
+#[test]
+fn test_thorchain_build_swap_deposit_multiple_affiliates() {
+    let input = Proto::SwapDepositInput {
+        coin: Some(rune_coin("100000000")),
+        to_asset: "ETH.ETH".into(),
+        to_address: "0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7".into(),
+        to_amount_limit: "0".into(),
+        stream_interval: 1,
+        affiliates: vec![
+            Proto::Affiliate {
+                name: "t".into(),
+                fee_bps: 10,
+            },
+            Proto::Affiliate {
+                name: "ss".into(),
+                fee_bps: 5,
+            },
+        ],
+        ..Proto::SwapDepositInput::default()
+    };
+
+    let output_data = build_swap_deposit(&input);
+    let output: Proto::SwapDepositOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.memo,
+        "=:ETH.ETH:0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7:0/1/0:t/ss:10/5"
+    );
+}
+
+#[test]
+fn test_thorchain_build_swap_deposit_invalid() {
+    // Invalid destination asset.
+    let input = Proto::SwapDepositInput {
+        coin: Some(rune_coin("100000000")),
+        to_asset: "DOGE".into(),
+        to_address: "DNhRF1h8J4ZnB1bxp9kaqhVLYetkx1nSJ5".into(),
+        ..Proto::SwapDepositInput::default()
+    };
+    let output_data = build_swap_deposit(&input);
+    let output: Proto::SwapDepositOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_memo);
+    assert!(output.message.is_none());
+
+    // Affiliate fee is greater than 10%.
+    let input = Proto::SwapDepositInput {
+        to_asset: "DOGE.DOGE".into(),
+        affiliates: vec![Proto::Affiliate {
+            name: "tr".into(),
+            fee_bps: 1001,
+        }],
+        ..input
+    };
+    let output_data = build_swap_deposit(&input);
+    let output: Proto::SwapDepositOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
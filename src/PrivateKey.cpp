@@ -189,7 +189,8 @@ PublicKey PrivateKey::getPublicKey(TWPublicKeyType type) const {
         break;
     }
 
-    case TWPublicKeyTypeStarkex: {
+    case TWPublicKeyTypeStarkex:
+    case TWPublicKeyTypeSr25519: {
         result = rust_get_public_from_private(this->bytes, type);
         break;
     }
@@ -241,7 +242,8 @@ Data PrivateKey::sign(const Data& digest, TWCurve curve) const {
             result.resize(65);
             success = ecdsa_sign_digest_checked(&nist256p1, key().data(), digest.data(), digest.size(), result.data(), result.data() + 64, nullptr) == 0;
         } break;
-    case TWCurveStarkex: {
+    case TWCurveStarkex:
+    case TWCurveSr25519: {
         result = rust_private_key_sign(key(), digest, curve);
         success = result.size() == 64;
     } break;
@@ -43,6 +43,8 @@ bool PublicKey::isValid(const Data& data, enum TWPublicKeyType type) {
         return size == secp256k1ExtendedSize && data[0] == 0x04;
     case TWPublicKeyTypeStarkex:
         return size == starkexSize;
+    case TWPublicKeyTypeSr25519:
+        return size == sr25519Size;
     default:
         return false;
     }
@@ -58,6 +60,7 @@ PublicKey::PublicKey(const Data& data, enum TWPublicKeyType type)
     }
     switch (type) {
     case TWPublicKeyTypeStarkex:
+    case TWPublicKeyTypeSr25519:
     case TWPublicKeyTypeSECP256k1:
     case TWPublicKeyTypeNIST256p1:
     case TWPublicKeyTypeSECP256k1Extended:
@@ -172,6 +175,7 @@ bool PublicKey::verify(const Data& signature, const Data& message) const {
         return ed25519_sign_open(message.data(), message.size(), ed25519PublicKey.data(), verifyBuffer.data()) == 0;
     }
     case TWPublicKeyTypeStarkex:
+    case TWPublicKeyTypeSr25519:
         return rust_public_key_verify(bytes, type, signature, message);
     default:
         throw std::logic_error("Not yet implemented");
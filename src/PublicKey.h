@@ -25,6 +25,9 @@ class PublicKey {
     /// The number of bytes in an starkex public key.
     static const size_t starkexSize = 32;
 
+    /// The number of bytes in an sr25519 public key.
+    static const size_t sr25519Size = 32;
+
     /// The number of bytes in a Cardano public key (two ed25519 public key + chain code).
     static const size_t cardanoKeySize = 2 * 2 * 32;
 